@@ -24,6 +24,18 @@ pub enum AttestError {
     Unauthorized,
     #[msg("Schema already exists.")]
     SchemaAlreadyExists,
+    #[msg("Attestation already exists.")]
+    AttestationExists,
+    #[msg("Signer is not in the verifier set.")]
+    NotVerifier,
+    #[msg("Program is paused.")]
+    Paused,
+    #[msg("Levy recipient does not match the schema.")]
+    WrongRecipient,
+    #[msg("No levies to withdraw.")]
+    NothingToWithdraw,
+    #[msg("Rewards program account does not match the schema.")]
+    RewardsProgramMismatch,
     // #[msg("Unauthorized authority.")]
     // Unauthorized,
 }
@@ -11,8 +11,8 @@ pub struct Attested {
     pub attester: Pubkey,
     /// Unique identifier (PDA) of the attestation.
     pub uid: Pubkey,
-    /// Timestamp of when the attestation was created.
-    pub time: u64,
+    /// Unix timestamp of when the attestation was created.
+    pub time: i64,
 }
 
 #[event]
@@ -25,14 +25,68 @@ pub struct Revoked {
     pub attester: Pubkey,
     /// Unique identifier (PDA) of the attestation.
     pub uid: Pubkey,
-    /// Timestamp of when the attestation was revoked.
-    pub time: u64,
+    /// Unix timestamp of when the attestation was revoked.
+    pub time: i64,
 }
 
 #[event]
 pub struct VerifiedAuthoritySignal {
     pub authority: Pubkey,
     pub is_verified: bool,
+    /// The verifier who reviewed the authority.
+    pub verified_by: Pubkey,
+    /// Timestamp of the review.
+    pub verified_at: i64,
+    /// URI of the evidence backing the review.
+    pub evidence_uri: String,
+}
+
+#[event]
+pub struct PauseToggled {
+    /// The admin who toggled the pause.
+    pub admin: Pubkey,
+    /// Whether the program is now paused.
+    pub paused: bool,
+}
+
+#[event]
+pub struct ProtocolFeeUpdated {
+    /// The admin who set the fee.
+    pub admin: Pubkey,
+    /// Share of levy withdrawals paid to the admin, in basis points.
+    pub protocol_fee_bps: u16,
+}
+
+#[event]
+pub struct LeviesWithdrawn {
+    /// The recipient the levies accrued to.
+    pub recipient: Pubkey,
+    /// SOL paid to the recipient.
+    pub lamports: u64,
+    /// Mint of the withdrawn tokens.
+    pub mint: Pubkey,
+    /// Tokens paid to the recipient, in base units.
+    pub tokens: u64,
+    /// SOL paid to the admin as the protocol share.
+    pub protocol_lamports: u64,
+    /// Tokens paid to the admin as the protocol share, in base units.
+    pub protocol_tokens: u64,
+}
+
+#[event]
+pub struct RewardsProgramUpdated {
+    /// Schema UID (PDA) the rewards program is set on.
+    pub schema: Pubkey,
+    /// Program called after every attestation, or `None` if the hook was removed.
+    pub rewards_program: Option<Pubkey>,
+}
+
+#[event]
+pub struct VerifierSetUpdated {
+    /// The key that manages the set.
+    pub admin: Pubkey,
+    /// Keys allowed to verify authorities.
+    pub verifiers: Vec<Pubkey>,
 }
 
 #[event]
@@ -1,17 +1,29 @@
 mod attest;
 mod delegated_attest;
+mod migrate;
 mod revoke;
 
 pub use attest::*;
 pub use delegated_attest::*;
+pub use migrate::*;
 pub use revoke::*;
 
 mod register_authority;
+mod verifier_set;
 mod verify_authority;
 
 pub use register_authority::*;
+pub use verifier_set::*;
 pub use verify_authority::*;
 
 mod create_schema;
+mod rewards;
 
 pub use create_schema::*;
+pub use rewards::*;
+
+mod config;
+mod levy;
+
+pub use config::*;
+pub use levy::*;
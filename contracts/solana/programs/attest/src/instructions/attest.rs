@@ -1,11 +1,13 @@
 use crate::errors::AttestError;
 use crate::events::Attested;
-use crate::state::{Attestation, SchemaData};
+use crate::state::{
+    Attestation, AttesterStats, LevyVault, ProgramConfig, RecentAttestations, SchemaData,
+};
+use crate::utils::{accrue_levy, invoke_rewards_hook, OnAttested};
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{transfer, Mint, Token, TokenAccount, Transfer},
+    token::{Mint, Token, TokenAccount},
 };
 
 #[derive(Accounts)]
@@ -33,13 +35,23 @@ pub struct Attest<'info> {
     )]
     pub attester_token_account: Account<'info, TokenAccount>,
 
+    /// The levy recipient's vault, where the levy accrues until withdrawn.
+    #[account(
+        init_if_needed,
+        payer = attester,
+        space = LevyVault::LEN,
+        seeds = [b"levy_vault", levy_receipent.key().as_ref()],
+        bump
+    )]
+    pub levy_vault: Account<'info, LevyVault>,
+
     #[account(
         init_if_needed,
         payer = attester,
         associated_token::mint = mint_account,
-        associated_token::authority = levy_receipent,
+        associated_token::authority = levy_vault,
     )]
-    pub levy_receipent_token_account: Account<'info, TokenAccount>,
+    pub levy_vault_token_account: Account<'info, TokenAccount>,
 
     /// The schema data account; must match the schema UID.
     #[account(
@@ -48,7 +60,7 @@ pub struct Attest<'info> {
     pub schema_data: Account<'info, SchemaData>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = attester,
         space = Attestation::LEN,
         seeds = [b"attestation", schema_data.key().as_ref(), recipient.key.as_ref(), attester.key.as_ref()],
@@ -56,6 +68,30 @@ pub struct Attest<'info> {
     )]
     pub attestation: Account<'info, Attestation>,
 
+    /// The schema's most recent attestations.
+    #[account(
+        init_if_needed,
+        payer = attester,
+        space = RecentAttestations::LEN,
+        seeds = [b"recent_attestations", schema_data.key().as_ref()],
+        bump
+    )]
+    pub recent_attestations: Account<'info, RecentAttestations>,
+
+    /// The attester's activity totals.
+    #[account(
+        init_if_needed,
+        payer = attester,
+        space = AttesterStats::LEN,
+        seeds = [b"attester_stats", attester.key.as_ref()],
+        bump
+    )]
+    pub attester_stats: Account<'info, AttesterStats>,
+
+    /// The program config; the instruction fails while it is paused.
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, ProgramConfig>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -65,69 +101,42 @@ pub fn attest_handler(
     ctx: Context<Attest>,
     data: String,
     ref_uid: Option<Pubkey>,
-    expiration_time: Option<u64>,
+    expiration_time: Option<i64>,
     revocable: bool,
 ) -> Result<()> {
+    require!(!ctx.accounts.config.paused, AttestError::Paused);
+
     let schema_data = &ctx.accounts.schema_data;
     let attestation = &mut ctx.accounts.attestation;
-    let current_time = Clock::get()?.unix_timestamp as u64;
+    let current_time = Clock::get()?.unix_timestamp;
     let levy = schema_data.levy.clone();
 
     require!(schema_data.to_account_info().owner == ctx.program_id, AttestError::InvalidData);
 
-    if let Some(lev) = levy {
-        // if asset is none, use SOL.
-        if lev.asset.is_none() {
-            system_program::transfer(
-                CpiContext::new(
-                    ctx.accounts.system_program.to_account_info(),
-                    system_program::Transfer {
-                        from: ctx.accounts.attester.to_account_info(),
-                        to: ctx.accounts.levy_receipent.to_account_info(),
-                    },
-                ),
-                lev.amount,
-            )?;
-        } else {
-            require!(
-                lev.asset.unwrap() == ctx.accounts.mint_account.key(),
-                AttestError::WrongAsset
-            );
-
-            transfer(
-                CpiContext::new(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.attester_token_account.to_account_info(),
-                        to: ctx.accounts.levy_receipent_token_account.to_account_info(),
-                        authority: ctx.accounts.attester.to_account_info(),
-                    },
-                ),
-                lev.amount * 10u64.pow(ctx.accounts.mint_account.decimals as u32), // Transfer amount, adjust for decimals
-            )?;
-        }
-    } else {
-        require!(
-            ctx.accounts.levy_receipent.key() == Pubkey::default(),
-            AttestError::ShouldBeUnused
-        );
-        require!(
-            ctx.accounts.levy_receipent_token_account.key() == Pubkey::default(),
-            AttestError::ShouldBeUnused
-        );
+    // Check if the attestation already exists by verifying if 'attester' is set.
+    if attestation.attester != Pubkey::default() {
+        return Err(AttestError::AttestationExists.into());
     }
 
+    accrue_levy(
+        levy,
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.attester.to_account_info(),
+        ctx.accounts.attester_token_account.to_account_info(),
+        ctx.accounts.levy_receipent.to_account_info(),
+        &mut ctx.accounts.levy_vault,
+        ctx.accounts.levy_vault_token_account.to_account_info(),
+        &ctx.accounts.mint_account,
+    )?;
+
     // Ensure data size is within limits
     if data.len() > Attestation::MAX_DATA_SIZE {
         return Err(AttestError::DataTooLarge.into());
     }
 
     // Ensure expiration time is in the future, if provided
-    if let Some(exp_time) = expiration_time {
-        if exp_time <= current_time {
-            return Err(AttestError::InvalidExpirationTime.into());
-        }
-    }
+    Attestation::validate_expiration(expiration_time, current_time)?;
 
     // Populate attestation fields
     attestation.schema = schema_data.uid;
@@ -140,6 +149,14 @@ pub fn attest_handler(
     attestation.revocable = revocable;
     attestation.revocation_time = None;
     attestation.uid = attestation.key();
+    attestation.version = Attestation::VERSION;
+
+    ctx.accounts
+        .recent_attestations
+        .record(schema_data.uid, attestation.uid);
+    ctx.accounts
+        .attester_stats
+        .record_issued(attestation.attester, current_time);
 
     // Emit an event to notify off-chain clients.
     emit!(Attested {
@@ -150,5 +167,17 @@ pub fn attest_handler(
         time: attestation.time,
     });
 
+    // Let the schema's rewards program react to the attestation.
+    invoke_rewards_hook(
+        schema_data.rewards_program,
+        ctx.remaining_accounts,
+        OnAttested {
+            schema: schema_data.uid,
+            recipient: attestation.recipient,
+            attester: attestation.attester,
+            uid: attestation.uid,
+        },
+    )?;
+
     Ok(())
 }
@@ -0,0 +1,90 @@
+use crate::errors::AttestError;
+use crate::events::{PauseToggled, ProtocolFeeUpdated};
+use crate::state::ProgramConfig;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    /// The program config, created once; its creator becomes the admin.
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"config"],
+        bump,
+        space = ProgramConfig::LEN,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ AttestError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetProtocolFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ AttestError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Creates the program config, unpaused and without a protocol fee.
+pub fn initialize_config_handler(ctx: Context<InitializeConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = *ctx.accounts.admin.key;
+    config.paused = false;
+    config.protocol_fee_bps = 0;
+    Ok(())
+}
+
+/// Pauses or unpauses attesting, revoking and creating schemas (admin only).
+///
+/// Acts as a circuit breaker while an incident is investigated; reads are unaffected.
+pub fn set_paused_handler(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.paused = paused;
+
+    emit!(PauseToggled {
+        admin: config.admin,
+        paused,
+    });
+
+    Ok(())
+}
+
+/// Sets the share of levy withdrawals paid to the admin, in basis points (admin only).
+pub fn set_protocol_fee_handler(ctx: Context<SetProtocolFee>, protocol_fee_bps: u16) -> Result<()> {
+    require!(
+        protocol_fee_bps <= ProgramConfig::MAX_PROTOCOL_FEE_BPS,
+        AttestError::InvalidData
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.protocol_fee_bps = protocol_fee_bps;
+
+    emit!(ProtocolFeeUpdated {
+        admin: config.admin,
+        protocol_fee_bps,
+    });
+
+    Ok(())
+}
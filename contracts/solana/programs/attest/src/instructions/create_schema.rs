@@ -1,6 +1,6 @@
 use crate::errors::AttestError;
 use crate::events::SchemaCreated;
-use crate::state::{AuthorityRecord, Levy, SchemaData};
+use crate::state::{AuthorityRecord, Levy, ProgramConfig, SchemaData, SchemaIndexPage};
 use anchor_lang::prelude::*;
 // use authority_resolver::AuthorityRecord;
 
@@ -27,6 +27,24 @@ pub struct CreateSchema<'info> {
     )]
     pub schema_data: Account<'info, SchemaData>,
 
+    /// The deployer's current schema index page.
+    #[account(
+        init_if_needed,
+        seeds = [
+            b"schema_index",
+            deployer.key().as_ref(),
+            &(authority_record.schema_count / SchemaIndexPage::CAPACITY).to_le_bytes(),
+        ],
+        bump,
+        payer = deployer,
+        space = SchemaIndexPage::LEN
+    )]
+    pub schema_index: Account<'info, SchemaIndexPage>,
+
+    /// The program config; the instruction fails while it is paused.
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, ProgramConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -38,6 +56,8 @@ pub fn create_schema_handler(
     revocable: bool,
     levy: Option<Levy>,
 ) -> Result<()> {
+    require!(!ctx.accounts.config.paused, AttestError::Paused);
+
     let schema_data = &mut ctx.accounts.schema_data;
 
     // Check if the schema already exists by verifying if 'deployer' is set.
@@ -56,6 +76,14 @@ pub fn create_schema_handler(
     schema_data.levy = levy;
     schema_data.deployer = *ctx.accounts.deployer.key;
 
+    // List the schema on the deployer's current index page.
+    let authority_record = &mut ctx.accounts.authority_record;
+    let schema_index = &mut ctx.accounts.schema_index;
+    schema_index.authority = *ctx.accounts.deployer.key;
+    schema_index.page = authority_record.schema_count / SchemaIndexPage::CAPACITY;
+    schema_index.schemas.push(uid);
+    authority_record.schema_count += 1;
+
     // Emit an event to notify off-chain clients.
     emit!(SchemaCreated {
         uid,
@@ -65,7 +93,8 @@ pub fn create_schema_handler(
             resolver: schema_data.resolver,
             revocable: schema_data.revocable,
             deployer: schema_data.deployer,
-            levy: schema_data.levy.clone()
+            levy: schema_data.levy.clone(),
+            rewards_program: schema_data.rewards_program,
         }
     });
 
@@ -1,7 +1,12 @@
 use crate::errors::AttestError;
 use crate::events::Attested;
-use crate::state::{Attestation, AttestationData, AttesterInfo, SchemaData};
-use crate::utils::{create_verify_signature_instruction, settle_levy};
+use crate::state::{
+    Attestation, AttestationData, AttesterInfo, AttesterStats, LevyVault, ProgramConfig,
+    RecentAttestations, SchemaData,
+};
+use crate::utils::{
+    accrue_levy, create_verify_signature_instruction, invoke_rewards_hook, OnAttested,
+};
 use anchor_lang::{prelude::*, solana_program};
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -40,13 +45,23 @@ pub struct DelegatedAttest<'info> {
     )]
     pub delegated_attester_token_account: Account<'info, TokenAccount>,
 
+    /// The levy recipient's vault, where the levy accrues until withdrawn.
+    #[account(
+        init_if_needed,
+        payer = delegated_attester,
+        space = LevyVault::LEN,
+        seeds = [b"levy_vault", levy_receipent.key().as_ref()],
+        bump
+    )]
+    pub levy_vault: Account<'info, LevyVault>,
+
     #[account(
         init_if_needed,
         payer = delegated_attester,
         associated_token::mint = mint_account,
-        associated_token::authority = levy_receipent,
+        associated_token::authority = levy_vault,
     )]
-    pub levy_receipent_token_account: Account<'info, TokenAccount>,
+    pub levy_vault_token_account: Account<'info, TokenAccount>,
 
     /// The schema data account; must match the schema UID.
     #[account(
@@ -56,7 +71,7 @@ pub struct DelegatedAttest<'info> {
     pub schema_data: Account<'info, SchemaData>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = delegated_attester,
         space = Attestation::LEN,
         seeds = [b"attestation", schema_data.key().as_ref(), recipient.as_ref(), attester.as_ref()],
@@ -64,6 +79,30 @@ pub struct DelegatedAttest<'info> {
     )]
     pub attestation: Account<'info, Attestation>,
 
+    /// The schema's most recent attestations.
+    #[account(
+        init_if_needed,
+        payer = delegated_attester,
+        space = RecentAttestations::LEN,
+        seeds = [b"recent_attestations", schema_data.key().as_ref()],
+        bump
+    )]
+    pub recent_attestations: Account<'info, RecentAttestations>,
+
+    /// The attester's activity totals.
+    #[account(
+        init_if_needed,
+        payer = delegated_attester,
+        space = AttesterStats::LEN,
+        seeds = [b"attester_stats", attester.as_ref()],
+        bump
+    )]
+    pub attester_stats: Account<'info, AttesterStats>,
+
+    /// The program config; the instruction fails while it is paused.
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, ProgramConfig>,
+
     // pub schema_registry_program: Program<'info, SchemaRegistry>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -102,12 +141,19 @@ pub fn delegated_attest_handler(
     )
     .expect("ed25519 verify invoke call failed");
 
+    require!(!ctx.accounts.config.paused, AttestError::Paused);
+
     let schema_data = &ctx.accounts.schema_data;
     let attestation = &mut ctx.accounts.attestation;
-    let current_time = Clock::get()?.unix_timestamp as u64;
+    let current_time = Clock::get()?.unix_timestamp;
     let levy = schema_data.levy.clone();
 
-    settle_levy(
+    // Check if the attestation already exists by verifying if 'attester' is set.
+    if attestation.attester != Pubkey::default() {
+        return Err(AttestError::AttestationExists.into());
+    }
+
+    accrue_levy(
         levy,
         ctx.accounts.system_program.to_account_info(),
         ctx.accounts.token_program.to_account_info(),
@@ -116,10 +162,10 @@ pub fn delegated_attest_handler(
             .delegated_attester_token_account
             .to_account_info(),
         ctx.accounts.levy_receipent.to_account_info(),
-        ctx.accounts.levy_receipent_token_account.to_account_info(),
+        &mut ctx.accounts.levy_vault,
+        ctx.accounts.levy_vault_token_account.to_account_info(),
         &ctx.accounts.mint_account,
-    )
-    .expect("levy settlement failed");
+    )?;
 
     // Ensure data size is within limits
     if attestation_data.data.len() > Attestation::MAX_DATA_SIZE {
@@ -127,11 +173,7 @@ pub fn delegated_attest_handler(
     }
 
     // Ensure expiration time is in the future, if provided
-    if let Some(exp_time) = attestation_data.expiration_time {
-        if exp_time <= current_time as u64 {
-            return Err(AttestError::InvalidExpirationTime.into());
-        }
-    }
+    Attestation::validate_expiration(attestation_data.expiration_time, current_time)?;
 
     // Populate attestation fields
     attestation.schema = schema_data.uid;
@@ -144,6 +186,14 @@ pub fn delegated_attest_handler(
     attestation.revocable = attestation_data.revocable;
     attestation.revocation_time = None;
     attestation.uid = attestation.key();
+    attestation.version = Attestation::VERSION;
+
+    ctx.accounts
+        .recent_attestations
+        .record(schema_data.uid, attestation.uid);
+    ctx.accounts
+        .attester_stats
+        .record_issued(attestation.attester, current_time);
 
     // Emit an event to notify off-chain clients.
     emit!(Attested {
@@ -154,5 +204,17 @@ pub fn delegated_attest_handler(
         time: attestation.time,
     });
 
+    // Let the schema's rewards program react to the attestation.
+    invoke_rewards_hook(
+        schema_data.rewards_program,
+        ctx.remaining_accounts,
+        OnAttested {
+            schema: schema_data.uid,
+            recipient: attestation.recipient,
+            attester: attestation.attester,
+            uid: attestation.uid,
+        },
+    )?;
+
     Ok(())
 }
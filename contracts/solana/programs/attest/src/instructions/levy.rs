@@ -0,0 +1,129 @@
+use crate::errors::AttestError;
+use crate::events::LeviesWithdrawn;
+use crate::state::{LevyVault, ProgramConfig};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer, Mint, Token, TokenAccount, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct WithdrawLevies<'info> {
+    /// The recipient the levies accrued to.
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"levy_vault", recipient.key().as_ref()],
+        bump,
+        has_one = recipient,
+    )]
+    pub levy_vault: Account<'info, LevyVault>,
+
+    /// The program config; sets the protocol share and who receives it.
+    #[account(seeds = [b"config"], bump, has_one = admin)]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// CHECK: Receives the protocol share; must be the config admin.
+    #[account(mut)]
+    pub admin: UncheckedAccount<'info>,
+
+    /// Mint of the token levies to withdraw alongside the SOL levies.
+    pub mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_account,
+        associated_token::authority = levy_vault,
+    )]
+    pub levy_vault_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        associated_token::mint = mint_account,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        associated_token::mint = mint_account,
+        associated_token::authority = admin,
+    )]
+    pub admin_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Pays out a recipient's accrued SOL levies and their token levies in one mint.
+///
+/// The protocol share set in the config goes to the admin; the rest goes to the recipient.
+pub fn withdraw_levies_handler(ctx: Context<WithdrawLevies>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lamports = ctx.accounts.levy_vault.accrued_lamports;
+    let tokens = ctx.accounts.levy_vault_token_account.amount;
+    require!(lamports > 0 || tokens > 0, AttestError::NothingToWithdraw);
+
+    // Reset the accrual before moving funds
+    let vault = &mut ctx.accounts.levy_vault;
+    vault.accrued_lamports = 0;
+    vault.withdrawn_lamports += lamports;
+
+    // The vault is owned by this program, so its lamports can be debited directly
+    let protocol_lamports = config.protocol_share(lamports);
+    if lamports > 0 {
+        **vault.to_account_info().try_borrow_mut_lamports()? -= lamports;
+        **ctx.accounts.recipient.try_borrow_mut_lamports()? += lamports - protocol_lamports;
+        **ctx.accounts.admin.try_borrow_mut_lamports()? += protocol_lamports;
+    }
+
+    let protocol_tokens = config.protocol_share(tokens);
+    if tokens > 0 {
+        let recipient_key = ctx.accounts.recipient.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"levy_vault",
+            recipient_key.as_ref(),
+            &[ctx.bumps.levy_vault],
+        ]];
+        let payouts = [
+            (
+                &ctx.accounts.recipient_token_account,
+                tokens - protocol_tokens,
+            ),
+            (&ctx.accounts.admin_token_account, protocol_tokens),
+        ];
+        for (to, amount) in payouts {
+            if amount == 0 {
+                continue;
+            }
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.levy_vault_token_account.to_account_info(),
+                        to: to.to_account_info(),
+                        authority: ctx.accounts.levy_vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    emit!(LeviesWithdrawn {
+        recipient: ctx.accounts.recipient.key(),
+        lamports: lamports - protocol_lamports,
+        mint: ctx.accounts.mint_account.key(),
+        tokens: tokens - protocol_tokens,
+        protocol_lamports,
+        protocol_tokens,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use crate::state::Attestation;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateAttestation<'info> {
+    /// Pays for the extra bytes of the current layout; anyone may migrate an attestation.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        realloc = Attestation::LEN,
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrades an attestation written with unsigned timestamps to the current layout.
+///
+/// Version `0` accounts stored `u64` timestamps, which share their encoding with `i64` for
+/// every time before 2^63; larger expirations read as negative and are clamped to `i64::MAX`.
+pub fn migrate_attestation_handler(ctx: Context<MigrateAttestation>) -> Result<()> {
+    let attestation = &mut ctx.accounts.attestation;
+    if attestation.version >= Attestation::VERSION {
+        return Ok(());
+    }
+
+    if let Some(expiration_time) = attestation.expiration_time {
+        if expiration_time < 0 {
+            attestation.expiration_time = Some(i64::MAX);
+        }
+    }
+    attestation.version = Attestation::VERSION;
+
+    Ok(())
+}
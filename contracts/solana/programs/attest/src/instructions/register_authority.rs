@@ -28,6 +28,10 @@ pub fn register_authority_handler(ctx: Context<RegisterAuthority>) -> Result<()>
         authority_record.authority = *ctx.accounts.authority.key;
         authority_record.is_verified = false;
         authority_record.first_deployment = Clock::get()?.unix_timestamp;
+        authority_record.verified_by = None;
+        authority_record.verified_at = None;
+        authority_record.evidence_uri = String::new();
+        authority_record.schema_count = 0;
     }
 
     // Return the AuthorityRecord struct itself
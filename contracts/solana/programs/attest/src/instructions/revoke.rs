@@ -1,6 +1,6 @@
 use crate::errors::AttestError;
 use crate::events::Revoked;
-use crate::state::Attestation;
+use crate::state::{Attestation, AttesterStats, ProgramConfig};
 use anchor_lang::prelude::*;
 
 /// Context for the `revoke` instruction, which revokes an existing attestation.
@@ -8,7 +8,7 @@ use anchor_lang::prelude::*;
 /// Accounts:
 /// - `attester`: The signer who is revoking the attestation.
 /// - `attestation`: The attestation account to be revoked.
-/// - `schema`: The schema data account associated with the attestation.
+/// - `attester_stats`: The attester's activity totals.
 #[derive(Accounts)]
 #[instruction(schema_uid: Pubkey, recipient: Pubkey)]
 pub struct Revoke<'info> {
@@ -24,6 +24,22 @@ pub struct Revoke<'info> {
     )]
     /// The attestation account to be revoked.
     pub attestation: Account<'info, Attestation>,
+
+    /// The attester's activity totals; created here for attestations issued before they were kept.
+    #[account(
+        init_if_needed,
+        payer = attester,
+        space = AttesterStats::LEN,
+        seeds = [b"attester_stats", attester.key.as_ref()],
+        bump
+    )]
+    pub attester_stats: Account<'info, AttesterStats>,
+
+    /// The program config; the instruction fails while it is paused.
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub system_program: Program<'info, System>,
 }
 
 /// Revokes an existing attestation and emits a `Revoked` event.
@@ -42,6 +58,7 @@ pub struct Revoke<'info> {
 /// * `AttestError::Irrevocable` - If the attestation is marked as irrevocable.
 /// * `AttestError::AlreadyRevoked` - If the attestation has already been revoked.
 /// * `AttestError::InvalidSchema` - If the attestation's schema does not match the provided schema UID.
+/// * `AttestError::Paused` - If the program is paused.
 ///
 /// # Implementation Details
 ///
@@ -49,6 +66,7 @@ pub struct Revoke<'info> {
 /// - **Revocability Check**: Ensures that the attestation is revocable.
 /// - **Revocation Status Check**: Ensures that the attestation has not already been revoked.
 /// - **Revocation Time Update**: Sets the `revocation_time` to the current timestamp.
+/// - **Attester Stats**: Counts the revocation in the attester's stats account.
 /// - **Event Emission**: Emits a `Revoked` event for off-chain indexing.
 ///
 /// # Why We Are Doing This
@@ -61,6 +79,8 @@ pub fn revoke_attestation_handler(
     _schema_uid: Pubkey,
     _recipient: Pubkey,
 ) -> Result<()> {
+    require!(!ctx.accounts.config.paused, AttestError::Paused);
+
     let attestation = &mut ctx.accounts.attestation;
 
     // Ensure the attestation is revocable
@@ -74,7 +94,11 @@ pub fn revoke_attestation_handler(
     }
 
     // Set revocation time
-    attestation.revocation_time = Some(Clock::get()?.unix_timestamp as u64);
+    let current_time = Clock::get()?.unix_timestamp;
+    attestation.revocation_time = Some(current_time);
+    ctx.accounts
+        .attester_stats
+        .record_revoked(attestation.attester, current_time);
 
     // Emit an event to notify off-chain clients.
     emit!(Revoked {
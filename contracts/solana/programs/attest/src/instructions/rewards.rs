@@ -0,0 +1,34 @@
+use crate::errors::AttestError;
+use crate::events::RewardsProgramUpdated;
+use crate::state::SchemaData;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRewardsProgram<'info> {
+    /// The schema's deployer.
+    pub deployer: Signer<'info>,
+
+    /// The schema the rewards program is set on.
+    #[account(
+        mut,
+        has_one = deployer @ AttestError::Unauthorized,
+    )]
+    pub schema_data: Account<'info, SchemaData>,
+}
+
+/// Sets the program called with `on_attested` after every attestation under a schema,
+/// or removes it when `rewards_program` is `None`.
+pub fn set_rewards_program_handler(
+    ctx: Context<SetRewardsProgram>,
+    rewards_program: Option<Pubkey>,
+) -> Result<()> {
+    let schema_data = &mut ctx.accounts.schema_data;
+    schema_data.rewards_program = rewards_program;
+
+    emit!(RewardsProgramUpdated {
+        schema: schema_data.uid,
+        rewards_program,
+    });
+
+    Ok(())
+}
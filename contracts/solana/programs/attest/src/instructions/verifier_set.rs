@@ -0,0 +1,64 @@
+use crate::errors::AttestError;
+use crate::events::VerifierSetUpdated;
+use crate::state::VerifierSet;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeVerifierSet<'info> {
+    /// The verifier set, created once; its creator becomes the set admin.
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"verifier_set"],
+        bump,
+        space = VerifierSet::LEN,
+    )]
+    pub verifier_set: Account<'info, VerifierSet>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetVerifiers<'info> {
+    #[account(
+        mut,
+        seeds = [b"verifier_set"],
+        bump,
+        has_one = admin @ AttestError::Unauthorized,
+    )]
+    pub verifier_set: Account<'info, VerifierSet>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Creates the verifier set with its initial verifiers.
+pub fn initialize_verifier_set_handler(
+    ctx: Context<InitializeVerifierSet>,
+    verifiers: Vec<Pubkey>,
+) -> Result<()> {
+    let verifier_set = &mut ctx.accounts.verifier_set;
+    verifier_set.admin = *ctx.accounts.admin.key;
+    write_verifiers(verifier_set, verifiers)
+}
+
+/// Replaces the verifiers in the set (set admin only).
+pub fn set_verifiers_handler(ctx: Context<SetVerifiers>, verifiers: Vec<Pubkey>) -> Result<()> {
+    write_verifiers(&mut ctx.accounts.verifier_set, verifiers)
+}
+
+fn write_verifiers(verifier_set: &mut VerifierSet, verifiers: Vec<Pubkey>) -> Result<()> {
+    if verifiers.len() > VerifierSet::MAX_VERIFIERS {
+        return Err(AttestError::DataTooLarge.into());
+    }
+    verifier_set.verifiers = verifiers;
+
+    emit!(VerifierSetUpdated {
+        admin: verifier_set.admin,
+        verifiers: verifier_set.verifiers.clone(),
+    });
+
+    Ok(())
+}
@@ -1,31 +1,49 @@
+use crate::errors::AttestError;
 use crate::events::VerifiedAuthoritySignal;
-use crate::state::AuthorityRecord;
+use crate::state::{AuthorityRecord, VerifierSet};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct VerifyAuthority<'info> {
     #[account(mut)]
     pub authority_record: Account<'info, AuthorityRecord>,
-    #[account(signer)]
-    pub admin: Signer<'info>, // The admin account
-}
 
-/// Verifies the authority (admin only).
-pub fn verify_authority_handler(ctx: Context<VerifyAuthority>, is_verified: bool) -> Result<()> {
-    // let expected_admin_pubkey = Pubkey::from_str(ADMIN_PUBLIC_KEY).unwrap();
+    /// The verifier set the signer must belong to.
+    #[account(
+        seeds = [b"verifier_set"],
+        bump,
+        constraint = verifier_set.verifiers.contains(verifier.key) @ AttestError::NotVerifier,
+    )]
+    pub verifier_set: Account<'info, VerifierSet>,
 
-    // require_keys_eq!(expected_admin_pubkey, ctx.accounts.admin.key());
+    /// The verifier reviewing the authority.
+    pub verifier: Signer<'info>,
+}
 
-    // if ctx.accounts.admin.key() != expected_admin_pubkey {
-    //     return Err(AuthorityError::Unauthorized.into());
-    // }
+/// Verifies the authority (verifier set members only), recording who reviewed it,
+/// when, and the evidence the decision rests on.
+pub fn verify_authority_handler(
+    ctx: Context<VerifyAuthority>,
+    is_verified: bool,
+    evidence_uri: String,
+) -> Result<()> {
+    // Ensure evidence URI size is within limits
+    if evidence_uri.len() > AuthorityRecord::MAX_EVIDENCE_URI_SIZE {
+        return Err(AttestError::DataTooLarge.into());
+    }
 
     let authority_record = &mut ctx.accounts.authority_record;
     authority_record.is_verified = is_verified;
+    authority_record.verified_by = Some(*ctx.accounts.verifier.key);
+    authority_record.verified_at = Some(Clock::get()?.unix_timestamp);
+    authority_record.evidence_uri = evidence_uri;
 
     emit!(VerifiedAuthoritySignal {
         authority: authority_record.authority,
         is_verified: authority_record.is_verified,
+        verified_by: *ctx.accounts.verifier.key,
+        verified_at: authority_record.verified_at.unwrap_or_default(),
+        evidence_uri: authority_record.evidence_uri.clone(),
     });
 
     Ok(())
@@ -32,7 +32,7 @@ pub mod attest {
         ctx: Context<Attest>,
         data: String,
         ref_uid: Option<Pubkey>,
-        expiration_time: Option<u64>,
+        expiration_time: Option<i64>,
         revocable: bool,
     ) -> Result<()> {
         attest_handler(ctx, data, ref_uid, expiration_time, revocable)
@@ -56,12 +56,47 @@ pub mod attest {
         revoke_attestation_handler(ctx, schema_uid, recipient)
     }
 
+    pub fn migrate_attestation(ctx: Context<MigrateAttestation>) -> Result<()> {
+        migrate_attestation_handler(ctx)
+    }
+
     pub fn register_authority(ctx: Context<RegisterAuthority>) -> Result<()> {
         register_authority_handler(ctx)
     }
 
-    pub fn verify_authority(ctx: Context<VerifyAuthority>, is_verified: bool) -> Result<()> {
-        verify_authority_handler(ctx, is_verified)
+    pub fn verify_authority(
+        ctx: Context<VerifyAuthority>,
+        is_verified: bool,
+        evidence_uri: String,
+    ) -> Result<()> {
+        verify_authority_handler(ctx, is_verified, evidence_uri)
+    }
+
+    pub fn initialize_verifier_set(
+        ctx: Context<InitializeVerifierSet>,
+        verifiers: Vec<Pubkey>,
+    ) -> Result<()> {
+        initialize_verifier_set_handler(ctx, verifiers)
+    }
+
+    pub fn set_verifiers(ctx: Context<SetVerifiers>, verifiers: Vec<Pubkey>) -> Result<()> {
+        set_verifiers_handler(ctx, verifiers)
+    }
+
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        initialize_config_handler(ctx)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        set_paused_handler(ctx, paused)
+    }
+
+    pub fn set_protocol_fee(ctx: Context<SetProtocolFee>, protocol_fee_bps: u16) -> Result<()> {
+        set_protocol_fee_handler(ctx, protocol_fee_bps)
+    }
+
+    pub fn withdraw_levies(ctx: Context<WithdrawLevies>) -> Result<()> {
+        withdraw_levies_handler(ctx)
     }
 
     pub fn create_schema(
@@ -74,4 +109,11 @@ pub mod attest {
     ) -> Result<()> {
         create_schema_handler(ctx, schema_name, schema, resolver, revocable, levy)
     }
+
+    pub fn set_rewards_program(
+        ctx: Context<SetRewardsProgram>,
+        rewards_program: Option<Pubkey>,
+    ) -> Result<()> {
+        set_rewards_program_handler(ctx, rewards_program)
+    }
 }
@@ -1,3 +1,4 @@
+use crate::errors::AttestError;
 use anchor_lang::prelude::*;
 
 #[account]
@@ -10,22 +11,26 @@ pub struct Attestation {
     pub attester: Pubkey, // 32 bytes
     /// Custom data associated with the attestation.
     pub data: String, // 4 bytes length prefix + data
-    /// Timestamp of when the attestation was created.
-    pub time: u64, // 8 bytes
+    /// Unix timestamp of when the attestation was created.
+    pub time: i64, // 8 bytes
     /// Reference to another attestation UID, if any.
     pub ref_uid: Option<Pubkey>, // 1 byte option tag + 32 bytes
-    /// Optional expiration time of the attestation.
-    pub expiration_time: Option<u64>, // 1 byte option tag + 8 bytes
-    /// Timestamp of when the attestation was revoked, if revoked.
-    pub revocation_time: Option<u64>, // 1 byte option tag + 8 bytes
+    /// Optional Unix timestamp the attestation expires at.
+    pub expiration_time: Option<i64>, // 1 byte option tag + 8 bytes
+    /// Unix timestamp of when the attestation was revoked, if revoked.
+    pub revocation_time: Option<i64>, // 1 byte option tag + 8 bytes
     /// Indicates whether the attestation is revocable.
     pub revocable: bool, // 1 byte
     /// Unique identifier (PDA) of this attestation.
     pub uid: Pubkey, // 32 bytes
+    /// Layout version; `0` for accounts written before timestamps were signed.
+    pub version: u8, // 1 byte
 }
 
 impl Attestation {
     pub const MAX_DATA_SIZE: usize = 1000; // Adjust as needed
+    /// Layout version written by this program: signed `i64` timestamps.
+    pub const VERSION: u8 = 1;
     pub const LEN: usize = 8  // Discriminator
         + 32  // schema UID Pubkey:PDA
         + 32  // recipient Pubkey
@@ -36,7 +41,16 @@ impl Attestation {
         + 1 + 8   // expiration_time Option<i64>
         + 1 + 8   // revocation_time Option<i64>
         + 1   // revocable bool
-        + 32; // uid Pubkey:PDA
+        + 32  // uid Pubkey:PDA
+        + 1; // version u8
+
+    /// Checks that an expiration time, if any, lies after `now`.
+    pub fn validate_expiration(expiration_time: Option<i64>, now: i64) -> Result<()> {
+        if let Some(expiration_time) = expiration_time {
+            require!(expiration_time > now, AttestError::InvalidExpirationTime);
+        }
+        Ok(())
+    }
 }
 
 #[account]
@@ -45,7 +59,7 @@ pub struct AttestationData {
     pub recipient: Pubkey,
     pub data: String,
     pub ref_uid: Option<Pubkey>,
-    pub expiration_time: Option<u64>,
+    pub expiration_time: Option<i64>,
     pub revocable: bool,
     pub nonce: u64, // For uniqueness and replay protection
 }
@@ -60,12 +74,161 @@ pub struct AttesterInfo {
 #[account]
 pub struct AuthorityRecord {
     pub authority: Pubkey,     // The public key of the authority (e.g., user).
-    pub is_verified: bool,     // Flag to check if the authority is verified by an admin.
+    pub is_verified: bool,     // Flag to check if the authority is verified by a verifier.
     pub first_deployment: i64, // Timestamp of their first schema deployment.
+    /// The verifier who last reviewed the authority.
+    pub verified_by: Option<Pubkey>,
+    /// Timestamp of the last review.
+    pub verified_at: Option<i64>,
+    /// URI of the evidence backing the last review.
+    pub evidence_uri: String,
+    /// Number of schemas the authority has created; selects the current `SchemaIndexPage`.
+    pub schema_count: u32,
 }
 
 impl AuthorityRecord {
-    pub const LEN: usize = 8 + 32 + 1 + 8; // Account discriminator + field sizes
+    pub const MAX_EVIDENCE_URI_SIZE: usize = 200;
+    pub const LEN: usize = 8  // Discriminator
+        + 32  // authority Pubkey
+        + 1   // is_verified bool
+        + 8   // first_deployment i64
+        + 1 + 32  // verified_by Option<Pubkey>
+        + 1 + 8   // verified_at Option<i64>
+        + 4 + Self::MAX_EVIDENCE_URI_SIZE // evidence_uri String (length prefix + data)
+        + 4; // schema_count u32
+}
+
+/// One page of the schema UIDs created by an authority, stored at the
+/// `["schema_index", authority, page]` PDA so clients can enumerate them page by page.
+#[account]
+pub struct SchemaIndexPage {
+    /// The authority whose schemas are listed.
+    pub authority: Pubkey, // 32 bytes
+    /// Page number; page `n` holds schemas `n * CAPACITY` to `(n + 1) * CAPACITY - 1`.
+    pub page: u32, // 4 bytes
+    /// Schema UIDs, oldest first.
+    pub schemas: Vec<Pubkey>, // 4 bytes length prefix + 32 bytes per schema
+}
+
+impl SchemaIndexPage {
+    pub const CAPACITY: u32 = 32;
+    pub const LEN: usize = 8  // Discriminator
+        + 32  // authority Pubkey
+        + 4   // page u32
+        + 4 + 32 * Self::CAPACITY as usize; // schemas Vec<Pubkey>
+}
+
+/// The most recent attestation PDAs under a schema, stored at the
+/// `["recent_attestations", schema]` PDA. Once full, the oldest entry is overwritten.
+#[account]
+pub struct RecentAttestations {
+    /// Schema UID (PDA) the attestations were issued under.
+    pub schema: Pubkey, // 32 bytes
+    /// Position the next attestation is written to once the buffer is full.
+    pub next: u32, // 4 bytes
+    /// Attestation PDAs; in insertion order until the buffer wraps at `next`.
+    pub attestations: Vec<Pubkey>, // 4 bytes length prefix + 32 bytes per attestation
+}
+
+impl RecentAttestations {
+    pub const CAPACITY: u32 = 32;
+    pub const LEN: usize = 8  // Discriminator
+        + 32  // schema UID Pubkey:PDA
+        + 4   // next u32
+        + 4 + 32 * Self::CAPACITY as usize; // attestations Vec<Pubkey>
+
+    /// Records an attestation, overwriting the oldest entry once the buffer is full.
+    pub fn record(&mut self, schema: Pubkey, attestation: Pubkey) {
+        self.schema = schema;
+        if (self.attestations.len() as u32) < Self::CAPACITY {
+            self.attestations.push(attestation);
+        } else {
+            self.attestations[self.next as usize] = attestation;
+        }
+        self.next = (self.next + 1) % Self::CAPACITY;
+    }
+}
+
+/// Activity totals for an attester, stored at the `["attester_stats", attester]` PDA so
+/// issuer profiles can be shown without scanning the attester's full history.
+#[account]
+pub struct AttesterStats {
+    /// The attester the totals belong to.
+    pub attester: Pubkey, // 32 bytes
+    /// Attestations issued, including ones since revoked.
+    pub issued: u64, // 8 bytes
+    /// Attestations revoked.
+    pub revoked: u64, // 8 bytes
+    /// Unix timestamp of the attester's first attestation.
+    pub first_activity: i64, // 8 bytes
+    /// Unix timestamp of the attester's latest attestation or revocation.
+    pub last_activity: i64, // 8 bytes
+}
+
+impl AttesterStats {
+    pub const LEN: usize = 8  // Discriminator
+        + 32  // attester Pubkey
+        + 8   // issued u64
+        + 8   // revoked u64
+        + 8   // first_activity i64
+        + 8; // last_activity i64
+
+    /// Counts an attestation issued at `now`.
+    pub fn record_issued(&mut self, attester: Pubkey, now: i64) {
+        self.attester = attester;
+        if self.issued == 0 {
+            self.first_activity = now;
+        }
+        self.issued += 1;
+        self.last_activity = now;
+    }
+
+    /// Counts an attestation revoked at `now`.
+    pub fn record_revoked(&mut self, attester: Pubkey, now: i64) {
+        self.attester = attester;
+        self.revoked += 1;
+        self.last_activity = now;
+    }
+}
+
+/// Program-wide settings, stored at the `config` PDA.
+#[account]
+pub struct ProgramConfig {
+    /// The key allowed to pause and unpause the program.
+    pub admin: Pubkey, // 32 bytes
+    /// Whether attesting, revoking and creating schemas is halted.
+    pub paused: bool, // 1 byte
+    /// Share of every levy withdrawal paid to the admin, in basis points.
+    pub protocol_fee_bps: u16, // 2 bytes
+}
+
+impl ProgramConfig {
+    pub const MAX_PROTOCOL_FEE_BPS: u16 = 10_000;
+    pub const LEN: usize = 8  // Discriminator
+        + 32  // admin Pubkey
+        + 1   // paused bool
+        + 2; // protocol_fee_bps u16
+
+    /// Returns the protocol's share of a withdrawn amount.
+    pub fn protocol_share(&self, amount: u64) -> u64 {
+        (amount as u128 * self.protocol_fee_bps as u128 / 10_000) as u64
+    }
+}
+
+/// The set of keys allowed to verify authorities, stored at the `verifier_set` PDA.
+#[account]
+pub struct VerifierSet {
+    /// The key that manages the set.
+    pub admin: Pubkey, // 32 bytes
+    /// Keys allowed to call `verify_authority`.
+    pub verifiers: Vec<Pubkey>, // 4 bytes length prefix + 32 bytes per verifier
+}
+
+impl VerifierSet {
+    pub const MAX_VERIFIERS: usize = 10;
+    pub const LEN: usize = 8  // Discriminator
+        + 32  // admin Pubkey
+        + 4 + 32 * Self::MAX_VERIFIERS; // verifiers Vec<Pubkey>
 }
 
 #[account]
@@ -86,6 +249,9 @@ pub struct SchemaData {
     pub deployer: Pubkey,
 
     pub levy: Option<Levy>,
+
+    /// Program called with `on_attested` after every attestation under the schema.
+    pub rewards_program: Option<Pubkey>,
 }
 
 impl SchemaData {
@@ -93,8 +259,9 @@ impl SchemaData {
     // 32 bytes for uid,
     // 1 byte for revocable,
     // 200 bytes for schema string,
-    // 32 bytes for deployer pubkey.
-    pub const LEN: usize = 8 + 32 + 1 + 200 + 32;
+    // 32 bytes for deployer pubkey,
+    // 33 bytes for rewards_program option.
+    pub const LEN: usize = 8 + 32 + 1 + 200 + 32 + 1 + 32;
 }
 
 #[account]
@@ -109,3 +276,24 @@ pub struct Levy {
     /// 32 bytes (Recipient of the levy)
     pub recipient: Pubkey,
 }
+
+/// Levies accrued to a recipient, stored at the `["levy_vault", recipient]` PDA until withdrawn.
+///
+/// SOL levies are held as lamports on the vault itself and tracked in `accrued_lamports`;
+/// token levies are held in the vault's associated token account for each mint.
+#[account]
+pub struct LevyVault {
+    /// The recipient the levies accrue to.
+    pub recipient: Pubkey, // 32 bytes
+    /// SOL levies accrued since the last withdrawal.
+    pub accrued_lamports: u64, // 8 bytes
+    /// SOL levies withdrawn over the vault's lifetime, including the protocol share.
+    pub withdrawn_lamports: u64, // 8 bytes
+}
+
+impl LevyVault {
+    pub const LEN: usize = 8  // Discriminator
+        + 32  // recipient Pubkey
+        + 8   // accrued_lamports u64
+        + 8; // withdrawn_lamports u64
+}
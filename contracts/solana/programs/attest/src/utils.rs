@@ -1,9 +1,12 @@
 use crate::errors::AttestError;
-use crate::state::{AttesterInfo, Levy};
+use crate::state::{AttesterInfo, Levy, LevyVault};
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use anchor_spl::token::{transfer, Mint, Transfer};
+use solana_program::hash::hash;
 use solana_program::instruction::Instruction;
+use solana_program::keccak;
+use solana_program::program::invoke;
 
 pub fn create_verify_signature_instruction(
     program_id: &Pubkey,
@@ -30,34 +33,60 @@ pub fn create_verify_signature_instruction(
     })
 }
 
-pub fn settle_levy<'info>(
+/// Derives a deterministic attestation UID from a schema, recipient and nonce.
+///
+/// Computes `keccak256(schema_uid || recipient || nonce)` with the nonce as big-endian
+/// bytes, the Solana counterpart of the Stellar protocol's UID with raw keys in place of
+/// XDR. The TypeScript SDK's `generateAttestationUid` derives the same bytes off-chain.
+pub fn generate_attestation_uid(schema_uid: &Pubkey, recipient: &Pubkey, nonce: u64) -> [u8; 32] {
+    keccak::hashv(&[
+        schema_uid.as_ref(),
+        recipient.as_ref(),
+        &nonce.to_be_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Charges a schema's levy to the attester and accrues it in the recipient's levy vault.
+///
+/// Recipients collect accrued levies with `withdraw_levies`.
+pub fn accrue_levy<'info>(
     levy: Option<Levy>,
     system_program: AccountInfo<'info>,
     token_program: AccountInfo<'info>,
     from: AccountInfo<'info>,
     from_token: AccountInfo<'info>,
     recipient: AccountInfo<'info>,
-    recipient_token: AccountInfo<'info>,
+    vault: &mut Account<'info, LevyVault>,
+    vault_token: AccountInfo<'info>,
     mint: &Account<'info, Mint>,
 ) -> Result<()> {
     match levy {
         Some(lev) => {
+            require!(
+                recipient.key() == lev.recipient,
+                AttestError::WrongRecipient
+            );
+            vault.recipient = lev.recipient;
+
             match lev.asset {
                 None => {
-                    // Transfer SOL
+                    // Hold SOL on the vault itself
                     system_program::transfer(
                         CpiContext::new(
                             system_program.clone(),
                             system_program::Transfer {
                                 from: from.clone(),
-                                to: recipient.clone(),
+                                to: vault.to_account_info(),
                             },
                         ),
                         lev.amount,
-                    )
+                    )?;
+                    vault.accrued_lamports += lev.amount;
+                    Ok(())
                 }
                 Some(asset) => {
-                    // Transfer tokens
+                    // Hold tokens in the vault's token account
                     require!(asset == mint.key(), AttestError::WrongAsset);
 
                     let adjusted_amount = lev.amount * 10u64.pow(mint.decimals as u32);
@@ -66,7 +95,7 @@ pub fn settle_levy<'info>(
                             token_program.clone(),
                             Transfer {
                                 from: from_token.clone(),
-                                to: recipient_token.clone(),
+                                to: vault_token.clone(),
                                 authority: from.clone(),
                             },
                         ),
@@ -82,10 +111,94 @@ pub fn settle_levy<'info>(
                 AttestError::ShouldBeUnused
             );
             require!(
-                recipient_token.key() == Pubkey::default(),
+                vault_token.key() == Pubkey::default(),
                 AttestError::ShouldBeUnused
             );
             Ok(())
         }
     }
 }
+
+/// The attestation passed to a schema's rewards program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OnAttested {
+    /// Schema UID associated with the attestation.
+    pub schema: Pubkey,
+    /// The recipient of the attestation.
+    pub recipient: Pubkey,
+    /// The attester who created the attestation.
+    pub attester: Pubkey,
+    /// Unique identifier (PDA) of the attestation.
+    pub uid: Pubkey,
+}
+
+/// Calls the `on_attested` instruction of a schema's rewards program, if it has one.
+///
+/// The first remaining account must be the rewards program; the rest are forwarded to it
+/// as its instruction accounts. Signer privileges are not forwarded, so the rewards
+/// program can only pay out from accounts it controls.
+pub fn invoke_rewards_hook<'info>(
+    rewards_program: Option<Pubkey>,
+    remaining_accounts: &[AccountInfo<'info>],
+    attested: OnAttested,
+) -> Result<()> {
+    let Some(program_id) = rewards_program else {
+        return Ok(());
+    };
+
+    let program = remaining_accounts
+        .first()
+        .ok_or(AttestError::RewardsProgramMismatch)?;
+    require!(
+        program.key() == program_id && program.executable,
+        AttestError::RewardsProgramMismatch
+    );
+
+    let accounts = remaining_accounts[1..]
+        .iter()
+        .map(|account| {
+            if account.is_writable {
+                AccountMeta::new(account.key(), false)
+            } else {
+                AccountMeta::new_readonly(account.key(), false)
+            }
+        })
+        .collect();
+
+    // Anchor instruction discriminator followed by the Borsh-encoded attestation
+    let mut data = hash(b"global:on_attested").to_bytes()[..8].to_vec();
+    attested.serialize(&mut data)?;
+
+    invoke(
+        &Instruction {
+            program_id,
+            accounts,
+            data,
+        },
+        remaining_accounts,
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shared with `packages/solana-sdk/__tests__/uid.test.ts`.
+    #[test]
+    fn generate_attestation_uid_matches_sdk_vector() {
+        let uid = generate_attestation_uid(
+            &Pubkey::new_from_array([1; 32]),
+            &Pubkey::new_from_array([2; 32]),
+            7,
+        );
+        assert_eq!(
+            uid,
+            [
+                0xa8, 0xa6, 0xda, 0x8c, 0x91, 0x30, 0x58, 0xeb, 0xb2, 0x49, 0xeb, 0x76, 0x5d, 0x84,
+                0x02, 0xed, 0x53, 0xeb, 0x51, 0xc0, 0x45, 0xbd, 0x77, 0x77, 0x91, 0xa3, 0xad, 0xb2,
+                0x74, 0x82, 0x77, 0xa1,
+            ]
+        );
+    }
+}
@@ -25,9 +25,17 @@ describe('attest.so', () => {
   const attestKeypair = anchor.web3.Keypair.generate()
   let schemaUID: PublicKey
 
+  // Schema index pages are addressed by their page number as a u32 little-endian seed
+  const pageSeed = (page: number) => {
+    const seed = Buffer.alloc(4)
+    seed.writeUInt32LE(page)
+    return seed
+  }
+
   let mintAcount: PublicKey
   let attesterTokenAccount: PublicKey
   let levyTokenAccount: PublicKey
+  let levyVaultTokenAccount: PublicKey
 
   before(async () => {
     console.log('Starting setup...')
@@ -78,6 +86,13 @@ describe('attest.so', () => {
     )
     console.log('Created levy token account:', levyTokenAccount.toBase58())
 
+    // Levies accrue in the recipient's vault until withdrawn
+    const [levyVaultPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('levy_vault'), levyRecipientKeypair.publicKey.toBuffer()],
+      attest.programId
+    )
+    levyVaultTokenAccount = await getAssociatedTokenAddress(mintAcount, levyVaultPDA, true)
+
     await mintTo(
       provider.connection,
       authorityKeypair,
@@ -87,6 +102,12 @@ describe('attest.so', () => {
       10_000
     )
     console.log('Minted tokens to attester token account')
+
+    await attest.methods
+      .initializeConfig()
+      .accounts({ admin: provider.wallet.publicKey })
+      .rpc()
+    console.log('Initialized program config')
   })
 
   it('user can register authority', async () => {
@@ -112,6 +133,111 @@ describe('attest.so', () => {
     expect(authorityAccount.isVerified).to.be.false
   })
 
+  it('verifier set member can verify authority with evidence', async () => {
+    console.log('Verifying authority...')
+    const [authorityRecordPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('authority'), authorityKeypair.publicKey.toBuffer()],
+      attest.programId
+    )
+    const [verifierSetPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('verifier_set')],
+      attest.programId
+    )
+    const evidenceUri = 'ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi'
+
+    await attest.methods
+      .initializeVerifierSet([provider.wallet.publicKey])
+      .accounts({ admin: provider.wallet.publicKey })
+      .rpc()
+
+    // Keys outside the verifier set cannot verify
+    let rejected = false
+    try {
+      await attest.methods
+        .verifyAuthority(true, evidenceUri)
+        .accounts({
+          authorityRecord: authorityRecordPDA,
+          verifier: unauthorityKeypair.publicKey,
+        })
+        .signers([unauthorityKeypair])
+        .rpc()
+    } catch (error) {
+      rejected = true
+      expect((error as AnchorError).error.errorCode.code).to.equal('NotVerifier')
+    }
+    expect(rejected).to.be.true()
+
+    await attest.methods
+      .verifyAuthority(true, evidenceUri)
+      .accounts({
+        authorityRecord: authorityRecordPDA,
+        verifier: provider.wallet.publicKey,
+      })
+      .rpc()
+    console.log('Authority verified successfully')
+
+    const authorityAccount = await attest.account.authorityRecord.fetch(authorityRecordPDA)
+    expect(authorityAccount.isVerified).to.be.true()
+    expect(authorityAccount.verifiedBy.toBase58()).to.equal(provider.wallet.publicKey.toBase58())
+    expect(authorityAccount.verifiedAt).to.be.not_null()
+    expect(authorityAccount.evidenceUri).to.equal(evidenceUri)
+
+    const verifierSet = await attest.account.verifierSet.fetch(verifierSetPDA)
+    expect(verifierSet.admin.toBase58()).to.equal(provider.wallet.publicKey.toBase58())
+  })
+
+  it('admin can pause and unpause the program', async () => {
+    console.log('Pausing program...')
+    const schemaName = 'paused-schema'
+    const [authorityRecordPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('authority'), authorityKeypair.publicKey.toBuffer()],
+      attest.programId
+    )
+    const [schemaIndexPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('schema_index'), authorityKeypair.publicKey.toBuffer(), pageSeed(0)],
+      attest.programId
+    )
+    const expectRejected = async (call: () => Promise<string>, code: string) => {
+      let rejected = false
+      try {
+        await call()
+      } catch (error) {
+        rejected = true
+        expect((error as AnchorError).error.errorCode.code).to.equal(code)
+      }
+      expect(rejected).to.be.true()
+    }
+
+    // Only the config admin can toggle the pause
+    await expectRejected(
+      () =>
+        attest.methods
+          .setPaused(true)
+          .accounts({ admin: unauthorityKeypair.publicKey })
+          .signers([unauthorityKeypair])
+          .rpc(),
+      'Unauthorized'
+    )
+
+    await attest.methods.setPaused(true).accounts({ admin: provider.wallet.publicKey }).rpc()
+    await expectRejected(
+      () =>
+        attest.methods
+          .createSchema(schemaName, '{"name": "paused"}', null, true, null)
+          .accounts({
+            deployer: authorityKeypair.publicKey,
+            authorityRecord: authorityRecordPDA,
+            schemaIndex: schemaIndexPDA,
+          })
+          .signers([authorityKeypair])
+          .rpc(),
+      'Paused'
+    )
+
+    await attest.methods.setPaused(false).accounts({ admin: provider.wallet.publicKey }).rpc()
+    console.log('Program unpaused')
+  })
+
   it('authority can create schema with token levy', async () => {
     console.log('Creating schema with token levy...')
     const schemaName = 'example-schema'
@@ -121,7 +247,7 @@ describe('attest.so', () => {
     const levy = {
       amount: new anchor.BN(10),
       asset: mintAcount,
-      recipient: authorityKeypair.publicKey,
+      recipient: levyRecipientKeypair.publicKey,
     }
 
     const [authorityRecordPDA, authorityRecordBump] = PublicKey.findProgramAddressSync(
@@ -136,11 +262,18 @@ describe('attest.so', () => {
     )
     console.log('Derived schemaData PDA:', schemaDataPDA.toBase58())
 
+    // The first schema lands on index page 0
+    const [schemaIndexPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('schema_index'), authorityKeypair.publicKey.toBuffer(), pageSeed(0)],
+      attest.programId
+    )
+
     await attest.methods
       .createSchema(schemaName, schemaContent, resolverAddress, revocable, levy)
       .accounts({
         deployer: authorityKeypair.publicKey,
         authorityRecord: authorityRecordPDA,
+        schemaIndex: schemaIndexPDA,
       })
       .signers([authorityKeypair])
       .rpc()
@@ -150,7 +283,7 @@ describe('attest.so', () => {
     console.log('Fetched schema data account:', schemeDataAccount)
     expect(schemeDataAccount.levy.amount.toNumber()).to.equal(10)
     expect(schemeDataAccount.levy.recipient.toBase58()).to.equal(
-      authorityKeypair.publicKey.toBase58()
+      levyRecipientKeypair.publicKey.toBase58()
     )
   })
 
@@ -192,16 +325,212 @@ describe('attest.so', () => {
       .rpc()
     console.log('Attestation completed successfully')
 
-    const levyTokenAccountBalance = await provider.connection.getTokenAccountBalance(
-      levyTokenAccount
+    const levyVaultBalance = await provider.connection.getTokenAccountBalance(
+      levyVaultTokenAccount
+    )
+    console.log('Levy vault balance:', levyVaultBalance.value.amount)
+    expect(levyVaultBalance.value.amount).to.equal('10')
+
+    const attestation = await attest.account.attestation.fetch(attestationPDA)
+    expect(attestation.version).to.equal(1)
+
+    const [attesterStatsPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('attester_stats'), attestKeypair.publicKey.toBuffer()],
+      attest.programId
     )
-    console.log('Levy token account balance:', levyTokenAccountBalance.value.amount)
-    expect(levyTokenAccountBalance.value.amount).to.equal('10')
+    const stats = await attest.account.attesterStats.fetch(attesterStatsPDA)
+    expect(stats.issued.toNumber()).to.equal(1)
+    expect(stats.revoked.toNumber()).to.equal(0)
+    expect(stats.firstActivity.toNumber()).to.equal(attestation.time.toNumber())
+    expect(stats.lastActivity.toNumber()).to.equal(attestation.time.toNumber())
 
     // Store schema UID for later tests
     schemaUID = schemaDataPDA
   })
 
+  it('attester cannot attest with an expiration in the past', async () => {
+    let rejected = false
+    try {
+      await attest.methods
+        .attest('Already expired.', null, new anchor.BN(Math.floor(Date.now() / 1000) - 60), true)
+        .accounts({
+          attester: attestKeypair.publicKey,
+          recipient: anchor.web3.Keypair.generate().publicKey,
+          levyReceipent: levyRecipientKeypair.publicKey,
+          mintAccount: mintAcount,
+          schemaData: schemaUID,
+        })
+        .signers([attestKeypair])
+        .rpc()
+    } catch (error) {
+      rejected = true
+      expect((error as AnchorError).error.errorCode.code).to.equal('InvalidExpirationTime')
+    }
+    expect(rejected).to.be.true()
+  })
+
+  it('attester cannot attest twice to the same recipient', async () => {
+    console.log('Attesting a duplicate...')
+    let rejected = false
+    try {
+      await attest.methods
+        .attest('This is a duplicate attestation.', null, null, true)
+        .accounts({
+          attester: attestKeypair.publicKey,
+          recipient: recipientKeypair.publicKey,
+          levyReceipent: levyRecipientKeypair.publicKey,
+          mintAccount: mintAcount,
+          schemaData: schemaUID,
+        })
+        .signers([attestKeypair])
+        .rpc()
+    } catch (error) {
+      rejected = true
+      expect((error as AnchorError).error.errorCode.code).to.equal('AttestationExists')
+    }
+    expect(rejected).to.be.true()
+
+    // The duplicate was rejected before any levy was charged
+    const levyVaultBalance = await provider.connection.getTokenAccountBalance(
+      levyVaultTokenAccount
+    )
+    expect(levyVaultBalance.value.amount).to.equal('10')
+  })
+
+  it('levy recipient can withdraw accrued levies less the protocol share', async () => {
+    const airdropSig = await provider.connection.requestAirdrop(
+      levyRecipientKeypair.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL
+    )
+    await provider.connection.confirmTransaction(airdropSig)
+
+    // 10% of every withdrawal goes to the admin
+    await attest.methods
+      .setProtocolFee(1_000)
+      .accounts({ admin: provider.wallet.publicKey })
+      .rpc()
+
+    await attest.methods
+      .withdrawLevies()
+      .accounts({
+        recipient: levyRecipientKeypair.publicKey,
+        admin: provider.wallet.publicKey,
+        mintAccount: mintAcount,
+      })
+      .signers([levyRecipientKeypair])
+      .rpc()
+
+    const levyVaultBalance = await provider.connection.getTokenAccountBalance(
+      levyVaultTokenAccount
+    )
+    expect(levyVaultBalance.value.amount).to.equal('0')
+    const recipientBalance = await provider.connection.getTokenAccountBalance(levyTokenAccount)
+    expect(recipientBalance.value.amount).to.equal('9')
+    const adminTokenAccount = await getAssociatedTokenAddress(mintAcount, provider.wallet.publicKey)
+    const adminBalance = await provider.connection.getTokenAccountBalance(adminTokenAccount)
+    expect(adminBalance.value.amount).to.equal('1')
+
+    // Nothing is left to withdraw
+    let rejected = false
+    try {
+      await attest.methods
+        .withdrawLevies()
+        .accounts({
+          recipient: levyRecipientKeypair.publicKey,
+          admin: provider.wallet.publicKey,
+          mintAccount: mintAcount,
+        })
+        .signers([levyRecipientKeypair])
+        .rpc()
+    } catch (error) {
+      rejected = true
+      expect((error as AnchorError).error.errorCode.code).to.equal('NothingToWithdraw')
+    }
+    expect(rejected).to.be.true()
+  })
+
+  it('index accounts list schemas by authority and recent attestations by schema', async () => {
+    const [schemaIndexPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('schema_index'), authorityKeypair.publicKey.toBuffer(), pageSeed(0)],
+      attest.programId
+    )
+    const schemaIndex = await attest.account.schemaIndexPage.fetch(schemaIndexPDA)
+    expect(schemaIndex.page).to.equal(0)
+    expect(schemaIndex.schemas.map((uid) => uid.toBase58())).to.deepEqual([schemaUID.toBase58()])
+
+    const [attestationPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('attestation'),
+        schemaUID.toBuffer(),
+        recipientKeypair.publicKey.toBuffer(),
+        attestKeypair.publicKey.toBuffer(),
+      ],
+      attest.programId
+    )
+    const [recentAttestationsPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('recent_attestations'), schemaUID.toBuffer()],
+      attest.programId
+    )
+    const recent = await attest.account.recentAttestations.fetch(recentAttestationsPDA)
+    expect(recent.schema.toBase58()).to.equal(schemaUID.toBase58())
+    expect(recent.attestations.map((uid) => uid.toBase58())).to.deepEqual([
+      attestationPDA.toBase58(),
+    ])
+  })
+
+  it('schema deployer can set a rewards program called after attestations', async () => {
+    // Only the schema's deployer can set its rewards program
+    let rejected = false
+    try {
+      await attest.methods
+        .setRewardsProgram(SystemProgram.programId)
+        .accounts({ deployer: unauthorityKeypair.publicKey, schemaData: schemaUID })
+        .signers([unauthorityKeypair])
+        .rpc()
+    } catch (error) {
+      rejected = true
+      expect((error as AnchorError).error.errorCode.code).to.equal('Unauthorized')
+    }
+    expect(rejected).to.be.true()
+
+    const rewardsProgram = anchor.web3.Keypair.generate().publicKey
+    await attest.methods
+      .setRewardsProgram(rewardsProgram)
+      .accounts({ deployer: authorityKeypair.publicKey, schemaData: schemaUID })
+      .signers([authorityKeypair])
+      .rpc()
+    const schemaData = await attest.account.schemaData.fetch(schemaUID)
+    expect(schemaData.rewardsProgram.toBase58()).to.equal(rewardsProgram.toBase58())
+
+    // Attesting without passing the rewards program fails the whole attestation
+    rejected = false
+    try {
+      await attest.methods
+        .attest('Rewarded attestation.', null, null, true)
+        .accounts({
+          attester: attestKeypair.publicKey,
+          recipient: anchor.web3.Keypair.generate().publicKey,
+          levyReceipent: levyRecipientKeypair.publicKey,
+          mintAccount: mintAcount,
+          schemaData: schemaUID,
+        })
+        .signers([attestKeypair])
+        .rpc()
+    } catch (error) {
+      rejected = true
+      expect((error as AnchorError).error.errorCode.code).to.equal('RewardsProgramMismatch')
+    }
+    expect(rejected).to.be.true()
+
+    await attest.methods
+      .setRewardsProgram(null)
+      .accounts({ deployer: authorityKeypair.publicKey, schemaData: schemaUID })
+      .signers([authorityKeypair])
+      .rpc()
+    const cleared = await attest.account.schemaData.fetch(schemaUID)
+    expect(cleared.rewardsProgram).to.be.null()
+  })
+
   it('attester can revoke attestation', async () => {
     console.log('Revoking attestation...')
 
@@ -237,6 +566,16 @@ describe('attest.so', () => {
     console.log('Attestation after revocation:', attestationAfter)
     expect(attestationAfter.revocationTime).to.be.not_null
 
+    // The revocation is counted in the attester's stats
+    const [attesterStatsPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('attester_stats'), attestKeypair.publicKey.toBuffer()],
+      attest.programId
+    )
+    const stats = await attest.account.attesterStats.fetch(attesterStatsPDA)
+    expect(stats.issued.toNumber()).to.equal(1)
+    expect(stats.revoked.toNumber()).to.equal(1)
+    expect(stats.lastActivity.toNumber()).to.equal(attestationAfter.revocationTime.toNumber())
+
     // Try to revoke again, should fail with AlreadyRevoked error
     try {
       await attest.methods
@@ -224,7 +224,8 @@ describe('Delegated Attestation Integration Tests', () => {
         subject: subjectKp.publicKey(),
         value: attestationValue,
         signature: Buffer.alloc(96),
-        expiration_time: undefined
+        expiration_time: undefined,
+        version: 1
     }
 
     // Get the DST for signing
@@ -322,7 +323,8 @@ describe('Delegated Attestation Integration Tests', () => {
         signature: Buffer.alloc(96),
         revoker: attestation.attester,
         schema_uid: attestation.schema_uid,
-        subject: attestation.subject
+        subject: attestation.subject,
+        version: 1
     }
 
     // Get the DST for revocation signing
@@ -325,7 +325,7 @@ export function generateAttestationUid(schemaUid: Buffer, subject: string, nonce
 /**
  * Creates the message to sign for delegated attestations.
  * Must match the exact format from `delegation.rs::create_attestation_message`.
- * The message is a concatenation of domain separator, schema UID, nonce, deadline,
+ * The message is a concatenation of domain separator, version, schema UID, nonce, deadline,
  * and value length, which is then hashed.
  *
  * @param request - The delegated attestation request object from the contract bindings.
@@ -334,12 +334,17 @@ export function generateAttestationUid(schemaUid: Buffer, subject: string, nonce
  */
 export function createAttestationMessage(request: ProtocolContract.DelegatedAttestationRequest, attestationDST: Buffer) {
   // Match exact format from Rust contract: 
-  // Domain Separator + Schema UID + Nonce + Deadline + [Expiration Time] + Value Length
+  // Domain Separator + Version + Schema UID + Nonce + Deadline + [Expiration Time] + Value Length
   const components: Buffer[] = []
   
   // Domain separation (ATTEST_PROTOCOL_V1_DELEGATED)
   components.push(attestationDST)
   
+  // Message format version (4 bytes, big-endian u32)
+  const versionBuffer = Buffer.allocUnsafe(4)
+  versionBuffer.writeUInt32BE(request.version, 0)
+  components.push(versionBuffer)
+  
   // Schema UID (32 bytes)
   components.push(Buffer.from(request.schema_uid))
   
@@ -367,7 +372,7 @@ export function createAttestationMessage(request: ProtocolContract.DelegatedAtte
 /**
  * Creates the message to sign for delegated revocations.
  * Must match the exact format from `delegation.rs::create_revocation_message`.
- * The message is a concatenation of domain separator, schema UID, nonce, and deadline,
+ * The message is a concatenation of domain separator, version, schema UID, nonce, and deadline,
  * which is then hashed.
  *
  * @param request - The delegated revocation request object from the contract bindings.
@@ -380,6 +385,11 @@ export function createRevocationMessage(request: ProtocolContract.DelegatedRevoc
   // Domain separation (REVOKE_PROTOCOL_V1_DELEGATED)
   components.push(revocationDST)
   
+  // Message format version (4 bytes, big-endian u32)
+  const versionBuffer = Buffer.allocUnsafe(4)
+  versionBuffer.writeUInt32BE(request.version, 0)
+  components.push(versionBuffer)
+  
   // Schema UID (32 bytes)
   components.push(Buffer.from(request.schema_uid))
   
@@ -11,6 +11,15 @@ pub const LEVY_WITHDRAWN: Symbol = symbol_short!("levy_wdrw");
 pub const OWNERSHIP_TRANSFERRED: Symbol = symbol_short!("own_trans");
 pub const OWNERSHIP_RENOUNCED: Symbol = symbol_short!("own_rncd");
 pub const PAYMENT_RECEIVED: Symbol = symbol_short!("pay_rcvd");
+pub const APPLICATION_SUBMITTED: Symbol = symbol_short!("app_subm");
+pub const APPLICATION_REVIEWED: Symbol = symbol_short!("app_rvwd");
+pub const BOND_POSTED: Symbol = symbol_short!("bond_post");
+pub const AUTHORITY_SLASHED: Symbol = symbol_short!("auth_slsh");
+pub const AUTHORITY_REINSTATED: Symbol = symbol_short!("auth_rein");
+pub const AUTHORITIES_IMPORTED: Symbol = symbol_short!("auth_imp");
+pub const HOOK_DECISION: Symbol = symbol_short!("hook_dec");
+pub const CONTRACT_UPGRADED: Symbol = symbol_short!("upgraded");
+pub const STORAGE_MIGRATED: Symbol = symbol_short!("migrated");
 
 // Helper functions to publish events with appropriate topics and data
 pub fn admin_register_authority(e: &Env, authority: &soroban_sdk::Address, metadata: &soroban_sdk::String) {
@@ -68,3 +77,83 @@ pub fn payment_received(e: &Env, payer: &soroban_sdk::Address, ref_id: &soroban_
         (payer.clone(), ref_id.clone(), amount),
     );
 }
+
+pub fn application_submitted(e: &Env, applicant: &soroban_sdk::Address, ref_id: &soroban_sdk::String) {
+    e.events().publish(
+        (APPLICATION_SUBMITTED, symbol_short!("submit")),
+        (applicant.clone(), ref_id.clone()),
+    );
+}
+
+pub fn application_approved(e: &Env, applicant: &soroban_sdk::Address) {
+    e.events()
+        .publish((APPLICATION_REVIEWED, symbol_short!("approve")), applicant.clone());
+}
+
+pub fn application_rejected(e: &Env, applicant: &soroban_sdk::Address, reason: &soroban_sdk::String) {
+    e.events().publish(
+        (APPLICATION_REVIEWED, symbol_short!("reject")),
+        (applicant.clone(), reason.clone()),
+    );
+}
+
+pub fn bond_posted(e: &Env, authority: &soroban_sdk::Address, amount: i128, total: i128) {
+    e.events().publish(
+        (BOND_POSTED, symbol_short!("deposit")),
+        (authority.clone(), amount, total),
+    );
+}
+
+pub fn authority_slashed(
+    e: &Env,
+    slasher: &soroban_sdk::Address,
+    authority: &soroban_sdk::Address,
+    amount: i128,
+    reason: &soroban_sdk::String,
+) {
+    e.events().publish(
+        (AUTHORITY_SLASHED, symbol_short!("slash")),
+        (slasher.clone(), authority.clone(), amount, reason.clone()),
+    );
+}
+
+pub fn authority_reinstated(e: &Env, authority: &soroban_sdk::Address) {
+    e.events()
+        .publish((AUTHORITY_REINSTATED, symbol_short!("reinstate")), authority.clone());
+}
+
+pub fn authorities_imported(e: &Env, admin: &soroban_sdk::Address, count: u32) {
+    e.events()
+        .publish((AUTHORITIES_IMPORTED, symbol_short!("import")), (admin.clone(), count));
+}
+
+pub fn contract_upgraded(e: &Env, admin: &soroban_sdk::Address, new_wasm_hash: &soroban_sdk::BytesN<32>) {
+    e.events().publish(
+        (CONTRACT_UPGRADED, symbol_short!("upgrade")),
+        (admin.clone(), new_wasm_hash.clone()),
+    );
+}
+
+pub fn storage_migrated(e: &Env, admin: &soroban_sdk::Address, from_version: u32, to_version: u32) {
+    e.events().publish(
+        (STORAGE_MIGRATED, symbol_short!("migrate")),
+        (admin.clone(), from_version, to_version),
+    );
+}
+
+/// Publishes the outcome of a resolver hook call; `rejection` is the ResolverError code, if rejected.
+///
+/// `hook` is the second topic (`attest` or `revoke`), so monitors can subscribe to either
+/// kind and reconcile each decision against the protocol event for the same UID.
+pub fn hook_decision(e: &Env, hook: Symbol, attestation: &crate::state::ResolverAttestation, rejection: Option<u32>) {
+    e.events().publish(
+        (HOOK_DECISION, hook),
+        (
+            attestation.uid.clone(),
+            attestation.schema_uid.clone(),
+            attestation.attester.clone(),
+            rejection.is_none(),
+            rejection.unwrap_or(0),
+        ),
+    );
+}
@@ -1,7 +1,7 @@
 use crate::errors::Error;
 use crate::events;
 use crate::state::{set_authority_data, set_registration_fee, RegisteredAuthorityData};
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 // Import macros we actually use
 use crate::require_owner;
 
@@ -43,6 +43,34 @@ pub fn admin_register_authority(
     Ok(())
 }
 
+/// Import pre-verified authority records, e.g. when migrating from a previous deployment.
+///
+/// Records are written as-is, so original registration times and ref_ids are preserved.
+/// Existing records for the same address are overwritten. Returns the number imported.
+pub fn admin_import_authorities(
+    env: &Env,
+    admin: &Address,
+    authorities: &Vec<RegisteredAuthorityData>,
+) -> Result<u32, Error> {
+    crate::admin_guard!(env, admin);
+
+    // Validate the whole batch before writing anything
+    let now = env.ledger().timestamp();
+    for data in authorities.iter() {
+        if data.registration_time > now {
+            return Err(Error::InvalidAuthorityData);
+        }
+    }
+
+    for data in authorities.iter() {
+        set_authority_data(env, &data);
+    }
+
+    events::authorities_imported(env, admin, authorities.len());
+
+    Ok(authorities.len())
+}
+
 /// Helper method for setting registration fee
 pub fn admin_set_registration_fee(
     env: &Env,
@@ -64,6 +92,47 @@ pub fn admin_set_registration_fee(
     Ok(())
 }
 
+/// Storage layout version written by this build of the contract.
+///
+/// Bump it, and add a step to `migrate_storage_step`, whenever an upgrade changes how
+/// existing entries are stored.
+pub const STORAGE_VERSION: u32 = 1;
+
+/// Replace the contract's code with an uploaded WASM, keeping all contract storage
+pub fn admin_upgrade(env: &Env, admin: &Address, new_wasm_hash: &BytesN<32>) -> Result<(), Error> {
+    crate::admin_guard!(env, admin);
+
+    env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+    events::contract_upgraded(env, admin, new_wasm_hash);
+
+    Ok(())
+}
+
+/// Bring storage up to `STORAGE_VERSION` after an upgrade. Returns the version after migrating.
+///
+/// Storage that is already current is left as is, so calling it again is harmless.
+pub fn admin_migrate_storage(env: &Env, admin: &Address) -> Result<u32, Error> {
+    crate::admin_guard!(env, admin);
+
+    let from_version = crate::state::get_storage_version(env);
+    if from_version >= STORAGE_VERSION {
+        return Ok(from_version);
+    }
+    for version in from_version..STORAGE_VERSION {
+        migrate_storage_step(env, version);
+    }
+    crate::state::set_storage_version(env, STORAGE_VERSION);
+
+    events::storage_migrated(env, admin, from_version, STORAGE_VERSION);
+
+    Ok(STORAGE_VERSION)
+}
+
+/// Migrate storage from `version` to `version + 1`
+///
+/// No entry has changed layout since versions were first recorded; add a step per version here when one does.
+fn migrate_storage_step(_env: &Env, _version: u32) {}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Helper functions
 // ══════════════════════════════════════════════════════════════════════════════
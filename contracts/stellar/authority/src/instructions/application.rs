@@ -0,0 +1,118 @@
+use crate::errors::Error;
+use crate::events;
+use crate::instructions::admin::require_init;
+use crate::state::{
+    get_application, get_payment_record, get_pending_applicants, set_application, set_authority_data,
+    set_pending_applicants, ApplicationStatus, AuthorityApplication, RegisteredAuthorityData,
+};
+use soroban_sdk::{Address, Env, String, Vec};
+// Import macros we actually use
+use crate::require_owner;
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Applicant Functions
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Submit an application to become a registered authority
+///
+/// The applicant must already have paid the verification fee, and `ref_id` must
+/// match the reference recorded with that payment.
+pub fn apply_for_authority(env: &Env, applicant: &Address, metadata: &String, ref_id: &String) -> Result<(), Error> {
+    require_init(env)?;
+    applicant.require_auth();
+
+    let payment = get_payment_record(env, applicant).ok_or(Error::InsufficientPayment)?;
+    if payment.ref_id != *ref_id {
+        return Err(Error::InvalidAuthorityData);
+    }
+
+    if let Some(existing) = get_application(env, applicant) {
+        if existing.status == ApplicationStatus::Pending {
+            return Err(Error::ApplicationExists);
+        }
+    }
+
+    let application = AuthorityApplication {
+        applicant: applicant.clone(),
+        metadata: metadata.clone(),
+        ref_id: ref_id.clone(),
+        submitted_at: env.ledger().timestamp(),
+        status: ApplicationStatus::Pending,
+        reason: None,
+    };
+    set_application(env, &application);
+
+    let mut pending = get_pending_applicants(env);
+    pending.push_back(applicant.clone());
+    set_pending_applicants(env, &pending);
+
+    events::application_submitted(env, applicant, ref_id);
+
+    Ok(())
+}
+
+/// List applications awaiting review, oldest first
+pub fn list_pending_applications(env: &Env) -> Vec<AuthorityApplication> {
+    let mut applications = Vec::new(env);
+    for applicant in get_pending_applicants(env).iter() {
+        if let Some(application) = get_application(env, &applicant) {
+            applications.push_back(application);
+        }
+    }
+    applications
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Admin Review Functions
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Approve a pending application and register the applicant as an authority
+pub fn approve_application(env: &Env, admin: &Address, applicant: &Address) -> Result<(), Error> {
+    crate::admin_guard!(env, admin);
+
+    let mut application = take_pending_application(env, applicant)?;
+    application.status = ApplicationStatus::Approved;
+    set_application(env, &application);
+
+    let data = RegisteredAuthorityData {
+        address: applicant.clone(),
+        metadata: application.metadata.clone(),
+        registration_time: env.ledger().timestamp(),
+        ref_id: application.ref_id,
+    };
+    set_authority_data(env, &data);
+
+    events::application_approved(env, applicant);
+    events::authority_registered(env, admin, applicant, &application.metadata);
+
+    Ok(())
+}
+
+/// Reject a pending application, recording the reason for the applicant
+pub fn reject_application(env: &Env, admin: &Address, applicant: &Address, reason: &String) -> Result<(), Error> {
+    crate::admin_guard!(env, admin);
+
+    let mut application = take_pending_application(env, applicant)?;
+    application.status = ApplicationStatus::Rejected;
+    application.reason = Some(reason.clone());
+    set_application(env, &application);
+
+    events::application_rejected(env, applicant, reason);
+
+    Ok(())
+}
+
+/// Removes an applicant from the review queue and returns their pending application
+fn take_pending_application(env: &Env, applicant: &Address) -> Result<AuthorityApplication, Error> {
+    let application = get_application(env, applicant)
+        .filter(|application| application.status == ApplicationStatus::Pending)
+        .ok_or(Error::ApplicationNotFound)?;
+
+    let mut pending = get_pending_applicants(env);
+    if let Some(index) = pending.first_index_of(applicant) {
+        pending.remove(index);
+    }
+    set_pending_applicants(env, &pending);
+
+    Ok(application)
+}
@@ -0,0 +1,116 @@
+use crate::errors::Error;
+use crate::events;
+use crate::instructions::admin::{get_admin, get_token_id, require_init};
+use crate::state::{get_bond, get_governance, get_required_bond, is_authority, is_suspended, set_bond, set_suspended};
+use soroban_sdk::{log, token, Address, Env, String};
+// Import macros we actually use
+use crate::require_owner;
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Admin Configuration
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Set the bond authorities must lock to attest (0 disables bonded mode)
+pub fn set_required_bond(env: &Env, admin: &Address, amount: &i128) -> Result<(), Error> {
+    crate::admin_guard!(env, admin);
+    if *amount < 0 {
+        return Err(Error::InvalidAmount);
+    }
+    crate::state::set_required_bond(env, amount);
+    Ok(())
+}
+
+/// Set the governance address allowed to slash alongside the admin
+pub fn set_governance(env: &Env, admin: &Address, governance: &Address) -> Result<(), Error> {
+    crate::admin_guard!(env, admin);
+    crate::state::set_governance(env, governance);
+    Ok(())
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Bond Management
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Lock collateral for an authority, or top up an existing bond
+pub fn post_bond(env: &Env, authority: &Address, amount: &i128) -> Result<(), Error> {
+    require_init(env)?;
+    authority.require_auth();
+    if *amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if !is_authority(env, authority) {
+        return Err(Error::AttesterNotAuthority);
+    }
+
+    let token_id = get_token_id(env)?;
+    token::Client::new(env, &token_id).transfer(authority, &env.current_contract_address(), amount);
+
+    let total = get_bond(env, authority) + amount;
+    set_bond(env, authority, &total);
+
+    events::bond_posted(env, authority, *amount, total);
+    Ok(())
+}
+
+/// Slash part of an authority's bond and suspend it
+///
+/// Callable by the admin or the governance address once fraud is proven. The slashed
+/// amount is transferred to the caller. The authority stays suspended until reinstated.
+pub fn slash_authority(
+    env: &Env,
+    caller: &Address,
+    authority: &Address,
+    amount: &i128,
+    reason: &String,
+) -> Result<(), Error> {
+    require_init(env)?;
+    caller.require_auth();
+    if *caller != get_admin(env)? && Some(caller.clone()) != get_governance(env) {
+        return Err(Error::NotAuthorized);
+    }
+    if *amount < 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let bond = get_bond(env, authority);
+    if *amount > bond {
+        return Err(Error::InsufficientBond);
+    }
+
+    set_bond(env, authority, &(bond - amount));
+    set_suspended(env, authority, true);
+
+    if *amount > 0 {
+        let token_id = get_token_id(env)?;
+        token::Client::new(env, &token_id).transfer(&env.current_contract_address(), caller, amount);
+    }
+
+    log!(env, "Authority {} slashed by {}: amount {}", authority, caller, *amount);
+    events::authority_slashed(env, caller, authority, *amount, reason);
+    Ok(())
+}
+
+/// Lift the suspension placed on an authority when it was slashed
+pub fn reinstate_authority(env: &Env, admin: &Address, authority: &Address) -> Result<(), Error> {
+    crate::admin_guard!(env, admin);
+    set_suspended(env, authority, false);
+    events::authority_reinstated(env, authority);
+    Ok(())
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Helper functions
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Returns an error if the authority is suspended or, in bonded mode, under-collateralized.
+pub fn require_bonded(env: &Env, authority: &Address) -> Result<(), Error> {
+    if is_suspended(env, authority) {
+        log!(env, "Authority {} is suspended.", authority);
+        return Err(Error::AuthoritySuspended);
+    }
+    if get_bond(env, authority) < get_required_bond(env) {
+        log!(env, "Authority {} has not locked the required bond.", authority);
+        return Err(Error::InsufficientBond);
+    }
+    Ok(())
+}
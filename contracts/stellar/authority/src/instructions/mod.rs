@@ -1,2 +1,4 @@
 pub mod admin;
+pub mod application;
+pub mod bond;
 pub mod resolver;
@@ -1,9 +1,10 @@
 use crate::errors::Error;
 use crate::events;
 use crate::instructions::admin::{get_token_id, require_init};
+use crate::instructions::bond::require_bonded;
 use crate::state::{
-    get_collected_levy, is_authority, remove_collected_levy, set_authority_data, set_collected_levy, Attestation,
-    RegisteredAuthorityData,
+    get_collected_levy, is_authority, remove_collected_levy, set_authority_data, set_collected_levy,
+    RegisteredAuthorityData, ResolverAttestation,
 };
 use soroban_sdk::{log, token, Address, Env, String};
 
@@ -47,12 +48,13 @@ pub fn register_authority(
 // ══════════════════════════════════════════════════════════════════════════════
 
 /// Attestation hook for verifying authority
-pub fn attest(env: &Env, attestation: &Attestation) -> Result<bool, Error> {
+pub fn attest(env: &Env, attestation: &ResolverAttestation) -> Result<bool, Error> {
     require_init(env)?;
     if !is_authority(env, &attestation.attester) {
         log!(env, "Attest hook: {} is NOT an authority.", attestation.attester);
         return Err(Error::AttesterNotAuthority);
     }
+    require_bonded(env, &attestation.attester)?;
 
     log!(
         env,
@@ -64,7 +66,7 @@ pub fn attest(env: &Env, attestation: &Attestation) -> Result<bool, Error> {
 }
 
 /// Revocation hook for verifying authority
-pub fn revoke(env: &Env, attestation: &Attestation) -> Result<bool, Error> {
+pub fn revoke(env: &Env, attestation: &ResolverAttestation) -> Result<bool, Error> {
     require_init(env)?;
     if is_authority(env, &attestation.attester) {
         log!(
@@ -1,6 +1,6 @@
 #![no_std]
-use resolvers::{ResolverAttestationData, ResolverError};
-use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env, String};
+use resolvers::ResolverError;
+use soroban_sdk::{contract, contractimpl, symbol_short, token, Address, BytesN, Env, String, Vec};
 
 // Import modules
 mod access_control;
@@ -13,10 +13,14 @@ mod state;
 // Re-export types for external use
 pub use errors::Error;
 pub use events::{
-    ADMIN_REG_AUTH, AUTHORITY_REGISTERED, LEVY_COLLECTED, LEVY_WITHDRAWN, OWNERSHIP_RENOUNCED, OWNERSHIP_TRANSFERRED,
-    PAYMENT_RECEIVED, SCHEMA_REGISTERED,
+    ADMIN_REG_AUTH, APPLICATION_REVIEWED, APPLICATION_SUBMITTED, AUTHORITY_REGISTERED, AUTHORITY_REINSTATED,
+    AUTHORITY_SLASHED, BOND_POSTED, CONTRACT_UPGRADED, HOOK_DECISION, LEVY_COLLECTED, LEVY_WITHDRAWN,
+    OWNERSHIP_RENOUNCED, OWNERSHIP_TRANSFERRED, PAYMENT_RECEIVED, SCHEMA_REGISTERED, STORAGE_MIGRATED,
+};
+pub use state::{
+    ApplicationStatus, AuthorityApplication, DataKey, PaymentRecord, RegisteredAuthorityData, ResolverAttestation,
+    SchemaHookStats,
 };
-pub use state::{Attestation, DataKey, PaymentRecord, RegisteredAuthorityData};
 
 #[contract]
 pub struct AuthorityResolverContract;
@@ -43,6 +47,7 @@ impl AuthorityResolverContract {
         state::set_token_id(&env, &token_contract_id);
         state::set_token_wasm_hash(&env, &token_wasm_hash);
         state::set_initialized(&env);
+        state::set_storage_version(&env, instructions::admin::STORAGE_VERSION);
         env.storage()
             .instance()
             .extend_ttl(env.storage().max_ttl() - 100, env.storage().max_ttl());
@@ -61,6 +66,46 @@ impl AuthorityResolverContract {
         instructions::admin::admin_register_authority(&env, &admin, &auth_to_reg, &metadata)
     }
 
+    pub fn import_authorities(
+        env: Env,
+        admin: Address,
+        authorities: Vec<RegisteredAuthorityData>,
+    ) -> Result<u32, Error> {
+        instructions::admin::admin_import_authorities(&env, &admin, &authorities)
+    }
+
+    /// Upgrade the contract to an uploaded WASM, keeping all authority and levy state
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The admin address (must be authenticated)
+    /// * `new_wasm_hash` - The hash of the uploaded WASM
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the contract code was replaced
+    /// * `Err(Error)` - If not authorized
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        instructions::admin::admin_upgrade(&env, &admin, &new_wasm_hash)
+    }
+
+    /// Migrate storage to the layout of the running code after an upgrade
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The admin address (must be authenticated)
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - The storage version after migrating
+    /// * `Err(Error)` - If not authorized
+    pub fn migrate_storage(env: Env, admin: Address) -> Result<u32, Error> {
+        instructions::admin::admin_migrate_storage(&env, &admin)
+    }
+
+    /// Get the storage layout version, or 0 for deployments from before versions were recorded
+    pub fn get_storage_version(env: Env) -> u32 {
+        state::get_storage_version(&env)
+    }
+
     // ──────────────────────────────────────────────────────────────────────────
     //                         Public/Hook Functions
     // ──────────────────────────────────────────────────────────────────────────
@@ -78,11 +123,15 @@ impl AuthorityResolverContract {
         Ok(state::is_authority(&env, &authority))
     }
 
-    pub fn attest(env: Env, attestation: Attestation) -> Result<bool, Error> {
+    pub fn get_authority_data(env: Env, authority: Address) -> Option<RegisteredAuthorityData> {
+        state::get_authority_data(&env, &authority)
+    }
+
+    pub fn attest(env: Env, attestation: ResolverAttestation) -> Result<bool, Error> {
         instructions::resolver::attest(&env, &attestation)
     }
 
-    pub fn revoke(env: Env, attestation: Attestation) -> Result<bool, Error> {
+    pub fn revoke(env: Env, attestation: ResolverAttestation) -> Result<bool, Error> {
         instructions::resolver::revoke(&env, &attestation)
     }
 
@@ -215,6 +264,135 @@ impl AuthorityResolverContract {
         state::get_payment_record(&env, &payer)
     }
 
+    // ──────────────────────────────────────────────────────────────────────────
+    //                         Application Queue Functions
+    // ──────────────────────────────────────────────────────────────────────────
+
+    /// Submit an application to become a registered authority
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `applicant` - The applicant address (must be authenticated and have paid the verification fee)
+    /// * `metadata` - Metadata describing the applicant
+    /// * `ref_id` - The reference ID used when paying the verification fee
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the application is queued for review
+    /// * `Err(Error)` - If unpaid, the ref_id does not match, or an application is already pending
+    pub fn apply_for_authority(env: Env, applicant: Address, metadata: String, ref_id: String) -> Result<(), Error> {
+        instructions::application::apply_for_authority(&env, &applicant, &metadata, &ref_id)
+    }
+
+    /// List applications awaiting admin review, oldest first
+    pub fn list_pending_applications(env: Env) -> Vec<AuthorityApplication> {
+        instructions::application::list_pending_applications(&env)
+    }
+
+    /// Get the latest application submitted by an applicant, whatever its status
+    pub fn get_application(env: Env, applicant: Address) -> Option<AuthorityApplication> {
+        state::get_application(&env, &applicant)
+    }
+
+    /// Approve a pending application, registering the applicant as an authority
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The admin address (must be authenticated)
+    /// * `applicant` - The applicant whose application is approved
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the applicant is now a registered authority
+    /// * `Err(Error)` - If not authorized or no application is pending
+    pub fn approve_application(env: Env, admin: Address, applicant: Address) -> Result<(), Error> {
+        instructions::application::approve_application(&env, &admin, &applicant)
+    }
+
+    /// Reject a pending application with a reason
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The admin address (must be authenticated)
+    /// * `applicant` - The applicant whose application is rejected
+    /// * `reason` - Why the application was rejected, visible to the applicant
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the application is rejected
+    /// * `Err(Error)` - If not authorized or no application is pending
+    pub fn reject_application(env: Env, admin: Address, applicant: Address, reason: String) -> Result<(), Error> {
+        instructions::application::reject_application(&env, &admin, &applicant, &reason)
+    }
+
+    // ──────────────────────────────────────────────────────────────────────────
+    //                         Bonding and Slashing Functions
+    // ──────────────────────────────────────────────────────────────────────────
+
+    /// Set the bond authorities must lock before they can attest
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The admin address (must be authenticated)
+    /// * `amount` - The required bond in token units; 0 disables bonded mode
+    pub fn set_required_bond(env: Env, admin: Address, amount: i128) -> Result<(), Error> {
+        instructions::bond::set_required_bond(&env, &admin, &amount)
+    }
+
+    /// Get the bond authorities must lock, or 0 when bonded mode is disabled
+    pub fn get_required_bond(env: Env) -> i128 {
+        state::get_required_bond(&env)
+    }
+
+    /// Set the governance address allowed to slash authorities alongside the admin
+    pub fn set_governance(env: Env, admin: Address, governance: Address) -> Result<(), Error> {
+        instructions::bond::set_governance(&env, &admin, &governance)
+    }
+
+    /// Lock collateral as a registered authority, or top up an existing bond
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `authority` - The registered authority (must be authenticated)
+    /// * `amount` - The amount of the contract token to lock
+    pub fn post_bond(env: Env, authority: Address, amount: i128) -> Result<(), Error> {
+        instructions::bond::post_bond(&env, &authority, &amount)
+    }
+
+    /// Get the bond currently locked by an authority
+    pub fn get_bond(env: Env, authority: Address) -> i128 {
+        state::get_bond(&env, &authority)
+    }
+
+    /// Slash an authority's bond and suspend it after fraud is proven
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `caller` - The admin or governance address (must be authenticated); receives the slashed amount
+    /// * `authority` - The authority to slash
+    /// * `amount` - The amount to slash, at most the authority's bond
+    /// * `reason` - Why the authority was slashed, emitted with the event
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the bond was slashed and the authority suspended
+    /// * `Err(Error)` - If not authorized or the amount exceeds the bond
+    pub fn slash_authority(
+        env: Env,
+        caller: Address,
+        authority: Address,
+        amount: i128,
+        reason: String,
+    ) -> Result<(), Error> {
+        instructions::bond::slash_authority(&env, &caller, &authority, &amount, &reason)
+    }
+
+    /// Lift the suspension placed on an authority when it was slashed
+    pub fn reinstate_authority(env: Env, admin: Address, authority: Address) -> Result<(), Error> {
+        instructions::bond::reinstate_authority(&env, &admin, &authority)
+    }
+
+    /// Check if an authority is suspended
+    pub fn is_suspended(env: Env, authority: Address) -> bool {
+        state::is_suspended(&env, &authority)
+    }
+
     /// Admin function to withdraw collected fees
     pub fn admin_withdraw_fees(env: Env, admin: Address, token_address: Address, amount: i128) -> Result<(), Error> {
         instructions::admin::require_init(&env)?;
@@ -235,37 +413,56 @@ impl AuthorityResolverContract {
     // ──────────────────────────────────────────────────────────────────────────
 
     /// Called before an attestation is created (resolver interface)
-    pub fn onattest(env: Env, attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+    ///
+    /// Rejections return `Ok(false)` rather than an error so the invocation is still
+    /// recorded in the schema's hook statistics and its `hook_dec` event is published.
+    pub fn onattest(env: Env, attestation: ResolverAttestation) -> Result<bool, ResolverError> {
         // Check if the attester has confirmed payment
-        if !state::has_confirmed_payment(&env, &attestation.attester) {
-            return Err(ResolverError::NotAuthorized);
-        }
-        Ok(true)
+        let rejection = if state::has_confirmed_payment(&env, &attestation.attester) {
+            None
+        } else {
+            Some(ResolverError::NotAuthorized as u32)
+        };
+        state::record_hook_invocation(&env, &attestation.schema_uid, rejection);
+        events::hook_decision(&env, symbol_short!("attest"), &attestation, rejection);
+        Ok(rejection.is_none())
+    }
+
+    /// Called before an attestation is revoked (resolver interface)
+    ///
+    /// Only registered authorities may revoke. Rejections return `Ok(false)` so the
+    /// `hook_dec` event is still published.
+    pub fn onrevoke(env: Env, attestation: ResolverAttestation) -> Result<bool, ResolverError> {
+        let rejection = if state::is_authority(&env, &attestation.attester) {
+            None
+        } else {
+            Some(ResolverError::NotAuthorized as u32)
+        };
+        events::hook_decision(&env, symbol_short!("revoke"), &attestation, rejection);
+        Ok(rejection.is_none())
     }
 
     /// Called after an attestation is created (resolver interface)
-    pub fn onresolve(env: Env, attestation: ResolverAttestationData) -> Result<(), ResolverError> {
-        // Register the attester as an authority after successful attestation
-        if state::has_confirmed_payment(&env, &attestation.attester) {
-            let payment_record = state::get_payment_record(&env, &attestation.attester);
-            if let Some(record) = payment_record {
-                let authority_data = state::RegisteredAuthorityData {
-                    address: attestation.attester.clone(),
-                    metadata: String::from_str(&env, "verified_authority"),
-                    registration_time: env.ledger().timestamp(),
-                    ref_id: record.ref_id,
-                };
-                state::set_authority_data(&env, &authority_data);
-
-                // Emit authority registered event
-                events::authority_registered(
-                    &env,
-                    &attestation.attester,
-                    &attestation.attester,
-                    &authority_data.metadata,
-                );
-            }
+    ///
+    /// Authority registration no longer happens here: paid applicants submit an
+    /// application with `apply_for_authority` and the admin reviews it.
+    pub fn onresolve(env: Env, attestation: ResolverAttestation) -> Result<(), ResolverError> {
+        if attestation.value > 0 {
+            state::record_schema_levy(&env, &attestation.schema_uid, attestation.value);
         }
         Ok(())
     }
+
+    /// Get resolver hook statistics for a schema
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `schema_uid` - The schema to report on
+    ///
+    /// # Returns
+    /// * `SchemaHookStats` - Invocation and acceptance counts, rejections keyed by
+    ///   `ResolverError` code, and the total levy collected
+    pub fn get_schema_hook_stats(env: Env, schema_uid: BytesN<32>) -> SchemaHookStats {
+        state::get_schema_hook_stats(&env, &schema_uid)
+    }
 }
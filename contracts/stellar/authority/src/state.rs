@@ -1,22 +1,10 @@
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, String};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Vec};
 
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Contract Data Structures
 // ══════════════════════════════════════════════════════════════════════════════
-#[derive(Debug, Clone, PartialEq, Eq)]
-#[contracttype]
-pub struct Attestation {
-    pub uid: BytesN<32>,
-    pub schema_uid: BytesN<32>,
-    pub recipient: Address,
-    pub attester: Address,
-    pub time: u64,
-    pub expiration_time: Option<u64>,
-    pub revocable: bool,
-    pub ref_uid: Option<Bytes>,
-    pub data: Bytes,
-    pub value: Option<i128>,
-}
+// Attestations reach the authority hooks in the shared flattened resolver shape
+pub use attest_types::ResolverAttestation;
 
 /// Payment record for organizations that paid the verification fee
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -38,6 +26,37 @@ pub struct RegisteredAuthorityData {
     pub ref_id: String, // reference to their org data on platform
 }
 
+/// Review status of an authority application
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum ApplicationStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// Application submitted by a paid applicant to become a registered authority
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct AuthorityApplication {
+    pub applicant: Address,
+    pub metadata: String,
+    pub ref_id: String, // must match the ref_id of their payment
+    pub submitted_at: u64,
+    pub status: ApplicationStatus,
+    pub reason: Option<String>, // admin's reason, set on rejection
+}
+
+/// Resolver hook activity for a single schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct SchemaHookStats {
+    pub invocations: u64,          // onattest calls for the schema
+    pub accepted: u64,             // onattest calls that allowed the attestation
+    pub rejections: Map<u32, u64>, // rejected onattest calls, keyed by ResolverError code
+    pub levy_collected: i128,      // total value attached to resolved attestations
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
@@ -52,6 +71,14 @@ pub enum DataKey {
     CollectedFees,   // Collected fees per authority
     RegAuthPrefix,   // Legacy prefix for registered authorities
     CollLevyPrefix,  // Prefix for collected levies
+    Application,     // Authority applications per applicant
+    PendingApps,     // Applicants awaiting review
+    RequiredBond,    // Minimum bond an authority must lock (0 disables bonding)
+    Governance,      // Optional address allowed to slash alongside the admin
+    Bond,            // Locked collateral per authority
+    Suspended,       // Authorities suspended after being slashed
+    HookStats,       // Resolver hook statistics per schema
+    StorageVersion,  // Storage layout version the contract's entries are in
 }
 
 // ══════════════════════════════════════════════════════════════════════════════
@@ -116,6 +143,141 @@ pub fn set_authority_data(env: &Env, data: &RegisteredAuthorityData) {
         .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
 }
 
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Application Queue Functions
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Gets the authority application for an applicant
+pub fn get_application(env: &Env, applicant: &Address) -> Option<AuthorityApplication> {
+    let key = (DataKey::Application, applicant.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Writes an authority application to storage with appropriate TTL
+pub fn set_application(env: &Env, application: &AuthorityApplication) {
+    let key = (DataKey::Application, application.applicant.clone());
+    env.storage().persistent().set(&key, application);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+}
+
+/// Gets the applicants whose applications await review, oldest first
+pub fn get_pending_applicants(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingApps)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Writes the list of applicants awaiting review
+pub fn set_pending_applicants(env: &Env, applicants: &Vec<Address>) {
+    env.storage().persistent().set(&DataKey::PendingApps, applicants);
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Bonding Functions
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Gets the minimum bond an authority must lock, or 0 when bonding is disabled
+pub fn get_required_bond(env: &Env) -> i128 {
+    env.storage().instance().get(&DataKey::RequiredBond).unwrap_or(0)
+}
+
+/// Sets the minimum bond an authority must lock
+pub fn set_required_bond(env: &Env, amount: &i128) {
+    env.storage().instance().set(&DataKey::RequiredBond, amount);
+}
+
+/// Gets the storage layout version, or 0 for deployments from before versions were recorded
+pub fn get_storage_version(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::StorageVersion).unwrap_or(0)
+}
+
+/// Sets the storage layout version
+pub fn set_storage_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::StorageVersion, &version);
+}
+
+/// Gets the governance address allowed to slash, if any
+pub fn get_governance(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Governance)
+}
+
+/// Sets the governance address allowed to slash
+pub fn set_governance(env: &Env, governance: &Address) {
+    env.storage().instance().set(&DataKey::Governance, governance);
+}
+
+/// Gets the bond locked by an authority
+pub fn get_bond(env: &Env, authority: &Address) -> i128 {
+    let key = (DataKey::Bond, authority.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Sets the bond locked by an authority
+pub fn set_bond(env: &Env, authority: &Address, amount: &i128) {
+    let key = (DataKey::Bond, authority.clone());
+    env.storage().persistent().set(&key, amount);
+}
+
+/// Checks whether an authority is suspended
+pub fn is_suspended(env: &Env, authority: &Address) -> bool {
+    let key = (DataKey::Suspended, authority.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Suspends or reinstates an authority
+pub fn set_suspended(env: &Env, authority: &Address, suspended: bool) {
+    let key = (DataKey::Suspended, authority.clone());
+    if suspended {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Hook Statistics Functions
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Gets the hook statistics for a schema
+pub fn get_schema_hook_stats(env: &Env, schema_uid: &BytesN<32>) -> SchemaHookStats {
+    let key = (DataKey::HookStats, schema_uid.clone());
+    env.storage().persistent().get(&key).unwrap_or(SchemaHookStats {
+        invocations: 0,
+        accepted: 0,
+        rejections: Map::new(env),
+        levy_collected: 0,
+    })
+}
+
+fn set_schema_hook_stats(env: &Env, schema_uid: &BytesN<32>, stats: &SchemaHookStats) {
+    let key = (DataKey::HookStats, schema_uid.clone());
+    env.storage().persistent().set(&key, stats);
+}
+
+/// Records an onattest invocation and its outcome; `rejection` is the ResolverError code, if rejected
+pub fn record_hook_invocation(env: &Env, schema_uid: &BytesN<32>, rejection: Option<u32>) {
+    let mut stats = get_schema_hook_stats(env, schema_uid);
+    stats.invocations += 1;
+    match rejection {
+        Some(code) => {
+            let count = stats.rejections.get(code).unwrap_or(0);
+            stats.rejections.set(code, count + 1);
+        },
+        None => stats.accepted += 1,
+    }
+    set_schema_hook_stats(env, schema_uid, &stats);
+}
+
+/// Adds the value attached to a resolved attestation to the schema's levy total
+pub fn record_schema_levy(env: &Env, schema_uid: &BytesN<32>, amount: i128) {
+    let mut stats = get_schema_hook_stats(env, schema_uid);
+    stats.levy_collected += amount;
+    set_schema_hook_stats(env, schema_uid, &stats);
+}
+
 /// Sets the initialized flag.
 pub fn set_initialized(env: &Env) {
     env.storage().instance().set(&DataKey::Initialized, &true);
@@ -3,6 +3,7 @@ extern crate std; // Needed for format!
 use soroban_sdk::{
     testutils::{Address as _, BytesN as _, Events as _, Ledger, LedgerInfo},
     token, // Import token types
+    vec,
     Address,
     Bytes,
     BytesN,
@@ -132,18 +133,19 @@ fn create_dummy_attestation(
     issuer: &Address, // Renamed from authority for clarity
     schema_uid: &BytesN<32>,
     recipient: Option<Address>, // Added recipient field
-) -> Attestation {
-    Attestation {
+) -> ResolverAttestation {
+    ResolverAttestation {
         uid: BytesN::random(env),
         schema_uid: schema_uid.clone(),
         recipient: recipient.unwrap_or_else(|| Address::generate(env)),
         attester: issuer.clone(),
         time: env.ledger().timestamp(),
-        expiration_time: None,
+        expiration_time: 0,
+        revocation_time: 0,
         revocable: true,
-        ref_uid: None,
+        ref_uid: Bytes::new(env),
         data: Bytes::new(env), // Use Bytes::new for empty Bytes
-        value: None,
+        value: 0,
     }
 }
 
@@ -165,7 +167,7 @@ fn test_initialize() {
 }
 
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #2)")] // Updated expected panic message
+#[should_panic(expected = "HostError: Error(Contract, #202)")] // Updated expected panic message
 fn test_call_before_initialize() {
     let env = Env::default();
     // Don't call setup_env which initializes
@@ -370,6 +372,55 @@ fn test_admin_register_authority() {
     */
 }
 
+#[test]
+fn test_import_authorities_preserves_registration_time() {
+    let setup = setup_env(true);
+    let migrated = Address::generate(&setup.env);
+    let record = RegisteredAuthorityData {
+        address: migrated.clone(),
+        metadata: SorobanString::from_str(&setup.env, "Migrated"),
+        registration_time: 1600000000,
+        ref_id: SorobanString::from_str(&setup.env, "legacy_org_42"),
+    };
+
+    let imported = setup
+        .resolver_client
+        .import_authorities(&setup.admin, &vec![&setup.env, record.clone()]);
+    assert_eq!(imported, 1);
+    assert!(setup.resolver_client.is_authority(&migrated));
+    assert_eq!(setup.resolver_client.get_authority_data(&migrated), Some(record));
+
+    // Records from the future are rejected, and nothing in the batch is written
+    let future = RegisteredAuthorityData {
+        address: Address::generate(&setup.env),
+        metadata: SorobanString::from_str(&setup.env, "Future"),
+        registration_time: setup.env.ledger().timestamp() + 1,
+        ref_id: SorobanString::from_str(&setup.env, "legacy_org_43"),
+    };
+    let result = setup
+        .resolver_client
+        .try_import_authorities(&setup.admin, &vec![&setup.env, future.clone()]);
+    assert_eq!(result, Err(Ok(Error::InvalidAuthorityData)));
+    assert!(!setup.resolver_client.is_authority(&future.address));
+}
+
+#[test]
+fn test_upgrade_and_migrate_storage_require_admin() {
+    let setup = setup_env(true);
+    let outsider = Address::generate(&setup.env);
+    assert_eq!(setup.resolver_client.get_storage_version(), 1);
+
+    let result = setup
+        .resolver_client
+        .try_upgrade(&outsider, &BytesN::from_array(&setup.env, &[7u8; 32]));
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+    let result = setup.resolver_client.try_migrate_storage(&outsider);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    // Storage written by this build is already current
+    assert_eq!(setup.resolver_client.migrate_storage(&setup.admin), 1);
+}
+
 /*
 #[test]
 fn test_admin_register_schema() {
@@ -653,3 +704,66 @@ fn test_collect_levies() {
     // IMPACT: Authority resolver focuses on authority management only
 }
 */
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Tests Bonding and Slashing
+// ══════════════════════════════════════════════════════════════════════════════
+#[test]
+fn test_bonded_mode_slash_and_reinstate() {
+    let setup = setup_env(true);
+    let authority = Address::generate(&setup.env);
+    let governance = Address::generate(&setup.env);
+    let schema_uid = BytesN::random(&setup.env);
+    let bond: i128 = 50_0000000;
+
+    setup.resolver_client.admin_register_authority(
+        &setup.admin,
+        &authority,
+        &SorobanString::from_str(&setup.env, "Bonded Attester"),
+    );
+    setup.token_admin_client.mint(&authority, &MINT_AMOUNT);
+    setup.resolver_client.set_required_bond(&setup.admin, &bond);
+    setup.resolver_client.set_governance(&setup.admin, &governance);
+
+    // Unbonded authorities cannot attest in bonded mode
+    let attestation = create_dummy_attestation(&setup.env, &authority, &schema_uid, None);
+    let result = setup.resolver_client.try_attest(&attestation);
+    assert!(matches!(result.err().unwrap(), Ok(Error::InsufficientBond)));
+
+    // Bond in two steps using the top-up path
+    setup.resolver_client.post_bond(&authority, &(bond / 2));
+    setup.resolver_client.post_bond(&authority, &(bond / 2));
+    assert_eq!(setup.resolver_client.get_bond(&authority), bond);
+    assert_eq!(setup.token_client.balance(&setup.resolver_address), bond);
+    assert!(setup.resolver_client.attest(&attestation));
+
+    // Only admin or governance can slash
+    let stranger = Address::generate(&setup.env);
+    let reason = SorobanString::from_str(&setup.env, "fraudulent credentials issued");
+    let result = setup
+        .resolver_client
+        .try_slash_authority(&stranger, &authority, &bond, &reason);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+    let result = setup
+        .resolver_client
+        .try_slash_authority(&governance, &authority, &(bond + 1), &reason);
+    assert!(matches!(result.err().unwrap(), Ok(Error::InsufficientBond)));
+
+    setup
+        .resolver_client
+        .slash_authority(&governance, &authority, &(bond / 5), &reason);
+    assert!(setup.resolver_client.is_suspended(&authority));
+    assert_eq!(setup.resolver_client.get_bond(&authority), bond - bond / 5);
+    assert_eq!(setup.token_client.balance(&governance), bond / 5);
+
+    let result = setup.resolver_client.try_attest(&attestation);
+    assert!(matches!(result.err().unwrap(), Ok(Error::AuthoritySuspended)));
+
+    // After reinstatement the authority must top the bond back up
+    setup.resolver_client.reinstate_authority(&setup.admin, &authority);
+    assert!(!setup.resolver_client.is_suspended(&authority));
+    let result = setup.resolver_client.try_attest(&attestation);
+    assert!(matches!(result.err().unwrap(), Ok(Error::InsufficientBond)));
+    setup.resolver_client.post_bond(&authority, &(bond / 5));
+    assert!(setup.resolver_client.attest(&attestation));
+}
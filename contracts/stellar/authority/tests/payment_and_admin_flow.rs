@@ -5,8 +5,8 @@ use soroban_sdk::{
     token, Address, Bytes, BytesN, Env, String as SorobanString, TryFromVal,
 };
 
-use authority::{AuthorityResolverContract, AuthorityResolverContractClient};
-use resolvers::ResolverAttestationData as ResolverAttestation;
+use authority::{ApplicationStatus, AuthorityResolverContract, AuthorityResolverContractClient, Error};
+use resolvers::{ResolverAttestationData as ResolverAttestation, ResolverError};
 
 const REGISTRATION_FEE: i128 = 100_0000000; // 100 XLM
 const REWARD_AMOUNT: i128 = 5_0000000; // 5 tokens for reward tests
@@ -286,3 +286,156 @@ fn token_reward_incentive_flow_distributes_rewards() {
     assert_eq!(balance, REWARD_AMOUNT);
     */
 }
+
+#[test]
+fn application_queue_approve_and_reject() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    let metadata = SorobanString::from_str(env, "Acme Certification Ltd");
+
+    // Applying requires a prior payment with a matching ref_id
+    let unpaid = Address::generate(env);
+    let res = client.try_apply_for_authority(&unpaid, &metadata, &SorobanString::from_str(env, "org-x"));
+    assert_eq!(res, Err(Ok(Error::InsufficientPayment)));
+
+    let good = Address::generate(env);
+    let bad = Address::generate(env);
+    let good_ref = SorobanString::from_str(env, "org-good");
+    let bad_ref = SorobanString::from_str(env, "org-bad");
+    token_admin.mint(&good, &REGISTRATION_FEE);
+    token_admin.mint(&bad, &REGISTRATION_FEE);
+    client.pay_verification_fee(&good, &good_ref, &setup.token_address);
+    client.pay_verification_fee(&bad, &bad_ref, &setup.token_address);
+
+    let res = client.try_apply_for_authority(&good, &metadata, &bad_ref);
+    assert_eq!(res, Err(Ok(Error::InvalidAuthorityData)));
+
+    client.apply_for_authority(&good, &metadata, &good_ref);
+    client.apply_for_authority(&bad, &metadata, &bad_ref);
+    let res = client.try_apply_for_authority(&good, &metadata, &good_ref);
+    assert_eq!(res, Err(Ok(Error::ApplicationExists)));
+
+    // Paying alone no longer registers an authority
+    assert!(!client.is_authority(&good));
+    let pending = client.list_pending_applications();
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending.get(0).unwrap().applicant, good);
+    assert_eq!(pending.get(0).unwrap().status, ApplicationStatus::Pending);
+
+    let non_admin = Address::generate(env);
+    let res = client.try_approve_application(&non_admin, &good);
+    assert_eq!(res, Err(Ok(Error::NotAuthorized)));
+
+    client.approve_application(&setup.admin, &good);
+    assert!(client.is_authority(&good));
+    assert_eq!(client.get_application(&good).unwrap().status, ApplicationStatus::Approved);
+
+    let reason = SorobanString::from_str(env, "documents could not be verified");
+    client.reject_application(&setup.admin, &bad, &reason);
+    assert!(!client.is_authority(&bad));
+    let rejected = client.get_application(&bad).unwrap();
+    assert_eq!(rejected.status, ApplicationStatus::Rejected);
+    assert_eq!(rejected.reason, Some(reason.clone()));
+
+    assert_eq!(client.list_pending_applications().len(), 0);
+    let res = client.try_reject_application(&setup.admin, &bad, &reason);
+    assert_eq!(res, Err(Ok(Error::ApplicationNotFound)));
+}
+
+#[test]
+fn schema_hook_stats_track_funnel() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+
+    let paid = Address::generate(env);
+    token_admin.mint(&paid, &REGISTRATION_FEE);
+    client.pay_verification_fee(&paid, &SorobanString::from_str(env, "org-5"), &setup.token_address);
+    let unpaid = Address::generate(env);
+
+    let mut att = build_resolver_attestation(env, &paid);
+    let schema_uid = att.schema_uid.clone();
+    assert!(client.onattest(&att));
+    att.value = 2_0000000;
+    client.onresolve(&att);
+
+    let mut rejected = build_resolver_attestation(env, &unpaid);
+    rejected.schema_uid = schema_uid.clone();
+    assert!(!client.onattest(&rejected));
+    assert!(!client.onattest(&rejected));
+
+    let stats = client.get_schema_hook_stats(&schema_uid);
+    assert_eq!(stats.invocations, 3);
+    assert_eq!(stats.accepted, 1);
+    assert_eq!(stats.rejections.get(ResolverError::NotAuthorized as u32), Some(2));
+    assert_eq!(stats.levy_collected, 2_0000000);
+
+    // Other schemas are tracked separately
+    let other = client.get_schema_hook_stats(&BytesN::random(env));
+    assert_eq!(other.invocations, 0);
+}
+
+#[test]
+fn hook_decisions_are_published_as_events() {
+    use authority::HOOK_DECISION;
+    use soroban_sdk::{symbol_short, vec, IntoVal};
+
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+
+    let paid = Address::generate(env);
+    token_admin.mint(&paid, &REGISTRATION_FEE);
+    let ref_id = SorobanString::from_str(env, "org-6");
+    client.pay_verification_fee(&paid, &ref_id, &setup.token_address);
+    let unpaid = Address::generate(env);
+    let rejected_code = ResolverError::NotAuthorized as u32;
+
+    let last_event = || {
+        let events = env.events().all();
+        events.slice(events.len() - 1..)
+    };
+    let decision = |hook, att: &ResolverAttestation, accepted: bool, reason: u32| {
+        vec![
+            env,
+            (
+                setup.contract_id.clone(),
+                (HOOK_DECISION, hook).into_val(env),
+                (
+                    att.uid.clone(),
+                    att.schema_uid.clone(),
+                    att.attester.clone(),
+                    accepted,
+                    reason,
+                )
+                    .into_val(env),
+            ),
+        ]
+    };
+
+    let att = build_resolver_attestation(env, &paid);
+    assert!(client.onattest(&att));
+    assert_eq!(last_event(), decision(symbol_short!("attest"), &att, true, 0));
+
+    let rejected = build_resolver_attestation(env, &unpaid);
+    assert!(!client.onattest(&rejected));
+    assert_eq!(
+        last_event(),
+        decision(symbol_short!("attest"), &rejected, false, rejected_code)
+    );
+
+    // Revocations are accepted only from registered authorities
+    assert!(!client.onrevoke(&att));
+    assert_eq!(
+        last_event(),
+        decision(symbol_short!("revoke"), &att, false, rejected_code)
+    );
+    client.apply_for_authority(&paid, &SorobanString::from_str(env, "meta"), &ref_id);
+    client.approve_application(&setup.admin, &paid);
+    assert!(client.onrevoke(&att));
+    assert_eq!(last_event(), decision(symbol_short!("revoke"), &att, true, 0));
+}
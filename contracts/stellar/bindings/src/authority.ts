@@ -81,36 +81,41 @@ export interface RegisteredAuthorityData {
 }
 
 export const Errors = {
-  1: {message:"AlreadyInitialized"},
-  2: {message:"NotInitialized"},
-  3: {message:"NotAuthorized"},
-  4: {message:"RecipientNotAuthority"},
-  5: {message:"AttesterNotAuthority"},
-  6: {message:"SchemaNotRegistered"},
-  7: {message:"InvalidSchemaRules"},
-  8: {message:"InsufficientPayment"},
-  9: {message:"NothingToWithdraw"},
-  10: {message:"TokenTransferFailed"},
-  11: {message:"WithdrawalFailed"},
-  12: {message:"UnauthorizedVerifier"},
-  13: {message:"VerifierInactive"},
-  14: {message:"ExceedsVerificationLevel"},
-  15: {message:"InvalidVerificationLevel"},
-  16: {message:"VerifierNotFound"},
-  17: {message:"InvalidAuthorityData"}
+  201: {message:"AlreadyInitialized"},
+  202: {message:"NotInitialized"},
+  203: {message:"NotAuthorized"},
+  204: {message:"RecipientNotAuthority"},
+  205: {message:"AttesterNotAuthority"},
+  206: {message:"SchemaNotRegistered"},
+  207: {message:"InvalidSchemaRules"},
+  208: {message:"InsufficientPayment"},
+  209: {message:"NothingToWithdraw"},
+  210: {message:"TokenTransferFailed"},
+  211: {message:"WithdrawalFailed"},
+  212: {message:"UnauthorizedVerifier"},
+  213: {message:"VerifierInactive"},
+  214: {message:"ExceedsVerificationLevel"},
+  215: {message:"InvalidVerificationLevel"},
+  216: {message:"VerifierNotFound"},
+  217: {message:"InvalidAuthorityData"},
+  218: {message:"ApplicationExists"},
+  219: {message:"ApplicationNotFound"},
+  220: {message:"AuthoritySuspended"},
+  221: {message:"InsufficientBond"},
+  222: {message:"InvalidAmount"}
 }
 
 export type ResolverType = {tag: "Default", values: void} | {tag: "Authority", values: void} | {tag: "TokenReward", values: void} | {tag: "FeeCollection", values: void} | {tag: "Hybrid", values: void} | {tag: "Staking", values: void} | {tag: "Custom", values: void};
 
 export const ResolverError = {
-  1: {message:"NotAuthorized"},
-  2: {message:"InvalidAttestation"},
-  3: {message:"InvalidSchema"},
-  4: {message:"InsufficientFunds"},
-  5: {message:"TokenTransferFailed"},
-  6: {message:"StakeRequired"},
-  7: {message:"ValidationFailed"},
-  8: {message:"CustomError"}
+  301: {message:"NotAuthorized"},
+  302: {message:"InvalidAttestation"},
+  303: {message:"InvalidSchema"},
+  304: {message:"InsufficientFunds"},
+  305: {message:"TokenTransferFailed"},
+  306: {message:"StakeRequired"},
+  307: {message:"ValidationFailed"},
+  308: {message:"CustomError"}
 }
 
 
@@ -86,6 +86,12 @@ authority: string;
  */
 definition: string;
   /**
+ * Optional UID of the version this schema supersedes
+ * 
+ * `None` for the first version of a schema.
+ */
+previous_uid: Option<Buffer>;
+  /**
  * Optional address of a resolver contract for this schema
  * 
  * If present, this contract will be called to handle attestation operations.
@@ -95,6 +101,10 @@ resolver: Option<string>;
  * Whether attestations using this schema can be revoked
  */
 revocable: boolean;
+  /**
+ * Version of the schema within its lineage, starting at 1
+ */
+version: u32;
 }
 
 /**
@@ -178,6 +188,10 @@ revoked: boolean;
  */
 schema_uid: Buffer;
   /**
+ * The version of the schema at the time this attestation was issued
+ */
+schema_version: u32;
+  /**
  * The address of the entity that is the subject of this attestation
  */
 subject: string;
@@ -256,6 +270,12 @@ signature: Buffer;
  * The address of the entity that is the subject of the attestation to revoke
  */
 subject: string;
+  /**
+ * Version of the signed message format
+ * 
+ * Signed into the message, and checked against the versions the contract accepts.
+ */
+version: u32;
 }
 
 
@@ -304,35 +324,65 @@ subject: string;
  * The value or content of the attestation
  */
 value: string;
+  /**
+ * Version of the signed message format
+ * 
+ * Signed into the message, and checked against the versions the contract accepts.
+ */
+version: u32;
 }
 
 export const Errors = {
-  1: {message:"TransferFailed"},
-  2: {message:"AuthorityNotRegistered"},
-  3: {message:"SchemaNotFound"},
-  4: {message:"AttestationExists"},
-  5: {message:"AttestationNotFound"},
-  6: {message:"NotAuthorized"},
-  7: {message:"StorageFailed"},
-  9: {message:"InvalidUid"},
-  10: {message:"ResolverError"},
-  11: {message:"SchemaHasNoResolver"},
-  12: {message:"AdminNotSet"},
-  13: {message:"AlreadyInitialized"},
-  14: {message:"NotInitialized"},
-  15: {message:"AttestationNotRevocable"},
-  16: {message:"InvalidSchemaDefinition"},
-  17: {message:"InvalidAttestationValue"},
-  18: {message:"InvalidReference"},
-  19: {message:"InvalidNonce"},
-  20: {message:"ExpiredSignature"},
-  21: {message:"InvalidSignature"},
-  22: {message:"AttestationExpired"},
-  23: {message:"InvalidDeadline"},
-  24: {message:"ResolverCallFailed"},
-  25: {message:"InvalidSignaturePoint"},
-  26: {message:"BlsPubKeyNotRegistered"},
-  27: {message:"IntegerOverflow"}
+  103: {message:"SchemaNotFound"},
+  104: {message:"AttestationExists"},
+  105: {message:"AttestationNotFound"},
+  106: {message:"NotAuthorized"},
+  110: {message:"ResolverError"},
+  111: {message:"SchemaHasNoResolver"},
+  112: {message:"AdminNotSet"},
+  113: {message:"AlreadyInitialized"},
+  114: {message:"NotInitialized"},
+  115: {message:"AttestationNotRevocable"},
+  116: {message:"InvalidSchemaDefinition"},
+  117: {message:"InvalidAttestationValue"},
+  118: {message:"InvalidReference"},
+  119: {message:"InvalidNonce"},
+  120: {message:"ExpiredSignature"},
+  121: {message:"InvalidSignature"},
+  122: {message:"AttestationExpired"},
+  123: {message:"InvalidDeadline"},
+  124: {message:"ResolverCallFailed"},
+  125: {message:"InvalidSignaturePoint"},
+  126: {message:"BlsPubKeyNotRegistered"},
+  127: {message:"IntegerOverflow"},
+  128: {message:"Secp256k1KeyNotRegistered"},
+  129: {message:"InvalidEncryptionEnvelope"},
+  130: {message:"InvalidAmount"},
+  131: {message:"InvalidExternalId"},
+  132: {message:"AttesterBanned"},
+  133: {message:"SchemaMismatch"},
+  134: {message:"InvalidClaimPreimage"},
+  135: {message:"RevocationTooEarly"},
+  136: {message:"InvalidFieldCommitments"},
+  137: {message:"InvalidAdminCouncil"},
+  138: {message:"ProposalNotFound"},
+  139: {message:"InvalidCursor"},
+  140: {message:"SchemaRevoked"},
+  141: {message:"InvalidDomain"},
+  142: {message:"DomainChallengeMismatch"},
+  143: {message:"CommitmentNotFound"},
+  144: {message:"RevealOutsideWindow"},
+  145: {message:"ExpirationTooFar"},
+  146: {message:"UnsupportedMessageVersion"},
+  147: {message:"UniquenessKeyTaken"},
+  148: {message:"UniquenessKeyMissing"},
+  149: {message:"SchemaSuperseded"},
+  150: {message:"SchemaAlreadyRegistered"},
+  151: {message:"NoPendingAdmin"},
+  152: {message:"ContractPaused"},
+  153: {message:"LocaleNotAllowed"},
+  154: {message:"AppealNotAllowed"},
+  155: {message:"NoPendingAppeal"}
 }
 
 export interface Client {
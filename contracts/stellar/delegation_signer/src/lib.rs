@@ -0,0 +1,60 @@
+//! # Delegation Signer
+//!
+//! Off-chain BLS12-381 signing of delegated attestation and revocation requests, for
+//! backend services that attest through relayers. Messages are built with the protocol
+//! crate's own `create_attestation_message` / `create_revocation_message` and hashed to
+//! G1 with the protocol's domain separation tag, so signatures always match what
+//! `attest_by_delegation` and `revoke_by_delegation` verify.
+//!
+//! ```ignore
+//! request.signature = sign_attestation_request(&secret_key, &request)?;
+//! ```
+use attest_types::{DelegatedAttestationRequest, DelegatedRevocationRequest};
+use blst::min_sig::SecretKey;
+use protocol::instructions::crypto::ATTEST_PROTOCOL_BLS_G1_DST;
+use protocol::instructions::delegation::{create_attestation_message, create_revocation_message};
+use soroban_sdk::BytesN;
+
+/// Why a request could not be signed.
+#[derive(Debug, PartialEq)]
+pub enum SignerError {
+    /// The secret key is not a valid BLS12-381 scalar
+    InvalidSecretKey,
+}
+
+/// Signs a delegated attestation request, returning the value for its `signature` field.
+///
+/// The request's `signature` field itself is not part of the signed message.
+pub fn sign_attestation_request(
+    secret_key: &[u8; 32],
+    request: &DelegatedAttestationRequest,
+) -> Result<BytesN<96>, SignerError> {
+    let env = request.schema_uid.env();
+    let message = create_attestation_message(env, request);
+    Ok(BytesN::from_array(env, &sign(secret_key, &message.to_array())?))
+}
+
+/// Signs a delegated revocation request, returning the value for its `signature` field.
+pub fn sign_revocation_request(
+    secret_key: &[u8; 32],
+    request: &DelegatedRevocationRequest,
+) -> Result<BytesN<96>, SignerError> {
+    let env = request.schema_uid.env();
+    let message = create_revocation_message(env, request);
+    Ok(BytesN::from_array(env, &sign(secret_key, &message.to_array())?))
+}
+
+/// Derives the uncompressed G2 public key to pass to `register_bls_key`.
+pub fn public_key(secret_key: &[u8; 32]) -> Result<[u8; 192], SignerError> {
+    Ok(parse_secret_key(secret_key)?.sk_to_pk().serialize())
+}
+
+fn sign(secret_key: &[u8; 32], message: &[u8; 32]) -> Result<[u8; 96], SignerError> {
+    Ok(parse_secret_key(secret_key)?
+        .sign(message, ATTEST_PROTOCOL_BLS_G1_DST, &[])
+        .serialize())
+}
+
+fn parse_secret_key(secret_key: &[u8; 32]) -> Result<SecretKey, SignerError> {
+    SecretKey::from_bytes(secret_key).map_err(|_| SignerError::InvalidSecretKey)
+}
@@ -0,0 +1,67 @@
+use attest_types::{DelegatedAttestationRequest, DelegatedRevocationRequest};
+use delegation_signer::{public_key, sign_attestation_request, sign_revocation_request, SignerError};
+use protocol::{AttestationContract, AttestationContractClient};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+
+const SECRET_KEY: [u8; 32] = [
+    34, 38, 144, 121, 33, 229, 89, 185, 68, 32, 10, 221, 176, 119, 70, 160, 41, 238, 104, 43, 146, 16, 63, 200, 77,
+    240, 207, 42, 165, 238, 248, 220,
+];
+
+/// **Test: Signed Requests Are Accepted by the Protocol**
+/// - The derived public key registers as the attester's BLS key
+/// - A signed attestation request and a signed revocation request both verify on-chain
+#[test]
+fn signed_requests_are_accepted_by_protocol() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let protocol_id = env.register(AttestationContract {}, ());
+    let protocol = AttestationContractClient::new(&env, &protocol_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    protocol.initialize(&admin);
+    let schema_uid = protocol.register(&admin, &String::from_str(&env, "kyc"), &None, &true);
+
+    let key = public_key(&SECRET_KEY).unwrap();
+    protocol.register_bls_key(&attester, &BytesN::from_array(&env, &key));
+
+    let mut request = DelegatedAttestationRequest {
+        schema_uid: schema_uid.clone(),
+        subject: subject.clone(),
+        value: String::from_str(&env, "{\"level\":2}"),
+        nonce: protocol.get_attester_nonce(&attester),
+        attester: attester.clone(),
+        expiration_time: None,
+        deadline: env.ledger().timestamp() + 1000,
+        version: 1,
+        signature: BytesN::from_array(&env, &[0; 96]),
+    };
+    request.signature = sign_attestation_request(&SECRET_KEY, &request).unwrap();
+    protocol.attest_by_delegation(&submitter, &request);
+
+    let uid = protocol::utils::generate_attestation_uid(&env, &schema_uid, &subject, request.nonce);
+    assert!(!protocol.get_attestation(&uid).revoked);
+
+    let mut revocation = DelegatedRevocationRequest {
+        attestation_uid: uid.clone(),
+        schema_uid,
+        subject,
+        nonce: protocol.get_attester_nonce(&attester),
+        revoker: attester,
+        deadline: env.ledger().timestamp() + 1000,
+        version: 1,
+        signature: BytesN::from_array(&env, &[0; 96]),
+    };
+    revocation.signature = sign_revocation_request(&SECRET_KEY, &revocation).unwrap();
+    protocol.revoke_by_delegation(&submitter, &revocation);
+    assert!(protocol.get_attestation(&uid).revoked);
+}
+
+/// **Test: Invalid Secret Keys Are Rejected**
+/// - A scalar outside the BLS12-381 field order cannot be used to sign
+#[test]
+fn invalid_secret_key_is_rejected() {
+    assert_eq!(public_key(&[0xff; 32]), Err(SignerError::InvalidSecretKey));
+}
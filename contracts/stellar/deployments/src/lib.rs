@@ -0,0 +1,241 @@
+//! # Deployments
+//!
+//! The address book of official protocol deployments. Every network the protocol is
+//! released to has one [`Deployment`] recording its contract IDs and the domain
+//! separation tags its delegated-signature verification expects, so tools and tests
+//! resolve contracts from one reviewed source instead of copying IDs around.
+//!
+//! The entries mirror `deployments.json`, which the deploy scripts write; a test keeps
+//! the two in step.
+//!
+//! ```ignore
+//! let testnet = deployment(Network::Testnet);
+//! testnet.ensure_dst(DstKind::Attest, get_attest_dst())?;
+//! let protocol_id = testnet.protocol_id()?;
+//! ```
+use std::fmt;
+use std::str::FromStr;
+
+/// A Stellar network the protocol can be deployed to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Network {
+    Testnet,
+    Futurenet,
+    Mainnet,
+}
+
+impl Network {
+    /// Every network with an address book entry.
+    pub const ALL: [Network; 3] = [Network::Testnet, Network::Futurenet, Network::Mainnet];
+
+    /// The `stellar` CLI network name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Network::Testnet => "testnet",
+            Network::Futurenet => "futurenet",
+            Network::Mainnet => "mainnet",
+        }
+    }
+
+    /// The network passphrase transactions are signed against.
+    pub fn passphrase(self) -> &'static str {
+        match self {
+            Network::Testnet => "Test SDF Network ; September 2015",
+            Network::Futurenet => "Test SDF Future Network ; October 2022",
+            Network::Mainnet => "Public Global Stellar Network ; September 2015",
+        }
+    }
+
+    /// Finds the network signing against `passphrase`.
+    pub fn from_passphrase(passphrase: &str) -> Option<Network> {
+        Network::ALL
+            .into_iter()
+            .find(|network| network.passphrase() == passphrase)
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Network {
+    type Err = DeploymentError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Network::ALL
+            .into_iter()
+            .find(|network| network.name() == name)
+            .ok_or_else(|| DeploymentError::UnknownNetwork(name.to_string()))
+    }
+}
+
+/// One deployed contract instance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Contract {
+    /// Strkey contract ID (`C...`)
+    pub id: &'static str,
+    /// Hex SHA-256 of the installed WASM
+    pub wasm_hash: &'static str,
+    /// RFC 3339 time of deployment
+    pub deployed_at: &'static str,
+}
+
+/// Which delegated-signature domain separation tag is being checked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DstKind {
+    /// Prefix of delegated attestation messages
+    Attest,
+    /// Prefix of delegated revocation messages
+    Revoke,
+    /// Hash-to-curve tag for BLS signatures
+    Bls,
+}
+
+/// The protocol as deployed on one network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Deployment {
+    pub network: Network,
+    /// The protocol contract, `None` until deployed
+    pub protocol: Option<Contract>,
+    /// The authority resolver contract, `None` until deployed
+    pub authority: Option<Contract>,
+    /// Attestation message prefix the deployed protocol verifies against
+    pub attest_dst: &'static [u8],
+    /// Revocation message prefix the deployed protocol verifies against
+    pub revoke_dst: &'static [u8],
+    /// BLS hash-to-curve tag the deployed protocol verifies against
+    pub bls_dst: &'static [u8],
+}
+
+/// Why an address book lookup or check failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeploymentError {
+    /// The name is not a known network
+    UnknownNetwork(String),
+    /// The contract has not been deployed to the network
+    NotDeployed { network: Network, contract: &'static str },
+    /// A signer is using a tag the network's deployment would reject
+    DstMismatch { network: Network, kind: DstKind },
+}
+
+impl fmt::Display for DeploymentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeploymentError::UnknownNetwork(name) => write!(f, "unknown network `{name}`"),
+            DeploymentError::NotDeployed { network, contract } => {
+                write!(f, "the {contract} contract is not deployed on {network}")
+            },
+            DeploymentError::DstMismatch { network, kind } => {
+                write!(
+                    f,
+                    "{kind:?} domain separation tag does not match the {network} deployment"
+                )
+            },
+        }
+    }
+}
+
+impl std::error::Error for DeploymentError {}
+
+impl Deployment {
+    /// The protocol contract ID.
+    pub fn protocol_id(&self) -> Result<&'static str, DeploymentError> {
+        self.protocol
+            .map(|contract| contract.id)
+            .ok_or(DeploymentError::NotDeployed {
+                network: self.network,
+                contract: "protocol",
+            })
+    }
+
+    /// The authority resolver contract ID.
+    pub fn authority_id(&self) -> Result<&'static str, DeploymentError> {
+        self.authority
+            .map(|contract| contract.id)
+            .ok_or(DeploymentError::NotDeployed {
+                network: self.network,
+                contract: "authority",
+            })
+    }
+
+    /// The tag of `kind` this deployment verifies against.
+    pub fn dst(&self, kind: DstKind) -> &'static [u8] {
+        match kind {
+            DstKind::Attest => self.attest_dst,
+            DstKind::Revoke => self.revoke_dst,
+            DstKind::Bls => self.bls_dst,
+        }
+    }
+
+    /// Refuses a domain separation tag other than the one this deployment verifies.
+    ///
+    /// Signers call this before signing for a network, so a request built for another
+    /// deployment fails locally instead of being relayed and rejected on-chain.
+    pub fn ensure_dst(&self, kind: DstKind, dst: &[u8]) -> Result<(), DeploymentError> {
+        if self.dst(kind) != dst {
+            return Err(DeploymentError::DstMismatch {
+                network: self.network,
+                kind,
+            });
+        }
+        Ok(())
+    }
+}
+
+const V1_ATTEST_DST: &[u8] = b"ATTEST_PROTOCOL_V1_DELEGATED";
+const V1_REVOKE_DST: &[u8] = b"REVOKE_PROTOCOL_V1_DELEGATED";
+const V1_BLS_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+
+const TESTNET: Deployment = Deployment {
+    network: Network::Testnet,
+    protocol: Some(Contract {
+        id: "CBFE5YSUHCRYEYEOLNN2RJAWMQ2PW525KTJ6TPWPNS5XLIREZQ3NA4KP",
+        wasm_hash: "5f91a35f4629473c814c2e949a530f4d3dfe6b44a6426f0d26e69523d83f287d",
+        deployed_at: "2025-11-07T12:44:26Z",
+    }),
+    authority: Some(Contract {
+        id: "CCMJGCRSQRZ56BDSLCAYV4BNS3SLIDPIP4CQYNT5X2VOPZIQ2ZM7GBVV",
+        wasm_hash: "fa5328baa04d7ed8ffa12bd1ac6a23cf1775a64c1a9f27364a9ebbba432b5d2f",
+        deployed_at: "2025-11-07T12:44:15Z",
+    }),
+    attest_dst: V1_ATTEST_DST,
+    revoke_dst: V1_REVOKE_DST,
+    bls_dst: V1_BLS_DST,
+};
+
+const FUTURENET: Deployment = Deployment {
+    network: Network::Futurenet,
+    protocol: None,
+    authority: None,
+    attest_dst: V1_ATTEST_DST,
+    revoke_dst: V1_REVOKE_DST,
+    bls_dst: V1_BLS_DST,
+};
+
+const MAINNET: Deployment = Deployment {
+    network: Network::Mainnet,
+    protocol: Some(Contract {
+        id: "CBUUI7WKGOTPCLXBPCHTKB5GNATWM4WAH4KMADY6GFCXOCNVF5OCW2WI",
+        wasm_hash: "6eeaf6691748956417b7929951ff3789f499190b2c220d3e758e3b62ddc66e7f",
+        deployed_at: "2025-11-05T07:12:13Z",
+    }),
+    authority: Some(Contract {
+        id: "CBKOB6XEEXYH5SEFQ4YSUEFJGYNBVISQBHQHVGCKB736A3JVGK7F77JG",
+        wasm_hash: "6bd7703a8bdef80d2a71483ef34f5682fec8df95e348c8b32d4140029d78cfaf",
+        deployed_at: "2025-11-05T07:22:15Z",
+    }),
+    attest_dst: V1_ATTEST_DST,
+    revoke_dst: V1_REVOKE_DST,
+    bls_dst: V1_BLS_DST,
+};
+
+/// The address book entry for `network`.
+pub fn deployment(network: Network) -> &'static Deployment {
+    match network {
+        Network::Testnet => &TESTNET,
+        Network::Futurenet => &FUTURENET,
+        Network::Mainnet => &MAINNET,
+    }
+}
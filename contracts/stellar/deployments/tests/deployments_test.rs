@@ -0,0 +1,68 @@
+use deployments::{deployment, DeploymentError, DstKind, Network};
+use protocol::instructions::crypto::ATTEST_PROTOCOL_BLS_G1_DST;
+use protocol::instructions::delegation::{get_attest_dst, get_revoke_dst};
+use serde_json::Value;
+
+/// **Test: The Address Book Matches `deployments.json`**
+/// - Networks missing from the file must be recorded as not deployed
+#[test]
+fn address_book_matches_deploy_record() {
+    let record: Value =
+        serde_json::from_str(include_str!("../../deployments.json")).expect("deployments.json is valid JSON");
+
+    for network in Network::ALL {
+        let entry = deployment(network);
+        for (name, contract) in [("protocol", entry.protocol), ("authority", entry.authority)] {
+            let recorded = &record[network.name()][name];
+            match contract {
+                Some(contract) => {
+                    assert_eq!(recorded["id"], contract.id, "{network} {name} id");
+                    assert_eq!(recorded["hash"], contract.wasm_hash, "{network} {name} hash");
+                    assert_eq!(
+                        recorded["timestamp"], contract.deployed_at,
+                        "{network} {name} timestamp"
+                    );
+                },
+                None => assert!(recorded.is_null(), "{network} {name} is deployed but not recorded"),
+            }
+        }
+    }
+
+    assert_eq!(
+        deployment(Network::Futurenet).protocol_id(),
+        Err(DeploymentError::NotDeployed {
+            network: Network::Futurenet,
+            contract: "protocol",
+        })
+    );
+    assert_eq!("mainnet".parse(), Ok(Network::Mainnet));
+    assert!("devnet".parse::<Network>().is_err());
+    assert_eq!(
+        Network::from_passphrase("Test SDF Network ; September 2015"),
+        Some(Network::Testnet)
+    );
+}
+
+/// **Test: Deployed Networks Expect the Protocol Crate's Tags**
+/// - A tag from another context is refused before anything is signed
+#[test]
+fn dst_guard_refuses_mismatched_tags() {
+    for network in Network::ALL {
+        let entry = deployment(network);
+        assert_eq!(entry.ensure_dst(DstKind::Attest, get_attest_dst()), Ok(()));
+        assert_eq!(entry.ensure_dst(DstKind::Revoke, get_revoke_dst()), Ok(()));
+        assert_eq!(entry.ensure_dst(DstKind::Bls, ATTEST_PROTOCOL_BLS_G1_DST), Ok(()));
+    }
+
+    let testnet = deployment(Network::Testnet);
+    assert_eq!(
+        testnet.ensure_dst(DstKind::Attest, get_revoke_dst()),
+        Err(DeploymentError::DstMismatch {
+            network: Network::Testnet,
+            kind: DstKind::Attest,
+        })
+    );
+    assert!(testnet
+        .ensure_dst(DstKind::Bls, b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_")
+        .is_err());
+}
@@ -0,0 +1,169 @@
+#![no_std]
+//! # Attest Error Registry
+//!
+//! Contract error codes for every attest.so Soroban contract, defined in one place
+//! so that codes never overlap between contracts. Each contract owns a range:
+//!
+//! | Range | Contract        | Enum                  |
+//! |-------|-----------------|-----------------------|
+//! | 1xx   | protocol        | `ProtocolError`       |
+//! | 2xx   | authority       | `AuthorityError`      |
+//! | 3xx   | resolvers       | `ResolverError`       |
+//! | 4xx   | gate            | `GateError`           |
+//! | 5xx   | subject_policy  | `SubjectPolicyError`  |
+//! | 6xx   | registry_facade | `RegistryFacadeError` |
+//!
+//! A host error such as `Error(Contract, #106)` therefore identifies both the failing
+//! contract and the failure without knowing which contract was invoked.
+//!
+//! Codes are append-only: retired codes are left as gaps rather than reused.
+use soroban_sdk::contracterror;
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Protocol Errors (1xx)
+// ══════════════════════════════════════════════════════════════════════════════
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ProtocolError {
+    SchemaNotFound = 103,
+    AttestationExists = 104,
+    AttestationNotFound = 105,
+    NotAuthorized = 106,
+    ResolverError = 110,
+    SchemaHasNoResolver = 111,
+    AdminNotSet = 112,
+    AlreadyInitialized = 113,
+    NotInitialized = 114,
+    AttestationNotRevocable = 115,
+    InvalidSchemaDefinition = 116,
+    InvalidAttestationValue = 117,
+    InvalidReference = 118,
+    InvalidNonce = 119,
+    ExpiredSignature = 120,
+    InvalidSignature = 121,
+    AttestationExpired = 122,
+    InvalidDeadline = 123,
+    ResolverCallFailed = 124,
+    InvalidSignaturePoint = 125,
+    BlsPubKeyNotRegistered = 126,
+    IntegerOverflow = 127,
+    Secp256k1KeyNotRegistered = 128,
+    InvalidEncryptionEnvelope = 129,
+    InvalidAmount = 130,
+    InvalidExternalId = 131,
+    AttesterBanned = 132,
+    SchemaMismatch = 133,
+    InvalidClaimPreimage = 134,
+    RevocationTooEarly = 135,
+    InvalidFieldCommitments = 136,
+    InvalidAdminCouncil = 137,
+    ProposalNotFound = 138,
+    InvalidCursor = 139,
+    SchemaRevoked = 140,
+    InvalidDomain = 141,
+    DomainChallengeMismatch = 142,
+    CommitmentNotFound = 143,
+    RevealOutsideWindow = 144,
+    ExpirationTooFar = 145,
+    UnsupportedMessageVersion = 146,
+    UniquenessKeyTaken = 147,
+    UniquenessKeyMissing = 148,
+    SchemaSuperseded = 149,
+    SchemaAlreadyRegistered = 150,
+    NoPendingAdmin = 151,
+    ContractPaused = 152,
+    LocaleNotAllowed = 153,
+    AppealNotAllowed = 154,
+    NoPendingAppeal = 155,
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Authority Errors (2xx)
+// ══════════════════════════════════════════════════════════════════════════════
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u32)]
+pub enum AuthorityError {
+    AlreadyInitialized = 201,
+    NotInitialized = 202,
+    NotAuthorized = 203,         // General auth failure
+    RecipientNotAuthority = 204, // Levy recipient must be registered
+    AttesterNotAuthority = 205,
+    SchemaNotRegistered = 206,
+    InvalidSchemaRules = 207,
+    InsufficientPayment = 208, // For registration fee
+    NothingToWithdraw = 209,
+    TokenTransferFailed = 210, // Deprecated/internal - transfer panics
+    WithdrawalFailed = 211,    // Deprecated/internal - transfer panics
+
+    // Trusted Verifier Errors
+    UnauthorizedVerifier = 212,
+    VerifierInactive = 213,
+    ExceedsVerificationLevel = 214,
+    InvalidVerificationLevel = 215,
+    VerifierNotFound = 216,
+    InvalidAuthorityData = 217,
+
+    // Application Queue Errors
+    ApplicationExists = 218,
+    ApplicationNotFound = 219,
+
+    // Bonding Errors
+    AuthoritySuspended = 220,
+    InsufficientBond = 221,
+    InvalidAmount = 222,
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Resolver Errors (3xx)
+// ══════════════════════════════════════════════════════════════════════════════
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ResolverError {
+    NotAuthorized = 301,
+    InvalidAttestation = 302,
+    InvalidSchema = 303,
+    InsufficientFunds = 304,
+    TokenTransferFailed = 305,
+    StakeRequired = 306,
+    ValidationFailed = 307,
+    CustomError = 308,
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Gate Errors (4xx)
+// ══════════════════════════════════════════════════════════════════════════════
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u32)]
+pub enum GateError {
+    AlreadyInitialized = 401,
+    NotInitialized = 402,
+    AttestationRequired = 403, // Subject holds no valid attestation for the schema
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Subject Policy Errors (5xx)
+// ══════════════════════════════════════════════════════════════════════════════
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u32)]
+pub enum SubjectPolicyError {
+    AlreadyInitialized = 501,
+    NotInitialized = 502,
+    CallNotAllowed = 503,   // The wallet was asked to authorize a call outside its policy
+    SchemaNotAllowed = 504, // The attestation's schema is not on the wallet's allowlist
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Registry Facade Errors (6xx)
+// ══════════════════════════════════════════════════════════════════════════════
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u32)]
+pub enum RegistryFacadeError {
+    AlreadyInitialized = 601,
+    NotInitialized = 602,
+}
@@ -0,0 +1,6 @@
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Contract Errors
+// ►
+// ► Defined in the shared `attest-errors` registry (gate range: 4xx).
+// ══════════════════════════════════════════════════════════════════════════════
+pub use attest_errors::GateError as Error;
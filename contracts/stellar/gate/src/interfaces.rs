@@ -0,0 +1,13 @@
+use soroban_sdk::{contractclient, Address, BytesN, Env};
+
+/// Protocol Contract Client Interface
+///
+/// The subset of the attestation protocol contract that the gate depends on.
+/// Declared locally rather than importing the protocol crate so the gate's Wasm
+/// does not re-export the protocol's contract functions.
+#[contractclient(name = "ProtocolClient")]
+pub trait Protocol {
+    /// Returns true if the subject's most recent attestation for the schema is
+    /// neither revoked nor expired
+    fn has_valid_attestation(env: Env, subject: Address, schema_uid: BytesN<32>) -> bool;
+}
@@ -0,0 +1,67 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+
+mod errors;
+pub mod interfaces;
+mod state;
+
+pub use errors::Error;
+pub use interfaces::ProtocolClient;
+pub use state::CACHE_TTL_LEDGERS;
+
+/// Attestation-based token-gating.
+///
+/// A small companion contract that other dapps can call as an authorization
+/// building block: `require_attestation` succeeds only when the subject holds a
+/// valid attestation for the schema in the protocol contract. Positive results are
+/// cached briefly in temporary storage so repeated checks stay cheap.
+#[contract]
+pub struct GateContract;
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Contract Implementation
+// ══════════════════════════════════════════════════════════════════════════════
+#[contractimpl]
+impl GateContract {
+    // ──────────────────────────────────────────────────────────────────────────
+    //                           Initialization
+    // ──────────────────────────────────────────────────────────────────────────
+
+    /// Binds the gate to an attestation protocol contract. Can only be called once.
+    pub fn initialize(env: Env, protocol: Address) -> Result<(), Error> {
+        if state::is_initialized(&env) {
+            return Err(Error::AlreadyInitialized);
+        }
+        state::set_protocol(&env, &protocol);
+        Ok(())
+    }
+
+    /// Returns the address of the protocol contract this gate checks against.
+    pub fn get_protocol(env: Env) -> Result<Address, Error> {
+        state::get_protocol(&env)
+    }
+
+    // ──────────────────────────────────────────────────────────────────────────
+    //                           Gating
+    // ──────────────────────────────────────────────────────────────────────────
+
+    /// Fails with `AttestationRequired` unless `subject` holds a valid attestation
+    /// for `schema_uid`.
+    ///
+    /// Successful checks are cached for `CACHE_TTL_LEDGERS` ledgers, so a revocation
+    /// may take up to that long to be reflected here. Failed checks are never cached.
+    pub fn require_attestation(env: Env, subject: Address, schema_uid: BytesN<32>) -> Result<(), Error> {
+        if state::is_cached(&env, &subject, &schema_uid) {
+            return Ok(());
+        }
+
+        let protocol = state::get_protocol(&env)?;
+        let client = ProtocolClient::new(&env, &protocol);
+        if !client.has_valid_attestation(&subject, &schema_uid) {
+            return Err(Error::AttestationRequired);
+        }
+
+        state::cache(&env, &subject, &schema_uid);
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+use crate::errors::Error;
+
+/// Number of ledgers (~5 seconds each) a positive attestation check stays cached.
+///
+/// Kept short so revocations in the protocol contract take effect quickly.
+pub const CACHE_TTL_LEDGERS: u32 = 60;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Protocol,                    // Address of the attestation protocol contract
+    Cached(Address, BytesN<32>), // (subject, schema_uid) -> last ledger the cached result is valid for
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Storage Helper Functions
+// ══════════════════════════════════════════════════════════════════════════════
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Protocol)
+}
+
+pub fn set_protocol(env: &Env, protocol: &Address) {
+    env.storage().instance().set(&DataKey::Protocol, protocol);
+}
+
+pub fn get_protocol(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Protocol)
+        .ok_or(Error::NotInitialized)
+}
+
+/// Returns true if a positive check for the pair is cached and still fresh.
+pub fn is_cached(env: &Env, subject: &Address, schema_uid: &BytesN<32>) -> bool {
+    let key = DataKey::Cached(subject.clone(), schema_uid.clone());
+    match env.storage().temporary().get::<DataKey, u32>(&key) {
+        Some(valid_until) => env.ledger().sequence() <= valid_until,
+        None => false,
+    }
+}
+
+/// Caches a positive check for the pair for `CACHE_TTL_LEDGERS` ledgers.
+pub fn cache(env: &Env, subject: &Address, schema_uid: &BytesN<32>) {
+    let key = DataKey::Cached(subject.clone(), schema_uid.clone());
+    let valid_until = env.ledger().sequence() + CACHE_TTL_LEDGERS;
+    env.storage().temporary().set(&key, &valid_until);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, CACHE_TTL_LEDGERS, CACHE_TTL_LEDGERS);
+}
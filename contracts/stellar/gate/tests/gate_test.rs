@@ -0,0 +1,82 @@
+use gate::{Error, GateContract, GateContractClient, CACHE_TTL_LEDGERS};
+use protocol::{AttestationContract, AttestationContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, String,
+};
+
+fn setup(
+    env: &Env,
+) -> (
+    AttestationContractClient<'_>,
+    GateContractClient<'_>,
+    Address,
+    BytesN<32>,
+) {
+    env.mock_all_auths();
+    let protocol_id = env.register(AttestationContract {}, ());
+    let protocol = AttestationContractClient::new(env, &protocol_id);
+    protocol.initialize(&Address::generate(env));
+
+    let gate_id = env.register(GateContract {}, ());
+    let gate = GateContractClient::new(env, &gate_id);
+    gate.initialize(&protocol_id);
+
+    let attester = Address::generate(env);
+    let schema_uid = protocol.register(&attester, &String::from_str(env, "membership"), &None, &true);
+
+    (protocol, gate, attester, schema_uid)
+}
+
+#[test]
+fn test_initialize_only_once() {
+    let env = Env::default();
+    let (protocol, gate, _, _) = setup(&env);
+
+    assert_eq!(gate.get_protocol(), protocol.address);
+    assert_eq!(
+        gate.try_initialize(&protocol.address),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_require_attestation_passes_for_attested_subject() {
+    let env = Env::default();
+    let (protocol, gate, subject, schema_uid) = setup(&env);
+
+    assert_eq!(
+        gate.try_require_attestation(&subject, &schema_uid),
+        Err(Ok(Error::AttestationRequired))
+    );
+
+    protocol.attest(&subject, &schema_uid, &String::from_str(&env, "member"), &None);
+    gate.require_attestation(&subject, &schema_uid);
+
+    // A different schema is not satisfied by this attestation
+    let other_schema = protocol.register(&subject, &String::from_str(&env, "other"), &None, &true);
+    assert_eq!(
+        gate.try_require_attestation(&subject, &other_schema),
+        Err(Ok(Error::AttestationRequired))
+    );
+}
+
+#[test]
+fn test_require_attestation_cache_expires_after_revocation() {
+    let env = Env::default();
+    let (protocol, gate, subject, schema_uid) = setup(&env);
+
+    let uid = protocol.attest(&subject, &schema_uid, &String::from_str(&env, "member"), &None);
+    gate.require_attestation(&subject, &schema_uid);
+
+    // The cached result is served while fresh, even after revocation
+    protocol.revoke(&subject, &uid);
+    gate.require_attestation(&subject, &schema_uid);
+
+    // Once the cache window passes the protocol is consulted again
+    env.ledger().with_mut(|li| li.sequence_number += CACHE_TTL_LEDGERS + 1);
+    assert_eq!(
+        gate.try_require_attestation(&subject, &schema_uid),
+        Err(Ok(Error::AttestationRequired))
+    );
+}
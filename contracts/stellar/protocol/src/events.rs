@@ -1,5 +1,23 @@
-use crate::state::{Attestation, Schema};
-use soroban_sdk::{symbol_short, Address, BytesN, Env, String};
+use crate::instructions::{get_schema_event_topic, is_schema_soulbound};
+use crate::state::{AdminOperation, Attestation, ProtocolMetrics, Schema, SchemaRevocation};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, IntoVal, String, Symbol, Val};
+
+/// Publishes an attestation lifecycle event, appending the schema's custom topic if set.
+fn publish_schema_event<D: IntoVal<Env, Val>>(env: &Env, schema_uid: &BytesN<32>, action: Symbol, data: D) {
+    match get_schema_event_topic(env, schema_uid) {
+        Some(topic) => env.events().publish((symbol_short!("ATTEST"), action, topic), data),
+        None => env.events().publish((symbol_short!("ATTEST"), action), data),
+    }
+}
+
+pub fn publish_schema_revoked(env: &Env, schema_uid: &BytesN<32>, revocation: &SchemaRevocation) {
+    let topics = (symbol_short!("SCHEMA"), symbol_short!("REVOKEALL"));
+    env.events().publish(topics, (schema_uid.clone(), revocation.clone()));
+}
+
+pub fn publish_metrics(env: &Env, metrics: &ProtocolMetrics) {
+    env.events().publish((symbol_short!("METRICS"),), metrics.clone());
+}
 
 pub fn schema_registered(env: &Env, schema_uid: &BytesN<32>, schema: &Schema, authority: &Address) {
     let topics = (symbol_short!("SCHEMA"), symbol_short!("REGISTER"));
@@ -8,7 +26,6 @@ pub fn schema_registered(env: &Env, schema_uid: &BytesN<32>, schema: &Schema, au
 }
 
 pub fn publish_attestation_event(env: &Env, attestation: &Attestation) {
-    let topics = (symbol_short!("ATTEST"), symbol_short!("CREATE"));
     let data: (BytesN<32>, Address, Address, String, u64, u64) = (
         attestation.uid.clone(),
         attestation.subject.clone(),
@@ -17,11 +34,33 @@ pub fn publish_attestation_event(env: &Env, attestation: &Attestation) {
         attestation.nonce,
         attestation.timestamp,
     );
-    env.events().publish(topics, data);
+    publish_schema_event(env, &attestation.schema_uid, symbol_short!("CREATE"), data);
+
+    // Localized attestations also emit their locale as a topic, so consumers can filter by language
+    if let Some(locale) = &attestation.locale {
+        let topics = (symbol_short!("ATTEST"), symbol_short!("LOCALE"), locale.clone());
+        env.events()
+            .publish(topics, (attestation.uid.clone(), attestation.schema_uid.clone()));
+    }
+
+    // ERC-5192: soulbound tokens emit `Locked` once minted
+    if is_schema_soulbound(env, &attestation.schema_uid) {
+        env.events()
+            .publish((symbol_short!("Locked"),), attestation.uid.clone());
+    }
+}
+
+pub fn publish_attestation_pending(env: &Env, attestation: &Attestation) {
+    let topics = (
+        symbol_short!("ATTEST"),
+        symbol_short!("PENDING"),
+        attestation.subject.clone(),
+    );
+    env.events()
+        .publish(topics, (attestation.uid.clone(), attestation.attester.clone()));
 }
 
 pub fn publish_revocation_event(env: &Env, attestation: &Attestation) {
-    let topics = (symbol_short!("ATTEST"), symbol_short!("REVOKE"));
     let data: (BytesN<32>, BytesN<32>, Address, Address, bool, u64) = (
         attestation.uid.clone(),
         attestation.schema_uid.clone(),
@@ -30,6 +69,140 @@ pub fn publish_revocation_event(env: &Env, attestation: &Attestation) {
         attestation.revoked,
         attestation.revocation_time.unwrap_or(0),
     );
+    publish_schema_event(env, &attestation.schema_uid, symbol_short!("REVOKE"), data);
+}
+
+pub fn publish_self_revocation_event(env: &Env, attestation: &Attestation) {
+    let data: (BytesN<32>, BytesN<32>, Address, u64) = (
+        attestation.uid.clone(),
+        attestation.schema_uid.clone(),
+        attestation.subject.clone(),
+        attestation.revocation_time.unwrap_or(0),
+    );
+    publish_schema_event(env, &attestation.schema_uid, symbol_short!("SELF_REV"), data);
+}
+
+pub fn publish_appeal_filed(env: &Env, attestation: &Attestation) {
+    let data: (BytesN<32>, Address) = (attestation.uid.clone(), attestation.subject.clone());
+    publish_schema_event(env, &attestation.schema_uid, symbol_short!("APPEAL"), data);
+}
+
+pub fn publish_appeal_resolved(env: &Env, attestation: &Attestation, reinstated: bool) {
+    let action = if reinstated {
+        symbol_short!("REINSTATE")
+    } else {
+        symbol_short!("UPHELD")
+    };
+    let data: (BytesN<32>, Address) = (attestation.uid.clone(), attestation.subject.clone());
+    publish_schema_event(env, &attestation.schema_uid, action, data);
+}
+
+pub fn publish_org_member_added(env: &Env, organization: &Address, member: &Address) {
+    let topics = (symbol_short!("ORG"), symbol_short!("ADD_MBR"));
+    let data: (Address, Address) = (organization.clone(), member.clone());
+    env.events().publish(topics, data);
+}
+
+pub fn publish_org_member_removed(env: &Env, organization: &Address, member: &Address) {
+    let topics = (symbol_short!("ORG"), symbol_short!("REM_MBR"));
+    let data: (Address, Address) = (organization.clone(), member.clone());
+    env.events().publish(topics, data);
+}
+
+pub fn publish_attester_banned(env: &Env, attester: &Address) {
+    let topics = (symbol_short!("ATTESTER"), symbol_short!("BAN"));
+    env.events().publish(topics, attester.clone());
+}
+
+pub fn publish_contract_paused(env: &Env, admin: &Address) {
+    let topics = (symbol_short!("CONTRACT"), symbol_short!("PAUSED"));
+    env.events().publish(topics, admin.clone());
+}
+
+pub fn publish_contract_unpaused(env: &Env, admin: &Address) {
+    let topics = (symbol_short!("CONTRACT"), symbol_short!("UNPAUSED"));
+    env.events().publish(topics, admin.clone());
+}
+
+pub fn publish_contract_upgraded(env: &Env, admin: &Address, new_wasm_hash: &BytesN<32>) {
+    let topics = (symbol_short!("CONTRACT"), symbol_short!("UPGRADE"));
+    env.events().publish(topics, (admin.clone(), new_wasm_hash.clone()));
+}
+
+pub fn publish_storage_migrated(env: &Env, admin: &Address, from_version: u32, to_version: u32) {
+    let topics = (symbol_short!("CONTRACT"), symbol_short!("MIGRATE"));
+    env.events().publish(topics, (admin.clone(), from_version, to_version));
+}
+
+pub fn publish_attester_unbanned(env: &Env, attester: &Address) {
+    let topics = (symbol_short!("ATTESTER"), symbol_short!("UNBAN"));
+    env.events().publish(topics, attester.clone());
+}
+
+pub fn publish_attester_domain_requested(env: &Env, attester: &Address, domain: &String) {
+    let topics = (symbol_short!("ATTESTER"), symbol_short!("DOMAINREQ"), attester.clone());
+    env.events().publish(topics, domain.clone());
+}
+
+pub fn publish_attester_domain_verified(env: &Env, attester: &Address, domain: &String) {
+    let topics = (symbol_short!("ATTESTER"), symbol_short!("DOMAIN"), attester.clone());
+    env.events().publish(topics, domain.clone());
+}
+
+pub fn publish_compromise_declared(env: &Env, attester: &Address, freeze_after: Option<u64>) {
+    let topics = (symbol_short!("ATTESTER"), symbol_short!("COMPROMIS"), attester.clone());
+    env.events().publish(topics, freeze_after);
+}
+
+pub fn publish_delegation_nonce_cancelled(env: &Env, attester: &Address, nonce: u64) {
+    let topics = (symbol_short!("ATTESTER"), symbol_short!("CANCEL"), attester.clone());
+    env.events().publish(topics, nonce);
+}
+
+pub fn publish_attestation_unfrozen(env: &Env, attestation_uid: &BytesN<32>) {
+    let topics = (symbol_short!("ATTEST"), symbol_short!("UNFREEZE"));
+    env.events().publish(topics, attestation_uid.clone());
+}
+
+pub fn publish_claimable_attestation(env: &Env, attestation_uid: &BytesN<32>, attester: &Address, expiry: u64) {
+    let topics = (
+        symbol_short!("ATTEST"),
+        symbol_short!("CLAIMABLE"),
+        attestation_uid.clone(),
+    );
+    let data: (Address, u64) = (attester.clone(), expiry);
+    env.events().publish(topics, data);
+}
+
+pub fn publish_attestation_committed(env: &Env, commitment: &BytesN<32>, attester: &Address) {
+    let topics = (symbol_short!("ATTEST"), symbol_short!("COMMIT"), attester.clone());
+    env.events().publish(topics, commitment.clone());
+}
+
+pub fn publish_legacy_import(env: &Env, admin: &Address, authorities: u32, attestations: u32) {
+    let topics = (symbol_short!("MIGRATE"), symbol_short!("IMPORT"));
+    let data: (Address, u32, u32) = (admin.clone(), authorities, attestations);
+    env.events().publish(topics, data);
+}
+
+pub fn publish_attestation_purged(env: &Env, attestation: &Attestation, reason: Symbol) {
+    let data: (BytesN<32>, Symbol) = (attestation.uid.clone(), reason);
+    publish_schema_event(env, &attestation.schema_uid, symbol_short!("PURGED"), data);
+}
+
+pub fn publish_attestation_expired(env: &Env, attestation: &Attestation) {
+    let data: (BytesN<32>, Option<u64>) = (attestation.uid.clone(), attestation.expiration_time);
+    publish_schema_event(env, &attestation.schema_uid, symbol_short!("EXPIRED"), data);
+}
+
+pub fn publish_attestation_ttl_extended(env: &Env, attestation: &Attestation, live_until_ledger: u32) {
+    let data: (BytesN<32>, u32) = (attestation.uid.clone(), live_until_ledger);
+    publish_schema_event(env, &attestation.schema_uid, symbol_short!("TTLEXTEND"), data);
+}
+
+pub fn publish_purge(env: &Env, caller: &Address, removed: u32, payout: i128) {
+    let topics = (symbol_short!("ATTEST"), symbol_short!("PURGE"));
+    let data: (Address, u32, i128) = (caller.clone(), removed, payout);
     env.events().publish(topics, data);
 }
 
@@ -39,6 +212,38 @@ pub fn publish_bls_key_registered(env: &Env, attester: &Address, public_key: &By
     env.events().publish(topics, data);
 }
 
+pub fn publish_secp256k1_key_registered(env: &Env, attester: &Address, eth_address: &BytesN<20>, timestamp: u64) {
+    let topics = (symbol_short!("SECP_KEY"), symbol_short!("REGISTER"));
+    let data: (Address, BytesN<20>, u64) = (attester.clone(), eth_address.clone(), timestamp);
+    env.events().publish(topics, data);
+}
+
+pub fn publish_admin_proposal(env: &Env, proposal_id: u32, proposer: &Address) {
+    let topics = (symbol_short!("ADMIN"), symbol_short!("PROPOSE"));
+    env.events().publish(topics, (proposal_id, proposer.clone()));
+}
+
+pub fn publish_admin_approval(env: &Env, proposal_id: u32, approver: &Address) {
+    let topics = (symbol_short!("ADMIN"), symbol_short!("APPROVE"));
+    env.events().publish(topics, (proposal_id, approver.clone()));
+}
+
+pub fn publish_admin_execution(env: &Env, proposal_id: u32, operation: &AdminOperation) {
+    let topics = (symbol_short!("ADMIN"), symbol_short!("EXECUTE"));
+    env.events().publish(topics, (proposal_id, operation.clone()));
+}
+
+pub fn publish_admin_transfer_proposed(env: &Env, admin: &Address, new_admin: &Address) {
+    let topics = (symbol_short!("ADMIN"), symbol_short!("PENDING"));
+    env.events().publish(topics, (admin.clone(), new_admin.clone()));
+}
+
+pub fn publish_admin_transferred(env: &Env, previous_admin: &Address, new_admin: &Address) {
+    let topics = (symbol_short!("ADMIN"), symbol_short!("ACCEPT"));
+    env.events()
+        .publish(topics, (previous_admin.clone(), new_admin.clone()));
+}
+
 pub fn publish_contract_initialized(env: &Env, admin: &Address) {
     let topics = (symbol_short!("CONTRACT"), symbol_short!("INIT"));
     let data: (Address,) = (admin.clone(),);
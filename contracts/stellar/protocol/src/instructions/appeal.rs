@@ -0,0 +1,177 @@
+use crate::errors::Error;
+use crate::events;
+use crate::instructions::moderation::ensure_not_paused;
+use crate::instructions::record_outbox_entry;
+use crate::instructions::schema::{get_schema_or_fail, record_schema_reinstatement};
+use crate::state::{Attestation, AttestationAppeal, DataKey, ExtendedKey, OutboxAction};
+use soroban_sdk::{Address, BytesN, Env};
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Sets how long subjects may appeal revocations under a schema.
+////////////////////////////////////////////////////////////////////////////////////
+/// Consumer-protection sensitive credentials need a way to contest a wrongful
+/// revocation. While set, the subject of a revoked attestation may `appeal` it within
+/// `window` seconds of the revocation, and the schema authority then confirms the
+/// revocation or reinstates the attestation with `resolve_appeal`.
+///
+/// # Authorization
+/// Requires authorization from the schema authority.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `caller` - The schema authority.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+/// * `window` - The challenge window in seconds, or 0 to disable appeals.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+/// * `Error::NotAuthorized` - If the caller is not the schema authority.
+pub fn set_schema_challenge_window(
+    env: &Env,
+    caller: Address,
+    schema_uid: BytesN<32>,
+    window: u64,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    let schema = get_schema_or_fail(env, &schema_uid)?;
+    if schema.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+
+    let key = DataKey::Extended(ExtendedKey::SchemaChallengeWindow(schema_uid));
+    if window == 0 {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, &window);
+    }
+    Ok(())
+}
+
+/// Returns a schema's challenge window in seconds, or 0 if its revocations cannot be appealed.
+pub fn get_schema_challenge_window(env: &Env, schema_uid: &BytesN<32>) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Extended(ExtendedKey::SchemaChallengeWindow(
+            schema_uid.clone(),
+        )))
+        .unwrap_or(0)
+}
+
+/// Returns the appeal against an attestation's revocation, if one was filed.
+pub fn get_attestation_appeal(env: &Env, attestation_uid: &BytesN<32>) -> Option<AttestationAppeal> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Extended(ExtendedKey::AttestationAppeal(
+            attestation_uid.clone(),
+        )))
+}
+
+/// Returns whether an attestation's revocation is appealed and awaiting the schema authority's decision.
+pub(crate) fn is_disputed(env: &Env, attestation_uid: &BytesN<32>) -> bool {
+    get_attestation_appeal(env, attestation_uid).is_some_and(|appeal| appeal.confirmed_at.is_none())
+}
+
+/// Appeals the revocation of an attestation, putting it in dispute.
+///
+/// # Authorization
+/// Requires authorization from the subject of the attestation.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `subject` - The subject of the revoked attestation
+/// * `attestation_uid` - The UID of the revoked attestation
+///
+/// # Errors
+/// * `Error::AttestationNotFound` - If the attestation does not exist
+/// * `Error::NotAuthorized` - If `subject` is not the attestation's subject
+/// * `Error::AppealNotAllowed` - If the attestation is not revoked, its schema has no
+///   challenge window, the window has closed, or the revocation was already appealed
+pub fn appeal(env: &Env, subject: Address, attestation_uid: BytesN<32>) -> Result<(), Error> {
+    ensure_not_paused(env)?;
+    subject.require_auth();
+
+    let attestation = get_attestation(env, &attestation_uid)?;
+    if attestation.subject != subject {
+        return Err(Error::NotAuthorized);
+    }
+
+    let window = get_schema_challenge_window(env, &attestation.schema_uid);
+    let Some(revoked_at) = attestation
+        .revocation_time
+        .filter(|_| attestation.revoked && window > 0)
+    else {
+        return Err(Error::AppealNotAllowed);
+    };
+    let now = env.ledger().timestamp();
+    if now > revoked_at.saturating_add(window) || get_attestation_appeal(env, &attestation_uid).is_some() {
+        return Err(Error::AppealNotAllowed);
+    }
+
+    let appeal = AttestationAppeal {
+        appellant: subject,
+        appealed_at: now,
+        confirmed_at: None,
+    };
+    env.storage().persistent().set(
+        &DataKey::Extended(ExtendedKey::AttestationAppeal(attestation_uid)),
+        &appeal,
+    );
+    events::publish_appeal_filed(env, &attestation);
+    Ok(())
+}
+
+/// Settles a disputed revocation, either confirming it or reinstating the attestation.
+///
+/// # Authorization
+/// Requires authorization from the schema authority.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The schema authority
+/// * `attestation_uid` - The UID of the disputed attestation
+/// * `reinstate` - True to reverse the revocation, false to confirm it
+///
+/// # Errors
+/// * `Error::AttestationNotFound` - If the attestation does not exist
+/// * `Error::NotAuthorized` - If the caller is not the schema authority
+/// * `Error::NoPendingAppeal` - If the attestation is not disputed
+pub fn resolve_appeal(env: &Env, caller: Address, attestation_uid: BytesN<32>, reinstate: bool) -> Result<(), Error> {
+    ensure_not_paused(env)?;
+    caller.require_auth();
+
+    let mut attestation = get_attestation(env, &attestation_uid)?;
+    let schema = get_schema_or_fail(env, &attestation.schema_uid)?;
+    if schema.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+
+    let appeal_key = DataKey::Extended(ExtendedKey::AttestationAppeal(attestation_uid.clone()));
+    let mut appeal = get_attestation_appeal(env, &attestation_uid)
+        .filter(|appeal| appeal.confirmed_at.is_none())
+        .ok_or(Error::NoPendingAppeal)?;
+
+    if reinstate {
+        attestation.revoked = false;
+        attestation.revocation_time = None;
+        env.storage()
+            .persistent()
+            .set(&DataKey::AttestationUID(attestation_uid), &attestation);
+        env.storage().persistent().remove(&appeal_key);
+        record_schema_reinstatement(env, &attestation.schema_uid);
+        record_outbox_entry(env, OutboxAction::Reinstated, &attestation);
+    } else {
+        appeal.confirmed_at = Some(env.ledger().timestamp());
+        env.storage().persistent().set(&appeal_key, &appeal);
+    }
+
+    events::publish_appeal_resolved(env, &attestation, reinstate);
+    Ok(())
+}
+
+fn get_attestation(env: &Env, attestation_uid: &BytesN<32>) -> Result<Attestation, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AttestationUID(attestation_uid.clone()))
+        .ok_or(Error::AttestationNotFound)
+}
@@ -1,10 +1,28 @@
 use crate::errors::Error;
-use crate::state::{Attestation, DataKey};
-use soroban_sdk::{Address, Bytes, BytesN, Env, String};
+use crate::state::{Attestation, AttestationStatus, DataKey, HookSimulation, OutboxAction, Schema, Subject};
+use soroban_sdk::{Address, Bytes, BytesN, Env, InvokeError, String, Symbol, Vec};
 
 use crate::events;
-use crate::interfaces::resolver::{ResolverAttestation, ResolverClient};
+use crate::instructions::appeal::is_disputed;
+use crate::instructions::compromise::is_frozen;
+use crate::instructions::consent::is_schema_consent_required;
+use crate::instructions::expiration::ensure_expiration_within_horizon;
+use crate::instructions::hook_queue::{enqueue_hooks, is_schema_hooks_deferred};
+use crate::instructions::listing::{index_attestation_listings, track_index_key};
+use crate::instructions::locale::ensure_locale_allowed;
+use crate::instructions::moderation::{ensure_attester_not_banned, ensure_not_paused};
+use crate::instructions::organization::{index_org_attestation, is_org_member};
+use crate::instructions::record_outbox_entry;
+use crate::instructions::schema::{
+    apply_schema_revocation, ensure_schema_not_revoked, get_schema_dedup_window, get_schema_min_active_duration,
+    get_schema_revocation, is_schema_soulbound, is_schema_subject_revocable, record_schema_attestation,
+    record_schema_revocation,
+};
+use crate::instructions::uniqueness::reserve_uniqueness_key;
+use crate::instructions::value::ensure_value_conforms;
+use crate::interfaces::resolver::{ResolverAttestation, ResolverClient, ResolverContext};
 use crate::utils::{self, generate_attestation_uid};
+use soroban_sdk::xdr::ToXdr;
 
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Resolver Cross-Contract Call Helpers
@@ -12,7 +30,7 @@ use crate::utils::{self, generate_attestation_uid};
 
 /// Calls onattest on a resolver contract
 /// Returns true if the attestation should be allowed, false otherwise
-fn call_resolver_onattest(
+pub(crate) fn call_resolver_onattest(
     env: &Env,
     resolver_address: &Address,
     attestation: &ResolverAttestation,
@@ -28,6 +46,41 @@ fn call_resolver_onattest(
     }
 }
 
+/// Calls expiration_override on a resolver contract once onattest has allowed an attestation
+/// Failures are treated as no override, as resolvers are not required to implement the hook
+fn call_resolver_expiration_override(
+    env: &Env,
+    resolver_address: &Address,
+    attestation: &ResolverAttestation,
+) -> Option<u64> {
+    let resolver_client = ResolverClient::new(env, resolver_address);
+
+    match resolver_client.try_expiration_override(attestation) {
+        Ok(Ok(expiration_time)) => expiration_time,
+        _ => None,
+    }
+}
+
+/// Replaces an attestation's expiration with the resolver's override, if it returns one.
+///
+/// The override is held to the same rules as an attester-supplied expiration: it must be
+/// in the future and within the schema's expiration horizon.
+pub(crate) fn apply_resolver_expiration(
+    env: &Env,
+    resolver_address: &Address,
+    resolver_attestation: &ResolverAttestation,
+    attestation: &mut Attestation,
+) -> Result<(), Error> {
+    if let Some(exp_time) = call_resolver_expiration_override(env, resolver_address, resolver_attestation) {
+        if exp_time <= env.ledger().timestamp() {
+            return Err(Error::InvalidDeadline);
+        }
+        ensure_expiration_within_horizon(env, &attestation.schema_uid, Some(exp_time))?;
+        attestation.expiration_time = Some(exp_time);
+    }
+    Ok(())
+}
+
 /// Calls onrevoke on a resolver contract
 /// Returns true if the revocation should be allowed, false otherwise
 fn call_resolver_onrevoke(
@@ -48,30 +101,72 @@ fn call_resolver_onrevoke(
 
 /// Calls onresolve on a resolver contract
 /// Failures are logged but don't revert the attestation or revocation
-fn call_resolver_onresolve(env: &Env, resolver_address: &Address, attestation: &ResolverAttestation) {
+pub(crate) fn call_resolver_onresolve(env: &Env, resolver_address: &Address, attestation: &ResolverAttestation) {
     let resolver_client = ResolverClient::new(env, resolver_address);
 
     // Ignore failures in onresolve - they're non-critical side effects
     let _ = resolver_client.try_onresolve(attestation);
 }
 
+/// Calls onrelayed on a resolver contract after a delegated attestation
+/// Failures are ignored, as resolvers are not required to implement the hook
+pub(crate) fn call_resolver_onrelayed(
+    env: &Env,
+    resolver_address: &Address,
+    attestation: &ResolverAttestation,
+    context: &ResolverContext,
+) {
+    let resolver_client = ResolverClient::new(env, resolver_address);
+
+    let _ = resolver_client.try_onrelayed(attestation, context);
+}
+
+/// Calls on_schema_attach on a resolver contract after a schema selecting it is registered
+/// Failures are ignored, as resolvers are not required to implement the hook
+pub(crate) fn call_resolver_on_schema_attach(
+    env: &Env,
+    resolver_address: &Address,
+    schema_uid: &BytesN<32>,
+    schema_authority: &Address,
+) {
+    let resolver_client = ResolverClient::new(env, resolver_address);
+
+    let _ = resolver_client.try_on_schema_attach(schema_uid, schema_authority);
+}
+
+/// Calls quote_fees on a resolver contract
+/// Failures are treated as no charges, as resolvers are not required to implement the hook
+fn call_resolver_quote_fees(
+    env: &Env,
+    resolver_address: &Address,
+    schema_uid: &BytesN<32>,
+    attester: &Address,
+) -> Vec<(Address, i128)> {
+    let resolver_client = ResolverClient::new(env, resolver_address);
+
+    match resolver_client.try_quote_fees(schema_uid, attester) {
+        Ok(Ok(charges)) => charges,
+        _ => Vec::new(env),
+    }
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Helper Functions for Resolver Integration
 // ══════════════════════════════════════════════════════════════════════════════
 
 /// Creates a ResolverAttestation from protocol Attestation data
 /// This converts between the protocol's internal format and the resolver interface format
-fn create_resolver_attestation(
+pub(crate) fn create_resolver_attestation(
     env: &Env,
     attestation: &Attestation,
     schema_uid: &BytesN<32>,
-    _value: &String,
+    value: &String,
 ) -> ResolverAttestation {
-    // Generate a UID for this attestation (protocol doesn't store UIDs currently)
-    let uid = generate_attestation_uid(env, schema_uid, &attestation.subject, attestation.nonce);
+    // Flattened: empty bytes = not set
+    let ref_uid = attestation.ref_uid.clone().map_or_else(|| Bytes::new(env), Bytes::from);
 
     ResolverAttestation {
-        uid,
+        uid: attestation.uid.clone(),
         schema_uid: schema_uid.clone(),
         recipient: attestation.subject.clone(),
         attester: attestation.attester.clone(),
@@ -79,8 +174,8 @@ fn create_resolver_attestation(
         expiration_time: attestation.expiration_time.unwrap_or(0), // Flattened: 0 = not set
         revocation_time: attestation.revocation_time.unwrap_or(0), // Flattened: 0 = not set
         revocable: true,                                           // Will be set based on schema
-        ref_uid: Bytes::new(env),                                  // Flattened: empty bytes = not set
-        data: Bytes::from_slice(env, b"placeholder"),              // TODO: Convert string to bytes properly
+        ref_uid,
+        data: utils::string_to_bytes(env, value),
         value: 0, // Flattened: 0 = not set (protocol doesn't support value field yet)
     }
 }
@@ -110,10 +205,505 @@ pub fn attest(
     value: String,
     expiration_time: Option<u64>,
 ) -> Result<BytesN<32>, Error> {
+    create_attestation(
+        env,
+        attester,
+        schema_uid,
+        value,
+        expiration_time,
+        AttestationOptions::default(),
+    )
+}
+
+/// Creates an attestation attributed to an organization.
+///
+/// The attester must be a registered member of `organization`. The attestation records
+/// the organization in `on_behalf_of` and is indexed under it, so institutional issuance
+/// is not tied to a single member's key.
+///
+/// # Authorization
+/// Requires authorization from the caller (attester).
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The member creating the attestation. This address will also be the subject of the attestation.
+/// * `organization` - The organization the attestation is issued on behalf of
+/// * `schema_uid` - The unique identifier of the schema
+/// * `value` - The attestation data
+/// * `expiration_time` - Optional expiration timestamp
+///
+/// # Returns
+/// * `Result<BytesN<32>, Error>` - The UID of the created attestation or error
+pub fn attest_on_behalf_of(
+    env: &Env,
+    attester: Address,
+    organization: Address,
+    schema_uid: BytesN<32>,
+    value: String,
+    expiration_time: Option<u64>,
+) -> Result<BytesN<32>, Error> {
+    if !is_org_member(env, &organization, &attester) {
+        return Err(Error::NotAuthorized);
+    }
+    let attestation_uid = create_attestation(
+        env,
+        attester,
+        schema_uid,
+        value,
+        expiration_time,
+        AttestationOptions {
+            on_behalf_of: Some(organization.clone()),
+            ..Default::default()
+        },
+    )?;
+    index_org_attestation(env, &organization, &attestation_uid);
+    Ok(attestation_uid)
+}
+
+/// Encryption schemes recognized for confidential attestations.
+///
+/// Identifiers name the key agreement, and the symmetric cipher used for `value`:
+/// * `X25519_XSALSA20_POLY1305` - NaCl `box`, as used by most wallet encryption APIs
+/// * `X25519_AES256GCM` - X25519 key agreement with AES-256-GCM
+/// * `ECIES_SECP256K1_AES256GCM` - ECIES over secp256k1 with AES-256-GCM, for EVM keys
+pub const SUPPORTED_ENCRYPTION_SCHEMES: [&str; 3] = [
+    "X25519_XSALSA20_POLY1305",
+    "X25519_AES256GCM",
+    "ECIES_SECP256K1_AES256GCM",
+];
+
+/// Maximum size of a wrapped content key, in bytes.
+pub const MAX_ENCRYPTED_KEY_LEN: u32 = 512;
+
+/// Validates the encryption envelope of a confidential attestation.
+///
+/// # Errors
+/// * `Error::InvalidEncryptionEnvelope` - If the scheme is not in `SUPPORTED_ENCRYPTION_SCHEMES`,
+///   or the wrapped key is empty or longer than `MAX_ENCRYPTED_KEY_LEN`
+pub fn validate_encryption_envelope(env: &Env, encryption_scheme: &Symbol, encrypted_key: &Bytes) -> Result<(), Error> {
+    let supported = SUPPORTED_ENCRYPTION_SCHEMES
+        .iter()
+        .any(|scheme| Symbol::new(env, scheme) == *encryption_scheme);
+    if !supported || encrypted_key.is_empty() || encrypted_key.len() > MAX_ENCRYPTED_KEY_LEN {
+        return Err(Error::InvalidEncryptionEnvelope);
+    }
+    Ok(())
+}
+
+/// Creates a confidential attestation whose `value` is ciphertext.
+///
+/// Behaves like `attest`, additionally recording which scheme encrypted the value and
+/// the content key wrapped for its reader(s), so any SDK can locate and decrypt it.
+///
+/// # Authorization
+/// Requires authorization from the caller (attester).
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The address creating the attestation. This address will also be the subject of the attestation.
+/// * `schema_uid` - The unique identifier of the schema
+/// * `value` - The encrypted attestation data
+/// * `expiration_time` - Optional expiration timestamp
+/// * `encryption_scheme` - One of `SUPPORTED_ENCRYPTION_SCHEMES`
+/// * `encrypted_key` - The wrapped content key
+///
+/// # Returns
+/// * `Result<BytesN<32>, Error>` - The UID of the created attestation or error
+pub fn attest_encrypted(
+    env: &Env,
+    attester: Address,
+    schema_uid: BytesN<32>,
+    value: String,
+    expiration_time: Option<u64>,
+    encryption_scheme: Symbol,
+    encrypted_key: Bytes,
+) -> Result<BytesN<32>, Error> {
+    validate_encryption_envelope(env, &encryption_scheme, &encrypted_key)?;
+    create_attestation(
+        env,
+        attester,
+        schema_uid,
+        value,
+        expiration_time,
+        AttestationOptions {
+            envelope: Some((encryption_scheme, encrypted_key)),
+            ..Default::default()
+        },
+    )
+}
+
+/// Maximum size of an external reference ID, in bytes.
+pub const MAX_EXTERNAL_ID_LEN: u32 = 64;
+
+/// Creates an attestation carrying an attester-defined external reference ID.
+///
+/// Behaves like `attest`, additionally indexing the attestation under `(attester, external_id)`
+/// so it can be found with `get_attestation_by_external_id` using the attester's own record ID.
+///
+/// # Authorization
+/// Requires authorization from the caller (attester).
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The address creating the attestation. This address will also be the subject of the attestation.
+/// * `schema_uid` - The unique identifier of the schema
+/// * `value` - The attestation data
+/// * `expiration_time` - Optional expiration timestamp
+/// * `external_id` - Opaque reference ID, unique per attester
+///
+/// # Returns
+/// * `Result<BytesN<32>, Error>` - The UID of the created attestation or error
+///
+/// # Errors
+/// * `Error::InvalidExternalId` - If `external_id` is empty or longer than `MAX_EXTERNAL_ID_LEN`
+/// * `Error::AttestationExists` - If the attester already used `external_id`
+pub fn attest_with_external_id(
+    env: &Env,
+    attester: Address,
+    schema_uid: BytesN<32>,
+    value: String,
+    expiration_time: Option<u64>,
+    external_id: Bytes,
+) -> Result<BytesN<32>, Error> {
+    if external_id.is_empty() || external_id.len() > MAX_EXTERNAL_ID_LEN {
+        return Err(Error::InvalidExternalId);
+    }
+    let index_key = DataKey::ExternalId(attester.clone(), external_id.clone());
+    if env.storage().persistent().has(&index_key) {
+        return Err(Error::AttestationExists);
+    }
+
+    let attestation_uid = create_attestation(
+        env,
+        attester,
+        schema_uid,
+        value,
+        expiration_time,
+        AttestationOptions {
+            external_id: Some(external_id),
+            ..Default::default()
+        },
+    )?;
+    env.storage().persistent().set(&index_key, &attestation_uid);
+    Ok(attestation_uid)
+}
+
+/// Creates an attestation written in a given locale.
+///
+/// Behaves like `attest`, additionally recording `locale` on the attestation, in its event
+/// and in the schema's per-locale listing, so multi-language credential products can
+/// filter and render attestations by language.
+///
+/// # Authorization
+/// Requires authorization from the caller (attester).
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The address creating the attestation. This address will also be the subject of the attestation.
+/// * `schema_uid` - The unique identifier of the schema
+/// * `value` - The attestation data
+/// * `expiration_time` - Optional expiration timestamp
+/// * `locale` - The language the attestation is written in, e.g. `en` or `pt_BR`
+///
+/// # Returns
+/// * `Result<BytesN<32>, Error>` - The UID of the created attestation or error
+///
+/// # Errors
+/// * `Error::LocaleNotAllowed` - If the schema restricts locales and `locale` is not among them
+pub fn attest_with_locale(
+    env: &Env,
+    attester: Address,
+    schema_uid: BytesN<32>,
+    value: String,
+    expiration_time: Option<u64>,
+    locale: Symbol,
+) -> Result<BytesN<32>, Error> {
+    create_attestation(
+        env,
+        attester,
+        schema_uid,
+        value,
+        expiration_time,
+        AttestationOptions {
+            locale: Some(locale),
+            ..Default::default()
+        },
+    )
+}
+
+/// Creates an attestation that builds on an existing one.
+///
+/// Behaves like `attest`, additionally recording `ref_uid` on the attestation and in the
+/// referenced attestation's listing, so chained credentials (e.g. course → degree) can
+/// be traversed on-chain with `get_referencing_attestations`.
+///
+/// # Authorization
+/// Requires authorization from the caller (attester).
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The address creating the attestation. This address will also be the subject of the attestation.
+/// * `schema_uid` - The unique identifier of the schema
+/// * `value` - The attestation data
+/// * `expiration_time` - Optional expiration timestamp
+/// * `ref_uid` - The UID of the attestation this one builds on
+///
+/// # Returns
+/// * `Result<BytesN<32>, Error>` - The UID of the created attestation or error
+///
+/// # Errors
+/// * `Error::InvalidReference` - If `ref_uid` does not exist or is revoked
+pub fn attest_with_ref(
+    env: &Env,
+    attester: Address,
+    schema_uid: BytesN<32>,
+    value: String,
+    expiration_time: Option<u64>,
+    ref_uid: BytesN<32>,
+) -> Result<BytesN<32>, Error> {
+    create_attestation(
+        env,
+        attester,
+        schema_uid,
+        value,
+        expiration_time,
+        AttestationOptions {
+            ref_uid: Some(ref_uid),
+            ..Default::default()
+        },
+    )
+}
+
+/// Retrieves an attestation by the external reference ID its attester attached to it.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The address that created the attestation
+/// * `external_id` - The reference ID passed to `attest_with_external_id`
+///
+/// # Returns
+/// * `Result<Attestation, Error>` - The attestation record or `Error::AttestationNotFound`
+pub fn get_attestation_by_external_id(env: &Env, attester: Address, external_id: Bytes) -> Result<Attestation, Error> {
+    let attestation_uid: BytesN<32> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ExternalId(attester, external_id))
+        .ok_or(Error::AttestationNotFound)?;
+    get_attestation_record(env, attestation_uid)
+}
+
+/// Creates an attestation whose subject is a muxed account.
+///
+/// Muxed (`M...`) addresses are an underlying account plus a 64-bit mux ID, used by
+/// exchanges and custodians to address sub-accounts of a single Stellar account. The
+/// attestation is stored and indexed under the underlying account as usual, so queries by
+/// the `G...` address keep working, and the mux ID is recorded alongside it so the
+/// sub-account can be queried on its own with `has_valid_subject_attestation`.
+///
+/// # Authorization
+/// Requires authorization from the caller (attester).
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The address creating the attestation
+/// * `schema_uid` - The unique identifier of the schema
+/// * `subject` - The underlying account of the subject sub-account
+/// * `mux_id` - The mux ID of the subject sub-account
+/// * `value` - The attestation data
+/// * `expiration_time` - Optional expiration timestamp
+///
+/// # Returns
+/// * `Result<BytesN<32>, Error>` - The UID of the created attestation or error
+///
+/// # Errors
+/// * `Error::NotAuthorized` - If the schema requires subject consent and the subject is
+///   not the attester
+pub fn attest_muxed(
+    env: &Env,
+    attester: Address,
+    schema_uid: BytesN<32>,
+    subject: Address,
+    mux_id: u64,
+    value: String,
+    expiration_time: Option<u64>,
+) -> Result<BytesN<32>, Error> {
+    create_attestation(
+        env,
+        attester,
+        schema_uid,
+        value,
+        expiration_time,
+        AttestationOptions {
+            subject: Some(Subject {
+                address: subject,
+                mux_id: Some(mux_id),
+            }),
+            ..Default::default()
+        },
+    )
+}
+
+/// Retrieves the subject of an attestation, including its mux ID if it was issued to a
+/// muxed account.
+///
+/// # Errors
+/// * `Error::AttestationNotFound` - If the attestation does not exist
+pub fn get_attestation_subject(env: &Env, attestation_uid: BytesN<32>) -> Result<Subject, Error> {
+    let attestation = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Attestation>(&DataKey::AttestationUID(attestation_uid.clone()))
+        .ok_or(Error::AttestationNotFound)?;
+    Ok(Subject {
+        address: attestation.subject,
+        mux_id: env.storage().persistent().get(&DataKey::SubjectMuxId(attestation_uid)),
+    })
+}
+
+/// Maximum number of field commitments a selective disclosure attestation can carry.
+pub const MAX_FIELD_COMMITMENTS: u32 = 32;
+
+/// Creates a selective disclosure attestation carrying per-field hash commitments.
+///
+/// Instead of the credential itself, the attester commits to each field as
+/// `sha256(field_value || salt)` with a fresh salt per field, and hands the values and salts
+/// to the subject off-chain. The subject can then reveal single fields through `verify_field`
+/// without exposing the rest of the credential. The attestation `value` is the hex-encoded
+/// SHA-256 of the concatenated commitments.
+///
+/// # Authorization
+/// Requires authorization from the caller (attester).
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The address creating the attestation. This address will also be the subject of the attestation.
+/// * `schema_uid` - The unique identifier of the schema
+/// * `field_commitments` - One commitment per schema field, in field order
+/// * `expiration_time` - Optional expiration timestamp
+///
+/// # Returns
+/// * `Result<BytesN<32>, Error>` - The UID of the created attestation or error
+///
+/// # Errors
+/// * `Error::InvalidFieldCommitments` - If there are no commitments or more than `MAX_FIELD_COMMITMENTS`
+pub fn attest_with_commitments(
+    env: &Env,
+    attester: Address,
+    schema_uid: BytesN<32>,
+    field_commitments: Vec<BytesN<32>>,
+    expiration_time: Option<u64>,
+) -> Result<BytesN<32>, Error> {
+    if field_commitments.is_empty() || field_commitments.len() > MAX_FIELD_COMMITMENTS {
+        return Err(Error::InvalidFieldCommitments);
+    }
+
+    let mut preimage = Bytes::new(env);
+    for commitment in field_commitments.iter() {
+        preimage.append(&commitment.into());
+    }
+    let root: BytesN<32> = env.crypto().sha256(&preimage).into();
+
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let mut root_hex = [0u8; 64];
+    for (i, byte) in root.to_array().iter().enumerate() {
+        root_hex[2 * i] = HEX[(byte >> 4) as usize];
+        root_hex[2 * i + 1] = HEX[(byte & 0x0f) as usize];
+    }
+
+    let attestation_uid = create_attestation(
+        env,
+        attester,
+        schema_uid,
+        String::from_bytes(env, &root_hex),
+        expiration_time,
+        AttestationOptions::default(),
+    )?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::FieldCommitments(attestation_uid.clone()), &field_commitments);
+    Ok(attestation_uid)
+}
+
+/// Verifies a single disclosed field of a selective disclosure attestation.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attestation_uid` - The unique identifier of the attestation
+/// * `field_index` - The position of the field in the attestation's commitments
+/// * `value` - The disclosed field value
+/// * `salt` - The salt the field was committed with
+///
+/// # Returns
+/// * `Result<bool, Error>` - True if `sha256(value || salt)` matches the committed field and
+///   the attestation has not been revoked
+///
+/// # Errors
+/// * `Error::AttestationNotFound` - If the attestation does not exist
+/// * `Error::AttestationExpired` - If the attestation has expired
+/// * `Error::InvalidFieldCommitments` - If the attestation carries no commitments or `field_index` is out of range
+pub fn verify_field(
+    env: &Env,
+    attestation_uid: BytesN<32>,
+    field_index: u32,
+    value: Bytes,
+    salt: BytesN<32>,
+) -> Result<bool, Error> {
+    let attestation = get_attestation_record(env, attestation_uid.clone())?;
+    let commitment = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<BytesN<32>>>(&DataKey::FieldCommitments(attestation_uid))
+        .and_then(|commitments| commitments.get(field_index))
+        .ok_or(Error::InvalidFieldCommitments)?;
+
+    if attestation.revoked {
+        return Ok(false);
+    }
+
+    let mut preimage = value;
+    preimage.append(&salt.into());
+    let digest: BytesN<32> = env.crypto().sha256(&preimage).into();
+    Ok(digest == commitment)
+}
+
+/// Optional attributes recorded on an attestation by the specialised attest entrypoints.
+#[derive(Default)]
+pub(crate) struct AttestationOptions {
+    envelope: Option<(Symbol, Bytes)>,
+    on_behalf_of: Option<Address>,
+    external_id: Option<Bytes>,
+    subject: Option<Subject>,
+    locale: Option<Symbol>,
+    ref_uid: Option<BytesN<32>>,
+}
+
+pub(crate) fn create_attestation(
+    env: &Env,
+    attester: Address,
+    schema_uid: BytesN<32>,
+    value: String,
+    expiration_time: Option<u64>,
+    options: AttestationOptions,
+) -> Result<BytesN<32>, Error> {
+    ensure_not_paused(env)?;
     attester.require_auth();
+    ensure_attester_not_banned(env, &attester)?;
 
     // Verify schema exists and get resolver info
     let schema = utils::get_schema(env, &schema_uid).ok_or(Error::SchemaNotFound)?;
+    ensure_schema_not_revoked(env, &schema_uid)?;
+    ensure_value_conforms(env, &schema_uid, &value)?;
+    ensure_locale_allowed(env, &schema_uid, &options.locale)?;
+    ensure_reference_valid(env, &options.ref_uid)?;
+
+    // Attestations about other subjects wait for consent, which direct attestations cannot
+    let (subject, mux_id) = match options.subject {
+        Some(subject) => (subject.address, subject.mux_id),
+        None => (attester.clone(), None),
+    };
+    if subject != attester && is_schema_consent_required(env, &schema_uid) {
+        return Err(Error::NotAuthorized);
+    }
 
     // Get next nonce for this attester
     let nonce = utils::get_next_nonce(env, &attester);
@@ -127,12 +717,13 @@ pub fn attest(
             return Err(Error::InvalidDeadline);
         }
     }
-    let subject = attester.clone();
+    ensure_expiration_within_horizon(env, &schema_uid, expiration_time)?;
     let attestation_uid = generate_attestation_uid(env, &schema_uid, &subject, nonce);
 
-    let attestation = Attestation {
+    let mut attestation = Attestation {
         uid: attestation_uid.clone(),
         schema_uid: schema_uid.clone(),
+        schema_version: schema.version,
         subject,
         attester: attester.clone(),
         value: value.clone(),
@@ -141,14 +732,27 @@ pub fn attest(
         expiration_time,
         revoked: false,
         revocation_time: None,
+        encryption_scheme: options.envelope.as_ref().map(|(scheme, _)| scheme.clone()),
+        encrypted_key: options.envelope.map(|(_, key)| key),
+        on_behalf_of: options.on_behalf_of,
+        external_id: options.external_id,
+        locale: options.locale,
+        ref_uid: options.ref_uid,
     };
 
+    reject_duplicate_attestation(env, &attestation)?;
+    reserve_uniqueness_key(env, &attestation)?;
+
+    // Schemas with deferred hooks leave the resolver to `process_hook_queue`
+    let deferred = schema.resolver.is_some() && is_schema_hooks_deferred(env, &schema_uid);
+    let resolver = schema.resolver.filter(|_| !deferred);
+
     // ═══════════════════════════════════════════════════════════════════════════
     // ► RESOLVER INTEGRATION: Before Attest Hook
     // ═══════════════════════════════════════════════════════════════════════════
 
     // Call resolver onattest hook if schema has a resolver
-    if let Some(resolver_address) = &schema.resolver {
+    if let Some(resolver_address) = &resolver {
         // Create resolver attestation format
         let resolver_attestation = create_resolver_attestation(env, &attestation, &schema_uid, &value);
 
@@ -158,6 +762,9 @@ pub fn attest(
         if !allowed {
             return Err(Error::ResolverError); // Resolver rejected the attestation
         }
+
+        // Let the resolver set the expiration, e.g. to the end of a subscription
+        apply_resolver_expiration(env, resolver_address, &resolver_attestation, &mut attestation)?;
     }
 
     // ═══════════════════════════════════════════════════════════════════════════
@@ -167,18 +774,22 @@ pub fn attest(
     // Store the attestation by its UID
     let attest_uid_key = DataKey::AttestationUID(attestation_uid.clone());
     env.storage().persistent().set(&attest_uid_key, &attestation);
+    let new_subject = index_subject_attestation(env, &attestation);
+    record_schema_attestation(env, &schema_uid, new_subject, current_time);
+    if let Some(mux_id) = mux_id {
+        index_muxed_subject_attestation(env, &attestation, mux_id);
+    }
 
     // Increment nonce for next attestation (using checked arithmetic to prevent overflow)
-    let nonce_key = DataKey::AttesterNonce(attester.clone());
     let new_nonce = nonce.checked_add(1).ok_or(Error::IntegerOverflow)?;
-    env.storage().persistent().set(&nonce_key, &new_nonce);
+    utils::set_next_nonce(env, &attester, new_nonce)?;
 
     // ═══════════════════════════════════════════════════════════════════════════
     // ► RESOLVER INTEGRATION: After Attest Hook
     // ═══════════════════════════════════════════════════════════════════════════
 
     // Call resolver onresolve hook if schema has a resolver
-    if let Some(resolver_address) = &schema.resolver {
+    if let Some(resolver_address) = &resolver {
         // Create resolver attestation format
         let resolver_attestation = create_resolver_attestation(env, &attestation, &schema_uid, &value);
 
@@ -186,8 +797,12 @@ pub fn attest(
         // Note: Failures here don't revert the attestation
         call_resolver_onresolve(env, resolver_address, &resolver_attestation);
     }
+    if deferred {
+        enqueue_hooks(env, &attestation_uid);
+    }
 
-    // Emit event
+    // Record in the outbox for off-chain pollers and emit event
+    record_outbox_entry(env, OutboxAction::Attested, &attestation);
     events::publish_attestation_event(env, &attestation);
 
     Ok(attestation_uid)
@@ -215,19 +830,16 @@ pub fn attest(
 pub fn get_attestation_record(env: &Env, attestation_uid: BytesN<32>) -> Result<Attestation, Error> {
     // Get attestation
     let attest_key = DataKey::AttestationUID(attestation_uid);
-    let attestation = env
+    let mut attestation = env
         .storage()
         .persistent()
         .get::<DataKey, Attestation>(&attest_key)
         .ok_or(Error::AttestationNotFound)?;
+    apply_schema_revocation(env, &mut attestation);
 
-    // Check if attestation is expired
+    // Check if attestation is expired; it stays stored until purged, which emits its expiry
     if let Some(exp_time) = attestation.expiration_time {
         if env.ledger().timestamp() > exp_time {
-            //clear this attestation from the storage
-            env.storage()
-                .persistent()
-                .remove(&DataKey::AttestationUID(attestation.uid.clone()));
             return Err(Error::AttestationExpired);
         }
     }
@@ -235,11 +847,68 @@ pub fn get_attestation_record(env: &Env, attestation_uid: BytesN<32>) -> Result<
     Ok(attestation)
 }
 
+/// Retrieves an attestation with its expiry and revocation status at the current ledger.
+///
+/// Unlike `get_attestation_record`, expired attestations are returned as stored rather
+/// than removed, flagged with `is_expired`.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attestation_uid` - The unique identifier of the attestation
+///
+/// # Returns
+/// * `Result<AttestationStatus, Error>` - The attestation and its computed status
+///
+/// # Errors
+/// * `Error::AttestationNotFound` - If no attestation is stored under the UID
+pub fn get_attestation_status(env: &Env, attestation_uid: BytesN<32>) -> Result<AttestationStatus, Error> {
+    let mut attestation = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Attestation>(&DataKey::AttestationUID(attestation_uid))
+        .ok_or(Error::AttestationNotFound)?;
+    apply_schema_revocation(env, &mut attestation);
+
+    let is_expired = attestation
+        .expiration_time
+        .is_some_and(|exp_time| env.ledger().timestamp() > exp_time);
+    Ok(AttestationStatus {
+        is_revoked: attestation.revoked,
+        is_expired,
+        is_disputed: is_disputed(env, &attestation.uid),
+        attestation,
+    })
+}
+
+/// Retrieves an attestation, scoped to the namespace of its schema.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `namespace` - The namespace the attestation's schema must belong to
+/// * `attestation_uid` - The unique identifier of the attestation
+///
+/// # Returns
+/// * `Result<Attestation, Error>` - The attestation record, or `AttestationNotFound` if it
+///   does not exist or its schema belongs to another namespace
+pub fn get_namespaced_attestation(
+    env: &Env,
+    namespace: &Symbol,
+    attestation_uid: BytesN<32>,
+) -> Result<Attestation, Error> {
+    let attestation = get_attestation_record(env, attestation_uid)?;
+    let schema = utils::get_schema(env, &attestation.schema_uid).ok_or(Error::SchemaNotFound)?;
+    if schema.namespace.as_ref() != Some(namespace) {
+        return Err(Error::AttestationNotFound);
+    }
+    Ok(attestation)
+}
+
 /// Revokes an attestation using the nonce-based system.
 ///
 /// # Arguments
 /// * `env` - The Soroban environment
-/// * `revoker` - The address revoking the attestation (must be the original attester)
+/// * `revoker` - The address revoking the attestation (the original attester, or the
+///   subject if the schema is subject-revocable)
 /// * `schema_uid` - The unique identifier of the schema
 /// * `subject` - The address that is the subject of the attestation
 /// * `nonce` - The nonce of the attestation to revoke
@@ -247,18 +916,22 @@ pub fn get_attestation_record(env: &Env, attestation_uid: BytesN<32>) -> Result<
 /// # Returns
 /// * `Result<(), Error>` - Success or error
 pub fn revoke_attestation(env: &Env, revoker: Address, attestation_uid: BytesN<32>) -> Result<(), Error> {
+    ensure_not_paused(env)?;
     revoker.require_auth();
 
     // Get the attestation
     let attest_key = DataKey::AttestationUID(attestation_uid);
-    let mut attestation = env
+    let attestation = env
         .storage()
         .persistent()
         .get::<DataKey, Attestation>(&attest_key)
         .ok_or(Error::AttestationNotFound)?;
 
-    // Verify the revoker is the original attester
-    if attestation.attester != revoker {
+    // Verify the revoker is the original attester, or the subject of a subject-revocable schema
+    let by_subject = attestation.attester != revoker
+        && attestation.subject == revoker
+        && is_schema_subject_revocable(env, &attestation.schema_uid);
+    if attestation.attester != revoker && !by_subject {
         return Err(Error::NotAuthorized);
     }
 
@@ -267,12 +940,109 @@ pub fn revoke_attestation(env: &Env, revoker: Address, attestation_uid: BytesN<3
         return Err(Error::AttestationNotFound);
     }
 
-    // Verify schema is revocable
+    // Verify schema is revocable; subjects may withdraw attestations about themselves regardless
     let schema = utils::get_schema(env, &attestation.schema_uid).ok_or(Error::SchemaNotFound)?;
-    if !schema.revocable {
+    if !schema.revocable && !by_subject {
         return Err(Error::AttestationNotRevocable);
     }
+    ensure_min_active_duration(env, &attestation)?;
 
+    let attestation = apply_revocation(env, &schema, &attest_key, attestation)?;
+
+    // Emit revocation event
+    events::publish_revocation_event(env, &attestation);
+
+    Ok(())
+}
+
+/// Revokes a self-attestation on behalf of its subject.
+///
+/// When the attester and the subject of an attestation are the same address, the
+/// subject may revoke it regardless of the schema's revocation policy. The schema's
+/// resolver, if any, is still consulted. A dedicated event distinguishes
+/// self-revocations from regular ones.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `subject` - The subject (and attester) of the attestation
+/// * `attestation_uid` - The unique identifier of the attestation to revoke
+///
+/// # Returns
+/// * `Result<(), Error>` - Success or error
+pub fn revoke_self_attestation(env: &Env, subject: Address, attestation_uid: BytesN<32>) -> Result<(), Error> {
+    ensure_not_paused(env)?;
+    subject.require_auth();
+
+    // Get the attestation
+    let attest_key = DataKey::AttestationUID(attestation_uid);
+    let attestation = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Attestation>(&attest_key)
+        .ok_or(Error::AttestationNotFound)?;
+
+    // Only self-attestations, and only by their subject
+    if attestation.subject != subject || attestation.attester != subject {
+        return Err(Error::NotAuthorized);
+    }
+
+    // Verify the attestation isn't already revoked
+    if attestation.revoked {
+        return Err(Error::AttestationNotFound);
+    }
+
+    let schema = utils::get_schema(env, &attestation.schema_uid).ok_or(Error::SchemaNotFound)?;
+    ensure_min_active_duration(env, &attestation)?;
+    let attestation = apply_revocation(env, &schema, &attest_key, attestation)?;
+
+    // Emit self-revocation event
+    events::publish_self_revocation_event(env, &attestation);
+
+    Ok(())
+}
+
+/// Rejects a reference to an attestation that does not exist or is revoked, individually
+/// or with its whole schema.
+///
+/// # Errors
+/// * `Error::InvalidReference` - If the referenced attestation is missing or revoked
+pub(crate) fn ensure_reference_valid(env: &Env, ref_uid: &Option<BytesN<32>>) -> Result<(), Error> {
+    let Some(ref_uid) = ref_uid else {
+        return Ok(());
+    };
+    let mut referenced = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Attestation>(&DataKey::AttestationUID(ref_uid.clone()))
+        .ok_or(Error::InvalidReference)?;
+    apply_schema_revocation(env, &mut referenced);
+    if referenced.revoked {
+        return Err(Error::InvalidReference);
+    }
+    Ok(())
+}
+
+/// Rejects revocation of an attestation younger than its schema's minimum active duration.
+///
+/// # Errors
+/// * `Error::RevocationTooEarly` - If the attestation was issued too recently
+pub(crate) fn ensure_min_active_duration(env: &Env, attestation: &Attestation) -> Result<(), Error> {
+    let min_active = get_schema_min_active_duration(env, &attestation.schema_uid);
+    if env.ledger().timestamp() < attestation.timestamp.saturating_add(min_active) {
+        return Err(Error::RevocationTooEarly);
+    }
+    Ok(())
+}
+
+/// Marks a validated attestation as revoked, running resolver hooks and bookkeeping.
+///
+/// Returns the updated attestation so the caller can emit the appropriate event.
+pub(crate) fn apply_revocation(
+    env: &Env,
+    schema: &Schema,
+    attest_key: &DataKey,
+    mut attestation: Attestation,
+) -> Result<Attestation, Error> {
     // ═══════════════════════════════════════════════════════════════════════════
     // ► RESOLVER INTEGRATION: Before Revoke Hook
     // ═══════════════════════════════════════════════════════════════════════════
@@ -300,7 +1070,7 @@ pub fn revoke_attestation(env: &Env, revoker: Address, attestation_uid: BytesN<3
     attestation.revocation_time = Some(env.ledger().timestamp());
 
     // Store updated attestation
-    env.storage().persistent().set(&attest_key, &attestation);
+    env.storage().persistent().set(attest_key, &attestation);
 
     // ═══════════════════════════════════════════════════════════════════════════
     // ► RESOLVER INTEGRATION: After Revoke Hook
@@ -317,8 +1087,313 @@ pub fn revoke_attestation(env: &Env, revoker: Address, attestation_uid: BytesN<3
         call_resolver_onresolve(env, resolver_address, &resolver_attestation);
     }
 
-    // Emit revocation event
-    events::publish_revocation_event(env, &attestation);
+    record_schema_revocation(env, &attestation.schema_uid);
+
+    // Record in the outbox for off-chain pollers
+    record_outbox_entry(env, OutboxAction::Revoked, &attestation);
 
+    Ok(attestation)
+}
+
+/// Records an attestation as the most recent one for its schema/subject pair, and appends it
+/// to its subject, schema and attester listings.
+///
+/// Returns true if this is the first attestation the subject has received under the schema.
+pub(crate) fn index_subject_attestation(env: &Env, attestation: &Attestation) -> bool {
+    index_attestation_listings(env, attestation);
+    let key = DataKey::SubjectAttestation(attestation.schema_uid.clone(), attestation.subject.clone());
+    let new_subject = !env.storage().persistent().has(&key);
+    env.storage().persistent().set(&key, &attestation.uid);
+    track_index_key(env, &attestation.uid, key);
+    new_subject
+}
+
+/// Checks whether a subject holds a valid attestation for a schema.
+///
+/// Only the most recent attestation for the schema/subject pair is considered. It is
+/// valid if it has not been revoked, has not expired and is not frozen pending compromise review.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `subject` - The address that is the subject of the attestation
+/// * `schema_uid` - The unique identifier of the schema
+///
+/// # Returns
+/// * `bool` - True if the subject's most recent attestation for the schema is valid
+pub fn has_valid_attestation(env: &Env, subject: &Address, schema_uid: &BytesN<32>) -> bool {
+    let key = DataKey::SubjectAttestation(schema_uid.clone(), subject.clone());
+    is_indexed_attestation_valid(env, &key)
+}
+
+/// Gets the most recent attestation issued to a subject under a schema.
+///
+/// Unlike `get_attestation`, expired attestations are returned as stored rather than
+/// removed, so callers should check `is_attestation_valid` before relying on one.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `subject` - The address that is the subject of the attestation
+/// * `schema_uid` - The unique identifier of the schema
+///
+/// # Returns
+/// * `Option<Attestation>` - The most recent attestation, if the subject has one still stored
+pub fn latest_attestation(env: &Env, subject: &Address, schema_uid: &BytesN<32>) -> Option<Attestation> {
+    let key = DataKey::SubjectAttestation(schema_uid.clone(), subject.clone());
+    let attestation_uid = env.storage().persistent().get::<DataKey, BytesN<32>>(&key)?;
+    let mut attestation = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Attestation>(&DataKey::AttestationUID(attestation_uid))?;
+    apply_schema_revocation(env, &mut attestation);
+    Some(attestation)
+}
+
+/// Checks whether a subject, in either plain or muxed form, holds a valid attestation for a schema.
+///
+/// Without a mux ID this is `has_valid_attestation` for the account, which also covers
+/// attestations issued to its muxed sub-accounts. With a mux ID, only the most recent
+/// attestation issued to that sub-account is considered.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `subject` - The subject account and optional mux ID
+/// * `schema_uid` - The unique identifier of the schema
+///
+/// # Returns
+/// * `bool` - True if the subject's most recent attestation for the schema is valid
+pub fn has_valid_subject_attestation(env: &Env, subject: &Subject, schema_uid: &BytesN<32>) -> bool {
+    match subject.mux_id {
+        Some(mux_id) => {
+            let key = DataKey::MuxedSubjectAttestation(schema_uid.clone(), subject.address.clone(), mux_id);
+            is_indexed_attestation_valid(env, &key)
+        },
+        None => has_valid_attestation(env, &subject.address, schema_uid),
+    }
+}
+
+/// Records an attestation as the most recent one for its schema and muxed subject.
+fn index_muxed_subject_attestation(env: &Env, attestation: &Attestation, mux_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SubjectMuxId(attestation.uid.clone()), &mux_id);
+    let key = DataKey::MuxedSubjectAttestation(attestation.schema_uid.clone(), attestation.subject.clone(), mux_id);
+    env.storage().persistent().set(&key, &attestation.uid);
+    track_index_key(env, &attestation.uid, key);
+}
+
+/// Checks whether the attestation stored under a subject index key is valid.
+fn is_indexed_attestation_valid(env: &Env, key: &DataKey) -> bool {
+    let Some(attestation_uid) = env.storage().persistent().get::<DataKey, BytesN<32>>(key) else {
+        return false;
+    };
+    is_attestation_valid(env, attestation_uid)
+}
+
+/// Checks whether the attestation stored under a UID exists and is valid.
+///
+/// Valid attestations are neither revoked, frozen, expired nor under a revoked schema.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attestation_uid` - The unique identifier of the attestation
+///
+/// # Returns
+/// * `bool` - True if the attestation exists and is valid
+pub fn is_attestation_valid(env: &Env, attestation_uid: BytesN<32>) -> bool {
+    let Some(attestation) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Attestation>(&DataKey::AttestationUID(attestation_uid))
+    else {
+        return false;
+    };
+
+    if attestation.revoked || is_frozen(env, &attestation) {
+        return false;
+    }
+    if get_schema_revocation(env, &attestation.schema_uid).is_some() {
+        return false;
+    }
+    match attestation.expiration_time {
+        Some(exp_time) => env.ledger().timestamp() <= exp_time,
+        None => true,
+    }
+}
+
+/// Checks whether an attestation is locked to its subject, following ERC-5192.
+///
+/// Attestations under soulbound schemas are locked. Revoked and expired attestations
+/// keep their lock status, as they are never transferred either.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attestation_uid` - The unique identifier of the attestation
+///
+/// # Errors
+/// * `Error::AttestationNotFound` - If the attestation does not exist
+pub fn is_locked(env: &Env, attestation_uid: BytesN<32>) -> Result<bool, Error> {
+    let attestation = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Attestation>(&DataKey::AttestationUID(attestation_uid))
+        .ok_or(Error::AttestationNotFound)?;
+    Ok(is_schema_soulbound(env, &attestation.schema_uid))
+}
+
+/// Checks whether an attestation is valid and was issued within the last `max_age_seconds`.
+///
+/// Lets consuming contracts require recently issued credentials, such as a proof of
+/// liveness from the last 30 days, without duplicating timestamp math.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attestation_uid` - The unique identifier of the attestation
+/// * `max_age_seconds` - The maximum time since issuance
+///
+/// # Returns
+/// * `bool` - True if the attestation exists, is valid and is no older than `max_age_seconds`
+pub fn is_attestation_fresh(env: &Env, attestation_uid: BytesN<32>, max_age_seconds: u64) -> bool {
+    let Some(attestation) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Attestation>(&DataKey::AttestationUID(attestation_uid.clone()))
+    else {
+        return false;
+    };
+    let age = env.ledger().timestamp().saturating_sub(attestation.timestamp);
+    age <= max_age_seconds && is_attestation_valid(env, attestation_uid)
+}
+
+/// Rejects an attestation identical to one issued within the schema's deduplication window.
+///
+/// Two attestations are identical when they share schema, attester, subject and value.
+/// Fingerprints of recent attestations are kept in temporary storage, so they expire on
+/// their own once the window has passed.
+///
+/// # Errors
+/// * `Error::AttestationExists` - If an identical attestation was issued within the window
+pub(crate) fn reject_duplicate_attestation(env: &Env, attestation: &Attestation) -> Result<(), Error> {
+    ensure_not_duplicate(env, attestation)?;
+    record_attestation_fingerprint(env, attestation);
     Ok(())
 }
+
+/// Checks that no identical attestation was issued within the schema's deduplication
+/// window, without recording this one.
+///
+/// # Errors
+/// * `Error::AttestationExists` - If an identical attestation was issued within the window
+pub(crate) fn ensure_not_duplicate(env: &Env, attestation: &Attestation) -> Result<(), Error> {
+    if get_schema_dedup_window(env, &attestation.schema_uid) == 0 {
+        return Ok(());
+    }
+
+    let key = attestation_fingerprint_key(env, attestation);
+    if let Some(expires_at) = env.storage().temporary().get::<DataKey, u32>(&key) {
+        if env.ledger().sequence() <= expires_at {
+            return Err(Error::AttestationExists);
+        }
+    }
+    Ok(())
+}
+
+/// Records an attestation's fingerprint for the schema's deduplication window, if one is set.
+pub(crate) fn record_attestation_fingerprint(env: &Env, attestation: &Attestation) {
+    let window = get_schema_dedup_window(env, &attestation.schema_uid);
+    if window == 0 {
+        return;
+    }
+
+    // Windows are bounded when set, but the network's maximum TTL may since have shrunk
+    let key = attestation_fingerprint_key(env, attestation);
+    let ttl = core::cmp::min(window, env.storage().max_ttl());
+    env.storage()
+        .temporary()
+        .set(&key, &env.ledger().sequence().saturating_add(window));
+    env.storage().temporary().extend_ttl(&key, ttl, ttl);
+}
+
+fn attestation_fingerprint_key(env: &Env, attestation: &Attestation) -> DataKey {
+    let mut fingerprint_input = Bytes::new(env);
+    fingerprint_input.append(&attestation.schema_uid.clone().to_xdr(env));
+    fingerprint_input.append(&attestation.attester.clone().to_xdr(env));
+    fingerprint_input.append(&attestation.subject.clone().to_xdr(env));
+    fingerprint_input.append(&attestation.value.clone().to_xdr(env));
+    DataKey::RecentAttestation(env.crypto().sha256(&fingerprint_input).into())
+}
+
+/// Dry-runs a schema's resolver hooks against a prospective attestation.
+///
+/// The attestation is converted exactly as `attest` would convert it and passed to the
+/// resolver's `onattest` hook. Nothing is stored by the protocol, and resolver failures
+/// are reported in the result instead of aborting the call.
+///
+/// # Note
+/// This is meant to be invoked through RPC transaction simulation, which never commits
+/// state. Resolvers with side effects in `onattest` (such as fee collection) would apply
+/// them if this were submitted in a real transaction.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `schema_uid` - The unique identifier of the schema whose hooks to run
+/// * `attestation` - The prospective attestation; its `schema_uid` is overridden
+///
+/// # Returns
+/// * `Result<HookSimulation, Error>` - The hook outcome, or `SchemaNotFound`
+pub fn simulate_attestation_hooks(
+    env: &Env,
+    schema_uid: BytesN<32>,
+    mut attestation: Attestation,
+) -> Result<HookSimulation, Error> {
+    let schema = utils::get_schema(env, &schema_uid).ok_or(Error::SchemaNotFound)?;
+
+    let Some(resolver_address) = schema.resolver else {
+        return Ok(HookSimulation {
+            resolver: None,
+            allowed: true,
+            error_code: None,
+        });
+    };
+
+    attestation.schema_uid = schema_uid.clone();
+    let resolver_attestation = create_resolver_attestation(env, &attestation, &schema_uid, &attestation.value);
+    let resolver_client = ResolverClient::new(env, &resolver_address);
+
+    let (allowed, error_code) = match resolver_client.try_onattest(&resolver_attestation) {
+        Ok(Ok(allowed)) => (allowed, None),
+        Ok(Err(_)) => (false, None),
+        Err(Ok(error)) => (false, Some(error.get_code())),
+        Err(Err(InvokeError::Contract(code))) => (false, Some(code)),
+        Err(Err(InvokeError::Abort)) => (false, None),
+    };
+
+    Ok(HookSimulation {
+        resolver: Some(resolver_address),
+        allowed,
+        error_code,
+    })
+}
+
+/// Quotes the tokens and amounts a schema's resolver would charge an attester.
+///
+/// Lets wallets authorize exact amounts before submitting an attestation instead of
+/// over-approving. Resolvers that do not implement `quote_fees` quote no charges.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `schema_uid` - The unique identifier of the schema to attest to
+/// * `attester` - The address that would create and pay for the attestation
+///
+/// # Returns
+/// * `Result<Vec<(Address, i128)>, Error>` - (token, amount) pairs, empty if nothing is charged
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If the schema does not exist
+pub fn quote_attest_cost(env: &Env, schema_uid: BytesN<32>, attester: Address) -> Result<Vec<(Address, i128)>, Error> {
+    let schema = utils::get_schema(env, &schema_uid).ok_or(Error::SchemaNotFound)?;
+
+    match schema.resolver {
+        Some(resolver_address) => Ok(call_resolver_quote_fees(env, &resolver_address, &schema_uid, &attester)),
+        None => Ok(Vec::new(env)),
+    }
+}
@@ -0,0 +1,189 @@
+use crate::errors::Error;
+use crate::events;
+use crate::instructions::attestation::{
+    apply_resolver_expiration, call_resolver_onattest, call_resolver_onresolve, create_resolver_attestation,
+    index_subject_attestation,
+};
+use crate::instructions::moderation::{ensure_attester_not_banned, ensure_not_paused};
+use crate::instructions::record_outbox_entry;
+use crate::instructions::schema::{ensure_schema_not_revoked, record_schema_attestation};
+use crate::instructions::uniqueness::reserve_uniqueness_key;
+use crate::instructions::value::ensure_value_conforms;
+use crate::state::{Attestation, ClaimableAttestation, DataKey, OutboxAction};
+use crate::utils::{self, generate_attestation_uid};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String};
+
+/// Issues an attestation whose recipient is bound later, by whoever holds a secret.
+///
+/// The attester shares the preimage of `claim_hash` off-chain (e.g. in a claim link).
+/// The attestation UID is fixed now, from the attester and their next nonce, and the
+/// attestation is only stored and indexed once claimed.
+///
+/// # Authorization
+/// Requires authorization from the attester.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The address issuing the attestation
+/// * `schema_uid` - The unique identifier of the schema
+/// * `value` - The attestation data
+/// * `claim_hash` - SHA-256 of the secret the recipient must present
+/// * `expiry` - Timestamp after which the attestation can no longer be claimed
+///
+/// # Returns
+/// * `Result<BytesN<32>, Error>` - The UID the attestation will be stored under once claimed
+///
+/// # Errors
+/// * `Error::AttesterBanned` - If the attester has been banned
+/// * `Error::SchemaNotFound` - If the schema does not exist
+/// * `Error::InvalidDeadline` - If `expiry` is not in the future
+pub fn attest_claimable(
+    env: &Env,
+    attester: Address,
+    schema_uid: BytesN<32>,
+    value: String,
+    claim_hash: BytesN<32>,
+    expiry: u64,
+) -> Result<BytesN<32>, Error> {
+    ensure_not_paused(env)?;
+    attester.require_auth();
+    ensure_attester_not_banned(env, &attester)?;
+
+    if utils::get_schema(env, &schema_uid).is_none() {
+        return Err(Error::SchemaNotFound);
+    }
+    ensure_value_conforms(env, &schema_uid, &value)?;
+
+    let current_time = env.ledger().timestamp();
+    if expiry <= current_time {
+        return Err(Error::InvalidDeadline);
+    }
+
+    // Consume the attester's nonce now so the UID cannot collide with later attestations
+    let nonce = utils::get_next_nonce(env, &attester);
+    let attestation_uid = generate_attestation_uid(env, &schema_uid, &attester, nonce);
+    let new_nonce = nonce.checked_add(1).ok_or(Error::IntegerOverflow)?;
+    utils::set_next_nonce(env, &attester, new_nonce)?;
+
+    let claimable = ClaimableAttestation {
+        attester: attester.clone(),
+        schema_uid,
+        value,
+        claim_hash,
+        expiry,
+        nonce,
+        created_at: current_time,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Claimable(attestation_uid.clone()), &claimable);
+
+    events::publish_claimable_attestation(env, &attestation_uid, &attester, expiry);
+
+    Ok(attestation_uid)
+}
+
+/// Claims a claimable attestation, binding it to the subject.
+///
+/// The attestation goes through the schema resolver like any direct attestation,
+/// with the claiming address as the recipient, and is then stored under its UID.
+///
+/// # Authorization
+/// Requires authorization from the subject.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attestation_uid` - The UID returned by `attest_claimable`
+/// * `preimage` - The secret whose SHA-256 is the attestation's `claim_hash`
+/// * `subject` - The address to bind the attestation to
+///
+/// # Errors
+/// * `Error::AttestationNotFound` - If there is no unclaimed attestation with this UID
+/// * `Error::AttestationExpired` - If the claim window has passed
+/// * `Error::InvalidClaimPreimage` - If the preimage does not hash to `claim_hash`
+/// * `Error::SchemaNotFound` - If the schema has since been removed
+/// * `Error::UniquenessKeyTaken` - If another active attestation holds the value's uniqueness key
+/// * `Error::ResolverError` - If the schema resolver rejects the attestation
+pub fn claim(env: &Env, attestation_uid: BytesN<32>, preimage: Bytes, subject: Address) -> Result<(), Error> {
+    ensure_not_paused(env)?;
+    subject.require_auth();
+
+    let claim_key = DataKey::Claimable(attestation_uid.clone());
+    let claimable: ClaimableAttestation = env
+        .storage()
+        .persistent()
+        .get(&claim_key)
+        .ok_or(Error::AttestationNotFound)?;
+
+    let current_time = env.ledger().timestamp();
+    if current_time > claimable.expiry {
+        return Err(Error::AttestationExpired);
+    }
+
+    let digest: BytesN<32> = env.crypto().sha256(&preimage).into();
+    if digest != claimable.claim_hash {
+        return Err(Error::InvalidClaimPreimage);
+    }
+
+    let schema = utils::get_schema(env, &claimable.schema_uid).ok_or(Error::SchemaNotFound)?;
+    ensure_schema_not_revoked(env, &claimable.schema_uid)?;
+
+    let mut attestation = Attestation {
+        uid: attestation_uid.clone(),
+        schema_uid: claimable.schema_uid.clone(),
+        schema_version: schema.version,
+        subject,
+        attester: claimable.attester.clone(),
+        value: claimable.value.clone(),
+        nonce: claimable.nonce,
+        timestamp: current_time,
+        expiration_time: None,
+        revoked: false,
+        revocation_time: None,
+        encryption_scheme: None,
+        encrypted_key: None,
+        on_behalf_of: None,
+        external_id: None,
+        locale: None,
+        ref_uid: None,
+    };
+
+    reserve_uniqueness_key(env, &attestation)?;
+
+    let resolver_attestation = create_resolver_attestation(env, &attestation, &claimable.schema_uid, &claimable.value);
+
+    if let Some(resolver_address) = &schema.resolver {
+        let allowed = call_resolver_onattest(env, resolver_address, &resolver_attestation)?;
+        if !allowed {
+            return Err(Error::ResolverError);
+        }
+        apply_resolver_expiration(env, resolver_address, &resolver_attestation, &mut attestation)?;
+    }
+
+    env.storage().persistent().remove(&claim_key);
+    env.storage()
+        .persistent()
+        .set(&DataKey::AttestationUID(attestation_uid), &attestation);
+    let new_subject = index_subject_attestation(env, &attestation);
+    record_schema_attestation(env, &claimable.schema_uid, new_subject, current_time);
+
+    if let Some(resolver_address) = &schema.resolver {
+        call_resolver_onresolve(env, resolver_address, &resolver_attestation);
+    }
+
+    record_outbox_entry(env, OutboxAction::Attested, &attestation);
+    events::publish_attestation_event(env, &attestation);
+
+    Ok(())
+}
+
+/// Retrieves an unclaimed attestation by the UID it will be stored under.
+///
+/// # Errors
+/// * `Error::AttestationNotFound` - If the attestation does not exist or was already claimed
+pub fn get_claimable_attestation(env: &Env, attestation_uid: BytesN<32>) -> Result<ClaimableAttestation, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Claimable(attestation_uid))
+        .ok_or(Error::AttestationNotFound)
+}
@@ -0,0 +1,197 @@
+use crate::errors::Error;
+use crate::events;
+use crate::instructions::appeal::is_disputed;
+use crate::instructions::listing::remove_index_keys;
+use crate::state::{Attestation, CleanupBounty, DataKey, ExtendedKey};
+use crate::utils;
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, Symbol, Vec};
+
+/// How long a revoked attestation is retained before it may be purged, in seconds (30 days).
+///
+/// Gives indexers and relying parties time to observe the revocation before the record disappears.
+pub const REVOKED_RETENTION_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+/// Adds funds to the cleanup bounty pool and sets the per-entry bounty.
+///
+/// The pool's token is fixed by the first funding; later fundings must use the same token.
+///
+/// # Authorization
+/// Requires authorization from the contract admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract admin, who pays `amount` into the pool
+/// * `token` - The token the bounty is paid in
+/// * `amount` - The amount to add to the pool
+/// * `bounty_per_entry` - The amount paid per entry removed
+///
+/// # Errors
+/// * `Error::InvalidAmount` - If `amount` is not positive or `bounty_per_entry` is negative
+/// * `Error::InvalidReference` - If `token` differs from the pool's token
+pub fn fund_cleanup_bounty(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    amount: i128,
+    bounty_per_entry: i128,
+) -> Result<(), Error> {
+    utils::require_admin(env, &admin)?;
+    if amount <= 0 || bounty_per_entry < 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let mut pool = match get_cleanup_bounty(env) {
+        Some(pool) if pool.token != token => return Err(Error::InvalidReference),
+        Some(pool) => pool,
+        None => CleanupBounty {
+            token: token.clone(),
+            bounty_per_entry,
+            balance: 0,
+        },
+    };
+
+    token::Client::new(env, &token).transfer(&admin, &env.current_contract_address(), &amount);
+
+    pool.balance = pool.balance.checked_add(amount).ok_or(Error::IntegerOverflow)?;
+    pool.bounty_per_entry = bounty_per_entry;
+    env.storage().instance().set(&DataKey::CleanupBounty, &pool);
+
+    Ok(())
+}
+
+/// Returns the cleanup bounty pool, if it has ever been funded.
+pub fn get_cleanup_bounty(env: &Env) -> Option<CleanupBounty> {
+    env.storage().instance().get(&DataKey::CleanupBounty)
+}
+
+/// Returns why an attestation may be removed from storage, or `None` if it may not.
+///
+/// Expired attestations are purgeable immediately (`EXPIRED`); revoked ones once
+/// `REVOKED_RETENTION_SECONDS` have passed since revocation (`REVOKED`).
+fn purge_reason(attestation: &Attestation, now: u64) -> Option<Symbol> {
+    if let Some(exp_time) = attestation.expiration_time {
+        if now > exp_time {
+            return Some(symbol_short!("EXPIRED"));
+        }
+    }
+    match attestation.revocation_time {
+        Some(revoked_at) if attestation.revoked && now > revoked_at.saturating_add(REVOKED_RETENTION_SECONDS) => {
+            Some(symbol_short!("REVOKED"))
+        },
+        _ => None,
+    }
+}
+
+/// Removes expired and long-revoked attestations, paying the caller a bounty per entry.
+///
+/// Permissionless: anyone may submit UIDs. Entries that are missing, not yet purgeable or
+/// revoked with an appeal still awaiting a decision are skipped. Each purged attestation is
+/// removed together with its listing entries and lookup indexes, and expired ones also emit
+/// an `EXPIRED` event. The payout is capped by the pool balance.
+///
+/// # Authorization
+/// Requires authorization from the caller, who receives the bounty.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The address submitting the purge and receiving the bounty
+/// * `attestation_uids` - The UIDs of the attestations to purge
+///
+/// # Returns
+/// * `Result<u32, Error>` - The number of entries actually removed
+///
+/// # Errors
+/// * `Error::InvalidAmount` - If the bounty owed for the removed entries overflows
+pub fn purge_attestations(env: &Env, caller: Address, attestation_uids: Vec<BytesN<32>>) -> Result<u32, Error> {
+    caller.require_auth();
+
+    let now = env.ledger().timestamp();
+    let mut removed: u32 = 0;
+    for uid in attestation_uids.iter() {
+        let key = DataKey::AttestationUID(uid.clone());
+        let Some(attestation) = env.storage().persistent().get::<DataKey, Attestation>(&key) else {
+            continue;
+        };
+        // A disputed revocation is the evidence its appeal is resolved against
+        if is_disputed(env, &uid) {
+            continue;
+        }
+        if let Some(reason) = purge_reason(&attestation, now) {
+            env.storage().persistent().remove(&key);
+            remove_index_keys(env, &uid);
+            if let Some(external_id) = attestation.external_id.clone() {
+                let index_key = DataKey::ExternalId(attestation.attester.clone(), external_id);
+                env.storage().persistent().remove(&index_key);
+            }
+            env.storage()
+                .persistent()
+                .remove(&DataKey::FieldCommitments(uid.clone()));
+            env.storage().persistent().remove(&DataKey::SubjectMuxId(uid.clone()));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::UnfrozenAttestation(uid.clone()));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Extended(ExtendedKey::AttestationAppeal(uid)));
+            if reason == symbol_short!("EXPIRED") {
+                events::publish_attestation_expired(env, &attestation);
+            }
+            events::publish_attestation_purged(env, &attestation, reason);
+            removed += 1;
+        }
+    }
+
+    let mut payout = 0;
+    if let Some(mut pool) = get_cleanup_bounty(env) {
+        let owed = pool
+            .bounty_per_entry
+            .checked_mul(removed as i128)
+            .ok_or(Error::InvalidAmount)?;
+        payout = core::cmp::min(owed, pool.balance);
+        if payout > 0 {
+            pool.balance -= payout;
+            env.storage().instance().set(&DataKey::CleanupBounty, &pool);
+            token::Client::new(env, &pool.token).transfer(&env.current_contract_address(), &caller, &payout);
+        }
+    }
+
+    events::publish_purge(env, &caller, removed, payout);
+
+    Ok(removed)
+}
+
+/// Extends the time-to-live of an attestation and its subject index in persistent storage.
+///
+/// Permissionless: anyone may pay the rent to keep an attestation from being archived.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attestation_uid` - The UID of the attestation to keep live
+/// * `extend_to` - The number of ledgers the entries should live for, capped at the network maximum
+///
+/// # Returns
+/// * `Result<u32, Error>` - The ledger sequence the attestation now lives until
+///
+/// # Errors
+/// * `Error::AttestationNotFound` - If the attestation does not exist
+pub fn extend_attestation_ttl(env: &Env, attestation_uid: BytesN<32>, extend_to: u32) -> Result<u32, Error> {
+    let key = DataKey::AttestationUID(attestation_uid.clone());
+    let attestation = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Attestation>(&key)
+        .ok_or(Error::AttestationNotFound)?;
+
+    let extend_to = core::cmp::min(extend_to, env.storage().max_ttl());
+    env.storage().persistent().extend_ttl(&key, extend_to, extend_to);
+
+    let index_key = DataKey::SubjectAttestation(attestation.schema_uid.clone(), attestation.subject.clone());
+    if env.storage().persistent().get::<DataKey, BytesN<32>>(&index_key) == Some(attestation_uid) {
+        env.storage().persistent().extend_ttl(&index_key, extend_to, extend_to);
+    }
+
+    let live_until_ledger = env.ledger().sequence().saturating_add(extend_to);
+    events::publish_attestation_ttl_extended(env, &attestation, live_until_ledger);
+
+    Ok(live_until_ledger)
+}
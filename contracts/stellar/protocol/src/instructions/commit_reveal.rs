@@ -0,0 +1,106 @@
+use crate::errors::Error;
+use crate::events;
+use crate::instructions::attestation::{create_attestation, AttestationOptions};
+use crate::instructions::moderation::ensure_attester_not_banned;
+use crate::state::{AttestationCommitment, AttestationReveal, DataKey};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+/// Number of ledgers after its commitment within which an attestation must be revealed
+/// (about one day at five-second ledgers).
+pub const REVEAL_WINDOW_LEDGERS: u32 = 17_280;
+
+/// Computes the commitment for an attestation: `sha256(attester XDR || reveal XDR)`.
+pub fn compute_attestation_commitment(env: &Env, attester: &Address, reveal: &AttestationReveal) -> BytesN<32> {
+    let mut preimage = Bytes::new(env);
+    preimage.append(&attester.clone().to_xdr(env));
+    preimage.append(&reveal.clone().to_xdr(env));
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Records a commitment to an attestation without disclosing its content.
+///
+/// For attestations whose content is market-sensitive (e.g. audit results), publishing
+/// the value in a pending transaction lets others act on it before it lands. Committing
+/// first fixes the content on-chain as an opaque hash; the attester then reveals it with
+/// [`reveal_attestation`] in a later ledger.
+///
+/// Recommitting the same hash restarts its reveal window.
+///
+/// # Authorization
+/// Requires authorization from the attester.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The address that will reveal and issue the attestation
+/// * `commitment` - The value of [`compute_attestation_commitment`] for the attestation
+///
+/// # Errors
+/// * `Error::AttesterBanned` - If the attester has been banned
+pub fn commit_attestation(env: &Env, attester: Address, commitment: BytesN<32>) -> Result<(), Error> {
+    attester.require_auth();
+    ensure_attester_not_banned(env, &attester)?;
+
+    let record = AttestationCommitment {
+        attester: attester.clone(),
+        committed_ledger: env.ledger().sequence(),
+    };
+    let key = DataKey::AttestationCommitment(commitment.clone());
+    env.storage().temporary().set(&key, &record);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, REVEAL_WINDOW_LEDGERS, REVEAL_WINDOW_LEDGERS);
+
+    events::publish_attestation_committed(env, &commitment, &attester);
+    Ok(())
+}
+
+/// Reveals a committed attestation and issues it, exactly as `attest` would.
+///
+/// The reveal must land in a later ledger than the commitment, so the commitment was
+/// final before the content became public, and within `REVEAL_WINDOW_LEDGERS` of it.
+/// The commitment is consumed.
+///
+/// # Authorization
+/// Requires authorization from the attester, checked when the attestation is issued.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The address that committed. This address will also be the subject of the attestation.
+/// * `reveal` - The committed attestation content and salt
+///
+/// # Returns
+/// * `Result<BytesN<32>, Error>` - The UID of the created attestation
+///
+/// # Errors
+/// * `Error::CommitmentNotFound` - If the attester has no live commitment matching the reveal,
+///   including one whose window has closed and whose entry has lapsed
+/// * `Error::RevealOutsideWindow` - If revealed in the commitment's own ledger, or after the window
+///   while the entry is still live
+/// * Any error `attest` returns for the revealed content
+pub fn reveal_attestation(env: &Env, attester: Address, reveal: AttestationReveal) -> Result<BytesN<32>, Error> {
+    let key = DataKey::AttestationCommitment(compute_attestation_commitment(env, &attester, &reveal));
+    let record: AttestationCommitment = env.storage().temporary().get(&key).ok_or(Error::CommitmentNotFound)?;
+
+    let current_ledger = env.ledger().sequence();
+    if current_ledger <= record.committed_ledger || current_ledger - record.committed_ledger > REVEAL_WINDOW_LEDGERS {
+        return Err(Error::RevealOutsideWindow);
+    }
+    env.storage().temporary().remove(&key);
+
+    create_attestation(
+        env,
+        attester,
+        reveal.schema_uid,
+        reveal.value,
+        reveal.expiration_time,
+        AttestationOptions::default(),
+    )
+}
+
+/// Returns a pending attestation commitment, if it exists and has not been revealed.
+pub fn get_attestation_commitment(env: &Env, commitment: &BytesN<32>) -> Option<AttestationCommitment> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::AttestationCommitment(commitment.clone()))
+}
@@ -0,0 +1,91 @@
+use crate::errors::Error;
+use crate::instructions::get_schema_definition;
+use crate::state::{SchemaCompatibility, SchemaDefinition};
+use crate::xdr_codec;
+use soroban_sdk::{BytesN, Env, String, Vec};
+
+/// Compares a registered schema's definition with a proposed successor.
+///
+/// Both definitions must be `XDR:`-encoded, and the proposed one must pass
+/// [`lint_definition`]. Fields are matched by name, so renaming a field reports it
+/// as removed and a new field added.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `schema_uid` - The registered schema being superseded
+/// * `definition` - The proposed `XDR:`-encoded definition
+///
+/// # Returns
+/// * `Result<SchemaCompatibility, Error>` - The breaking and additive field changes
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If the schema does not exist
+/// * `Error::InvalidSchemaDefinition` - If either definition is not XDR-encoded, or the
+///   proposed one fails linting
+pub fn check_schema_compatibility(
+    env: &Env,
+    schema_uid: &BytesN<32>,
+    definition: &String,
+) -> Result<SchemaCompatibility, Error> {
+    let current = get_schema_definition(env, schema_uid)?;
+    let proposed = xdr_codec::decode_schema(env, definition)?;
+    lint_definition(&proposed)?;
+    Ok(compare_definitions(env, &current, &proposed))
+}
+
+/// Rejects definitions with unnamed, untyped or repeated fields, which consumers
+/// cannot address unambiguously.
+///
+/// # Errors
+/// * `Error::InvalidSchemaDefinition` - If any field fails the checks
+pub fn lint_definition(definition: &SchemaDefinition) -> Result<(), Error> {
+    for (index, field) in definition.fields.iter().enumerate() {
+        if field.name.is_empty() || field.field_type.is_empty() {
+            return Err(Error::InvalidSchemaDefinition);
+        }
+        if definition
+            .fields
+            .iter()
+            .skip(index + 1)
+            .any(|other| other.name == field.name)
+        {
+            return Err(Error::InvalidSchemaDefinition);
+        }
+    }
+    Ok(())
+}
+
+/// Classifies the field changes from `current` to `proposed`.
+pub fn compare_definitions(env: &Env, current: &SchemaDefinition, proposed: &SchemaDefinition) -> SchemaCompatibility {
+    let mut report = SchemaCompatibility {
+        breaking: false,
+        removed: Vec::new(env),
+        retyped: Vec::new(env),
+        relaxed: Vec::new(env),
+        added: Vec::new(env),
+        tightened: Vec::new(env),
+    };
+
+    for old in current.fields.iter() {
+        let Some(new) = proposed.fields.iter().find(|field| field.name == old.name) else {
+            report.removed.push_back(old.name);
+            continue;
+        };
+        if new.field_type != old.field_type {
+            report.retyped.push_back(old.name.clone());
+        }
+        if new.optional && !old.optional {
+            report.relaxed.push_back(old.name.clone());
+        } else if old.optional && !new.optional {
+            report.tightened.push_back(old.name.clone());
+        }
+    }
+    for new in proposed.fields.iter() {
+        if !current.fields.iter().any(|field| field.name == new.name) {
+            report.added.push_back(new.name);
+        }
+    }
+
+    report.breaking = !report.removed.is_empty() || !report.retyped.is_empty() || !report.relaxed.is_empty();
+    report
+}
@@ -0,0 +1,131 @@
+use crate::errors::Error;
+use crate::events;
+use crate::state::{Attestation, DataKey, ExtendedKey};
+use crate::utils;
+use soroban_sdk::{Address, BytesN, Env};
+
+/// Amount the attester's nonce is advanced by when a compromise is declared.
+///
+/// Delegated requests are only accepted with unused nonces in the `NONCE_WINDOW` slots
+/// above the counter. Skipping the counter well past that window rejects every request
+/// already signed with a nonce in it, whether or not its bit was set out of order.
+pub const COMPROMISE_NONCE_SKIP: u64 = 1_000_000;
+
+/// Declares that an attester's signing keys have leaked.
+///
+/// The attester's registered BLS and secp256k1 keys are removed, so no delegated
+/// attestation or revocation can be verified against them, and the attester can register
+/// fresh keys. The attester's nonce is advanced by `COMPROMISE_NONCE_SKIP`.
+///
+/// If `freeze_after` is given, every attestation by the attester issued from that timestamp
+/// up to the declaration is frozen pending review: it stays stored but no longer counts as
+/// valid until the admin unfreezes it. Attestations issued after the declaration, once the
+/// attester has rotated to fresh keys, are not frozen. A later declaration replaces the
+/// freeze window of an earlier one.
+///
+/// # Authorization
+/// Requires authorization from the attester.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The attester whose keys are compromised
+/// * `freeze_after` - Optional timestamp from which the attester's attestations are frozen
+pub fn declare_compromise(env: &Env, attester: Address, freeze_after: Option<u64>) -> Result<(), Error> {
+    attester.require_auth();
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::AttesterPublicKey(attester.clone()));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::AttesterSecp256k1Key(attester.clone()));
+
+    let next_nonce = utils::get_next_nonce(env, &attester)
+        .checked_add(COMPROMISE_NONCE_SKIP)
+        .ok_or(Error::IntegerOverflow)?;
+    utils::set_next_nonce(env, &attester, next_nonce)?;
+
+    if let Some(timestamp) = freeze_after {
+        env.storage()
+            .persistent()
+            .set(&DataKey::AttesterFreezeAfter(attester.clone()), &timestamp);
+        env.storage().persistent().set(
+            &DataKey::Extended(ExtendedKey::AttesterFreezeUntil(attester.clone())),
+            &env.ledger().timestamp(),
+        );
+    }
+
+    events::publish_compromise_declared(env, &attester, freeze_after);
+    Ok(())
+}
+
+/// Unfreezes an attestation frozen by a compromise declaration, once it has been reviewed.
+///
+/// # Authorization
+/// Requires authorization from the contract admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract admin
+/// * `attestation_uid` - The unique identifier of the attestation to unfreeze
+///
+/// # Errors
+/// * `Error::NotAuthorized` - If the caller is not the admin
+/// * `Error::AttestationNotFound` - If the attestation does not exist
+pub fn unfreeze_attestation(env: &Env, admin: Address, attestation_uid: BytesN<32>) -> Result<(), Error> {
+    utils::require_admin(env, &admin)?;
+
+    if !env
+        .storage()
+        .persistent()
+        .has(&DataKey::AttestationUID(attestation_uid.clone()))
+    {
+        return Err(Error::AttestationNotFound);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::UnfrozenAttestation(attestation_uid.clone()), &true);
+
+    events::publish_attestation_unfrozen(env, &attestation_uid);
+    Ok(())
+}
+
+/// Checks whether an attestation is frozen pending compromise review.
+///
+/// # Errors
+/// * `Error::AttestationNotFound` - If the attestation does not exist
+pub fn is_attestation_frozen(env: &Env, attestation_uid: BytesN<32>) -> Result<bool, Error> {
+    let attestation = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Attestation>(&DataKey::AttestationUID(attestation_uid))
+        .ok_or(Error::AttestationNotFound)?;
+    Ok(is_frozen(env, &attestation))
+}
+
+/// Checks whether an attestation falls within its attester's compromise freeze window and
+/// has not been unfrozen by the admin.
+pub(crate) fn is_frozen(env: &Env, attestation: &Attestation) -> bool {
+    let Some(freeze_after) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, u64>(&DataKey::AttesterFreezeAfter(attestation.attester.clone()))
+    else {
+        return false;
+    };
+    // Windows declared before their end was recorded stay open-ended
+    let freeze_until = env
+        .storage()
+        .persistent()
+        .get::<DataKey, u64>(&DataKey::Extended(ExtendedKey::AttesterFreezeUntil(
+            attestation.attester.clone(),
+        )))
+        .unwrap_or(u64::MAX);
+
+    (freeze_after..=freeze_until).contains(&attestation.timestamp)
+        && !env
+            .storage()
+            .persistent()
+            .has(&DataKey::UnfrozenAttestation(attestation.uid.clone()))
+}
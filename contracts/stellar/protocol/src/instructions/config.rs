@@ -0,0 +1,31 @@
+use crate::instructions::attestation::{MAX_ENCRYPTED_KEY_LEN, MAX_EXTERNAL_ID_LEN};
+use crate::instructions::cleanup::{get_cleanup_bounty, REVOKED_RETENTION_SECONDS};
+use crate::instructions::moderation::is_paused;
+use crate::instructions::outbox::OUTBOX_CAPACITY;
+use crate::state::{DataKey, ProtocolConfig, ProtocolLimits};
+use soroban_sdk::{Env, String};
+
+/// Assembles a snapshot of the contract's configuration from storage.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+///
+/// # Returns
+/// * `ProtocolConfig` - The admin, pause flag, version, cleanup bounty settings and limits
+pub fn get_config(env: &Env) -> ProtocolConfig {
+    let bounty = get_cleanup_bounty(env);
+    ProtocolConfig {
+        admin: env.storage().instance().get(&DataKey::Admin),
+        paused: is_paused(env),
+        version: String::from_str(env, env!("CARGO_PKG_VERSION")),
+        cleanup_bounty_token: bounty.as_ref().map(|pool| pool.token.clone()),
+        cleanup_bounty_per_entry: bounty.as_ref().map_or(0, |pool| pool.bounty_per_entry),
+        cleanup_bounty_balance: bounty.map_or(0, |pool| pool.balance),
+        limits: ProtocolLimits {
+            outbox_capacity: OUTBOX_CAPACITY,
+            max_external_id_len: MAX_EXTERNAL_ID_LEN,
+            max_encrypted_key_len: MAX_ENCRYPTED_KEY_LEN,
+            revoked_retention_seconds: REVOKED_RETENTION_SECONDS,
+        },
+    }
+}
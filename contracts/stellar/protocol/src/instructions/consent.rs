@@ -0,0 +1,159 @@
+use crate::errors::Error;
+use crate::events;
+use crate::instructions::attestation::{
+    call_resolver_onresolve, create_resolver_attestation, index_subject_attestation,
+};
+use crate::instructions::hook_queue::enqueue_hooks;
+use crate::instructions::moderation::{ensure_attester_not_banned, ensure_not_paused};
+use crate::instructions::record_outbox_entry;
+use crate::instructions::schema::{ensure_schema_not_revoked, get_schema_or_fail, record_schema_attestation};
+use crate::instructions::uniqueness::reserve_uniqueness_key;
+use crate::state::{Attestation, DataKey, ExtendedKey, OutboxAction};
+use soroban_sdk::{Address, BytesN, Env};
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Sets whether a schema's attestations need the subject's acceptance.
+////////////////////////////////////////////////////////////////////////////////////
+/// While set, delegated attestations about a subject other than the attester are held
+/// as pending until the subject calls `accept_attestation`, so credentials such as
+/// employment or membership record both parties' consent. Direct attestations are
+/// always about the attester itself and are not affected.
+///
+/// # Authorization
+/// Requires authorization from the schema authority.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `caller` - The schema authority.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+/// * `required` - Whether subjects must accept attestations under the schema.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+/// * `Error::NotAuthorized` - If the caller is not the schema authority.
+pub fn set_schema_requires_consent(
+    env: &Env,
+    caller: Address,
+    schema_uid: BytesN<32>,
+    required: bool,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    let schema = get_schema_or_fail(env, &schema_uid)?;
+    if schema.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+
+    let key = DataKey::Extended(ExtendedKey::SchemaRequiresConsent(schema_uid));
+    if required {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+    Ok(())
+}
+
+/// Returns whether a schema's attestations need the subject's acceptance.
+pub fn is_schema_consent_required(env: &Env, schema_uid: &BytesN<32>) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Extended(ExtendedKey::SchemaRequiresConsent(
+            schema_uid.clone(),
+        )))
+        .unwrap_or(false)
+}
+
+/// Returns an attestation awaiting its subject's acceptance.
+///
+/// # Errors
+/// * `Error::AttestationNotFound` - If no attestation with the UID is pending
+pub fn get_pending_attestation(env: &Env, attestation_uid: BytesN<32>) -> Result<Attestation, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Extended(ExtendedKey::PendingAttestation(attestation_uid)))
+        .ok_or(Error::AttestationNotFound)
+}
+
+/// Holds an attestation until its subject accepts it.
+///
+/// The UID and nonce are already fixed and the resolver's `onattest` hook has already run,
+/// so the pending attestation is finalized unchanged by `accept_attestation`. Its uniqueness
+/// key is only reserved then, so a pending attestation the subject never accepts does not
+/// block the key. With `hooks_deferred`, no hook has run yet and the attestation is queued
+/// for the hook queue once accepted.
+pub(crate) fn hold_for_consent(env: &Env, attestation: &Attestation, hooks_deferred: bool) {
+    let key = DataKey::Extended(ExtendedKey::PendingAttestation(attestation.uid.clone()));
+    env.storage().persistent().set(&key, attestation);
+    if hooks_deferred {
+        env.storage().persistent().set(
+            &DataKey::Extended(ExtendedKey::PendingHooksDeferred(attestation.uid.clone())),
+            &true,
+        );
+    }
+    events::publish_attestation_pending(env, attestation);
+}
+
+/// Accepts a pending attestation, recording it as a regular attestation.
+///
+/// The attester, schema and uniqueness checks are repeated, since the attester may have
+/// been banned, the schema revoked or the uniqueness key taken while it was pending.
+///
+/// # Authorization
+/// Requires authorization from the subject of the attestation.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `subject` - The subject of the pending attestation
+/// * `attestation_uid` - The UID of the pending attestation
+///
+/// # Errors
+/// * `Error::AttestationNotFound` - If no attestation with the UID is pending
+/// * `Error::NotAuthorized` - If `subject` is not the attestation's subject
+/// * `Error::AttestationExpired` - If the attestation expired before it was accepted
+/// * `Error::AttesterBanned` - If the attester has been banned since
+/// * `Error::SchemaRevoked` - If the schema has been revoked since
+/// * `Error::UniquenessKeyTaken` - If another active attestation holds the same uniqueness key
+pub fn accept_attestation(env: &Env, subject: Address, attestation_uid: BytesN<32>) -> Result<(), Error> {
+    ensure_not_paused(env)?;
+    subject.require_auth();
+
+    let attestation = get_pending_attestation(env, attestation_uid.clone())?;
+    if attestation.subject != subject {
+        return Err(Error::NotAuthorized);
+    }
+    if let Some(expiration_time) = attestation.expiration_time {
+        if expiration_time <= env.ledger().timestamp() {
+            return Err(Error::AttestationExpired);
+        }
+    }
+    ensure_attester_not_banned(env, &attestation.attester)?;
+    ensure_schema_not_revoked(env, &attestation.schema_uid)?;
+    reserve_uniqueness_key(env, &attestation)?;
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Extended(ExtendedKey::PendingAttestation(
+            attestation_uid.clone(),
+        )));
+    env.storage()
+        .persistent()
+        .set(&DataKey::AttestationUID(attestation_uid), &attestation);
+    let new_subject = index_subject_attestation(env, &attestation);
+    record_schema_attestation(env, &attestation.schema_uid, new_subject, attestation.timestamp);
+
+    // Run the hooks left for acceptance, as `attest` does once the attestation is stored
+    let deferred_key = DataKey::Extended(ExtendedKey::PendingHooksDeferred(attestation.uid.clone()));
+    if env.storage().persistent().has(&deferred_key) {
+        env.storage().persistent().remove(&deferred_key);
+        enqueue_hooks(env, &attestation.uid);
+    } else if let Some(resolver_address) = get_schema_or_fail(env, &attestation.schema_uid)?.resolver {
+        let resolver_attestation =
+            create_resolver_attestation(env, &attestation, &attestation.schema_uid, &attestation.value);
+        call_resolver_onresolve(env, &resolver_address, &resolver_attestation);
+    }
+
+    // Record in the outbox for off-chain pollers and emit event
+    record_outbox_entry(env, OutboxAction::Attested, &attestation);
+    events::publish_attestation_event(env, &attestation);
+    Ok(())
+}
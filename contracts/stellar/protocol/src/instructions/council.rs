@@ -0,0 +1,293 @@
+use crate::errors::Error;
+use crate::events;
+use crate::state::{AdminCouncil, AdminOperation, AdminProposal, DataKey, GovernanceKey};
+use crate::utils;
+use soroban_sdk::{vec, Address, BytesN, Env, Vec};
+
+/// Gets the council that approves critical admin operations.
+///
+/// Until a council is configured, the admin alone forms a 1-of-1 council.
+///
+/// # Errors
+/// * `Error::AdminNotSet` - If the contract has not been initialized
+pub fn get_admin_council(env: &Env) -> Result<AdminCouncil, Error> {
+    if let Some(council) = env
+        .storage()
+        .instance()
+        .get(&DataKey::Governance(GovernanceKey::Council))
+    {
+        return Ok(council);
+    }
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(Error::AdminNotSet)?;
+    Ok(AdminCouncil {
+        members: vec![env, admin],
+        threshold: 1,
+    })
+}
+
+/// Proposes a critical admin operation, counting as the proposer's approval.
+///
+/// The operation executes as soon as `threshold` council members have approved it, which
+/// is immediately if the council threshold is 1.
+///
+/// # Authorization
+/// Requires authorization from the proposer, who must be a council member.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `proposer` - The council member proposing the operation
+/// * `operation` - The operation to execute once approved
+///
+/// # Returns
+/// * `Result<u32, Error>` - The proposal ID
+///
+/// # Errors
+/// * `Error::NotAuthorized` - If the proposer is not a council member
+/// * `Error::InvalidAdminCouncil` - If a proposed council is empty, has duplicate members or
+///   a threshold outside `1..=members.len()`
+pub fn propose_admin_operation(env: &Env, proposer: Address, operation: AdminOperation) -> Result<u32, Error> {
+    proposer.require_auth();
+    let council = get_admin_council(env)?;
+    if !council.members.contains(&proposer) {
+        return Err(Error::NotAuthorized);
+    }
+    if let AdminOperation::SetCouncil(members, threshold) = &operation {
+        validate_council(members, *threshold)?;
+    }
+
+    let count_key = DataKey::Governance(GovernanceKey::ProposalCount);
+    let proposal_id: u32 = env.storage().instance().get(&count_key).unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&count_key, &proposal_id.checked_add(1).ok_or(Error::IntegerOverflow)?);
+
+    let proposal = AdminProposal {
+        operation,
+        proposer: proposer.clone(),
+        approvals: vec![env, proposer.clone()],
+        created_at: env.ledger().timestamp(),
+    };
+    events::publish_admin_proposal(env, proposal_id, &proposer);
+    resolve_proposal(env, proposal_id, proposal, &council);
+
+    Ok(proposal_id)
+}
+
+/// Approves a pending critical admin operation, executing it once the threshold is reached.
+///
+/// # Authorization
+/// Requires authorization from the approver, who must be a council member.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `approver` - The council member approving the operation
+/// * `proposal_id` - The ID returned by `propose_admin_operation`
+///
+/// # Returns
+/// * `Result<bool, Error>` - True if this approval executed the operation
+///
+/// # Errors
+/// * `Error::NotAuthorized` - If the approver is not a council member
+/// * `Error::ProposalNotFound` - If there is no pending proposal with this ID
+pub fn approve_admin_operation(env: &Env, approver: Address, proposal_id: u32) -> Result<bool, Error> {
+    approver.require_auth();
+    let council = get_admin_council(env)?;
+    if !council.members.contains(&approver) {
+        return Err(Error::NotAuthorized);
+    }
+
+    let mut proposal = get_admin_proposal(env, proposal_id)?;
+    if !proposal.approvals.contains(&approver) {
+        proposal.approvals.push_back(approver.clone());
+    }
+    events::publish_admin_approval(env, proposal_id, &approver);
+
+    Ok(resolve_proposal(env, proposal_id, proposal, &council))
+}
+
+/// Gets a pending critical admin operation.
+///
+/// # Errors
+/// * `Error::ProposalNotFound` - If there is no pending proposal with this ID
+pub fn get_admin_proposal(env: &Env, proposal_id: u32) -> Result<AdminProposal, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Governance(GovernanceKey::Proposal(proposal_id)))
+        .ok_or(Error::ProposalNotFound)
+}
+
+pub(crate) fn validate_council(members: &Vec<Address>, threshold: u32) -> Result<(), Error> {
+    if threshold == 0 || threshold > members.len() {
+        return Err(Error::InvalidAdminCouncil);
+    }
+    for (i, member) in members.iter().enumerate() {
+        if members.first_index_of(&member) != Some(i as u32) {
+            return Err(Error::InvalidAdminCouncil);
+        }
+    }
+    Ok(())
+}
+
+/// Rejects single-admin calls to operations that go through council proposals once a
+/// council is configured.
+///
+/// # Errors
+/// * `Error::NotAuthorized` - If a council is configured
+pub(crate) fn ensure_no_council(env: &Env) -> Result<(), Error> {
+    if env
+        .storage()
+        .instance()
+        .has(&DataKey::Governance(GovernanceKey::Council))
+    {
+        return Err(Error::NotAuthorized);
+    }
+    Ok(())
+}
+
+/// Executes a proposal if enough current council members approved it, otherwise stores it.
+///
+/// Approvals from addresses that have since left the council are not counted.
+fn resolve_proposal(env: &Env, proposal_id: u32, proposal: AdminProposal, council: &AdminCouncil) -> bool {
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|approver| council.members.contains(approver))
+        .count() as u32;
+
+    let proposal_key = DataKey::Governance(GovernanceKey::Proposal(proposal_id));
+    if approvals < council.threshold {
+        env.storage().persistent().set(&proposal_key, &proposal);
+        return false;
+    }
+    env.storage().persistent().remove(&proposal_key);
+
+    match &proposal.operation {
+        AdminOperation::Upgrade(wasm_hash) => env.deployer().update_current_contract_wasm(wasm_hash.clone()),
+        AdminOperation::SetAdmin(admin) => {
+            env.storage().instance().set(&DataKey::Admin, admin);
+            env.storage()
+                .instance()
+                .remove(&DataKey::Governance(GovernanceKey::PendingAdmin));
+        },
+        AdminOperation::SetCouncil(members, threshold) => {
+            env.storage().instance().set(
+                &DataKey::Governance(GovernanceKey::Council),
+                &AdminCouncil {
+                    members: members.clone(),
+                    threshold: *threshold,
+                },
+            );
+            // A transfer proposed before the council existed can no longer be accepted
+            env.storage()
+                .instance()
+                .remove(&DataKey::Governance(GovernanceKey::PendingAdmin));
+        },
+        AdminOperation::Pause => {
+            env.storage().instance().set(&DataKey::Paused, &true);
+            events::publish_contract_paused(env, &proposal.proposer);
+        },
+        AdminOperation::Unpause => {
+            env.storage().instance().remove(&DataKey::Paused);
+            events::publish_contract_unpaused(env, &proposal.proposer);
+        },
+    }
+    events::publish_admin_execution(env, proposal_id, &proposal.operation);
+    true
+}
+
+/// Proposes a new admin, who takes over once they call `accept_admin`.
+///
+/// Replaces any earlier pending proposal. Proposing the current admin cancels a pending
+/// transfer. Once a council is configured, admin changes go through
+/// `AdminOperation::SetAdmin` proposals instead.
+///
+/// # Authorization
+/// Requires authorization from the current admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The current admin
+/// * `new_admin` - The address proposed as the next admin
+///
+/// # Errors
+/// * `Error::AdminNotSet` - If the contract has not been initialized
+/// * `Error::NotAuthorized` - If the caller is not the admin, or a council is configured
+pub fn propose_admin(env: &Env, admin: Address, new_admin: Address) -> Result<(), Error> {
+    utils::require_admin(env, &admin)?;
+    ensure_no_council(env)?;
+
+    let pending_key = DataKey::Governance(GovernanceKey::PendingAdmin);
+    if new_admin == admin {
+        env.storage().instance().remove(&pending_key);
+    } else {
+        env.storage().instance().set(&pending_key, &new_admin);
+    }
+    events::publish_admin_transfer_proposed(env, &admin, &new_admin);
+    Ok(())
+}
+
+/// Completes a two-step admin transfer, making the pending admin the admin.
+///
+/// # Authorization
+/// Requires authorization from the pending admin.
+///
+/// # Errors
+/// * `Error::NoPendingAdmin` - If no admin transfer has been proposed
+/// * `Error::NotAuthorized` - If a council is configured
+pub fn accept_admin(env: &Env) -> Result<Address, Error> {
+    ensure_no_council(env)?;
+    let pending_key = DataKey::Governance(GovernanceKey::PendingAdmin);
+    let new_admin: Address = env
+        .storage()
+        .instance()
+        .get(&pending_key)
+        .ok_or(Error::NoPendingAdmin)?;
+    new_admin.require_auth();
+
+    let previous_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(Error::AdminNotSet)?;
+    env.storage().instance().set(&DataKey::Admin, &new_admin);
+    env.storage().instance().remove(&pending_key);
+    events::publish_admin_transferred(env, &previous_admin, &new_admin);
+    Ok(new_admin)
+}
+
+/// Gets the address proposed as the next admin, if a transfer is pending.
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Governance(GovernanceKey::PendingAdmin))
+}
+
+/// Replaces the contract's code with an uploaded WASM, keeping all contract storage.
+///
+/// Once a council is configured, upgrades go through `AdminOperation::Upgrade`
+/// proposals instead. After an upgrade that changes the storage layout, the admin calls
+/// `migrate_storage` to bring existing entries up to date.
+///
+/// # Authorization
+/// Requires authorization from the admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract admin
+/// * `new_wasm_hash` - Hash of the uploaded WASM to run from now on
+///
+/// # Errors
+/// * `Error::AdminNotSet` - If the contract has not been initialized
+/// * `Error::NotAuthorized` - If the caller is not the admin, or a council is configured
+pub fn upgrade(env: &Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+    utils::require_admin(env, &admin)?;
+    ensure_no_council(env)?;
+
+    env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+    events::publish_contract_upgraded(env, &admin, &new_wasm_hash);
+    Ok(())
+}
@@ -92,7 +92,7 @@ const signature = signaturePoint.toRawBytes(false); // -> 96-byte Uint8Array
 
 */
 use crate::errors::Error;
-use crate::state::{BlsPublicKey, DataKey};
+use crate::state::{BlsPublicKey, DataKey, Secp256k1Signer};
 use soroban_sdk::{
     crypto::bls12_381::{G1Affine, G2Affine},
     Address, Bytes, BytesN, Env, Vec,
@@ -100,7 +100,7 @@ use soroban_sdk::{
 
 /// Attest Protocol domain separation tag for BLS G1 signature hashing.
 /// This is the standard DST for BLS signatures over G1.
-const ATTEST_PROTOCOL_BLS_G1_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+pub const ATTEST_PROTOCOL_BLS_G1_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
 
 /// The uncompressed G2 generator point for the BLS12-381 curve. This is a standard,
 /// well-known constant. It's the point against which signatures are verified.
@@ -265,3 +265,127 @@ pub fn verify_bls_signature(
         Err(Error::InvalidSignature)
     }
 }
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Secp256k1 (EVM-style) Signatures
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Offset added to the recovery id by EVM wallets (`v` = 27 or 28).
+const EVM_RECOVERY_ID_OFFSET: u8 = 27;
+
+/// Registers a secp256k1 signer (EVM address) for an attester.
+///
+/// Each wallet address can register exactly one secp256k1 signer.
+/// Once registered, the signer is immutable - cannot be updated or revoked.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The address of the attester registering the signer
+/// * `eth_address` - The 20-byte EVM address of the secp256k1 key
+///
+/// # Returns
+/// * `Result<(), Error>` - Success or error (fails if a signer already exists)
+pub fn register_secp256k1_signer(env: &Env, attester: Address, eth_address: BytesN<20>) -> Result<(), Error> {
+    attester.require_auth();
+
+    let signer_key = DataKey::AttesterSecp256k1Key(attester.clone());
+
+    // Check if this address already has a signer registered
+    if env.storage().persistent().has(&signer_key) {
+        // Signer already registered - immutable, cannot update
+        return Err(Error::AlreadyInitialized);
+    }
+
+    let timestamp = env.ledger().timestamp();
+    let signer = Secp256k1Signer {
+        eth_address: eth_address.clone(),
+        registered_at: timestamp,
+    };
+
+    env.storage().persistent().set(&signer_key, &signer);
+    crate::events::publish_secp256k1_key_registered(env, &attester, &eth_address, timestamp);
+
+    Ok(())
+}
+
+/// Gets the secp256k1 signer for an attester.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The address of the attester
+///
+/// # Returns
+/// * `Result<Secp256k1Signer, Error>` - The signer if registered
+pub fn get_secp256k1_signer(env: &Env, attester: &Address) -> Result<Secp256k1Signer, Error> {
+    let signer_key = DataKey::AttesterSecp256k1Key(attester.clone());
+    env.storage()
+        .persistent()
+        .get::<DataKey, Secp256k1Signer>(&signer_key)
+        .ok_or(Error::Secp256k1KeyNotRegistered)
+}
+
+/// Derives the 20-byte EVM address from an uncompressed SEC-1 secp256k1 public key.
+///
+/// The address is the last 20 bytes of `keccak256(x || y)`, where the leading
+/// `0x04` tag byte of the public key is dropped.
+pub fn eth_address_from_public_key(env: &Env, public_key: &BytesN<65>) -> BytesN<20> {
+    let point = Bytes::from(public_key.clone()).slice(1..65);
+    let digest: BytesN<32> = env.crypto().keccak256(&point).into();
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&digest.to_array()[12..32]);
+    BytesN::from_array(env, &address)
+}
+
+/// **CRITICAL CRYPTOGRAPHIC FUNCTION**: Verifies a recoverable secp256k1 signature.
+///
+/// The signed digest is `keccak256(message)`, where `message` is the exact same
+/// domain-separated payload used for BLS delegation. The public key is recovered
+/// from the signature with `secp256k1_recover`, reduced to its EVM address and
+/// compared against the signer registered for the attester.
+///
+/// # Signature Format
+/// 65 bytes laid out as `r (32) || s (32) || v (1)`. `v` may be the raw recovery
+/// id (0 or 1) or the EVM-style value (27 or 28). `s` must be in the lower half
+/// of the curve order, as produced by standard EVM wallets.
+///
+/// # Parameters
+/// * `env` - The Soroban environment for cryptographic host functions.
+/// * `message` - The raw, domain-separated message bytes that were signed.
+/// * `signature` - The 65-byte recoverable signature.
+/// * `attester` - The wallet address whose registered signer must match.
+///
+/// # Returns
+/// * `Ok(())` if the recovered EVM address matches the registered signer.
+/// * `Err(Error::Secp256k1KeyNotRegistered)` if the attester has no registered signer.
+/// * `Err(Error::InvalidSignature)` if the recovery id is malformed or the signer does not match.
+pub fn verify_secp256k1_signature(
+    env: &Env,
+    message: &Bytes,
+    signature: &BytesN<65>,
+    attester: &Address,
+) -> Result<(), Error> {
+    let signer = get_secp256k1_signer(env, attester)?;
+
+    let signature_bytes = Bytes::from(signature.clone());
+    let mut recovery_id = signature_bytes.get(64).ok_or(Error::InvalidSignature)?;
+    if recovery_id >= EVM_RECOVERY_ID_OFFSET {
+        recovery_id -= EVM_RECOVERY_ID_OFFSET;
+    }
+    if recovery_id > 3 {
+        return Err(Error::InvalidSignature);
+    }
+
+    let rs: BytesN<64> = signature_bytes
+        .slice(0..64)
+        .try_into()
+        .map_err(|_| Error::InvalidSignature)?;
+
+    let digest = env.crypto().keccak256(message);
+    let public_key = env.crypto().secp256k1_recover(&digest, &rs, recovery_id as u32);
+
+    if eth_address_from_public_key(env, &public_key) == signer.eth_address {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
@@ -1,9 +1,25 @@
 use crate::errors::Error;
 use crate::events;
-use crate::instructions::verify_bls_signature;
-use crate::state::{Attestation, DataKey, DelegatedAttestationRequest, DelegatedRevocationRequest};
+use crate::instructions::attestation::{
+    apply_resolver_expiration, apply_revocation, call_resolver_onattest, call_resolver_onrelayed,
+    call_resolver_onresolve, create_resolver_attestation, ensure_min_active_duration, ensure_not_duplicate,
+    index_subject_attestation, record_attestation_fingerprint,
+};
+use crate::instructions::consent::{hold_for_consent, is_schema_consent_required};
+use crate::instructions::expiration::ensure_expiration_within_horizon;
+use crate::instructions::hook_queue::{enqueue_hooks, is_schema_hooks_deferred};
+use crate::instructions::moderation::{ensure_attester_not_banned, ensure_not_paused};
+use crate::instructions::schema::{ensure_schema_not_revoked, record_schema_attestation};
+use crate::instructions::uniqueness::{check_uniqueness_key, reserve_uniqueness_key};
+use crate::instructions::value::ensure_value_conforms;
+use crate::instructions::{record_outbox_entry, verify_bls_signature, verify_secp256k1_signature};
+use crate::interfaces::resolver::ResolverContext;
+use crate::state::{
+    Attestation, DataKey, DelegatedAttestationRequest, DelegatedRevocationRequest, OutboxAction, Schema,
+};
 use crate::utils::{self, generate_attestation_uid};
-use soroban_sdk::{Address, Bytes, BytesN, Env};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
 
 /// Domain separator for creating delegated attestation signatures.
 /// This MUST be unique to prevent signature reuse in other contexts.
@@ -13,12 +29,24 @@ const ATTEST_DOMAIN_SEPARATOR: &[u8] = b"ATTEST_PROTOCOL_V1_DELEGATED";
 /// This MUST be unique and different from the attestation separator.
 const REVOKE_DOMAIN_SEPARATOR: &[u8] = b"REVOKE_PROTOCOL_V1_DELEGATED";
 
+/// The current delegated message format version, set on newly signed requests.
+pub const MESSAGE_VERSION: u32 = 1;
+
+/// Message format versions this build can verify; the accepted set is a subset of these.
+pub const SUPPORTED_MESSAGE_VERSIONS: [u32; 1] = [MESSAGE_VERSION];
+
+/// How far above an attester's nonce counter a delegated request's nonce may be,
+/// so requests can be submitted out of order without reserving arbitrary future nonces.
+pub const NONCE_WINDOW: u64 = 256;
+
 /// Creates an attestation through delegated signature.
 ///
 /// This function allows anyone to submit a pre-signed attestation request on-chain.
 /// The original attester signs the attestation data off-chain, and any party can
 /// submit it on-chain (paying the transaction fees).
 ///
+/// The schema's resolver hooks run as they do for `attest`, followed by `onrelayed`.
+///
 /// Important: The BLS signature is created by the ATTESTER (the entity making
 /// claims about subjects), not by the subject being attested. The subject never
 /// needs to interact with the blockchain in this flow.
@@ -38,22 +66,15 @@ const REVOKE_DOMAIN_SEPARATOR: &[u8] = b"REVOKE_PROTOCOL_V1_DELEGATED";
 /// * `Error::ExpiredSignature` - If the deadline has passed
 /// * `Error::InvalidSignature` - If the signature verification fails
 /// * `Error::BlsPubKeyNotRegistered` - If the BLS public key is not registered
-/// * `Error::InvalidNonce` - If the nonce doesn't match expected value
+/// * `Error::InvalidNonce` - If the nonce was already used or is outside the nonce window
 /// * `Error::SchemaNotFound` - If the schema doesn't exist
+/// * `Error::ResolverError` - If the schema's resolver rejects the attestation
 pub fn attest_by_delegation(env: &Env, submitter: Address, request: DelegatedAttestationRequest) -> Result<(), Error> {
+    ensure_not_paused(env)?;
     submitter.require_auth();
 
-    // Verify deadline hasn't passed
-    let current_time = env.ledger().timestamp();
-    if current_time > request.deadline {
-        return Err(Error::ExpiredSignature);
-    }
-
-    // Verify schema exists
-    let _schema = utils::get_schema(env, &request.schema_uid).ok_or(Error::SchemaNotFound)?;
-
-    // Verify and increment nonce
-    verify_and_increment_nonce(env, &request.attester, request.nonce)?;
+    // Verify deadline, schema and nonce
+    verify_attestation_request(env, &request)?;
 
     // Create message for signature verification
     let message = create_attestation_message(env, &request);
@@ -61,30 +82,259 @@ pub fn attest_by_delegation(env: &Env, submitter: Address, request: DelegatedAtt
     // Verify BLS12-381 signature
     verify_bls_signature(env, &message, &request.signature, &request.attester)?;
 
+    let mut attestation = build_delegated_attestation(env, &request)?;
+    let hooks_deferred = resolve_delegated_attestation(env, &mut attestation)?;
+    store_delegated_attestation(env, &submitter, attestation, hooks_deferred)?;
+    Ok(())
+}
+
+/// Creates attestations for a batch of BLS-signed delegated requests, independently.
+///
+/// Every check on a request, including deduplication and uniqueness, runs before anything
+/// is written for it, followed by the schema resolver's `onattest` hook. A request that
+/// fails therefore leaves no protocol state behind and does not consume its nonce, and
+/// the rest of the batch still goes through. Requests are processed
+/// in order, so one attester's requests may use consecutive nonces within the same batch.
+///
+/// Signatures that are not valid curve points abort the host's pairing check and
+/// therefore the whole batch; relayers should decode signatures before submitting.
+///
+/// # Authorization
+/// Requires authorization from the submitter (who pays fees), not the original attesters.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `submitter` - The address submitting the transaction (pays fees)
+/// * `requests` - The delegated attestation requests with signatures
+///
+/// # Returns
+/// * `Vec<Result<BytesN<32>, Error>>` - Per request, the new attestation UID or why it failed
+///
+/// # Errors
+/// * `Error::ContractPaused` - If the protocol is paused; no request is processed
+pub fn submit_delegated_batch(
+    env: &Env,
+    submitter: Address,
+    requests: Vec<DelegatedAttestationRequest>,
+) -> Result<Vec<Result<BytesN<32>, Error>>, Error> {
+    ensure_not_paused(env)?;
+    submitter.require_auth();
+
+    let mut outcomes = Vec::new(env);
+    for request in requests.iter() {
+        outcomes.push_back(attest_delegated_batch_item(env, &submitter, &request));
+    }
+    Ok(outcomes)
+}
+
+/// Processes one request of a delegated batch, running every check before the first write.
+fn attest_delegated_batch_item(
+    env: &Env,
+    submitter: &Address,
+    request: &DelegatedAttestationRequest,
+) -> Result<BytesN<32>, Error> {
+    check_attestation_request(env, request)?;
+    check_nonce_unused(env, &request.attester, request.nonce)?;
+
+    let message = create_attestation_message(env, request);
+    verify_bls_signature(env, &message, &request.signature, &request.attester)?;
+    let mut attestation = build_delegated_attestation(env, request)?;
+    let hooks_deferred = resolve_delegated_attestation(env, &mut attestation)?;
+
+    // Every check has passed, so the nonce is only consumed for a request that gets stored
+    verify_and_increment_nonce(env, &request.attester, request.nonce)?;
+    store_delegated_attestation(env, submitter, attestation, hooks_deferred)
+}
+
+/// Creates an attestation through a delegated secp256k1 (EVM-style) signature.
+///
+/// This is the secp256k1 counterpart of [`attest_by_delegation`]. The attester signs
+/// `keccak256` of [`build_secp256k1_attestation_message`], which binds the subject and a
+/// hash of the value, with an EVM-compatible key, and any party can submit it on-chain. The `signature` field of
+/// the request is not used on this path and may be zeroed.
+///
+/// # Authorization
+/// Requires authorization from the submitter (who pays fees), not the original attester.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `submitter` - The address submitting the transaction (pays fees)
+/// * `request` - The delegated attestation request
+/// * `signature` - The 65-byte recoverable secp256k1 signature (`r || s || v`)
+///
+/// # Returns
+/// * `Result<(), Error>` - Success or error
+///
+/// # Errors
+/// * `Error::ExpiredSignature` - If the deadline has passed
+/// * `Error::InvalidSignature` - If the recovered signer does not match
+/// * `Error::Secp256k1KeyNotRegistered` - If the attester has no registered secp256k1 signer
+/// * `Error::InvalidNonce` - If the nonce was already used or is outside the nonce window
+/// * `Error::SchemaNotFound` - If the schema doesn't exist
+/// * `Error::ResolverError` - If the schema's resolver rejects the attestation
+pub fn attest_by_delegation_secp256k1(
+    env: &Env,
+    submitter: Address,
+    request: DelegatedAttestationRequest,
+    signature: BytesN<65>,
+) -> Result<(), Error> {
+    ensure_not_paused(env)?;
+    submitter.require_auth();
+
+    // Verify deadline, schema and nonce
+    verify_attestation_request(env, &request)?;
+
+    // Verify secp256k1 signature over the raw message
+    let message = build_secp256k1_attestation_message(env, &request);
+    verify_secp256k1_signature(env, &message, &signature, &request.attester)?;
+
+    let mut attestation = build_delegated_attestation(env, &request)?;
+    let hooks_deferred = resolve_delegated_attestation(env, &mut attestation)?;
+    store_delegated_attestation(env, &submitter, attestation, hooks_deferred)?;
+    Ok(())
+}
+
+/// Validates the signature-independent parts of a delegated attestation request
+/// and consumes the attester's nonce.
+fn verify_attestation_request(env: &Env, request: &DelegatedAttestationRequest) -> Result<(), Error> {
+    check_attestation_request(env, request)?;
+
+    // Verify and increment nonce
+    verify_and_increment_nonce(env, &request.attester, request.nonce)
+}
+
+/// Checks the deadline and schema of a delegated attestation request without writing anything.
+fn check_attestation_request(env: &Env, request: &DelegatedAttestationRequest) -> Result<(), Error> {
+    // Verify deadline hasn't passed
+    if env.ledger().timestamp() > request.deadline {
+        return Err(Error::ExpiredSignature);
+    }
+    ensure_message_version_accepted(env, request.version)?;
+
+    // Verify schema exists and has not been revoked
+    let _schema = utils::get_schema(env, &request.schema_uid).ok_or(Error::SchemaNotFound)?;
+    ensure_schema_not_revoked(env, &request.schema_uid)?;
+    ensure_expiration_within_horizon(env, &request.schema_uid, request.expiration_time)?;
+    ensure_value_conforms(env, &request.schema_uid, &request.value)
+}
+
+/// Builds the attestation described by a delegated request, rejecting banned attesters,
+/// duplicates and uniqueness collisions without writing anything.
+fn build_delegated_attestation(env: &Env, request: &DelegatedAttestationRequest) -> Result<Attestation, Error> {
+    ensure_attester_not_banned(env, &request.attester)?;
+    let schema = utils::get_schema(env, &request.schema_uid).ok_or(Error::SchemaNotFound)?;
+
     let attestation_uid = generate_attestation_uid(env, &request.schema_uid, &request.subject, request.nonce);
 
     // Create attestation record
     let attestation = Attestation {
         uid: attestation_uid.clone(),
         schema_uid: request.schema_uid.clone(),
+        schema_version: schema.version,
         subject: request.subject.clone(),
         attester: request.attester.clone(),
         value: request.value.clone(),
         nonce: request.nonce,
-        timestamp: current_time,
+        timestamp: env.ledger().timestamp(),
         expiration_time: request.expiration_time,
         revoked: false,
         revocation_time: None,
+        encryption_scheme: None,
+        encrypted_key: None,
+        on_behalf_of: None,
+        external_id: None,
+        locale: None,
+        ref_uid: None,
     };
 
-    // Store attestation
-    let attest_key = DataKey::AttestationUID(attestation_uid);
-    env.storage().persistent().set(&attest_key, &attestation);
+    ensure_not_duplicate(env, &attestation)?;
+    check_uniqueness_key(env, &attestation)?;
+    Ok(attestation)
+}
 
-    // Emit event
-    events::publish_attestation_event(env, &attestation);
+/// Runs the schema resolver's `onattest` hook on a delegated attestation and applies its
+/// expiration override, as `attest` does before storing an attestation.
+///
+/// Returns whether the schema defers its hooks to the hook queue instead, in which case
+/// no hook runs now and the attestation is queued once stored.
+///
+/// # Errors
+/// * `Error::ResolverError` - If the resolver rejects the attestation
+/// * `Error::ResolverCallFailed` - If the resolver's `onattest` hook fails
+/// * `Error::InvalidDeadline` - If the resolver's expiration override is in the past
+fn resolve_delegated_attestation(env: &Env, attestation: &mut Attestation) -> Result<bool, Error> {
+    let schema = utils::get_schema(env, &attestation.schema_uid).ok_or(Error::SchemaNotFound)?;
+    let Some(resolver_address) = schema.resolver else {
+        return Ok(false);
+    };
+    if is_schema_hooks_deferred(env, &attestation.schema_uid) {
+        return Ok(true);
+    }
 
-    Ok(())
+    let resolver_attestation =
+        create_resolver_attestation(env, attestation, &attestation.schema_uid, &attestation.value);
+    if !call_resolver_onattest(env, &resolver_address, &resolver_attestation)? {
+        return Err(Error::ResolverError);
+    }
+    apply_resolver_expiration(env, &resolver_address, &resolver_attestation, attestation)?;
+    Ok(false)
+}
+
+/// Stores an attestation built from a verified delegated request and emits its event.
+///
+/// Records its deduplication fingerprint and reserves its uniqueness key, both already
+/// checked by [`build_delegated_attestation`]. If the schema has a resolver, its
+/// `onresolve` hook runs, or the attestation is queued when `hooks_deferred`, and its
+/// `onrelayed` hook is then given the submitter so it can reimburse the relayer.
+///
+/// Under schemas requiring subject consent, an attestation about another address is held
+/// pending instead. Its uniqueness key is reserved and its remaining hooks run once accepted.
+fn store_delegated_attestation(
+    env: &Env,
+    submitter: &Address,
+    attestation: Attestation,
+    hooks_deferred: bool,
+) -> Result<BytesN<32>, Error> {
+    record_attestation_fingerprint(env, &attestation);
+
+    // Schemas requiring consent hold the attestation until the subject accepts it
+    let pending =
+        attestation.subject != attestation.attester && is_schema_consent_required(env, &attestation.schema_uid);
+    if pending {
+        hold_for_consent(env, &attestation, hooks_deferred);
+    } else {
+        reserve_uniqueness_key(env, &attestation)?;
+        let attest_key = DataKey::AttestationUID(attestation.uid.clone());
+        env.storage().persistent().set(&attest_key, &attestation);
+        let new_subject = index_subject_attestation(env, &attestation);
+        record_schema_attestation(env, &attestation.schema_uid, new_subject, attestation.timestamp);
+    }
+
+    // Run the after-attest hook, then let the resolver reimburse the relayer; failures
+    // don't revert the attestation
+    let schema = utils::get_schema(env, &attestation.schema_uid).ok_or(Error::SchemaNotFound)?;
+    if let Some(resolver_address) = &schema.resolver {
+        let resolver_attestation =
+            create_resolver_attestation(env, &attestation, &attestation.schema_uid, &attestation.value);
+        if !pending && !hooks_deferred {
+            call_resolver_onresolve(env, resolver_address, &resolver_attestation);
+        }
+        let context = ResolverContext {
+            submitter: submitter.clone(),
+        };
+        call_resolver_onrelayed(env, resolver_address, &resolver_attestation, &context);
+    }
+
+    // Pending attestations are queued, recorded and announced once accepted
+    if !pending {
+        if hooks_deferred {
+            enqueue_hooks(env, &attestation.uid);
+        }
+        record_outbox_entry(env, OutboxAction::Attested, &attestation);
+        events::publish_attestation_event(env, &attestation);
+    }
+
+    Ok(attestation.uid)
 }
 
 /// Revokes an attestation through delegated signature.
@@ -93,6 +343,9 @@ pub fn attest_by_delegation(env: &Env, submitter: Address, request: DelegatedAtt
 /// revocation also requires a signature from the original attester
 /// to prevent unauthorized revocations.
 ///
+/// The schema's resolver is consulted through `onrevoke` and notified through
+/// `onresolve`, as it is for `revoke_attestation`.
+///
 /// # Arguments
 /// * `env` - The Soroban environment
 /// * `submitter` - The address submitting the transaction
@@ -101,131 +354,206 @@ pub fn attest_by_delegation(env: &Env, submitter: Address, request: DelegatedAtt
 /// # Returns
 /// * `Result<(), Error>` - Success or error
 pub fn revoke_by_delegation(env: &Env, submitter: Address, request: DelegatedRevocationRequest) -> Result<(), Error> {
+    ensure_not_paused(env)?;
     submitter.require_auth();
 
+    let (attest_key, attestation, schema) = check_revocation_request(env, &request)?;
+
+    // Create message for signature verification
+    let message = create_revocation_message(env, &request);
+
+    // Verify BLS12-381 signature
+    verify_bls_signature(env, &message, &request.signature, &request.revoker)?;
+
+    let attestation = apply_revocation(env, &schema, &attest_key, attestation)?;
+    events::publish_revocation_event(env, &attestation);
+    Ok(())
+}
+
+/// Revokes an attestation through a delegated secp256k1 (EVM-style) signature.
+///
+/// Same checks as [`revoke_by_delegation`], but the revoker signs `keccak256` of
+/// [`build_secp256k1_revocation_message`] with the secp256k1 key registered through
+/// `register_secp256k1_signer`. The message binds the attestation UID and the revoker's
+/// nonce, which is consumed, so a signature revokes exactly one attestation once. The
+/// `signature` field inside `request` is ignored.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `submitter` - The address submitting the transaction
+/// * `request` - The delegated revocation request
+/// * `signature` - The 65-byte recoverable secp256k1 signature (`r || s || v`)
+///
+/// # Returns
+/// * `Result<(), Error>` - Success or error
+///
+/// # Errors
+/// * `Error::ExpiredSignature` - If the deadline has passed
+/// * `Error::AttestationNotFound` - If the attestation does not exist or is already revoked
+/// * `Error::NotAuthorized` - If the revoker is not the original attester, or the signed
+///   schema is not the attestation's
+/// * `Error::InvalidSignature` - If the recovered signer does not match
+/// * `Error::Secp256k1KeyNotRegistered` - If the revoker has no registered secp256k1 signer
+/// * `Error::InvalidNonce` - If the nonce was already used or is outside the nonce window
+/// * `Error::ResolverError` - If the schema's resolver rejects the revocation
+pub fn revoke_by_delegation_secp256k1(
+    env: &Env,
+    submitter: Address,
+    request: DelegatedRevocationRequest,
+    signature: BytesN<65>,
+) -> Result<(), Error> {
+    ensure_not_paused(env)?;
+    submitter.require_auth();
+
+    let (attest_key, attestation, schema) = check_revocation_request(env, &request)?;
+
+    // Verify secp256k1 signature over the raw message, then consume its nonce
+    let message = build_secp256k1_revocation_message(env, &request);
+    verify_secp256k1_signature(env, &message, &signature, &request.revoker)?;
+    verify_and_increment_nonce(env, &request.revoker, request.nonce)?;
+
+    let attestation = apply_revocation(env, &schema, &attest_key, attestation)?;
+    events::publish_revocation_event(env, &attestation);
+    Ok(())
+}
+
+/// Checks the deadline, revoker and schema of a delegated revocation request and
+/// returns the attestation it targets with its schema, without writing anything.
+fn check_revocation_request(
+    env: &Env,
+    request: &DelegatedRevocationRequest,
+) -> Result<(DataKey, Attestation, Schema), Error> {
     // Verify deadline hasn't passed
-    let current_time = env.ledger().timestamp();
-    if current_time > request.deadline {
+    if env.ledger().timestamp() > request.deadline {
         return Err(Error::ExpiredSignature);
     }
+    ensure_message_version_accepted(env, request.version)?;
 
     // Get the attestation
     let attest_key = DataKey::AttestationUID(request.attestation_uid.clone());
 
-    let mut attestation = env
+    let attestation = env
         .storage()
         .persistent()
         .get::<DataKey, Attestation>(&attest_key)
         .ok_or(Error::AttestationNotFound)?;
 
+    // Verify the attestation isn't already revoked
+    if attestation.revoked {
+        return Err(Error::AttestationNotFound);
+    }
+
     // Verify the revoker is the original attester
     if attestation.attester != request.revoker {
         return Err(Error::NotAuthorized);
     }
 
-    // Verify schema is revocable
-    let schema = utils::get_schema(env, &request.schema_uid).ok_or(Error::SchemaNotFound)?;
+    // Verify the signed schema is the attestation's own, and that it is revocable
+    if attestation.schema_uid != request.schema_uid {
+        return Err(Error::NotAuthorized);
+    }
+    let schema = utils::get_schema(env, &attestation.schema_uid).ok_or(Error::SchemaNotFound)?;
     if !schema.revocable {
         return Err(Error::AttestationNotRevocable);
     }
+    ensure_min_active_duration(env, &attestation)?;
 
-    // Create message for signature verification
-    let message = create_revocation_message(env, &request);
-
-    // Verify BLS12-381 signature
-    verify_bls_signature(env, &message, &request.signature, &request.revoker)?;
-
-    // Update attestation
-    attestation.revoked = true;
-    attestation.revocation_time = Some(current_time);
-
-    // Store updated attestation
-    env.storage().persistent().set(&attest_key, &attestation);
-
-    // Emit revocation event
-    events::publish_revocation_event(env, &attestation);
-
-    Ok(())
+    Ok((attest_key, attestation, schema))
 }
 
-/// Verifies and increments the nonce for an attester.
-///
-/// # Arguments
-/// * `env` - The Soroban environment
-/// * `attester` - The address of the attester
-/// * `expected_nonce` - The expected nonce value
-///
-/// # Returns
-/// * `Result<(), Error>` - Success or error
-/// **CRITICAL SECURITY FUNCTION**: Verifies and increments the nonce for an attester
+/// **CRITICAL SECURITY FUNCTION**: Verifies and consumes a delegated nonce for an attester
 ///
 /// This function implements the core replay attack protection for delegated attestations.
-/// Each attester has an independent nonce counter that MUST increment sequentially.
-/// This prevents signature replay attacks and ensures attestation ordering.
+/// Each attester has an independent nonce counter plus a bitmap of nonces consumed out of
+/// order, in the style of EIP-2612 permit batching. A relayer can submit requests signed
+/// with nonces `counter..counter + NONCE_WINDOW` in any order without one blocking another.
 ///
 /// # Security Model
-/// - **Nonce Uniqueness**: Each attester has independent nonce sequence (0, 1, 2, ...)
-/// - **Sequential Requirement**: Nonces must be used in exact order (no skipping)
+/// - **Nonce Uniqueness**: Each attester has independent nonce space (0, 1, 2, ...)
+/// - **Bounded Window**: Only nonces below `counter + NONCE_WINDOW` are accepted
 /// - **One-Time Use**: Each nonce can only be used once per attester
-/// - **Atomic Operation**: Verification and increment are atomic (either both succeed or both fail)
+/// - **Atomic Operation**: Verification and consumption are atomic (either both succeed or both fail)
 ///
 /// # Attack Prevention
 /// - **Replay Attacks**: Same signature cannot be used twice
-/// - **Nonce Skipping**: Cannot use future nonces to reserve slots
-/// - **Parallel Processing**: Prevents race conditions in signature submission
-/// - **Ordering Attacks**: Ensures attestations process in signed order
+/// - **Nonce Reservation**: Far-future nonces outside the window are rejected
+/// - **Parallel Submission**: First submission of a nonce wins, later ones fail the nonce check
+/// - **Key Rotation**: Skipping the counter past the window (see `declare_compromise`) invalidates every pending request
 ///
 /// # Parameters
 /// * `env` - Soroban environment for storage operations
 /// * `attester` - Address whose nonce is being verified (original signer)
-/// * `expected_nonce` - The nonce value from the signed request
+/// * `nonce` - The nonce value from the signed request
 ///
 /// # Returns
-/// * `Ok(())` - Nonce verified and incremented successfully
-/// * `Err(Error::InvalidNonce)` - Nonce doesn't match expected value (replay/skip attempt)
+/// * `Ok(())` - Nonce verified and consumed successfully
+/// * `Err(Error::InvalidNonce)` - Nonce already used or outside the window (replay/skip attempt)
 ///
 /// # Critical Invariants
-/// 1. **Monotonic Increment**: Nonces always increase by exactly 1
-/// 2. **No Rollback**: Once incremented, nonce cannot be reset or decreased
-/// 3. **Per-Attester Isolation**: Different attesters have independent nonce sequences
-/// 4. **Storage Consistency**: Nonce updates are persistent and atomic
-///
-/// # Attack Vectors & Mitigations
-/// * **Signature Replay**: Using same signature multiple times
-///   - *Mitigation*: Once nonce is used, it can never be used again
-/// * **Nonce Front-Running**: Submitting signatures out of order
-///   - *Mitigation*: Only exact next nonce is accepted
-/// * **Parallel Submission**: Multiple parties submitting same signed request
-///   - *Mitigation*: First submission wins, subsequent fail nonce check
-/// * **Nonce Prediction**: Attempting to use future nonces
-///   - *Mitigation*: Only current expected nonce accepted
-///
-/// # Implementation Notes
-/// - Nonce starts at 0 for new attesters (first attestation uses nonce 0)
-/// - Each successful verification increments nonce by exactly 1
-/// - Failed verifications don't affect nonce state
-/// - Storage operations are atomic (no partial state possible)
-fn verify_and_increment_nonce(env: &Env, attester: &Address, expected_nonce: u64) -> Result<(), Error> {
-    let nonce_key = DataKey::AttesterNonce(attester.clone());
-
-    // Get current nonce (default to 0 for new attesters)
-    // This creates the starting point for each attester's nonce sequence
-    let current_nonce = env.storage().persistent().get::<DataKey, u64>(&nonce_key).unwrap_or(0);
-
-    // CRITICAL SECURITY CHECK: Verify nonce matches expected value exactly
-    // This prevents replay attacks and ensures sequential processing
-    if current_nonce != expected_nonce {
-        return Err(Error::InvalidNonce);
+/// 1. **Counter Points at an Unused Nonce**: Consuming the counter's nonce advances it past
+///    any nonces already consumed out of order
+/// 2. **No Rollback**: Once consumed, a nonce cannot be reused
+/// 3. **Per-Attester Isolation**: Different attesters have independent nonce spaces
+/// 4. **Shared with Direct Attestations**: Direct attestations take the counter's nonce, which
+///    is never one consumed out of order, so attestation UIDs cannot collide
+fn verify_and_increment_nonce(env: &Env, attester: &Address, nonce: u64) -> Result<(), Error> {
+    let next_nonce = check_nonce_unused(env, attester, nonce)?;
+
+    if nonce == next_nonce {
+        let new_nonce = next_nonce.checked_add(1).ok_or(Error::IntegerOverflow)?;
+        utils::set_next_nonce(env, attester, new_nonce)?;
+    } else {
+        utils::set_nonce_bit(env, attester, nonce);
     }
 
-    // ATOMIC OPERATION: Increment and store new nonce (using checked arithmetic to prevent overflow)
-    // This ensures the nonce can never be used again
-    let new_nonce = current_nonce.checked_add(1).ok_or(Error::IntegerOverflow)?;
-    env.storage().persistent().set(&nonce_key, &new_nonce);
+    Ok(())
+}
 
+/// Returns whether an attester's nonce has been consumed, either by the sequential
+/// counter or out of order by a delegated request.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The address of the attester
+/// * `nonce` - The nonce to check
+///
+/// # Returns
+/// * `bool` - `true` if a request signed with `nonce` can no longer be submitted
+pub fn is_nonce_used(env: &Env, attester: &Address, nonce: u64) -> bool {
+    nonce < utils::get_next_nonce(env, attester) || utils::is_nonce_bit_set(env, attester, nonce)
+}
+
+/// Cancels a signed but unsubmitted delegated request by consuming its nonce.
+///
+/// A leaked request would otherwise stay valid until its deadline; once its nonce is
+/// consumed, any submission of it fails with `InvalidNonce`.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The attester who signed the request. Must authorize the call.
+/// * `nonce` - The nonce of the request to cancel
+///
+/// # Errors
+/// * `Error::InvalidNonce` - If the nonce was already used or is outside the nonce window
+pub fn cancel_delegation_nonce(env: &Env, attester: Address, nonce: u64) -> Result<(), Error> {
+    attester.require_auth();
+
+    verify_and_increment_nonce(env, &attester, nonce)?;
+
+    events::publish_delegation_nonce_cancelled(env, &attester, nonce);
     Ok(())
 }
 
+/// Checks that a delegated nonce is unused and inside the attester's window without
+/// consuming it, returning the attester's current counter.
+fn check_nonce_unused(env: &Env, attester: &Address, nonce: u64) -> Result<u64, Error> {
+    let next_nonce = utils::get_next_nonce(env, attester);
+    if nonce < next_nonce || nonce - next_nonce >= NONCE_WINDOW || utils::is_nonce_bit_set(env, attester, nonce) {
+        return Err(Error::InvalidNonce);
+    }
+    Ok(next_nonce)
+}
+
 /// **CRITICAL CRYPTOGRAPHIC FUNCTION**: Creates deterministic message for BLS signature verification
 ///
 /// This function constructs the exact message that was signed off-chain by the attester.
@@ -243,6 +571,7 @@ fn verify_and_increment_nonce(env: &Env, attester: &Address, expected_nonce: u64
 /// # Message Structure
 /// ```rust,ignore
 /// Domain Separator: "ATTEST_PROTOCOL_V1_DELEGATED" (26 bytes)
+/// Version:          4 bytes (big-endian u32)
 /// Schema UID:       32 bytes
 /// Nonce:            8 bytes (big-endian u64)
 /// Deadline:         8 bytes (big-endian u64)
@@ -285,11 +614,25 @@ fn verify_and_increment_nonce(env: &Env, attester: &Address, expected_nonce: u64
 /// 4. **Edge Cases**: Test with optional fields present/absent
 /// 5. **Encoding Validation**: Verify big-endian encoding consistency
 pub fn create_attestation_message(env: &Env, request: &DelegatedAttestationRequest) -> BytesN<32> {
+    // CRYPTOGRAPHIC HASH: SHA256 of complete message
+    // This hash is what gets signed by BLS private key off-chain
+    env.crypto().sha256(&build_attestation_message(env, request)).into()
+}
+
+/// Builds the raw, domain-separated attestation message bytes.
+///
+/// This is the pre-image hashed by [`create_attestation_message`] for BLS signatures.
+/// See [`create_attestation_message`] for the exact field layout. Secp256k1 signatures
+/// cover [`build_secp256k1_attestation_message`] instead.
+pub fn build_attestation_message(env: &Env, request: &DelegatedAttestationRequest) -> Bytes {
     let mut message = Bytes::new(env);
 
     // DOMAIN SEPARATION: Use the defined constant for clarity and safety.
     message.extend_from_slice(ATTEST_DOMAIN_SEPARATOR);
 
+    // MESSAGE VERSION (4 bytes, big-endian), so each format's signatures stay distinct
+    message.extend_from_slice(&request.version.to_be_bytes());
+
     // FIELD 1: Schema UID (32 bytes, deterministic order)
     message.extend_from_slice(&request.schema_uid.to_array());
 
@@ -316,9 +659,41 @@ pub fn create_attestation_message(env: &Env, request: &DelegatedAttestationReque
     let value_len_bytes = (request.value.len() as u64).to_be_bytes();
     message.extend_from_slice(&value_len_bytes);
 
-    // CRYPTOGRAPHIC HASH: SHA256 of complete message
-    // This hash is what gets signed by BLS private key off-chain
-    env.crypto().sha256(&message).into()
+    message
+}
+
+/// Builds the raw attestation message signed with secp256k1 keys, hashed with `keccak256`.
+///
+/// Unlike [`build_attestation_message`], it binds the subject and a SHA-256 hash of the
+/// value, so a submitter cannot redirect the attestation or swap in a value of the same length.
+///
+/// # Message Structure
+/// ```rust,ignore
+/// Domain Separator: "ATTEST_PROTOCOL_V1_DELEGATED" (28 bytes)
+/// Version:          4 bytes (big-endian u32)
+/// Schema UID:       32 bytes
+/// Subject:          XDR-encoded address
+/// Nonce:            8 bytes (big-endian u64)
+/// Deadline:         8 bytes (big-endian u64)
+/// Expiration Time:  8 bytes (big-endian u64, only if present)
+/// Value Hash:       32 bytes (SHA-256 of the value's UTF-8 bytes)
+/// ```
+pub fn build_secp256k1_attestation_message(env: &Env, request: &DelegatedAttestationRequest) -> Bytes {
+    let mut message = Bytes::new(env);
+
+    message.extend_from_slice(ATTEST_DOMAIN_SEPARATOR);
+    message.extend_from_slice(&request.version.to_be_bytes());
+    message.extend_from_slice(&request.schema_uid.to_array());
+    message.append(&request.subject.clone().to_xdr(env));
+    message.extend_from_slice(&request.nonce.to_be_bytes());
+    message.extend_from_slice(&request.deadline.to_be_bytes());
+    if let Some(exp_time) = request.expiration_time {
+        message.extend_from_slice(&exp_time.to_be_bytes());
+    }
+    let value_hash = env.crypto().sha256(&utils::string_to_bytes(env, &request.value));
+    message.extend_from_slice(&value_hash.to_array());
+
+    message
 }
 
 /// Creates the message to be signed for revocation delegation.
@@ -330,10 +705,19 @@ pub fn create_attestation_message(env: &Env, request: &DelegatedAttestationReque
 /// # Returns
 /// * `BytesN<32>` - The hash of the message to be signed
 pub fn create_revocation_message(env: &Env, request: &DelegatedRevocationRequest) -> BytesN<32> {
+    // Return hash of the complete message
+    env.crypto().sha256(&build_revocation_message(env, request)).into()
+}
+
+/// Builds the raw, domain-separated revocation message bytes.
+///
+/// Hashed with SHA-256 by [`create_revocation_message`] for BLS signatures.
+pub fn build_revocation_message(env: &Env, request: &DelegatedRevocationRequest) -> Bytes {
     let mut message = Bytes::new(env);
 
     // DOMAIN SEPARATION: Use the defined constant.
     message.extend_from_slice(REVOKE_DOMAIN_SEPARATOR);
+    message.extend_from_slice(&request.version.to_be_bytes());
 
     // Encode request data deterministically
     message.extend_from_slice(&request.schema_uid.to_array());
@@ -345,8 +729,84 @@ pub fn create_revocation_message(env: &Env, request: &DelegatedRevocationRequest
     let deadline_bytes = request.deadline.to_be_bytes();
     message.extend_from_slice(&deadline_bytes);
 
-    // Return hash of the complete message
-    env.crypto().sha256(&message).into()
+    message
+}
+
+/// Builds the raw revocation message signed with secp256k1 keys, hashed with `keccak256`.
+///
+/// Unlike [`build_revocation_message`], it binds the attestation UID, so a signature
+/// cannot be replayed against another attestation under the same schema.
+///
+/// # Message Structure
+/// ```rust,ignore
+/// Domain Separator: "REVOKE_PROTOCOL_V1_DELEGATED" (28 bytes)
+/// Version:          4 bytes (big-endian u32)
+/// Schema UID:       32 bytes
+/// Attestation UID:  32 bytes
+/// Nonce:            8 bytes (big-endian u64)
+/// Deadline:         8 bytes (big-endian u64)
+/// ```
+pub fn build_secp256k1_revocation_message(env: &Env, request: &DelegatedRevocationRequest) -> Bytes {
+    let mut message = Bytes::new(env);
+
+    message.extend_from_slice(REVOKE_DOMAIN_SEPARATOR);
+    message.extend_from_slice(&request.version.to_be_bytes());
+    message.extend_from_slice(&request.schema_uid.to_array());
+    message.extend_from_slice(&request.attestation_uid.to_array());
+    message.extend_from_slice(&request.nonce.to_be_bytes());
+    message.extend_from_slice(&request.deadline.to_be_bytes());
+
+    message
+}
+
+/// Sets which delegated message format versions the contract accepts.
+///
+/// Lets a new message format roll out without invalidating requests already signed
+/// with the previous one: both versions are accepted while relayers drain old
+/// requests, and the old version is dropped afterwards.
+///
+/// # Authorization
+/// Requires authorization from the contract admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract admin
+/// * `versions` - The accepted versions, each one of `SUPPORTED_MESSAGE_VERSIONS`
+///
+/// # Errors
+/// * `Error::NotAuthorized` - If the caller is not the admin
+/// * `Error::UnsupportedMessageVersion` - If `versions` is empty or lists a version this build cannot verify
+pub fn set_accepted_message_versions(env: &Env, admin: Address, versions: Vec<u32>) -> Result<(), Error> {
+    utils::require_admin(env, &admin)?;
+
+    if versions.is_empty()
+        || versions
+            .iter()
+            .any(|version| !SUPPORTED_MESSAGE_VERSIONS.contains(&version))
+    {
+        return Err(Error::UnsupportedMessageVersion);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::AcceptedMessageVersions, &versions);
+    Ok(())
+}
+
+/// Returns the delegated message format versions the contract accepts.
+///
+/// Defaults to [`MESSAGE_VERSION`] alone until the admin configures the set.
+pub fn get_accepted_message_versions(env: &Env) -> Vec<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AcceptedMessageVersions)
+        .unwrap_or_else(|| Vec::from_array(env, [MESSAGE_VERSION]))
+}
+
+fn ensure_message_version_accepted(env: &Env, version: u32) -> Result<(), Error> {
+    if !get_accepted_message_versions(env).contains(version) {
+        return Err(Error::UnsupportedMessageVersion);
+    }
+    Ok(())
 }
 
 /// Returns the domain separation tag used for creating delegated attestation signatures.
@@ -0,0 +1,129 @@
+use crate::errors::Error;
+use crate::instructions::get_schema_definition;
+use crate::state::{Attestation, DataKey};
+use crate::utils::string_to_bytes;
+use soroban_sdk::{Bytes, BytesN, Env, String, Vec};
+
+/// Compares the structured fields of two attestations under the same schema.
+///
+/// Field names come from the schema's `XDR:` definition; each field's raw JSON
+/// value is extracted from both attestation values and compared byte for byte. A
+/// field present in one value and absent from the other counts as differing.
+/// Revoked and expired attestations can be compared, for amendment chains and audits.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `uid_a` - The first attestation UID
+/// * `uid_b` - The second attestation UID
+///
+/// # Returns
+/// * `Result<Vec<String>, Error>` - Names of the differing fields, in schema order
+///
+/// # Errors
+/// * `Error::AttestationNotFound` - If either attestation does not exist
+/// * `Error::SchemaMismatch` - If the attestations use different schemas
+/// * `Error::SchemaNotFound` - If the shared schema does not exist
+/// * `Error::InvalidSchemaDefinition` - If the schema's definition is not XDR-encoded
+/// * `Error::InvalidAttestationValue` - If either value is encrypted
+pub fn diff_attestations(env: &Env, uid_a: BytesN<32>, uid_b: BytesN<32>) -> Result<Vec<String>, Error> {
+    let a = load_attestation(env, uid_a)?;
+    let b = load_attestation(env, uid_b)?;
+    if a.schema_uid != b.schema_uid {
+        return Err(Error::SchemaMismatch);
+    }
+    if a.encryption_scheme.is_some() || b.encryption_scheme.is_some() {
+        return Err(Error::InvalidAttestationValue);
+    }
+
+    let definition = get_schema_definition(env, &a.schema_uid)?;
+    let value_a = string_to_bytes(env, &a.value);
+    let value_b = string_to_bytes(env, &b.value);
+
+    let mut differing = Vec::new(env);
+    for field in definition.fields.iter() {
+        let key = string_to_bytes(env, &field.name);
+        if extract_json_value(&value_a, &key) != extract_json_value(&value_b, &key) {
+            differing.push_back(field.name);
+        }
+    }
+    Ok(differing)
+}
+
+fn load_attestation(env: &Env, uid: BytesN<32>) -> Result<Attestation, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AttestationUID(uid))
+        .ok_or(Error::AttestationNotFound)
+}
+
+/// Returns the raw JSON value of `key` in a flat or nested JSON object, if present.
+///
+/// Matches the first occurrence of `"key"` that is followed by a colon, so string
+/// values equal to the key are not mistaken for it.
+pub(crate) fn extract_json_value(json: &Bytes, key: &Bytes) -> Option<Bytes> {
+    let len = json.len();
+    let key_len = key.len();
+    let mut i = 0;
+    while i + key_len + 2 <= len {
+        if json.get_unchecked(i) == b'"'
+            && json.get_unchecked(i + key_len + 1) == b'"'
+            && json.slice(i + 1..i + 1 + key_len) == *key
+        {
+            let mut pos = skip_whitespace(json, i + key_len + 2);
+            if pos < len && json.get_unchecked(pos) == b':' {
+                pos = skip_whitespace(json, pos + 1);
+                let end = value_end(json, pos)?;
+                return Some(json.slice(pos..end));
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+fn skip_whitespace(json: &Bytes, mut pos: u32) -> u32 {
+    while pos < json.len() && matches!(json.get_unchecked(pos), b' ' | b'\t' | b'\n' | b'\r') {
+        pos += 1;
+    }
+    pos
+}
+
+/// Returns the index just past the JSON value starting at `start`.
+fn value_end(json: &Bytes, start: u32) -> Option<u32> {
+    let len = json.len();
+    let mut depth = 0u32;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut pos = start;
+    while pos < len {
+        let c = json.get_unchecked(pos);
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == b'\\' {
+                escaped = true;
+            } else if c == b'"' {
+                in_string = false;
+                if depth == 0 {
+                    return Some(pos + 1);
+                }
+            }
+        } else {
+            match c {
+                b'"' => in_string = true,
+                b'{' | b'[' => depth += 1,
+                b'}' | b']' if depth == 0 => return Some(pos),
+                b'}' | b']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(pos + 1);
+                    }
+                },
+                b',' | b' ' | b'\t' | b'\n' | b'\r' if depth == 0 => return Some(pos),
+                _ => {},
+            }
+        }
+        pos += 1;
+    }
+    (depth == 0 && !in_string && pos > start).then_some(pos)
+}
@@ -0,0 +1,106 @@
+use crate::errors::Error;
+use crate::events;
+use crate::state::{AttesterDomain, DataKey, PendingAttesterDomain};
+use crate::utils;
+use soroban_sdk::{Address, BytesN, Env, String};
+
+/// Longest home domain accepted, matching the DNS name length limit.
+pub const MAX_DOMAIN_LEN: u32 = 253;
+
+/// Requests that an attester be bound to a home domain.
+///
+/// The attester publishes a token in the `stellar.toml` hosted on `domain` and submits
+/// its SHA-256 hash as `challenge_hash`. The binding takes effect once the admin, acting
+/// as the verification oracle, fetches the token and confirms it with
+/// `verify_attester_domain`. A new request replaces any pending one; a verified binding
+/// stays in place until the new domain is verified.
+///
+/// # Authorization
+/// Requires authorization from the attester.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The attester claiming the domain
+/// * `domain` - The home domain, e.g. `university.edu`
+/// * `challenge_hash` - SHA-256 hash of the token hosted on the domain
+///
+/// # Errors
+/// * `Error::InvalidDomain` - If the domain is empty or longer than `MAX_DOMAIN_LEN`
+pub fn request_attester_domain(
+    env: &Env,
+    attester: Address,
+    domain: String,
+    challenge_hash: BytesN<32>,
+) -> Result<(), Error> {
+    attester.require_auth();
+
+    if domain.is_empty() || domain.len() > MAX_DOMAIN_LEN {
+        return Err(Error::InvalidDomain);
+    }
+
+    let pending = PendingAttesterDomain {
+        domain,
+        challenge_hash,
+        requested_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::PendingAttesterDomain(attester.clone()), &pending);
+
+    events::publish_attester_domain_requested(env, &attester, &pending.domain);
+    Ok(())
+}
+
+/// Confirms a pending domain binding after checking the token hosted on the domain.
+///
+/// # Authorization
+/// Requires authorization from the contract admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract admin
+/// * `attester` - The attester whose pending binding is confirmed
+/// * `token_hash` - SHA-256 hash of the token found in the domain's `stellar.toml`
+///
+/// # Errors
+/// * `Error::NotAuthorized` - If the caller is not the admin
+/// * `Error::DomainChallengeMismatch` - If there is no pending binding or the hash differs
+pub fn verify_attester_domain(
+    env: &Env,
+    admin: Address,
+    attester: Address,
+    token_hash: BytesN<32>,
+) -> Result<(), Error> {
+    utils::require_admin(env, &admin)?;
+
+    let pending_key = DataKey::PendingAttesterDomain(attester.clone());
+    let pending: PendingAttesterDomain = env
+        .storage()
+        .persistent()
+        .get(&pending_key)
+        .ok_or(Error::DomainChallengeMismatch)?;
+    if pending.challenge_hash != token_hash {
+        return Err(Error::DomainChallengeMismatch);
+    }
+
+    let binding = AttesterDomain {
+        domain: pending.domain,
+        challenge_hash: pending.challenge_hash,
+        verified_at: env.ledger().timestamp(),
+        verified_by: admin,
+    };
+    env.storage().persistent().remove(&pending_key);
+    env.storage()
+        .persistent()
+        .set(&DataKey::AttesterDomain(attester.clone()), &binding);
+
+    events::publish_attester_domain_verified(env, &attester, &binding.domain);
+    Ok(())
+}
+
+/// Gets the verified home domain of an attester, if any.
+pub fn get_attester_domain(env: &Env, attester: &Address) -> Option<AttesterDomain> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AttesterDomain(attester.clone()))
+}
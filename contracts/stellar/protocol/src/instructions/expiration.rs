@@ -0,0 +1,114 @@
+use crate::errors::Error;
+use crate::instructions::schema::get_schema_or_fail;
+use crate::state::DataKey;
+use crate::utils;
+use soroban_sdk::{Address, BytesN, Env};
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Sets the protocol-wide cap on how far ahead an attestation may expire.
+////////////////////////////////////////////////////////////////////////////////////
+/// While set, `attest` and the delegated flows reject attestations whose
+/// `expiration_time` lies more than `seconds` after the current ledger time. Bounding
+/// expirations keeps storage rent projections finite and forces credentials to be
+/// renewed periodically. Attestations without an expiration are not affected.
+///
+/// # Authorization
+/// Requires authorization from the contract admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `admin` - The contract admin.
+/// * `seconds` - The maximum horizon in seconds. `0` removes the cap.
+///
+/// # Errors
+/// * `Error::AdminNotSet` - If the contract has not been initialized.
+/// * `Error::NotAuthorized` - If the caller is not the admin.
+pub fn set_expiration_horizon(env: &Env, admin: Address, seconds: u64) -> Result<(), Error> {
+    utils::require_admin(env, &admin)?;
+
+    if seconds == 0 {
+        env.storage().instance().remove(&DataKey::ExpirationHorizon);
+    } else {
+        env.storage().instance().set(&DataKey::ExpirationHorizon, &seconds);
+    }
+    Ok(())
+}
+
+/// Returns the protocol-wide expiration horizon in seconds, or 0 if uncapped.
+pub fn get_expiration_horizon(env: &Env) -> u64 {
+    env.storage().instance().get(&DataKey::ExpirationHorizon).unwrap_or(0)
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Sets a schema's cap on how far ahead its attestations may expire.
+////////////////////////////////////////////////////////////////////////////////////
+/// Applies on top of the protocol-wide cap: when both are set, the tighter one wins.
+///
+/// # Authorization
+/// Requires authorization from the schema authority.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `caller` - The schema authority.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+/// * `seconds` - The maximum horizon in seconds. `0` removes the cap.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+/// * `Error::NotAuthorized` - If the caller is not the schema authority.
+pub fn set_schema_expiration_horizon(
+    env: &Env,
+    caller: Address,
+    schema_uid: BytesN<32>,
+    seconds: u64,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    let schema = get_schema_or_fail(env, &schema_uid)?;
+    if schema.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+
+    let key = DataKey::SchemaExpirationHorizon(schema_uid);
+    if seconds == 0 {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, &seconds);
+    }
+    Ok(())
+}
+
+/// Returns a schema's own expiration horizon in seconds, or 0 if uncapped.
+pub fn get_schema_expiration_horizon(env: &Env, schema_uid: &BytesN<32>) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaExpirationHorizon(schema_uid.clone()))
+        .unwrap_or(0)
+}
+
+/// Rejects an expiration time beyond the tighter of the protocol and schema horizons.
+///
+/// # Errors
+/// * `Error::ExpirationTooFar` - If `expiration_time` exceeds an effective horizon
+pub(crate) fn ensure_expiration_within_horizon(
+    env: &Env,
+    schema_uid: &BytesN<32>,
+    expiration_time: Option<u64>,
+) -> Result<(), Error> {
+    let Some(expiration_time) = expiration_time else {
+        return Ok(());
+    };
+    let horizon = [
+        get_expiration_horizon(env),
+        get_schema_expiration_horizon(env, schema_uid),
+    ]
+    .into_iter()
+    .filter(|&seconds| seconds > 0)
+    .min();
+    match horizon {
+        Some(horizon) if expiration_time > env.ledger().timestamp().saturating_add(horizon) => {
+            Err(Error::ExpirationTooFar)
+        },
+        _ => Ok(()),
+    }
+}
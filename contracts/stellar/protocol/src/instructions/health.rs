@@ -0,0 +1,85 @@
+use crate::instructions::council::{get_admin_council, validate_council};
+use crate::instructions::listing::is_listing_consistent;
+use crate::instructions::outbox::{get_outbox_head, OUTBOX_CAPACITY};
+use crate::instructions::schema::load_protocol_metrics;
+use crate::state::{Attestation, DataKey, ListingKey, OutboxEntry};
+use soroban_sdk::{symbol_short, Env, Symbol, Vec};
+
+/// Runs the protocol's invariant checks and returns the names of those that fail.
+///
+/// An empty result means every check passed. Checks:
+/// * `ADMIN` - The admin is set
+/// * `COUNCIL` - The admin council has distinct members and a reachable threshold
+/// * `METRICS` - Revocations do not exceed attestations, active schemas do not exceed schemas
+/// * `OUTBOX` - Every retained outbox slot holds the entry its sequence number maps to
+/// * `RECORD` - Sampled attestations still stored match the outbox entries referencing them
+/// * `LISTING` - Sampled listings have an entry at every position below their count and none beyond
+///
+/// Sampled checks cover the attestations in the retained outbox, i.e. the most recent
+/// `OUTBOX_CAPACITY` attestation changes.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+///
+/// # Returns
+/// * `Vec<Symbol>` - The names of the failed checks, in the order above
+pub fn self_check(env: &Env) -> Vec<Symbol> {
+    let mut failed = Vec::new(env);
+
+    if !env.storage().instance().has(&DataKey::Admin) {
+        failed.push_back(symbol_short!("ADMIN"));
+    }
+
+    let council_valid = match get_admin_council(env) {
+        Ok(council) => validate_council(&council.members, council.threshold).is_ok(),
+        Err(_) => false,
+    };
+    if !council_valid {
+        failed.push_back(symbol_short!("COUNCIL"));
+    }
+
+    let metrics = load_protocol_metrics(env);
+    if metrics.total_revocations > metrics.total_attestations || metrics.active_schemas > metrics.total_schemas {
+        failed.push_back(symbol_short!("METRICS"));
+    }
+
+    let head = get_outbox_head(env);
+    let oldest_retained = head.saturating_sub(OUTBOX_CAPACITY) + 1;
+    let (mut outbox_valid, mut records_valid, mut listings_valid) = (true, true, true);
+    for seq in oldest_retained..=head {
+        let entry = env
+            .storage()
+            .persistent()
+            .get::<DataKey, OutboxEntry>(&DataKey::OutboxEntry(seq % OUTBOX_CAPACITY));
+        let Some(entry) = entry.filter(|entry| entry.seq == seq) else {
+            outbox_valid = false;
+            continue;
+        };
+
+        // Purged attestations are legitimately missing
+        if let Some(attestation) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Attestation>(&DataKey::AttestationUID(entry.attestation_uid.clone()))
+        {
+            records_valid &= attestation.schema_uid == entry.schema_uid
+                && attestation.subject == entry.subject
+                && attestation.attester == entry.attester;
+        }
+
+        listings_valid &= is_listing_consistent(env, ListingKey::Subject(entry.subject))
+            && is_listing_consistent(env, ListingKey::Schema(entry.schema_uid))
+            && is_listing_consistent(env, ListingKey::Attester(entry.attester));
+    }
+    if !outbox_valid {
+        failed.push_back(symbol_short!("OUTBOX"));
+    }
+    if !records_valid {
+        failed.push_back(symbol_short!("RECORD"));
+    }
+    if !listings_valid {
+        failed.push_back(symbol_short!("LISTING"));
+    }
+
+    failed
+}
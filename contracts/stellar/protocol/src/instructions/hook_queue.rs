@@ -0,0 +1,152 @@
+use crate::errors::Error;
+use crate::events;
+use crate::instructions::attestation::{call_resolver_onattest, call_resolver_onresolve, create_resolver_attestation};
+use crate::instructions::record_outbox_entry;
+use crate::instructions::schema::{get_schema_or_fail, record_schema_revocation};
+use crate::state::{Attestation, DataKey, OutboxAction};
+use crate::utils;
+use soroban_sdk::{Address, BytesN, Env};
+
+/// Maximum number of queue entries a single `process_hook_queue` call works through.
+pub const MAX_HOOK_BATCH: u32 = 25;
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Defers a schema's resolver hooks to the hook queue, or restores inline hooks.
+////////////////////////////////////////////////////////////////////////////////////
+/// With deferred hooks, `attest` stores the attestation without calling the resolver
+/// and enqueues it instead; `process_hook_queue` later runs `onattest` and `onresolve`,
+/// revoking the attestation if the resolver rejects it. Slow or expensive resolvers then
+/// no longer add to the latency and fee of the attest transaction, at the cost of the
+/// attestation reading as valid until its hooks have run.
+///
+/// Entries already queued are processed even after deferral is turned off.
+///
+/// # Authorization
+/// Requires authorization from the schema authority.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `caller` - The schema authority.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+/// * `deferred` - Whether the schema's hooks are deferred.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+/// * `Error::NotAuthorized` - If the caller is not the schema authority.
+pub fn set_schema_deferred_hooks(
+    env: &Env,
+    caller: Address,
+    schema_uid: BytesN<32>,
+    deferred: bool,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    let schema = get_schema_or_fail(env, &schema_uid)?;
+    if schema.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+
+    let key = DataKey::SchemaDeferredHooks(schema_uid);
+    if deferred {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+    Ok(())
+}
+
+/// Returns whether a schema's resolver hooks are deferred to the hook queue.
+pub fn is_schema_hooks_deferred(env: &Env, schema_uid: &BytesN<32>) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaDeferredHooks(schema_uid.clone()))
+        .unwrap_or(false)
+}
+
+/// Returns the number of attestations waiting for their deferred hooks to run.
+pub fn get_hook_queue_length(env: &Env) -> u64 {
+    let (head, tail) = queue_bounds(env);
+    tail - head
+}
+
+/// Appends a stored attestation to the hook queue.
+pub(crate) fn enqueue_hooks(env: &Env, attestation_uid: &BytesN<32>) {
+    let (_, tail) = queue_bounds(env);
+    env.storage()
+        .persistent()
+        .set(&DataKey::HookQueueEntry(tail), attestation_uid);
+    env.storage().instance().set(&DataKey::HookQueueTail, &(tail + 1));
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Runs the deferred resolver hooks of the oldest queued attestations.
+////////////////////////////////////////////////////////////////////////////////////
+/// Permissionless: anyone can crank the queue, and entries are processed in the
+/// order they were enqueued. For each entry the schema resolver's `onattest` hook
+/// runs first; if it rejects the attestation or fails, the attestation is revoked
+/// (without an `onrevoke` call, since the resolver never accepted it) and a
+/// revocation event is emitted. Otherwise `onresolve` runs, as it would inline.
+///
+/// Attestations that were revoked, expired or purged while queued are dropped
+/// without calling the resolver.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `limit` - Maximum number of entries to process, capped at `MAX_HOOK_BATCH`.
+///
+/// # Returns
+/// * `u32` - The number of entries removed from the queue.
+pub fn process_hook_queue(env: &Env, limit: u32) -> u32 {
+    let (mut head, tail) = queue_bounds(env);
+    let mut processed = 0;
+    while head < tail && processed < limit.min(MAX_HOOK_BATCH) {
+        let entry_key = DataKey::HookQueueEntry(head);
+        if let Some(attestation_uid) = env.storage().persistent().get::<DataKey, BytesN<32>>(&entry_key) {
+            run_deferred_hooks(env, &attestation_uid);
+        }
+        env.storage().persistent().remove(&entry_key);
+        head += 1;
+        processed += 1;
+    }
+    env.storage().instance().set(&DataKey::HookQueueHead, &head);
+    processed
+}
+
+fn queue_bounds(env: &Env) -> (u64, u64) {
+    let storage = env.storage().instance();
+    (
+        storage.get(&DataKey::HookQueueHead).unwrap_or(0),
+        storage.get(&DataKey::HookQueueTail).unwrap_or(0),
+    )
+}
+
+fn run_deferred_hooks(env: &Env, attestation_uid: &BytesN<32>) {
+    let attest_key = DataKey::AttestationUID(attestation_uid.clone());
+    let Some(mut attestation) = env.storage().persistent().get::<DataKey, Attestation>(&attest_key) else {
+        return;
+    };
+    let expired = attestation
+        .expiration_time
+        .is_some_and(|expiration| env.ledger().timestamp() > expiration);
+    if attestation.revoked || expired {
+        return;
+    }
+    let Some(resolver_address) = utils::get_schema(env, &attestation.schema_uid).and_then(|schema| schema.resolver)
+    else {
+        return;
+    };
+
+    let resolver_attestation =
+        create_resolver_attestation(env, &attestation, &attestation.schema_uid, &attestation.value);
+    if call_resolver_onattest(env, &resolver_address, &resolver_attestation) == Ok(true) {
+        call_resolver_onresolve(env, &resolver_address, &resolver_attestation);
+        return;
+    }
+
+    attestation.revoked = true;
+    attestation.revocation_time = Some(env.ledger().timestamp());
+    env.storage().persistent().set(&attest_key, &attestation);
+    record_schema_revocation(env, &attestation.schema_uid);
+    record_outbox_entry(env, OutboxAction::Revoked, &attestation);
+    events::publish_revocation_event(env, &attestation);
+}
@@ -0,0 +1,340 @@
+use crate::errors::Error;
+use crate::state::{Attestation, AttestationPage, DataKey, ExtendedKey, ListingKey, SubjectType};
+use crate::utils::subject_type;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Symbol, Vec};
+
+/// Maximum number of attestation UIDs returned per page.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Appends an attestation to its subject, schema, schema-by-subject-type and attester listings,
+/// to its schema-by-locale listing when it has a locale, and to the listing of the attestation
+/// it references, if any.
+pub(crate) fn index_attestation_listings(env: &Env, attestation: &Attestation) {
+    let mut listings = Vec::from_array(
+        env,
+        [
+            ListingKey::Subject(attestation.subject.clone()),
+            ListingKey::Schema(attestation.schema_uid.clone()),
+            ListingKey::SchemaSubjectType(attestation.schema_uid.clone(), subject_type(env, &attestation.subject)),
+            ListingKey::Attester(attestation.attester.clone()),
+        ],
+    );
+    if let Some(locale) = &attestation.locale {
+        listings.push_back(ListingKey::SchemaLocale(attestation.schema_uid.clone(), locale.clone()));
+    }
+    if let Some(ref_uid) = &attestation.ref_uid {
+        listings.push_back(ListingKey::Referencing(ref_uid.clone()));
+    }
+
+    for listing in listings.iter() {
+        let position = append_listing(env, listing.clone(), &attestation.uid);
+        track_index_key(env, &attestation.uid, DataKey::Listing(listing, position));
+    }
+}
+
+/// Appends a UID to a listing, returning the position it was written at.
+pub(crate) fn append_listing(env: &Env, listing: ListingKey, uid: &BytesN<32>) -> u32 {
+    let count_key = DataKey::ListingCount(listing.clone());
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    env.storage().persistent().set(&DataKey::Listing(listing, count), uid);
+    env.storage().persistent().set(&count_key, &(count + 1));
+    count
+}
+
+/// Records a listing or lookup entry pointing at an attestation, so it is removed along
+/// with the attestation when it is purged.
+pub(crate) fn track_index_key(env: &Env, attestation_uid: &BytesN<32>, key: DataKey) {
+    let tracked_key = DataKey::Extended(ExtendedKey::AttestationIndexKeys(attestation_uid.clone()));
+    let mut keys: Vec<DataKey> = env
+        .storage()
+        .persistent()
+        .get(&tracked_key)
+        .unwrap_or_else(|| Vec::new(env));
+    keys.push_back(key);
+    env.storage().persistent().set(&tracked_key, &keys);
+}
+
+/// Removes the listing and lookup entries recorded for an attestation by `track_index_key`.
+///
+/// Lookup entries that have since moved on to a newer attestation are left in place.
+/// Removed listing entries leave a hole at their position, so cursors stay valid.
+pub(crate) fn remove_index_keys(env: &Env, attestation_uid: &BytesN<32>) {
+    let tracked_key = DataKey::Extended(ExtendedKey::AttestationIndexKeys(attestation_uid.clone()));
+    let keys: Vec<DataKey> = env
+        .storage()
+        .persistent()
+        .get(&tracked_key)
+        .unwrap_or_else(|| Vec::new(env));
+    for key in keys.iter() {
+        if env.storage().persistent().get::<DataKey, BytesN<32>>(&key).as_ref() == Some(attestation_uid) {
+            env.storage().persistent().remove(&key);
+        }
+    }
+    env.storage().persistent().remove(&tracked_key);
+}
+
+/// Returns the number of entries in a listing.
+pub(crate) fn listing_len(env: &Env, listing: &ListingKey) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ListingCount(listing.clone()))
+        .unwrap_or(0)
+}
+
+/// Returns every entry in a listing, oldest first. Only for listings known to stay short.
+pub(crate) fn listing_entries(env: &Env, listing: &ListingKey) -> Vec<BytesN<32>> {
+    let mut uids = Vec::new(env);
+    for index in 0..listing_len(env, listing) {
+        if let Some(uid) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, BytesN<32>>(&DataKey::Listing(listing.clone(), index))
+        {
+            uids.push_back(uid);
+        }
+    }
+    uids
+}
+
+/// Returns true if a listing has no entry at the position its count says is next.
+///
+/// Purged attestations leave holes anywhere below the count, so only the position at the
+/// count is read, which also keeps the check cheap on long listings.
+pub(crate) fn is_listing_consistent(env: &Env, listing: ListingKey) -> bool {
+    let count: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ListingCount(listing.clone()))
+        .unwrap_or(0);
+    !env.storage().persistent().has(&DataKey::Listing(listing, count))
+}
+
+/// Lists the UIDs of attestations about a subject, oldest first.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `subject` - The subject address
+/// * `cursor` - The `next_cursor` of the previous page, or empty to start from the beginning
+/// * `limit` - Maximum number of UIDs to return, capped at `MAX_PAGE_SIZE`
+///
+/// # Errors
+/// * `Error::InvalidCursor` - If `cursor` was not returned by this listing
+pub fn list_subject_attestations(
+    env: &Env,
+    subject: Address,
+    cursor: Bytes,
+    limit: u32,
+) -> Result<AttestationPage, Error> {
+    read_listing(env, ListingKey::Subject(subject), cursor, limit)
+}
+
+/// Lists the UIDs of attestations issued under a schema, oldest first.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `schema_uid` - The unique identifier of the schema
+/// * `cursor` - The `next_cursor` of the previous page, or empty to start from the beginning
+/// * `limit` - Maximum number of UIDs to return, capped at `MAX_PAGE_SIZE`
+///
+/// # Errors
+/// * `Error::InvalidCursor` - If `cursor` was not returned by this listing
+pub fn list_schema_attestations(
+    env: &Env,
+    schema_uid: BytesN<32>,
+    cursor: Bytes,
+    limit: u32,
+) -> Result<AttestationPage, Error> {
+    read_listing(env, ListingKey::Schema(schema_uid), cursor, limit)
+}
+
+/// Lists the UIDs of attestations issued under a schema about subjects of one type, oldest first.
+///
+/// Separates attestations about contracts, such as audits and risk scores, from those
+/// about accounts without paging through the whole schema.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `schema_uid` - The unique identifier of the schema
+/// * `subject_type` - Whether to list attestations about accounts or contracts
+/// * `cursor` - The `next_cursor` of the previous page, or empty to start from the beginning
+/// * `limit` - Maximum number of UIDs to return, capped at `MAX_PAGE_SIZE`
+///
+/// # Errors
+/// * `Error::InvalidCursor` - If `cursor` was not returned by this listing
+pub fn list_schema_attestations_by_type(
+    env: &Env,
+    schema_uid: BytesN<32>,
+    subject_type: SubjectType,
+    cursor: Bytes,
+    limit: u32,
+) -> Result<AttestationPage, Error> {
+    read_listing(
+        env,
+        ListingKey::SchemaSubjectType(schema_uid, subject_type),
+        cursor,
+        limit,
+    )
+}
+
+/// Lists the UIDs of attestations issued under a schema in one locale, oldest first.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `schema_uid` - The unique identifier of the schema
+/// * `locale` - The locale the attestations were written in
+/// * `cursor` - The `next_cursor` of the previous page, or empty to start from the beginning
+/// * `limit` - Maximum number of UIDs to return, capped at `MAX_PAGE_SIZE`
+///
+/// # Errors
+/// * `Error::InvalidCursor` - If `cursor` was not returned by this listing
+pub fn list_attestations_by_locale(
+    env: &Env,
+    schema_uid: BytesN<32>,
+    locale: Symbol,
+    cursor: Bytes,
+    limit: u32,
+) -> Result<AttestationPage, Error> {
+    read_listing(env, ListingKey::SchemaLocale(schema_uid, locale), cursor, limit)
+}
+
+/// Lists the UIDs of attestations that reference an attestation, oldest first.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attestation_uid` - The UID of the referenced attestation
+/// * `cursor` - The `next_cursor` of the previous page, or empty to start from the beginning
+/// * `limit` - Maximum number of UIDs to return, capped at `MAX_PAGE_SIZE`
+///
+/// # Errors
+/// * `Error::InvalidCursor` - If `cursor` was not returned by this listing
+pub fn get_referencing_attestations(
+    env: &Env,
+    attestation_uid: BytesN<32>,
+    cursor: Bytes,
+    limit: u32,
+) -> Result<AttestationPage, Error> {
+    read_listing(env, ListingKey::Referencing(attestation_uid), cursor, limit)
+}
+
+/// Lists the UIDs of attestations issued by an attester, oldest first.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attester` - The attester address
+/// * `cursor` - The `next_cursor` of the previous page, or empty to start from the beginning
+/// * `limit` - Maximum number of UIDs to return, capped at `MAX_PAGE_SIZE`
+///
+/// # Errors
+/// * `Error::InvalidCursor` - If `cursor` was not returned by this listing
+pub fn list_attester_attestations(
+    env: &Env,
+    attester: Address,
+    cursor: Bytes,
+    limit: u32,
+) -> Result<AttestationPage, Error> {
+    read_listing(env, ListingKey::Attester(attester), cursor, limit)
+}
+
+/// Draws a deterministic pseudo-random sample of the attestations issued under a schema.
+///
+/// Lets auditors spot-check issuance quality without paging through the whole schema.
+/// Positions are drawn without replacement from the schema listing using Floyd's
+/// algorithm, with `sha256(seed || draw)` as the randomness, so the same seed over the
+/// same listing always yields the same sample. Appending attestations changes the
+/// population, and therefore the sample; auditors should commit to a seed only once the
+/// population they audit is fixed.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `schema_uid` - The unique identifier of the schema
+/// * `seed` - The seed the sample is derived from
+/// * `count` - Number of UIDs to sample, capped at `MAX_PAGE_SIZE` and the listing length
+///
+/// # Returns
+/// * `Vec<BytesN<32>>` - The sampled UIDs, oldest first
+pub fn sample_attestations(env: &Env, schema_uid: BytesN<32>, seed: BytesN<32>, count: u32) -> Vec<BytesN<32>> {
+    let listing = ListingKey::Schema(schema_uid);
+    let population: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ListingCount(listing.clone()))
+        .unwrap_or(0);
+    let count = count.min(MAX_PAGE_SIZE).min(population);
+
+    // Floyd's algorithm: one draw per sampled position, kept sorted by listing order
+    let mut positions: Vec<u32> = Vec::new(env);
+    for upper in population - count..population {
+        let drawn = (random_u64(env, &seed, upper) % (upper as u64 + 1)) as u32;
+        let position = if positions.contains(drawn) { upper } else { drawn };
+        let index = positions.binary_search(position).unwrap_or_else(|index| index);
+        positions.insert(index, position);
+    }
+
+    let mut uids = Vec::new(env);
+    for position in positions.iter() {
+        if let Some(uid) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, BytesN<32>>(&DataKey::Listing(listing.clone(), position))
+        {
+            uids.push_back(uid);
+        }
+    }
+    uids
+}
+
+fn random_u64(env: &Env, seed: &BytesN<32>, draw: u32) -> u64 {
+    let mut preimage = Bytes::from_array(env, &seed.to_array());
+    preimage.extend_from_array(&draw.to_be_bytes());
+    let digest = env.crypto().sha256(&preimage).to_array();
+    let mut word = [0u8; 8];
+    word.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(word)
+}
+
+/// Reads a page of a listing.
+///
+/// Listings are append-only, so a cursor is the position after the last entry returned.
+/// Attestations appended while a caller is paging land after that position: pages never
+/// shift, and the final cursor can be kept to fetch only newer entries later.
+fn read_listing(env: &Env, listing: ListingKey, cursor: Bytes, limit: u32) -> Result<AttestationPage, Error> {
+    let count: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ListingCount(listing.clone()))
+        .unwrap_or(0);
+    let start = decode_cursor(&cursor)?;
+    if start > count {
+        return Err(Error::InvalidCursor);
+    }
+    let end = core::cmp::min(count, start.saturating_add(core::cmp::min(limit, MAX_PAGE_SIZE)));
+
+    let mut uids = Vec::new(env);
+    for index in start..end {
+        if let Some(uid) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, BytesN<32>>(&DataKey::Listing(listing.clone(), index))
+        {
+            uids.push_back(uid);
+        }
+    }
+
+    Ok(AttestationPage {
+        uids,
+        next_cursor: Bytes::from_array(env, &end.to_be_bytes()),
+        has_more: end < count,
+    })
+}
+
+fn decode_cursor(cursor: &Bytes) -> Result<u32, Error> {
+    if cursor.is_empty() {
+        return Ok(0);
+    }
+    if cursor.len() != 4 {
+        return Err(Error::InvalidCursor);
+    }
+    let mut position = [0u8; 4];
+    cursor.copy_into_slice(&mut position);
+    Ok(u32::from_be_bytes(position))
+}
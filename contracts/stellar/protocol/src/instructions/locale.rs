@@ -0,0 +1,68 @@
+use crate::errors::Error;
+use crate::instructions::schema::get_schema_or_fail;
+use crate::state::{DataKey, ExtendedKey};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Sets the locales a schema's attestations may be written in.
+////////////////////////////////////////////////////////////////////////////////////
+/// While set, `attest_with_locale` rejects locales outside the list, so multi-language
+/// credential products only ever see locales they can render. Attestations without a
+/// locale are not affected.
+///
+/// # Authorization
+/// Requires authorization from the schema authority.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `caller` - The schema authority.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+/// * `locales` - The allowed locales, e.g. `en` and `pt_BR`. Empty allows any locale.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+/// * `Error::NotAuthorized` - If the caller is not the schema authority.
+pub fn set_schema_locales(
+    env: &Env,
+    caller: Address,
+    schema_uid: BytesN<32>,
+    locales: Vec<Symbol>,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    let schema = get_schema_or_fail(env, &schema_uid)?;
+    if schema.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+
+    let key = DataKey::Extended(ExtendedKey::SchemaLocales(schema_uid));
+    if locales.is_empty() {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, &locales);
+    }
+    Ok(())
+}
+
+/// Returns the locales a schema's attestations may be written in, or an empty list if any is allowed.
+pub fn get_schema_locales(env: &Env, schema_uid: &BytesN<32>) -> Vec<Symbol> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Extended(ExtendedKey::SchemaLocales(schema_uid.clone())))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Rejects a locale outside the schema's allowed locales.
+///
+/// # Errors
+/// * `Error::LocaleNotAllowed` - If the schema restricts locales and `locale` is not among them
+pub(crate) fn ensure_locale_allowed(env: &Env, schema_uid: &BytesN<32>, locale: &Option<Symbol>) -> Result<(), Error> {
+    let Some(locale) = locale else {
+        return Ok(());
+    };
+    let allowed = get_schema_locales(env, schema_uid);
+    if !allowed.is_empty() && !allowed.contains(locale) {
+        return Err(Error::LocaleNotAllowed);
+    }
+    Ok(())
+}
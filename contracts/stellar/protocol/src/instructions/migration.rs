@@ -0,0 +1,134 @@
+use crate::errors::Error;
+use crate::events;
+use crate::instructions::attestation::index_subject_attestation;
+use crate::instructions::schema::{record_schema_attestation, record_schema_revocation};
+use crate::state::{Attestation, Authority, DataKey, GovernanceKey};
+use crate::utils;
+use soroban_sdk::{Address, Env, Vec};
+
+/// Imports authorities and attestations exported from a previous protocol deployment.
+///
+/// Records are written as exported, so UIDs, nonces, timestamps and revocation state are
+/// preserved and existing references to them stay valid. Resolver hooks are not run, as
+/// the records were already resolved by the deployment that issued them. Each attester's
+/// nonce is advanced past the highest imported nonce so new attestations cannot collide.
+///
+/// The batch is validated as a whole: if any attestation fails, nothing is imported.
+///
+/// # Authorization
+/// Requires authorization from the contract admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract admin
+/// * `authorities` - Authority records to import
+/// * `attestations` - Attestation records to import
+///
+/// # Returns
+/// * `Result<u32, Error>` - The number of attestations imported
+///
+/// # Errors
+/// * `Error::NotAuthorized` - If the caller is not the admin
+/// * `Error::SchemaNotFound` - If an attestation's schema has not been registered on this deployment
+/// * `Error::AttestationExists` - If an attestation UID is already stored
+pub fn import_legacy(
+    env: &Env,
+    admin: Address,
+    authorities: Vec<Authority>,
+    attestations: Vec<Attestation>,
+) -> Result<u32, Error> {
+    utils::require_admin(env, &admin)?;
+
+    for authority in authorities.iter() {
+        env.storage()
+            .instance()
+            .set(&DataKey::Authority(authority.address.clone()), &authority);
+    }
+
+    for attestation in attestations.iter() {
+        if utils::get_schema(env, &attestation.schema_uid).is_none() {
+            return Err(Error::SchemaNotFound);
+        }
+
+        let uid_key = DataKey::AttestationUID(attestation.uid.clone());
+        if env.storage().persistent().has(&uid_key) {
+            return Err(Error::AttestationExists);
+        }
+        env.storage().persistent().set(&uid_key, &attestation);
+
+        let new_subject = index_subject_attestation(env, &attestation);
+        record_schema_attestation(env, &attestation.schema_uid, new_subject, attestation.timestamp);
+        if attestation.revoked {
+            record_schema_revocation(env, &attestation.schema_uid);
+        }
+
+        if attestation.nonce >= utils::get_next_nonce(env, &attestation.attester) {
+            let next_nonce = attestation.nonce.checked_add(1).ok_or(Error::IntegerOverflow)?;
+            utils::set_next_nonce(env, &attestation.attester, next_nonce)?;
+        }
+    }
+
+    events::publish_legacy_import(env, &admin, authorities.len(), attestations.len());
+    Ok(attestations.len())
+}
+
+/// Storage layout version written by this build of the contract.
+///
+/// Bump it, and add a step to `migrate_storage_step`, whenever an upgrade changes how
+/// existing entries are stored.
+pub const STORAGE_VERSION: u32 = 1;
+
+/// Gets the storage layout version of the contract's entries.
+///
+/// Deployments initialized before storage versions were recorded report 0.
+pub fn get_storage_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Governance(GovernanceKey::StorageVersion))
+        .unwrap_or(0)
+}
+
+pub(crate) fn set_storage_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Governance(GovernanceKey::StorageVersion), &version);
+}
+
+/// Brings the contract's storage up to `STORAGE_VERSION` after an upgrade.
+///
+/// Runs each migration step between the stored version and `STORAGE_VERSION` in order.
+/// Storage that is already current is left as is, so calling it again is harmless.
+///
+/// # Authorization
+/// Requires authorization from the contract admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract admin
+///
+/// # Returns
+/// * `Result<u32, Error>` - The storage version after migrating
+///
+/// # Errors
+/// * `Error::NotAuthorized` - If the caller is not the admin
+pub fn migrate_storage(env: &Env, admin: Address) -> Result<u32, Error> {
+    utils::require_admin(env, &admin)?;
+
+    let from_version = get_storage_version(env);
+    if from_version >= STORAGE_VERSION {
+        return Ok(from_version);
+    }
+    for version in from_version..STORAGE_VERSION {
+        migrate_storage_step(env, version);
+    }
+    set_storage_version(env, STORAGE_VERSION);
+
+    events::publish_storage_migrated(env, &admin, from_version, STORAGE_VERSION);
+    Ok(STORAGE_VERSION)
+}
+
+/// Migrates storage from `version` to `version + 1`.
+///
+/// Version 1 only started recording the storage version, so no step rewrites entries yet;
+/// later versions add a match on `version` here.
+fn migrate_storage_step(_env: &Env, _version: u32) {}
@@ -1,14 +1,97 @@
+pub mod appeal;
 pub mod attestation;
+pub mod claim;
+pub mod cleanup;
+pub mod commit_reveal;
+pub mod compatibility;
+pub mod compromise;
+pub mod config;
+pub mod consent;
+pub mod council;
 pub mod crypto;
 pub mod delegation;
+pub mod diff;
+pub mod domain;
+pub mod expiration;
+pub mod health;
+pub mod hook_queue;
+pub mod listing;
+pub mod locale;
+pub mod migration;
+pub mod moderation;
+pub mod organization;
+pub mod outbox;
+pub mod proof;
 pub mod schema;
+pub mod uniqueness;
+pub mod value;
+pub mod voting;
 
 // Pub use all functions from the submodules to make them accessible from the parent `instructions` module.
-pub use self::attestation::{attest, get_attestation_record, revoke_attestation};
-pub use self::crypto::{get_bls_public_key, register_bls_public_key, verify_bls_signature};
+pub use self::appeal::{
+    appeal, get_attestation_appeal, get_schema_challenge_window, resolve_appeal, set_schema_challenge_window,
+};
+pub use self::attestation::{
+    attest, attest_encrypted, attest_muxed, attest_on_behalf_of, attest_with_commitments, attest_with_external_id,
+    attest_with_locale, attest_with_ref, get_attestation_by_external_id, get_attestation_record,
+    get_attestation_status, get_attestation_subject, get_namespaced_attestation, has_valid_attestation,
+    has_valid_subject_attestation, is_attestation_fresh, is_attestation_valid, is_locked, latest_attestation,
+    quote_attest_cost, revoke_attestation, revoke_self_attestation, simulate_attestation_hooks, verify_field,
+};
+pub use self::claim::{attest_claimable, claim, get_claimable_attestation};
+pub use self::cleanup::{extend_attestation_ttl, fund_cleanup_bounty, get_cleanup_bounty, purge_attestations};
+pub use self::commit_reveal::{
+    commit_attestation, compute_attestation_commitment, get_attestation_commitment, reveal_attestation,
+};
+pub use self::compatibility::check_schema_compatibility;
+pub use self::compromise::{declare_compromise, is_attestation_frozen, unfreeze_attestation};
+pub use self::config::get_config;
+pub use self::consent::{
+    accept_attestation, get_pending_attestation, is_schema_consent_required, set_schema_requires_consent,
+};
+pub use self::council::{
+    accept_admin, approve_admin_operation, get_admin_council, get_admin_proposal, get_pending_admin, propose_admin,
+    propose_admin_operation, upgrade,
+};
+pub use self::crypto::{
+    get_bls_public_key, get_secp256k1_signer, register_bls_public_key, register_secp256k1_signer, verify_bls_signature,
+    verify_secp256k1_signature,
+};
 pub use self::delegation::{
-    attest_by_delegation, create_attestation_message, create_revocation_message, get_attest_dst, get_revoke_dst,
-    revoke_by_delegation,
+    attest_by_delegation, attest_by_delegation_secp256k1, build_attestation_message, build_revocation_message,
+    build_secp256k1_attestation_message, build_secp256k1_revocation_message, cancel_delegation_nonce,
+    create_attestation_message, create_revocation_message, get_accepted_message_versions, get_attest_dst,
+    get_revoke_dst, is_nonce_used, revoke_by_delegation, revoke_by_delegation_secp256k1, set_accepted_message_versions,
+    submit_delegated_batch,
+};
+pub use self::diff::diff_attestations;
+pub use self::domain::{get_attester_domain, request_attester_domain, verify_attester_domain};
+pub use self::expiration::{
+    get_expiration_horizon, get_schema_expiration_horizon, set_expiration_horizon, set_schema_expiration_horizon,
+};
+pub use self::health::self_check;
+pub use self::hook_queue::{
+    get_hook_queue_length, is_schema_hooks_deferred, process_hook_queue, set_schema_deferred_hooks,
+};
+pub use self::listing::{
+    get_referencing_attestations, list_attestations_by_locale, list_attester_attestations, list_schema_attestations,
+    list_schema_attestations_by_type, list_subject_attestations, sample_attestations,
+};
+pub use self::locale::{get_schema_locales, set_schema_locales};
+pub use self::migration::{get_storage_version, import_legacy, migrate_storage};
+pub use self::moderation::{admin_revoke, ban_attester, is_attester_banned, is_paused, pause, unban_attester, unpause};
+pub use self::organization::{add_org_member, get_org_attestations, is_org_member, remove_org_member};
+pub use self::outbox::{get_outbox_entries, get_outbox_head, record_outbox_entry};
+pub use self::proof::get_validity_proof;
+pub use self::schema::get_schema_or_fail;
+pub use self::schema::{get_namespace_schemas, get_namespaced_schema};
+pub use self::schema::{
+    get_schema_attestation_count, get_schema_dedup_window, get_schema_definition, get_schema_event_topic,
+    get_schema_min_active_duration, get_schema_revocation, get_schema_stats, is_schema_soulbound,
+    is_schema_subject_revocable, revoke_all, set_schema_dedup_window, set_schema_event_topic,
+    set_schema_min_active_duration, set_schema_soulbound, set_schema_subject_revocable,
 };
-pub use self::schema::register_schema;
-pub use self::schema::get_schema_or_fail;
\ No newline at end of file
+pub use self::schema::{get_schema_versions, register_schema, register_schema_version};
+pub use self::uniqueness::{find_by_uniqueness_key, get_schema_uniqueness_field, set_schema_uniqueness_field};
+pub use self::value::{decode_attestation_value, encode_attestation_value, ensure_value_conforms};
+pub use self::voting::{get_voting_weight, get_voting_weight_rule, set_voting_weight_rule};
@@ -0,0 +1,179 @@
+use crate::errors::Error;
+use crate::events;
+use crate::instructions::attestation::apply_revocation;
+use crate::instructions::council::ensure_no_council;
+use crate::state::{Attestation, DataKey};
+use crate::utils;
+use soroban_sdk::{Address, BytesN, Env};
+
+/// Bans an attester from creating new attestations.
+///
+/// An emergency tool for operators against spam campaigns discovered after the fact.
+/// Banned attesters are rejected by `attest` and all delegated attestation flows.
+/// Attestations they already issued are unaffected.
+///
+/// # Authorization
+/// Requires authorization from the contract admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract admin
+/// * `attester` - The attester to ban
+pub fn ban_attester(env: &Env, admin: Address, attester: Address) -> Result<(), Error> {
+    utils::require_admin(env, &admin)?;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::BannedAttester(attester.clone()), &true);
+
+    events::publish_attester_banned(env, &attester);
+    Ok(())
+}
+
+/// Revokes an attestation as the admin.
+///
+/// A moderation tool for removing abusive attestations without the attester's
+/// cooperation. Unlike attester revocations, it is not subject to the schema's
+/// minimum active duration. The schema must still be revocable, and its resolver,
+/// if any, is still consulted.
+///
+/// # Authorization
+/// Requires authorization from the contract admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract admin
+/// * `attestation_uid` - The unique identifier of the attestation to revoke
+///
+/// # Errors
+/// * `Error::NotAuthorized` - If the caller is not the admin
+/// * `Error::AttestationNotFound` - If the attestation does not exist or is already revoked
+/// * `Error::AttestationNotRevocable` - If the schema does not allow revocation
+pub fn admin_revoke(env: &Env, admin: Address, attestation_uid: BytesN<32>) -> Result<(), Error> {
+    utils::require_admin(env, &admin)?;
+
+    let attest_key = DataKey::AttestationUID(attestation_uid);
+    let attestation = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Attestation>(&attest_key)
+        .ok_or(Error::AttestationNotFound)?;
+    if attestation.revoked {
+        return Err(Error::AttestationNotFound);
+    }
+
+    let schema = utils::get_schema(env, &attestation.schema_uid).ok_or(Error::SchemaNotFound)?;
+    if !schema.revocable {
+        return Err(Error::AttestationNotRevocable);
+    }
+
+    let attestation = apply_revocation(env, &schema, &attest_key, attestation)?;
+    events::publish_revocation_event(env, &attestation);
+    Ok(())
+}
+
+/// Lifts a ban placed with `ban_attester`.
+///
+/// # Authorization
+/// Requires authorization from the contract admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract admin
+/// * `attester` - The attester to unban
+pub fn unban_attester(env: &Env, admin: Address, attester: Address) -> Result<(), Error> {
+    utils::require_admin(env, &admin)?;
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::BannedAttester(attester.clone()));
+
+    events::publish_attester_unbanned(env, &attester);
+    Ok(())
+}
+
+/// Checks whether an attester is banned.
+pub fn is_attester_banned(env: &Env, attester: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BannedAttester(attester.clone()))
+        .unwrap_or(false)
+}
+
+/// Rejects attestations from banned attesters.
+///
+/// # Errors
+/// * `Error::AttesterBanned` - If the attester is banned
+pub(crate) fn ensure_attester_not_banned(env: &Env, attester: &Address) -> Result<(), Error> {
+    if is_attester_banned(env, attester) {
+        return Err(Error::AttesterBanned);
+    }
+    Ok(())
+}
+
+/// Pauses attesting and revoking contract-wide.
+///
+/// A circuit breaker for incident response, e.g. when a resolver or signature
+/// verification bug is discovered. While paused, every attest, claim and revoke
+/// entrypoint fails with `ContractPaused`. Reads and admin moderation such as
+/// `admin_revoke` keep working. Once a council is configured, pausing goes through
+/// `AdminOperation::Pause` proposals instead.
+///
+/// # Authorization
+/// Requires authorization from the contract admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract admin
+///
+/// # Errors
+/// * `Error::NotAuthorized` - If the caller is not the admin, or a council is configured
+pub fn pause(env: &Env, admin: Address) -> Result<(), Error> {
+    utils::require_admin(env, &admin)?;
+    ensure_no_council(env)?;
+
+    env.storage().instance().set(&DataKey::Paused, &true);
+
+    events::publish_contract_paused(env, &admin);
+    Ok(())
+}
+
+/// Lifts a pause set by `pause`.
+///
+/// Once a council is configured, unpausing goes through `AdminOperation::Unpause`
+/// proposals instead.
+///
+/// # Authorization
+/// Requires authorization from the contract admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract admin
+///
+/// # Errors
+/// * `Error::NotAuthorized` - If the caller is not the admin, or a council is configured
+pub fn unpause(env: &Env, admin: Address) -> Result<(), Error> {
+    utils::require_admin(env, &admin)?;
+    ensure_no_council(env)?;
+
+    env.storage().instance().remove(&DataKey::Paused);
+
+    events::publish_contract_unpaused(env, &admin);
+    Ok(())
+}
+
+/// Checks whether the contract is paused.
+pub fn is_paused(env: &Env) -> bool {
+    env.storage().instance().get(&DataKey::Paused).unwrap_or(false)
+}
+
+/// Rejects attestation writes while the contract is paused.
+///
+/// # Errors
+/// * `Error::ContractPaused` - If the contract is paused
+pub(crate) fn ensure_not_paused(env: &Env) -> Result<(), Error> {
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use crate::events;
+use crate::instructions::listing::track_index_key;
+use crate::state::DataKey;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+/// Adds a member to an organization.
+///
+/// Members can issue attestations on behalf of the organization. Adding an existing
+/// member is a no-op.
+///
+/// # Authorization
+/// Requires authorization from the organization address.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `organization` - The organization address
+/// * `member` - The address to add as a member
+pub fn add_org_member(env: &Env, organization: Address, member: Address) {
+    organization.require_auth();
+
+    let key = DataKey::OrgMember(organization.clone(), member.clone());
+    env.storage().persistent().set(&key, &true);
+
+    events::publish_org_member_added(env, &organization, &member);
+}
+
+/// Removes a member from an organization.
+///
+/// Attestations the member already issued on behalf of the organization remain
+/// attributed to it.
+///
+/// # Authorization
+/// Requires authorization from the organization address.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `organization` - The organization address
+/// * `member` - The address to remove
+pub fn remove_org_member(env: &Env, organization: Address, member: Address) {
+    organization.require_auth();
+
+    let key = DataKey::OrgMember(organization.clone(), member.clone());
+    env.storage().persistent().remove(&key);
+
+    events::publish_org_member_removed(env, &organization, &member);
+}
+
+/// Checks whether an address is a member of an organization.
+pub fn is_org_member(env: &Env, organization: &Address, member: &Address) -> bool {
+    let key = DataKey::OrgMember(organization.clone(), member.clone());
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
+
+/// Appends an attestation to an organization's attestation index.
+pub(crate) fn index_org_attestation(env: &Env, organization: &Address, attestation_uid: &BytesN<32>) {
+    let count_key = DataKey::OrgAttestationCount(organization.clone());
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    let key = DataKey::OrgAttestation(organization.clone(), count);
+    env.storage().persistent().set(&key, attestation_uid);
+    env.storage().persistent().set(&count_key, &(count + 1));
+    track_index_key(env, attestation_uid, key);
+}
+
+/// Lists the UIDs of attestations issued on behalf of an organization, in issue order.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `organization` - The organization address
+/// * `start` - Index of the first attestation to return
+/// * `limit` - Maximum number of attestation UIDs to return
+///
+/// # Returns
+/// * `Vec<BytesN<32>>` - The attestation UIDs in `[start, start + limit)`
+pub fn get_org_attestations(env: &Env, organization: &Address, start: u32, limit: u32) -> Vec<BytesN<32>> {
+    let count: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::OrgAttestationCount(organization.clone()))
+        .unwrap_or(0);
+    let end = core::cmp::min(count, start.saturating_add(limit));
+
+    let mut attestation_uids = Vec::new(env);
+    for index in start..end {
+        if let Some(uid) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, BytesN<32>>(&DataKey::OrgAttestation(organization.clone(), index))
+        {
+            attestation_uids.push_back(uid);
+        }
+    }
+    attestation_uids
+}
@@ -0,0 +1,70 @@
+use crate::state::{Attestation, DataKey, OutboxAction, OutboxEntry};
+use soroban_sdk::{Env, Vec};
+
+/// Number of entries retained in the outbox ring buffer.
+///
+/// Once the buffer is full, each new entry overwrites the oldest one.
+pub const OUTBOX_CAPACITY: u64 = 64;
+
+/// Returns the sequence number of the most recent outbox entry, or 0 if none exist.
+pub fn get_outbox_head(env: &Env) -> u64 {
+    env.storage().instance().get(&DataKey::OutboxSeq).unwrap_or(0)
+}
+
+/// Appends a summary of an attestation state change to the outbox.
+///
+/// The entry is written to slot `seq % OUTBOX_CAPACITY`, overwriting whatever
+/// entry previously occupied that slot.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `action` - Whether the attestation was created or revoked
+/// * `attestation` - The attestation as stored after the change
+pub fn record_outbox_entry(env: &Env, action: OutboxAction, attestation: &Attestation) {
+    let seq = get_outbox_head(env) + 1;
+
+    let entry = OutboxEntry {
+        seq,
+        action,
+        attestation_uid: attestation.uid.clone(),
+        schema_uid: attestation.schema_uid.clone(),
+        subject: attestation.subject.clone(),
+        attester: attestation.attester.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::OutboxEntry(seq % OUTBOX_CAPACITY), &entry);
+    env.storage().instance().set(&DataKey::OutboxSeq, &seq);
+}
+
+/// Retrieves all retained outbox entries with a sequence number greater than `since_seq`.
+///
+/// Entries are returned in ascending sequence order. If the first returned entry's
+/// sequence is greater than `since_seq + 1`, the caller has fallen more than
+/// `OUTBOX_CAPACITY` entries behind and the missing entries have been overwritten.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `since_seq` - The last sequence number the caller has processed (0 to read from the start)
+///
+/// # Returns
+/// * `Vec<OutboxEntry>` - The retained entries newer than `since_seq`
+pub fn get_outbox_entries(env: &Env, since_seq: u64) -> Vec<OutboxEntry> {
+    let head = get_outbox_head(env);
+    let oldest_retained = head.saturating_sub(OUTBOX_CAPACITY) + 1;
+    let start = core::cmp::max(since_seq.saturating_add(1), oldest_retained);
+
+    let mut entries = Vec::new(env);
+    for seq in start..=head {
+        if let Some(entry) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, OutboxEntry>(&DataKey::OutboxEntry(seq % OUTBOX_CAPACITY))
+        {
+            entries.push_back(entry);
+        }
+    }
+    entries
+}
@@ -0,0 +1,34 @@
+use crate::errors::Error;
+use crate::instructions::get_attestation_record;
+use crate::state::{DataKey, ValidityProof};
+use soroban_sdk::{xdr::ToXdr, BytesN, Env};
+
+/// Builds a validity proof for an attestation.
+///
+/// The proof pairs the attestation with its ledger entry key and canonical XDR
+/// serialization so light clients and bridges can verify it against a ledger header
+/// without trusting an RPC node's decoding.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `attestation_uid` - The unique identifier of the attestation
+///
+/// # Returns
+/// * `Result<ValidityProof, Error>` - The proof, or `Error::AttestationNotFound` /
+///   `Error::AttestationExpired` if the attestation does not exist or has expired
+pub fn get_validity_proof(env: &Env, attestation_uid: BytesN<32>) -> Result<ValidityProof, Error> {
+    let attestation = get_attestation_record(env, attestation_uid.clone())?;
+
+    let key_xdr = DataKey::AttestationUID(attestation_uid).to_xdr(env);
+    let value_xdr = attestation.clone().to_xdr(env);
+    let value_hash = env.crypto().sha256(&value_xdr).into();
+
+    Ok(ValidityProof {
+        attestation,
+        contract: env.current_contract_address(),
+        key_xdr,
+        value_xdr,
+        value_hash,
+        ledger_sequence: env.ledger().sequence(),
+    })
+}
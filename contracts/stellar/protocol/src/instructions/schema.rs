@@ -1,8 +1,14 @@
 use crate::errors::Error;
 use crate::events;
-use crate::state::{DataKey, Schema};
+use crate::instructions::attestation::call_resolver_on_schema_attach;
+use crate::instructions::listing::{append_listing, listing_entries, listing_len};
+use crate::state::{
+    Attestation, DataKey, ExtendedKey, ListingKey, ProtocolMetrics, Schema, SchemaDefinition, SchemaRevocation,
+    SchemaStats,
+};
 use crate::utils;
-use soroban_sdk::{Address, BytesN, Env, String};
+use crate::xdr_codec;
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
 
 ////////////////////////////////////////////////////////////////////////////////////
 /// Retrieves a schema record by its unique identifier (UID).
@@ -46,11 +52,16 @@ pub fn get_schema_or_fail(env: &Env, schema_uid: &BytesN<32>) -> Result<Schema,
 ///               validation or resolution services for attestations using this schema.
 /// * `revocable` - A boolean flag indicating whether attestations made against this schema
 ///                can be revoked later by the authority.
+/// * `namespace` - An optional namespace to register the schema under. Namespaced schemas get
+///   distinct UIDs and are listed separately per namespace.
 ///
 /// # Returns
 /// * `Result<BytesN<32>, Error>` - The unique 32-byte identifier (UID) of the newly registered schema,
 ///                               or an error if the registration fails.
 ///
+/// # Errors
+/// * `Error::InvalidSchemaDefinition` - If an `XDR:`-prefixed definition does not decode.
+///
 /// # Example
 /// ```ignore
 /// let schema_definition = String::from_str(&env,
@@ -70,7 +81,8 @@ pub fn get_schema_or_fail(env: &Env, schema_uid: &BytesN<32>) -> Result<Schema,
 ///     university_address,
 ///     schema_definition,
 ///     None,
-///     true
+///     true,
+///     None
 /// )?;
 /// ```
 pub fn register_schema(
@@ -79,12 +91,24 @@ pub fn register_schema(
     schema_definition: String,
     resolver: Option<Address>,
     revocable: bool,
+    namespace: Option<Symbol>,
 ) -> Result<BytesN<32>, Error> {
     // Require authorization from the caller
     caller.require_auth();
 
+    // Definitions using the XDR prefix convention must decode
+    if xdr_codec::is_xdr_definition(env, &schema_definition) {
+        xdr_codec::decode_schema(env, &schema_definition)?;
+    }
+
     // Generate schema UID
-    let schema_uid = utils::generate_schema_uid(env, &schema_definition, &caller, &resolver);
+    let schema_uid = utils::generate_schema_uid(env, &schema_definition, &caller, &resolver, &namespace);
+
+    // Re-registering an existing schema keeps its place in its version lineage
+    let existing = utils::get_schema(env, &schema_uid);
+    let (version, previous_uid) = existing
+        .as_ref()
+        .map_or((1, None), |schema| (schema.version, schema.previous_uid.clone()));
 
     // Store schema
     let schema = Schema {
@@ -92,12 +116,669 @@ pub fn register_schema(
         definition: schema_definition.clone(),
         resolver,
         revocable,
+        namespace,
+        version,
+        previous_uid,
     };
-    let schema_key = DataKey::Schema(schema_uid.clone());
-    env.storage().instance().set(&schema_key, &schema);
+    store_schema(env, &caller, &schema_uid, &schema, existing.is_none());
+
+    Ok(schema_uid)
+}
+
+/// Stores a schema and, the first time it is registered, lists it under its namespace,
+/// counts it in the protocol metrics and attaches its resolver.
+fn store_schema(env: &Env, caller: &Address, schema_uid: &BytesN<32>, schema: &Schema, is_new: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Schema(schema_uid.clone()), schema);
+
+    // List the schema under its namespace the first time it is registered
+    if let (Some(namespace), true) = (schema.namespace.clone(), is_new) {
+        let count_key = DataKey::NamespaceSchemaCount(namespace.clone());
+        let count: u32 = env.storage().instance().get(&count_key).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::NamespaceSchema(namespace, count), schema_uid);
+        env.storage().instance().set(&count_key, &(count + 1));
+    }
+
+    if is_new {
+        let mut metrics = load_protocol_metrics(env);
+        metrics.total_schemas += 1;
+        store_protocol_metrics(env, &metrics);
+    }
 
     // Publish schema registration event
-    events::schema_registered(env, &schema_uid, &schema, &caller);
+    events::schema_registered(env, schema_uid, schema, caller);
+
+    // Let the resolver initialize its per-schema configuration the first time it is attached
+    if let (Some(resolver), true) = (&schema.resolver, is_new) {
+        call_resolver_on_schema_attach(env, resolver, schema_uid, caller);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Registers a new version of a schema, linked to the version it supersedes.
+////////////////////////////////////////////////////////////////////////////////////
+/// The new version keeps the resolver, revocability and namespace of the previous one and
+/// gets its own UID, so attestations issued under either version stay distinguishable.
+/// Lineages are linear: only the latest version of a schema can be superseded.
+///
+/// # Authorization
+/// Requires authorization from the caller, who must be the authority of `old_uid`.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `caller` - The schema authority.
+/// * `old_uid` - The UID of the latest version of the schema.
+/// * `new_definition` - The definition of the new version.
+///
+/// # Returns
+/// * `Result<BytesN<32>, Error>` - The UID of the new version.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If `old_uid` is not a registered schema.
+/// * `Error::NotAuthorized` - If the caller is not the schema authority.
+/// * `Error::InvalidSchemaDefinition` - If an `XDR:`-prefixed definition does not decode.
+/// * `Error::SchemaSuperseded` - If `old_uid` already has a newer version.
+/// * `Error::SchemaAlreadyRegistered` - If the new definition matches an already registered schema.
+pub fn register_schema_version(
+    env: &Env,
+    caller: Address,
+    old_uid: BytesN<32>,
+    new_definition: String,
+) -> Result<BytesN<32>, Error> {
+    caller.require_auth();
+    let previous = get_schema_or_fail(env, &old_uid)?;
+    if previous.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+    if xdr_codec::is_xdr_definition(env, &new_definition) {
+        xdr_codec::decode_schema(env, &new_definition)?;
+    }
+
+    let lineage = ListingKey::SchemaVersions(first_version_uid(env, &old_uid, &previous));
+    let versions = listing_len(env, &lineage);
+    if previous.version != versions.max(1) {
+        return Err(Error::SchemaSuperseded);
+    }
+
+    let schema_uid = utils::generate_schema_uid(env, &new_definition, &caller, &previous.resolver, &previous.namespace);
+    if utils::get_schema(env, &schema_uid).is_some() {
+        return Err(Error::SchemaAlreadyRegistered);
+    }
+
+    // The lineage is only listed once it has a second version
+    if versions == 0 {
+        append_listing(env, lineage.clone(), &old_uid);
+    }
+    append_listing(env, lineage, &schema_uid);
+
+    let schema = Schema {
+        authority: caller.clone(),
+        definition: new_definition,
+        resolver: previous.resolver,
+        revocable: previous.revocable,
+        namespace: previous.namespace,
+        version: previous.version + 1,
+        previous_uid: Some(old_uid),
+    };
+    store_schema(env, &caller, &schema_uid, &schema, true);
 
     Ok(schema_uid)
 }
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Retrieves every version of the lineage a schema belongs to.
+////////////////////////////////////////////////////////////////////////////////////
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `schema_uid` - The UID of any version of the schema.
+///
+/// # Returns
+/// * `Result<Vec<BytesN<32>>, Error>` - The UIDs of all versions, first version first.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+pub fn get_schema_versions(env: &Env, schema_uid: &BytesN<32>) -> Result<Vec<BytesN<32>>, Error> {
+    let schema = get_schema_or_fail(env, schema_uid)?;
+    let first = first_version_uid(env, schema_uid, &schema);
+    let versions = listing_entries(env, &ListingKey::SchemaVersions(first.clone()));
+    if versions.is_empty() {
+        return Ok(Vec::from_array(env, [first]));
+    }
+    Ok(versions)
+}
+
+/// Follows `previous_uid` links back to the first version of a schema's lineage.
+fn first_version_uid(env: &Env, schema_uid: &BytesN<32>, schema: &Schema) -> BytesN<32> {
+    let mut uid = schema_uid.clone();
+    let mut previous_uid = schema.previous_uid.clone();
+    while let Some(previous) = previous_uid {
+        previous_uid = utils::get_schema(env, &previous).and_then(|schema| schema.previous_uid);
+        uid = previous;
+    }
+    uid
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Decodes a registered schema's `XDR:`-prefixed definition into its typed form.
+////////////////////////////////////////////////////////////////////////////////////
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+///
+/// # Returns
+/// * `Result<SchemaDefinition, Error>` - The typed schema definition.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+/// * `Error::InvalidSchemaDefinition` - If the schema's definition is not XDR-encoded.
+pub fn get_schema_definition(env: &Env, schema_uid: &BytesN<32>) -> Result<SchemaDefinition, Error> {
+    let schema = get_schema_or_fail(env, schema_uid)?;
+    xdr_codec::decode_schema(env, &schema.definition)
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Retrieves the running statistics for a schema.
+////////////////////////////////////////////////////////////////////////////////////
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+///
+/// # Returns
+/// * `Result<SchemaStats, Error>` - The schema's statistics, all zero if nothing has been issued yet.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+pub fn get_schema_stats(env: &Env, schema_uid: &BytesN<32>) -> Result<SchemaStats, Error> {
+    get_schema_or_fail(env, schema_uid)?;
+    Ok(load_schema_stats(env, schema_uid))
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Retrieves the number of attestations ever issued under a schema.
+////////////////////////////////////////////////////////////////////////////////////
+/// Revoked and expired attestations are still counted; see [`get_schema_stats`] for the
+/// breakdown.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+///
+/// # Returns
+/// * `Result<u64, Error>` - The `issued` counter of the schema's statistics.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+pub fn get_schema_attestation_count(env: &Env, schema_uid: &BytesN<32>) -> Result<u64, Error> {
+    Ok(get_schema_stats(env, schema_uid)?.issued)
+}
+
+fn load_schema_stats(env: &Env, schema_uid: &BytesN<32>) -> SchemaStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SchemaStats(schema_uid.clone()))
+        .unwrap_or_default()
+}
+
+/// Number of attestations between two `METRICS` heartbeat events.
+pub const METRICS_HEARTBEAT_INTERVAL: u64 = 100;
+
+/// Loads the protocol metrics.
+///
+/// Deployments upgraded from versions that kept the metrics in instance storage fall back
+/// to that copy until the metrics are next updated.
+pub(crate) fn load_protocol_metrics(env: &Env) -> ProtocolMetrics {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProtocolMetrics)
+        .or_else(|| env.storage().instance().get(&DataKey::ProtocolMetrics))
+        .unwrap_or_default()
+}
+
+/// Stores the protocol metrics in persistent storage and keeps them from being archived.
+///
+/// Unlike temporary storage, the counters cannot expire during a quiet period. The TTL is
+/// only bumped once it falls below half the network maximum, so most updates pay no
+/// extension fee.
+fn store_protocol_metrics(env: &Env, metrics: &ProtocolMetrics) {
+    let max_ttl = env.storage().max_ttl();
+    env.storage().persistent().set(&DataKey::ProtocolMetrics, metrics);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::ProtocolMetrics, max_ttl / 2, max_ttl);
+    if env.storage().instance().has(&DataKey::ProtocolMetrics) {
+        env.storage().instance().remove(&DataKey::ProtocolMetrics);
+    }
+}
+
+/// Updates a schema's statistics for a newly issued attestation.
+///
+/// Also updates the protocol-wide metrics, publishing them every `METRICS_HEARTBEAT_INTERVAL`
+/// attestations.
+pub(crate) fn record_schema_attestation(env: &Env, schema_uid: &BytesN<32>, new_subject: bool, timestamp: u64) {
+    let mut stats = load_schema_stats(env, schema_uid);
+    let mut metrics = load_protocol_metrics(env);
+    metrics.total_attestations += 1;
+    if stats.active == 0 {
+        metrics.active_schemas += 1;
+    }
+    store_protocol_metrics(env, &metrics);
+    if metrics.total_attestations.is_multiple_of(METRICS_HEARTBEAT_INTERVAL) {
+        events::publish_metrics(env, &metrics);
+    }
+
+    stats.issued += 1;
+    stats.active += 1;
+    if new_subject {
+        stats.unique_subjects += 1;
+    }
+    stats.last_issued_at = timestamp;
+    env.storage()
+        .persistent()
+        .set(&DataKey::SchemaStats(schema_uid.clone()), &stats);
+}
+
+/// Updates a schema's statistics for a revoked attestation.
+pub(crate) fn record_schema_revocation(env: &Env, schema_uid: &BytesN<32>) {
+    let mut stats = load_schema_stats(env, schema_uid);
+    let mut metrics = load_protocol_metrics(env);
+    metrics.total_revocations += 1;
+    if stats.active == 1 {
+        metrics.active_schemas = metrics.active_schemas.saturating_sub(1);
+    }
+    store_protocol_metrics(env, &metrics);
+
+    stats.revoked += 1;
+    stats.active = stats.active.saturating_sub(1);
+    env.storage()
+        .persistent()
+        .set(&DataKey::SchemaStats(schema_uid.clone()), &stats);
+}
+
+/// Updates a schema's statistics for a revoked attestation reinstated on appeal.
+pub(crate) fn record_schema_reinstatement(env: &Env, schema_uid: &BytesN<32>) {
+    let mut stats = load_schema_stats(env, schema_uid);
+    if stats.active == 0 {
+        let mut metrics = load_protocol_metrics(env);
+        metrics.active_schemas += 1;
+        store_protocol_metrics(env, &metrics);
+    }
+
+    stats.revoked = stats.revoked.saturating_sub(1);
+    stats.active += 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::SchemaStats(schema_uid.clone()), &stats);
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Retrieves a schema by UID, scoped to a namespace.
+////////////////////////////////////////////////////////////////////////////////////
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `namespace` - The namespace the schema must belong to.
+/// * `schema_uid` - The 32-byte unique identifier of the schema to retrieve.
+///
+/// # Returns
+/// * `Result<Schema, Error>` - The `Schema` record if found in the namespace, otherwise an error.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If the schema does not exist or belongs to another namespace.
+pub fn get_namespaced_schema(env: &Env, namespace: &Symbol, schema_uid: &BytesN<32>) -> Result<Schema, Error> {
+    let schema = get_schema_or_fail(env, schema_uid)?;
+    if schema.namespace.as_ref() != Some(namespace) {
+        return Err(Error::SchemaNotFound);
+    }
+    Ok(schema)
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Lists the schema UIDs registered under a namespace, in registration order.
+////////////////////////////////////////////////////////////////////////////////////
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `namespace` - The namespace to list.
+/// * `start` - Index of the first schema to return.
+/// * `limit` - Maximum number of schema UIDs to return.
+///
+/// # Returns
+/// * `Vec<BytesN<32>>` - The schema UIDs in `[start, start + limit)`, empty if `start` is past the end.
+pub fn get_namespace_schemas(env: &Env, namespace: &Symbol, start: u32, limit: u32) -> Vec<BytesN<32>> {
+    let count: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NamespaceSchemaCount(namespace.clone()))
+        .unwrap_or(0);
+    let end = core::cmp::min(count, start.saturating_add(limit));
+
+    let mut schema_uids = Vec::new(env);
+    for index in start..end {
+        if let Some(schema_uid) = env
+            .storage()
+            .instance()
+            .get::<DataKey, BytesN<32>>(&DataKey::NamespaceSchema(namespace.clone(), index))
+        {
+            schema_uids.push_back(schema_uid);
+        }
+    }
+    schema_uids
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Configures duplicate-attestation rejection for a schema.
+////////////////////////////////////////////////////////////////////////////////////
+/// While enabled, an attestation with the same attester, subject and value as one
+/// issued under the schema within the last `window_ledgers` ledgers is rejected.
+/// This keeps relayer retries from creating near-duplicate records.
+///
+/// # Authorization
+/// Requires authorization from the schema authority.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `caller` - The schema authority.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+/// * `window_ledgers` - The deduplication window in ledgers. `0` disables deduplication.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+/// * `Error::NotAuthorized` - If the caller is not the schema authority.
+/// * `Error::InvalidDeadline` - If the window outlasts the maximum storage TTL, as its
+///   fingerprints could then be evicted before the window ends.
+pub fn set_schema_dedup_window(
+    env: &Env,
+    caller: Address,
+    schema_uid: BytesN<32>,
+    window_ledgers: u32,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    let schema = get_schema_or_fail(env, &schema_uid)?;
+    if schema.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+    if window_ledgers > env.storage().max_ttl() {
+        return Err(Error::InvalidDeadline);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::SchemaDedupWindow(schema_uid), &window_ledgers);
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Sets the custom event topic for a schema.
+////////////////////////////////////////////////////////////////////////////////////
+/// When set, the topic is appended after the standard topics of every attestation
+/// and revocation event for the schema (e.g. `ATTEST`, `CREATE`, `topic`), so products
+/// can subscribe to their own attestations with a topic filter.
+///
+/// # Authorization
+/// Requires authorization from the schema authority.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `caller` - The schema authority.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+/// * `topic` - The custom topic, or `None` to stop emitting one.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+/// * `Error::NotAuthorized` - If the caller is not the schema authority.
+pub fn set_schema_event_topic(
+    env: &Env,
+    caller: Address,
+    schema_uid: BytesN<32>,
+    topic: Option<Symbol>,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    let schema = get_schema_or_fail(env, &schema_uid)?;
+    if schema.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+
+    let key = DataKey::SchemaEventTopic(schema_uid);
+    match topic {
+        Some(topic) => env.storage().instance().set(&key, &topic),
+        None => env.storage().instance().remove(&key),
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Sets the minimum time a schema's attestations must stay active.
+////////////////////////////////////////////////////////////////////////////////////
+/// While set, attestations under the schema cannot be revoked by their attester until
+/// `seconds` have passed since issuance. This stops issue-and-revoke spam from gaming
+/// reward resolvers. The admin can still revoke earlier with `admin_revoke`.
+///
+/// # Authorization
+/// Requires authorization from the schema authority.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `caller` - The schema authority.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+/// * `seconds` - The minimum active duration. `0` removes the restriction.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+/// * `Error::NotAuthorized` - If the caller is not the schema authority.
+pub fn set_schema_min_active_duration(
+    env: &Env,
+    caller: Address,
+    schema_uid: BytesN<32>,
+    seconds: u64,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    let schema = get_schema_or_fail(env, &schema_uid)?;
+    if schema.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+
+    let key = DataKey::SchemaMinActiveDuration(schema_uid);
+    if seconds == 0 {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, &seconds);
+    }
+    Ok(())
+}
+
+/// Returns a schema's minimum active duration in seconds, or 0 if unrestricted.
+pub fn get_schema_min_active_duration(env: &Env, schema_uid: &BytesN<32>) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaMinActiveDuration(schema_uid.clone()))
+        .unwrap_or(0)
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Marks a schema as soulbound, or clears the mark.
+////////////////////////////////////////////////////////////////////////////////////
+/// Attestations are never transferable, but soulbound schemas also follow the
+/// ERC-5192 conventions NFT-adjacent tooling indexes: each attestation created under
+/// the schema emits a `Locked` event, and `is_locked` reports it as locked.
+///
+/// # Authorization
+/// Requires authorization from the schema authority.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `caller` - The schema authority.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+/// * `soulbound` - Whether the schema is soulbound.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+/// * `Error::NotAuthorized` - If the caller is not the schema authority.
+pub fn set_schema_soulbound(env: &Env, caller: Address, schema_uid: BytesN<32>, soulbound: bool) -> Result<(), Error> {
+    caller.require_auth();
+
+    let schema = get_schema_or_fail(env, &schema_uid)?;
+    if schema.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+
+    let key = DataKey::SchemaSoulbound(schema_uid);
+    if soulbound {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Lets the subjects of a schema's attestations revoke them, or withdraws the right.
+////////////////////////////////////////////////////////////////////////////////////
+/// Self-sovereign schemas need subjects to be able to withdraw attestations about
+/// themselves, e.g. to honour a right-to-erasure request. While set, `revoke` accepts
+/// the subject as well as the attester, even if the schema is not otherwise revocable.
+///
+/// # Authorization
+/// Requires authorization from the schema authority.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `caller` - The schema authority.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+/// * `subject_revocable` - Whether subjects may revoke attestations about themselves.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+/// * `Error::NotAuthorized` - If the caller is not the schema authority.
+pub fn set_schema_subject_revocable(
+    env: &Env,
+    caller: Address,
+    schema_uid: BytesN<32>,
+    subject_revocable: bool,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    let schema = get_schema_or_fail(env, &schema_uid)?;
+    if schema.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+
+    let key = DataKey::Extended(ExtendedKey::SchemaSubjectRevocable(schema_uid));
+    if subject_revocable {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Revokes every attestation under a schema at once.
+////////////////////////////////////////////////////////////////////////////////////
+/// An emergency operation for catastrophic issuer compromise, where revoking each
+/// attestation by UID is impractical. No attestation record is rewritten: the schema is
+/// flagged, and from then on its attestations read as revoked at the flag's timestamp
+/// and never count as valid. No new attestations can be issued under the schema.
+///
+/// # Authorization
+/// Requires authorization from the schema authority.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `caller` - The schema authority.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+/// * `reason` - Why the schema was revoked, for relying parties and indexers.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+/// * `Error::NotAuthorized` - If the caller is not the schema authority.
+/// * `Error::SchemaRevoked` - If the schema has already been revoked.
+pub fn revoke_all(env: &Env, caller: Address, schema_uid: BytesN<32>, reason: String) -> Result<(), Error> {
+    caller.require_auth();
+
+    let schema = get_schema_or_fail(env, &schema_uid)?;
+    if schema.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+    ensure_schema_not_revoked(env, &schema_uid)?;
+
+    let revocation = SchemaRevocation {
+        reason,
+        revoked_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::SchemaRevocation(schema_uid.clone()), &revocation);
+
+    events::publish_schema_revoked(env, &schema_uid, &revocation);
+    Ok(())
+}
+
+/// Returns a schema's global revocation, if `revoke_all` was called on it.
+pub fn get_schema_revocation(env: &Env, schema_uid: &BytesN<32>) -> Option<SchemaRevocation> {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaRevocation(schema_uid.clone()))
+}
+
+/// Rejects operations on a schema revoked with `revoke_all`.
+///
+/// # Errors
+/// * `Error::SchemaRevoked` - If the schema has been revoked
+pub(crate) fn ensure_schema_not_revoked(env: &Env, schema_uid: &BytesN<32>) -> Result<(), Error> {
+    if env
+        .storage()
+        .instance()
+        .has(&DataKey::SchemaRevocation(schema_uid.clone()))
+    {
+        return Err(Error::SchemaRevoked);
+    }
+    Ok(())
+}
+
+/// Marks an attestation read from storage as revoked if its schema was revoked with `revoke_all`.
+pub(crate) fn apply_schema_revocation(env: &Env, attestation: &mut Attestation) {
+    if attestation.revoked {
+        return;
+    }
+    if let Some(revocation) = get_schema_revocation(env, &attestation.schema_uid) {
+        attestation.revoked = true;
+        attestation.revocation_time = Some(revocation.revoked_at);
+    }
+}
+
+/// Returns whether a schema is soulbound.
+pub fn is_schema_soulbound(env: &Env, schema_uid: &BytesN<32>) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaSoulbound(schema_uid.clone()))
+        .unwrap_or(false)
+}
+
+/// Returns whether subjects may revoke attestations about themselves under a schema.
+pub fn is_schema_subject_revocable(env: &Env, schema_uid: &BytesN<32>) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Extended(ExtendedKey::SchemaSubjectRevocable(
+            schema_uid.clone(),
+        )))
+        .unwrap_or(false)
+}
+
+/// Returns a schema's custom event topic, if one is set.
+pub fn get_schema_event_topic(env: &Env, schema_uid: &BytesN<32>) -> Option<Symbol> {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaEventTopic(schema_uid.clone()))
+}
+
+/// Returns a schema's deduplication window in ledgers, or 0 if disabled.
+pub fn get_schema_dedup_window(env: &Env, schema_uid: &BytesN<32>) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaDedupWindow(schema_uid.clone()))
+        .unwrap_or(0)
+}
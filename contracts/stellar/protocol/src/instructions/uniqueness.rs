@@ -0,0 +1,143 @@
+use crate::errors::Error;
+use crate::instructions::attestation::is_attestation_valid;
+use crate::instructions::diff::extract_json_value;
+use crate::instructions::get_schema_definition;
+use crate::instructions::listing::track_index_key;
+use crate::instructions::schema::get_schema_or_fail;
+use crate::state::{Attestation, DataKey, FieldValue, UniquenessKey};
+use crate::utils::string_to_bytes;
+use crate::value_codec;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, String};
+
+////////////////////////////////////////////////////////////////////////////////////
+/// Declares the value field whose hash must be unique among a schema's active attestations.
+////////////////////////////////////////////////////////////////////////////////////
+/// While set, `attest` and the delegated and claimable flows reject an attestation
+/// whose uniqueness key matches that of another active attestation under the schema,
+/// whatever its subject. A KYC schema keyed on a national ID hash, for instance, then
+/// cannot issue one person duplicate credentials for different wallets. Once the
+/// holding attestation is revoked or expires, the key can be issued again.
+///
+/// The key is `sha256` of the field's raw JSON value (so `"42"` and `42` differ), or of
+/// the field's `FieldValue` XDR for `BIN:` structured values. Attestations whose value
+/// lacks the field, including encrypted ones, are rejected.
+///
+/// # Authorization
+/// Requires authorization from the schema authority.
+///
+/// # Arguments
+/// * `env` - The Soroban environment.
+/// * `caller` - The schema authority.
+/// * `schema_uid` - The 32-byte unique identifier of the schema.
+/// * `field` - The name of the value field, or `None` to lift the constraint.
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If no schema with the given UID exists in storage.
+/// * `Error::NotAuthorized` - If the caller is not the schema authority.
+pub fn set_schema_uniqueness_field(
+    env: &Env,
+    caller: Address,
+    schema_uid: BytesN<32>,
+    field: Option<String>,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    let schema = get_schema_or_fail(env, &schema_uid)?;
+    if schema.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+
+    let key = DataKey::Uniqueness(UniquenessKey::Field(schema_uid));
+    match field {
+        Some(field) => env.storage().instance().set(&key, &field),
+        None => env.storage().instance().remove(&key),
+    }
+    Ok(())
+}
+
+/// Returns the name of a schema's uniqueness field, if one is declared.
+pub fn get_schema_uniqueness_field(env: &Env, schema_uid: &BytesN<32>) -> Option<String> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Uniqueness(UniquenessKey::Field(schema_uid.clone())))
+}
+
+/// Returns the UID of the active attestation holding a uniqueness key under a schema.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `schema_uid` - The unique identifier of the schema
+/// * `key_hash` - The uniqueness key, as described in [`set_schema_uniqueness_field`]
+///
+/// # Returns
+/// * `Option<BytesN<32>>` - The holding attestation, or `None` if the key is free
+pub fn find_by_uniqueness_key(env: &Env, schema_uid: BytesN<32>, key_hash: BytesN<32>) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, BytesN<32>>(&DataKey::Uniqueness(UniquenessKey::Holder(schema_uid, key_hash)))
+        .filter(|uid| is_attestation_valid(env, uid.clone()))
+}
+
+/// Reserves an attestation's uniqueness key, if its schema declares one.
+///
+/// Called before the attestation is stored; the key points at its UID from then on.
+///
+/// # Errors
+/// * `Error::UniquenessKeyMissing` - If the value does not carry the uniqueness field
+/// * `Error::UniquenessKeyTaken` - If another active attestation holds the same key
+pub(crate) fn reserve_uniqueness_key(env: &Env, attestation: &Attestation) -> Result<(), Error> {
+    if let Some(key) = check_uniqueness_key(env, attestation)? {
+        env.storage().persistent().set(&key, &attestation.uid);
+        track_index_key(env, &attestation.uid, key);
+    }
+    Ok(())
+}
+
+/// Checks that an attestation's uniqueness key is free without reserving it.
+///
+/// # Returns
+/// * `Option<DataKey>` - The storage key [`reserve_uniqueness_key`] would claim, or
+///   `None` if the schema declares no uniqueness field
+///
+/// # Errors
+/// * `Error::UniquenessKeyMissing` - If the value does not carry the uniqueness field
+/// * `Error::UniquenessKeyTaken` - If another active attestation holds the same key
+pub(crate) fn check_uniqueness_key(env: &Env, attestation: &Attestation) -> Result<Option<DataKey>, Error> {
+    let Some(field) = get_schema_uniqueness_field(env, &attestation.schema_uid) else {
+        return Ok(None);
+    };
+    if attestation.encryption_scheme.is_some() {
+        return Err(Error::UniquenessKeyMissing);
+    }
+
+    let raw = extract_key(env, attestation, &field)?;
+    let key_hash: BytesN<32> = env.crypto().sha256(&raw).into();
+    if find_by_uniqueness_key(env, attestation.schema_uid.clone(), key_hash.clone()).is_some() {
+        return Err(Error::UniquenessKeyTaken);
+    }
+
+    Ok(Some(DataKey::Uniqueness(UniquenessKey::Holder(
+        attestation.schema_uid.clone(),
+        key_hash,
+    ))))
+}
+
+fn extract_key(env: &Env, attestation: &Attestation, field: &String) -> Result<Bytes, Error> {
+    if !value_codec::is_structured_value(env, &attestation.value) {
+        let value = string_to_bytes(env, &attestation.value);
+        return extract_json_value(&value, &string_to_bytes(env, field)).ok_or(Error::UniquenessKeyMissing);
+    }
+
+    let definition = get_schema_definition(env, &attestation.schema_uid)?;
+    let values = value_codec::decode_value(env, &definition, &attestation.value)?;
+    let index = definition
+        .fields
+        .iter()
+        .position(|schema_field| schema_field.name == *field)
+        .ok_or(Error::UniquenessKeyMissing)?;
+    match values.get(index as u32) {
+        Some(FieldValue::Absent) | None => Err(Error::UniquenessKeyMissing),
+        Some(value) => Ok(value.to_xdr(env)),
+    }
+}
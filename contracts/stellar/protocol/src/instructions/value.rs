@@ -0,0 +1,61 @@
+use crate::errors::Error;
+use crate::instructions::get_schema_definition;
+use crate::state::FieldValue;
+use crate::value_codec;
+use soroban_sdk::{BytesN, Env, String, Vec};
+
+/// Encodes field values as a `BIN:`-prefixed structured value for a registered schema.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `schema_uid` - The schema whose field list lays out the value
+/// * `values` - One entry per schema field, in declared order
+///
+/// # Returns
+/// * `Result<String, Error>` - The structured value string
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If the schema does not exist
+/// * `Error::InvalidSchemaDefinition` - If the schema's definition is not XDR-encoded, or
+///   declares a type the codec does not support
+/// * `Error::InvalidAttestationValue` - If the values do not match the schema's fields
+pub fn encode_attestation_value(env: &Env, schema_uid: &BytesN<32>, values: &Vec<FieldValue>) -> Result<String, Error> {
+    let definition = get_schema_definition(env, schema_uid)?;
+    value_codec::encode_value(env, &definition, values)
+}
+
+/// Decodes a `BIN:`-prefixed structured value against a registered schema.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `schema_uid` - The schema whose field list lays out the value
+/// * `value` - The structured value string
+///
+/// # Returns
+/// * `Result<Vec<FieldValue>, Error>` - One entry per schema field, in declared order
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If the schema does not exist
+/// * `Error::InvalidSchemaDefinition` - If the schema's definition is not XDR-encoded, or
+///   declares a type the codec does not support
+/// * `Error::InvalidAttestationValue` - If the value is not a canonical encoding for the schema
+pub fn decode_attestation_value(env: &Env, schema_uid: &BytesN<32>, value: &String) -> Result<Vec<FieldValue>, Error> {
+    let definition = get_schema_definition(env, schema_uid)?;
+    value_codec::decode_value(env, &definition, value)
+}
+
+/// Rejects structured values that do not decode against the schema they are issued
+/// under, so every stored `BIN:` value can be read back by consumers.
+///
+/// Values without the `BIN:` prefix are left to the schema's own conventions.
+///
+/// # Errors
+/// * `Error::InvalidSchemaDefinition` - If the value is structured but the schema's
+///   definition is not XDR-encoded
+/// * `Error::InvalidAttestationValue` - If the value is structured but does not decode
+pub fn ensure_value_conforms(env: &Env, schema_uid: &BytesN<32>, value: &String) -> Result<(), Error> {
+    if value_codec::is_structured_value(env, value) {
+        decode_attestation_value(env, schema_uid, value)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use crate::errors::Error;
+use crate::instructions::diff::extract_json_value;
+use crate::instructions::{get_schema_or_fail, has_valid_attestation};
+use crate::state::{Attestation, DataKey, VotingWeightRule};
+use crate::utils::{bytes_to_string, string_to_bytes};
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+/// Sets how a schema's attestations are converted into voting weight.
+///
+/// # Authorization
+/// Requires authorization from the schema authority.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The schema authority
+/// * `schema_uid` - The unique identifier of the schema
+/// * `rule` - The weight rule, or `None` to give every valid holder a weight of 1
+///
+/// # Errors
+/// * `Error::SchemaNotFound` - If the schema does not exist
+/// * `Error::NotAuthorized` - If the caller is not the schema authority
+pub fn set_voting_weight_rule(
+    env: &Env,
+    caller: Address,
+    schema_uid: BytesN<32>,
+    rule: Option<VotingWeightRule>,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    let schema = get_schema_or_fail(env, &schema_uid)?;
+    if schema.authority != caller {
+        return Err(Error::NotAuthorized);
+    }
+
+    let key = DataKey::SchemaVotingWeight(schema_uid);
+    match rule {
+        Some(rule) => env.storage().instance().set(&key, &rule),
+        None => env.storage().instance().remove(&key),
+    }
+    Ok(())
+}
+
+/// Returns a schema's voting weight rule, if one is set.
+pub fn get_voting_weight_rule(env: &Env, schema_uid: &BytesN<32>) -> Option<VotingWeightRule> {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVotingWeight(schema_uid.clone()))
+}
+
+/// Derives a subject's voting weight from their attestation under a schema.
+///
+/// Intended as a voter-eligibility source for governance contracts. Only the subject's
+/// most recent attestation counts, and only while it is unrevoked and unexpired.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `subject` - The address whose weight is requested
+/// * `schema_uid` - The unique identifier of the schema
+///
+/// # Returns
+/// * `u64` - 0 without a valid attestation; 1 if the schema has no weight rule; otherwise
+///   the weight the rule assigns to the attestation value (0 if encrypted or unmatched)
+pub fn get_voting_weight(env: &Env, subject: &Address, schema_uid: &BytesN<32>) -> u64 {
+    if !has_valid_attestation(env, subject, schema_uid) {
+        return 0;
+    }
+    let Some(rule) = get_voting_weight_rule(env, schema_uid) else {
+        return 1;
+    };
+
+    let key = DataKey::SubjectAttestation(schema_uid.clone(), subject.clone());
+    let Some(attestation) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, BytesN<32>>(&key)
+        .and_then(|uid| {
+            env.storage()
+                .persistent()
+                .get::<DataKey, Attestation>(&DataKey::AttestationUID(uid))
+        })
+    else {
+        return 0;
+    };
+    if attestation.encryption_scheme.is_some() {
+        return 0;
+    }
+
+    let value = string_to_bytes(env, &attestation.value);
+    let Some(raw) = extract_json_value(&value, &string_to_bytes(env, &rule.field)) else {
+        return 0;
+    };
+
+    if raw.len() >= 2 && raw.get_unchecked(0) == b'"' && raw.get_unchecked(raw.len() - 1) == b'"' {
+        let tier = bytes_to_string(env, &raw.slice(1..raw.len() - 1));
+        return rule.tiers.get(tier).unwrap_or(0);
+    }
+    parse_weight(&raw).unwrap_or(0)
+}
+
+/// Parses a non-negative JSON integer, rejecting anything else.
+fn parse_weight(raw: &Bytes) -> Option<u64> {
+    if raw.is_empty() {
+        return None;
+    }
+    let mut weight: u64 = 0;
+    for c in raw.iter() {
+        if !c.is_ascii_digit() {
+            return None;
+        }
+        weight = weight.checked_mul(10)?.checked_add((c - b'0') as u64)?;
+    }
+    Some(weight)
+}
@@ -1,23 +1,7 @@
-use soroban_sdk::{contractclient, contracttype, Address, Bytes, BytesN, Env};
-
-/************************************************
-* Flattened Attestation Struct for Resolver Calls
-************************************************/
-#[derive(Debug, Clone)]
-#[contracttype]
-pub struct ResolverAttestation {
-    pub uid: BytesN<32>,
-    pub schema_uid: BytesN<32>,
-    pub recipient: Address,
-    pub attester: Address,
-    pub time: u64,
-    pub expiration_time: u64, // Flattened: 0 = not set
-    pub revocation_time: u64, // Flattened: 0 = not set
-    pub revocable: bool,
-    pub ref_uid: Bytes, // Flattened: empty bytes = not set
-    pub data: Bytes,
-    pub value: i128, // Flattened: 0 = not set
-}
+use soroban_sdk::{contractclient, Address, BytesN, Env, Vec};
+
+// Flattened hook view and relay context are shared with the resolvers crate
+pub use attest_types::{ResolverAttestation, ResolverContext};
 
 /// Resolver Contract Client Interface
 ///
@@ -32,6 +16,10 @@ pub struct ResolverAttestation {
 /// - onattest: Validates whether an attestation should be allowed (pre-creation)
 /// - onrevoke: Validates whether a revocation should be allowed (pre-revocation)  
 /// - onresolve: Handles post-processing after attestation/revocation (side effects)
+/// - expiration_override: Sets the stored expiration of an allowed attestation
+/// - onrelayed: Handles post-processing after a delegated attestation, with the relayer context
+/// - on_schema_attach: Initializes per-schema configuration when a schema selects the resolver
+/// - quote_fees: Reports the tokens and amounts onattest would charge (read-only)
 ///
 /// Security Model:
 /// - onattest/onrevoke return boolean values that gate protocol actions
@@ -44,6 +32,12 @@ pub trait Resolver {
     /// Returns true if attestation should be allowed, false to reject
     fn onattest(env: &Env, attestation: &ResolverAttestation) -> bool;
 
+    /// Called right after onattest allows an attestation, before it is stored. Returning
+    /// Some(timestamp) replaces the attester's expiration (e.g. a membership resolver ending
+    /// the attestation with the subscription); None keeps it.
+    /// Optional: failures (including resolvers that don't implement it) keep the attester's expiration
+    fn expiration_override(env: &Env, attestation: &ResolverAttestation) -> Option<u64>;
+
     /// Called before an attestation is revoked - CRITICAL for access control
     /// Returns true if revocation should be allowed, false to reject
     fn onrevoke(env: &Env, attestation: &ResolverAttestation) -> bool;
@@ -51,4 +45,18 @@ pub trait Resolver {
     /// Called after an attestation is attested or revoked - for side effects (rewards, cleanup, etc.)
     /// Failures are logged but don't revert the attestation or revocation
     fn onresolve(env: &Env, attestation: &ResolverAttestation);
+
+    /// Called after an attestation submitted through `attest_by_delegation` is stored.
+    /// The context identifies the submitter so resolvers can reimburse relayers.
+    /// Optional: failures (including resolvers that don't implement it) are ignored
+    fn onrelayed(env: &Env, attestation: &ResolverAttestation, context: &ResolverContext);
+
+    /// Called when a newly registered schema attaches this resolver, so it can set up
+    /// per-schema configuration (fees, limits) without a separate admin transaction.
+    /// Optional: failures (including resolvers that don't implement it) are ignored
+    fn on_schema_attach(env: &Env, schema_uid: &BytesN<32>, schema_authority: &Address);
+
+    /// Returns the (token, amount) pairs onattest would take from the attester for this schema.
+    /// Optional: failures (including resolvers that don't implement it) quote no charges
+    fn quote_fees(env: &Env, schema_uid: &BytesN<32>, attester: &Address) -> Vec<(Address, i128)>;
 }
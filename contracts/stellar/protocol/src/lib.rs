@@ -1,6 +1,6 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String, Symbol, Vec};
 
 pub mod errors;
 pub mod events;
@@ -8,12 +8,47 @@ pub mod instructions;
 pub mod interfaces;
 pub mod state;
 pub mod utils;
+pub mod value_codec;
+pub mod xdr_codec;
 
-use state::{Attestation, BlsPublicKey, DataKey, DelegatedAttestationRequest, DelegatedRevocationRequest, Schema};
+use state::{
+    AdminCouncil, AdminOperation, AdminProposal, Attestation, AttestationAppeal, AttestationCommitment,
+    AttestationPage, AttestationReveal, AttestationStatus, AttesterDomain, Authority, BlsPublicKey,
+    ClaimableAttestation, CleanupBounty, DataKey, DelegatedAttestationRequest, DelegatedRevocationRequest, FieldValue,
+    HookSimulation, OutboxEntry, ProtocolConfig, Schema, SchemaCompatibility, SchemaDefinition, SchemaRevocation,
+    SchemaStats, Secp256k1Signer, Subject, SubjectType, ValidityProof, VotingWeightRule,
+};
 
 use instructions::{
-    attest, attest_by_delegation, get_attest_dst, get_attestation_record, get_bls_public_key, get_revoke_dst,
-    register_bls_public_key, register_schema, revoke_attestation, revoke_by_delegation, get_schema_or_fail,
+    accept_admin, accept_attestation, add_org_member, admin_revoke, appeal, approve_admin_operation, attest,
+    attest_by_delegation, attest_by_delegation_secp256k1, attest_claimable, attest_encrypted, attest_muxed,
+    attest_on_behalf_of, attest_with_commitments, attest_with_external_id, attest_with_locale, attest_with_ref,
+    ban_attester, cancel_delegation_nonce, check_schema_compatibility, claim, commit_attestation,
+    compute_attestation_commitment, declare_compromise, decode_attestation_value, diff_attestations,
+    encode_attestation_value, extend_attestation_ttl, find_by_uniqueness_key, fund_cleanup_bounty,
+    get_accepted_message_versions, get_admin_council, get_admin_proposal, get_attest_dst, get_attestation_appeal,
+    get_attestation_by_external_id, get_attestation_commitment, get_attestation_record, get_attestation_status,
+    get_attestation_subject, get_attester_domain, get_bls_public_key, get_claimable_attestation, get_cleanup_bounty,
+    get_config, get_expiration_horizon, get_hook_queue_length, get_namespace_schemas, get_namespaced_attestation,
+    get_namespaced_schema, get_org_attestations, get_outbox_entries, get_pending_admin, get_pending_attestation,
+    get_referencing_attestations, get_revoke_dst, get_schema_attestation_count, get_schema_challenge_window,
+    get_schema_dedup_window, get_schema_definition, get_schema_event_topic, get_schema_expiration_horizon,
+    get_schema_locales, get_schema_min_active_duration, get_schema_or_fail, get_schema_revocation, get_schema_stats,
+    get_schema_uniqueness_field, get_schema_versions, get_secp256k1_signer, get_storage_version, get_validity_proof,
+    get_voting_weight, get_voting_weight_rule, has_valid_attestation, has_valid_subject_attestation, import_legacy,
+    is_attestation_fresh, is_attestation_frozen, is_attestation_valid, is_attester_banned, is_locked, is_nonce_used,
+    is_org_member, is_paused, is_schema_consent_required, is_schema_hooks_deferred, is_schema_subject_revocable,
+    latest_attestation, list_attestations_by_locale, list_attester_attestations, list_schema_attestations,
+    list_schema_attestations_by_type, list_subject_attestations, migrate_storage, pause, process_hook_queue,
+    propose_admin, propose_admin_operation, purge_attestations, quote_attest_cost, register_bls_public_key,
+    register_schema, register_schema_version, register_secp256k1_signer, remove_org_member, request_attester_domain,
+    resolve_appeal, reveal_attestation, revoke_all, revoke_attestation, revoke_by_delegation,
+    revoke_by_delegation_secp256k1, revoke_self_attestation, sample_attestations, self_check,
+    set_accepted_message_versions, set_expiration_horizon, set_schema_challenge_window, set_schema_dedup_window,
+    set_schema_deferred_hooks, set_schema_event_topic, set_schema_expiration_horizon, set_schema_locales,
+    set_schema_min_active_duration, set_schema_requires_consent, set_schema_soulbound, set_schema_subject_revocable,
+    set_schema_uniqueness_field, set_voting_weight_rule, simulate_attestation_hooks, submit_delegated_batch,
+    unban_attester, unfreeze_attestation, unpause, upgrade, verify_attester_domain, verify_field,
 };
 
 #[contract]
@@ -38,6 +73,7 @@ impl AttestationContract {
             return Err(errors::Error::AlreadyInitialized);
         }
         env.storage().instance().set(&DataKey::Admin, &admin);
+        instructions::migration::set_storage_version(&env, instructions::migration::STORAGE_VERSION);
 
         // Emit contract initialization event
         events::publish_contract_initialized(&env, &admin);
@@ -45,6 +81,32 @@ impl AttestationContract {
         Ok(())
     }
 
+    /// Runs the protocol's invariant checks, for post-upgrade verification and monitoring.
+    ///
+    /// Checks that the admin and admin council are valid, that the protocol metrics are
+    /// consistent, and that the attestations behind the retained outbox entries have
+    /// matching records and listings with no gaps or entries beyond their counts.
+    ///
+    /// # Returns
+    ///
+    /// Returns the names of the failed checks (`ADMIN`, `COUNCIL`, `METRICS`, `OUTBOX`,
+    /// `RECORD`, `LISTING`), or an empty vector if the protocol is healthy.
+    pub fn self_check(env: Env) -> Vec<Symbol> {
+        self_check(&env)
+    }
+
+    /// Gets a snapshot of the contract's configuration.
+    ///
+    /// Lets monitoring tools and SDK health checks read the admin, version, cleanup bounty
+    /// pool and limits in one call, without knowing individual storage keys.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `ProtocolConfig` assembled from storage.
+    pub fn get_config(env: Env) -> ProtocolConfig {
+        get_config(&env)
+    }
+
     /// Registers a new attestation schema.
     ///
     /// A schema defines the structure and rules for attestations. Each schema is
@@ -53,7 +115,8 @@ impl AttestationContract {
     /// # Arguments
     ///
     /// * `caller` - The address of the entity registering the schema. The caller is designated as the schema's creator.
-    /// * `schema_definition` - A string defining the schema. The format of this string is up to the implementer.
+    /// * `schema_definition` - A string defining the schema. The format of this string is up to the implementer,
+    ///   except that definitions prefixed with `XDR:` must decode (see `encode_schema`).
     /// * `resolver` - An optional address of a contract that can resolve or validate attestations against this schema.
     /// * `revocable` - A boolean indicating whether attestations made against this schema can be revoked.
     ///
@@ -68,7 +131,36 @@ impl AttestationContract {
         resolver: Option<Address>,
         revocable: bool,
     ) -> Result<BytesN<32>, errors::Error> {
-        register_schema(&env, caller, schema_definition, resolver, revocable)
+        register_schema(&env, caller, schema_definition, resolver, revocable, None)
+    }
+
+    /// Registers a new attestation schema under a namespace.
+    ///
+    /// Namespaces let multiple organizations run distinct logical registries on a single
+    /// contract instance. A namespaced schema gets a UID distinct from the same definition
+    /// registered globally or in another namespace, and is listed only under its namespace.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The address of the entity registering the schema. The caller is designated as the schema's creator.
+    /// * `namespace` - The namespace to register the schema under.
+    /// * `schema_definition` - A string defining the schema. The format of this string is up to the implementer.
+    /// * `resolver` - An optional address of a contract that can resolve or validate attestations against this schema.
+    /// * `revocable` - A boolean indicating whether attestations made against this schema can be revoked.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the 32-byte UID of the newly registered schema,
+    /// or an error if the registration fails.
+    pub fn register_in_namespace(
+        env: Env,
+        caller: Address,
+        namespace: Symbol,
+        schema_definition: String,
+        resolver: Option<Address>,
+        revocable: bool,
+    ) -> Result<BytesN<32>, errors::Error> {
+        register_schema(&env, caller, schema_definition, resolver, revocable, Some(namespace))
     }
 
     /// Retrieves a registered schema by its UID.
@@ -85,159 +177,2484 @@ impl AttestationContract {
         get_schema_or_fail(&env, &schema_uid)
     }
 
-    /// Creates an attestation where the attester is also the subject.
+    /// Registers a new version of a schema that supersedes `old_uid`.
     ///
-    /// This function creates a new attestation based on a specified schema. The `attester`
-    /// must authorize this operation by signing the transaction, and they will also be the subject of the attestation.
+    /// The new version inherits the resolver, revocability and namespace of `old_uid`,
+    /// records it as `previous_uid` and increments `version`. Only the schema authority can
+    /// register a version, and only the latest version of a lineage can be superseded.
     ///
     /// # Arguments
     ///
-    /// * `attester` - The address of the entity making the attestation. Must be the transaction signer.
-    /// * `schema_uid` - The UID of the schema for which the attestation is being made.
-    /// * `value` - The value of the attestation, conforming to the schema's definition.
-    /// * `expiration_time` - An optional Unix timestamp indicating when the attestation expires.
+    /// * `caller` - The schema authority (must authorize the call).
+    /// * `old_uid` - The UID of the latest version of the schema.
+    /// * `new_definition` - The definition of the new version.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` containing the 32-byte UID of the newly created attestation,
-    /// or an error if the process fails.
-    pub fn attest(
+    /// Returns a `Result` containing the UID of the new version, or an error if the
+    /// caller is not the authority or `old_uid` has already been superseded.
+    pub fn register_schema_version(
         env: Env,
-        attester: Address,
-        schema_uid: BytesN<32>,
-        value: String,
-        expiration_time: Option<u64>,
+        caller: Address,
+        old_uid: BytesN<32>,
+        new_definition: String,
     ) -> Result<BytesN<32>, errors::Error> {
-        attest(&env, attester, schema_uid, value, expiration_time)
+        register_schema_version(&env, caller, old_uid, new_definition)
     }
 
-    /// Revokes an existing attestation.
+    /// Retrieves every version of the lineage a schema belongs to.
     ///
-    /// Only the original attester or an authorized party (as defined by the schema) can
-    /// revoke an attestation. The schema must also permit revocations.
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The UID of any version of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the UIDs of all versions, first version first,
+    /// or an error if the schema does not exist.
+    pub fn get_schema_versions(env: Env, schema_uid: BytesN<32>) -> Result<Vec<BytesN<32>>, errors::Error> {
+        get_schema_versions(&env, &schema_uid)
+    }
+
+    /// Configures duplicate-attestation rejection for a schema.
+    ///
+    /// While enabled, an attestation with the same attester, subject and value as one issued
+    /// under the schema within the window is rejected with `AttestationExists`. This keeps
+    /// relayer retries from creating near-duplicate records.
     ///
     /// # Arguments
     ///
-    /// * `revoker` - The address of the entity revoking the attestation. Must be authorized to perform this action.
-    /// * `attestation_uid` - The UID of the attestation to be revoked.
+    /// * `caller` - The schema authority. Must authorize the transaction.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `window_ledgers` - The deduplication window in ledgers. `0` disables deduplication.
     ///
     /// # Returns
     ///
-    /// Returns `Ok(())` on successful revocation, or an error if the revocation fails.
-    pub fn revoke(env: Env, revoker: Address, attestation_uid: BytesN<32>) -> Result<(), errors::Error> {
-        revoke_attestation(&env, revoker, attestation_uid)
+    /// Returns `Ok(())` on success, or an error if the schema does not exist, the caller
+    /// is not its authority, or the window outlasts the maximum storage TTL.
+    pub fn set_schema_dedup_window(
+        env: Env,
+        caller: Address,
+        schema_uid: BytesN<32>,
+        window_ledgers: u32,
+    ) -> Result<(), errors::Error> {
+        set_schema_dedup_window(&env, caller, schema_uid, window_ledgers)
     }
 
-    /// Retrieves an attestation by its UID.
+    /// Gets a schema's deduplication window in ledgers.
     ///
     /// # Arguments
     ///
-    /// * `attestation_uid` - The 32-byte unique identifier of the attestation to retrieve.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` containing the `Attestation` struct if found, or an error
-    /// if no attestation with the given UID exists.
-    pub fn get_attestation(env: Env, attestation_uid: BytesN<32>) -> Result<Attestation, errors::Error> {
-        get_attestation_record(&env, attestation_uid)
+    /// Returns the window in ledgers, or `0` if deduplication is disabled.
+    pub fn get_schema_dedup_window(env: Env, schema_uid: BytesN<32>) -> u32 {
+        get_schema_dedup_window(&env, &schema_uid)
     }
 
-    // ══════════════════════════════════════════════════════════════════════════════
-    // ► Delegated Attestation Functions
-    // ══════════════════════════════════════════════════════════════════════════════
+    /// Sets the minimum time a schema's attestations stay active before they can be revoked.
+    ///
+    /// Prevents issue-and-revoke spam that games reward resolvers: attester revocations
+    /// within the window fail with `RevocationTooEarly`. The admin is not restricted.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The schema authority. Must authorize the transaction.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `seconds` - The minimum active duration in seconds. `0` removes the restriction.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the schema does not exist or the caller
+    /// is not its authority.
+    pub fn set_schema_min_active_duration(
+        env: Env,
+        caller: Address,
+        schema_uid: BytesN<32>,
+        seconds: u64,
+    ) -> Result<(), errors::Error> {
+        set_schema_min_active_duration(&env, caller, schema_uid, seconds)
+    }
 
-    /// Creates an attestation using a delegated signature.
+    /// Gets a schema's minimum active duration in seconds.
     ///
-    /// This method allows for gas-less attestations where a `submitter` can post an
-    /// attestation on behalf of an `attester`. The `attester`'s authorization is
-    /// verified through a signed `DelegatedAttestationRequest`. The attestation UID
-    /// can be derived off-chain from the request parameters.
+    /// # Arguments
     ///
-    /// Anyone can submit this transaction, paying the fees.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns the duration in seconds, or `0` if attestations can be revoked at any time.
+    pub fn get_schema_min_active_duration(env: Env, schema_uid: BytesN<32>) -> u64 {
+        get_schema_min_active_duration(&env, &schema_uid)
+    }
+
+    /// Caps how far in the future a schema's attestations may expire.
+    ///
+    /// Attestations with a later `expiration_time` fail with `ExpirationTooFar`. When the
+    /// protocol-wide cap is also set, the tighter of the two applies.
     ///
     /// # Arguments
     ///
-    /// * `submitter` - The address submitting the transaction, which must authorize the invocation.
-    /// * `request` - The `DelegatedAttestationRequest` struct containing the attestation details and the attester's signature.
+    /// * `caller` - The schema authority. Must authorize the transaction.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `seconds` - The maximum horizon in seconds. `0` removes the cap.
     ///
     /// # Returns
     ///
-    /// Returns `Ok(())` on success, or an error if the request is invalid or signature verification fails.
-    pub fn attest_by_delegation(
+    /// Returns `Ok(())` on success, or an error if the schema does not exist or the caller
+    /// is not its authority.
+    pub fn set_schema_expiration_horizon(
         env: Env,
-        submitter: Address,
-        request: DelegatedAttestationRequest,
+        caller: Address,
+        schema_uid: BytesN<32>,
+        seconds: u64,
     ) -> Result<(), errors::Error> {
-        attest_by_delegation(&env, submitter, request)
+        set_schema_expiration_horizon(&env, caller, schema_uid, seconds)
     }
 
-    /// Revokes an attestation using a delegated signature.
+    /// Gets a schema's own expiration horizon in seconds.
     ///
-    /// This method allows for gas-less revocations where a `submitter` can post a
-    /// revocation on behalf of a `revoker`. The `revoker`'s authorization is
-    /// verified through a signed `DelegatedRevocationRequest`.
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns the horizon in seconds, or `0` if the schema sets no cap.
+    pub fn get_schema_expiration_horizon(env: Env, schema_uid: BytesN<32>) -> u64 {
+        get_schema_expiration_horizon(&env, &schema_uid)
+    }
+
+    /// Caps how far in the future any attestation may expire.
+    ///
+    /// Keeps storage rent projections bounded and forces periodic credential renewal.
     ///
     /// # Arguments
     ///
-    /// * `submitter` - The address submitting the transaction, which must authorize the invocation.
-    /// * `request` - The `DelegatedRevocationRequest` struct containing the revocation details and the revoker's signature.
+    /// * `admin` - The contract admin. Must authorize the transaction.
+    /// * `seconds` - The maximum horizon in seconds, e.g. five years. `0` removes the cap.
     ///
     /// # Returns
     ///
-    /// Returns `Ok(())` on success, or an error if the request is invalid or signature verification fails.
-    pub fn revoke_by_delegation(
+    /// Returns `Ok(())` on success, or an error if the caller is not the admin.
+    pub fn set_expiration_horizon(env: Env, admin: Address, seconds: u64) -> Result<(), errors::Error> {
+        set_expiration_horizon(&env, admin, seconds)
+    }
+
+    /// Gets the protocol-wide expiration horizon in seconds.
+    ///
+    /// # Returns
+    ///
+    /// Returns the horizon in seconds, or `0` if expirations are uncapped protocol-wide.
+    pub fn get_expiration_horizon(env: Env) -> u64 {
+        get_expiration_horizon(&env)
+    }
+
+    /// Sets a custom event topic for a schema's attestations.
+    ///
+    /// The topic is emitted alongside the standard `ATTEST` topics on every attestation and
+    /// revocation event for the schema, so products can subscribe to only their own
+    /// attestations with a topic filter instead of filtering the full event stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The schema authority. Must authorize the transaction.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `topic` - The custom topic, or `None` to stop emitting one.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the schema does not exist or the caller
+    /// is not its authority.
+    pub fn set_schema_event_topic(
         env: Env,
-        submitter: Address,
-        request: DelegatedRevocationRequest,
+        caller: Address,
+        schema_uid: BytesN<32>,
+        topic: Option<Symbol>,
     ) -> Result<(), errors::Error> {
-        revoke_by_delegation(&env, submitter, request)
+        set_schema_event_topic(&env, caller, schema_uid, topic)
     }
 
-    /// Gets the next nonce for an attester.
+    /// Gets a schema's custom event topic.
     ///
-    /// Nonces are used in delegated requests to prevent replay attacks. Each delegated
-    /// request from an attester must have a unique, sequential nonce.
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns the topic, or `None` if the schema emits only the standard topics.
+    pub fn get_schema_event_topic(env: Env, schema_uid: BytesN<32>) -> Option<Symbol> {
+        get_schema_event_topic(&env, &schema_uid)
+    }
+
+    /// Restricts the locales a schema's attestations may be written in.
+    ///
+    /// While set, `attest_with_locale` only accepts the listed locales. Attestations
+    /// without a locale are unaffected.
     ///
     /// # Arguments
     ///
-    /// * `attester` - The address of the attester.
+    /// * `caller` - The schema authority. Must authorize the transaction.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `locales` - The allowed locales, or an empty list to allow any locale.
     ///
     /// # Returns
     ///
-    /// Returns the next expected nonce (`u64`) for the given attester.
-    pub fn get_attester_nonce(env: Env, attester: Address) -> u64 {
-        utils::get_next_nonce(&env, &attester)
+    /// Returns `Ok(())` on success, or an error if the schema does not exist or the caller
+    /// is not its authority.
+    pub fn set_schema_locales(
+        env: Env,
+        caller: Address,
+        schema_uid: BytesN<32>,
+        locales: Vec<Symbol>,
+    ) -> Result<(), errors::Error> {
+        set_schema_locales(&env, caller, schema_uid, locales)
     }
 
-    /// Registers a BLS public key for an attester.
+    /// Gets the locales a schema's attestations may be written in.
     ///
-    /// This public key can be used to verify delegated attestations and revocations,
-    /// enabling more advanced cryptographic operations. The attester must authorize this registration.
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns the allowed locales, or an empty list if any locale is allowed.
+    pub fn get_schema_locales(env: Env, schema_uid: BytesN<32>) -> Vec<Symbol> {
+        get_schema_locales(&env, &schema_uid)
+    }
+
+    /// Requires subjects to accept attestations made about them under a schema, or lifts the requirement.
+    ///
+    /// While set, delegated attestations about another address stay pending until the
+    /// subject calls `accept_attestation`.
     ///
     /// # Arguments
     ///
-    /// * `attester` - The address of the attester for whom the BLS key is being registered. Must authorize transaction.
-    /// * `public_key` - The 192-byte BLS public key.
+    /// * `caller` - The schema authority. Must authorize the transaction.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `required` - Whether subjects must accept attestations under the schema.
     ///
     /// # Returns
     ///
-    /// Returns `Ok(())` on successful registration, or an error if one already exists or registration fails.
-    pub fn register_bls_key(env: Env, attester: Address, public_key: BytesN<192>) -> Result<(), errors::Error> {
-        register_bls_public_key(&env, attester, public_key)
+    /// Returns `Ok(())` on success, or an error if the schema does not exist or the caller
+    /// is not its authority.
+    pub fn set_schema_requires_consent(
+        env: Env,
+        caller: Address,
+        schema_uid: BytesN<32>,
+        required: bool,
+    ) -> Result<(), errors::Error> {
+        set_schema_requires_consent(&env, caller, schema_uid, required)
     }
 
-    /// Gets the BLS public key for an attester.
+    /// Checks whether subjects must accept attestations made under a schema.
     ///
     /// # Arguments
     ///
-    /// * `attester` - The address of the attester.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` containing the `BlsPublicKey` if found, or an error if no key
-    /// is registered for the given attester.
-    pub fn get_bls_key(env: Env, attester: Address) -> Result<BlsPublicKey, errors::Error> {
-        get_bls_public_key(&env, &attester)
+    /// Returns `true` if attestations about other addresses are held until accepted.
+    pub fn is_schema_consent_required(env: Env, schema_uid: BytesN<32>) -> bool {
+        is_schema_consent_required(&env, &schema_uid)
+    }
+
+    /// Marks a schema as soulbound, or clears the mark.
+    ///
+    /// Attestations created under a soulbound schema emit an ERC-5192-style `Locked(uid)`
+    /// event and are reported as locked by `is_locked`, so NFT-adjacent tooling that follows
+    /// lock-event conventions can index them as credentials.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The schema authority. Must authorize the transaction.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `soulbound` - Whether the schema is soulbound.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the schema does not exist or the caller
+    /// is not its authority.
+    pub fn set_schema_soulbound(
+        env: Env,
+        caller: Address,
+        schema_uid: BytesN<32>,
+        soulbound: bool,
+    ) -> Result<(), errors::Error> {
+        set_schema_soulbound(&env, caller, schema_uid, soulbound)
+    }
+
+    /// Sets how long after a revocation under a schema its subject may appeal it.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The schema authority. Must authorize the transaction.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `window` - The challenge window in seconds, or 0 to disable appeals.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the schema does not exist or the caller
+    /// is not its authority.
+    pub fn set_schema_challenge_window(
+        env: Env,
+        caller: Address,
+        schema_uid: BytesN<32>,
+        window: u64,
+    ) -> Result<(), errors::Error> {
+        set_schema_challenge_window(&env, caller, schema_uid, window)
+    }
+
+    /// Gets how long after a revocation under a schema its subject may appeal it.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns the challenge window in seconds, or 0 if revocations cannot be appealed.
+    pub fn get_schema_challenge_window(env: Env, schema_uid: BytesN<32>) -> u64 {
+        get_schema_challenge_window(&env, &schema_uid)
+    }
+
+    /// Lets the subjects of a schema's attestations revoke them through `revoke`, or withdraws the right.
+    ///
+    /// Intended for self-sovereign schemas, where subjects must be able to erase attestations
+    /// about themselves even if the schema is otherwise not revocable.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The schema authority. Must authorize the transaction.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `subject_revocable` - Whether subjects may revoke attestations about themselves.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the schema does not exist or the caller
+    /// is not its authority.
+    pub fn set_schema_subject_revocable(
+        env: Env,
+        caller: Address,
+        schema_uid: BytesN<32>,
+        subject_revocable: bool,
+    ) -> Result<(), errors::Error> {
+        set_schema_subject_revocable(&env, caller, schema_uid, subject_revocable)
+    }
+
+    /// Checks whether subjects may revoke attestations about themselves under a schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the schema is subject-revocable.
+    pub fn is_schema_subject_revocable(env: Env, schema_uid: BytesN<32>) -> bool {
+        is_schema_subject_revocable(&env, &schema_uid)
+    }
+
+    /// Defers a schema's resolver hooks to the hook queue, or restores inline hooks.
+    ///
+    /// With deferred hooks, `attest` stores the attestation immediately and enqueues its
+    /// resolver processing for `process_hook_queue`, so slow resolvers don't add to the
+    /// latency and fee of attesting. Queued attestations read as valid until their hooks
+    /// run, and are revoked if the resolver then rejects them.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The schema authority. Must authorize the transaction.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `deferred` - Whether the schema's hooks are deferred.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the schema does not exist or the caller
+    /// is not its authority.
+    pub fn set_schema_deferred_hooks(
+        env: Env,
+        caller: Address,
+        schema_uid: BytesN<32>,
+        deferred: bool,
+    ) -> Result<(), errors::Error> {
+        set_schema_deferred_hooks(&env, caller, schema_uid, deferred)
+    }
+
+    /// Checks whether a schema's resolver hooks are deferred to the hook queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the schema's hooks are deferred.
+    pub fn is_schema_hooks_deferred(env: Env, schema_uid: BytesN<32>) -> bool {
+        is_schema_hooks_deferred(&env, &schema_uid)
+    }
+
+    /// Runs the deferred resolver hooks of the oldest queued attestations.
+    ///
+    /// Permissionless, so keepers, relayers or the schema authority can crank the queue.
+    /// Attestations the resolver rejects are revoked; those revoked, expired or purged
+    /// while queued are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of queue entries to process, capped at 25.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of entries removed from the queue.
+    pub fn process_hook_queue(env: Env, limit: u32) -> u32 {
+        process_hook_queue(&env, limit)
+    }
+
+    /// Gets the number of attestations waiting for their deferred hooks to run.
+    ///
+    /// # Returns
+    ///
+    /// Returns the hook queue length.
+    pub fn get_hook_queue_length(env: Env) -> u64 {
+        get_hook_queue_length(&env)
+    }
+
+    /// Revokes every attestation under a schema at once, for catastrophic issuer compromise.
+    ///
+    /// No attestation record is rewritten. Attestations under the schema are reported as
+    /// revoked by `get_attestation` and never count as valid, and no new attestations can be
+    /// issued under it. The revocation cannot be undone.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The schema authority. Must authorize the transaction.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `reason` - Why the schema was revoked.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the schema does not exist, the caller is
+    /// not its authority, or the schema is already revoked.
+    pub fn revoke_all(env: Env, caller: Address, schema_uid: BytesN<32>, reason: String) -> Result<(), errors::Error> {
+        revoke_all(&env, caller, schema_uid, reason)
+    }
+
+    /// Gets a schema's global revocation, if `revoke_all` was called on it.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    ///
+    /// # Returns
+    ///
+    /// The reason and time of the revocation, or `None` if the schema is not revoked.
+    pub fn get_schema_revocation(env: Env, schema_uid: BytesN<32>) -> Option<SchemaRevocation> {
+        get_schema_revocation(&env, &schema_uid)
+    }
+
+    /// Sets how a schema's attestations translate into voting weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The schema authority. Must authorize the transaction.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `rule` - The attestation field and tier weights to use, or `None` to give every
+    ///   valid holder a weight of 1.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the schema does not exist or the caller
+    /// is not its authority.
+    pub fn set_voting_weight_rule(
+        env: Env,
+        caller: Address,
+        schema_uid: BytesN<32>,
+        rule: Option<VotingWeightRule>,
+    ) -> Result<(), errors::Error> {
+        set_voting_weight_rule(&env, caller, schema_uid, rule)
+    }
+
+    /// Gets a schema's voting weight rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns the rule, or `None` if every valid holder has a weight of 1.
+    pub fn get_voting_weight_rule(env: Env, schema_uid: BytesN<32>) -> Option<VotingWeightRule> {
+        get_voting_weight_rule(&env, &schema_uid)
+    }
+
+    /// Declares the value field whose hash must be unique among a schema's active attestations.
+    ///
+    /// Prevents one person from obtaining duplicate credentials with different wallets, e.g.
+    /// by keying a KYC schema on a national ID hash: a second active attestation with the
+    /// same key fails with `UniquenessKeyTaken`, and values without the field fail with
+    /// `UniquenessKeyMissing`.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The schema authority. Must authorize the transaction.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `field` - The name of the value field, or `None` to lift the constraint.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the schema does not exist or the caller
+    /// is not its authority.
+    pub fn set_schema_uniqueness_field(
+        env: Env,
+        caller: Address,
+        schema_uid: BytesN<32>,
+        field: Option<String>,
+    ) -> Result<(), errors::Error> {
+        set_schema_uniqueness_field(&env, caller, schema_uid, field)
+    }
+
+    /// Gets the name of a schema's uniqueness field.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns the field name, or `None` if attestations need not be unique.
+    pub fn get_schema_uniqueness_field(env: Env, schema_uid: BytesN<32>) -> Option<String> {
+        get_schema_uniqueness_field(&env, &schema_uid)
+    }
+
+    /// Finds the active attestation holding a uniqueness key under a schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `key_hash` - `sha256` of the field's raw JSON value, or of its `FieldValue` XDR for
+    ///   structured values.
+    ///
+    /// # Returns
+    ///
+    /// Returns the UID of the holding attestation, or `None` if the key is free.
+    pub fn find_by_uniqueness_key(env: Env, schema_uid: BytesN<32>, key_hash: BytesN<32>) -> Option<BytesN<32>> {
+        find_by_uniqueness_key(&env, schema_uid, key_hash)
+    }
+
+    /// Gets a subject's voting weight under a schema, for use by governance contracts.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject` - The voter's address.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns 0 if the subject holds no valid attestation under the schema, otherwise the
+    /// weight derived from their attestation by the schema's voting weight rule.
+    pub fn get_voting_weight(env: Env, subject: Address, schema_uid: BytesN<32>) -> u64 {
+        get_voting_weight(&env, &subject, &schema_uid)
+    }
+
+    /// Retrieves a registered schema by its UID, scoped to a namespace.
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace` - The namespace the schema must belong to.
+    /// * `schema_uid` - The 32-byte unique identifier of the schema to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `Schema` struct if found, or an error
+    /// if the schema does not exist or belongs to a different namespace.
+    pub fn get_namespaced_schema(env: Env, namespace: Symbol, schema_uid: BytesN<32>) -> Result<Schema, errors::Error> {
+        get_namespaced_schema(&env, &namespace, &schema_uid)
+    }
+
+    /// Lists the schemas registered under a namespace, in registration order.
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace` - The namespace to list.
+    /// * `start` - Index of the first schema to return.
+    /// * `limit` - Maximum number of schema UIDs to return.
+    ///
+    /// # Returns
+    ///
+    /// Returns the UIDs of up to `limit` schemas starting at `start`.
+    pub fn get_namespace_schemas(env: Env, namespace: Symbol, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        get_namespace_schemas(&env, &namespace, start, limit)
+    }
+
+    /// Encodes a typed schema definition as an `XDR:`-prefixed definition string.
+    ///
+    /// Encoding is deterministic, so clients can use this view to produce the exact
+    /// string (and therefore schema UID) for a definition before registering it.
+    ///
+    /// # Arguments
+    ///
+    /// * `definition` - The typed schema definition.
+    ///
+    /// # Returns
+    ///
+    /// Returns `XDR:` followed by the base64 XDR of the definition.
+    pub fn encode_schema(env: Env, definition: SchemaDefinition) -> String {
+        xdr_codec::encode_schema(&env, &definition)
+    }
+
+    /// Decodes a registered schema's `XDR:`-prefixed definition.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the typed `SchemaDefinition`, or an error if the
+    /// schema does not exist or its definition is not XDR-encoded.
+    pub fn get_schema_definition(env: Env, schema_uid: BytesN<32>) -> Result<SchemaDefinition, errors::Error> {
+        get_schema_definition(&env, &schema_uid)
+    }
+
+    /// Encodes field values as a `BIN:`-prefixed structured value for a schema.
+    ///
+    /// The value is laid out by the schema's declared field order rather than as JSON,
+    /// so clients can use this view to produce the exact string to attest with.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `values` - One entry per schema field, in declared order, with
+    ///   `FieldValue::Absent` for omitted optional fields.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing `BIN:` followed by the base64 payload, or an error
+    /// if the schema's definition is not XDR-encoded or the values do not match its fields.
+    pub fn encode_value(env: Env, schema_uid: BytesN<32>, values: Vec<FieldValue>) -> Result<String, errors::Error> {
+        encode_attestation_value(&env, &schema_uid, &values)
+    }
+
+    /// Decodes a `BIN:`-prefixed structured value against a schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `value` - The structured value string.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing one `FieldValue` per schema field, or an error if
+    /// the value is not a canonical encoding for the schema.
+    pub fn decode_value(env: Env, schema_uid: BytesN<32>, value: String) -> Result<Vec<FieldValue>, errors::Error> {
+        decode_attestation_value(&env, &schema_uid, &value)
+    }
+
+    /// Compares a registered schema's definition with a proposed successor definition.
+    ///
+    /// Lets authorities check a "v2" before registering it: removed fields, type changes
+    /// and required fields made optional are reported as breaking, new fields and
+    /// optional fields made required as additive.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the registered schema.
+    /// * `definition` - The proposed `XDR:`-prefixed definition.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `SchemaCompatibility` report, or an error if the
+    /// schema does not exist or either definition is not a valid XDR definition.
+    pub fn check_schema_compatibility(
+        env: Env,
+        schema_uid: BytesN<32>,
+        definition: String,
+    ) -> Result<SchemaCompatibility, errors::Error> {
+        check_schema_compatibility(&env, &schema_uid, &definition)
+    }
+
+    /// Retrieves the running statistics for a schema.
+    ///
+    /// Statistics are maintained incrementally on every attestation and revocation, so
+    /// explorers can render a schema page from a single call.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `SchemaStats` (issued, revoked, active, unique
+    /// subjects and last issuance time), or an error if the schema does not exist.
+    pub fn get_schema_stats(env: Env, schema_uid: BytesN<32>) -> Result<SchemaStats, errors::Error> {
+        get_schema_stats(&env, &schema_uid)
+    }
+
+    /// Retrieves the number of attestations ever issued under a schema.
+    ///
+    /// Shorthand for the `issued` field of `get_schema_stats`, for resolvers and analytics
+    /// that only need issuance volume.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the count, including revoked and expired attestations,
+    /// or an error if the schema does not exist.
+    pub fn get_schema_attestation_count(env: Env, schema_uid: BytesN<32>) -> Result<u64, errors::Error> {
+        get_schema_attestation_count(&env, &schema_uid)
+    }
+
+    /// Creates an attestation where the attester is also the subject.
+    ///
+    /// This function creates a new attestation based on a specified schema. The `attester`
+    /// must authorize this operation by signing the transaction, and they will also be the subject of the attestation.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the entity making the attestation. Must be the transaction signer.
+    /// * `schema_uid` - The UID of the schema for which the attestation is being made.
+    /// * `value` - The value of the attestation, conforming to the schema's definition.
+    /// * `expiration_time` - An optional Unix timestamp indicating when the attestation expires.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the 32-byte UID of the newly created attestation,
+    /// or an error if the process fails.
+    pub fn attest(
+        env: Env,
+        attester: Address,
+        schema_uid: BytesN<32>,
+        value: String,
+        expiration_time: Option<u64>,
+    ) -> Result<BytesN<32>, errors::Error> {
+        attest(&env, attester, schema_uid, value, expiration_time)
+    }
+
+    /// Dry-runs a schema's resolver hooks against a prospective attestation.
+    ///
+    /// Invokes the resolver's `onattest` hook with the attestation converted exactly as
+    /// `attest` would, without storing anything, and reports rejections and resolver
+    /// errors instead of failing. Intended for RPC simulation so SDKs can show gating
+    /// failures before users sign a real transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The UID of the schema whose resolver hooks to run.
+    /// * `attestation` - The prospective attestation. Its `schema_uid` is replaced by `schema_uid`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `HookSimulation` describing the resolver's verdict, or an error if the
+    /// schema does not exist.
+    pub fn simulate_hooks(
+        env: Env,
+        schema_uid: BytesN<32>,
+        attestation: Attestation,
+    ) -> Result<HookSimulation, errors::Error> {
+        simulate_attestation_hooks(&env, schema_uid, attestation)
+    }
+
+    /// Quotes what a schema's resolver would charge an attester for an attestation.
+    ///
+    /// Lets wallets authorize exact token amounts ahead of submission instead of
+    /// over-approving.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The UID of the schema to attest to.
+    /// * `attester` - The address that would create and pay for the attestation.
+    ///
+    /// # Returns
+    ///
+    /// Returns the (token, amount) pairs the resolver would take, empty if nothing is
+    /// charged, or an error if the schema does not exist.
+    pub fn quote_attest_cost(
+        env: Env,
+        schema_uid: BytesN<32>,
+        attester: Address,
+    ) -> Result<Vec<(Address, i128)>, errors::Error> {
+        quote_attest_cost(&env, schema_uid, attester)
+    }
+
+    /// Creates an attestation on behalf of an organization.
+    ///
+    /// The attester must be a registered member of the organization. The organization is
+    /// recorded in the attestation's `on_behalf_of` field and the attestation is listed
+    /// under the organization, so institutional issuance isn't tied to one member's key.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The member making the attestation. Must be the transaction signer.
+    /// * `organization` - The organization the attestation is issued on behalf of.
+    /// * `schema_uid` - The UID of the schema for which the attestation is being made.
+    /// * `value` - The value of the attestation, conforming to the schema's definition.
+    /// * `expiration_time` - An optional Unix timestamp indicating when the attestation expires.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the 32-byte UID of the newly created attestation,
+    /// or `NotAuthorized` if the attester is not a member of the organization.
+    pub fn attest_on_behalf_of(
+        env: Env,
+        attester: Address,
+        organization: Address,
+        schema_uid: BytesN<32>,
+        value: String,
+        expiration_time: Option<u64>,
+    ) -> Result<BytesN<32>, errors::Error> {
+        attest_on_behalf_of(&env, attester, organization, schema_uid, value, expiration_time)
+    }
+
+    /// Creates an attestation carrying an external reference ID.
+    ///
+    /// Identical to `attest`, but also attaches an opaque `external_id` chosen by the attester,
+    /// such as an internal record ID, so the attestation can later be found with
+    /// `get_attestation_by_external_id` without an off-chain mapping service.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the entity making the attestation. Must be the transaction signer.
+    /// * `schema_uid` - The UID of the schema for which the attestation is being made.
+    /// * `value` - The value or content of the attestation.
+    /// * `expiration_time` - An optional Unix timestamp indicating when the attestation expires.
+    /// * `external_id` - The reference ID (1 to 64 bytes), unique per attester.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the 32-byte UID of the newly created attestation,
+    /// or `AttestationExists` if the attester already used `external_id`.
+    pub fn attest_with_external_id(
+        env: Env,
+        attester: Address,
+        schema_uid: BytesN<32>,
+        value: String,
+        expiration_time: Option<u64>,
+        external_id: Bytes,
+    ) -> Result<BytesN<32>, errors::Error> {
+        attest_with_external_id(&env, attester, schema_uid, value, expiration_time, external_id)
+    }
+
+    /// Creates an attestation written in a given locale.
+    ///
+    /// Identical to `attest`, but also records `locale` on the attestation and in its
+    /// `ATTEST`/`CREATE` event, and lists it under `list_attestations_by_locale`.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the entity making the attestation. Must be the transaction signer.
+    /// * `schema_uid` - The UID of the schema for which the attestation is being made.
+    /// * `value` - The value or content of the attestation.
+    /// * `expiration_time` - An optional Unix timestamp indicating when the attestation expires.
+    /// * `locale` - The language of the attestation, e.g. `en` or `pt_BR`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the 32-byte UID of the newly created attestation,
+    /// or `LocaleNotAllowed` if the schema restricts locales and `locale` is not among them.
+    pub fn attest_with_locale(
+        env: Env,
+        attester: Address,
+        schema_uid: BytesN<32>,
+        value: String,
+        expiration_time: Option<u64>,
+        locale: Symbol,
+    ) -> Result<BytesN<32>, errors::Error> {
+        attest_with_locale(&env, attester, schema_uid, value, expiration_time, locale)
+    }
+
+    /// Creates an attestation that builds on an existing one.
+    ///
+    /// Identical to `attest`, but also records `ref_uid` on the attestation and lists it
+    /// under `get_referencing_attestations(ref_uid, ..)`, so chained credentials can be
+    /// traversed on-chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the entity making the attestation. Must be the transaction signer.
+    /// * `schema_uid` - The UID of the schema for which the attestation is being made.
+    /// * `value` - The value or content of the attestation.
+    /// * `expiration_time` - An optional Unix timestamp indicating when the attestation expires.
+    /// * `ref_uid` - The UID of the attestation this one builds on.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the 32-byte UID of the newly created attestation,
+    /// or `InvalidReference` if `ref_uid` does not exist or is revoked.
+    pub fn attest_with_ref(
+        env: Env,
+        attester: Address,
+        schema_uid: BytesN<32>,
+        value: String,
+        expiration_time: Option<u64>,
+        ref_uid: BytesN<32>,
+    ) -> Result<BytesN<32>, errors::Error> {
+        attest_with_ref(&env, attester, schema_uid, value, expiration_time, ref_uid)
+    }
+
+    /// Creates a selective disclosure attestation from per-field hash commitments.
+    ///
+    /// Each field is committed as `sha256(field_value || salt)`. The values and salts are
+    /// shared with the subject off-chain, who can later prove single fields (e.g. "over 18")
+    /// with `verify_field` without revealing the whole credential on-chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the entity making the attestation. Must be the transaction signer.
+    /// * `schema_uid` - The UID of the schema for which the attestation is being made.
+    /// * `field_commitments` - One commitment per field (1 to 32), in field order.
+    /// * `expiration_time` - An optional Unix timestamp indicating when the attestation expires.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the 32-byte UID of the newly created attestation,
+    /// or `InvalidFieldCommitments` if the commitment count is out of range.
+    pub fn attest_with_commitments(
+        env: Env,
+        attester: Address,
+        schema_uid: BytesN<32>,
+        field_commitments: Vec<BytesN<32>>,
+        expiration_time: Option<u64>,
+    ) -> Result<BytesN<32>, errors::Error> {
+        attest_with_commitments(&env, attester, schema_uid, field_commitments, expiration_time)
+    }
+
+    /// Verifies a disclosed field of a selective disclosure attestation.
+    ///
+    /// # Arguments
+    ///
+    /// * `attestation_uid` - The UID of the attestation.
+    /// * `field_index` - The position of the field in the attestation's commitments.
+    /// * `value` - The disclosed field value.
+    /// * `salt` - The salt the field was committed with.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the field matches its commitment and the attestation is not revoked,
+    /// or an error if the attestation does not exist, has expired or has no such field.
+    pub fn verify_field(
+        env: Env,
+        attestation_uid: BytesN<32>,
+        field_index: u32,
+        value: Bytes,
+        salt: BytesN<32>,
+    ) -> Result<bool, errors::Error> {
+        verify_field(&env, attestation_uid, field_index, value, salt)
+    }
+
+    /// Creates an attestation about a muxed sub-account.
+    ///
+    /// For exchanges and custodians issuing credentials to muxed (`M...`) accounts. The
+    /// attestation is indexed under the subject's underlying account and under the
+    /// sub-account's mux ID, so it can be queried in either form. Schemas that require
+    /// subject consent reject attestations about subjects other than the attester.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the entity making the attestation. Must be the transaction signer.
+    /// * `schema_uid` - The UID of the schema for which the attestation is being made.
+    /// * `subject` - The underlying (`G...`) account of the subject sub-account.
+    /// * `mux_id` - The mux ID of the subject sub-account.
+    /// * `value` - The value or content of the attestation.
+    /// * `expiration_time` - An optional Unix timestamp indicating when the attestation expires.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the 32-byte UID of the newly created attestation.
+    pub fn attest_muxed(
+        env: Env,
+        attester: Address,
+        schema_uid: BytesN<32>,
+        subject: Address,
+        mux_id: u64,
+        value: String,
+        expiration_time: Option<u64>,
+    ) -> Result<BytesN<32>, errors::Error> {
+        attest_muxed(&env, attester, schema_uid, subject, mux_id, value, expiration_time)
+    }
+
+    /// Issues an attestation to be claimed later by whoever holds a secret.
+    ///
+    /// The recipient is unknown at issue time; the attester hands the preimage of
+    /// `claim_hash` to them off-chain, e.g. in a claim link, and they call `claim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the entity making the attestation. Must be the transaction signer.
+    /// * `schema_uid` - The UID of the schema for which the attestation is being made.
+    /// * `value` - The value or content of the attestation.
+    /// * `claim_hash` - The SHA-256 hash of the claim secret.
+    /// * `expiry` - The Unix timestamp after which the attestation can no longer be claimed.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the 32-byte UID the attestation will have once claimed,
+    /// or an error if the schema is missing or `expiry` is not in the future.
+    pub fn attest_claimable(
+        env: Env,
+        attester: Address,
+        schema_uid: BytesN<32>,
+        value: String,
+        claim_hash: BytesN<32>,
+        expiry: u64,
+    ) -> Result<BytesN<32>, errors::Error> {
+        attest_claimable(&env, attester, schema_uid, value, claim_hash, expiry)
+    }
+
+    /// Claims an attestation issued with `attest_claimable`, binding it to `subject`.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The UID returned by `attest_claimable`.
+    /// * `preimage` - The claim secret whose SHA-256 hash was committed by the attester.
+    /// * `subject` - The address to bind the attestation to. Must be the transaction signer.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` once the attestation is stored, or an error if it is unknown,
+    /// already claimed, past its expiry, or the preimage does not match.
+    pub fn claim(env: Env, uid: BytesN<32>, preimage: Bytes, subject: Address) -> Result<(), errors::Error> {
+        claim(&env, uid, preimage, subject)
+    }
+
+    /// Accepts an attestation held pending under a schema that requires subject consent.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject` - The subject of the attestation. Must authorize the transaction.
+    /// * `uid` - The UID of the pending attestation.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` once the attestation is stored, or an error if it is not pending,
+    /// `subject` is not its subject, it expired before being accepted, or it would no longer
+    /// be issued: the attester is banned, the schema revoked or the uniqueness key taken.
+    pub fn accept_attestation(env: Env, subject: Address, uid: BytesN<32>) -> Result<(), errors::Error> {
+        accept_attestation(&env, subject, uid)
+    }
+
+    /// Gets an attestation awaiting its subject's acceptance.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The UID of the pending attestation.
+    ///
+    /// # Returns
+    ///
+    /// Returns the pending attestation, or `AttestationNotFound` if none is pending under the UID.
+    pub fn get_pending_attestation(env: Env, uid: BytesN<32>) -> Result<Attestation, errors::Error> {
+        get_pending_attestation(&env, uid)
+    }
+
+    /// Commits to an attestation by hash, ahead of revealing its content.
+    ///
+    /// Prevents front-running of market-sensitive attestations (e.g. audit results): the
+    /// content stays private until `reveal_attestation` in a later ledger.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the entity making the attestation. Must be the transaction signer.
+    /// * `commitment` - The hash returned by `compute_attestation_commitment` for the attestation.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the attester is banned.
+    pub fn commit_attestation(env: Env, attester: Address, commitment: BytesN<32>) -> Result<(), errors::Error> {
+        commit_attestation(&env, attester, commitment)
+    }
+
+    /// Reveals a committed attestation and issues it as `attest` would.
+    ///
+    /// Must be called in a later ledger than `commit_attestation`, and within about a day
+    /// (17,280 ledgers) of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address that committed. Must be the transaction signer.
+    /// * `reveal` - The committed schema UID, value, expiration time and salt.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the 32-byte UID of the new attestation, or an error if
+    /// no live commitment matches, the reveal is outside the window, or the attestation is
+    /// rejected.
+    pub fn reveal_attestation(
+        env: Env,
+        attester: Address,
+        reveal: AttestationReveal,
+    ) -> Result<BytesN<32>, errors::Error> {
+        reveal_attestation(&env, attester, reveal)
+    }
+
+    /// Computes the commitment `commit_attestation` expects for an attestation.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address that will commit and reveal.
+    /// * `reveal` - The attestation content and salt.
+    ///
+    /// # Returns
+    ///
+    /// Returns `sha256(attester XDR || reveal XDR)`.
+    pub fn compute_attestation_commitment(env: Env, attester: Address, reveal: AttestationReveal) -> BytesN<32> {
+        compute_attestation_commitment(&env, &attester, &reveal)
+    }
+
+    /// Gets a pending attestation commitment.
+    ///
+    /// # Arguments
+    ///
+    /// * `commitment` - The commitment hash.
+    ///
+    /// # Returns
+    ///
+    /// Returns the commitment record, or `None` if it was revealed, its window closed, or
+    /// it never existed.
+    pub fn get_attestation_commitment(env: Env, commitment: BytesN<32>) -> Option<AttestationCommitment> {
+        get_attestation_commitment(&env, &commitment)
+    }
+
+    /// Retrieves an attestation that has been issued but not yet claimed.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The UID returned by `attest_claimable`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `ClaimableAttestation`, or `AttestationNotFound`.
+    pub fn get_claimable_attestation(env: Env, uid: BytesN<32>) -> Result<ClaimableAttestation, errors::Error> {
+        get_claimable_attestation(&env, uid)
+    }
+
+    /// Creates a confidential attestation whose value is encrypted.
+    ///
+    /// Identical to `attest`, but also records the encryption envelope so SDK consumers
+    /// can interoperate on confidential attestations: `value` holds the ciphertext,
+    /// `encryption_scheme` names how it was encrypted, and `encrypted_key` holds the
+    /// content key wrapped for the intended reader(s).
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the entity making the attestation. Must be the transaction signer.
+    /// * `schema_uid` - The UID of the schema for which the attestation is being made.
+    /// * `value` - The encrypted value of the attestation.
+    /// * `expiration_time` - An optional Unix timestamp indicating when the attestation expires.
+    /// * `encryption_scheme` - The scheme identifier, e.g. `X25519_XSALSA20_POLY1305`.
+    /// * `encrypted_key` - The wrapped content key (1 to 512 bytes).
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the 32-byte UID of the newly created attestation,
+    /// or `InvalidEncryptionEnvelope` if the scheme is unsupported or the key is malformed.
+    pub fn attest_encrypted(
+        env: Env,
+        attester: Address,
+        schema_uid: BytesN<32>,
+        value: String,
+        expiration_time: Option<u64>,
+        encryption_scheme: Symbol,
+        encrypted_key: Bytes,
+    ) -> Result<BytesN<32>, errors::Error> {
+        attest_encrypted(
+            &env,
+            attester,
+            schema_uid,
+            value,
+            expiration_time,
+            encryption_scheme,
+            encrypted_key,
+        )
+    }
+
+    /// Revokes an existing attestation.
+    ///
+    /// Only the original attester or an authorized party (as defined by the schema) can
+    /// revoke an attestation. The schema must also permit revocations, unless it is
+    /// subject-revocable and the subject revokes an attestation about themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `revoker` - The address of the entity revoking the attestation. Must be authorized to perform this action.
+    /// * `attestation_uid` - The UID of the attestation to be revoked.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful revocation, or an error if the revocation fails.
+    pub fn revoke(env: Env, revoker: Address, attestation_uid: BytesN<32>) -> Result<(), errors::Error> {
+        revoke_attestation(&env, revoker, attestation_uid)
+    }
+
+    /// Revokes a self-attestation on behalf of its subject.
+    ///
+    /// When an attestation's attester and subject are the same address, the subject can
+    /// revoke it even if the schema is not revocable. The schema's resolver is still
+    /// consulted, and an `ATTEST`/`SELF_REV` event is emitted instead of the regular
+    /// revocation event.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject` - The subject (and attester) of the attestation. Must authorize the transaction.
+    /// * `attestation_uid` - The unique identifier of the attestation to revoke.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the revocation is successful, or `NotAuthorized` if the
+    /// attestation is not a self-attestation of `subject`.
+    pub fn revoke_self(env: Env, subject: Address, attestation_uid: BytesN<32>) -> Result<(), errors::Error> {
+        revoke_self_attestation(&env, subject, attestation_uid)
+    }
+
+    /// Appeals the revocation of an attestation within its schema's challenge window.
+    ///
+    /// The attestation stays revoked but is reported as disputed until the schema authority
+    /// calls `resolve_appeal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject` - The subject of the revoked attestation. Must authorize the transaction.
+    /// * `attestation_uid` - The UID of the revoked attestation.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` once the appeal is filed, or `AppealNotAllowed` if the attestation is
+    /// not revoked, the challenge window is disabled or closed, or it was already appealed.
+    pub fn appeal(env: Env, subject: Address, attestation_uid: BytesN<32>) -> Result<(), errors::Error> {
+        appeal(&env, subject, attestation_uid)
+    }
+
+    /// Settles an appealed revocation, confirming it or reinstating the attestation.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The schema authority. Must authorize the transaction.
+    /// * `attestation_uid` - The UID of the disputed attestation.
+    /// * `reinstate` - True to reverse the revocation, false to confirm it.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, `NotAuthorized` if the caller is not the schema authority,
+    /// or `NoPendingAppeal` if the attestation is not disputed.
+    pub fn resolve_appeal(
+        env: Env,
+        caller: Address,
+        attestation_uid: BytesN<32>,
+        reinstate: bool,
+    ) -> Result<(), errors::Error> {
+        resolve_appeal(&env, caller, attestation_uid, reinstate)
+    }
+
+    /// Gets the appeal filed against an attestation's revocation.
+    ///
+    /// # Arguments
+    ///
+    /// * `attestation_uid` - The UID of the attestation.
+    ///
+    /// # Returns
+    ///
+    /// Returns the appeal, or `None` if the revocation was never appealed or the attestation
+    /// was reinstated.
+    pub fn get_attestation_appeal(env: Env, attestation_uid: BytesN<32>) -> Option<AttestationAppeal> {
+        get_attestation_appeal(&env, &attestation_uid)
+    }
+
+    /// Retrieves an attestation by its UID.
+    ///
+    /// # Arguments
+    ///
+    /// * `attestation_uid` - The 32-byte unique identifier of the attestation to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `Attestation` struct if found, or an error
+    /// if no attestation with the given UID exists.
+    pub fn get_attestation(env: Env, attestation_uid: BytesN<32>) -> Result<Attestation, errors::Error> {
+        get_attestation_record(&env, attestation_uid)
+    }
+
+    /// Retrieves an attestation with its expiry and revocation status computed at the
+    /// current ledger.
+    ///
+    /// Expired attestations are returned with `is_expired` set rather than rejected,
+    /// schema-wide revocations are reflected in `is_revoked`, and appealed revocations
+    /// awaiting the schema authority's decision in `is_disputed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `attestation_uid` - The 32-byte unique identifier of the attestation to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// Returns the attestation and its status, or `AttestationNotFound` if no attestation
+    /// with the given UID exists.
+    pub fn get_attestation_status(env: Env, attestation_uid: BytesN<32>) -> Result<AttestationStatus, errors::Error> {
+        get_attestation_status(&env, attestation_uid)
+    }
+
+    /// Retrieves an attestation by the external reference ID its attester attached to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address that created the attestation.
+    /// * `external_id` - The reference ID passed to `attest_with_external_id`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `Attestation` if found, or `AttestationNotFound`.
+    pub fn get_attestation_by_external_id(
+        env: Env,
+        attester: Address,
+        external_id: Bytes,
+    ) -> Result<Attestation, errors::Error> {
+        get_attestation_by_external_id(&env, attester, external_id)
+    }
+
+    /// Lists the structured fields that differ between two attestations.
+    ///
+    /// Both attestations must share a schema with an `XDR:` definition; the value of
+    /// each schema field is compared, so amendment chains can be audited on-chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid_a` - The first attestation UID.
+    /// * `uid_b` - The second attestation UID.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the names of the differing fields, or an error if
+    /// either attestation is missing or encrypted, or their schemas differ.
+    pub fn diff_attestations(env: Env, uid_a: BytesN<32>, uid_b: BytesN<32>) -> Result<Vec<String>, errors::Error> {
+        diff_attestations(&env, uid_a, uid_b)
+    }
+
+    /// Builds a validity proof for an attestation.
+    ///
+    /// Returns the attestation together with its ledger entry key and canonical XDR
+    /// serialization, so light clients and bridges can verify the attestation against
+    /// a Stellar ledger header.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The unique identifier of the attestation.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `ValidityProof`, or an error if the attestation
+    /// does not exist or has expired.
+    pub fn get_validity_proof(env: Env, uid: BytesN<32>) -> Result<ValidityProof, errors::Error> {
+        get_validity_proof(&env, uid)
+    }
+
+    /// Retrieves an attestation by its UID, scoped to the namespace of its schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace` - The namespace the attestation's schema must belong to.
+    /// * `attestation_uid` - The unique identifier of the attestation.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `Attestation` if found, or an error if it does not
+    /// exist, has expired, or belongs to a different namespace.
+    pub fn get_namespaced_attestation(
+        env: Env,
+        namespace: Symbol,
+        attestation_uid: BytesN<32>,
+    ) -> Result<Attestation, errors::Error> {
+        get_namespaced_attestation(&env, &namespace, attestation_uid)
+    }
+
+    // ══════════════════════════════════════════════════════════════════════════════
+    // ► Delegated Attestation Functions
+    // ══════════════════════════════════════════════════════════════════════════════
+
+    /// Checks whether a subject holds a valid attestation for a schema.
+    ///
+    /// Only the subject's most recent attestation for the schema is considered. This is
+    /// intended as a cheap building block for token-gating and other access checks made
+    /// by other contracts.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject` - The address that is the subject of the attestation.
+    /// * `schema_uid` - The UID of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the most recent attestation exists, is not revoked, and has not expired.
+    pub fn has_valid_attestation(env: Env, subject: Address, schema_uid: BytesN<32>) -> bool {
+        has_valid_attestation(&env, &subject, &schema_uid)
+    }
+
+    /// Checks whether an attestation is currently valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `attestation_uid` - The UID of the attestation.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the attestation exists and is not revoked, frozen, expired, or
+    /// under a revoked schema.
+    pub fn is_attestation_valid(env: Env, attestation_uid: BytesN<32>) -> bool {
+        is_attestation_valid(&env, attestation_uid)
+    }
+
+    /// Gets the most recent attestation issued to a subject under a schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject` - The address that is the subject of the attestation.
+    /// * `schema_uid` - The UID of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns the attestation, or `None` if the subject has none still stored. Expired
+    /// attestations are returned as stored; use `is_attestation_valid` to check them.
+    pub fn latest_attestation(env: Env, subject: Address, schema_uid: BytesN<32>) -> Option<Attestation> {
+        latest_attestation(&env, &subject, &schema_uid)
+    }
+
+    /// Checks whether a plain or muxed subject holds a valid attestation for a schema.
+    ///
+    /// Without a mux ID, this matches `has_valid_attestation` for the account, including
+    /// attestations issued to its muxed sub-accounts. With a mux ID, only attestations issued
+    /// to that sub-account count.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject` - The subject account and optional mux ID.
+    /// * `schema_uid` - The UID of the schema.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the most recent matching attestation exists, is not revoked, and has not expired.
+    pub fn has_valid_subject_attestation(env: Env, subject: Subject, schema_uid: BytesN<32>) -> bool {
+        has_valid_subject_attestation(&env, &subject, &schema_uid)
+    }
+
+    /// Gets the subject of an attestation, including its mux ID if it was issued to a muxed account.
+    ///
+    /// # Arguments
+    ///
+    /// * `attestation_uid` - The UID of the attestation.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Subject`, or `AttestationNotFound` if the attestation does not exist.
+    pub fn get_attestation_subject(env: Env, attestation_uid: BytesN<32>) -> Result<Subject, errors::Error> {
+        get_attestation_subject(&env, attestation_uid)
+    }
+
+    /// Returns whether an address is an account or a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address to classify.
+    ///
+    /// # Returns
+    ///
+    /// Returns `SubjectType::Account` for `G...` addresses and `SubjectType::Contract`
+    /// for `C...` addresses.
+    pub fn get_subject_type(env: Env, address: Address) -> SubjectType {
+        utils::subject_type(&env, &address)
+    }
+
+    /// Lists the UIDs of attestations about a subject, oldest first, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject` - The subject address.
+    /// * `cursor` - The `next_cursor` of the previous page, or empty bytes for the first page.
+    /// * `limit` - Maximum number of UIDs to return (at most 100).
+    ///
+    /// # Returns
+    ///
+    /// Returns the page and the cursor for the next one. Cursors stay valid while new
+    /// attestations are appended, or `InvalidCursor` is returned for a malformed cursor.
+    pub fn list_subject_attestations(
+        env: Env,
+        subject: Address,
+        cursor: Bytes,
+        limit: u32,
+    ) -> Result<AttestationPage, errors::Error> {
+        list_subject_attestations(&env, subject, cursor, limit)
+    }
+
+    /// Lists the UIDs of attestations issued under a schema, oldest first, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The UID of the schema.
+    /// * `cursor` - The `next_cursor` of the previous page, or empty bytes for the first page.
+    /// * `limit` - Maximum number of UIDs to return (at most 100).
+    ///
+    /// # Returns
+    ///
+    /// Returns the page and the cursor for the next one. Cursors stay valid while new
+    /// attestations are appended, or `InvalidCursor` is returned for a malformed cursor.
+    pub fn list_schema_attestations(
+        env: Env,
+        schema_uid: BytesN<32>,
+        cursor: Bytes,
+        limit: u32,
+    ) -> Result<AttestationPage, errors::Error> {
+        list_schema_attestations(&env, schema_uid, cursor, limit)
+    }
+
+    /// Lists the UIDs of attestations issued under a schema, oldest first, one page at a time.
+    ///
+    /// Alias of `list_schema_attestations`, paired with `get_schema_attestation_count`.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The UID of the schema.
+    /// * `cursor` - The `next_cursor` of the previous page, or empty bytes for the first page.
+    /// * `limit` - Maximum number of UIDs to return (at most 100).
+    ///
+    /// # Returns
+    ///
+    /// Returns the page and the cursor for the next one, or `InvalidCursor` for a malformed cursor.
+    pub fn get_attestations_by_schema(
+        env: Env,
+        schema_uid: BytesN<32>,
+        cursor: Bytes,
+        limit: u32,
+    ) -> Result<AttestationPage, errors::Error> {
+        list_schema_attestations(&env, schema_uid, cursor, limit)
+    }
+
+    /// Lists the UIDs of attestations issued under a schema about accounts only, or about
+    /// contracts only, oldest first, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `subject_type` - Whether to list attestations about accounts or contracts.
+    /// * `cursor` - The `next_cursor` of the previous page, or empty bytes for the first page.
+    /// * `limit` - Maximum number of UIDs to return (at most 100).
+    ///
+    /// # Returns
+    ///
+    /// Returns the page and the cursor for the next one, or `InvalidCursor` for a
+    /// malformed cursor.
+    pub fn list_schema_attestations_by_type(
+        env: Env,
+        schema_uid: BytesN<32>,
+        subject_type: SubjectType,
+        cursor: Bytes,
+        limit: u32,
+    ) -> Result<AttestationPage, errors::Error> {
+        list_schema_attestations_by_type(&env, schema_uid, subject_type, cursor, limit)
+    }
+
+    /// Lists the UIDs of attestations issued under a schema in one locale, oldest first,
+    /// one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `locale` - The locale passed to `attest_with_locale`.
+    /// * `cursor` - The `next_cursor` of the previous page, or empty bytes for the first page.
+    /// * `limit` - Maximum number of UIDs to return (at most 100).
+    ///
+    /// # Returns
+    ///
+    /// Returns the page and the cursor for the next one, or `InvalidCursor` for a
+    /// malformed cursor.
+    pub fn list_attestations_by_locale(
+        env: Env,
+        schema_uid: BytesN<32>,
+        locale: Symbol,
+        cursor: Bytes,
+        limit: u32,
+    ) -> Result<AttestationPage, errors::Error> {
+        list_attestations_by_locale(&env, schema_uid, locale, cursor, limit)
+    }
+
+    /// Lists the UIDs of attestations that reference an attestation, oldest first, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The UID of the referenced attestation.
+    /// * `cursor` - The `next_cursor` of the previous page, or empty bytes for the first page.
+    /// * `limit` - Maximum number of UIDs to return (at most 100).
+    ///
+    /// # Returns
+    ///
+    /// Returns the page and the cursor for the next one, or `InvalidCursor` for a
+    /// malformed cursor.
+    pub fn get_referencing_attestations(
+        env: Env,
+        uid: BytesN<32>,
+        cursor: Bytes,
+        limit: u32,
+    ) -> Result<AttestationPage, errors::Error> {
+        get_referencing_attestations(&env, uid, cursor, limit)
+    }
+
+    /// Lists the UIDs of attestations issued by an attester, oldest first, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The attester address.
+    /// * `cursor` - The `next_cursor` of the previous page, or empty bytes for the first page.
+    /// * `limit` - Maximum number of UIDs to return (at most 100).
+    ///
+    /// # Returns
+    ///
+    /// Returns the page and the cursor for the next one. Cursors stay valid while new
+    /// attestations are appended, or `InvalidCursor` is returned for a malformed cursor.
+    pub fn list_attester_attestations(
+        env: Env,
+        attester: Address,
+        cursor: Bytes,
+        limit: u32,
+    ) -> Result<AttestationPage, errors::Error> {
+        list_attester_attestations(&env, attester, cursor, limit)
+    }
+
+    /// Draws a deterministic pseudo-random sample of the attestations issued under a schema.
+    ///
+    /// Lets auditors verify issuance quality without downloading the entire set. The same
+    /// seed yields the same sample until new attestations are issued under the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_uid` - The 32-byte unique identifier of the schema.
+    /// * `seed` - The seed the sample is derived from.
+    /// * `count` - Number of UIDs to sample (at most 100).
+    ///
+    /// # Returns
+    ///
+    /// Returns the sampled UIDs, oldest first; fewer than `count` if the schema has fewer
+    /// attestations.
+    pub fn sample_attestations(env: Env, schema_uid: BytesN<32>, seed: BytesN<32>, count: u32) -> Vec<BytesN<32>> {
+        sample_attestations(&env, schema_uid, seed, count)
+    }
+
+    /// Checks whether an attestation is valid and was issued recently.
+    ///
+    /// Lets consuming contracts require credentials issued within a window, such as a
+    /// proof of liveness from the last 30 days.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The UID of the attestation.
+    /// * `max_age_seconds` - The maximum time since the attestation was issued.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the attestation exists, is neither revoked, frozen nor expired,
+    /// and is no older than `max_age_seconds`.
+    pub fn is_attestation_fresh(env: Env, uid: BytesN<32>, max_age_seconds: u64) -> bool {
+        is_attestation_fresh(&env, uid, max_age_seconds)
+    }
+
+    /// Checks whether an attestation is locked to its subject (ERC-5192 `locked`).
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The UID of the attestation.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing `true` if the attestation's schema is soulbound, or
+    /// `AttestationNotFound`.
+    pub fn is_locked(env: Env, uid: BytesN<32>) -> Result<bool, errors::Error> {
+        is_locked(&env, uid)
+    }
+
+    /// Creates an attestation using a delegated signature.
+    ///
+    /// This method allows for gas-less attestations where a `submitter` can post an
+    /// attestation on behalf of an `attester`. The `attester`'s authorization is
+    /// verified through a signed `DelegatedAttestationRequest`. The attestation UID
+    /// can be derived off-chain from the request parameters.
+    ///
+    /// Anyone can submit this transaction, paying the fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `submitter` - The address submitting the transaction, which must authorize the invocation.
+    /// * `request` - The `DelegatedAttestationRequest` struct containing the attestation details and the attester's signature.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the request is invalid or signature verification fails.
+    pub fn attest_by_delegation(
+        env: Env,
+        submitter: Address,
+        request: DelegatedAttestationRequest,
+    ) -> Result<(), errors::Error> {
+        attest_by_delegation(&env, submitter, request)
+    }
+
+    /// Creates attestations for a batch of delegated requests, each processed independently.
+    ///
+    /// A request that fails validation or signature verification is reported and leaves no
+    /// state behind, without reverting the rest of the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `submitter` - The address submitting the transaction, which must authorize the invocation.
+    /// * `requests` - The `DelegatedAttestationRequest`s, each carrying its attester's BLS signature.
+    ///
+    /// # Returns
+    ///
+    /// Returns, in request order, the UID of each created attestation or the error it failed with,
+    /// or `ContractPaused` without processing any request while the protocol is paused.
+    pub fn submit_delegated_batch(
+        env: Env,
+        submitter: Address,
+        requests: Vec<DelegatedAttestationRequest>,
+    ) -> Result<Vec<Result<BytesN<32>, errors::Error>>, errors::Error> {
+        submit_delegated_batch(&env, submitter, requests)
+    }
+
+    /// Creates an attestation using a delegated secp256k1 (EVM-style) signature.
+    ///
+    /// Works like `attest_by_delegation`, but the attester authorizes the request with
+    /// a recoverable secp256k1 signature over `keccak256` of
+    /// `build_secp256k1_attestation_message`, which also binds the subject and a hash of
+    /// the value, so keys held in EVM wallets or HSMs can be used directly. The
+    /// `signature` field inside `request` is ignored on this path.
+    ///
+    /// # Arguments
+    ///
+    /// * `submitter` - The address submitting the transaction, which must authorize the invocation.
+    /// * `request` - The `DelegatedAttestationRequest` struct containing the attestation details.
+    /// * `signature` - The 65-byte recoverable signature (`r || s || v`).
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the request is invalid or signature verification fails.
+    pub fn attest_by_delegation_secp256k1(
+        env: Env,
+        submitter: Address,
+        request: DelegatedAttestationRequest,
+        signature: BytesN<65>,
+    ) -> Result<(), errors::Error> {
+        attest_by_delegation_secp256k1(&env, submitter, request, signature)
+    }
+
+    /// Revokes an attestation using a delegated signature.
+    ///
+    /// This method allows for gas-less revocations where a `submitter` can post a
+    /// revocation on behalf of a `revoker`. The `revoker`'s authorization is
+    /// verified through a signed `DelegatedRevocationRequest`.
+    ///
+    /// # Arguments
+    ///
+    /// * `submitter` - The address submitting the transaction, which must authorize the invocation.
+    /// * `request` - The `DelegatedRevocationRequest` struct containing the revocation details and the revoker's signature.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the request is invalid or signature verification fails.
+    pub fn revoke_by_delegation(
+        env: Env,
+        submitter: Address,
+        request: DelegatedRevocationRequest,
+    ) -> Result<(), errors::Error> {
+        revoke_by_delegation(&env, submitter, request)
+    }
+
+    /// Revokes an attestation using a delegated secp256k1 (EVM-style) signature.
+    ///
+    /// The revocation counterpart of `attest_by_delegation_secp256k1`: the revoker signs
+    /// `keccak256` of `build_secp256k1_revocation_message`, which binds the attestation UID
+    /// and a nonce consumed on submission. The `signature` field inside `request` is ignored
+    /// on this path.
+    ///
+    /// # Arguments
+    ///
+    /// * `submitter` - The address submitting the transaction, which must authorize the invocation.
+    /// * `request` - The `DelegatedRevocationRequest` struct containing the revocation details.
+    /// * `signature` - The 65-byte recoverable signature (`r || s || v`).
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the request is invalid or signature verification fails.
+    pub fn revoke_by_delegation_secp256k1(
+        env: Env,
+        submitter: Address,
+        request: DelegatedRevocationRequest,
+        signature: BytesN<65>,
+    ) -> Result<(), errors::Error> {
+        revoke_by_delegation_secp256k1(&env, submitter, request, signature)
+    }
+
+    /// Sets which delegated message format versions the contract accepts.
+    ///
+    /// Lets a new signed message format roll out without invalidating requests already
+    /// signed with the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The contract admin. Must authorize the transaction.
+    /// * `versions` - The accepted versions. Must be non-empty, and each must be supported by this build.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the caller is not the admin or a version
+    /// is unsupported.
+    pub fn set_accepted_message_versions(env: Env, admin: Address, versions: Vec<u32>) -> Result<(), errors::Error> {
+        set_accepted_message_versions(&env, admin, versions)
+    }
+
+    /// Gets the delegated message format versions the contract accepts.
+    ///
+    /// # Returns
+    ///
+    /// Returns the accepted versions; only the current version until the admin configures them.
+    pub fn get_accepted_message_versions(env: Env) -> Vec<u32> {
+        get_accepted_message_versions(&env)
+    }
+
+    /// Adds a member to an organization.
+    ///
+    /// Members can issue attestations on behalf of the organization through `attest_on_behalf_of`.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization` - The organization address. Must authorize the transaction.
+    /// * `member` - The address to add as a member.
+    pub fn add_org_member(env: Env, organization: Address, member: Address) {
+        add_org_member(&env, organization, member)
+    }
+
+    /// Removes a member from an organization.
+    ///
+    /// Attestations already issued by the member remain attributed to the organization.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization` - The organization address. Must authorize the transaction.
+    /// * `member` - The address to remove.
+    pub fn remove_org_member(env: Env, organization: Address, member: Address) {
+        remove_org_member(&env, organization, member)
+    }
+
+    /// Checks whether an address is a member of an organization.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization` - The organization address.
+    /// * `member` - The address to check.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if `member` is currently a member of `organization`.
+    pub fn is_org_member(env: Env, organization: Address, member: Address) -> bool {
+        is_org_member(&env, &organization, &member)
+    }
+
+    /// Lists attestations issued on behalf of an organization, in issue order.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization` - The organization address.
+    /// * `start` - Index of the first attestation to return.
+    /// * `limit` - Maximum number of attestation UIDs to return.
+    ///
+    /// # Returns
+    ///
+    /// Returns the UIDs of up to `limit` attestations starting at `start`.
+    pub fn get_org_attestations(env: Env, organization: Address, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        get_org_attestations(&env, &organization, start, limit)
+    }
+
+    /// Imports authorities and attestations exported from a previous deployment.
+    ///
+    /// Records keep their original UIDs, timestamps and revocation state, so data can move
+    /// to a new deployment without re-issuing it. Resolver hooks are not run.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The contract admin. Must authorize the transaction.
+    /// * `authorities` - The authority records to import.
+    /// * `attestations` - The attestation records to import. Their schemas must already be registered.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of attestations imported, or an error if the
+    /// caller is not the admin, a schema is missing, or an attestation UID already exists.
+    pub fn import_legacy(
+        env: Env,
+        admin: Address,
+        authorities: Vec<Authority>,
+        attestations: Vec<Attestation>,
+    ) -> Result<u32, errors::Error> {
+        import_legacy(&env, admin, authorities, attestations)
+    }
+
+    /// Bans an attester protocol-wide.
+    ///
+    /// An emergency tool against spam campaigns discovered after the fact. Banned attesters
+    /// cannot create attestations through `attest` or any delegated flow until unbanned.
+    /// Attestations they already issued are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The contract admin. Must authorize the transaction.
+    /// * `attester` - The attester to ban.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the caller is not the admin.
+    pub fn ban_attester(env: Env, admin: Address, attester: Address) -> Result<(), errors::Error> {
+        ban_attester(&env, admin, attester)
+    }
+
+    /// Lifts a protocol-wide ban on an attester.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The contract admin. Must authorize the transaction.
+    /// * `attester` - The attester to unban.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the caller is not the admin.
+    pub fn unban_attester(env: Env, admin: Address, attester: Address) -> Result<(), errors::Error> {
+        unban_attester(&env, admin, attester)
+    }
+
+    /// Requests that an attester be bound to a home domain.
+    ///
+    /// The attester hosts a token in the domain's `stellar.toml` and submits its SHA-256
+    /// hash. The binding takes effect once the admin confirms it with `verify_attester_domain`.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The attester claiming the domain. Must authorize the transaction.
+    /// * `domain` - The home domain, e.g. `university.edu`.
+    /// * `challenge_hash` - SHA-256 hash of the token hosted on the domain.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the domain is empty or too long.
+    pub fn request_attester_domain(
+        env: Env,
+        attester: Address,
+        domain: String,
+        challenge_hash: BytesN<32>,
+    ) -> Result<(), errors::Error> {
+        request_attester_domain(&env, attester, domain, challenge_hash)
+    }
+
+    /// Confirms an attester's pending home domain binding.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The contract admin. Must authorize the transaction.
+    /// * `attester` - The attester whose pending binding is confirmed.
+    /// * `token_hash` - SHA-256 hash of the token found in the domain's `stellar.toml`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the caller is not the admin or the hash
+    /// does not match the pending challenge.
+    pub fn verify_attester_domain(
+        env: Env,
+        admin: Address,
+        attester: Address,
+        token_hash: BytesN<32>,
+    ) -> Result<(), errors::Error> {
+        verify_attester_domain(&env, admin, attester, token_hash)
+    }
+
+    /// Gets an attester's verified home domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The attester to look up.
+    ///
+    /// # Returns
+    ///
+    /// Returns the verified binding, or `None` if the attester has no verified domain.
+    pub fn get_attester_domain(env: Env, attester: Address) -> Option<AttesterDomain> {
+        get_attester_domain(&env, &attester)
+    }
+
+    /// Revokes an attestation as the admin, for moderation.
+    ///
+    /// Not subject to the schema's minimum active duration. The schema must be revocable,
+    /// and its resolver is still consulted.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The contract admin. Must authorize the transaction.
+    /// * `attestation_uid` - The UID of the attestation to revoke.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the caller is not the admin or the
+    /// attestation cannot be revoked.
+    pub fn admin_revoke(env: Env, admin: Address, attestation_uid: BytesN<32>) -> Result<(), errors::Error> {
+        admin_revoke(&env, admin, attestation_uid)
+    }
+
+    /// Checks whether an attester is banned.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The attester to check.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the attester is currently banned.
+    pub fn is_attester_banned(env: Env, attester: Address) -> bool {
+        is_attester_banned(&env, &attester)
+    }
+
+    /// Pauses attesting and revoking contract-wide.
+    ///
+    /// An incident-response lever for when a resolver or signature bug is discovered.
+    /// While paused, attest, claim and revoke entrypoints, including delegated ones, fail
+    /// with `ContractPaused`. Reads and `admin_revoke` keep working.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The contract admin. Must authorize the transaction.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or `NotAuthorized` if the caller is not the admin or a
+    /// council is configured, in which case pausing goes through `AdminOperation::Pause`.
+    pub fn pause(env: Env, admin: Address) -> Result<(), errors::Error> {
+        pause(&env, admin)
+    }
+
+    /// Lifts a pause set by `pause`.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The contract admin. Must authorize the transaction.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or `NotAuthorized` if the caller is not the admin or a
+    /// council is configured, in which case unpausing goes through `AdminOperation::Unpause`.
+    pub fn unpause(env: Env, admin: Address) -> Result<(), errors::Error> {
+        unpause(&env, admin)
+    }
+
+    /// Checks whether the contract is paused.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` while attesting and revoking are paused.
+    pub fn is_paused(env: Env) -> bool {
+        is_paused(&env)
+    }
+
+    /// Declares that the attester's signing keys have leaked.
+    ///
+    /// Removes the attester's BLS and secp256k1 keys and skips their nonce ahead, so no
+    /// pre-signed or forged delegated request can be submitted. Optionally freezes the
+    /// attester's attestations issued between a timestamp and the declaration until the
+    /// admin reviews them; attestations issued after the declaration are not frozen.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The compromised attester. Must authorize the transaction.
+    /// * `freeze_after` - Optional timestamp from which the attester's attestations are frozen.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success.
+    pub fn declare_compromise(env: Env, attester: Address, freeze_after: Option<u64>) -> Result<(), errors::Error> {
+        declare_compromise(&env, attester, freeze_after)
+    }
+
+    /// Unfreezes an attestation frozen by a compromise declaration after review.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The contract admin. Must authorize the transaction.
+    /// * `attestation_uid` - The UID of the attestation to unfreeze.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the caller is not the admin or the
+    /// attestation does not exist.
+    pub fn unfreeze_attestation(env: Env, admin: Address, attestation_uid: BytesN<32>) -> Result<(), errors::Error> {
+        unfreeze_attestation(&env, admin, attestation_uid)
+    }
+
+    /// Checks whether an attestation is frozen pending compromise review.
+    ///
+    /// # Arguments
+    ///
+    /// * `attestation_uid` - The UID of the attestation to check.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the attestation is frozen, or an error if it does not exist.
+    pub fn is_attestation_frozen(env: Env, attestation_uid: BytesN<32>) -> Result<bool, errors::Error> {
+        is_attestation_frozen(&env, attestation_uid)
+    }
+
+    /// Proposes a critical admin operation (upgrade, admin or council change) to the admin council.
+    ///
+    /// The proposal counts as the proposer's approval. It executes once `threshold` council
+    /// members have approved it, which is immediately for the default 1-of-1 council made
+    /// up of the admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `proposer` - A council member. Must authorize the transaction.
+    /// * `operation` - The operation to execute once approved.
+    ///
+    /// # Returns
+    ///
+    /// Returns the proposal ID, or an error if the proposer is not a council member or a
+    /// proposed council is invalid.
+    pub fn propose_admin_operation(
+        env: Env,
+        proposer: Address,
+        operation: AdminOperation,
+    ) -> Result<u32, errors::Error> {
+        propose_admin_operation(&env, proposer, operation)
+    }
+
+    /// Approves a pending critical admin operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `approver` - A council member. Must authorize the transaction.
+    /// * `proposal_id` - The ID returned by `propose_admin_operation`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if this approval reached the threshold and executed the operation, or
+    /// an error if the approver is not a council member or the proposal is not pending.
+    pub fn approve_admin_operation(env: Env, approver: Address, proposal_id: u32) -> Result<bool, errors::Error> {
+        approve_admin_operation(&env, approver, proposal_id)
+    }
+
+    /// Gets a pending critical admin operation and its approvals.
+    ///
+    /// # Arguments
+    ///
+    /// * `proposal_id` - The ID returned by `propose_admin_operation`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `AdminProposal`, or `ProposalNotFound` if it is not pending.
+    pub fn get_admin_proposal(env: Env, proposal_id: u32) -> Result<AdminProposal, errors::Error> {
+        get_admin_proposal(&env, proposal_id)
+    }
+
+    /// Gets the council that approves critical admin operations.
+    ///
+    /// # Returns
+    ///
+    /// Returns the council, which is the admin alone with threshold 1 until one is configured.
+    pub fn get_admin_council(env: Env) -> Result<AdminCouncil, errors::Error> {
+        get_admin_council(&env)
+    }
+
+    /// Proposes a new admin, the first step of a two-step admin transfer.
+    ///
+    /// The transfer completes when `new_admin` calls `accept_admin`, so a mistyped
+    /// address cannot take over the contract. Proposing the current admin cancels a
+    /// pending transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The current admin. Must authorize the transaction.
+    /// * `new_admin` - The address proposed as the next admin.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or `NotAuthorized` if the caller is not the admin
+    /// or a council is configured, in which case admin changes go through council proposals.
+    pub fn propose_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), errors::Error> {
+        propose_admin(&env, admin, new_admin)
+    }
+
+    /// Accepts a pending admin transfer, making the proposed address the admin.
+    ///
+    /// Must be authorized by the proposed admin.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new admin, `NoPendingAdmin` if no transfer has been proposed, or
+    /// `NotAuthorized` if a council has been configured since.
+    pub fn accept_admin(env: Env) -> Result<Address, errors::Error> {
+        accept_admin(&env)
+    }
+
+    /// Gets the address proposed as the next admin.
+    ///
+    /// # Returns
+    ///
+    /// Returns the pending admin, or `None` if no transfer is pending.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        get_pending_admin(&env)
+    }
+
+    /// Upgrades the contract to an uploaded WASM, keeping all attestation state.
+    ///
+    /// Only available while no admin council is configured; afterwards upgrades go through
+    /// `AdminOperation::Upgrade` proposals. Call `migrate_storage` after an upgrade that
+    /// changes the storage layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The contract admin. Must authorize the transaction.
+    /// * `new_wasm_hash` - The hash of the uploaded WASM.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or `NotAuthorized` if the caller is not the admin
+    /// or a council is configured.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), errors::Error> {
+        upgrade(&env, admin, new_wasm_hash)
+    }
+
+    /// Migrates the contract's storage to the layout of the running code.
+    ///
+    /// Safe to call more than once; storage that is already current is left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The contract admin. Must authorize the transaction.
+    ///
+    /// # Returns
+    ///
+    /// Returns the storage version after migrating, or `NotAuthorized` if the caller is
+    /// not the admin.
+    pub fn migrate_storage(env: Env, admin: Address) -> Result<u32, errors::Error> {
+        migrate_storage(&env, admin)
+    }
+
+    /// Gets the storage layout version of the contract's entries.
+    ///
+    /// # Returns
+    ///
+    /// Returns the version, or 0 for deployments initialized before versions were recorded.
+    pub fn get_storage_version(env: Env) -> u32 {
+        get_storage_version(&env)
+    }
+
+    /// Adds funds to the cleanup bounty pool.
+    ///
+    /// The pool pays callers of `purge` a bounty for each expired or long-revoked
+    /// attestation they remove, keeping persistent storage lean without privileged jobs.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The contract admin. Must authorize the transaction and pays `amount` into the pool.
+    /// * `token` - The token the bounty is paid in. Fixed by the first funding.
+    /// * `amount` - The amount to add to the pool.
+    /// * `bounty_per_entry` - The amount paid per entry removed.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an error if the caller is not the admin, the amounts
+    /// are invalid, or the token differs from the pool's token.
+    pub fn fund_cleanup_bounty(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+        bounty_per_entry: i128,
+    ) -> Result<(), errors::Error> {
+        fund_cleanup_bounty(&env, admin, token, amount, bounty_per_entry)
+    }
+
+    /// Gets the cleanup bounty pool.
+    ///
+    /// # Returns
+    ///
+    /// Returns the pool's token, per-entry bounty and remaining balance, or `None` if it
+    /// has never been funded.
+    pub fn get_cleanup_bounty(env: Env) -> Option<CleanupBounty> {
+        get_cleanup_bounty(&env)
+    }
+
+    /// Removes expired and long-revoked attestations from storage for a bounty.
+    ///
+    /// Permissionless. Expired attestations can be purged immediately and revoked ones
+    /// 30 days after revocation. Entries that are missing, not yet eligible or under an
+    /// open appeal are skipped. Purged attestations also leave their listings and lookup
+    /// indexes. The caller is paid the per-entry bounty for each entry removed, capped by
+    /// the pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The address receiving the bounty. Must authorize the transaction.
+    /// * `uids` - The UIDs of the attestations to purge.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of entries actually removed.
+    pub fn purge(env: Env, caller: Address, uids: Vec<BytesN<32>>) -> Result<u32, errors::Error> {
+        purge_attestations(&env, caller, uids)
+    }
+
+    /// Extends how long an attestation stays in persistent storage before being archived.
+    ///
+    /// Permissionless: anyone may pay the rent to keep an attestation live. Emits a
+    /// `TTLEXTEND` event so indexers can track when the attestation would be archived.
+    ///
+    /// # Arguments
+    ///
+    /// * `attestation_uid` - The UID of the attestation to keep live.
+    /// * `extend_to` - The number of ledgers to keep it live for, capped at the network maximum.
+    ///
+    /// # Returns
+    ///
+    /// Returns the ledger sequence the attestation now lives until, or an error if it does
+    /// not exist.
+    pub fn extend_attestation_ttl(env: Env, attestation_uid: BytesN<32>, extend_to: u32) -> Result<u32, errors::Error> {
+        extend_attestation_ttl(&env, attestation_uid, extend_to)
+    }
+
+    /// Gets the next nonce for an attester.
+    ///
+    /// Nonces are used in delegated requests to prevent replay attacks. Each delegated
+    /// request from an attester must have a unique nonce, at most `NONCE_WINDOW` above
+    /// this value; requests inside the window may be submitted in any order.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the attester.
+    ///
+    /// # Returns
+    ///
+    /// Returns the next expected nonce (`u64`) for the given attester.
+    pub fn get_attester_nonce(env: Env, attester: Address) -> u64 {
+        utils::get_next_nonce(&env, &attester)
+    }
+
+    /// Checks whether a delegated request signed with `nonce` has already been consumed.
+    ///
+    /// Relayers submitting delegated requests out of order use this to tell which of an
+    /// attester's signed requests are still pending.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the attester.
+    /// * `nonce` - The nonce to check.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the nonce was used, either sequentially or out of order.
+    pub fn is_attester_nonce_used(env: Env, attester: Address, nonce: u64) -> bool {
+        is_nonce_used(&env, &attester, nonce)
+    }
+
+    /// Cancels a signed but unsubmitted delegated request.
+    ///
+    /// Consumes the request's nonce so no relayer can submit it, even before its deadline.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The attester who signed the request. Must authorize the transaction.
+    /// * `nonce` - The nonce of the request to cancel.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or `InvalidNonce` if the nonce was already used or is
+    /// outside the nonce window.
+    pub fn cancel_delegation_nonce(env: Env, attester: Address, nonce: u64) -> Result<(), errors::Error> {
+        cancel_delegation_nonce(&env, attester, nonce)
+    }
+
+    /// Registers a BLS public key for an attester.
+    ///
+    /// This public key can be used to verify delegated attestations and revocations,
+    /// enabling more advanced cryptographic operations. The attester must authorize this registration.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the attester for whom the BLS key is being registered. Must authorize transaction.
+    /// * `public_key` - The 192-byte BLS public key.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful registration, or an error if one already exists or registration fails.
+    pub fn register_bls_key(env: Env, attester: Address, public_key: BytesN<192>) -> Result<(), errors::Error> {
+        register_bls_public_key(&env, attester, public_key)
+    }
+
+    /// Gets the BLS public key for an attester.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the attester.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `BlsPublicKey` if found, or an error if no key
+    /// is registered for the given attester.
+    pub fn get_bls_key(env: Env, attester: Address) -> Result<BlsPublicKey, errors::Error> {
+        get_bls_public_key(&env, &attester)
+    }
+
+    /// Registers a secp256k1 (EVM-style) signer for an attester.
+    ///
+    /// The signer is identified by its 20-byte EVM address and can be used to verify
+    /// delegated attestations submitted through `attest_by_delegation_secp256k1`.
+    /// The attester must authorize this registration.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the attester for whom the signer is being registered. Must authorize transaction.
+    /// * `eth_address` - The 20-byte EVM address of the secp256k1 key.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful registration, or an error if one already exists or registration fails.
+    pub fn register_secp256k1_key(env: Env, attester: Address, eth_address: BytesN<20>) -> Result<(), errors::Error> {
+        register_secp256k1_signer(&env, attester, eth_address)
+    }
+
+    /// Gets the secp256k1 signer for an attester.
+    ///
+    /// # Arguments
+    ///
+    /// * `attester` - The address of the attester.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `Secp256k1Signer` if found, or an error if no signer
+    /// is registered for the given attester.
+    pub fn get_secp256k1_key(env: Env, attester: Address) -> Result<Secp256k1Signer, errors::Error> {
+        get_secp256k1_signer(&env, &attester)
+    }
+
+    /// Gets recent attestation and revocation summaries from the on-chain outbox.
+    ///
+    /// The outbox retains the most recent `OUTBOX_CAPACITY` state changes, each with a
+    /// monotonically increasing sequence number. Off-chain consumers can poll with the
+    /// last sequence they processed to catch up reliably, even after the corresponding
+    /// events have fallen outside Horizon's retention window.
+    ///
+    /// # Arguments
+    ///
+    /// * `since_seq` - The last sequence number already processed. Pass `0` to read every retained entry.
+    ///
+    /// # Returns
+    ///
+    /// Returns the retained `OutboxEntry` records with `seq > since_seq`, in ascending order.
+    /// If the first entry's `seq` is greater than `since_seq + 1`, older entries were overwritten.
+    pub fn get_outbox(env: Env, since_seq: u64) -> Vec<OutboxEntry> {
+        get_outbox_entries(&env, since_seq)
     }
 
     /// Gets the domain separation tag (DST) for delegated attestations.
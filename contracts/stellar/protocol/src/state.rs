@@ -1,4 +1,7 @@
-use soroban_sdk::{contracttype, Address, BytesN, String};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Map, String, Symbol, Vec};
+
+// Domain types shared with the authority and resolver contracts
+pub use attest_types::{Attestation, Authority, DelegatedAttestationRequest, DelegatedRevocationRequest, Schema};
 
 /// ╔══════════════════════════════════════════════════════════════════════════╗
 /// ║                                 DataKey                                   ║
@@ -29,165 +32,745 @@ pub enum DataKey {
     ///
     /// One-to-one mapping: wallet address -> BLS public key
     AttesterPublicKey(Address),
+    /// Key for storing the secp256k1 (EVM-style) signer for an attester
+    ///
+    /// One-to-one mapping: wallet address -> 20-byte EVM address
+    AttesterSecp256k1Key(Address),
+    /// Key for storing the UID of the most recent attestation for a schema/subject pair
+    ///
+    /// Used for cheap validity checks by subject without knowing the attestation UID
+    SubjectAttestation(BytesN<32>, Address),
+    /// Key for storing the running statistics of a schema, indexed by the schema's unique identifier
+    SchemaStats(BytesN<32>),
+    /// Key for storing a schema's deduplication window in ledgers (0 or absent disables it)
+    SchemaDedupWindow(BytesN<32>),
+    /// Key for storing a schema's custom event topic, appended to its attestation events
+    SchemaEventTopic(BytesN<32>),
+    /// Key for storing the minimum time, in seconds, a schema's attestations stay active before revocation
+    SchemaMinActiveDuration(BytesN<32>),
+    /// Key for marking a schema as soulbound, so its attestations emit ERC-5192-style lock events
+    SchemaSoulbound(BytesN<32>),
+    /// Key for storing how far ahead, in seconds, a schema's attestations may expire
+    SchemaExpirationHorizon(BytesN<32>),
+    /// Key for storing how far ahead, in seconds, any attestation may expire
+    ExpirationHorizon,
+    /// Key for storing the delegated message format versions the contract accepts
+    AcceptedMessageVersions,
+    /// Key for storing a schema's attestation uniqueness constraint and the keys it has reserved
+    Uniqueness(UniquenessKey),
+    /// Key for tracking a recent attestation fingerprint in temporary storage
+    ///
+    /// Fingerprint is `sha256(schema_uid || attester || subject || value)`
+    RecentAttestation(BytesN<32>),
+    /// Key for marking an address as a member of an organization: (organization, member)
+    OrgMember(Address, Address),
+    /// Key for storing the number of attestations issued on behalf of an organization
+    OrgAttestationCount(Address),
+    /// Key for storing an attestation UID issued on behalf of an organization, indexed by issue order
+    OrgAttestation(Address, u32),
+    /// Key for storing the number of schemas registered under a namespace
+    NamespaceSchemaCount(Symbol),
+    /// Key for storing a schema UID registered under a namespace, indexed by registration order
+    NamespaceSchema(Symbol, u32),
+    /// Key for mapping an attester's external reference ID to an attestation UID
+    ExternalId(Address, Bytes),
+    /// Key for marking an attester as banned by the admin
+    BannedAttester(Address),
+    /// Key for storing the cleanup bounty pool configuration and balance
+    CleanupBounty,
+    /// Key for storing the sequence number of the most recent outbox entry
+    OutboxSeq,
+    /// Key for storing an outbox entry, indexed by its ring buffer slot
+    OutboxEntry(u64),
+    /// Key for storing an unclaimed attestation, indexed by the UID it will be stored under
+    Claimable(BytesN<32>),
+    /// Key for storing a schema's voting weight rule, indexed by the schema's unique identifier
+    SchemaVotingWeight(BytesN<32>),
+    /// Key for storing the timestamp from which a compromised attester's attestations are frozen,
+    /// up to its `ExtendedKey::AttesterFreezeUntil`
+    AttesterFreezeAfter(Address),
+    /// Key for marking an attestation as reviewed and unfrozen by the admin
+    UnfrozenAttestation(BytesN<32>),
+    /// Key for storing the per-field hash commitments of a selective disclosure attestation
+    FieldCommitments(BytesN<32>),
+    /// Key for storing the protocol-wide counters reported in metrics heartbeats
+    ///
+    /// Held in persistent storage; earlier versions kept it in instance storage
+    ProtocolMetrics,
+    /// Key for storing the mux ID of an attestation issued to a muxed account
+    SubjectMuxId(BytesN<32>),
+    /// Key for storing the UID of the most recent attestation for a schema and muxed subject: (schema, account, mux ID)
+    MuxedSubjectAttestation(BytesN<32>, Address, u64),
+    /// Key for storing the admin council, its proposals and a pending admin transfer
+    Governance(GovernanceKey),
+    /// Key for storing the number of attestations appended to a listing
+    ListingCount(ListingKey),
+    /// Key for storing an attestation UID in a listing, indexed by append order
+    Listing(ListingKey, u32),
+    /// Key for storing a schema's global revocation, set by `revoke_all`
+    SchemaRevocation(BytesN<32>),
+    /// Key for storing an attester's home domain binding awaiting verification
+    PendingAttesterDomain(Address),
+    /// Key for storing an attester's verified home domain
+    AttesterDomain(Address),
+    /// Key for marking a schema's resolver hooks as deferred to the hook queue
+    SchemaDeferredHooks(BytesN<32>),
+    /// Key for storing the sequence number of the next hook queue entry to process
+    HookQueueHead,
+    /// Key for storing the sequence number the next hook queue entry is written under
+    HookQueueTail,
+    /// Key for storing the UID of an attestation awaiting its deferred resolver hooks, indexed by sequence
+    HookQueueEntry(u64),
+    /// Key for storing an attestation commitment awaiting its reveal, indexed by the commitment hash
+    AttestationCommitment(BytesN<32>),
+    /// Key for marking the contract as paused by the admin
+    Paused,
+    /// Key for entries added after `DataKey` reached the contract type variant limit
+    Extended(ExtendedKey),
 }
 
 /// ╔══════════════════════════════════════════════════════════════════════════╗
-/// ║                               Authority                                   ║
+/// ║                           Schema Definition                               ║
 /// ╚══════════════════════════════════════════════════════════════════════════╝
 ///
-/// Represents an authority that can create schemas and attestations.
+/// Typed form of an `XDR:`-prefixed schema definition string.
 ///
-/// Authorities are registered entities with specific permissions in the system
-/// that can create schemas and issue attestations.
-#[derive(Debug, Clone)]
+/// Converted to and from the string form by `xdr_codec::encode_schema` and
+/// `xdr_codec::decode_schema`.
 #[contracttype]
-pub struct Authority {
-    /// The Stellar address of the authority
-    pub address: Address,
-    /// Metadata describing the authority
-    ///
-    /// Typically in JSON format, containing information about the authority.
-    pub metadata: String,
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaDefinition {
+    /// Human-readable schema name
+    pub name: String,
+    /// Description of what attestations under the schema assert
+    pub description: String,
+    /// The fields an attestation value carries
+    pub fields: Vec<SchemaField>,
+}
+
+/// A single field of a [`SchemaDefinition`].
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaField {
+    /// Field name
+    pub name: String,
+    /// Field type, e.g. `string`, `bool`, `u64` or `address`
+    pub field_type: String,
+    /// Whether attestation values may omit the field
+    pub optional: bool,
 }
 
 /// ╔══════════════════════════════════════════════════════════════════════════╗
-/// ║                                 Schema                                    ║
+/// ║                            Structured Value                               ║
 /// ╚══════════════════════════════════════════════════════════════════════════╝
 ///
-/// Represents a schema definition that attestations can follow.
+/// The value of one schema field in a `BIN:`-prefixed structured attestation value.
 ///
-/// Schemas define the structure and validation rules for attestations.
-/// The definition field supports multiple formats:
-/// - XDR-encoded: Stellar-native binary format for structured data
-/// - JSON: Human-readable structured format
+/// Converted to and from the string form by `value_codec::encode_value` and
+/// `value_codec::decode_value`, one entry per field of the schema definition.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
-pub struct Schema {
-    /// The address of the authority that created this schema
-    pub authority: Address,
-    /// The schema definition in any supported format
-    ///
-    /// Supports XDR-encoded structured data or JSON
-    pub definition: String,
-    /// Optional address of a resolver contract for this schema
+pub enum FieldValue {
+    /// An optional field the value omits
+    Absent,
+    Bool(bool),
+    U32(u32),
+    U64(u64),
+    I64(i64),
+    I128(i128),
+    String(String),
+    Bytes(Bytes),
+    Address(Address),
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                          Schema Compatibility                             ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Field-level changes between a schema definition and a proposed successor, as seen
+/// by consumers reading attestation values.
+///
+/// Removing a field, changing its type or making it optional can break consumers that
+/// rely on it; adding fields or making optional fields required cannot.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaCompatibility {
+    /// Whether any breaking change was found
+    pub breaking: bool,
+    /// Fields dropped from the definition (breaking)
+    pub removed: Vec<String>,
+    /// Fields whose type changed (breaking)
+    pub retyped: Vec<String>,
+    /// Required fields that became optional (breaking)
+    pub relaxed: Vec<String>,
+    /// Fields new to the definition (additive)
+    pub added: Vec<String>,
+    /// Optional fields that became required (additive)
+    pub tightened: Vec<String>,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                              Schema Stats                                 ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Running totals for a schema, maintained incrementally on every attestation
+/// and revocation so explorers can render a schema page from a single call.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SchemaStats {
+    /// Total number of attestations issued under this schema
+    pub issued: u64,
+    /// Total number of attestations revoked under this schema
+    pub revoked: u64,
+    /// Number of issued attestations that have not been revoked
     ///
-    /// If present, this contract will be called to handle attestation operations.
-    pub resolver: Option<Address>,
-    /// Whether attestations using this schema can be revoked
-    pub revocable: bool,
+    /// Attestations that have merely expired are still counted as active.
+    pub active: u64,
+    /// Number of distinct subjects that have received an attestation under this schema
+    pub unique_subjects: u64,
+    /// Timestamp of the most recent attestation, or 0 if none have been issued
+    pub last_issued_at: u64,
 }
 
 /// ╔══════════════════════════════════════════════════════════════════════════╗
-/// ║                      DelegatedAttestationRequest                          ║
+/// ║                                Subject                                    ║
 /// ╚══════════════════════════════════════════════════════════════════════════╝
 ///
-/// Represents a request for delegated attestations.
+/// An attestation subject in either plain (`G...`/`C...`) or muxed (`M...`) form.
 ///
-/// This allows an attester to sign an attestation off-chain, which can then be
-/// submitted on-chain by any party (who will pay the transaction fees).
+/// A muxed account is its underlying `address` plus a `mux_id` addressing one of its
+/// sub-accounts, as used by exchanges and custodians.
 #[contracttype]
-#[derive(Clone)]
-pub struct DelegatedAttestationRequest {
-    /// The unique identifier of the schema this attestation follows
+#[derive(Clone, Debug, PartialEq)]
+pub struct Subject {
+    /// The underlying account
+    pub address: Address,
+    /// The mux ID of the sub-account, if the subject is a muxed account
+    pub mux_id: Option<u64>,
+}
+
+/// Whether an attestation subject is an account or a contract.
+///
+/// Attestations about contracts, such as audits and risk scores, are indexed separately
+/// from those about accounts so either kind can be listed on its own.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum SubjectType {
+    /// A Stellar account (`G...`)
+    Account = 0,
+    /// A Soroban contract (`C...`)
+    Contract = 1,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                            Protocol Metrics                               ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Protocol-wide counters, published in a `METRICS` event every
+/// `METRICS_HEARTBEAT_INTERVAL` attestations for lightweight monitors.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProtocolMetrics {
+    /// Total number of attestations issued
+    pub total_attestations: u64,
+    /// Total number of attestations revoked
+    pub total_revocations: u64,
+    /// Number of registered schemas
+    pub total_schemas: u64,
+    /// Number of schemas with at least one unrevoked attestation
+    pub active_schemas: u64,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                            BLS Public Key                                 ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Represents a BLS12-381 public key for an attester.
+///
+/// Each wallet address can have exactly one BLS public key. No updates or revocations.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlsPublicKey {
+    /// The BLS12-381 G2 public key (192 bytes compressed)
+    pub key: BytesN<192>,
+    /// Timestamp when this key was registered
+    pub registered_at: u64,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                         Secp256k1 Signer                                  ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Represents an EVM-style secp256k1 signer bound to an attester.
+///
+/// The signer is identified by its 20-byte EVM address (the last 20 bytes of the
+/// Keccak256 hash of the uncompressed public key), so keys held in MetaMask or an
+/// HSM can authorize delegated requests without a new key ceremony.
+///
+/// Each wallet address can have exactly one secp256k1 signer. No updates or revocations.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Secp256k1Signer {
+    /// The 20-byte EVM address derived from the secp256k1 public key
+    pub eth_address: BytesN<20>,
+    /// Timestamp when this signer was registered
+    pub registered_at: u64,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                            Outbox Action                                  ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// The kind of state change recorded in an outbox entry.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutboxAction {
+    /// A new attestation was created
+    Attested,
+    /// An existing attestation was revoked
+    Revoked,
+    /// A revoked attestation was reinstated after an appeal
+    Reinstated,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                            Outbox Entry                                   ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// A summary of an attestation or revocation kept in the on-chain outbox.
+///
+/// The outbox is a fixed-size ring buffer of the most recent state changes. Each
+/// entry carries a monotonically increasing sequence number so off-chain consumers
+/// can poll for everything after the last sequence they processed, even when the
+/// corresponding events have aged out of Horizon's retention window.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutboxEntry {
+    /// Sequence number of this entry, starting at 1
+    pub seq: u64,
+    /// Whether this entry records an attestation or a revocation
+    pub action: OutboxAction,
+    /// The unique identifier of the affected attestation
+    pub attestation_uid: BytesN<32>,
+    /// The unique identifier of the schema the attestation follows
     pub schema_uid: BytesN<32>,
-    /// The address of the entity that is the subject of this attestation
+    /// The address of the entity that is the subject of the attestation
     pub subject: Address,
-    /// The address of the original attester (who signed off-chain)
+    /// The address of the entity that created the attestation
     pub attester: Address,
-    /// The value or content of the attestation
-    pub value: String,
-    /// The nonce for this attestation (must be the next expected nonce for the attester)
-    pub nonce: u64,
-    /// Expiration timestamp for this signed request
-    ///
-    /// After this time, the signature is no longer valid and cannot be submitted.
-    pub deadline: u64,
-    /// Optional expiration time for the attestation itself
-    pub expiration_time: Option<u64>,
-    /// BLS12-381 G1 signature of the request data (96 bytes)
-    pub signature: BytesN<96>,
+    /// Ledger timestamp at which the change was recorded
+    pub timestamp: u64,
 }
 
 /// ╔══════════════════════════════════════════════════════════════════════════╗
-/// ║                      DelegatedRevocationRequest                           ║
+/// ║                           Hook Simulation                                 ║
 /// ╚══════════════════════════════════════════════════════════════════════════╝
 ///
-/// Represents a request for delegated revocation.
+/// The outcome of dry-running a schema's resolver hooks against a prospective attestation.
 ///
-/// This allows an attester to sign a revocation off-chain, which can then be
-/// submitted on-chain by any party.
+/// Lets SDKs surface gating failures to users before they sign a real transaction.
 #[contracttype]
-#[derive(Clone)]
-pub struct DelegatedRevocationRequest {
-    /// The unique identifier of the attestation to revoke
-    pub attestation_uid: BytesN<32>,
-    /// The unique identifier of the schema
+#[derive(Clone, Debug, PartialEq)]
+pub struct HookSimulation {
+    /// The schema's resolver, or `None` if the schema has no resolver
+    pub resolver: Option<Address>,
+    /// Whether the resolver's `onattest` hook would allow the attestation
+    pub allowed: bool,
+    /// The resolver's contract error code, if the hook failed rather than returning a verdict
+    pub error_code: Option<u32>,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                            Validity Proof                                 ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Everything a light client or bridge needs to check an attestation against a
+/// Stellar ledger header.
+///
+/// The attestation is stored as a persistent contract data entry. The verifier rebuilds
+/// its `LedgerKey::ContractData` from `contract`, `key_xdr` and persistent durability,
+/// then checks that the entry's value matches `value_xdr` in the proven ledger state.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidityProof {
+    /// The attestation record
+    pub attestation: Attestation,
+    /// The contract that owns the ledger entry
+    pub contract: Address,
+    /// XDR `ScVal` encoding of the entry's storage key
+    pub key_xdr: Bytes,
+    /// XDR `ScVal` encoding of the entry's value, i.e. the canonical attestation bytes
+    pub value_xdr: Bytes,
+    /// SHA-256 of `value_xdr`, for compact commitments
+    pub value_hash: BytesN<32>,
+    /// The ledger sequence the proof was produced at
+    pub ledger_sequence: u32,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                          Attestation Status                               ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// An attestation together with its status evaluated against the current ledger, so
+/// consumers do not each re-implement the expiry and revocation checks.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttestationStatus {
+    /// The attestation record, with any schema-wide revocation applied
+    pub attestation: Attestation,
+    /// Whether the expiration time has passed at the current ledger timestamp
+    pub is_expired: bool,
+    /// Whether the attestation, or its whole schema, has been revoked
+    pub is_revoked: bool,
+    /// Whether the subject has appealed the revocation and the schema authority has not yet decided
+    pub is_disputed: bool,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                          Attestation Appeal                               ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// A subject's appeal against the revocation of an attestation about them.
+///
+/// The attestation is disputed until the schema authority confirms the revocation or
+/// reinstates it. Reinstating removes the appeal, so a later revocation can be appealed anew.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttestationAppeal {
+    /// The subject who appealed
+    pub appellant: Address,
+    /// Timestamp when the appeal was filed
+    pub appealed_at: u64,
+    /// Timestamp when the schema authority confirmed the revocation, or `None` while disputed
+    pub confirmed_at: Option<u64>,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                            Cleanup Bounty                                 ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// A pool, funded by the admin, that pays callers of `purge` for each expired or
+/// long-revoked attestation they remove from persistent storage.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CleanupBounty {
+    /// The token the bounty is paid in
+    pub token: Address,
+    /// Amount paid per entry removed
+    pub bounty_per_entry: i128,
+    /// Remaining pool balance held by the contract
+    pub balance: i128,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                         Claimable Attestation                             ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// An attestation issued before its recipient is known (a claim link).
+///
+/// Whoever presents the preimage of `claim_hash` before `expiry` becomes the subject,
+/// at which point the record is promoted to a regular attestation under the same UID.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClaimableAttestation {
+    /// The address that issued the attestation
+    pub attester: Address,
+    /// The unique identifier of the schema the attestation follows
     pub schema_uid: BytesN<32>,
-    /// The address of the entity that is the subject of the attestation to revoke
-    pub subject: Address,
-    /// The nonce of the attestation to revoke
+    /// The value or content of the attestation
+    pub value: String,
+    /// SHA-256 of the secret shared with the intended recipient
+    pub claim_hash: BytesN<32>,
+    /// Timestamp after which the attestation can no longer be claimed
+    pub expiry: u64,
+    /// The attester nonce consumed when the claimable attestation was issued
     pub nonce: u64,
-    /// The address of the original attester (who signed off-chain)
-    pub revoker: Address,
-    /// Expiration timestamp for this signed request
-    pub deadline: u64,
-    /// BLS12-381 G1 signature of the request data (96 bytes)
-    pub signature: BytesN<96>,
+    /// Timestamp when the claimable attestation was issued
+    pub created_at: u64,
 }
 
 /// ╔══════════════════════════════════════════════════════════════════════════╗
-/// ║                            Attestation                                    ║
+/// ║                             Commit-Reveal                                 ║
 /// ╚══════════════════════════════════════════════════════════════════════════╝
 ///
-/// Represents an attestation with support for both direct and delegated attestations.
+/// A hash-only commitment to an attestation, recorded before its content is public.
 ///
-/// Used for tracking attestations and supporting multiple attestations per schema/subject
-/// pair through nonces.
+/// Held in temporary storage until revealed or until the reveal window closes.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
-pub struct Attestation {
-    /// The unique identifier of the attestation
-    pub uid: BytesN<32>,
-    /// The unique identifier of the schema this attestation follows
-    pub schema_uid: BytesN<32>,
-    /// The address of the entity that is the subject of this attestation
-    pub subject: Address,
-    /// The address of the entity that created this attestation
-    ///
-    /// In direct attestations, this is the caller.
-    /// In delegated attestations, this is the original signer.
+pub struct AttestationCommitment {
+    /// The address that committed, and must reveal
     pub attester: Address,
+    /// Ledger sequence the commitment was recorded in
+    pub committed_ledger: u32,
+}
+
+/// The content behind an [`AttestationCommitment`].
+///
+/// The commitment is `sha256(attester XDR || reveal XDR)`; the random `salt` keeps
+/// low-entropy values (e.g. "pass"/"fail") from being guessed from the hash.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttestationReveal {
+    /// The unique identifier of the schema the attestation follows
+    pub schema_uid: BytesN<32>,
     /// The value or content of the attestation
     pub value: String,
-    /// Unique nonce for this attestation
-    ///
-    /// Allows for multiple attestations of the same schema for the same subject,
-    /// and prevents replay attacks in delegated attestations.
-    pub nonce: u64,
-    /// Timestamp when the attestation was created
-    pub timestamp: u64,
-    /// Optional expiration timestamp
-    ///
-    /// If set, the attestation is considered invalid after this time.
+    /// Optional timestamp after which the attestation expires
     pub expiration_time: Option<u64>,
-    /// Whether this attestation has been revoked
-    pub revoked: bool,
-    /// Optional timestamp when the attestation was revoked
-    pub revocation_time: Option<u64>,
+    /// Random blinding bytes chosen by the attester
+    pub salt: BytesN<32>,
 }
 
 /// ╔══════════════════════════════════════════════════════════════════════════╗
-/// ║                            BLS Public Key                                 ║
+/// ║                          Voting Weight Rule                               ║
 /// ╚══════════════════════════════════════════════════════════════════════════╝
 ///
-/// Represents a BLS12-381 public key for an attester.
+/// How a schema's attestations translate into voting weight for governance contracts.
 ///
-/// Each wallet address can have exactly one BLS public key. No updates or revocations.
+/// The weight is read from `field` of the subject's current attestation value: a
+/// JSON integer is used as-is, and a JSON string is looked up in `tiers` (e.g.
+/// `"gold"` => 3). Values that match neither carry no weight.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
-pub struct BlsPublicKey {
-    /// The BLS12-381 G2 public key (192 bytes compressed)
-    pub key: BytesN<192>,
-    /// Timestamp when this key was registered
-    pub registered_at: u64,
+pub struct VotingWeightRule {
+    /// Name of the attestation field holding the weight or tier
+    pub field: String,
+    /// Weight per tier name, for string-valued fields
+    pub tiers: Map<String, u64>,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                           Protocol Config                                 ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// A snapshot of the contract's configuration, for monitoring tools and SDK health
+/// checks that should not need to know individual storage keys.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProtocolConfig {
+    /// The contract admin, or `None` before initialization
+    pub admin: Option<Address>,
+    /// Whether attestations and revocations are paused by the admin
+    pub paused: bool,
+    /// The contract's crate version
+    pub version: String,
+    /// The token the cleanup bounty is paid in, or `None` if the pool has never been funded
+    pub cleanup_bounty_token: Option<Address>,
+    /// Amount paid per purged entry, or 0 if the pool has never been funded
+    pub cleanup_bounty_per_entry: i128,
+    /// Remaining cleanup bounty pool balance
+    pub cleanup_bounty_balance: i128,
+    /// Compile-time limits enforced by the contract
+    pub limits: ProtocolLimits,
+}
+
+/// Compile-time limits enforced by the protocol contract.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProtocolLimits {
+    /// Number of entries retained in the outbox ring buffer
+    pub outbox_capacity: u64,
+    /// Maximum length of an attestation's external reference ID, in bytes
+    pub max_external_id_len: u32,
+    /// Maximum length of an encrypted attestation's wrapped key, in bytes
+    pub max_encrypted_key_len: u32,
+    /// Seconds a revoked attestation is retained before it can be purged
+    pub revoked_retention_seconds: u64,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                             Admin Council                                 ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// The M-of-N set of addresses that must approve critical admin operations.
+///
+/// Replaces an external multisig: approvals are collected in contract storage and the
+/// operation executes once `threshold` members have approved it.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminCouncil {
+    /// The council members
+    pub members: Vec<Address>,
+    /// The number of member approvals an operation needs
+    pub threshold: u32,
+}
+
+/// A critical admin operation requiring council approval.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AdminOperation {
+    /// Upgrade the contract to the uploaded Wasm with this hash
+    Upgrade(BytesN<32>),
+    /// Replace the admin used for routine admin operations
+    SetAdmin(Address),
+    /// Replace the council with new members and threshold
+    SetCouncil(Vec<Address>, u32),
+    /// Pause attesting and revoking contract-wide
+    Pause,
+    /// Lift a pause
+    Unpause,
+}
+
+/// A critical admin operation awaiting council approvals.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminProposal {
+    /// The operation to execute once approved
+    pub operation: AdminOperation,
+    /// The council member who proposed the operation
+    pub proposer: Address,
+    /// The addresses that have approved the operation, including the proposer
+    pub approvals: Vec<Address>,
+    /// Timestamp when the operation was proposed
+    pub created_at: u64,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                          Attestation Listings                             ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Identifies an append-only listing of attestation UIDs, or of schema UIDs for
+/// `SchemaVersions`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ListingKey {
+    /// Attestations about a subject
+    Subject(Address),
+    /// Attestations issued under a schema
+    Schema(BytesN<32>),
+    /// Attestations issued by an attester
+    Attester(Address),
+    /// Attestations issued under a schema about subjects of one type
+    SchemaSubjectType(BytesN<32>, SubjectType),
+    /// Versions of a schema lineage, keyed by the UID of its first version
+    SchemaVersions(BytesN<32>),
+    /// Attestations issued under a schema in one locale
+    SchemaLocale(BytesN<32>, Symbol),
+    /// Attestations that reference an attestation through `ref_uid`
+    Referencing(BytesN<32>),
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                            Governance Key                                 ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Identifies the storage of admin governance: the council, its proposals, two-step
+/// admin transfers and the storage version migrated to after upgrades.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GovernanceKey {
+    /// The council that approves critical admin operations
+    Council,
+    /// The number of critical admin operations ever proposed
+    ProposalCount,
+    /// A pending critical admin operation, indexed by proposal ID
+    Proposal(u32),
+    /// The address proposed as the next admin, until it accepts
+    PendingAdmin,
+    /// The storage layout version the contract's entries are in
+    StorageVersion,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                            Uniqueness Key                                 ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Identifies the storage of a schema's attestation uniqueness constraint.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum UniquenessKey {
+    /// The name of the value field a schema's attestations must be unique on
+    Field(BytesN<32>),
+    /// The attestation holding a uniqueness key hash: (schema, key hash)
+    Holder(BytesN<32>, BytesN<32>),
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                            Extended Key                                   ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Identifies storage added once `DataKey` reached the 50-variant limit of contract
+/// types, so new features keep getting keys without renaming existing entries.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExtendedKey {
+    /// One 128-bit word of an attester's nonce bitmap, indexed by `nonce / 128`,
+    /// marking nonces above the counter consumed out of order by delegated requests
+    AttesterNonceBitmap(Address, u64),
+    /// The locales a schema's attestations may be written in
+    SchemaLocales(BytesN<32>),
+    /// Whether a schema's attestations need the subject's acceptance
+    SchemaRequiresConsent(BytesN<32>),
+    /// An attestation held until its subject accepts it
+    PendingAttestation(BytesN<32>),
+    /// Marks a schema whose attestation subjects may revoke attestations about themselves
+    SchemaSubjectRevocable(BytesN<32>),
+    /// How long after a revocation under a schema its subject may appeal it, in seconds
+    SchemaChallengeWindow(BytesN<32>),
+    /// The subject's appeal against an attestation's revocation
+    AttestationAppeal(BytesN<32>),
+    /// The listing and lookup entries pointing at an attestation, removed with it when it is purged
+    AttestationIndexKeys(BytesN<32>),
+    /// When an attester declared its compromise, ending the window its attestations are frozen in
+    AttesterFreezeUntil(Address),
+    /// Marks a pending attestation whose resolver hooks are to be queued once it is accepted
+    PendingHooksDeferred(BytesN<32>),
+}
+
+/// A page of a listing of attestation UIDs.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttestationPage {
+    /// The attestation UIDs, oldest first
+    pub uids: Vec<BytesN<32>>,
+    /// Opaque cursor to pass for the next page; stays valid as new attestations are appended
+    pub next_cursor: Bytes,
+    /// Whether more entries follow this page at the time of the call
+    pub has_more: bool,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                           Schema Revocation                               ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Records that every attestation under a schema was revoked at once by its authority.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaRevocation {
+    /// Why the schema was revoked
+    pub reason: String,
+    /// Timestamp of the revocation, reported as the revocation time of its attestations
+    pub revoked_at: u64,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                            Attester Domains                               ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// A home domain claimed by an attester, awaiting verification of its hosted token.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingAttesterDomain {
+    /// The claimed home domain
+    pub domain: String,
+    /// SHA-256 hash of the token the attester hosts in the domain's `stellar.toml`
+    pub challenge_hash: BytesN<32>,
+    /// Timestamp of the request
+    pub requested_at: u64,
+}
+
+/// An attester's verified home domain, letting verifiers display who issued an attestation.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttesterDomain {
+    /// The verified home domain
+    pub domain: String,
+    /// SHA-256 hash of the hosted token that was checked
+    pub challenge_hash: BytesN<32>,
+    /// Timestamp of the verification
+    pub verified_at: u64,
+    /// The admin that verified the hosted token
+    pub verified_by: Address,
 }
@@ -1,18 +1,21 @@
-use crate::state::{Authority, DataKey, Schema};
-use soroban_sdk::xdr::ToXdr;
-use soroban_sdk::{Address, Bytes, BytesN, Env, String};
+use crate::errors::Error;
+use crate::state::{Authority, DataKey, ExtendedKey, Schema, SubjectType};
+use soroban_sdk::xdr::{FromXdr, ToXdr};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Symbol};
 
 ////////////////////////////////////////////////////////////////////////////////////
 /// Generates a unique identifier (SHA256 hash) for a schema.
 ////////////////////////////////////////////////////////////////////////////////////
 /// The UID is derived from the schema definition, the registering authority,
-/// and the optional resolver address.
+/// the optional resolver address, and the optional namespace. Schemas outside a
+/// namespace keep the same UID they had before namespaces were introduced.
 ///
 /// # Arguments
 /// * `env` - The Soroban environment providing access to cryptographic functions.
 /// * `schema_definition` - The schema definition string (supports multiple formats).
 /// * `authority` - The address of the authority registering the schema.
 /// * `resolver` - An optional address of a resolver contract associated with the schema.
+/// * `namespace` - An optional namespace the schema is registered under.
 ///
 /// # Returns
 /// * `BytesN<32>` - The unique 32-byte identifier (UID) for the schema.
@@ -22,6 +25,7 @@ pub fn generate_schema_uid(
     schema_definition: &String,
     authority: &Address,
     resolver: &Option<Address>,
+    namespace: &Option<Symbol>,
 ) -> BytesN<32> {
     let mut schema_data_to_hash = Bytes::new(env);
     schema_data_to_hash.append(&schema_definition.clone().to_xdr(env));
@@ -29,6 +33,9 @@ pub fn generate_schema_uid(
     if let Some(resolver_addr) = resolver {
         schema_data_to_hash.append(&resolver_addr.clone().to_xdr(env));
     }
+    if let Some(namespace) = namespace {
+        schema_data_to_hash.append(&namespace.clone().to_xdr(env));
+    }
     env.crypto().sha256(&schema_data_to_hash).into()
 }
 ////////////////////////////////////////////////////////////////////////////////////
@@ -121,6 +128,65 @@ pub fn get_schema(env: &Env, schema_uid: &BytesN<32>) -> Option<Schema> {
     env.storage().instance().get(&key)
 }
 
+/// Builds a Soroban string from raw UTF-8 bytes.
+///
+/// The inverse of [`string_to_bytes`]: the bytes are wrapped in an `ScVal::String`
+/// XDR envelope (type tag, length, padding) and decoded by the host.
+pub fn bytes_to_string(env: &Env, bytes: &Bytes) -> String {
+    let mut xdr_bytes = Bytes::from_array(env, &[0, 0, 0, 14]); // SCV_STRING
+    xdr_bytes.extend_from_array(&bytes.len().to_be_bytes());
+    xdr_bytes.append(bytes);
+    while !xdr_bytes.len().is_multiple_of(4) {
+        xdr_bytes.push_back(0);
+    }
+    String::from_xdr(env, &xdr_bytes).unwrap_or_else(|_| String::from_str(env, ""))
+}
+
+/// Returns the raw UTF-8 bytes of a Soroban string.
+///
+/// The XDR encoding of an `ScVal::String` is a 4-byte type tag and a 4-byte length
+/// followed by the string bytes (plus padding), so the bytes are sliced out of it.
+pub fn string_to_bytes(env: &Env, value: &String) -> Bytes {
+    let xdr_bytes = value.clone().to_xdr(env);
+    xdr_bytes.slice(8..8 + value.len())
+}
+
+/// Returns whether an address is an account or a contract.
+///
+/// The XDR encoding of an `ScVal::Address` is a 4-byte type tag followed by the
+/// 4-byte `ScAddress` kind, whose last byte is 0 for accounts and 1 for contracts.
+pub fn subject_type(env: &Env, address: &Address) -> SubjectType {
+    let xdr_bytes = address.clone().to_xdr(env);
+    if xdr_bytes.get_unchecked(7) == 0 {
+        SubjectType::Account
+    } else {
+        SubjectType::Contract
+    }
+}
+
+/// Verifies that the caller is the contract admin.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The address claiming to be the admin. Must authorize the transaction.
+///
+/// # Errors
+/// * `Error::AdminNotSet` - If the contract has not been initialized
+/// * `Error::NotAuthorized` - If the caller is not the admin
+pub fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+    caller.require_auth();
+
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(Error::AdminNotSet)?;
+    if *caller != admin {
+        return Err(Error::NotAuthorized);
+    }
+    Ok(())
+}
+
 /// Gets the next nonce for an attester.
 ///
 /// # Arguments
@@ -134,67 +200,54 @@ pub fn get_next_nonce(env: &Env, attester: &Address) -> u64 {
     env.storage().persistent().get::<DataKey, u64>(&nonce_key).unwrap_or(0)
 }
 
-/// Creates a base64-encoded XDR string from a Soroban string value.
+/// Number of nonces tracked by each word of an attester's nonce bitmap.
+const NONCE_BITMAP_WORD_BITS: u64 = 128;
+
+fn nonce_bitmap_key(attester: &Address, word: u64) -> DataKey {
+    DataKey::Extended(ExtendedKey::AttesterNonceBitmap(attester.clone(), word))
+}
+
+/// Returns whether a nonce at or above the counter was already consumed out of order.
+pub fn is_nonce_bit_set(env: &Env, attester: &Address, nonce: u64) -> bool {
+    let word_key = nonce_bitmap_key(attester, nonce / NONCE_BITMAP_WORD_BITS);
+    let word = env.storage().persistent().get::<DataKey, u128>(&word_key).unwrap_or(0);
+    word & (1u128 << (nonce % NONCE_BITMAP_WORD_BITS)) != 0
+}
+
+/// Marks a nonce above the counter as consumed in the attester's nonce bitmap.
+pub fn set_nonce_bit(env: &Env, attester: &Address, nonce: u64) {
+    let word_key = nonce_bitmap_key(attester, nonce / NONCE_BITMAP_WORD_BITS);
+    let word = env.storage().persistent().get::<DataKey, u128>(&word_key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&word_key, &(word | (1u128 << (nonce % NONCE_BITMAP_WORD_BITS))));
+}
+
+/// Moves an attester's nonce counter to `next_nonce`, then past any nonces already
+/// consumed out of order, so the counter always points at an unused nonce.
 ///
-/// This utility function takes a Soroban string, converts it to XDR bytes,
-/// hashes those bytes using SHA256, and then encodes the hash as a base64 XDR string.
-/// This is useful for creating deterministic identifiers or for XDR serialization
-/// in Soroban contracts.
+/// Bitmap words the counter has fully passed while skipping are removed.
 ///
 /// # Arguments
 /// * `env` - The Soroban environment
-/// * `value` - A Soroban string value to process
-///
-/// # Returns   
-/// * `String` - A Soroban string containing the base64-encoded XDR representation
-///   of the SHA256 hash of the input string's XDR bytes
+/// * `attester` - The address of the attester
+/// * `next_nonce` - The lowest nonce the counter may point at
 ///
-/// # Example
-/// ```ignore
-/// let some_string = String::from_str(&env, "hello world");
-/// let xdr_string = create_xdr_string(&env, &some_string);
-/// // Returns a base64-encoded XDR string of the SHA256 hash
-/// ```
-pub fn create_xdr_string(env: &Env, value: &String) -> String {
-    // Convert the input string to XDR bytes
-    let xdr_bytes = value.clone().to_xdr(env);
-
-    // Hash the XDR bytes using SHA256 to create a deterministic identifier
-    let hash: BytesN<32> = env.crypto().sha256(&xdr_bytes).into();
-
-    // Convert the hash to a string representation
-    // Since we're in a no_std environment, we'll create a deterministic string
-    // based on the first few bytes of the hash
-    let hash_array = hash.to_array();
-
-    // Convert prefix to a simple character representation
-    // We'll use the first byte of the hash as a simple identifier
-    let suffix_char = match hash_array[0] % 16 {
-        0 => "0",
-        1 => "1",
-        2 => "2",
-        3 => "3",
-        4 => "4",
-        5 => "5",
-        6 => "6",
-        7 => "7",
-        8 => "8",
-        9 => "9",
-        10 => "A",
-        11 => "B",
-        12 => "C",
-        13 => "D",
-        14 => "E",
-        _ => "F",
-    };
-
-    // Build the final string
-    let mut result_str = [0u8; 5];
-    result_str[0] = b'X';
-    result_str[1] = b'D';
-    result_str[2] = b'R';
-    result_str[3] = b':';
-    result_str[4] = suffix_char.as_bytes()[0];
-
-    String::from_bytes(env, &result_str)
+/// # Returns
+/// * `Result<u64, Error>` - The stored counter value
+pub fn set_next_nonce(env: &Env, attester: &Address, next_nonce: u64) -> Result<u64, Error> {
+    let mut next_nonce = next_nonce;
+    while is_nonce_bit_set(env, attester, next_nonce) {
+        next_nonce = next_nonce.checked_add(1).ok_or(Error::IntegerOverflow)?;
+        if next_nonce.is_multiple_of(NONCE_BITMAP_WORD_BITS) {
+            let passed_word = next_nonce / NONCE_BITMAP_WORD_BITS - 1;
+            env.storage()
+                .persistent()
+                .remove(&nonce_bitmap_key(attester, passed_word));
+        }
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::AttesterNonce(attester.clone()), &next_nonce);
+    Ok(next_nonce)
 }
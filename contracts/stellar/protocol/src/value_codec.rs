@@ -0,0 +1,294 @@
+//! Compact binary codec for structured attestation values.
+//!
+//! A structured value string is `BIN:` followed by the base64 of a payload laid out
+//! by the schema's field list, in declared order:
+//!
+//! ```text
+//! version          1 byte (1)
+//! presence bitmap  one bit per schema field, least significant bit first
+//! fields           each present field, in declared order:
+//!   bool           1 byte, 0 or 1
+//!   u32            4 bytes big-endian
+//!   u64, i64       8 bytes big-endian
+//!   i128           16 bytes big-endian
+//!   string, bytes  LEB128 length, then the raw bytes
+//!   address        1 byte kind (0 account, 1 contract), then the 32-byte key or hash
+//! ```
+//!
+//! Field names and JSON punctuation never appear in the value, so the payload of a
+//! typical credential is about half the size of its JSON; after base64 the stored
+//! string is still around 30% smaller. Encoding is canonical: unused bitmap bits
+//! must be clear and lengths minimally encoded, so equal values always produce the
+//! same string.
+
+use crate::errors::Error;
+use crate::state::{FieldValue, SchemaDefinition};
+use crate::utils::{bytes_to_string, string_to_bytes};
+use crate::xdr_codec::{base64_decode, base64_encode};
+use soroban_sdk::xdr::{FromXdr, ToXdr};
+use soroban_sdk::{Address, Bytes, Env, String, Vec};
+
+/// Prefix marking an attestation value as a structured value
+pub const VALUE_PREFIX: &[u8] = b"BIN:";
+
+/// Payload layout version
+const VALUE_VERSION: u8 = 1;
+
+/// ScVal discriminant of an address, and the ScAddress and PublicKey kinds used here
+const SCV_ADDRESS: u32 = 18;
+const ADDRESS_ACCOUNT: u8 = 0;
+const ADDRESS_CONTRACT: u8 = 1;
+
+/// The value types a schema field can declare.
+#[derive(Clone, Copy, PartialEq)]
+enum ValueType {
+    Bool,
+    U32,
+    U64,
+    I64,
+    I128,
+    String,
+    Bytes,
+    Address,
+}
+
+impl ValueType {
+    fn parse(env: &Env, field_type: &String) -> Option<ValueType> {
+        let names = [
+            ("bool", ValueType::Bool),
+            ("u32", ValueType::U32),
+            ("u64", ValueType::U64),
+            ("i64", ValueType::I64),
+            ("i128", ValueType::I128),
+            ("string", ValueType::String),
+            ("bytes", ValueType::Bytes),
+            ("address", ValueType::Address),
+        ];
+        names
+            .into_iter()
+            .find(|(name, _)| String::from_str(env, name) == *field_type)
+            .map(|(_, value_type)| value_type)
+    }
+}
+
+/// Returns true if the value uses the `BIN:` prefix convention.
+pub fn is_structured_value(env: &Env, value: &String) -> bool {
+    let prefix_len = VALUE_PREFIX.len() as u32;
+    value.len() >= prefix_len
+        && string_to_bytes(env, value).slice(0..prefix_len) == Bytes::from_slice(env, VALUE_PREFIX)
+}
+
+/// Encodes field values as a `BIN:`-prefixed structured value.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `definition` - The schema definition the value is laid out by
+/// * `values` - One entry per schema field, in declared order; `FieldValue::Absent` for
+///   omitted optional fields
+///
+/// # Returns
+/// * `Result<String, Error>` - `BIN:` followed by the base64 payload
+///
+/// # Errors
+/// * `Error::InvalidSchemaDefinition` - If a field declares a type the codec does not support
+/// * `Error::InvalidAttestationValue` - If the values do not match the fields in number,
+///   type or presence
+pub fn encode_value(env: &Env, definition: &SchemaDefinition, values: &Vec<FieldValue>) -> Result<String, Error> {
+    let field_count = definition.fields.len();
+    if values.len() != field_count {
+        return Err(Error::InvalidAttestationValue);
+    }
+
+    let mut bitmap = [0u8; 32];
+    let mut body = Bytes::new(env);
+    for (index, (field, value)) in definition.fields.iter().zip(values.iter()).enumerate() {
+        let value_type = ValueType::parse(env, &field.field_type).ok_or(Error::InvalidSchemaDefinition)?;
+        match (value_type, value) {
+            (_, FieldValue::Absent) if field.optional => continue,
+            (ValueType::Bool, FieldValue::Bool(flag)) => body.push_back(flag as u8),
+            (ValueType::U32, FieldValue::U32(number)) => body.extend_from_array(&number.to_be_bytes()),
+            (ValueType::U64, FieldValue::U64(number)) => body.extend_from_array(&number.to_be_bytes()),
+            (ValueType::I64, FieldValue::I64(number)) => body.extend_from_array(&number.to_be_bytes()),
+            (ValueType::I128, FieldValue::I128(number)) => body.extend_from_array(&number.to_be_bytes()),
+            (ValueType::String, FieldValue::String(text)) => write_opaque(&mut body, &string_to_bytes(env, &text)),
+            (ValueType::Bytes, FieldValue::Bytes(bytes)) => write_opaque(&mut body, &bytes),
+            (ValueType::Address, FieldValue::Address(address)) => write_address(env, &mut body, &address),
+            _ => return Err(Error::InvalidAttestationValue),
+        }
+        *bitmap.get_mut(index / 8).ok_or(Error::InvalidSchemaDefinition)? |= 1 << (index % 8);
+    }
+
+    let mut payload = Bytes::from_array(env, &[VALUE_VERSION]);
+    payload.extend_from_slice(&bitmap[..bitmap_len(field_count)]);
+    payload.append(&body);
+
+    let mut encoded = Bytes::from_slice(env, VALUE_PREFIX);
+    encoded.append(&base64_encode(env, &payload));
+    Ok(bytes_to_string(env, &encoded))
+}
+
+/// Decodes a `BIN:`-prefixed structured value into one entry per schema field.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `definition` - The schema definition the value is laid out by
+/// * `value` - The structured value string
+///
+/// # Returns
+/// * `Result<Vec<FieldValue>, Error>` - The field values in declared order, with
+///   `FieldValue::Absent` for omitted optional fields
+///
+/// # Errors
+/// * `Error::InvalidSchemaDefinition` - If a field declares a type the codec does not support
+/// * `Error::InvalidAttestationValue` - If the value is not a canonical encoding for the schema
+pub fn decode_value(env: &Env, definition: &SchemaDefinition, value: &String) -> Result<Vec<FieldValue>, Error> {
+    if !is_structured_value(env, value) {
+        return Err(Error::InvalidAttestationValue);
+    }
+    let raw = string_to_bytes(env, value);
+    let payload = base64_decode(env, &raw.slice(VALUE_PREFIX.len() as u32..)).ok_or(Error::InvalidAttestationValue)?;
+
+    let field_count = definition.fields.len();
+    let mut reader = ValueReader {
+        payload: &payload,
+        pos: 0,
+    };
+    if reader.read_u8() != Some(VALUE_VERSION) {
+        return Err(Error::InvalidAttestationValue);
+    }
+    let bitmap = reader
+        .read(bitmap_len(field_count) as u32)
+        .ok_or(Error::InvalidAttestationValue)?;
+    // Bits past the last field must be clear
+    for bit in field_count..bitmap.len() * 8 {
+        if bitmap.get_unchecked(bit / 8) & (1 << (bit % 8)) != 0 {
+            return Err(Error::InvalidAttestationValue);
+        }
+    }
+
+    let mut values = Vec::new(env);
+    for (index, field) in definition.fields.iter().enumerate() {
+        let value_type = ValueType::parse(env, &field.field_type).ok_or(Error::InvalidSchemaDefinition)?;
+        if bitmap.get_unchecked((index / 8) as u32) & (1 << (index % 8)) == 0 {
+            if !field.optional {
+                return Err(Error::InvalidAttestationValue);
+            }
+            values.push_back(FieldValue::Absent);
+            continue;
+        }
+        let value = reader
+            .read_value(env, value_type)
+            .ok_or(Error::InvalidAttestationValue)?;
+        values.push_back(value);
+    }
+    if reader.pos != payload.len() {
+        return Err(Error::InvalidAttestationValue); // Trailing bytes
+    }
+    Ok(values)
+}
+
+fn bitmap_len(field_count: u32) -> usize {
+    field_count.div_ceil(8) as usize
+}
+
+fn write_opaque(out: &mut Bytes, data: &Bytes) {
+    let mut len = data.len();
+    loop {
+        let byte = (len & 0x7f) as u8;
+        len >>= 7;
+        if len == 0 {
+            out.push_back(byte);
+            break;
+        }
+        out.push_back(byte | 0x80);
+    }
+    out.append(data);
+}
+
+/// Writes an address as its kind and 32-byte payload, taken from the host's XDR
+/// encoding of the `ScAddress`.
+fn write_address(env: &Env, out: &mut Bytes, address: &Address) {
+    let xdr = address.clone().to_xdr(env);
+    // Tag (4) | ScAddress kind (4) | account: PublicKey kind (4) + key (32), contract: hash (32)
+    if xdr.get_unchecked(7) == ADDRESS_ACCOUNT {
+        out.push_back(ADDRESS_ACCOUNT);
+        out.append(&xdr.slice(12..44));
+    } else {
+        out.push_back(ADDRESS_CONTRACT);
+        out.append(&xdr.slice(8..40));
+    }
+}
+
+/// Cursor over a structured value payload.
+struct ValueReader<'a> {
+    payload: &'a Bytes,
+    pos: u32,
+}
+
+impl ValueReader<'_> {
+    fn read(&mut self, len: u32) -> Option<Bytes> {
+        let end = self.pos.checked_add(len)?;
+        if end > self.payload.len() {
+            return None;
+        }
+        let bytes = self.payload.slice(self.pos..end);
+        self.pos = end;
+        Some(bytes)
+    }
+
+    fn read_u8(&mut self) -> Option<u8> {
+        Some(self.read(1)?.get_unchecked(0))
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let mut array = [0u8; N];
+        self.read(N as u32)?.copy_into_slice(&mut array);
+        Some(array)
+    }
+
+    /// Reads a minimally encoded LEB128 length, then that many bytes.
+    fn read_opaque(&mut self) -> Option<Bytes> {
+        let mut len: u32 = 0;
+        for shift in (0..35).step_by(7) {
+            let byte = self.read_u8()?;
+            if shift > 0 && byte == 0 {
+                return None; // Non-minimal encoding
+            }
+            len |= ((byte & 0x7f) as u32).checked_shl(shift)?;
+            if byte & 0x80 == 0 {
+                return self.read(len);
+            }
+        }
+        None
+    }
+
+    fn read_address(&mut self, env: &Env) -> Option<Address> {
+        let kind = self.read_u8()?;
+        let payload = self.read(32)?;
+        let mut xdr = Bytes::from_array(env, &SCV_ADDRESS.to_be_bytes());
+        match kind {
+            ADDRESS_ACCOUNT => xdr.extend_from_array(&[0, 0, 0, 0, 0, 0, 0, 0]),
+            ADDRESS_CONTRACT => xdr.extend_from_array(&[0, 0, 0, 1]),
+            _ => return None,
+        }
+        xdr.append(&payload);
+        Address::from_xdr(env, &xdr).ok()
+    }
+
+    fn read_value(&mut self, env: &Env, value_type: ValueType) -> Option<FieldValue> {
+        Some(match value_type {
+            ValueType::Bool => match self.read_u8()? {
+                0 => FieldValue::Bool(false),
+                1 => FieldValue::Bool(true),
+                _ => return None,
+            },
+            ValueType::U32 => FieldValue::U32(u32::from_be_bytes(self.read_array()?)),
+            ValueType::U64 => FieldValue::U64(u64::from_be_bytes(self.read_array()?)),
+            ValueType::I64 => FieldValue::I64(i64::from_be_bytes(self.read_array()?)),
+            ValueType::I128 => FieldValue::I128(i128::from_be_bytes(self.read_array()?)),
+            ValueType::String => FieldValue::String(bytes_to_string(env, &self.read_opaque()?)),
+            ValueType::Bytes => FieldValue::Bytes(self.read_opaque()?),
+            ValueType::Address => FieldValue::Address(self.read_address(env)?),
+        })
+    }
+}
@@ -0,0 +1,324 @@
+//! Deterministic codec for `XDR:`-prefixed schema definitions.
+//!
+//! A schema definition string starting with `XDR:` carries a base64-encoded
+//! `ScVal::Map` in the layout produced by the SDK's `SorobanSchemaEncoder`:
+//!
+//! ```text
+//! { name: String, description: String, fields: [{ name: String, type: String, optional: bool }] }
+//! ```
+//!
+//! Encoding is canonical: map keys are sorted by the host and only the keys above
+//! are written, so equal definitions always produce identical strings (and schema
+//! UIDs). Decoding ignores keys it does not know, such as field `validation` rules.
+
+use crate::errors::Error;
+use crate::state::{SchemaDefinition, SchemaField};
+use crate::utils::{bytes_to_string, string_to_bytes};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Bytes, Env, IntoVal, Map, String, Symbol, Val, Vec};
+
+/// Prefix marking a schema definition as XDR-encoded
+pub const XDR_PREFIX: &[u8] = b"XDR:";
+
+/// Standard base64 alphabet (RFC 4648), as used by `ScVal.toXDR('base64')`
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Returns true if the definition uses the `XDR:` prefix convention.
+pub fn is_xdr_definition(env: &Env, definition: &String) -> bool {
+    let prefix_len = XDR_PREFIX.len() as u32;
+    definition.len() >= prefix_len
+        && string_to_bytes(env, definition).slice(0..prefix_len) == Bytes::from_slice(env, XDR_PREFIX)
+}
+
+/// Encodes a typed schema definition as an `XDR:`-prefixed string.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `definition` - The typed schema definition
+///
+/// # Returns
+/// * `String` - `XDR:` followed by the base64 XDR of the definition map
+pub fn encode_schema(env: &Env, definition: &SchemaDefinition) -> String {
+    let mut fields: Vec<Map<Symbol, Val>> = Vec::new(env);
+    for field in definition.fields.iter() {
+        let mut entry: Map<Symbol, Val> = Map::new(env);
+        entry.set(Symbol::new(env, "name"), field.name.into_val(env));
+        entry.set(Symbol::new(env, "type"), field.field_type.into_val(env));
+        entry.set(Symbol::new(env, "optional"), field.optional.into_val(env));
+        fields.push_back(entry);
+    }
+
+    let mut map: Map<Symbol, Val> = Map::new(env);
+    map.set(Symbol::new(env, "name"), definition.name.into_val(env));
+    map.set(Symbol::new(env, "description"), definition.description.into_val(env));
+    map.set(Symbol::new(env, "fields"), fields.into_val(env));
+
+    let mut encoded = Bytes::from_slice(env, XDR_PREFIX);
+    encoded.append(&base64_encode(env, &map.to_xdr(env)));
+    bytes_to_string(env, &encoded)
+}
+
+/// Decodes an `XDR:`-prefixed schema definition string into its typed form.
+///
+/// The XDR is parsed directly rather than through the host, so definitions written
+/// by other encoders with unsorted map keys are accepted and malformed input fails
+/// with an error instead of aborting. A missing `description` decodes as an empty
+/// string and a missing field `optional` flag as `false`.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `definition` - The schema definition string
+///
+/// # Returns
+/// * `Result<SchemaDefinition, Error>` - The typed definition
+///
+/// # Errors
+/// * `Error::InvalidSchemaDefinition` - If the prefix, base64, XDR or map layout is invalid
+pub fn decode_schema(env: &Env, definition: &String) -> Result<SchemaDefinition, Error> {
+    if !is_xdr_definition(env, definition) {
+        return Err(Error::InvalidSchemaDefinition);
+    }
+    let raw = string_to_bytes(env, definition);
+    let xdr = base64_decode(env, &raw.slice(XDR_PREFIX.len() as u32..)).ok_or(Error::InvalidSchemaDefinition)?;
+
+    let mut reader = XdrReader { xdr: &xdr, pos: 0 };
+    let definition = reader.read_definition(env).ok_or(Error::InvalidSchemaDefinition)?;
+    if reader.pos != xdr.len() {
+        return Err(Error::InvalidSchemaDefinition); // Trailing bytes
+    }
+    Ok(definition)
+}
+
+// ScVal type discriminants used by schema definitions
+const SCV_BOOL: u32 = 0;
+const SCV_STRING: u32 = 14;
+const SCV_SYMBOL: u32 = 15;
+const SCV_VEC: u32 = 16;
+const SCV_MAP: u32 = 17;
+
+/// Maximum nesting depth of values skipped under unknown keys
+const MAX_SKIP_DEPTH: u32 = 8;
+
+/// Minimal cursor over `ScVal` XDR, covering the value types found in schema definitions.
+struct XdrReader<'a> {
+    xdr: &'a Bytes,
+    pos: u32,
+}
+
+impl XdrReader<'_> {
+    fn read_u32(&mut self) -> Option<u32> {
+        if self.pos.checked_add(4)? > self.xdr.len() {
+            return None;
+        }
+        let mut word = [0u8; 4];
+        self.xdr.slice(self.pos..self.pos + 4).copy_into_slice(&mut word);
+        self.pos += 4;
+        Some(u32::from_be_bytes(word))
+    }
+
+    fn skip(&mut self, len: u32) -> Option<()> {
+        let end = self.pos.checked_add(len)?;
+        if end > self.xdr.len() {
+            return None;
+        }
+        self.pos = end;
+        Some(())
+    }
+
+    /// Reads variable-length opaque data (string, symbol or bytes body), consuming padding.
+    fn read_opaque(&mut self) -> Option<Bytes> {
+        let len = self.read_u32()?;
+        let start = self.pos;
+        self.skip(len.checked_add(3)? & !3)?;
+        Some(self.xdr.slice(start..start + len))
+    }
+
+    fn expect_tag(&mut self, tag: u32) -> Option<()> {
+        (self.read_u32()? == tag).then_some(())
+    }
+
+    /// Reads the entry count of a present (non-`None`) vec or map.
+    fn read_len(&mut self, tag: u32) -> Option<u32> {
+        self.expect_tag(tag)?;
+        self.expect_tag(1)?; // Option discriminant: Some
+        self.read_u32()
+    }
+
+    fn read_string(&mut self, env: &Env) -> Option<String> {
+        self.expect_tag(SCV_STRING)?;
+        Some(bytes_to_string(env, &self.read_opaque()?))
+    }
+
+    fn read_bool(&mut self) -> Option<bool> {
+        self.expect_tag(SCV_BOOL)?;
+        match self.read_u32()? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+
+    fn read_key(&mut self) -> Option<Bytes> {
+        self.expect_tag(SCV_SYMBOL)?;
+        self.read_opaque()
+    }
+
+    fn read_definition(&mut self, env: &Env) -> Option<SchemaDefinition> {
+        let (mut name, mut description, mut fields) = (None, None, None);
+        for _ in 0..self.read_len(SCV_MAP)? {
+            let key = self.read_key()?;
+            if key == Bytes::from_slice(env, b"name") {
+                name = Some(self.read_string(env)?);
+            } else if key == Bytes::from_slice(env, b"description") {
+                description = Some(self.read_string(env)?);
+            } else if key == Bytes::from_slice(env, b"fields") {
+                let mut parsed = Vec::new(env);
+                for _ in 0..self.read_len(SCV_VEC)? {
+                    parsed.push_back(self.read_field(env)?);
+                }
+                fields = Some(parsed);
+            } else {
+                self.skip_val(0)?;
+            }
+        }
+
+        Some(SchemaDefinition {
+            name: name?,
+            description: description.unwrap_or(String::from_str(env, "")),
+            fields: fields?,
+        })
+    }
+
+    fn read_field(&mut self, env: &Env) -> Option<SchemaField> {
+        let (mut name, mut field_type, mut optional) = (None, None, false);
+        for _ in 0..self.read_len(SCV_MAP)? {
+            let key = self.read_key()?;
+            if key == Bytes::from_slice(env, b"name") {
+                name = Some(self.read_string(env)?);
+            } else if key == Bytes::from_slice(env, b"type") {
+                field_type = Some(self.read_string(env)?);
+            } else if key == Bytes::from_slice(env, b"optional") {
+                optional = self.read_bool()?;
+            } else {
+                self.skip_val(0)?;
+            }
+        }
+
+        Some(SchemaField {
+            name: name?,
+            field_type: field_type?,
+            optional,
+        })
+    }
+
+    /// Skips one value of any type that can appear under an unknown key.
+    fn skip_val(&mut self, depth: u32) -> Option<()> {
+        if depth > MAX_SKIP_DEPTH {
+            return None;
+        }
+        let tag = self.read_u32()?;
+        match tag {
+            1 => Some(()),                             // Void
+            0 | 3 | 4 => self.skip(4),                 // Bool, U32, I32
+            5..=8 => self.skip(8),                     // U64, I64, Timepoint, Duration
+            9 | 10 => self.skip(16),                   // U128, I128
+            11 | 12 => self.skip(32),                  // U256, I256
+            13..=15 => self.read_opaque().map(|_| ()), // Bytes, String, Symbol
+            SCV_VEC | SCV_MAP => {
+                match self.read_u32()? {
+                    0 => return Some(()), // None
+                    1 => {},
+                    _ => return None,
+                }
+                for _ in 0..self.read_u32()? {
+                    if tag == SCV_MAP {
+                        self.skip_val(depth + 1)?; // Key
+                    }
+                    self.skip_val(depth + 1)?;
+                }
+                Some(())
+            },
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn base64_encode(env: &Env, input: &Bytes) -> Bytes {
+    let mut output = Bytes::new(env);
+    let len = input.len();
+    let mut i = 0;
+    while i < len {
+        let b0 = input.get_unchecked(i) as u32;
+        let b1 = if i + 1 < len {
+            input.get_unchecked(i + 1) as u32
+        } else {
+            0
+        };
+        let b2 = if i + 2 < len {
+            input.get_unchecked(i + 2) as u32
+        } else {
+            0
+        };
+        let triple = (b0 << 16) | (b1 << 8) | b2;
+
+        output.push_back(BASE64_ALPHABET[(triple >> 18) as usize & 0x3f]);
+        output.push_back(BASE64_ALPHABET[(triple >> 12) as usize & 0x3f]);
+        output.push_back(if i + 1 < len {
+            BASE64_ALPHABET[(triple >> 6) as usize & 0x3f]
+        } else {
+            b'='
+        });
+        output.push_back(if i + 2 < len {
+            BASE64_ALPHABET[triple as usize & 0x3f]
+        } else {
+            b'='
+        });
+        i += 3;
+    }
+    output
+}
+
+pub(crate) fn base64_decode(env: &Env, input: &Bytes) -> Option<Bytes> {
+    let len = input.len();
+    if len == 0 || !len.is_multiple_of(4) {
+        return None;
+    }
+
+    let mut output = Bytes::new(env);
+    let mut i = 0;
+    while i < len {
+        let last_chunk = i + 4 == len;
+        let mut triple = 0u32;
+        let mut padding = 0u32;
+        for j in 0..4 {
+            let c = input.get_unchecked(i + j);
+            let sextet = match c {
+                b'A'..=b'Z' => c - b'A',
+                b'a'..=b'z' => c - b'a' + 26,
+                b'0'..=b'9' => c - b'0' + 52,
+                b'+' => 62,
+                b'/' => 63,
+                // Padding may only appear in the last two positions of the final chunk
+                b'=' if last_chunk && j >= 2 => {
+                    padding += 1;
+                    0
+                },
+                _ => return None,
+            };
+            if padding > 0 && c != b'=' {
+                return None;
+            }
+            triple = (triple << 6) | sextet as u32;
+        }
+
+        output.push_back((triple >> 16) as u8);
+        if padding < 2 {
+            output.push_back((triple >> 8) as u8);
+        }
+        if padding < 1 {
+            output.push_back(triple as u8);
+        }
+        i += 4;
+    }
+    Some(output)
+}
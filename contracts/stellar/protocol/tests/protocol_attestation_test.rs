@@ -1,25 +1,35 @@
 use protocol::{
     errors::Error,
-    state::{Attestation, DataKey},
-    utils::{create_xdr_string, generate_attestation_uid},
+    instructions::commit_reveal::REVEAL_WINDOW_LEDGERS,
+    state::{Attestation, AttestationReveal, DataKey},
+    state::{SchemaDefinition, SchemaField, Subject, VotingWeightRule},
+    utils::generate_attestation_uid,
+    xdr_codec::encode_schema,
     AttestationContract, AttestationContractClient,
 };
 use soroban_sdk::{
     panic_with_error, symbol_short,
     testutils::{Address as _, Events, Ledger, LedgerInfo, MockAuth, MockAuthInvoke},
-    Address, BytesN, Env, IntoVal, String as SorobanString, TryIntoVal,
+    vec, Address, Bytes, BytesN, Env, IntoVal, String as SorobanString, Symbol, TryIntoVal,
 };
 
+fn simple_schema_definition(env: &Env) -> SchemaDefinition {
+    SchemaDefinition {
+        name: SorobanString::from_str(env, "Simple"),
+        description: SorobanString::from_str(env, "Simple"),
+        fields: soroban_sdk::vec![
+            env,
+            SchemaField {
+                name: SorobanString::from_str(env, "value"),
+                field_type: SorobanString::from_str(env, "string"),
+                optional: false,
+            },
+        ],
+    }
+}
+
 fn return_schema_definition(env: &Env) -> String {
-    let schema = create_xdr_string(
-        &env,
-        &SorobanString::from_str(
-            &env,
-            r#"{"name":"Simple","version":"1.0","description":"Simple","fields":[]}"#,
-        ),
-    )
-    .to_string();
-    format!("XDR:{}", schema)
+    encode_schema(env, &simple_schema_definition(env)).to_string()
 }
 /// **Test: Generate Compatible Attestation UID**
 ///
@@ -143,14 +153,7 @@ fn create_and_get_attestation() {
     // register schema
     let schema_definition = SorobanString::from_str(
         &env,
-        &create_xdr_string(
-            &env,
-            &SorobanString::from_str(
-                &env,
-                r#"{"name":"Simple","version":"1.0","description":"Simple","fields":[]}"#,
-            ),
-        )
-        .to_string(),
+        &return_schema_definition(&env),
     );
     let resolver: Option<Address> = None;
     let revocable = true;
@@ -811,3 +814,1164 @@ fn test_handling_expired_attestations() {
     dbg!(&result);
     // assert_eq!(new_result, Err(Ok(protocol::errors::Error::AttestationNotFound.into())));
 }
+
+/// **Test: Confidential Attestation with an Encryption Envelope**
+///
+/// Verifies that `attest_encrypted` stores the scheme identifier and wrapped key
+/// alongside the ciphertext, while plain attestations leave both fields unset.
+///
+/// **Key Assertions:**
+/// - Envelope fields round-trip through storage
+/// - Unknown scheme identifiers are rejected with `InvalidEncryptionEnvelope`
+/// - Empty and oversized wrapped keys are rejected with `InvalidEncryptionEnvelope`
+#[test]
+fn attest_with_encryption_envelope() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&attester, &SorobanString::from_str(&env, "confidential"), &None, &true);
+
+    let ciphertext = SorobanString::from_str(&env, "b64:3q2+7w==");
+    let scheme = Symbol::new(&env, "X25519_XSALSA20_POLY1305");
+    let wrapped_key = Bytes::from_array(&env, &[0xab; 72]);
+
+    let uid = client.attest_encrypted(&attester, &schema_uid, &ciphertext, &None, &scheme, &wrapped_key);
+    let attestation = client.get_attestation(&uid);
+    assert_eq!(attestation.value, ciphertext);
+    assert_eq!(attestation.encryption_scheme, Some(scheme.clone()));
+    assert_eq!(attestation.encrypted_key, Some(wrapped_key.clone()));
+
+    let plain_uid = client.attest(&attester, &schema_uid, &SorobanString::from_str(&env, "{}"), &None);
+    let plain = client.get_attestation(&plain_uid);
+    assert_eq!(plain.encryption_scheme, None);
+    assert_eq!(plain.encrypted_key, None);
+
+    let unknown_scheme = Symbol::new(&env, "ROT13");
+    let result = client.try_attest_encrypted(
+        &attester,
+        &schema_uid,
+        &ciphertext,
+        &None,
+        &unknown_scheme,
+        &wrapped_key,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidEncryptionEnvelope)));
+
+    let result = client.try_attest_encrypted(&attester, &schema_uid, &ciphertext, &None, &scheme, &Bytes::new(&env));
+    assert_eq!(result, Err(Ok(Error::InvalidEncryptionEnvelope)));
+
+    let oversized_key = Bytes::from_array(&env, &[0u8; 513]);
+    let result = client.try_attest_encrypted(&attester, &schema_uid, &ciphertext, &None, &scheme, &oversized_key);
+    assert_eq!(result, Err(Ok(Error::InvalidEncryptionEnvelope)));
+}
+
+/// **Test: Schema-Level Deduplication of Identical Attestations**
+///
+/// Verifies that when a schema authority enables a deduplication window, an identical
+/// (attester, subject, value) attestation is rejected until the window has passed.
+///
+/// **Key Assertions:**
+/// - Without a window, identical attestations are accepted
+/// - Within the window, an identical attestation fails with `AttestationExists`
+/// - A different value is not considered a duplicate
+/// - Once the window passes, the same attestation is accepted again
+/// - Only the schema authority can configure the window
+/// - Windows longer than the maximum storage TTL are rejected with `InvalidDeadline`
+#[test]
+fn dedup_window_rejects_identical_attestations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let authority = Address::generate(&env);
+    let attester = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&authority, &SorobanString::from_str(&env, "dedup"), &None, &true);
+    let value = SorobanString::from_str(&env, "{\"score\":1}");
+
+    // Disabled by default
+    assert_eq!(client.get_schema_dedup_window(&schema_uid), 0);
+    client.attest(&attester, &schema_uid, &value, &None);
+    client.attest(&attester, &schema_uid, &value, &None);
+
+    let result = client.try_set_schema_dedup_window(&attester, &schema_uid, &10);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    client.set_schema_dedup_window(&authority, &schema_uid, &10);
+    assert_eq!(client.get_schema_dedup_window(&schema_uid), 10);
+
+    client.attest(&attester, &schema_uid, &value, &None);
+    let result = client.try_attest(&attester, &schema_uid, &value, &None);
+    assert_eq!(result, Err(Ok(Error::AttestationExists)));
+
+    let other_value = SorobanString::from_str(&env, "{\"score\":2}");
+    client.attest(&attester, &schema_uid, &other_value, &None);
+
+    env.ledger().with_mut(|li| li.sequence_number += 11);
+    client.attest(&attester, &schema_uid, &value, &None);
+
+    let result = client.try_set_schema_dedup_window(&authority, &schema_uid, &u32::MAX);
+    assert_eq!(result, Err(Ok(Error::InvalidDeadline)));
+
+    let max_ttl = env.as_contract(&contract_id, || env.storage().max_ttl());
+    client.set_schema_dedup_window(&authority, &schema_uid, &max_ttl);
+    client.attest(&attester, &schema_uid, &other_value, &None);
+    let result = client.try_attest(&attester, &schema_uid, &other_value, &None);
+    assert_eq!(result, Err(Ok(Error::AttestationExists)));
+}
+
+/// **Test: Attestations Issued on Behalf of an Organization**
+///
+/// Verifies that registered organization members can attest on behalf of the
+/// organization, and that such attestations are attributed to and listed under it.
+///
+/// **Key Assertions:**
+/// - Non-members are rejected with `NotAuthorized`
+/// - `on_behalf_of` records the organization
+/// - `get_org_attestations` lists only the organization's attestations, in order
+/// - Removed members can no longer attest for the organization
+#[test]
+fn attest_on_behalf_of_organization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let org = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&org, &SorobanString::from_str(&env, "employment"), &None, &true);
+    let value = SorobanString::from_str(&env, "{\"role\":\"engineer\"}");
+
+    let result = client.try_attest_on_behalf_of(&alice, &org, &schema_uid, &value, &None);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    client.add_org_member(&org, &alice);
+    client.add_org_member(&org, &bob);
+    assert!(client.is_org_member(&org, &alice));
+
+    let first = client.attest_on_behalf_of(&alice, &org, &schema_uid, &value, &None);
+    let second = client.attest_on_behalf_of(&bob, &org, &schema_uid, &value, &None);
+    let personal = client.attest(&alice, &schema_uid, &value, &None);
+
+    let attestation = client.get_attestation(&first);
+    assert_eq!(attestation.attester, alice);
+    assert_eq!(attestation.on_behalf_of, Some(org.clone()));
+    assert_eq!(client.get_attestation(&personal).on_behalf_of, None);
+
+    let org_attestations = client.get_org_attestations(&org, &0, &10);
+    assert_eq!(org_attestations.len(), 2);
+    assert_eq!(org_attestations.get(0).unwrap(), first);
+    assert_eq!(org_attestations.get(1).unwrap(), second);
+    assert_eq!(client.get_org_attestations(&org, &1, &1).get(0).unwrap(), second);
+
+    client.remove_org_member(&org, &bob);
+    assert!(!client.is_org_member(&org, &bob));
+    let result = client.try_attest_on_behalf_of(&bob, &org, &schema_uid, &value, &None);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+}
+
+/// **Test: Attestation Lookup by External Reference ID**
+///
+/// Verifies that an attester can attach its own record ID to an attestation and
+/// later resolve the on-chain UID from it.
+///
+/// **Key Assertions:**
+/// - `get_attestation_by_external_id` returns the attestation the ID was attached to
+/// - External IDs are scoped per attester
+/// - Reusing an external ID is rejected with `AttestationExists`
+/// - Empty external IDs are rejected with `InvalidExternalId`
+#[test]
+fn attestation_lookup_by_external_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&attester, &SorobanString::from_str(&env, "kyc"), &None, &true);
+    let value = SorobanString::from_str(&env, "{\"level\":2}");
+    let external_id = Bytes::from_slice(&env, b"CRM-000123");
+
+    let uid = client.attest_with_external_id(&attester, &schema_uid, &value, &None, &external_id);
+    let attestation = client.get_attestation_by_external_id(&attester, &external_id);
+    assert_eq!(attestation.uid, uid);
+    assert_eq!(attestation.external_id, Some(external_id.clone()));
+
+    let result = client.try_get_attestation_by_external_id(&other, &external_id);
+    assert_eq!(result, Err(Ok(Error::AttestationNotFound)));
+
+    let value = SorobanString::from_str(&env, "{\"level\":3}");
+    let result = client.try_attest_with_external_id(&attester, &schema_uid, &value, &None, &external_id);
+    assert_eq!(result, Err(Ok(Error::AttestationExists)));
+
+    let result = client.try_attest_with_external_id(&attester, &schema_uid, &value, &None, &Bytes::new(&env));
+    assert_eq!(result, Err(Ok(Error::InvalidExternalId)));
+}
+
+/// **Test: Selective Disclosure Field Commitments**
+///
+/// Verifies that a subject can prove a single committed field without the rest of
+/// the credential appearing on-chain.
+///
+/// **Key Assertions:**
+/// - A field verifies with its value and salt, but not with another value or salt
+/// - Out-of-range field indexes and attestations without commitments are rejected
+/// - Fields of revoked attestations no longer verify
+#[test]
+fn selective_disclosure_verifies_single_fields() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&attester, &SorobanString::from_str(&env, "id-card"), &None, &true);
+
+    let commit = |value: &Bytes, salt: &BytesN<32>| -> BytesN<32> {
+        let mut preimage = value.clone();
+        preimage.append(&salt.clone().into());
+        env.crypto().sha256(&preimage).into()
+    };
+    let name = Bytes::from_slice(&env, b"Ada Lovelace");
+    let over_18 = Bytes::from_slice(&env, b"true");
+    let name_salt = BytesN::from_array(&env, &[1u8; 32]);
+    let over_18_salt = BytesN::from_array(&env, &[2u8; 32]);
+    let commitments = vec![&env, commit(&name, &name_salt), commit(&over_18, &over_18_salt)];
+
+    let uid = client.attest_with_commitments(&attester, &schema_uid, &commitments, &None);
+    assert_eq!(client.get_attestation(&uid).value.len(), 64);
+
+    assert!(client.verify_field(&uid, &1, &over_18, &over_18_salt));
+    assert!(!client.verify_field(&uid, &1, &Bytes::from_slice(&env, b"false"), &over_18_salt));
+    assert!(!client.verify_field(&uid, &1, &over_18, &name_salt));
+
+    let result = client.try_verify_field(&uid, &2, &over_18, &over_18_salt);
+    assert_eq!(result, Err(Ok(Error::InvalidFieldCommitments)));
+    let plain_uid = client.attest(&attester, &schema_uid, &SorobanString::from_str(&env, "{}"), &None);
+    let result = client.try_verify_field(&plain_uid, &0, &name, &name_salt);
+    assert_eq!(result, Err(Ok(Error::InvalidFieldCommitments)));
+    let result = client.try_attest_with_commitments(&attester, &schema_uid, &vec![&env], &None);
+    assert_eq!(result, Err(Ok(Error::InvalidFieldCommitments)));
+
+    client.revoke(&attester, &uid);
+    assert!(!client.verify_field(&uid, &1, &over_18, &over_18_salt));
+}
+
+/// **Test: Attestations to Muxed Sub-Accounts**
+///
+/// Verifies that an issuer can attest about an exchange's muxed sub-accounts and that
+/// queries resolve both the plain account and the muxed form.
+///
+/// **Key Assertions:**
+/// - The attestation is stored under the underlying account and records the mux ID
+/// - Muxed queries only match the sub-account the attestation was issued to
+/// - Plain queries and listings on the account match attestations issued to any of its sub-accounts
+/// - Consent-required schemas reject muxed attestations about other subjects
+#[test]
+fn muxed_subject_attestations_resolve_either_form() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let exchange = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&issuer, &SorobanString::from_str(&env, "kyc"), &None, &true);
+    let value = SorobanString::from_str(&env, "{\"level\":2}");
+    let muxed = |mux_id| Subject {
+        address: exchange.clone(),
+        mux_id: Some(mux_id),
+    };
+    let plain = Subject {
+        address: exchange.clone(),
+        mux_id: None,
+    };
+
+    assert!(!client.has_valid_subject_attestation(&plain, &schema_uid));
+    let uid = client.attest_muxed(&issuer, &schema_uid, &exchange, &42, &value, &None);
+
+    let attestation = client.get_attestation(&uid);
+    assert_eq!(
+        (attestation.subject, attestation.attester),
+        (exchange.clone(), issuer.clone())
+    );
+    assert_eq!(client.get_attestation_subject(&uid), muxed(42));
+    assert!(client.has_valid_subject_attestation(&muxed(42), &schema_uid));
+    assert!(!client.has_valid_subject_attestation(&muxed(7), &schema_uid));
+    assert!(client.has_valid_subject_attestation(&plain, &schema_uid));
+    assert!(client.has_valid_attestation(&exchange, &schema_uid));
+    assert!(!client.has_valid_attestation(&issuer, &schema_uid));
+    assert_eq!(
+        client.list_subject_attestations(&exchange, &Bytes::new(&env), &10).uids,
+        vec![&env, uid.clone()]
+    );
+
+    let plain_uid = client.attest(&issuer, &schema_uid, &value, &None);
+    let issuer_subject = Subject {
+        address: issuer.clone(),
+        mux_id: None,
+    };
+    assert_eq!(client.get_attestation_subject(&plain_uid), issuer_subject);
+
+    client.revoke(&issuer, &uid);
+    assert!(!client.has_valid_subject_attestation(&muxed(42), &schema_uid));
+    assert!(!client.has_valid_subject_attestation(&plain, &schema_uid));
+
+    client.set_schema_requires_consent(&issuer, &schema_uid, &true);
+    assert_eq!(
+        client.try_attest_muxed(&issuer, &schema_uid, &exchange, &42, &value, &None),
+        Err(Ok(Error::NotAuthorized))
+    );
+}
+
+/// **Test: Cursor Pagination of Attestation Listings**
+///
+/// Verifies that attestation listings by subject, schema and attester page with opaque
+/// cursors that stay stable while new attestations are appended.
+///
+/// **Key Assertions:**
+/// - Pages are returned oldest first and `has_more` reflects the remaining entries
+/// - Attestations appended mid-pagination appear after the existing entries, never shifting pages
+/// - The final cursor resumes with only newer attestations
+/// - Malformed and out-of-range cursors are rejected with `InvalidCursor`
+#[test]
+fn attestation_listings_page_with_stable_cursors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&attester, &SorobanString::from_str(&env, "listing"), &None, &true);
+    let other_schema = client.register(&attester, &SorobanString::from_str(&env, "other"), &None, &true);
+    let value = SorobanString::from_str(&env, "{\"k\":\"v\"}");
+
+    let mut uids = std::vec::Vec::new();
+    for _ in 0..3 {
+        uids.push(client.attest(&attester, &schema_uid, &value, &None));
+    }
+    let other_uid = client.attest(&attester, &other_schema, &value, &None);
+
+    let first = client.list_schema_attestations(&schema_uid, &Bytes::new(&env), &2);
+    assert_eq!(first.uids, vec![&env, uids[0].clone(), uids[1].clone()]);
+    assert!(first.has_more);
+
+    // Appended mid-pagination: lands after the existing entries
+    uids.push(client.attest(&attester, &schema_uid, &value, &None));
+    let second = client.list_schema_attestations(&schema_uid, &first.next_cursor, &2);
+    assert_eq!(second.uids, vec![&env, uids[2].clone(), uids[3].clone()]);
+    assert!(!second.has_more);
+
+    let newer = client.attest(&attester, &schema_uid, &value, &None);
+    let resumed = client.list_schema_attestations(&schema_uid, &second.next_cursor, &10);
+    assert_eq!(resumed.uids, vec![&env, newer.clone()]);
+
+    let by_attester = client.list_attester_attestations(&attester, &Bytes::new(&env), &10);
+    assert_eq!(by_attester.uids.len(), 6);
+    assert_eq!(by_attester.uids.get(3).unwrap(), other_uid);
+    let by_subject = client.list_subject_attestations(&attester, &Bytes::new(&env), &10);
+    assert_eq!(by_subject.uids, by_attester.uids);
+
+    let result = client.try_list_schema_attestations(&schema_uid, &Bytes::from_slice(&env, &[1, 2]), &2);
+    assert_eq!(result, Err(Ok(Error::InvalidCursor)));
+    let result = client.try_list_schema_attestations(&schema_uid, &Bytes::from_array(&env, &99u32.to_be_bytes()), &2);
+    assert_eq!(result, Err(Ok(Error::InvalidCursor)));
+}
+
+/// **Test: Per-Schema Event Topic**
+///
+/// Verifies that a schema authority can register a custom topic that is emitted
+/// alongside the standard attestation topics.
+///
+/// **Key Assertions:**
+/// - Only the schema authority can set the topic
+/// - Attestation and revocation events carry the custom topic after the standard ones
+/// - Clearing the topic restores the standard topics
+#[test]
+fn schema_event_topic_is_appended_to_attestation_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let authority = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&authority, &SorobanString::from_str(&env, "loyalty"), &None, &true);
+    let topic = Symbol::new(&env, "acme_loyalty");
+
+    let result = client.try_set_schema_event_topic(&stranger, &schema_uid, &Some(topic.clone()));
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    client.set_schema_event_topic(&authority, &schema_uid, &Some(topic.clone()));
+    assert_eq!(client.get_schema_event_topic(&schema_uid), Some(topic.clone()));
+
+    let value = SorobanString::from_str(&env, "{\"tier\":\"gold\"}");
+    let uid = client.attest(&authority, &schema_uid, &value, &None);
+    let expected_topics = (symbol_short!("ATTEST"), symbol_short!("CREATE"), topic.clone()).into_val(&env);
+    assert_eq!(env.events().all().last().unwrap().1, expected_topics);
+
+    client.revoke(&authority, &uid);
+    let expected_topics = (symbol_short!("ATTEST"), symbol_short!("REVOKE"), topic).into_val(&env);
+    assert_eq!(env.events().all().last().unwrap().1, expected_topics);
+
+    client.set_schema_event_topic(&authority, &schema_uid, &None);
+    assert_eq!(client.get_schema_event_topic(&schema_uid), None);
+    client.attest(&authority, &schema_uid, &value, &None);
+    let expected_topics = (symbol_short!("ATTEST"), symbol_short!("CREATE")).into_val(&env);
+    assert_eq!(env.events().all().last().unwrap().1, expected_topics);
+}
+
+/// **Test: Attestation Validity Proof**
+///
+/// Verifies that `get_validity_proof` returns the exact ledger entry key and value
+/// a light client would find in the contract's persistent storage.
+///
+/// **Key Assertions:**
+/// - `key_xdr` is the XDR of the attestation's storage key
+/// - `value_xdr` decodes to the stored attestation and hashes to `value_hash`
+/// - Unknown UIDs are rejected with `AttestationNotFound`
+#[test]
+fn validity_proof_matches_stored_ledger_entry() {
+    use soroban_sdk::xdr::{FromXdr, ToXdr};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&attester, &SorobanString::from_str(&env, "bridge"), &None, &true);
+    let uid = client.attest(&attester, &schema_uid, &SorobanString::from_str(&env, "{\"ok\":true}"), &None);
+
+    let proof = client.get_validity_proof(&uid);
+    assert_eq!(proof.contract, contract_id);
+    assert_eq!(proof.attestation.uid, uid);
+    assert_eq!(proof.ledger_sequence, env.ledger().sequence());
+
+    let stored: Attestation = env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AttestationUID(uid.clone()))
+            .unwrap()
+    });
+    assert_eq!(proof.key_xdr, DataKey::AttestationUID(uid.clone()).to_xdr(&env));
+    assert_eq!(proof.value_xdr, stored.clone().to_xdr(&env));
+    assert_eq!(Attestation::from_xdr(&env, &proof.value_xdr).unwrap(), stored);
+    let value_hash: BytesN<32> = env.crypto().sha256(&proof.value_xdr).into();
+    assert_eq!(proof.value_hash, value_hash);
+
+    let result = client.try_get_validity_proof(&BytesN::from_array(&env, &[0u8; 32]));
+    assert_eq!(result, Err(Ok(Error::AttestationNotFound)));
+}
+
+/// **Test: Diff Structured Fields Between Two Attestations**
+/// - Register an XDR schema with three fields
+/// - Attest an original and an amended value
+/// - Verify only the changed and removed fields are reported
+/// - Verify attestations under different schemas cannot be compared
+#[test]
+fn diff_attestations_reports_changed_fields() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let attester = Address::generate(&env);
+    client.initialize(&Address::generate(&env));
+
+    let field = |name: &str, field_type: &str| SchemaField {
+        name: SorobanString::from_str(&env, name),
+        field_type: SorobanString::from_str(&env, field_type),
+        optional: true,
+    };
+    let definition = SchemaDefinition {
+        name: SorobanString::from_str(&env, "Licence"),
+        description: SorobanString::from_str(&env, "Professional licence"),
+        fields: soroban_sdk::vec![&env, field("holder", "string"), field("grade", "u32"), field("scope", "string")],
+    };
+    let schema_uid = client.register(&attester, &client.encode_schema(&definition), &None, &true);
+
+    let original = client.attest(
+        &attester,
+        &schema_uid,
+        &SorobanString::from_str(&env, r#"{"holder":"Ada, \"A\"","grade":2,"scope":{"regions":["EU","US"]}}"#),
+        &None,
+    );
+    let amended = client.attest(
+        &attester,
+        &schema_uid,
+        &SorobanString::from_str(&env, r#"{ "grade": 3, "holder": "Ada, \"A\"" }"#),
+        &None,
+    );
+
+    let differing = client.diff_attestations(&original, &amended);
+    assert_eq!(
+        differing,
+        soroban_sdk::vec![&env, SorobanString::from_str(&env, "grade"), SorobanString::from_str(&env, "scope")]
+    );
+    assert_eq!(client.diff_attestations(&original, &original).len(), 0);
+
+    let other_schema = client.register(&attester, &SorobanString::from_str(&env, "other"), &None, &true);
+    let other = client.attest(&attester, &other_schema, &SorobanString::from_str(&env, "{}"), &None);
+    assert_eq!(
+        client.try_diff_attestations(&original, &other),
+        Err(Ok(Error::SchemaMismatch))
+    );
+    let other_2 = client.attest(&attester, &other_schema, &SorobanString::from_str(&env, "{}"), &None);
+    assert_eq!(
+        client.try_diff_attestations(&other, &other_2),
+        Err(Ok(Error::InvalidSchemaDefinition))
+    );
+}
+
+#[test]
+fn claimable_attestation_binds_to_claimer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let attester = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&Address::generate(&env));
+
+    let schema_uid = client.register(&attester, &SorobanString::from_str(&env, "badge"), &None, &true);
+    let secret = Bytes::from_slice(&env, b"claim-link-secret");
+    let claim_hash: BytesN<32> = env.crypto().sha256(&secret).into();
+    let value = SorobanString::from_str(&env, "attended");
+    let expiry = env.ledger().timestamp() + 3600;
+
+    let uid = client.attest_claimable(&attester, &schema_uid, &value, &claim_hash, &expiry);
+    assert_eq!(client.get_claimable_attestation(&uid).claim_hash, claim_hash);
+    assert_eq!(client.try_get_attestation(&uid), Err(Ok(Error::AttestationNotFound)));
+
+    let wrong = Bytes::from_slice(&env, b"guess");
+    assert_eq!(
+        client.try_claim(&uid, &wrong, &recipient),
+        Err(Ok(Error::InvalidClaimPreimage))
+    );
+
+    client.claim(&uid, &secret, &recipient);
+    let attestation = client.get_attestation(&uid);
+    assert_eq!(attestation.subject, recipient);
+    assert_eq!(attestation.attester, attester);
+    assert_eq!(attestation.value, value);
+    assert!(client.has_valid_attestation(&recipient, &schema_uid));
+
+    // The claim is single use
+    assert_eq!(
+        client.try_claim(&uid, &secret, &Address::generate(&env)),
+        Err(Ok(Error::AttestationNotFound))
+    );
+    assert_eq!(
+        client.try_get_claimable_attestation(&uid),
+        Err(Ok(Error::AttestationNotFound))
+    );
+
+    let late = client.attest_claimable(&attester, &schema_uid, &value, &claim_hash, &expiry);
+    env.ledger().with_mut(|li| li.timestamp = expiry + 1);
+    assert_eq!(
+        client.try_claim(&late, &secret, &recipient),
+        Err(Ok(Error::AttestationExpired))
+    );
+}
+
+#[test]
+fn import_legacy_preserves_attestation_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let value = SorobanString::from_str(&env, "legacy");
+
+    // Export from the old deployment
+    let legacy_id = env.register(AttestationContract {}, ());
+    let legacy = AttestationContractClient::new(&env, &legacy_id);
+    legacy.initialize(&admin);
+    let definition = return_schema_definition(&env);
+    let definition = SorobanString::from_str(&env, &definition);
+    let legacy_schema = legacy.register(&attester, &definition, &None, &true);
+    let first = legacy.attest(&attester, &legacy_schema, &value, &None);
+    let second = legacy.attest(&attester, &legacy_schema, &value, &None);
+    legacy.revoke(&attester, &second);
+    let exported = soroban_sdk::vec![&env, legacy.get_attestation(&first), legacy.get_attestation(&second)];
+
+    // Import into the new deployment
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+    let schema_uid = client.register(&attester, &definition, &None, &true);
+    assert_eq!(schema_uid, legacy_schema);
+
+    let imported = client.import_legacy(&admin, &soroban_sdk::vec![&env], &exported);
+    assert_eq!(imported, 2);
+    assert_eq!(client.get_attestation(&first), exported.get(0).unwrap());
+    assert!(client.get_attestation(&second).revoked);
+    assert_eq!(client.get_schema_stats(&schema_uid).issued, 2);
+    assert_eq!(client.get_schema_stats(&schema_uid).revoked, 1);
+
+    // New attestations continue after the imported nonces
+    let next = client.attest(&attester, &schema_uid, &value, &None);
+    assert_eq!(client.get_attestation(&next).nonce, 2);
+
+    assert_eq!(
+        client.try_import_legacy(&admin, &soroban_sdk::vec![&env], &exported),
+        Err(Ok(Error::AttestationExists))
+    );
+}
+
+#[test]
+fn voting_weight_follows_membership_tier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let member = Address::generate(&env);
+    client.initialize(&Address::generate(&env));
+
+    let schema_uid = client.register(&member, &SorobanString::from_str(&env, "membership"), &None, &true);
+    assert_eq!(client.get_voting_weight(&member, &schema_uid), 0);
+
+    let uid = client.attest(
+        &member,
+        &schema_uid,
+        &SorobanString::from_str(&env, r#"{"tier":"gold","seats":4}"#),
+        &None,
+    );
+    assert_eq!(client.get_voting_weight(&member, &schema_uid), 1);
+
+    let mut tiers = soroban_sdk::Map::new(&env);
+    tiers.set(SorobanString::from_str(&env, "silver"), 2u64);
+    tiers.set(SorobanString::from_str(&env, "gold"), 5u64);
+    let tier_rule = VotingWeightRule {
+        field: SorobanString::from_str(&env, "tier"),
+        tiers,
+    };
+    client.set_voting_weight_rule(&member, &schema_uid, &Some(tier_rule));
+    assert_eq!(client.get_voting_weight(&member, &schema_uid), 5);
+
+    let seat_rule = VotingWeightRule {
+        field: SorobanString::from_str(&env, "seats"),
+        tiers: soroban_sdk::Map::new(&env),
+    };
+    client.set_voting_weight_rule(&member, &schema_uid, &Some(seat_rule));
+    assert_eq!(client.get_voting_weight(&member, &schema_uid), 4);
+
+    assert_eq!(
+        client.try_set_voting_weight_rule(&Address::generate(&env), &schema_uid, &None),
+        Err(Ok(Error::NotAuthorized))
+    );
+
+    client.revoke(&member, &uid);
+    assert_eq!(client.get_voting_weight(&member, &schema_uid), 0);
+}
+
+#[test]
+fn soulbound_schema_attestations_emit_lock_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let attester = Address::generate(&env);
+    client.initialize(&Address::generate(&env));
+
+    let value = SorobanString::from_str(&env, "{}");
+    let schema_uid = client.register(&attester, &SorobanString::from_str(&env, "diploma"), &None, &true);
+    let unlocked = client.attest(&attester, &schema_uid, &value, &None);
+    let last = env.events().all().last().unwrap();
+    assert_ne!(last.1, (symbol_short!("Locked"),).into_val(&env));
+    assert!(!client.is_locked(&unlocked));
+
+    client.set_schema_soulbound(&attester, &schema_uid, &true);
+    let locked = client.attest(&attester, &schema_uid, &value, &None);
+    let last = env.events().all().last().unwrap();
+    assert_eq!(last.1, (symbol_short!("Locked"),).into_val(&env));
+    let event_uid: BytesN<32> = last.2.try_into_val(&env).unwrap();
+    assert_eq!(event_uid, locked);
+    assert!(client.is_locked(&locked));
+
+    assert_eq!(
+        client.try_set_schema_soulbound(&Address::generate(&env), &schema_uid, &false),
+        Err(Ok(Error::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_is_locked(&BytesN::from_array(&env, &[0; 32])),
+        Err(Ok(Error::AttestationNotFound))
+    );
+}
+
+/// **Test: Attestation Freshness**
+/// - Attestations are fresh up to and including `max_age_seconds` after issuance
+/// - Revoked and unknown attestations are never fresh
+#[test]
+fn attestation_freshness_follows_issue_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let attester = Address::generate(&env);
+    client.initialize(&Address::generate(&env));
+
+    let thirty_days = 30 * 24 * 60 * 60;
+    let value = SorobanString::from_str(&env, "{\"liveness\":true}");
+    let schema_uid = client.register(&attester, &SorobanString::from_str(&env, "liveness"), &None, &true);
+    let uid = client.attest(&attester, &schema_uid, &value, &None);
+    assert!(client.is_attestation_fresh(&uid, &thirty_days));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + thirty_days);
+    assert!(client.is_attestation_fresh(&uid, &thirty_days));
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    assert!(!client.is_attestation_fresh(&uid, &thirty_days));
+
+    let renewed = client.attest(&attester, &schema_uid, &value, &None);
+    assert!(client.is_attestation_fresh(&renewed, &thirty_days));
+    client.revoke(&attester, &renewed);
+    assert!(!client.is_attestation_fresh(&renewed, &thirty_days));
+    assert!(!client.is_attestation_fresh(&BytesN::from_array(&env, &[0; 32]), &thirty_days));
+}
+
+/// **Test: Attester Home Domain Binding**
+/// - A claimed domain is only exposed once the admin confirms the hosted token's hash
+/// - Mismatched hashes and non-admin callers are rejected
+#[test]
+fn attester_domain_binding_requires_matching_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    client.initialize(&admin);
+
+    let domain = SorobanString::from_str(&env, "university.edu");
+    let token = Bytes::from_slice(&env, b"attest-domain-token-42");
+    let challenge_hash: BytesN<32> = env.crypto().sha256(&token).into();
+
+    assert_eq!(
+        client.try_request_attester_domain(&attester, &SorobanString::from_str(&env, ""), &challenge_hash),
+        Err(Ok(Error::InvalidDomain))
+    );
+    assert_eq!(
+        client.try_verify_attester_domain(&admin, &attester, &challenge_hash),
+        Err(Ok(Error::DomainChallengeMismatch))
+    );
+
+    client.request_attester_domain(&attester, &domain, &challenge_hash);
+    assert_eq!(client.get_attester_domain(&attester), None);
+
+    let wrong_hash: BytesN<32> = env.crypto().sha256(&Bytes::from_slice(&env, b"other")).into();
+    assert_eq!(
+        client.try_verify_attester_domain(&admin, &attester, &wrong_hash),
+        Err(Ok(Error::DomainChallengeMismatch))
+    );
+    assert_eq!(
+        client.try_verify_attester_domain(&attester, &attester, &challenge_hash),
+        Err(Ok(Error::NotAuthorized))
+    );
+
+    client.verify_attester_domain(&admin, &attester, &challenge_hash);
+    let binding = client.get_attester_domain(&attester).unwrap();
+    assert_eq!(binding.domain, domain);
+    assert_eq!(binding.challenge_hash, challenge_hash);
+    assert_eq!(binding.verified_by, admin);
+
+    // The pending challenge is consumed by verification
+    assert_eq!(
+        client.try_verify_attester_domain(&admin, &attester, &challenge_hash),
+        Err(Ok(Error::DomainChallengeMismatch))
+    );
+}
+
+/// **Test: Commit-Reveal Attestations**
+///
+/// Verifies that an attestation committed by hash can only be issued by revealing
+/// matching content in a later ledger, within the reveal window.
+///
+/// **Key Assertions:**
+/// - Revealing in the commitment's own ledger fails with `RevealOutsideWindow`
+/// - A reveal that does not match the commitment fails with `CommitmentNotFound`
+/// - A timely reveal issues the attestation and consumes the commitment
+/// - A reveal after the window fails once the commitment has lapsed
+#[test]
+fn commit_reveal_attestations_respect_the_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.min_persistent_entry_ttl = 2 * REVEAL_WINDOW_LEDGERS);
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let authority = Address::generate(&env);
+    let attester = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&authority, &SorobanString::from_str(&env, "audit"), &None, &true);
+    let reveal = AttestationReveal {
+        schema_uid: schema_uid.clone(),
+        value: SorobanString::from_str(&env, "{\"passed\":false}"),
+        expiration_time: None,
+        salt: BytesN::from_array(&env, &[7; 32]),
+    };
+    let commitment = client.compute_attestation_commitment(&attester, &reveal);
+
+    client.commit_attestation(&attester, &commitment);
+    let record = client.get_attestation_commitment(&commitment).unwrap();
+    assert_eq!(record.attester, attester);
+    assert_eq!(
+        client.try_reveal_attestation(&attester, &reveal),
+        Err(Ok(Error::RevealOutsideWindow))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    let mut tampered = reveal.clone();
+    tampered.value = SorobanString::from_str(&env, "{\"passed\":true}");
+    assert_eq!(
+        client.try_reveal_attestation(&attester, &tampered),
+        Err(Ok(Error::CommitmentNotFound))
+    );
+
+    let uid = client.reveal_attestation(&attester, &reveal);
+    assert_eq!(client.get_attestation(&uid).value, reveal.value);
+    assert_eq!(client.get_attestation_commitment(&commitment), None);
+    assert_eq!(
+        client.try_reveal_attestation(&attester, &reveal),
+        Err(Ok(Error::CommitmentNotFound))
+    );
+
+    let late = AttestationReveal {
+        salt: BytesN::from_array(&env, &[8; 32]),
+        ..reveal
+    };
+    client.commit_attestation(&attester, &client.compute_attestation_commitment(&attester, &late));
+    // The commitment lapses with the window
+    env.ledger().with_mut(|li| li.sequence_number += REVEAL_WINDOW_LEDGERS + 1);
+    assert_eq!(
+        client.try_reveal_attestation(&attester, &late),
+        Err(Ok(Error::CommitmentNotFound))
+    );
+}
+
+/// **Test: Expiration Horizon Caps**
+///
+/// Verifies that the protocol-wide and per-schema caps bound how far in the future
+/// an attestation may expire, with the tighter cap applying.
+///
+/// **Key Assertions:**
+/// - Without a cap, distant expirations are accepted
+/// - Expirations beyond the effective horizon fail with `ExpirationTooFar`
+/// - Expirations at the horizon, and attestations without expiration, are accepted
+/// - Only the admin and the schema authority can set their caps
+#[test]
+fn expiration_horizon_caps_attestation_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let authority = Address::generate(&env);
+    let attester = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&authority, &SorobanString::from_str(&env, "license"), &None, &true);
+    let value = SorobanString::from_str(&env, "{\"class\":\"B\"}");
+    let five_years = 5 * 365 * 24 * 60 * 60;
+
+    assert_eq!(client.get_expiration_horizon(), 0);
+    client.attest(&attester, &schema_uid, &value, &Some(1_000 + 10 * five_years));
+
+    assert_eq!(
+        client.try_set_expiration_horizon(&attester, &five_years),
+        Err(Ok(Error::NotAuthorized))
+    );
+    client.set_expiration_horizon(&admin, &five_years);
+    assert_eq!(
+        client.try_attest(&attester, &schema_uid, &value, &Some(1_000 + five_years + 1)),
+        Err(Ok(Error::ExpirationTooFar))
+    );
+    client.attest(&attester, &schema_uid, &value, &Some(1_000 + five_years));
+    client.attest(&attester, &schema_uid, &value, &None);
+
+    // The tighter schema cap wins
+    assert_eq!(
+        client.try_set_schema_expiration_horizon(&attester, &schema_uid, &3_600),
+        Err(Ok(Error::NotAuthorized))
+    );
+    client.set_schema_expiration_horizon(&authority, &schema_uid, &3_600);
+    assert_eq!(client.get_schema_expiration_horizon(&schema_uid), 3_600);
+    assert_eq!(
+        client.try_attest(&attester, &schema_uid, &value, &Some(1_000 + 3_601)),
+        Err(Ok(Error::ExpirationTooFar))
+    );
+    client.attest(&attester, &schema_uid, &value, &Some(1_000 + 3_600));
+
+    client.set_expiration_horizon(&admin, &0);
+    client.set_schema_expiration_horizon(&authority, &schema_uid, &0);
+    client.attest(&attester, &schema_uid, &value, &Some(1_000 + 10 * five_years));
+}
+
+/// **Test: Deterministic Attestation Sampling**
+///
+/// Verifies that auditors can draw a reproducible random sample of a schema's
+/// attestations without listing the full set.
+///
+/// **Key Assertions:**
+/// - The same seed yields the same sample, and a different seed a different one
+/// - Samples contain distinct UIDs of the schema, oldest first
+/// - Asking for more than the schema holds returns every attestation
+#[test]
+fn schema_attestations_sample_deterministically() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&attester, &SorobanString::from_str(&env, "sampled"), &None, &true);
+    let uids: std::vec::Vec<BytesN<32>> = (0..20)
+        .map(|index| {
+            let value = SorobanString::from_str(&env, &std::format!("{{\"n\":{index}}}"));
+            client.attest(&attester, &schema_uid, &value, &None)
+        })
+        .collect();
+
+    let seed = BytesN::from_array(&env, &[1; 32]);
+    let sample = client.sample_attestations(&schema_uid, &seed, &5);
+    assert_eq!(sample.len(), 5);
+    assert_eq!(client.sample_attestations(&schema_uid, &seed, &5), sample);
+    assert_ne!(
+        client.sample_attestations(&schema_uid, &BytesN::from_array(&env, &[2; 32]), &5),
+        sample
+    );
+
+    let positions: std::vec::Vec<usize> = sample
+        .iter()
+        .map(|uid| uids.iter().position(|candidate| *candidate == uid).unwrap())
+        .collect();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let everything = client.sample_attestations(&schema_uid, &seed, &50);
+    assert_eq!(everything, soroban_sdk::Vec::from_slice(&env, &uids));
+}
+
+/// **Test: Uniqueness Keys Across Wallets**
+///
+/// Verifies that a schema with a uniqueness field allows only one active attestation
+/// per key, whichever wallet it is issued to.
+///
+/// **Key Assertions:**
+/// - A second attestation with the same key fails with `UniquenessKeyTaken`
+/// - Values without the field fail with `UniquenessKeyMissing`
+/// - `find_by_uniqueness_key` resolves the holder from the key hash
+/// - Revoking the holder frees the key
+/// - Only the schema authority can declare the field
+#[test]
+fn uniqueness_key_blocks_duplicate_credentials() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let authority = Address::generate(&env);
+    let first_wallet = Address::generate(&env);
+    let second_wallet = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&authority, &SorobanString::from_str(&env, "kyc"), &None, &true);
+    let field = SorobanString::from_str(&env, "national_id_hash");
+    assert_eq!(
+        client.try_set_schema_uniqueness_field(&first_wallet, &schema_uid, &Some(field.clone())),
+        Err(Ok(Error::NotAuthorized))
+    );
+    client.set_schema_uniqueness_field(&authority, &schema_uid, &Some(field.clone()));
+    assert_eq!(client.get_schema_uniqueness_field(&schema_uid), Some(field));
+
+    let value = SorobanString::from_str(&env, "{\"national_id_hash\":\"9f86d0\",\"level\":2}");
+    let uid = client.attest(&first_wallet, &schema_uid, &value, &None);
+    let key_hash: BytesN<32> = env.crypto().sha256(&Bytes::from_slice(&env, b"\"9f86d0\"")).into();
+    assert_eq!(client.find_by_uniqueness_key(&schema_uid, &key_hash), Some(uid.clone()));
+
+    let same_person = SorobanString::from_str(&env, "{\"level\":3,\"national_id_hash\":\"9f86d0\"}");
+    assert_eq!(
+        client.try_attest(&second_wallet, &schema_uid, &same_person, &None),
+        Err(Ok(Error::UniquenessKeyTaken))
+    );
+    assert_eq!(
+        client.try_attest(&second_wallet, &schema_uid, &SorobanString::from_str(&env, "{\"level\":3}"), &None),
+        Err(Ok(Error::UniquenessKeyMissing))
+    );
+
+    client.revoke(&first_wallet, &uid);
+    assert_eq!(client.find_by_uniqueness_key(&schema_uid, &key_hash), None);
+    let reissued = client.attest(&second_wallet, &schema_uid, &same_person, &None);
+    assert_eq!(client.find_by_uniqueness_key(&schema_uid, &key_hash), Some(reissued));
+}
+
+/// **Test: Attestation Status Computed at the Current Ledger**
+///
+/// Verifies that `get_attestation_status` reports expiry and revocation so consumers
+/// do not re-implement the checks.
+///
+/// **Key Assertions:**
+/// - A fresh attestation is neither expired nor revoked
+/// - Past its expiration it is flagged expired but still returned, unlike `get_attestation`
+/// - Direct and schema-wide revocations are both reported as revoked
+/// - Unknown UIDs fail with `AttestationNotFound`
+#[test]
+fn attestation_status_reports_expiry_and_revocation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let authority = Address::generate(&env);
+    let attester = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&authority, &SorobanString::from_str(&env, "membership"), &None, &true);
+    let value = SorobanString::from_str(&env, "member");
+    let expiring_uid = client.attest(&attester, &schema_uid, &value, &Some(2_000));
+    let revoked_uid = client.attest(&attester, &schema_uid, &value, &None);
+    let open_uid = client.attest(&attester, &schema_uid, &value, &None);
+
+    let status = client.get_attestation_status(&expiring_uid);
+    assert_eq!(status.attestation.uid, expiring_uid);
+    assert!(!status.is_expired);
+    assert!(!status.is_revoked);
+
+    client.revoke(&attester, &revoked_uid);
+    let status = client.get_attestation_status(&revoked_uid);
+    assert!(status.is_revoked);
+    assert!(!status.is_expired);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_001);
+    let status = client.get_attestation_status(&expiring_uid);
+    assert!(status.is_expired);
+    assert!(!status.is_revoked);
+    assert_eq!(client.get_attestation_status(&expiring_uid), status);
+
+    client.revoke_all(&authority, &schema_uid, &SorobanString::from_str(&env, "retired"));
+    let status = client.get_attestation_status(&open_uid);
+    assert!(status.is_revoked);
+    assert!(!status.is_expired);
+
+    let unknown = BytesN::from_array(&env, &[9; 32]);
+    assert_eq!(client.try_get_attestation_status(&unknown), Err(Ok(Error::AttestationNotFound)));
+}
+
+#[test]
+fn attestations_carry_locales_limited_by_schema() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let authority = Address::generate(&env);
+    let attester = Address::generate(&env);
+    client.initialize(&admin);
+
+    let schema_uid = client.register(&authority, &SorobanString::from_str(&env, "diploma"), &None, &true);
+    let value = SorobanString::from_str(&env, "diploma");
+    let en = Symbol::new(&env, "en");
+    let pt_br = Symbol::new(&env, "pt_BR");
+
+    // Any locale is accepted until the schema authority restricts them
+    assert_eq!(client.get_schema_locales(&schema_uid).len(), 0);
+    let fr_uid = client.attest_with_locale(&attester, &schema_uid, &value, &None, &Symbol::new(&env, "fr"));
+    assert_eq!(client.get_attestation(&fr_uid).locale, Some(Symbol::new(&env, "fr")));
+
+    let locales = vec![&env, en.clone(), pt_br.clone()];
+    let result = client.try_set_schema_locales(&attester, &schema_uid, &locales);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+    client.set_schema_locales(&authority, &schema_uid, &locales);
+    assert_eq!(client.get_schema_locales(&schema_uid), locales);
+
+    let en_uid = client.attest_with_locale(&attester, &schema_uid, &value, &None, &en);
+    let last = env.events().all().last().unwrap();
+    assert_eq!(last.1, (symbol_short!("ATTEST"), symbol_short!("LOCALE"), en.clone()).into_val(&env));
+    let (event_uid, event_schema_uid): (BytesN<32>, BytesN<32>) = last.2.try_into_val(&env).unwrap();
+    assert_eq!(event_uid, en_uid);
+    assert_eq!(event_schema_uid, schema_uid);
+    assert_eq!(client.get_attestation(&en_uid).locale, Some(en.clone()));
+
+    let result = client.try_attest_with_locale(&attester, &schema_uid, &value, &None, &Symbol::new(&env, "fr"));
+    assert_eq!(result, Err(Ok(Error::LocaleNotAllowed)));
+
+    // Attestations without a locale are unaffected by the restriction
+    let plain_uid = client.attest(&attester, &schema_uid, &value, &None);
+    assert_eq!(client.get_attestation(&plain_uid).locale, None);
+
+    let page = client.list_attestations_by_locale(&schema_uid, &en, &Bytes::new(&env), &10);
+    assert_eq!(page.uids, vec![&env, en_uid]);
+    let page = client.list_attestations_by_locale(&schema_uid, &pt_br, &Bytes::new(&env), &10);
+    assert_eq!(page.uids.len(), 0);
+
+    client.set_schema_locales(&authority, &schema_uid, &vec![&env]);
+    client.attest_with_locale(&attester, &schema_uid, &value, &None, &Symbol::new(&env, "fr"));
+}
+
+#[test]
+fn references_must_be_live_and_are_traversable() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let university = Address::generate(&env);
+    client.initialize(&admin);
+
+    let course_schema = client.register(&university, &SorobanString::from_str(&env, "course"), &None, &true);
+    let degree_schema = client.register(&university, &SorobanString::from_str(&env, "degree"), &None, &true);
+    let course = SorobanString::from_str(&env, "algorithms");
+    let degree = SorobanString::from_str(&env, "bsc");
+
+    let unknown = BytesN::from_array(&env, &[9; 32]);
+    let result = client.try_attest_with_ref(&university, &degree_schema, &degree, &None, &unknown);
+    assert_eq!(result, Err(Ok(Error::InvalidReference)));
+
+    let course_uid = client.attest(&university, &course_schema, &course, &None);
+    let first = client.attest_with_ref(&university, &degree_schema, &degree, &None, &course_uid);
+    let second = client.attest_with_ref(&university, &degree_schema, &degree, &None, &course_uid);
+    assert_eq!(client.get_attestation(&first).ref_uid, Some(course_uid.clone()));
+    assert_eq!(client.get_attestation(&course_uid).ref_uid, None);
+
+    let page = client.get_referencing_attestations(&course_uid, &Bytes::new(&env), &1);
+    assert_eq!(page.uids, vec![&env, first.clone()]);
+    assert!(page.has_more);
+    let page = client.get_referencing_attestations(&course_uid, &page.next_cursor, &1);
+    assert_eq!(page.uids, vec![&env, second]);
+    assert!(!page.has_more);
+    assert_eq!(client.get_referencing_attestations(&first, &Bytes::new(&env), &10).uids.len(), 0);
+
+    // Revoked attestations, individually or with their schema, can no longer be referenced
+    client.revoke(&university, &first);
+    let result = client.try_attest_with_ref(&university, &degree_schema, &degree, &None, &first);
+    assert_eq!(result, Err(Ok(Error::InvalidReference)));
+    client.revoke_all(&university, &course_schema, &SorobanString::from_str(&env, "retired"));
+    let result = client.try_attest_with_ref(&university, &degree_schema, &degree, &None, &course_uid);
+    assert_eq!(result, Err(Ok(Error::InvalidReference)));
+}
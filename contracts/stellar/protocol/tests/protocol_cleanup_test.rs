@@ -0,0 +1,293 @@
+use protocol::{
+    errors::Error,
+    instructions::cleanup::REVOKED_RETENTION_SECONDS,
+    state::{DataKey, UniquenessKey},
+    AttestationContract, AttestationContractClient,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, BytesN, Env, IntoVal, String as SorobanString, Symbol, TryIntoVal,
+};
+
+struct Setup<'a> {
+    client: AttestationContractClient<'a>,
+    admin: Address,
+    attester: Address,
+    schema_uid: BytesN<32>,
+    token: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let attester = Address::generate(env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&attester, &SorobanString::from_str(env, "cleanup-schema"), &None, &true);
+
+    let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    StellarAssetClient::new(env, &token).mint(&admin, &1_000);
+
+    Setup {
+        client,
+        admin,
+        attester,
+        schema_uid,
+        token,
+    }
+}
+
+/// **Test: Purge Removes Only Eligible Entries and Pays a Bounty**
+/// - Verifies expired attestations are removed and paid for
+/// - Verifies active and recently revoked attestations are skipped
+/// - Verifies revoked attestations become purgeable after the retention period
+#[test]
+fn test_purge_removes_eligible_entries_and_pays_bounty() {
+    let env = Env::default();
+    let s = setup(&env);
+    let caller = Address::generate(&env);
+    let token = TokenClient::new(&env, &s.token);
+
+    s.client.fund_cleanup_bounty(&s.admin, &s.token, &100, &10);
+    assert_eq!(token.balance(&s.admin), 900);
+
+    let expiring = s.client.attest(
+        &s.attester,
+        &s.schema_uid,
+        &SorobanString::from_str(&env, "a"),
+        &Some(2_000),
+    );
+    let active = s
+        .client
+        .attest(&s.attester, &s.schema_uid, &SorobanString::from_str(&env, "b"), &None);
+    let revoked = s
+        .client
+        .attest(&s.attester, &s.schema_uid, &SorobanString::from_str(&env, "c"), &None);
+    s.client.revoke(&s.attester, &revoked);
+
+    env.ledger().with_mut(|l| l.timestamp = 2_001);
+    let removed = s
+        .client
+        .purge(&caller, &vec![&env, expiring.clone(), active.clone(), revoked.clone()]);
+    assert_eq!(removed, 1);
+    assert_eq!(token.balance(&caller), 10);
+    assert!(s.client.try_get_attestation(&expiring).is_err());
+    assert!(s.client.try_get_attestation(&active).is_ok());
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = 1_000 + REVOKED_RETENTION_SECONDS + 1);
+    // Already-removed UIDs are skipped and not paid for again.
+    let removed = s.client.purge(&caller, &vec![&env, expiring, revoked.clone()]);
+    assert_eq!(removed, 1);
+    assert_eq!(token.balance(&caller), 20);
+    assert!(s.client.try_get_attestation(&revoked).is_err());
+    assert_eq!(s.client.get_cleanup_bounty().unwrap().balance, 80);
+}
+
+/// **Test: Bounty Payout Is Capped by the Pool Balance**
+/// - Verifies purging still succeeds when the pool cannot cover the full bounty
+#[test]
+fn test_purge_payout_capped_by_pool() {
+    let env = Env::default();
+    let s = setup(&env);
+    let caller = Address::generate(&env);
+
+    s.client.fund_cleanup_bounty(&s.admin, &s.token, &15, &10);
+    let first = s.client.attest(
+        &s.attester,
+        &s.schema_uid,
+        &SorobanString::from_str(&env, "a"),
+        &Some(1_500),
+    );
+    let second = s.client.attest(
+        &s.attester,
+        &s.schema_uid,
+        &SorobanString::from_str(&env, "b"),
+        &Some(1_500),
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 1_501);
+    assert_eq!(s.client.purge(&caller, &vec![&env, first, second]), 2);
+    assert_eq!(TokenClient::new(&env, &s.token).balance(&caller), 15);
+    assert_eq!(s.client.get_cleanup_bounty().unwrap().balance, 0);
+}
+
+/// **Test: Only the Admin Can Fund the Bounty Pool**
+#[test]
+fn test_fund_cleanup_bounty_requires_admin() {
+    let env = Env::default();
+    let s = setup(&env);
+    let stranger = Address::generate(&env);
+
+    let result = s.client.try_fund_cleanup_bounty(&stranger, &s.token, &100, &10);
+    assert_eq!(result.err(), Some(Ok(Error::NotAuthorized)));
+
+    let result = s.client.try_fund_cleanup_bounty(&s.admin, &s.token, &0, &10);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidAmount)));
+}
+
+/// **Test: Storage Lifecycle Events**
+/// - Verifies each purged attestation emits a `PURGED` event with the reason
+/// - Verifies expired attestations stay stored until purged, which emits their `EXPIRED` event
+/// - Verifies TTL extensions emit a `TTLEXTEND` event with the new live-until ledger
+#[test]
+fn test_storage_lifecycle_events() {
+    let env = Env::default();
+    let s = setup(&env);
+    let caller = Address::generate(&env);
+
+    let expiring = s.client.attest(
+        &s.attester,
+        &s.schema_uid,
+        &SorobanString::from_str(&env, "a"),
+        &Some(2_000),
+    );
+    let active = s
+        .client
+        .attest(&s.attester, &s.schema_uid, &SorobanString::from_str(&env, "b"), &None);
+
+    let live_until = s.client.extend_attestation_ttl(&active, &10_000);
+    assert_eq!(live_until, env.ledger().sequence() + 10_000);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("ATTEST"), symbol_short!("TTLEXTEND")).into_val(&env)
+    );
+    let (uid, ledger): (BytesN<32>, u32) = data.try_into_val(&env).unwrap();
+    assert_eq!((uid, ledger), (active.clone(), live_until));
+
+    // Reading an expired attestation fails without removing it
+    env.ledger().with_mut(|l| l.timestamp = 2_001);
+    assert_eq!(
+        s.client.try_get_attestation(&expiring),
+        Err(Ok(Error::AttestationExpired))
+    );
+    assert!(s.client.get_attestation_status(&expiring).is_expired);
+
+    s.client.purge(&caller, &vec![&env, expiring.clone(), active]);
+    let purged: std::vec::Vec<(BytesN<32>, Symbol)> = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| *topics == (symbol_short!("ATTEST"), symbol_short!("PURGED")).into_val(&env))
+        .map(|(_, _, data)| data.try_into_val(&env).unwrap())
+        .collect();
+    assert_eq!(purged, std::vec![(expiring.clone(), symbol_short!("EXPIRED"))]);
+    let expired: std::vec::Vec<(BytesN<32>, Option<u64>)> = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| *topics == (symbol_short!("ATTEST"), symbol_short!("EXPIRED")).into_val(&env))
+        .map(|(_, _, data)| data.try_into_val(&env).unwrap())
+        .collect();
+    assert_eq!(expired, std::vec![(expiring.clone(), Some(2_000))]);
+
+    assert_eq!(
+        s.client.try_extend_attestation_ttl(&expiring, &10_000),
+        Err(Ok(Error::AttestationNotFound))
+    );
+}
+
+/// **Test: Purge Removes an Attestation's Indexes**
+/// - Verifies purged attestations drop out of their subject, schema and attester listings
+/// - Verifies the subject index and uniqueness key no longer point at a purged attestation
+/// - Verifies later attestations keep their listing positions
+#[test]
+fn test_purge_removes_indexes() {
+    let env = Env::default();
+    let s = setup(&env);
+    let caller = Address::generate(&env);
+    let contract_id = s.client.address.clone();
+    s.client
+        .set_schema_uniqueness_field(&s.attester, &s.schema_uid, &Some(SorobanString::from_str(&env, "id")));
+
+    let expiring = s.client.attest(
+        &s.attester,
+        &s.schema_uid,
+        &SorobanString::from_str(&env, "{\"id\":1}"),
+        &Some(2_000),
+    );
+    let key_hash: BytesN<32> = env.crypto().sha256(&soroban_sdk::Bytes::from_slice(&env, b"1")).into();
+    let holder_key = DataKey::Uniqueness(UniquenessKey::Holder(s.schema_uid.clone(), key_hash));
+    let subject_key = DataKey::SubjectAttestation(s.schema_uid.clone(), s.attester.clone());
+    env.as_contract(&contract_id, || {
+        assert!(env.storage().persistent().has(&holder_key));
+        assert!(env.storage().persistent().has(&subject_key));
+    });
+
+    env.ledger().with_mut(|l| l.timestamp = 2_001);
+    assert_eq!(s.client.purge(&caller, &vec![&env, expiring.clone()]), 1);
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().persistent().has(&holder_key));
+        assert!(!env.storage().persistent().has(&subject_key));
+    });
+
+    let active = s.client.attest(
+        &s.attester,
+        &s.schema_uid,
+        &SorobanString::from_str(&env, "{\"id\":1}"),
+        &None,
+    );
+    let empty = soroban_sdk::Bytes::new(&env);
+    let page = s.client.list_schema_attestations(&s.schema_uid, &empty, &10);
+    assert_eq!(page.uids, vec![&env, active.clone()]);
+    assert_eq!(
+        page.next_cursor,
+        soroban_sdk::Bytes::from_array(&env, &2_u32.to_be_bytes())
+    );
+    assert_eq!(
+        s.client.list_subject_attestations(&s.attester, &empty, &10).uids,
+        vec![&env, active.clone()]
+    );
+    assert_eq!(
+        s.client.list_attester_attestations(&s.attester, &empty, &10).uids,
+        vec![&env, active]
+    );
+    assert!(s.client.self_check().is_empty());
+}
+
+/// **Test: Purge Spares Appealed Revocations and Rejects Overflowing Bounties**
+/// - Verifies a revoked attestation under an open appeal is not purged
+/// - Verifies it becomes purgeable once the appeal is resolved
+/// - Verifies a bounty that overflows fails with `InvalidAmount` instead of panicking
+#[test]
+fn test_purge_skips_open_appeals_and_checks_bounty_overflow() {
+    let env = Env::default();
+    let s = setup(&env);
+    let caller = Address::generate(&env);
+
+    s.client.set_schema_challenge_window(&s.attester, &s.schema_uid, &3_600);
+    let revoked = s
+        .client
+        .attest(&s.attester, &s.schema_uid, &SorobanString::from_str(&env, "a"), &None);
+    s.client.revoke(&s.attester, &revoked);
+    s.client.appeal(&s.attester, &revoked);
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = 1_000 + REVOKED_RETENTION_SECONDS + 1);
+    assert_eq!(s.client.purge(&caller, &vec![&env, revoked.clone()]), 0);
+    assert!(s.client.get_attestation_appeal(&revoked).is_some());
+
+    s.client.resolve_appeal(&s.attester, &revoked, &false);
+    s.client.fund_cleanup_bounty(&s.admin, &s.token, &100, &i128::MAX);
+    let expired = s.client.attest(
+        &s.attester,
+        &s.schema_uid,
+        &SorobanString::from_str(&env, "b"),
+        &Some(env.ledger().timestamp() + 1),
+    );
+    env.ledger().with_mut(|l| l.timestamp += 2);
+    assert_eq!(
+        s.client.try_purge(&caller, &vec![&env, revoked.clone(), expired]),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    s.client.fund_cleanup_bounty(&s.admin, &s.token, &100, &10);
+    assert_eq!(s.client.purge(&caller, &vec![&env, revoked.clone()]), 1);
+    assert!(s.client.get_attestation_appeal(&revoked).is_none());
+}
@@ -640,6 +640,7 @@ fn test_end_to_end_bls_signature_verification() {
         attester: attester.clone(),
         expiration_time: Some(9876543210),
         deadline: env.ledger().timestamp() + 666,
+        version: 1,
         signature: BytesN::from_array(&env, &[0; 96]), // Not used for this test
     };
 
@@ -648,6 +649,7 @@ fn test_end_to_end_bls_signature_verification() {
 
         let attestation_domain_separator = instructions::delegation::get_attest_dst();
         message_payload.extend_from_slice(attestation_domain_separator);
+        message_payload.extend_from_slice(&request.version.to_be_bytes());
 
         message_payload.extend_from_slice(&request.schema_uid.to_array());
         message_payload.extend_from_slice(&request.nonce.to_be_bytes());
@@ -728,6 +730,7 @@ fn test_clean_room_attestation_message_hash() {
         attester: attester.clone(),
         expiration_time: Some(9876543210),
         deadline: 1234567890,
+        version: 1,
         signature: BytesN::from_array(&env, &[0; 96]), // Not used for this test
     };
 
@@ -739,6 +742,7 @@ fn test_clean_room_attestation_message_hash() {
 
         let attestation_domain_separator = instructions::delegation::get_attest_dst();
         message_payload.extend_from_slice(attestation_domain_separator);
+        message_payload.extend_from_slice(&request.version.to_be_bytes());
 
         message_payload.extend_from_slice(&request.schema_uid.to_array());
         message_payload.extend_from_slice(&request.nonce.to_be_bytes());
@@ -784,6 +788,7 @@ fn test_clean_room_revocation_message_hash() {
         */
         attestation_uid: BytesN::from_array(&env, &[0; 32]),
         revoker: attester.clone(),
+        version: 1,
         signature: BytesN::from_array(&env, &[0; 96]), // Not used for this test
     };
 
@@ -795,6 +800,7 @@ fn test_clean_room_revocation_message_hash() {
 
         let revocation_domain_separator = instructions::delegation::get_revoke_dst();
         payload.extend_from_slice(revocation_domain_separator);
+        payload.extend_from_slice(&request.version.to_be_bytes());
 
         payload.extend_from_slice(&request.schema_uid.to_array());
         payload.extend_from_slice(&request.nonce.to_be_bytes());
@@ -2,15 +2,22 @@ mod testutils;
 
 use protocol::{
     errors::Error as ProtocolError,
-    instructions::delegation::{create_attestation_message, create_revocation_message},
+    instructions::delegation::{
+        build_attestation_message, build_secp256k1_attestation_message, build_secp256k1_revocation_message,
+        create_attestation_message, create_revocation_message, MESSAGE_VERSION, NONCE_WINDOW,
+    },
     state::{DelegatedAttestationRequest, DelegatedRevocationRequest},
     AttestationContract, AttestationContractClient,
 };
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
-    Address, BytesN, Env, String as SorobanString,
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, BytesN, Env, IntoVal, String as SorobanString, TryIntoVal,
+};
+use testutils::{
+    create_delegated_attestation_request, secp256k1_test_eth_address, sign_secp256k1, TEST_BLS_G2_PUBLIC_KEY,
+    TEST_BLS_PRIVATE_KEY,
 };
-use testutils::{create_delegated_attestation_request, TEST_BLS_G2_PUBLIC_KEY, TEST_BLS_PRIVATE_KEY};
 // No need for bls12_381 directly or rand_core since we use the test helpers
 
 // --- The corrected test implementation ---
@@ -205,6 +212,7 @@ fn test_delegated_revocation_with_valid_signature() {
         nonce: client.get_attester_nonce(&attester), // should be 1 after attestation
         revoker: attester.clone(),
         deadline: env.ledger().timestamp() + 1000,
+        version: 1,
         signature: BytesN::from_array(&env, &[0; 96]), // Placeholder
     };
 
@@ -276,6 +284,7 @@ fn test_delegated_action_with_expired_deadline() {
             attester: attester.clone(),
             expiration_time: None,
             deadline: 500, // Expired deadline (timestamp 0 is always in the past)
+            version: 1,
             signature: BytesN::from_array(&env, &[0; 96]),
         };
 
@@ -327,6 +336,7 @@ fn test_delegated_action_with_expired_deadline() {
             nonce: client.get_attester_nonce(&attester), // should be 1 after attestation
             revoker: attester.clone(),
             deadline: 500, // Expired deadline (timestamp 0 is always in the past)
+            version: 1,
             signature: BytesN::from_array(&env, &[0; 96]),
         };
 
@@ -349,3 +359,1169 @@ fn test_delegated_action_with_expired_deadline() {
         assert!(!attestation.revoked);
     }
 }
+
+/// **Test: Delegated Attestation with a secp256k1 (EVM-style) Signature**
+///
+/// This test verifies that an attester holding an EVM key can authorize a delegated
+/// attestation by signing `keccak256` of the standard attestation message.
+///
+/// # Workflow
+/// 1. The attester registers the EVM address of their secp256k1 key
+/// 2. The attester signs the domain-separated message with that key
+/// 3. Changing the subject or value of the signed request fails with `InvalidSignature`
+/// 4. A submitter posts the request through `attest_by_delegation_secp256k1`
+/// 5. The attestation is stored and the shared nonce is consumed
+/// 6. Replaying the same request fails with `InvalidNonce`
+#[test]
+fn test_delegated_attestation_with_secp256k1_signature() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "schema"), &None, &true);
+
+    let eth_address = secp256k1_test_eth_address(&env);
+    client.register_secp256k1_key(&attester, &eth_address);
+    assert_eq!(client.get_secp256k1_key(&attester).eth_address, eth_address);
+
+    let request = DelegatedAttestationRequest {
+        schema_uid: schema_uid.clone(),
+        subject: subject.clone(),
+        value: SorobanString::from_str(&env, "{\"key\":\"value\"}"),
+        nonce: 0,
+        attester: attester.clone(),
+        expiration_time: None,
+        deadline: env.ledger().timestamp() + 1000,
+        version: 1,
+        signature: BytesN::from_array(&env, &[0; 96]), // Unused on the secp256k1 path
+    };
+    let signature = sign_secp256k1(&env, &build_secp256k1_attestation_message(&env, &request));
+
+    // The signature binds the subject and the value, not just its length
+    let redirected = DelegatedAttestationRequest {
+        subject: Address::generate(&env),
+        ..request.clone()
+    };
+    let result = client.try_attest_by_delegation_secp256k1(&submitter, &redirected, &signature);
+    assert_eq!(result, Err(Ok(ProtocolError::InvalidSignature)));
+    let tampered = DelegatedAttestationRequest {
+        value: SorobanString::from_str(&env, "{\"key\":\"forge\"}"),
+        ..request.clone()
+    };
+    let result = client.try_attest_by_delegation_secp256k1(&submitter, &tampered, &signature);
+    assert_eq!(result, Err(Ok(ProtocolError::InvalidSignature)));
+
+    client.attest_by_delegation_secp256k1(&submitter, &request, &signature);
+
+    let attestation_uid = protocol::utils::generate_attestation_uid(&env, &schema_uid, &subject, 0);
+    let attestation = client.get_attestation(&attestation_uid);
+    assert_eq!(attestation.attester, attester);
+    assert_eq!(client.get_attester_nonce(&attester), 1);
+
+    let replay = client.try_attest_by_delegation_secp256k1(&submitter, &request, &signature);
+    assert_eq!(replay, Err(Ok(ProtocolError::InvalidNonce)));
+}
+
+/// **Test: secp256k1 Signature from the Wrong Key is Rejected**
+///
+/// Verifies that a signature whose recovered EVM address does not match the signer
+/// registered for the attester fails with `InvalidSignature`, and that attesters
+/// without a registered signer fail with `Secp256k1KeyNotRegistered`.
+#[test]
+fn test_delegated_attestation_with_mismatched_secp256k1_signer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "schema"), &None, &true);
+
+    let request = DelegatedAttestationRequest {
+        schema_uid: schema_uid.clone(),
+        subject: subject.clone(),
+        value: SorobanString::from_str(&env, "{\"key\":\"value\"}"),
+        nonce: 0,
+        attester: attester.clone(),
+        expiration_time: None,
+        deadline: env.ledger().timestamp() + 1000,
+        version: 1,
+        signature: BytesN::from_array(&env, &[0; 96]),
+    };
+    let signature = sign_secp256k1(&env, &build_secp256k1_attestation_message(&env, &request));
+
+    // No signer registered yet
+    let result = client.try_attest_by_delegation_secp256k1(&submitter, &request, &signature);
+    assert_eq!(result, Err(Ok(ProtocolError::Secp256k1KeyNotRegistered)));
+
+    // Register a different EVM address than the one that signed
+    client.register_secp256k1_key(&attester, &BytesN::from_array(&env, &[7u8; 20]));
+    let result = client.try_attest_by_delegation_secp256k1(&submitter, &request, &signature);
+    assert_eq!(result, Err(Ok(ProtocolError::InvalidSignature)));
+    assert_eq!(client.get_attester_nonce(&attester), 0);
+}
+
+/// **Test: Delegated Revocation with a secp256k1 (EVM-style) Signature**
+///
+/// # Workflow
+/// 1. The attester registers their EVM address and attests through the secp256k1 path
+/// 2. A revocation signed by a different EVM key is rejected with `InvalidSignature`
+/// 3. The signature does not carry over to another attestation of the same schema
+/// 4. A revocation signed by the registered key is accepted through `revoke_by_delegation_secp256k1`
+/// 5. Replaying it fails, and its nonce cannot be reused to revoke another attestation
+#[test]
+fn test_delegated_revocation_with_secp256k1_signature() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "schema"), &None, &true);
+    client.register_secp256k1_key(&attester, &secp256k1_test_eth_address(&env));
+
+    let request = DelegatedAttestationRequest {
+        schema_uid: schema_uid.clone(),
+        subject: subject.clone(),
+        value: SorobanString::from_str(&env, "{\"key\":\"value\"}"),
+        nonce: 0,
+        attester: attester.clone(),
+        expiration_time: None,
+        deadline: env.ledger().timestamp() + 1000,
+        version: 1,
+        signature: BytesN::from_array(&env, &[0; 96]),
+    };
+    let signature = sign_secp256k1(&env, &build_secp256k1_attestation_message(&env, &request));
+    client.attest_by_delegation_secp256k1(&submitter, &request, &signature);
+    let attestation_uid = protocol::utils::generate_attestation_uid(&env, &schema_uid, &subject, 0);
+
+    let other_subject = Address::generate(&env);
+    let other_request = DelegatedAttestationRequest {
+        subject: other_subject.clone(),
+        nonce: 1,
+        ..request.clone()
+    };
+    let other_signature = sign_secp256k1(&env, &build_secp256k1_attestation_message(&env, &other_request));
+    client.attest_by_delegation_secp256k1(&submitter, &other_request, &other_signature);
+    let other_uid = protocol::utils::generate_attestation_uid(&env, &schema_uid, &other_subject, 1);
+
+    let revocation = DelegatedRevocationRequest {
+        attestation_uid: attestation_uid.clone(),
+        schema_uid: schema_uid.clone(),
+        subject: subject.clone(),
+        nonce: client.get_attester_nonce(&attester),
+        revoker: attester.clone(),
+        deadline: env.ledger().timestamp() + 1000,
+        version: 1,
+        signature: BytesN::from_array(&env, &[0; 96]), // Unused on the secp256k1 path
+    };
+    let signature = sign_secp256k1(&env, &build_secp256k1_revocation_message(&env, &revocation));
+
+    // The attestation signature does not authorize the revocation
+    let wrong = sign_secp256k1(&env, &build_secp256k1_attestation_message(&env, &request));
+    let result = client.try_revoke_by_delegation_secp256k1(&submitter, &revocation, &wrong);
+    assert_eq!(result, Err(Ok(ProtocolError::InvalidSignature)));
+    assert!(!client.get_attestation(&attestation_uid).revoked);
+
+    // The signature is bound to the attestation UID
+    let retargeted = DelegatedRevocationRequest {
+        attestation_uid: other_uid.clone(),
+        ..revocation.clone()
+    };
+    let result = client.try_revoke_by_delegation_secp256k1(&submitter, &retargeted, &signature);
+    assert_eq!(result, Err(Ok(ProtocolError::InvalidSignature)));
+
+    client.revoke_by_delegation_secp256k1(&submitter, &revocation, &signature);
+
+    let attestation = client.get_attestation(&attestation_uid);
+    assert!(attestation.revoked);
+    assert!(attestation.revocation_time.is_some());
+    assert!(client.is_attester_nonce_used(&attester, &revocation.nonce));
+
+    let replay = client.try_revoke_by_delegation_secp256k1(&submitter, &revocation, &signature);
+    assert_eq!(replay, Err(Ok(ProtocolError::AttestationNotFound)));
+
+    let reused_nonce = sign_secp256k1(&env, &build_secp256k1_revocation_message(&env, &retargeted));
+    let result = client.try_revoke_by_delegation_secp256k1(&submitter, &retargeted, &reused_nonce);
+    assert_eq!(result, Err(Ok(ProtocolError::InvalidNonce)));
+    assert!(!client.get_attestation(&other_uid).revoked);
+}
+
+/// **Test: Delegated Revocations Are Checked Against the Attestation's Schema and Run Resolver Hooks**
+///
+/// # Workflow
+/// 1. A revocation naming another revocable schema is rejected on both paths with `NotAuthorized`
+/// 2. A revocation the resolver rejects in `onrevoke` fails with `ResolverError` and keeps its nonce
+/// 3. An accepted secp256k1 revocation passes the attestation to `onrevoke` and `onresolve`
+#[test]
+fn delegated_revocations_run_resolver_hooks() {
+    use protocol::interfaces::resolver::ResolverAttestation;
+    use protocol::utils::generate_attestation_uid;
+    use testutils::DummyResolver;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let resolver_id = env.register(DummyResolver, ());
+
+    client.initialize(&admin);
+    let schema_uid = client.register(
+        &admin,
+        &SorobanString::from_str(&env, "schema"),
+        &Some(resolver_id.clone()),
+        &true,
+    );
+    let other_schema_uid = client.register(&admin, &SorobanString::from_str(&env, "other"), &None, &true);
+    client.register_secp256k1_key(&attester, &secp256k1_test_eth_address(&env));
+
+    let request = DelegatedAttestationRequest {
+        schema_uid: schema_uid.clone(),
+        subject: subject.clone(),
+        value: SorobanString::from_str(&env, "{\"key\":\"value\"}"),
+        nonce: 0,
+        attester: attester.clone(),
+        expiration_time: None,
+        deadline: env.ledger().timestamp() + 1000,
+        version: 1,
+        signature: BytesN::from_array(&env, &[0; 96]),
+    };
+    let signature = sign_secp256k1(&env, &build_secp256k1_attestation_message(&env, &request));
+    client.attest_by_delegation_secp256k1(&submitter, &request, &signature);
+    let attestation_uid = generate_attestation_uid(&env, &schema_uid, &subject, 0);
+
+    let revocation = DelegatedRevocationRequest {
+        attestation_uid: attestation_uid.clone(),
+        schema_uid: schema_uid.clone(),
+        subject: subject.clone(),
+        nonce: client.get_attester_nonce(&attester),
+        revoker: attester.clone(),
+        deadline: env.ledger().timestamp() + 1000,
+        version: 1,
+        signature: BytesN::from_array(&env, &[0; 96]),
+    };
+
+    // The signed schema must be the attestation's own
+    let mismatched = DelegatedRevocationRequest {
+        schema_uid: other_schema_uid,
+        ..revocation.clone()
+    };
+    let mismatched_signature = sign_secp256k1(&env, &build_secp256k1_revocation_message(&env, &mismatched));
+    let result = client.try_revoke_by_delegation_secp256k1(&submitter, &mismatched, &mismatched_signature);
+    assert_eq!(result, Err(Ok(ProtocolError::NotAuthorized)));
+    let result = client.try_revoke_by_delegation(&submitter, &mismatched);
+    assert_eq!(result, Err(Ok(ProtocolError::NotAuthorized)));
+
+    // The resolver can veto the revocation
+    let signature = sign_secp256k1(&env, &build_secp256k1_revocation_message(&env, &revocation));
+    env.as_contract(&resolver_id, || {
+        env.storage().instance().set(&symbol_short!("ALLOW_REV"), &false)
+    });
+    let result = client.try_revoke_by_delegation_secp256k1(&submitter, &revocation, &signature);
+    assert_eq!(result, Err(Ok(ProtocolError::ResolverError)));
+    assert!(!client.get_attestation(&attestation_uid).revoked);
+    assert!(!client.is_attester_nonce_used(&attester, &revocation.nonce));
+
+    env.as_contract(&resolver_id, || {
+        env.storage().instance().set(&symbol_short!("ALLOW_REV"), &true)
+    });
+    client.revoke_by_delegation_secp256k1(&submitter, &revocation, &signature);
+    assert!(client.get_attestation(&attestation_uid).revoked);
+
+    let (revoked, resolved_uid) = env.as_contract(&resolver_id, || {
+        let revoked: ResolverAttestation = env.storage().instance().get(&symbol_short!("LASTONREV")).unwrap();
+        let resolved_uid: BytesN<32> = env.storage().instance().get(&symbol_short!("ONRES_UID")).unwrap();
+        (revoked, resolved_uid)
+    });
+    assert_eq!(revoked.uid, attestation_uid);
+    assert_eq!(resolved_uid, attestation_uid);
+}
+
+/// **Test: Banned Attesters Are Rejected in Direct and Delegated Flows**
+///
+/// # Workflow
+/// 1. A non-admin cannot ban attesters.
+/// 2. The admin bans the attester; both `attest` and `attest_by_delegation` fail
+///    with `AttesterBanned` and no nonce is consumed.
+/// 3. After `unban_attester`, the same signed delegated request succeeds.
+#[test]
+fn test_banned_attester_rejected_in_direct_and_delegated_flows() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "schema"), &None, &true);
+    client.register_bls_key(&attester, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+
+    let result = client.try_ban_attester(&submitter, &attester);
+    assert_eq!(result, Err(Ok(ProtocolError::NotAuthorized)));
+
+    client.ban_attester(&admin, &attester);
+    assert!(client.is_attester_banned(&attester));
+
+    let value = SorobanString::from_str(&env, "spam");
+    let result = client.try_attest(&attester, &schema_uid, &value, &None);
+    assert_eq!(result, Err(Ok(ProtocolError::AttesterBanned)));
+
+    let request = create_delegated_attestation_request(&env, &attester, 0, &schema_uid, &subject);
+    let result = client.try_attest_by_delegation(&submitter, &request);
+    assert_eq!(result, Err(Ok(ProtocolError::AttesterBanned)));
+    assert_eq!(client.get_attester_nonce(&attester), 0);
+
+    client.unban_attester(&admin, &attester);
+    assert!(!client.is_attester_banned(&attester));
+    client.attest_by_delegation(&submitter, &request);
+    assert_eq!(client.get_attester_nonce(&attester), 1);
+}
+
+/// **Test: Pausing Blocks Attest and Revoke in Direct and Delegated Flows**
+///
+/// # Workflow
+/// 1. A non-admin cannot pause; the admin pauses the contract.
+/// 2. Direct and delegated attestations and revocations fail with `ContractPaused`.
+/// 3. The admin can still revoke while paused.
+/// 4. After unpausing, the same delegated request is accepted.
+#[test]
+fn test_pause_blocks_attest_and_revoke() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "schema"), &None, &true);
+    client.register_bls_key(&attester, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+    let value = SorobanString::from_str(&env, "value");
+    let first_uid = client.attest(&attester, &schema_uid, &value, &None);
+    let second_uid = client.attest(&attester, &schema_uid, &value, &None);
+
+    let result = client.try_pause(&submitter);
+    assert_eq!(result, Err(Ok(ProtocolError::NotAuthorized)));
+    assert!(!client.is_paused());
+
+    client.pause(&admin);
+    assert!(client.is_paused());
+    assert!(client.get_config().paused);
+
+    let result = client.try_attest(&attester, &schema_uid, &value, &None);
+    assert_eq!(result, Err(Ok(ProtocolError::ContractPaused)));
+    let result = client.try_revoke(&attester, &first_uid);
+    assert_eq!(result, Err(Ok(ProtocolError::ContractPaused)));
+
+    let nonce = client.get_attester_nonce(&attester);
+    let request = create_delegated_attestation_request(&env, &attester, nonce, &schema_uid, &subject);
+    let result = client.try_attest_by_delegation(&submitter, &request);
+    assert_eq!(result, Err(Ok(ProtocolError::ContractPaused)));
+    assert_eq!(client.get_attester_nonce(&attester), nonce);
+
+    let revocation = DelegatedRevocationRequest {
+        attestation_uid: first_uid.clone(),
+        schema_uid: schema_uid.clone(),
+        subject: subject.clone(),
+        nonce,
+        revoker: attester.clone(),
+        deadline: env.ledger().timestamp() + 1000,
+        version: MESSAGE_VERSION,
+        signature: BytesN::from_array(&env, &[0; 96]),
+    };
+    let result = client.try_revoke_by_delegation(&submitter, &revocation);
+    assert_eq!(result, Err(Ok(ProtocolError::ContractPaused)));
+
+    client.admin_revoke(&admin, &second_uid);
+    assert!(client.get_attestation(&second_uid).revoked);
+
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+    client.attest_by_delegation(&submitter, &request);
+    assert_eq!(client.get_attester_nonce(&attester), nonce + 1);
+    client.revoke(&attester, &first_uid);
+    assert!(client.get_attestation(&first_uid).revoked);
+}
+
+/// **Test: Resolvers Receive the Relayer of Delegated Attestations**
+///
+/// # Workflow
+/// 1. A schema is registered with a resolver implementing `onrelayed`.
+/// 2. A submitter relays a delegated attestation for the schema.
+/// 3. The resolver's `onrelayed` hook receives the submitter in its context.
+#[test]
+fn test_delegated_attestation_passes_submitter_to_resolver() {
+    use soroban_sdk::symbol_short;
+    use testutils::DummyResolver;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let resolver_id = env.register(DummyResolver, ());
+
+    client.initialize(&admin);
+    let schema_uid = client.register(
+        &admin,
+        &SorobanString::from_str(&env, "schema"),
+        &Some(resolver_id.clone()),
+        &true,
+    );
+    client.register_bls_key(&attester, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+
+    let request = create_delegated_attestation_request(&env, &attester, 0, &schema_uid, &subject);
+    client.attest_by_delegation(&submitter, &request);
+
+    let relayer: Option<Address> =
+        env.as_contract(&resolver_id, || env.storage().instance().get(&symbol_short!("RELAY_SUB")));
+    assert_eq!(relayer, Some(submitter));
+}
+
+/// **Test: Delegated Attestations Run the Resolver Hooks of `attest`**
+///
+/// # Workflow
+/// 1. A delegated attestation runs the resolver's `onattest` and `onresolve` hooks
+/// 2. A request the resolver rejects fails with `ResolverError`, alone or in a batch,
+///    without consuming its nonce
+/// 3. Under a consent schema, `onattest` gates submission and `onresolve` runs on acceptance
+/// 4. With deferred hooks, the attestation is queued instead, on acceptance if pending
+#[test]
+fn delegated_attestations_run_resolver_hooks() {
+    use protocol::utils::generate_attestation_uid;
+    use soroban_sdk::{symbol_short, vec};
+    use testutils::DummyResolver;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let resolver_id = env.register(DummyResolver, ());
+
+    client.initialize(&admin);
+    let schema_uid = client.register(
+        &admin,
+        &SorobanString::from_str(&env, "schema"),
+        &Some(resolver_id.clone()),
+        &true,
+    );
+    client.register_bls_key(&attester, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+    let resolved_uid = || -> Option<BytesN<32>> {
+        env.as_contract(&resolver_id, || {
+            env.storage().instance().get(&symbol_short!("ONRES_UID"))
+        })
+    };
+    let set_allowed = |allowed: bool| {
+        env.as_contract(&resolver_id, || {
+            env.storage().instance().set(&symbol_short!("ALLOW_ATT"), &allowed)
+        })
+    };
+
+    let request = create_delegated_attestation_request(&env, &attester, 0, &schema_uid, &subject);
+    client.attest_by_delegation(&submitter, &request);
+    assert_eq!(
+        resolved_uid(),
+        Some(generate_attestation_uid(&env, &schema_uid, &subject, 0))
+    );
+
+    set_allowed(false);
+    let gated = create_delegated_attestation_request(&env, &attester, 1, &schema_uid, &subject);
+    let result = client.try_attest_by_delegation(&submitter, &gated);
+    assert_eq!(result, Err(Ok(ProtocolError::ResolverError)));
+    let outcomes = client.submit_delegated_batch(&submitter, &vec![&env, gated.clone()]);
+    assert_eq!(outcomes, vec![&env, Err(ProtocolError::ResolverError)]);
+    assert!(!client.is_attester_nonce_used(&attester, &1));
+
+    // Consent: the resolver gates submission, and resolves on acceptance
+    client.set_schema_requires_consent(&admin, &schema_uid, &true);
+    let result = client.try_attest_by_delegation(&submitter, &gated);
+    assert_eq!(result, Err(Ok(ProtocolError::ResolverError)));
+    set_allowed(true);
+    client.attest_by_delegation(&submitter, &gated);
+    let pending_uid = generate_attestation_uid(&env, &schema_uid, &subject, 1);
+    assert_ne!(resolved_uid(), Some(pending_uid.clone()));
+    client.accept_attestation(&subject, &pending_uid);
+    assert_eq!(resolved_uid(), Some(pending_uid.clone()));
+
+    // Deferred hooks: queued when stored, or when accepted if pending
+    client.set_schema_deferred_hooks(&admin, &schema_uid, &true);
+    let deferred = create_delegated_attestation_request(&env, &attester, 2, &schema_uid, &subject);
+    client.attest_by_delegation(&submitter, &deferred);
+    assert_eq!(client.get_hook_queue_length(), 0);
+    client.accept_attestation(&subject, &generate_attestation_uid(&env, &schema_uid, &subject, 2));
+    assert_eq!(client.get_hook_queue_length(), 1);
+
+    client.set_schema_requires_consent(&admin, &schema_uid, &false);
+    let direct = create_delegated_attestation_request(&env, &attester, 3, &schema_uid, &subject);
+    client.attest_by_delegation(&submitter, &direct);
+    assert_eq!(client.get_hook_queue_length(), 2);
+    assert_eq!(resolved_uid(), Some(pending_uid));
+
+    assert_eq!(client.process_hook_queue(&10), 2);
+    assert_eq!(
+        resolved_uid(),
+        Some(generate_attestation_uid(&env, &schema_uid, &subject, 3))
+    );
+}
+
+/// **Test: Declaring a Key Compromise Cuts Off Delegation and Freezes Recent Attestations**
+///
+/// # Workflow
+/// 1. The attester issues one attestation before and one after the suspected leak.
+/// 2. The attester declares a compromise with a freeze from the leak onwards.
+/// 3. The BLS key is removed and a pre-signed delegated request is rejected.
+/// 4. Only the attestation issued after the leak is frozen, until the admin unfreezes it.
+/// 5. Attestations issued after the declaration are not frozen.
+#[test]
+fn test_declare_compromise_invalidates_keys_and_freezes_recent_attestations() {
+    use protocol::instructions::compromise::COMPROMISE_NONCE_SKIP;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let before_schema = client.register(&admin, &SorobanString::from_str(&env, "before"), &None, &true);
+    let after_schema = client.register(&admin, &SorobanString::from_str(&env, "after"), &None, &true);
+    client.register_bls_key(&attester, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+    let value = SorobanString::from_str(&env, "{\"key\":\"value\"}");
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let before_uid = client.attest(&attester, &before_schema, &value, &None);
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    let after_uid = client.attest(&attester, &after_schema, &value, &None);
+    let presigned = create_delegated_attestation_request(&env, &attester, 2, &before_schema, &subject);
+
+    client.declare_compromise(&attester, &Some(1_500));
+
+    assert!(client.try_get_bls_key(&attester).is_err());
+    assert_eq!(client.get_attester_nonce(&attester), 2 + COMPROMISE_NONCE_SKIP);
+    assert!(client.try_attest_by_delegation(&submitter, &presigned).is_err());
+
+    assert!(!client.is_attestation_frozen(&before_uid));
+    assert!(client.is_attestation_frozen(&after_uid));
+    assert!(client.has_valid_attestation(&attester, &before_schema));
+    assert!(!client.has_valid_attestation(&attester, &after_schema));
+
+    let result = client.try_unfreeze_attestation(&attester, &after_uid);
+    assert_eq!(result, Err(Ok(ProtocolError::NotAuthorized)));
+    client.unfreeze_attestation(&admin, &after_uid);
+    assert!(!client.is_attestation_frozen(&after_uid));
+    assert!(client.has_valid_attestation(&attester, &after_schema));
+
+    env.ledger().with_mut(|li| li.timestamp = 3_000);
+    let recovered_uid = client.attest(&attester, &before_schema, &value, &None);
+    assert!(!client.is_attestation_frozen(&recovered_uid));
+    assert!(client.has_valid_attestation(&attester, &before_schema));
+}
+
+/// **Test: Delegated Batches Report Per-Request Outcomes**
+///
+/// # Workflow
+/// 1. A relayer submits a batch mixing valid requests, a request whose signature no longer
+///    matches its content, and a request with a stale nonce.
+/// 2. Valid requests are stored and their UIDs returned; failed requests report their error.
+/// 3. A failed request does not consume its nonce, so a later request can reuse it.
+#[test]
+fn test_delegated_batch_reports_partial_failures() {
+    use protocol::utils::generate_attestation_uid;
+    use soroban_sdk::vec;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "schema"), &None, &true);
+    client.register_bls_key(&attester, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+
+    let first = create_delegated_attestation_request(&env, &attester, 0, &schema_uid, &subject);
+    let mut tampered = create_delegated_attestation_request(&env, &attester, 1, &schema_uid, &subject);
+    tampered.deadline += 1;
+    let second = create_delegated_attestation_request(&env, &attester, 1, &schema_uid, &subject);
+
+    let outcomes = client.submit_delegated_batch(&submitter, &vec![&env, first.clone(), tampered, second, first]);
+    assert_eq!(
+        outcomes,
+        vec![
+            &env,
+            Ok(generate_attestation_uid(&env, &schema_uid, &subject, 0)),
+            Err(ProtocolError::InvalidSignature),
+            Ok(generate_attestation_uid(&env, &schema_uid, &subject, 1)),
+            Err(ProtocolError::InvalidNonce),
+        ]
+    );
+    assert_eq!(client.get_attester_nonce(&attester), 2);
+    let stored = client.get_attestation(&generate_attestation_uid(&env, &schema_uid, &subject, 1));
+    assert_eq!(stored.subject, subject);
+}
+
+/// **Test: Paused Protocol Rejects Whole Delegated Batches**
+///
+/// # Workflow
+/// 1. While the protocol is paused, submitting a batch fails as a whole
+/// 2. No nonce is consumed, so the same batch goes through once unpaused
+#[test]
+fn test_delegated_batch_rejected_while_paused() {
+    use soroban_sdk::vec;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "schema"), &None, &true);
+    client.register_bls_key(&attester, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+
+    let batch = vec![
+        &env,
+        create_delegated_attestation_request(&env, &attester, 0, &schema_uid, &subject),
+        create_delegated_attestation_request(&env, &attester, 1, &schema_uid, &subject),
+    ];
+
+    client.pause(&admin);
+    let result = client.try_submit_delegated_batch(&submitter, &batch);
+    assert_eq!(result, Err(Ok(ProtocolError::ContractPaused)));
+    assert_eq!(client.get_attester_nonce(&attester), 0);
+    assert!(!client.is_attester_nonce_used(&attester, &1));
+
+    client.unpause(&admin);
+    let outcomes = client.submit_delegated_batch(&submitter, &batch);
+    assert!(outcomes.iter().all(|outcome| outcome.is_ok()));
+    assert_eq!(client.get_attester_nonce(&attester), 2);
+}
+
+/// **Test: Uniqueness Collisions in a Batch Leave No State Behind**
+///
+/// # Workflow
+/// 1. Under a schema with a uniqueness field and a deduplication window, a batch holds two
+///    requests carrying the same uniqueness key for different subjects
+/// 2. The second request fails with `UniquenessKeyTaken` without consuming its nonce
+/// 3. No fingerprint is recorded for it, so once the holding attestation is revoked the
+///    same request goes through
+#[test]
+fn test_delegated_batch_uniqueness_collision_leaves_no_state() {
+    use protocol::utils::generate_attestation_uid;
+    use soroban_sdk::vec;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let first_subject = Address::generate(&env);
+    let second_subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&attester, &SorobanString::from_str(&env, "schema"), &None, &true);
+    client.set_schema_uniqueness_field(&attester, &schema_uid, &Some(SorobanString::from_str(&env, "key")));
+    client.set_schema_dedup_window(&attester, &schema_uid, &1_000);
+    client.register_bls_key(&attester, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+
+    let first = create_delegated_attestation_request(&env, &attester, 0, &schema_uid, &first_subject);
+    let colliding = create_delegated_attestation_request(&env, &attester, 1, &schema_uid, &second_subject);
+    let outcomes = client.submit_delegated_batch(&submitter, &vec![&env, first, colliding.clone()]);
+    let first_uid = generate_attestation_uid(&env, &schema_uid, &first_subject, 0);
+    assert_eq!(
+        outcomes,
+        vec![&env, Ok(first_uid.clone()), Err(ProtocolError::UniquenessKeyTaken)]
+    );
+    assert!(!client.is_attester_nonce_used(&attester, &1));
+
+    client.revoke(&attester, &first_uid);
+    let outcomes = client.submit_delegated_batch(&submitter, &vec![&env, colliding]);
+    assert_eq!(
+        outcomes,
+        vec![
+            &env,
+            Ok(generate_attestation_uid(&env, &schema_uid, &second_subject, 1))
+        ]
+    );
+    assert_eq!(client.get_attester_nonce(&attester), 2);
+}
+
+/// **Test: Delegated Nonces Can Be Consumed Out of Order**
+///
+/// # Workflow
+/// 1. A request signed with nonce 2 lands before nonces 0 and 1 and is accepted
+/// 2. Replaying it fails, and the counter stays at 0 until nonce 0 is consumed
+/// 3. Consuming nonces 0 and 1 moves the counter past the already used nonce 2
+/// 4. A direct attestation takes the next unused nonce
+/// 5. Nonces beyond `NONCE_WINDOW` above the counter are rejected
+#[test]
+fn test_delegated_nonces_can_be_consumed_out_of_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "schema"), &None, &true);
+    client.register_bls_key(&attester, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+
+    let third = create_delegated_attestation_request(&env, &attester, 2, &schema_uid, &subject);
+    client.attest_by_delegation(&submitter, &third);
+    assert_eq!(client.get_attester_nonce(&attester), 0);
+    assert!(client.is_attester_nonce_used(&attester, &2));
+    assert!(!client.is_attester_nonce_used(&attester, &0));
+
+    let replay = client.try_attest_by_delegation(&submitter, &third);
+    assert_eq!(replay, Err(Ok(ProtocolError::InvalidNonce)));
+
+    let first = create_delegated_attestation_request(&env, &attester, 0, &schema_uid, &subject);
+    client.attest_by_delegation(&submitter, &first);
+    assert_eq!(client.get_attester_nonce(&attester), 1);
+
+    let second = create_delegated_attestation_request(&env, &attester, 1, &schema_uid, &subject);
+    client.attest_by_delegation(&submitter, &second);
+    assert_eq!(client.get_attester_nonce(&attester), 3);
+
+    let value = SorobanString::from_str(&env, "direct");
+    let direct_uid = client.attest(&attester, &schema_uid, &value, &None);
+    assert_eq!(client.get_attestation(&direct_uid).nonce, 3);
+    assert_eq!(client.get_attester_nonce(&attester), 4);
+
+    let too_far = create_delegated_attestation_request(&env, &attester, 4 + NONCE_WINDOW, &schema_uid, &subject);
+    let result = client.try_attest_by_delegation(&submitter, &too_far);
+    assert_eq!(result, Err(Ok(ProtocolError::InvalidNonce)));
+}
+
+/// **Test: Attesters Can Cancel Signed but Unsubmitted Requests**
+///
+/// # Workflow
+/// 1. The attester signs a request with nonce 1, then cancels nonce 1 before submission
+/// 2. A cancellation event is emitted and the leaked request can no longer be submitted
+/// 3. The still-pending nonce 0 request is unaffected and moves the counter past nonce 1
+/// 4. Cancelling an already used nonce fails with `InvalidNonce`
+#[test]
+fn test_cancel_delegation_nonce_invalidates_signed_request() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "schema"), &None, &true);
+    client.register_bls_key(&attester, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+
+    let pending = create_delegated_attestation_request(&env, &attester, 0, &schema_uid, &subject);
+    let leaked = create_delegated_attestation_request(&env, &attester, 1, &schema_uid, &subject);
+
+    client.cancel_delegation_nonce(&attester, &1);
+    let last = env.events().all().last().unwrap();
+    let expected_topics = (symbol_short!("ATTESTER"), symbol_short!("CANCEL"), attester.clone()).into_val(&env);
+    assert_eq!(last.1, expected_topics);
+    let cancelled_nonce: u64 = last.2.try_into_val(&env).unwrap();
+    assert_eq!(cancelled_nonce, 1);
+    assert!(client.is_attester_nonce_used(&attester, &1));
+
+    let result = client.try_attest_by_delegation(&submitter, &leaked);
+    assert_eq!(result, Err(Ok(ProtocolError::InvalidNonce)));
+
+    client.attest_by_delegation(&submitter, &pending);
+    assert_eq!(client.get_attester_nonce(&attester), 2);
+
+    let result = client.try_cancel_delegation_nonce(&attester, &0);
+    assert_eq!(result, Err(Ok(ProtocolError::InvalidNonce)));
+}
+
+/// **Test: Delegated Message Version Negotiation**
+///
+/// Verifies that delegated requests carry a signed message format version and that
+/// the contract only accepts the versions the admin has configured.
+///
+/// **Key Assertions:**
+/// - The current version is accepted by default
+/// - The version is part of the signed message
+/// - Requests with an unaccepted version fail with `UnsupportedMessageVersion` without consuming the nonce
+/// - Only the admin can configure the accepted set, which must be non-empty and supported
+#[test]
+fn delegated_requests_negotiate_message_version() {
+    use soroban_sdk::vec;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "schema"), &None, &true);
+    client.register_bls_key(&attester, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+    assert_eq!(client.get_accepted_message_versions(), vec![&env, MESSAGE_VERSION]);
+
+    let request = create_delegated_attestation_request(&env, &attester, 0, &schema_uid, &subject);
+    let mut next_version = request.clone();
+    next_version.version = MESSAGE_VERSION + 1;
+    assert_ne!(
+        build_attestation_message(&env, &request),
+        build_attestation_message(&env, &next_version)
+    );
+    assert_eq!(
+        client.try_attest_by_delegation(&submitter, &next_version),
+        Err(Ok(ProtocolError::UnsupportedMessageVersion))
+    );
+    assert_eq!(client.get_attester_nonce(&attester), 0);
+
+    assert_eq!(
+        client.try_set_accepted_message_versions(&attester, &vec![&env, MESSAGE_VERSION]),
+        Err(Ok(ProtocolError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_set_accepted_message_versions(&admin, &vec![&env, MESSAGE_VERSION, MESSAGE_VERSION + 1]),
+        Err(Ok(ProtocolError::UnsupportedMessageVersion))
+    );
+    assert_eq!(
+        client.try_set_accepted_message_versions(&admin, &vec![&env]),
+        Err(Ok(ProtocolError::UnsupportedMessageVersion))
+    );
+
+    client.set_accepted_message_versions(&admin, &vec![&env, MESSAGE_VERSION]);
+    client.attest_by_delegation(&submitter, &request);
+    assert_eq!(client.get_attester_nonce(&attester), 1);
+}
+
+/// **Test: Contract Subjects Are Indexed Separately From Account Subjects**
+///
+/// An auditor attests by delegation about a deployed protocol contract and about an
+/// account under the same schema. Each lands in the listing for its subject type.
+///
+/// **Key Assertions:**
+/// - `get_subject_type` classifies `C...` addresses as contracts and `G...` as accounts
+/// - Attestations about contracts are accepted and resolve to the contract subject
+/// - `list_schema_attestations_by_type` returns only the attestations of the requested type
+#[test]
+fn contract_subjects_are_listed_by_type() {
+    use protocol::state::SubjectType;
+    use soroban_sdk::{vec, Bytes};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let auditor = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let audited_protocol = env.register(AttestationContract {}, ());
+    let account = Address::from_str(&env, "GD25F6Z56KYTB4I4EU7KHGLM43VRBNENAUQ3GP24FZIO6WNAAJMUA7P5");
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "audit"), &None, &true);
+    client.register_bls_key(&auditor, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+    assert_eq!(client.get_subject_type(&audited_protocol), SubjectType::Contract);
+    assert_eq!(client.get_subject_type(&account), SubjectType::Account);
+
+    let audit = create_delegated_attestation_request(&env, &auditor, 0, &schema_uid, &audited_protocol);
+    client.attest_by_delegation(&submitter, &audit);
+    let kyc = create_delegated_attestation_request(&env, &auditor, 1, &schema_uid, &account);
+    client.attest_by_delegation(&submitter, &kyc);
+
+    let all = client.list_schema_attestations(&schema_uid, &Bytes::new(&env), &10).uids;
+    assert_eq!(all.len(), 2);
+    let contracts =
+        client.list_schema_attestations_by_type(&schema_uid, &SubjectType::Contract, &Bytes::new(&env), &10);
+    let accounts =
+        client.list_schema_attestations_by_type(&schema_uid, &SubjectType::Account, &Bytes::new(&env), &10);
+    assert_eq!(contracts.uids, vec![&env, all.get(0).unwrap()]);
+    assert_eq!(accounts.uids, vec![&env, all.get(1).unwrap()]);
+    assert!(!contracts.has_more);
+    assert_eq!(
+        client.get_attestation_subject(&contracts.uids.get(0).unwrap()).address,
+        audited_protocol
+    );
+}
+
+/// **Test: Subject-Consented Attestations**
+///
+/// # Workflow
+/// 1. The schema authority requires subject consent
+/// 2. A delegated attestation about the subject is held pending and announced with a `PENDING` event
+/// 3. Only the subject can accept it, after which it is stored, listed and no longer pending
+/// 4. Lifting the requirement stores later attestations directly
+#[test]
+fn subject_consent_holds_attestations_until_accepted() {
+    use soroban_sdk::{vec, Bytes};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let employer = Address::generate(&env);
+    let employee = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "employment"), &None, &true);
+    client.register_bls_key(&employer, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+
+    let result = client.try_set_schema_requires_consent(&employer, &schema_uid, &true);
+    assert_eq!(result, Err(Ok(ProtocolError::NotAuthorized)));
+    client.set_schema_requires_consent(&admin, &schema_uid, &true);
+    assert!(client.is_schema_consent_required(&schema_uid));
+
+    let request = create_delegated_attestation_request(&env, &employer, 0, &schema_uid, &employee);
+    client.attest_by_delegation(&submitter, &request);
+    let last = env.events().all().last().unwrap();
+    let expected_topics = (symbol_short!("ATTEST"), symbol_short!("PENDING"), employee.clone()).into_val(&env);
+    assert_eq!(last.1, expected_topics);
+    let (uid, attester): (BytesN<32>, Address) = last.2.try_into_val(&env).unwrap();
+    assert_eq!(attester, employer);
+
+    assert_eq!(client.get_pending_attestation(&uid).subject, employee);
+    assert_eq!(client.try_get_attestation(&uid), Err(Ok(ProtocolError::AttestationNotFound)));
+    assert_eq!(client.get_attester_nonce(&employer), 1);
+
+    let result = client.try_accept_attestation(&employer, &uid);
+    assert_eq!(result, Err(Ok(ProtocolError::NotAuthorized)));
+
+    client.accept_attestation(&employee, &uid);
+    assert_eq!(client.get_attestation(&uid).attester, employer);
+    let listed = client.list_subject_attestations(&employee, &Bytes::new(&env), &10).uids;
+    assert_eq!(listed, vec![&env, uid.clone()]);
+    assert_eq!(client.try_get_pending_attestation(&uid), Err(Ok(ProtocolError::AttestationNotFound)));
+    let result = client.try_accept_attestation(&employee, &uid);
+    assert_eq!(result, Err(Ok(ProtocolError::AttestationNotFound)));
+
+    client.set_schema_requires_consent(&admin, &schema_uid, &false);
+    assert!(!client.is_schema_consent_required(&schema_uid));
+    let request = create_delegated_attestation_request(&env, &employer, 1, &schema_uid, &employee);
+    client.attest_by_delegation(&submitter, &request);
+    let listed = client.list_subject_attestations(&employee, &Bytes::new(&env), &10).uids;
+    assert_eq!(listed.len(), 2);
+}
+
+/// **Test: Accepting a Pending Attestation Repeats the Issuance Checks**
+///
+/// # Workflow
+/// 1. Three pending attestations carrying the same uniqueness key are held; pending
+///    attestations do not reserve the key
+/// 2. None can be accepted while the attester is banned
+/// 3. Once the first is accepted, the second fails with `UniquenessKeyTaken` until the
+///    first is revoked
+/// 4. After the schema is revoked, the third can no longer be accepted
+#[test]
+fn consent_acceptance_rechecks_ban_revocation_and_uniqueness() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let employer = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let employees = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "employment"), &None, &true);
+    client.set_schema_requires_consent(&admin, &schema_uid, &true);
+    client.set_schema_uniqueness_field(&admin, &schema_uid, &Some(SorobanString::from_str(&env, "key")));
+    client.register_bls_key(&employer, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+
+    for (nonce, employee) in employees.iter().enumerate() {
+        let request = create_delegated_attestation_request(&env, &employer, nonce as u64, &schema_uid, employee);
+        client.attest_by_delegation(&submitter, &request);
+    }
+    let [first, second, third] = [0, 1, 2]
+        .map(|nonce| protocol::utils::generate_attestation_uid(&env, &schema_uid, &employees[nonce], nonce as u64));
+
+    client.ban_attester(&admin, &employer);
+    let result = client.try_accept_attestation(&employees[0], &first);
+    assert_eq!(result, Err(Ok(ProtocolError::AttesterBanned)));
+    client.unban_attester(&admin, &employer);
+
+    client.accept_attestation(&employees[0], &first);
+    let result = client.try_accept_attestation(&employees[1], &second);
+    assert_eq!(result, Err(Ok(ProtocolError::UniquenessKeyTaken)));
+    assert_eq!(client.get_pending_attestation(&second).subject, employees[1]);
+
+    client.revoke(&employer, &first);
+    client.accept_attestation(&employees[1], &second);
+    assert!(client.is_attestation_valid(&second));
+
+    client.revoke_all(&admin, &schema_uid, &SorobanString::from_str(&env, "discontinued"));
+    let result = client.try_accept_attestation(&employees[2], &third);
+    assert_eq!(result, Err(Ok(ProtocolError::SchemaRevoked)));
+}
+
+/// **Test: Subjects Revoke Attestations About Themselves on Subject-Revocable Schemas**
+///
+/// # Workflow
+/// 1. A delegated attestation about a subject is issued under a non-revocable schema
+/// 2. Neither the attester nor the subject can revoke it
+/// 3. Once the authority marks the schema subject-revocable, the subject revokes it with `revoke`
+/// 4. Third parties remain unauthorized
+#[test]
+fn subject_revocable_schemas_let_subjects_revoke() {
+    use soroban_sdk::Bytes;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "profile"), &None, &false);
+    client.register_bls_key(&attester, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+    for nonce in 0..2 {
+        let request = create_delegated_attestation_request(&env, &attester, nonce, &schema_uid, &subject);
+        client.attest_by_delegation(&submitter, &request);
+    }
+    let uids = client.list_subject_attestations(&subject, &Bytes::new(&env), &10).uids;
+    let (first, second) = (uids.get(0).unwrap(), uids.get(1).unwrap());
+
+    assert_eq!(client.try_revoke(&attester, &first), Err(Ok(ProtocolError::AttestationNotRevocable)));
+    assert_eq!(client.try_revoke(&subject, &first), Err(Ok(ProtocolError::NotAuthorized)));
+
+    let result = client.try_set_schema_subject_revocable(&attester, &schema_uid, &true);
+    assert_eq!(result, Err(Ok(ProtocolError::NotAuthorized)));
+    client.set_schema_subject_revocable(&admin, &schema_uid, &true);
+    assert!(client.is_schema_subject_revocable(&schema_uid));
+
+    assert_eq!(client.try_revoke(&stranger, &first), Err(Ok(ProtocolError::NotAuthorized)));
+    assert_eq!(client.try_revoke(&attester, &first), Err(Ok(ProtocolError::AttestationNotRevocable)));
+    client.revoke(&subject, &first);
+    assert!(client.get_attestation(&first).revoked);
+
+    client.set_schema_subject_revocable(&admin, &schema_uid, &false);
+    assert_eq!(client.try_revoke(&subject, &second), Err(Ok(ProtocolError::NotAuthorized)));
+}
+
+/// **Test: Revocation Appeals Within the Challenge Window**
+///
+/// # Workflow
+/// 1. Without a challenge window, revocations cannot be appealed
+/// 2. The subject appeals a revocation within the window; the attestation is disputed
+/// 3. The schema authority confirms one revocation, which cannot be appealed again
+/// 4. The authority reinstates another attestation, which becomes valid again
+/// 5. Appeals after the window closes are rejected
+#[test]
+fn subjects_appeal_revocations_within_challenge_window() {
+    use soroban_sdk::Bytes;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let lender = Address::generate(&env);
+    let borrower = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "credit"), &None, &true);
+    client.register_bls_key(&lender, &BytesN::from_array(&env, &TEST_BLS_G2_PUBLIC_KEY));
+    for nonce in 0..3 {
+        let request = create_delegated_attestation_request(&env, &lender, nonce, &schema_uid, &borrower);
+        client.attest_by_delegation(&submitter, &request);
+    }
+    let uids = client.list_subject_attestations(&borrower, &Bytes::new(&env), &10).uids;
+    let (confirmed, reinstated, late) = (uids.get(0).unwrap(), uids.get(1).unwrap(), uids.get(2).unwrap());
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.revoke(&lender, &confirmed);
+    assert_eq!(client.try_appeal(&borrower, &confirmed), Err(Ok(ProtocolError::AppealNotAllowed)));
+
+    client.set_schema_challenge_window(&admin, &schema_uid, &500);
+    assert_eq!(client.get_schema_challenge_window(&schema_uid), 500);
+    assert_eq!(client.try_appeal(&borrower, &reinstated), Err(Ok(ProtocolError::AppealNotAllowed)));
+    assert_eq!(client.try_appeal(&lender, &confirmed), Err(Ok(ProtocolError::NotAuthorized)));
+
+    client.appeal(&borrower, &confirmed);
+    let last = env.events().all().last().unwrap();
+    assert_eq!(last.1, (symbol_short!("ATTEST"), symbol_short!("APPEAL")).into_val(&env));
+    assert!(client.get_attestation_status(&confirmed).is_disputed);
+    assert_eq!(client.get_attestation_appeal(&confirmed).unwrap().appellant, borrower);
+
+    let result = client.try_resolve_appeal(&lender, &confirmed, &true);
+    assert_eq!(result, Err(Ok(ProtocolError::NotAuthorized)));
+    client.resolve_appeal(&admin, &confirmed, &false);
+    let last = env.events().all().last().unwrap();
+    assert_eq!(last.1, (symbol_short!("ATTEST"), symbol_short!("UPHELD")).into_val(&env));
+    let status = client.get_attestation_status(&confirmed);
+    assert!(status.is_revoked && !status.is_disputed);
+    assert_eq!(client.try_appeal(&borrower, &confirmed), Err(Ok(ProtocolError::AppealNotAllowed)));
+    let result = client.try_resolve_appeal(&admin, &confirmed, &true);
+    assert_eq!(result, Err(Ok(ProtocolError::NoPendingAppeal)));
+
+    client.revoke(&lender, &reinstated);
+    client.revoke(&lender, &late);
+    assert_eq!(client.get_schema_stats(&schema_uid).active, 0);
+    env.ledger().with_mut(|li| li.timestamp = 1_500);
+    client.appeal(&borrower, &reinstated);
+    client.resolve_appeal(&admin, &reinstated, &true);
+    let last = env.events().all().last().unwrap();
+    assert_eq!(last.1, (symbol_short!("ATTEST"), symbol_short!("REINSTATE")).into_val(&env));
+    assert!(!client.get_attestation(&reinstated).revoked);
+    assert!(client.get_attestation_appeal(&reinstated).is_none());
+    assert_eq!(client.get_schema_stats(&schema_uid).active, 1);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_501);
+    assert_eq!(client.try_appeal(&borrower, &late), Err(Ok(ProtocolError::AppealNotAllowed)));
+}
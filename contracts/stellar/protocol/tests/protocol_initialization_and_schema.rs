@@ -1,8 +1,17 @@
-use protocol::{state::Schema, utils::create_xdr_string, AttestationContract, AttestationContractClient};
+use protocol::{
+    errors::Error,
+    instructions::{migration::STORAGE_VERSION, schema::METRICS_HEARTBEAT_INTERVAL},
+    state::{
+        AdminCouncil, AdminOperation, DataKey, GovernanceKey, ListingKey, ProtocolMetrics, Schema, SchemaDefinition,
+        SchemaField,
+    },
+    xdr_codec::{decode_schema, encode_schema},
+    AttestationContract, AttestationContractClient,
+};
 use soroban_sdk::{
     symbol_short,
-    testutils::{Address as _, Events, MockAuth, MockAuthInvoke},
-    Address, BytesN, Env, IntoVal, String as SorobanString, TryIntoVal,
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    vec, Address, Bytes, BytesN, Env, IntoVal, String as SorobanString, TryIntoVal,
 };
 
 struct SchemaRegistrationParams {
@@ -12,6 +21,26 @@ struct SchemaRegistrationParams {
     revocable: bool,
 }
 
+fn certificate_definition(env: &Env) -> SchemaDefinition {
+    SchemaDefinition {
+        name: SorobanString::from_str(env, "Certificate"),
+        description: SorobanString::from_str(env, "Revocable_Certificate_Schema"),
+        fields: soroban_sdk::vec![
+            env,
+            SchemaField {
+                name: SorobanString::from_str(env, "certificate_type"),
+                field_type: SorobanString::from_str(env, "string"),
+                optional: false,
+            },
+            SchemaField {
+                name: SorobanString::from_str(env, "issued_date"),
+                field_type: SorobanString::from_str(env, "u64"),
+                optional: true,
+            },
+        ],
+    }
+}
+
 /*
  * Comprehensive test for contract initialization and schema registration
  *
@@ -58,14 +87,7 @@ fn initialize_and_register_schema() {
 		},
 		SchemaRegistrationParams {
 			name: "schema_xdr_with_revocable",
-			schema_definition: format!(
-				"{}{}",
-				"XDR:",
-				create_xdr_string(
-					&env,
-					&SorobanString::from_str(&env, r#"{"name":"Certificate","version":"1.5","description":"Revocable_Certificate_Schema","fields":[{"name":"certificate_type","type":"string"},{"name":"issued_date","type":"number"}]}"#),
-				).to_string()
-			),
+			schema_definition: encode_schema(&env, &certificate_definition(&env)).to_string(),
 			resolver: None,
 			revocable: true,
 		},
@@ -116,3 +138,721 @@ fn initialize_and_register_schema() {
         assert_eq!(event_data.1.revocable, case.revocable);
     }
 }
+
+/*
+ * Schema statistics are maintained incrementally
+ *
+ * Verifies that `get_schema_stats`:
+ * - Starts at zero for a freshly registered schema
+ * - Counts issuance, revocation, active attestations and distinct subjects
+ * - Tracks the timestamp of the most recent issuance
+ * - Fails with `SchemaNotFound` for unknown schemas
+ */
+#[test]
+fn schema_stats_track_attestations_and_revocations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "stats"), &None, &true);
+
+    let stats = client.get_schema_stats(&schema_uid);
+    assert_eq!(stats.issued, 0);
+    assert_eq!(stats.unique_subjects, 0);
+    assert_eq!(stats.last_issued_at, 0);
+
+    let value = SorobanString::from_str(&env, "{\"k\":\"v\"}");
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let first = client.attest(&alice, &schema_uid, &value, &None);
+    client.attest(&alice, &schema_uid, &value, &None);
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.attest(&bob, &schema_uid, &value, &None);
+    client.revoke(&alice, &first);
+
+    let stats = client.get_schema_stats(&schema_uid);
+    assert_eq!(stats.issued, 3);
+    assert_eq!(stats.revoked, 1);
+    assert_eq!(stats.active, 2);
+    assert_eq!(stats.unique_subjects, 2);
+    assert_eq!(stats.last_issued_at, 2_000);
+
+    let unknown = BytesN::from_array(&env, &[9u8; 32]);
+    assert_eq!(client.try_get_schema_stats(&unknown), Err(Ok(Error::SchemaNotFound)));
+}
+
+/*
+ * Per-schema attestation count and index getters
+ *
+ * Verifies that:
+ * - `get_schema_attestation_count` matches the schema's `issued` counter, revocations included
+ * - `get_attestations_by_schema` pages through the same UIDs as `list_schema_attestations`
+ * - The count fails with `SchemaNotFound` for unknown schemas
+ */
+#[test]
+fn schema_attestation_count_and_index_getters() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "indexed"), &None, &true);
+    assert_eq!(client.get_schema_attestation_count(&schema_uid), 0);
+
+    let value = SorobanString::from_str(&env, "{\"k\":\"v\"}");
+    let first = client.attest(&alice, &schema_uid, &value, &None);
+    let second = client.attest(&alice, &schema_uid, &value, &None);
+    let third = client.attest(&alice, &schema_uid, &value, &None);
+    client.revoke(&alice, &second);
+    assert_eq!(client.get_schema_attestation_count(&schema_uid), 3);
+
+    let page = client.get_attestations_by_schema(&schema_uid, &Bytes::new(&env), &2);
+    assert_eq!(
+        page,
+        client.list_schema_attestations(&schema_uid, &Bytes::new(&env), &2)
+    );
+    assert_eq!(page.uids, vec![&env, first, second]);
+    assert!(page.has_more);
+    let rest = client.get_attestations_by_schema(&schema_uid, &page.next_cursor, &2);
+    assert_eq!(rest.uids, vec![&env, third]);
+    assert!(!rest.has_more);
+
+    let unknown = BytesN::from_array(&env, &[9u8; 32]);
+    assert_eq!(
+        client.try_get_schema_attestation_count(&unknown),
+        Err(Ok(Error::SchemaNotFound))
+    );
+}
+
+/*
+ * Protocol metrics heartbeat
+ *
+ * Verifies that:
+ * - A `METRICS` event is published on every `METRICS_HEARTBEAT_INTERVAL`th attestation only
+ * - It reports total attestations, revocations, registered schemas and schemas with active attestations
+ */
+#[test]
+fn metrics_heartbeat_every_interval_attestations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+
+    client.initialize(&admin);
+    let busy = client.register(&admin, &SorobanString::from_str(&env, "busy"), &None, &true);
+    let quiet = client.register(&admin, &SorobanString::from_str(&env, "quiet"), &None, &true);
+    client.register(&admin, &SorobanString::from_str(&env, "unused"), &None, &true);
+
+    let metrics_events = |env: &Env| {
+        env.events()
+            .all()
+            .iter()
+            .filter(|(_, topics, _)| *topics == (symbol_short!("METRICS"),).into_val(env))
+            .count()
+    };
+
+    let value = SorobanString::from_str(&env, "{\"k\":\"v\"}");
+    let quiet_uid = client.attest(&attester, &quiet, &value, &None);
+    client.revoke(&attester, &quiet_uid);
+    for _ in 1..METRICS_HEARTBEAT_INTERVAL - 1 {
+        client.attest(&attester, &busy, &value, &None);
+        assert_eq!(metrics_events(&env), 0);
+    }
+
+    client.attest(&attester, &busy, &value, &None);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == (symbol_short!("METRICS"),).into_val(&env))
+        .unwrap();
+    let metrics: ProtocolMetrics = data.try_into_val(&env).unwrap();
+    assert_eq!(
+        metrics,
+        ProtocolMetrics {
+            total_attestations: METRICS_HEARTBEAT_INTERVAL,
+            total_revocations: 1,
+            total_schemas: 3,
+            active_schemas: 1,
+        }
+    );
+}
+
+/*
+ * Protocol metrics in persistent storage
+ *
+ * Verifies that:
+ * - Metrics stay exact across a long idle period between heartbeats
+ * - Counts that fall between heartbeats are not lost
+ */
+#[test]
+fn metrics_survive_long_idle_periods() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+    env.ledger().with_mut(|li| {
+        li.min_temp_entry_ttl = 16;
+        li.min_persistent_entry_ttl = 1_000_000;
+        li.max_entry_ttl = 1_000_000;
+    });
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "busy"), &None, &true);
+
+    let value = SorobanString::from_str(&env, "{\"k\":\"v\"}");
+    for _ in 0..METRICS_HEARTBEAT_INTERVAL - 1 {
+        client.attest(&attester, &schema_uid, &value, &None);
+    }
+    client.register(&admin, &SorobanString::from_str(&env, "late"), &None, &true);
+
+    // A long idle stretch between heartbeats must not drop the counts recorded so far
+    env.ledger().with_mut(|li| li.sequence_number += 100_000);
+    for _ in 0..METRICS_HEARTBEAT_INTERVAL + 1 {
+        client.attest(&attester, &schema_uid, &value, &None);
+    }
+
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == (symbol_short!("METRICS"),).into_val(&env))
+        .unwrap();
+    let metrics: ProtocolMetrics = data.try_into_val(&env).unwrap();
+    assert_eq!(
+        metrics,
+        ProtocolMetrics {
+            total_attestations: 2 * METRICS_HEARTBEAT_INTERVAL,
+            total_revocations: 0,
+            total_schemas: 2,
+            active_schemas: 1,
+        }
+    );
+}
+
+/*
+ * Multi-admin threshold for critical operations
+ *
+ * Verifies that:
+ * - The admin alone forms a 1-of-1 council whose proposals execute immediately
+ * - Once a 2-of-3 council is set, proposals wait for a second member's approval
+ * - Non-members cannot propose or approve, and repeated approvals are not double counted
+ * - Invalid councils are rejected
+ */
+#[test]
+fn admin_council_requires_threshold_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let (alice, bob, carol) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+    let new_admin = Address::generate(&env);
+
+    client.initialize(&admin);
+    assert_eq!(client.get_admin_council().members, vec![&env, admin.clone()]);
+
+    let members = vec![&env, alice.clone(), bob.clone(), carol.clone()];
+    client.propose_admin_operation(&admin, &AdminOperation::SetCouncil(members.clone(), 2));
+    assert_eq!(client.get_admin_council(), AdminCouncil { members, threshold: 2 });
+
+    let set_admin = AdminOperation::SetAdmin(new_admin.clone());
+    let result = client.try_propose_admin_operation(&admin, &set_admin);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+    let result = client.try_propose_admin_operation(
+        &alice,
+        &AdminOperation::SetCouncil(vec![&env, alice.clone(), alice.clone()], 1),
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidAdminCouncil)));
+
+    let proposal_id = client.propose_admin_operation(&alice, &set_admin);
+    assert_eq!(
+        client.get_admin_proposal(&proposal_id).approvals,
+        vec![&env, alice.clone()]
+    );
+    let result = client.try_approve_admin_operation(&admin, &proposal_id);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+    assert!(!client.approve_admin_operation(&alice, &proposal_id));
+    assert_eq!(client.get_config().admin, Some(admin.clone()));
+
+    assert!(client.approve_admin_operation(&bob, &proposal_id));
+    assert_eq!(client.get_config().admin, Some(new_admin));
+    let result = client.try_approve_admin_operation(&carol, &proposal_id);
+    assert_eq!(result, Err(Ok(Error::ProposalNotFound)));
+}
+
+/*
+ * Two-step admin transfer
+ *
+ * Verifies that:
+ * - Proposing a new admin leaves the admin unchanged until the proposed address accepts
+ * - Accepting requires the pending admin's authorization and publishes the transfer
+ * - Only the admin can propose, and not once a council governs admin changes
+ * - Setting a council cancels a pending transfer, which can then not be accepted
+ */
+#[test]
+fn admin_transfer_requires_acceptance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    assert_eq!(client.try_accept_admin(), Err(Ok(Error::NoPendingAdmin)));
+    assert_eq!(
+        client.try_propose_admin(&new_admin, &new_admin),
+        Err(Ok(Error::NotAuthorized))
+    );
+
+    client.propose_admin(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    assert_eq!(client.get_config().admin, Some(admin.clone()));
+
+    assert_eq!(client.accept_admin(), new_admin);
+    assert_eq!(env.auths()[0].0, new_admin);
+    assert_eq!(
+        env.events().all().slice(env.events().all().len() - 1..),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (symbol_short!("ADMIN"), symbol_short!("ACCEPT")).into_val(&env),
+                (admin.clone(), new_admin.clone()).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.get_config().admin, Some(new_admin.clone()));
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(client.try_propose_admin(&admin, &admin), Err(Ok(Error::NotAuthorized)));
+
+    // A transfer pending when the council is set can no longer be accepted
+    client.propose_admin(&new_admin, &admin);
+    let council = vec![&env, new_admin.clone(), admin.clone()];
+    client.propose_admin_operation(&new_admin, &AdminOperation::SetCouncil(council, 1));
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(client.try_accept_admin(), Err(Ok(Error::NotAuthorized)));
+    assert_eq!(client.get_config().admin, Some(new_admin.clone()));
+    assert_eq!(
+        client.try_propose_admin(&new_admin, &admin),
+        Err(Ok(Error::NotAuthorized))
+    );
+}
+
+/*
+ * Upgrades and storage migration
+ *
+ * Verifies that:
+ * - A new deployment starts at the current storage version
+ * - Deployments from before storage versions were recorded migrate to the current one once
+ * - Only the admin can upgrade or migrate, and upgrades go through the council once configured
+ */
+#[test]
+fn upgrade_and_migrate_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    client.initialize(&admin);
+    assert_eq!(client.get_storage_version(), STORAGE_VERSION);
+
+    assert_eq!(client.try_migrate_storage(&outsider), Err(Ok(Error::NotAuthorized)));
+    let wasm_hash = BytesN::from_array(&env, &[7; 32]);
+    assert_eq!(client.try_upgrade(&outsider, &wasm_hash), Err(Ok(Error::NotAuthorized)));
+
+    // Simulate a deployment initialized before storage versions were recorded
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .remove(&DataKey::Governance(GovernanceKey::StorageVersion));
+    });
+    assert_eq!(client.get_storage_version(), 0);
+
+    assert_eq!(client.migrate_storage(&admin), STORAGE_VERSION);
+    assert_eq!(
+        env.events().all().slice(env.events().all().len() - 1..),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (symbol_short!("CONTRACT"), symbol_short!("MIGRATE")).into_val(&env),
+                (admin.clone(), 0u32, STORAGE_VERSION).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.get_storage_version(), STORAGE_VERSION);
+
+    assert_eq!(client.migrate_storage(&admin), STORAGE_VERSION);
+
+    let council = vec![&env, admin.clone(), outsider.clone()];
+    client.propose_admin_operation(&admin, &AdminOperation::SetCouncil(council, 2));
+    assert_eq!(client.try_upgrade(&admin, &wasm_hash), Err(Ok(Error::NotAuthorized)));
+}
+
+/*
+ * Pausing under an admin council
+ *
+ * Verifies that:
+ * - Once a council is configured, the admin can no longer pause or unpause directly
+ * - Pause and unpause proposals take effect once the council threshold approves them
+ */
+#[test]
+fn council_governs_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let member = Address::generate(&env);
+    client.initialize(&admin);
+
+    let council = vec![&env, admin.clone(), member.clone()];
+    client.propose_admin_operation(&admin, &AdminOperation::SetCouncil(council, 2));
+    assert_eq!(client.try_pause(&admin), Err(Ok(Error::NotAuthorized)));
+    assert!(!client.is_paused());
+
+    let proposal_id = client.propose_admin_operation(&admin, &AdminOperation::Pause);
+    assert!(!client.is_paused());
+    assert!(client.approve_admin_operation(&member, &proposal_id));
+    assert!(client.is_paused());
+    assert_eq!(client.try_unpause(&admin), Err(Ok(Error::NotAuthorized)));
+
+    let proposal_id = client.propose_admin_operation(&member, &AdminOperation::Unpause);
+    assert!(client.approve_admin_operation(&admin, &proposal_id));
+    assert!(!client.is_paused());
+}
+
+/*
+ * Namespaced schema registration
+ *
+ * Verifies that:
+ * - The same definition registered in two namespaces and globally yields three distinct UIDs
+ * - Each namespace lists only its own schemas
+ * - Namespaced schema and attestation lookups reject UIDs from other namespaces
+ */
+#[test]
+fn register_schemas_in_namespaces() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let authority = Address::generate(&env);
+    client.initialize(&admin);
+
+    let acme = symbol_short!("acme");
+    let globex = symbol_short!("globex");
+    let definition = SorobanString::from_str(&env, "employee");
+
+    let acme_uid = client.register_in_namespace(&authority, &acme, &definition, &None, &true);
+    let globex_uid = client.register_in_namespace(&authority, &globex, &definition, &None, &true);
+    let global_uid = client.register(&authority, &definition, &None, &true);
+    assert_ne!(acme_uid, globex_uid);
+    assert_ne!(acme_uid, global_uid);
+    assert_ne!(globex_uid, global_uid);
+
+    // Re-registering does not duplicate the listing
+    client.register_in_namespace(&authority, &acme, &definition, &None, &true);
+    let acme_schemas = client.get_namespace_schemas(&acme, &0, &10);
+    assert_eq!(acme_schemas.len(), 1);
+    assert_eq!(acme_schemas.get(0).unwrap(), acme_uid);
+    assert_eq!(client.get_namespace_schemas(&globex, &0, &10).len(), 1);
+    assert_eq!(client.get_namespace_schemas(&acme, &1, &10).len(), 0);
+
+    assert_eq!(
+        client.get_namespaced_schema(&acme, &acme_uid).namespace,
+        Some(acme.clone())
+    );
+    assert_eq!(
+        client.try_get_namespaced_schema(&acme, &globex_uid),
+        Err(Ok(Error::SchemaNotFound))
+    );
+    assert_eq!(
+        client.try_get_namespaced_schema(&acme, &global_uid),
+        Err(Ok(Error::SchemaNotFound))
+    );
+
+    let value = SorobanString::from_str(&env, "{\"k\":\"v\"}");
+    let attestation_uid = client.attest(&authority, &acme_uid, &value, &None);
+    assert_eq!(
+        client.get_namespaced_attestation(&acme, &attestation_uid).schema_uid,
+        acme_uid
+    );
+    assert_eq!(
+        client.try_get_namespaced_attestation(&globex, &attestation_uid),
+        Err(Ok(Error::AttestationNotFound))
+    );
+}
+
+/*
+ * XDR schema definitions are validated at registration and decodable via a view.
+ *
+ * Covers:
+ * - encode/decode round trip through the codec and the `encode_schema` view
+ * - decoding a definition produced by the TypeScript SDK's `SorobanSchemaEncoder`
+ * - rejection of malformed `XDR:` definitions at registration
+ * - `get_schema_definition` for XDR and non-XDR schemas
+ */
+#[test]
+fn xdr_schema_definitions_are_validated_and_decodable() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let authority = Address::generate(&env);
+    client.initialize(&Address::generate(&env));
+
+    let definition = certificate_definition(&env);
+    let encoded = encode_schema(&env, &definition);
+    assert!(encoded.to_string().starts_with("XDR:AAAAEQ"));
+    assert_eq!(client.encode_schema(&definition), encoded);
+    assert_eq!(decode_schema(&env, &encoded), Ok(definition.clone()));
+
+    let schema_uid = client.register(&authority, &encoded, &None, &true);
+    assert_eq!(client.get_schema_definition(&schema_uid), definition);
+
+    // Produced by the SDK: unsorted keys, extra `version` key, field `optional` flags
+    let sdk_encoded = SorobanString::from_str(
+        &env,
+        "XDR:AAAAEQAAAAEAAAAEAAAADwAAAARuYW1lAAAADgAAABdEeW5hbWljIFNjaGVtYSA2ZDA1NmIzOAAAAAAPAAAAB3ZlcnNpb24AAAAADgAAAAMxLjAAAAAADwAAAAtkZXNjcmlwdGlvbgAAAAAOAAAAI1Rlc3Qgc2NoZW1hIGZvciBpbnRlZ3JhdGlvbiB0ZXN0aW5nAAAAAA8AAAAGZmllbGRzAAAAAAAQAAAAAQAAAAMAAAARAAAAAQAAAAMAAAAPAAAABG5hbWUAAAAOAAAACHZlcmlmaWVkAAAADwAAAAR0eXBlAAAADgAAAARib29sAAAADwAAAAhvcHRpb25hbAAAAAAAAAAAAAAAEQAAAAEAAAADAAAADwAAAARuYW1lAAAADgAAAAVzY29yZQAAAAAAAA8AAAAEdHlwZQAAAA4AAAADdTY0AAAAAA8AAAAIb3B0aW9uYWwAAAAAAAAAAAAAABEAAAABAAAAAwAAAA8AAAAEbmFtZQAAAA4AAAAIbWV0YWRhdGEAAAAPAAAABHR5cGUAAAAOAAAABnN0cmluZwAAAAAADwAAAAhvcHRpb25hbAAAAAAAAAAA",
+    );
+    let sdk_definition = decode_schema(&env, &sdk_encoded).unwrap();
+    assert_eq!(
+        sdk_definition.name,
+        SorobanString::from_str(&env, "Dynamic Schema 6d056b38")
+    );
+    assert_eq!(sdk_definition.fields.len(), 3);
+    assert_eq!(
+        sdk_definition.fields.get(1).unwrap().field_type,
+        SorobanString::from_str(&env, "u64")
+    );
+
+    for malformed in ["XDR:", "XDR:not base64!", "XDR:AAAAAw=="] {
+        let result = client.try_register(&authority, &SorobanString::from_str(&env, malformed), &None, &true);
+        assert_eq!(result, Err(Ok(Error::InvalidSchemaDefinition)));
+    }
+
+    let json_uid = client.register(
+        &authority,
+        &SorobanString::from_str(&env, "{\"name\":\"Json\"}"),
+        &None,
+        &true,
+    );
+    assert_eq!(
+        client.try_get_schema_definition(&json_uid),
+        Err(Ok(Error::InvalidSchemaDefinition))
+    );
+}
+
+#[test]
+fn config_snapshot_reflects_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+
+    let config = client.get_config();
+    assert_eq!(config.admin, None);
+    assert_eq!(config.cleanup_bounty_token, None);
+    assert_eq!(config.version, SorobanString::from_str(&env, env!("CARGO_PKG_VERSION")));
+    assert_eq!(config.limits.max_external_id_len, 64);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    assert_eq!(client.get_config().admin, Some(admin));
+}
+
+/*
+ * Protocol self-check
+ *
+ * Verifies that:
+ * - An uninitialized contract fails the admin and council checks
+ * - A healthy contract with revocations passes every check
+ * - A listing counter out of step with its entries is reported
+ */
+#[test]
+fn self_check_reports_broken_invariants() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+
+    assert_eq!(
+        client.self_check(),
+        vec![&env, symbol_short!("ADMIN"), symbol_short!("COUNCIL")]
+    );
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&admin, &SorobanString::from_str(&env, "health"), &None, &true);
+    let value = SorobanString::from_str(&env, "{\"k\":\"v\"}");
+    let revoked = client.attest(&attester, &schema_uid, &value, &None);
+    client.attest(&attester, &schema_uid, &value, &Some(env.ledger().timestamp() + 10));
+    client.revoke(&attester, &revoked);
+    assert_eq!(client.self_check(), vec![&env]);
+
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ListingCount(ListingKey::Schema(schema_uid.clone())), &1_u32);
+    });
+    assert_eq!(client.self_check(), vec![&env, symbol_short!("LISTING")]);
+}
+
+/*
+ * Schema compatibility check
+ *
+ * Verifies that:
+ * - Adding a field and making an optional field required is additive
+ * - Removing, retyping and relaxing fields is breaking
+ * - Proposed definitions with repeated field names fail linting
+ */
+#[test]
+fn schema_compatibility_classifies_field_changes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let authority = Address::generate(&env);
+    client.initialize(&Address::generate(&env));
+    let schema_uid = client.register(
+        &authority,
+        &encode_schema(&env, &certificate_definition(&env)),
+        &None,
+        &true,
+    );
+
+    let field = |name: &str, field_type: &str, optional: bool| SchemaField {
+        name: SorobanString::from_str(&env, name),
+        field_type: SorobanString::from_str(&env, field_type),
+        optional,
+    };
+    let successor = |fields: soroban_sdk::Vec<SchemaField>| {
+        let mut definition = certificate_definition(&env);
+        definition.fields = fields;
+        encode_schema(&env, &definition)
+    };
+
+    let additive = client.check_schema_compatibility(
+        &schema_uid,
+        &successor(vec![
+            &env,
+            field("certificate_type", "string", false),
+            field("issued_date", "u64", false),
+            field("issuer", "address", true),
+        ]),
+    );
+    assert!(!additive.breaking);
+    assert_eq!(additive.added, vec![&env, SorobanString::from_str(&env, "issuer")]);
+    assert_eq!(
+        additive.tightened,
+        vec![&env, SorobanString::from_str(&env, "issued_date")]
+    );
+
+    let breaking = client.check_schema_compatibility(
+        &schema_uid,
+        &successor(vec![&env, field("certificate_type", "symbol", true)]),
+    );
+    assert!(breaking.breaking);
+    assert_eq!(
+        breaking.removed,
+        vec![&env, SorobanString::from_str(&env, "issued_date")]
+    );
+    assert_eq!(
+        breaking.retyped,
+        vec![&env, SorobanString::from_str(&env, "certificate_type")]
+    );
+    assert_eq!(
+        breaking.relaxed,
+        vec![&env, SorobanString::from_str(&env, "certificate_type")]
+    );
+    assert!(breaking.added.is_empty());
+
+    let repeated = successor(vec![
+        &env,
+        field("certificate_type", "string", false),
+        field("certificate_type", "u64", true),
+    ]);
+    assert_eq!(
+        client.try_check_schema_compatibility(&schema_uid, &repeated),
+        Err(Ok(Error::InvalidSchemaDefinition))
+    );
+}
+
+/*
+ * Schema versions form a linear lineage from the first version.
+ *
+ * Verifies that:
+ * - A new version links to the one it supersedes and inherits its settings
+ * - The version chain is returned first version first from any member
+ * - Only the latest version can be superseded, and only by the schema authority
+ * - Attestations record the version of the schema they were issued under
+ */
+#[test]
+fn schema_versions_form_a_lineage() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let authority = Address::generate(&env);
+    client.initialize(&Address::generate(&env));
+
+    let v1 = client.register(&authority, &SorobanString::from_str(&env, "badge-v1"), &None, &false);
+    assert_eq!(client.get_schema_versions(&v1), vec![&env, v1.clone()]);
+    let first = client.get_schema(&v1);
+    assert_eq!((first.version, first.previous_uid), (1, None));
+
+    let v2 = client.register_schema_version(&authority, &v1, &SorobanString::from_str(&env, "badge-v2"));
+    let v3 = client.register_schema_version(&authority, &v2, &SorobanString::from_str(&env, "badge-v3"));
+    let third = client.get_schema(&v3);
+    assert_eq!((third.version, third.previous_uid), (3, Some(v2.clone())));
+    assert!(!third.revocable);
+
+    let lineage = vec![&env, v1.clone(), v2.clone(), v3.clone()];
+    assert_eq!(client.get_schema_versions(&v1), lineage);
+    assert_eq!(client.get_schema_versions(&v3), lineage);
+
+    assert_eq!(
+        client.try_register_schema_version(&authority, &v2, &SorobanString::from_str(&env, "badge-v3b")),
+        Err(Ok(Error::SchemaSuperseded))
+    );
+    assert_eq!(
+        client.try_register_schema_version(&authority, &v3, &SorobanString::from_str(&env, "badge-v1")),
+        Err(Ok(Error::SchemaAlreadyRegistered))
+    );
+    assert_eq!(
+        client.try_register_schema_version(
+            &Address::generate(&env),
+            &v3,
+            &SorobanString::from_str(&env, "badge-v4")
+        ),
+        Err(Ok(Error::NotAuthorized))
+    );
+
+    let value = SorobanString::from_str(&env, "{\"k\":\"v\"}");
+    let v1_attestation = client.attest(&authority, &v1, &value, &None);
+    let v3_attestation = client.attest(&authority, &v3, &value, &None);
+    assert_eq!(client.get_attestation(&v1_attestation).schema_version, 1);
+    assert_eq!(client.get_attestation(&v3_attestation).schema_version, 3);
+}
@@ -0,0 +1,88 @@
+use protocol::{
+    instructions::outbox::OUTBOX_CAPACITY, state::OutboxAction, AttestationContract, AttestationContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String as SorobanString};
+
+fn setup(env: &Env) -> (AttestationContractClient<'_>, Address, BytesN<32>) {
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let attester = Address::generate(env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&attester, &SorobanString::from_str(env, "outbox-schema"), &None, &true);
+
+    (client, attester, schema_uid)
+}
+
+/// **Test: Outbox Records Attestations and Revocations in Order**
+/// - Verifies every attest/revoke appends an entry with an increasing sequence number
+/// - Verifies `since_seq` only returns entries the caller has not yet processed
+#[test]
+fn test_outbox_records_attestations_and_revocations() {
+    let env = Env::default();
+    let (client, attester, schema_uid) = setup(&env);
+
+    assert_eq!(client.get_outbox(&0).len(), 0);
+
+    let value = SorobanString::from_str(&env, "{\"k\":\"v\"}");
+    let first_uid = client.attest(&attester, &schema_uid, &value, &None);
+    let second_uid = client.attest(&attester, &schema_uid, &value, &None);
+    client.revoke(&attester, &first_uid);
+
+    let entries = client.get_outbox(&0);
+    assert_eq!(entries.len(), 3);
+
+    let first = entries.get(0).unwrap();
+    assert_eq!(first.seq, 1);
+    assert_eq!(first.action, OutboxAction::Attested);
+    assert_eq!(first.attestation_uid, first_uid);
+    assert_eq!(first.schema_uid, schema_uid);
+    assert_eq!(first.attester, attester);
+
+    let second = entries.get(1).unwrap();
+    assert_eq!(second.seq, 2);
+    assert_eq!(second.action, OutboxAction::Attested);
+    assert_eq!(second.attestation_uid, second_uid);
+
+    let third = entries.get(2).unwrap();
+    assert_eq!(third.seq, 3);
+    assert_eq!(third.action, OutboxAction::Revoked);
+    assert_eq!(third.attestation_uid, first_uid);
+
+    // Catch up from the last processed sequence
+    let newer = client.get_outbox(&2);
+    assert_eq!(newer.len(), 1);
+    assert_eq!(newer.get(0).unwrap().seq, 3);
+    assert_eq!(client.get_outbox(&3).len(), 0);
+    assert_eq!(client.get_outbox(&100).len(), 0);
+}
+
+/// **Test: Outbox Overwrites the Oldest Entries Once Full**
+/// - Verifies only the most recent `OUTBOX_CAPACITY` entries are retained
+/// - Verifies a lagging poller can detect the gap from the first returned sequence
+#[test]
+fn test_outbox_wraps_around_when_full() {
+    let env = Env::default();
+    let (client, attester, schema_uid) = setup(&env);
+
+    let value = SorobanString::from_str(&env, "{\"k\":\"v\"}");
+    let total = OUTBOX_CAPACITY + 5;
+    for _ in 0..total {
+        client.attest(&attester, &schema_uid, &value, &None);
+    }
+
+    let entries = client.get_outbox(&0);
+    assert_eq!(entries.len() as u64, OUTBOX_CAPACITY);
+    assert_eq!(entries.get(0).unwrap().seq, 6);
+    assert_eq!(entries.get(entries.len() - 1).unwrap().seq, total);
+
+    // A poller that processed up to seq 3 has missed seqs 4 and 5
+    let lagging = client.get_outbox(&3);
+    assert!(lagging.get(0).unwrap().seq > 4);
+
+    let recent = client.get_outbox(&(total - 2));
+    assert_eq!(recent.len(), 2);
+    assert_eq!(recent.get(0).unwrap().seq, total - 1);
+}
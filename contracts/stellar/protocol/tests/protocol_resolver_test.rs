@@ -1,10 +1,12 @@
 mod testutils;
 
-use protocol::{interfaces::resolver::ResolverAttestation, AttestationContract, AttestationContractClient};
+use protocol::{
+    interfaces::resolver::ResolverAttestation, state::Attestation, AttestationContract, AttestationContractClient,
+};
 use soroban_sdk::{
-    contract, contractimpl,
+    contract, contracterror, contractimpl, symbol_short,
     testutils::{Address as _, Ledger},
-    Address, BytesN, Env, String as SorobanString,
+    vec, Address, BytesN, Env, String as SorobanString,
 };
 
 // Mock resolver contracts for testing
@@ -50,6 +52,97 @@ mod always_approve_resolver {
     }
 }
 
+mod gated_resolver {
+    use super::*;
+
+    #[contracterror]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[repr(u32)]
+    pub enum GateError {
+        Blocked = 42,
+    }
+
+    /// A resolver that rejects self-attestations and fails with a
+    /// contract error for attestations that expire
+    #[contract]
+    pub struct GatedResolver;
+
+    #[contractimpl]
+    impl GatedResolver {
+        pub fn onattest(_env: Env, attestation: ResolverAttestation) -> Result<bool, GateError> {
+            if attestation.expiration_time != 0 {
+                return Err(GateError::Blocked);
+            }
+            Ok(attestation.attester != attestation.recipient)
+        }
+
+        pub fn onrevoke(_env: Env, _attestation: ResolverAttestation) -> bool {
+            true
+        }
+
+        pub fn onresolve(_env: Env, _attestation: ResolverAttestation) {}
+    }
+}
+
+mod quoting_resolver {
+    use super::*;
+
+    /// A resolver that charges every attester 25 units of a configured token
+    #[contract]
+    pub struct QuotingResolver;
+
+    #[contractimpl]
+    impl QuotingResolver {
+        pub fn set_token(env: Env, token: Address) {
+            env.storage().instance().set(&symbol_short!("TOKEN"), &token);
+        }
+
+        pub fn onattest(_env: Env, _attestation: ResolverAttestation) -> bool {
+            true
+        }
+
+        pub fn onrevoke(_env: Env, _attestation: ResolverAttestation) -> bool {
+            true
+        }
+
+        pub fn onresolve(_env: Env, _attestation: ResolverAttestation) {}
+
+        pub fn quote_fees(env: Env, _schema_uid: BytesN<32>, _attester: Address) -> soroban_sdk::Vec<(Address, i128)> {
+            let token: Address = env.storage().instance().get(&symbol_short!("TOKEN")).unwrap();
+            soroban_sdk::vec![&env, (token, 25)]
+        }
+    }
+}
+
+mod subscription_resolver {
+    use super::*;
+
+    /// A resolver that approves attestations and ends them with a configured subscription
+    #[contract]
+    pub struct SubscriptionResolver;
+
+    #[contractimpl]
+    impl SubscriptionResolver {
+        pub fn set_subscription_end(env: Env, end: u64) {
+            env.storage().instance().set(&symbol_short!("END"), &end);
+        }
+
+        pub fn onattest(_env: Env, _attestation: ResolverAttestation) -> bool {
+            true
+        }
+
+        pub fn onrevoke(_env: Env, _attestation: ResolverAttestation) -> bool {
+            true
+        }
+
+        pub fn onresolve(_env: Env, _attestation: ResolverAttestation) {}
+
+        pub fn expiration_override(env: Env, _attestation: ResolverAttestation) -> Option<u64> {
+            env.storage().instance().get(&symbol_short!("END"))
+        }
+    }
+}
+
 /// **Test: Schema With Resolver That Allows Attestations**
 /// - Create schema with a resolver that approves attestations
 /// - Attestation should succeed
@@ -311,6 +404,46 @@ fn test_resolver_onresolve_hook_called() {
     assert_eq!(stored_attester.unwrap(), attester);
 }
 
+/// **Test: Resolver on_schema_attach Hook Called On Registration**
+/// - Register a schema with DummyResolver
+/// - Verify the resolver received the schema UID and its authority
+/// - Verify schemas without the hook implemented still register
+#[test]
+fn test_resolver_on_schema_attach_hook_called() {
+    use soroban_sdk::symbol_short;
+    use testutils::DummyResolver;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let authority = Address::generate(&env);
+    client.initialize(&Address::generate(&env));
+
+    let resolver_id = env.register(DummyResolver, ());
+    let schema_uid = client.register(
+        &authority,
+        &SorobanString::from_str(&env, "attached_schema"),
+        &Some(resolver_id.clone()),
+        &true,
+    );
+
+    let attached: Option<(BytesN<32>, Address)> = env.as_contract(&resolver_id, || {
+        env.storage().instance().get(&symbol_short!("ATTACHED"))
+    });
+    assert_eq!(attached, Some((schema_uid, authority.clone())));
+
+    // A resolver address without the hook does not block registration
+    let plain_resolver = Address::generate(&env);
+    client.register(
+        &authority,
+        &SorobanString::from_str(&env, "plain_schema"),
+        &Some(plain_resolver),
+        &true,
+    );
+}
+
 /// **Test: Resolver onresolve Hook Called After Revocation**
 /// - Create and then revoke an attestation
 /// - Verify onresolve is called after revocation
@@ -382,3 +515,273 @@ fn test_resolver_onresolve_hook_called_after_revocation() {
     assert!(stored_attester.is_some());
     assert_eq!(stored_attester.unwrap(), attester);
 }
+
+/// **Test: Dry-Run of Resolver Hooks**
+/// - Schemas without a resolver are always allowed
+/// - A resolver verdict is reported without storing an attestation
+/// - A resolver contract error is reported with its code instead of aborting
+#[test]
+fn test_simulate_hooks_reports_resolver_outcome() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1000;
+    });
+
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    client.initialize(&admin);
+
+    let resolver_address = env.register(gated_resolver::GatedResolver, ());
+    let gated_schema = client.register(
+        &admin,
+        &SorobanString::from_str(&env, "gated_schema"),
+        &Some(resolver_address.clone()),
+        &true,
+    );
+    let open_schema = client.register(&admin, &SorobanString::from_str(&env, "open_schema"), &None, &true);
+
+    let mut attestation = Attestation {
+        uid: BytesN::from_array(&env, &[0; 32]),
+        schema_uid: BytesN::from_array(&env, &[0; 32]),
+        schema_version: 1,
+        subject: subject.clone(),
+        attester: attester.clone(),
+        value: SorobanString::from_str(&env, "{\"test\":\"data\"}"),
+        nonce: 0,
+        timestamp: 1000,
+        expiration_time: None,
+        revoked: false,
+        revocation_time: None,
+        encryption_scheme: None,
+        encrypted_key: None,
+        on_behalf_of: None,
+        external_id: None,
+        locale: None,
+        ref_uid: None,
+    };
+
+    let open = client.simulate_hooks(&open_schema, &attestation);
+    assert_eq!(open.resolver, None);
+    assert!(open.allowed);
+
+    let allowed = client.simulate_hooks(&gated_schema, &attestation);
+    assert_eq!(allowed.resolver, Some(resolver_address));
+    assert!(allowed.allowed);
+    assert_eq!(allowed.error_code, None);
+
+    attestation.subject = attester.clone();
+    let rejected = client.simulate_hooks(&gated_schema, &attestation);
+    assert!(!rejected.allowed);
+    assert_eq!(rejected.error_code, None);
+
+    attestation.expiration_time = Some(2000);
+    let failed = client.simulate_hooks(&gated_schema, &attestation);
+    assert!(!failed.allowed);
+    assert_eq!(failed.error_code, Some(42));
+
+    // Nothing was stored by the dry run
+    assert_eq!(client.get_attester_nonce(&attester), 0);
+    assert_eq!(client.get_schema_stats(&gated_schema).issued, 0);
+}
+
+/// **Test: Attestation Cost Quotes**
+/// - Schemas without a resolver cost nothing
+/// - Resolvers without a `quote_fees` hook quote no charges
+/// - A resolver's quoted charges are returned as-is
+/// - Unknown schemas fail with `SchemaNotFound`
+#[test]
+fn test_quote_attest_cost_reports_resolver_charges() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.initialize(&admin);
+
+    let quoting_address = env.register(quoting_resolver::QuotingResolver, ());
+    quoting_resolver::QuotingResolverClient::new(&env, &quoting_address).set_token(&token);
+    let approve_address = env.register(always_approve_resolver::AlwaysApproveResolver, ());
+
+    let open_schema = client.register(&admin, &SorobanString::from_str(&env, "open_schema"), &None, &true);
+    let approve_schema = client.register(
+        &admin,
+        &SorobanString::from_str(&env, "approve_schema"),
+        &Some(approve_address),
+        &true,
+    );
+    let paid_schema = client.register(
+        &admin,
+        &SorobanString::from_str(&env, "paid_schema"),
+        &Some(quoting_address),
+        &true,
+    );
+
+    assert_eq!(client.quote_attest_cost(&open_schema, &attester), vec![&env]);
+    assert_eq!(client.quote_attest_cost(&approve_schema, &attester), vec![&env]);
+    assert_eq!(
+        client.quote_attest_cost(&paid_schema, &attester),
+        vec![&env, (token, 25_i128)]
+    );
+    assert_eq!(
+        client.try_quote_attest_cost(&BytesN::from_array(&env, &[9; 32]), &attester),
+        Err(Ok(protocol::errors::Error::SchemaNotFound))
+    );
+}
+
+
+/// **Test: Deferred Hooks Run From the Hook Queue**
+/// - With deferred hooks, `attest` succeeds without calling the resolver, even one that
+///   would reject the attestation inline
+/// - `process_hook_queue` runs `onresolve` for accepted attestations and revokes
+///   rejected ones, in enqueue order and up to the requested limit
+#[test]
+fn test_deferred_hooks_run_from_queue() {
+    use gated_resolver::GatedResolver;
+    use testutils::DummyResolver;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1000;
+    });
+
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    client.initialize(&admin);
+
+    let dummy_id = env.register(DummyResolver, ());
+    let gated_id = env.register(GatedResolver, ());
+    let accepting_schema = client.register(
+        &attester,
+        &SorobanString::from_str(&env, "deferred_accepting"),
+        &Some(dummy_id.clone()),
+        &true,
+    );
+    let gated_schema = client.register(
+        &attester,
+        &SorobanString::from_str(&env, "deferred_gated"),
+        &Some(gated_id),
+        &true,
+    );
+    let value = SorobanString::from_str(&env, "{\"test\":\"data\"}");
+
+    // GatedResolver rejects self-attestations inline
+    assert!(client.try_attest(&attester, &gated_schema, &value, &None).is_err());
+
+    client.set_schema_deferred_hooks(&attester, &accepting_schema, &true);
+    client.set_schema_deferred_hooks(&attester, &gated_schema, &true);
+    assert!(client.is_schema_hooks_deferred(&gated_schema));
+
+    let accepted_uid = client.attest(&attester, &accepting_schema, &value, &None);
+    let rejected_uid = client.attest(&attester, &gated_schema, &value, &None);
+    assert_eq!(client.get_hook_queue_length(), 2);
+    assert!(client.is_attestation_valid(&rejected_uid));
+    let onresolve_uid = || -> Option<BytesN<32>> {
+        env.as_contract(&dummy_id, || env.storage().instance().get(&symbol_short!("ONRES_UID")))
+    };
+    assert_eq!(onresolve_uid(), None);
+
+    assert_eq!(client.process_hook_queue(&1), 1);
+    assert_eq!(onresolve_uid(), Some(accepted_uid.clone()));
+    assert!(!client.get_attestation(&accepted_uid).revoked);
+
+    assert_eq!(client.process_hook_queue(&10), 1);
+    assert_eq!(client.get_hook_queue_length(), 0);
+    let rejected: Attestation = client.get_attestation(&rejected_uid);
+    assert!(rejected.revoked);
+    assert_eq!(rejected.revocation_time, Some(1000));
+    assert!(!client.is_attestation_valid(&rejected_uid));
+    assert_eq!(client.process_hook_queue(&10), 0);
+}
+
+/// **Test: Resolver Overrides the Attestation Expiration**
+/// - A resolver that returns no override keeps the attester's expiration
+/// - A resolver override replaces the attester's expiration when stored
+/// - An override in the past is rejected
+#[test]
+fn test_resolver_expiration_override() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1000;
+    });
+
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+    client.initialize(&admin);
+
+    let resolver_address = env.register(subscription_resolver::SubscriptionResolver, ());
+    let resolver = subscription_resolver::SubscriptionResolverClient::new(&env, &resolver_address);
+    let schema_uid = client.register(
+        &attester,
+        &SorobanString::from_str(&env, "membership"),
+        &Some(resolver_address),
+        &true,
+    );
+    let value = SorobanString::from_str(&env, "{\"tier\":\"gold\"}");
+
+    let uid = client.attest(&attester, &schema_uid, &value, &Some(5000));
+    assert_eq!(client.get_attestation(&uid).expiration_time, Some(5000));
+
+    resolver.set_subscription_end(&9000);
+    let uid = client.attest(&attester, &schema_uid, &value, &Some(5000));
+    assert_eq!(client.get_attestation(&uid).expiration_time, Some(9000));
+    let uid = client.attest(&attester, &schema_uid, &value, &None);
+    assert_eq!(client.get_attestation(&uid).expiration_time, Some(9000));
+
+    resolver.set_subscription_end(&500);
+    let result = client.try_attest(&attester, &schema_uid, &value, &None);
+    assert_eq!(result, Err(Ok(protocol::errors::Error::InvalidDeadline)));
+}
+
+/// **Test: Claimed Attestations Reach the Resolver Under Their Issued UID**
+/// - Issue a claimable attestation under a schema with DummyResolver
+/// - Claim it for a recipient, which fixes the subject after the UID was issued
+/// - Verify `onattest` and `onresolve` both received the issued UID
+#[test]
+fn test_claimed_attestation_reaches_resolver_with_issued_uid() {
+    use soroban_sdk::Bytes;
+    use testutils::DummyResolver;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let attester = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let resolver_id = env.register(DummyResolver, ());
+    client.initialize(&Address::generate(&env));
+
+    let schema_uid = client.register(
+        &attester,
+        &SorobanString::from_str(&env, "badge"),
+        &Some(resolver_id.clone()),
+        &true,
+    );
+    let secret = Bytes::from_slice(&env, b"claim-link-secret");
+    let claim_hash: BytesN<32> = env.crypto().sha256(&secret).into();
+    let value = SorobanString::from_str(&env, "attended");
+    let expiry = env.ledger().timestamp() + 3600;
+    let uid = client.attest_claimable(&attester, &schema_uid, &value, &claim_hash, &expiry);
+
+    client.claim(&uid, &secret, &recipient);
+    let (attested, resolved_uid) = env.as_contract(&resolver_id, || {
+        let attested: ResolverAttestation = env.storage().instance().get(&symbol_short!("LASTONATT")).unwrap();
+        let resolved_uid: BytesN<32> = env.storage().instance().get(&symbol_short!("ONRES_UID")).unwrap();
+        (attested, resolved_uid)
+    });
+    assert_eq!(attested.uid, uid);
+    assert_eq!(attested.recipient, recipient);
+    assert_eq!(resolved_uid, uid);
+}
@@ -1,7 +1,7 @@
 use protocol::{errors::Error, AttestationContract, AttestationContractClient};
 use soroban_sdk::{
     symbol_short,
-    testutils::{Address as _, Events, MockAuth, MockAuthInvoke},
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
     Address, BytesN, Env, IntoVal, String as SorobanString, TryIntoVal,
 };
 
@@ -477,3 +477,141 @@ fn test_revoking_non_existent_attestation_fails() {
     println!("Finished: {}", "___non_existent_attestation_fails");
     println!("=============================================================");
 }
+
+/// **Test: Subject Self-Revocation Bypasses Schema Revocation Policy**
+/// - Self-attestation on a non-revocable schema cannot be revoked through `revoke`
+/// - The subject can revoke it through `revoke_self`, emitting a SELF_REV event
+/// - Attestations whose subject differs from the attester are rejected
+#[test]
+fn test_subject_can_self_revoke_on_non_revocable_schema() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let subject = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_definition = SorobanString::from_str(&env, "permanent");
+    let schema_uid: BytesN<32> = client.register(&admin, &schema_definition, &None, &false);
+
+    let value = SorobanString::from_str(&env, "{\"k\":\"v\"}");
+    let attestation_uid = client.attest(&subject, &schema_uid, &value, &None);
+
+    let result = client.try_revoke(&subject, &attestation_uid);
+    assert_eq!(result, Err(Ok(Error::AttestationNotRevocable)));
+
+    let stranger = Address::generate(&env);
+    let result = client.try_revoke_self(&stranger, &attestation_uid);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    client.revoke_self(&subject, &attestation_uid);
+
+    let events = env.events().all();
+    let last = events.last().unwrap();
+    assert_eq!(last.1, (symbol_short!("ATTEST"), symbol_short!("SELF_REV")).into_val(&env));
+    let event_data: (BytesN<32>, BytesN<32>, Address, u64) = last.2.try_into_val(&env).unwrap();
+    assert_eq!(event_data.0, attestation_uid);
+    assert_eq!(event_data.2, subject);
+
+    let fetched = client.get_attestation(&attestation_uid);
+    assert!(fetched.revoked);
+
+    let result = client.try_revoke_self(&subject, &attestation_uid);
+    assert_eq!(result, Err(Ok(Error::AttestationNotFound)));
+}
+
+/// **Test: Minimum Active Duration Blocks Early Revocation**
+/// - Attester revocations fail with `RevocationTooEarly` inside the window
+/// - The admin can revoke inside the window
+/// - Attester revocations succeed once the window has passed
+#[test]
+fn test_min_active_duration_delays_revocation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attester = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&attester, &SorobanString::from_str(&env, "reward"), &None, &true);
+    client.set_schema_min_active_duration(&attester, &schema_uid, &3600);
+    assert_eq!(client.get_schema_min_active_duration(&schema_uid), 3600);
+
+    let value = SorobanString::from_str(&env, "{\"k\":\"v\"}");
+    let first = client.attest(&attester, &schema_uid, &value, &None);
+    let second = client.attest(&attester, &schema_uid, &value, &None);
+
+    assert_eq!(client.try_revoke(&attester, &first), Err(Ok(Error::RevocationTooEarly)));
+    assert_eq!(
+        client.try_revoke_self(&attester, &first),
+        Err(Ok(Error::RevocationTooEarly))
+    );
+    assert_eq!(
+        client.try_admin_revoke(&attester, &first),
+        Err(Ok(Error::NotAuthorized))
+    );
+
+    client.admin_revoke(&admin, &first);
+    assert!(client.get_attestation(&first).revoked);
+
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    client.revoke(&attester, &second);
+    assert!(client.get_attestation(&second).revoked);
+}
+
+/// **Test: Schema-Wide Revocation**
+/// - Only the schema authority can call `revoke_all`
+/// - Existing attestations read as revoked at the revocation time and are no longer valid
+/// - New attestations and a second `revoke_all` fail with `SchemaRevoked`
+/// - Attestations under other schemas are unaffected
+#[test]
+fn test_revoke_all_revokes_whole_schema() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let authority = Address::generate(&env);
+
+    client.initialize(&admin);
+    let schema_uid = client.register(&authority, &SorobanString::from_str(&env, "compromised"), &None, &true);
+    let other_schema = client.register(&authority, &SorobanString::from_str(&env, "healthy"), &None, &true);
+
+    let value = SorobanString::from_str(&env, "{\"k\":\"v\"}");
+    let uid = client.attest(&authority, &schema_uid, &value, &None);
+    let other_uid = client.attest(&authority, &other_schema, &value, &None);
+    assert!(client.has_valid_attestation(&authority, &schema_uid));
+
+    let reason = SorobanString::from_str(&env, "issuer key compromised");
+    assert_eq!(
+        client.try_revoke_all(&admin, &schema_uid, &reason),
+        Err(Ok(Error::NotAuthorized))
+    );
+    assert_eq!(client.get_schema_revocation(&schema_uid), None);
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    client.revoke_all(&authority, &schema_uid, &reason);
+
+    let revocation = client.get_schema_revocation(&schema_uid).unwrap();
+    assert_eq!(revocation.reason, reason);
+    assert_eq!(revocation.revoked_at, 5_000);
+
+    let attestation = client.get_attestation(&uid);
+    assert!(attestation.revoked);
+    assert_eq!(attestation.revocation_time, Some(5_000));
+    assert!(!client.has_valid_attestation(&authority, &schema_uid));
+
+    assert!(!client.get_attestation(&other_uid).revoked);
+    assert!(client.has_valid_attestation(&authority, &other_schema));
+
+    assert_eq!(
+        client.try_attest(&authority, &schema_uid, &value, &None),
+        Err(Ok(Error::SchemaRevoked))
+    );
+    assert_eq!(
+        client.try_revoke_all(&authority, &schema_uid, &reason),
+        Err(Ok(Error::SchemaRevoked))
+    );
+}
@@ -0,0 +1,203 @@
+use attest_types::value::{self as native, FieldSpec};
+use protocol::{
+    errors::Error,
+    state::{FieldValue, SchemaDefinition, SchemaField},
+    xdr_codec::encode_schema,
+    AttestationContract, AttestationContractClient,
+};
+use soroban_sdk::{
+    testutils::Address as _, vec, Address, Bytes, BytesN, Env, String as SorobanString, Vec as SorobanVec,
+};
+
+const HOLDER: &str = "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7";
+
+fn field(env: &Env, name: &str, field_type: &str, optional: bool) -> SchemaField {
+    SchemaField {
+        name: SorobanString::from_str(env, name),
+        field_type: SorobanString::from_str(env, field_type),
+        optional,
+    }
+}
+
+fn credential_definition(env: &Env) -> SchemaDefinition {
+    SchemaDefinition {
+        name: SorobanString::from_str(env, "Credential"),
+        description: SorobanString::from_str(env, "Professional_Credential"),
+        fields: vec![
+            env,
+            field(env, "credential_type", "string", false),
+            field(env, "holder", "address", false),
+            field(env, "issuer", "address", false),
+            field(env, "level", "u32", false),
+            field(env, "issued_at", "u64", false),
+            field(env, "expires_at", "u64", true),
+            field(env, "score", "i128", false),
+            field(env, "evidence", "bytes", true),
+            field(env, "verified", "bool", false),
+        ],
+    }
+}
+
+fn setup(env: &Env) -> (AttestationContractClient<'_>, Address, BytesN<32>) {
+    let contract_id = env.register(AttestationContract {}, ());
+    let client = AttestationContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let authority = Address::generate(env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+    let definition = encode_schema(env, &credential_definition(env));
+    let schema_uid = client.register(&authority, &definition, &None, &true);
+    (client, authority, schema_uid)
+}
+
+fn credential_values(env: &Env, issuer: &Address) -> SorobanVec<FieldValue> {
+    vec![
+        env,
+        FieldValue::String(SorobanString::from_str(env, "Certified Kubernetes Administrator")),
+        FieldValue::Address(Address::from_str(env, HOLDER)),
+        FieldValue::Address(issuer.clone()),
+        FieldValue::U32(3),
+        FieldValue::U64(1_700_000_000),
+        FieldValue::Absent,
+        FieldValue::I128(-42),
+        FieldValue::Bytes(Bytes::from_array(env, &[0xde, 0xad, 0xbe, 0xef])),
+        FieldValue::Bool(true),
+    ]
+}
+
+/// **Test: Structured Values Round-Trip and Are Accepted on Attest**
+/// - Account and contract addresses, optional fields and every scalar type survive
+///   encoding, and the encoding is well under the size of the equivalent JSON
+#[test]
+fn structured_value_round_trips_and_attests() {
+    let env = Env::default();
+    let (client, authority, schema_uid) = setup(&env);
+
+    let values = credential_values(&env, &authority);
+    let value = client.encode_value(&schema_uid, &values);
+    assert!(value.to_string().starts_with("BIN:"));
+    assert_eq!(client.decode_value(&schema_uid, &value), values);
+    // Encoding is deterministic
+    assert_eq!(client.encode_value(&schema_uid, &values), value);
+
+    let json = format!(
+        r#"{{"credential_type":"Certified Kubernetes Administrator","holder":"{HOLDER}","issuer":"{}","level":3,"issued_at":1700000000,"score":"-42","evidence":"deadbeef","verified":true}}"#,
+        authority.to_string().to_string()
+    );
+    // The payload is about half the JSON; base64 brings the stored string to about 70%
+    assert!(
+        value.len() * 10 < json.len() as u32 * 7,
+        "{} vs {}",
+        value.len(),
+        json.len()
+    );
+
+    let attestation_uid = client.attest(&authority, &schema_uid, &value, &None);
+    let stored = client.get_attestation(&attestation_uid);
+    assert_eq!(client.decode_value(&schema_uid, &stored.value), values);
+}
+
+/// **Test: Malformed and Non-Conforming Structured Values Are Rejected**
+/// - Wrong arity, wrong types and missing required fields fail to encode
+/// - Truncated, padded or garbled payloads fail to decode and cannot be attested
+/// - Plain values are still accepted under the same schema
+#[test]
+fn non_conforming_values_are_rejected() {
+    let env = Env::default();
+    let (client, authority, schema_uid) = setup(&env);
+    let values = credential_values(&env, &authority);
+
+    let mut short = values.clone();
+    short.pop_back();
+    assert_eq!(
+        client.try_encode_value(&schema_uid, &short),
+        Err(Ok(Error::InvalidAttestationValue))
+    );
+    let mut retyped = values.clone();
+    retyped.set(3, FieldValue::U64(3));
+    assert_eq!(
+        client.try_encode_value(&schema_uid, &retyped),
+        Err(Ok(Error::InvalidAttestationValue))
+    );
+    let mut missing = values.clone();
+    missing.set(0, FieldValue::Absent);
+    assert_eq!(
+        client.try_encode_value(&schema_uid, &missing),
+        Err(Ok(Error::InvalidAttestationValue))
+    );
+
+    // Hand-built payloads for a single required bool field: version, bitmap, value
+    let flag_schema = SchemaDefinition {
+        name: SorobanString::from_str(&env, "Flag"),
+        description: SorobanString::from_str(&env, "Flag"),
+        fields: vec![&env, field(&env, "flag", "bool", false)],
+    };
+    let flag_uid = client.register(&authority, &encode_schema(&env, &flag_schema), &None, &true);
+    assert_eq!(
+        client.decode_value(&flag_uid, &SorobanString::from_str(&env, "BIN:AQEB")),
+        vec![&env, FieldValue::Bool(true)]
+    );
+    let malformed = [
+        "BIN:",
+        "BIN:!!!!",
+        "BIN:AgEB",     // Unknown version
+        "BIN:AQA=",     // Required field absent
+        "BIN:AQMB",     // Bitmap bit set past the last field
+        "BIN:AQEC",     // Bool out of range
+        "BIN:AQEBAA==", // Trailing byte
+    ];
+    for value in malformed {
+        let value = SorobanString::from_str(&env, value);
+        assert_eq!(
+            client.try_decode_value(&flag_uid, &value),
+            Err(Ok(Error::InvalidAttestationValue))
+        );
+        assert_eq!(
+            client.try_attest(&authority, &flag_uid, &value, &None),
+            Err(Ok(Error::InvalidAttestationValue))
+        );
+    }
+
+    let plain = SorobanString::from_str(&env, "{\"credential_type\":\"CKA\"}");
+    client.attest(&authority, &schema_uid, &plain, &None);
+}
+
+/// **Test: Native and On-Chain Encodings Are Byte-Identical**
+/// - A value built off-chain with `attest_types::value` matches `encode_value`, and
+///   each side decodes the other's output
+#[test]
+fn native_codec_matches_contract() {
+    let env = Env::default();
+    let (client, authority, schema_uid) = setup(&env);
+
+    let fields: std::vec::Vec<FieldSpec> = serde_json::from_value(serde_json::json!([
+        {"name": "credential_type", "type": "string"},
+        {"name": "holder", "type": "address"},
+        {"name": "issuer", "type": "address"},
+        {"name": "level", "type": "u32"},
+        {"name": "issued_at", "type": "u64"},
+        {"name": "expires_at", "type": "u64", "optional": true},
+        {"name": "score", "type": "i128"},
+        {"name": "evidence", "type": "bytes", "optional": true},
+        {"name": "verified", "type": "bool"},
+    ]))
+    .unwrap();
+    let json = serde_json::json!({
+        "credential_type": "Certified Kubernetes Administrator",
+        "holder": HOLDER,
+        "issuer": authority.to_string().to_string(),
+        "level": 3,
+        "issued_at": 1_700_000_000u64,
+        "score": "-42",
+        "evidence": "deadbeef",
+        "verified": true,
+    });
+    let native = native::encode_value(&fields, json.as_object().unwrap()).unwrap();
+
+    let on_chain = client.encode_value(&schema_uid, &credential_values(&env, &authority));
+    assert_eq!(native, on_chain.to_string());
+    assert_eq!(
+        &native::decode_value(&fields, &on_chain.to_string()).unwrap(),
+        json.as_object().unwrap()
+    );
+}
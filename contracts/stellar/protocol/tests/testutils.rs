@@ -85,6 +85,7 @@ pub fn create_delegated_attestation_request(
         attester: attester.clone(),
         expiration_time: None,
         deadline: env.ledger().timestamp() + 1000,
+        version: 1,
         signature: BytesN::from_array(env, &[0; 96]), // Placeholder
     };
 
@@ -101,12 +102,48 @@ pub fn create_delegated_attestation_request(
     request
 }
 
+// =======================================================================================
+//
+//                              SECP256K1 TEST HELPERS
+//
+// =======================================================================================
+
+/// Deterministic secp256k1 private key used to emulate an EVM wallet in tests.
+#[allow(dead_code)]
+pub const TEST_SECP256K1_PRIVATE_KEY: [u8; 32] = [
+    0x4c, 0x08, 0x83, 0xa6, 0x91, 0x02, 0x93, 0x7d, 0x62, 0x31, 0x47, 0x1b, 0x5d, 0xbb, 0x62, 0x04, 0xfe, 0x51, 0x29,
+    0x61, 0x70, 0x82, 0x79, 0x2a, 0xe4, 0x68, 0xd0, 0x1a, 0x3f, 0x36, 0x23, 0x18,
+];
+
+/// Returns the 20-byte EVM address of `TEST_SECP256K1_PRIVATE_KEY`.
+#[allow(dead_code)]
+pub fn secp256k1_test_eth_address(env: &Env) -> BytesN<20> {
+    let signing_key = k256::ecdsa::SigningKey::from_slice(&TEST_SECP256K1_PRIVATE_KEY).unwrap();
+    let encoded = signing_key.verifying_key().to_encoded_point(false);
+    let public_key = BytesN::<65>::from_array(env, encoded.as_bytes().try_into().unwrap());
+    protocol::instructions::crypto::eth_address_from_public_key(env, &public_key)
+}
+
+/// Signs `keccak256(message)` with `TEST_SECP256K1_PRIVATE_KEY`, returning an
+/// EVM-style `r || s || v` signature with `v` in {27, 28}.
+#[allow(dead_code)]
+pub fn sign_secp256k1(env: &Env, message: &soroban_sdk::Bytes) -> BytesN<65> {
+    let signing_key = k256::ecdsa::SigningKey::from_slice(&TEST_SECP256K1_PRIVATE_KEY).unwrap();
+    let digest = env.crypto().keccak256(message).to_array();
+    let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&digest).unwrap();
+
+    let mut bytes = [0u8; 65];
+    bytes[..64].copy_from_slice(&signature.to_bytes());
+    bytes[64] = recovery_id.to_byte() + 27;
+    BytesN::from_array(env, &bytes)
+}
+
 // =======================================================================================
 //
 //                                DUMMY RESOLVER FOR TESTING
 //
 // =======================================================================================
-use protocol::interfaces::resolver::ResolverAttestation;
+use protocol::interfaces::resolver::{ResolverAttestation, ResolverContext};
 use soroban_sdk::{contract, contractimpl, symbol_short};
 
 #[contract]
@@ -148,4 +185,18 @@ impl DummyResolver {
             .instance()
             .set(&symbol_short!("ONRES_ATT"), &attestation.attester);
     }
+
+    /// Relayed-attestation callback. Records the submitter from the context.
+    pub fn onrelayed(env: Env, _attestation: ResolverAttestation, context: ResolverContext) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("RELAY_SUB"), &context.submitter);
+    }
+
+    /// Schema-attach callback. Records the schema and its authority.
+    pub fn on_schema_attach(env: Env, schema_uid: BytesN<32>, schema_authority: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("ATTACHED"), &(schema_uid, schema_authority));
+    }
 }
@@ -0,0 +1,36 @@
+#![no_std]
+//! # Attest Protocol Client
+//!
+//! The read-only surface of the attestation protocol contract, for third-party
+//! Soroban contracts that consume attestations. Depending on this crate instead of
+//! the protocol crate keeps the consumer's Wasm free of the protocol's contract
+//! functions and pins it to a small ABI that is kept stable across releases.
+//!
+//! ```ignore
+//! let protocol = ProtocolClient::new(&env, &protocol_address);
+//! if !protocol.is_attestation_valid(&attestation_uid) {
+//!     panic_with_error!(&env, MyError::NotAttested);
+//! }
+//! ```
+use soroban_sdk::{contractclient, Address, BytesN, Env};
+
+pub use attest_errors::ProtocolError;
+pub use attest_types::Attestation;
+
+/// Read methods of the attestation protocol contract.
+#[contractclient(name = "ProtocolClient")]
+pub trait AttestationReader {
+    /// Returns the attestation with this UID.
+    ///
+    /// Fails with `AttestationNotFound` if it does not exist, or `AttestationExpired`
+    /// if it has expired.
+    fn get_attestation(env: Env, attestation_uid: BytesN<32>) -> Result<Attestation, ProtocolError>;
+
+    /// Returns true if the attestation exists and is not revoked, frozen, expired, or
+    /// under a revoked schema
+    fn is_attestation_valid(env: Env, attestation_uid: BytesN<32>) -> bool;
+
+    /// Returns the most recent attestation issued to the subject under the schema, if
+    /// still stored, whether or not it is valid
+    fn latest_attestation(env: Env, subject: Address, schema_uid: BytesN<32>) -> Option<Attestation>;
+}
@@ -0,0 +1,46 @@
+use protocol::{AttestationContract, AttestationContractClient};
+use protocol_client::{ProtocolClient, ProtocolError};
+use soroban_sdk::{
+    testutils::{Address as _, BytesN as _, Ledger},
+    Address, BytesN, Env, String,
+};
+
+/// **Test: The Read Interface Matches the Deployed Protocol**
+/// - Each method decodes the protocol's responses through the minimal client
+/// - Validity follows revocation and expiry
+#[test]
+fn read_interface_matches_protocol() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let protocol_id = env.register(AttestationContract {}, ());
+    let protocol = AttestationContractClient::new(&env, &protocol_id);
+    let reader = ProtocolClient::new(&env, &protocol_id);
+    protocol.initialize(&Address::generate(&env));
+
+    let attester = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let schema_uid = protocol.register(&attester, &String::from_str(&env, "membership"), &None, &true);
+    assert_eq!(reader.latest_attestation(&subject, &schema_uid), None);
+
+    let value = String::from_str(&env, "{\"tier\":\"gold\"}");
+    let expiry = env.ledger().timestamp() + 100;
+    let uid = protocol.attest(&attester, &schema_uid, &value, &Some(expiry));
+    let attestation = reader.get_attestation(&uid);
+    assert_eq!(attestation.attester, attester);
+    assert!(reader.is_attestation_valid(&uid));
+    assert_eq!(reader.latest_attestation(&attester, &schema_uid), Some(attestation));
+
+    env.ledger().with_mut(|l| l.timestamp = expiry + 1);
+    assert!(!reader.is_attestation_valid(&uid));
+    assert!(reader.latest_attestation(&attester, &schema_uid).is_some());
+    assert_eq!(
+        reader.try_get_attestation(&uid),
+        Err(Ok(ProtocolError::AttestationExpired))
+    );
+
+    let renewed = protocol.attest(&attester, &schema_uid, &value, &None);
+    protocol.revoke(&attester, &renewed);
+    assert!(!reader.is_attestation_valid(&renewed));
+    assert!(reader.latest_attestation(&attester, &schema_uid).unwrap().revoked);
+    assert!(!reader.is_attestation_valid(&BytesN::random(&env)));
+}
@@ -0,0 +1,6 @@
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Contract Errors
+// ►
+// ► Defined in the shared `attest-errors` registry (registry facade range: 6xx).
+// ══════════════════════════════════════════════════════════════════════════════
+pub use attest_errors::RegistryFacadeError as Error;
@@ -0,0 +1,54 @@
+use soroban_sdk::{contractclient, contracttype, Address, Bytes, BytesN, Env, String, Vec};
+
+/// A page of attestation UIDs as returned by the protocol contract.
+///
+/// Mirrors the protocol's `AttestationPage` so it can be decoded without
+/// depending on the protocol crate.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttestationPage {
+    pub uids: Vec<BytesN<32>>,
+    pub next_cursor: Bytes,
+    pub has_more: bool,
+}
+
+/// An attester's verified home domain as stored by the protocol contract.
+///
+/// Mirrors the protocol's `AttesterDomain`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttesterDomain {
+    pub domain: String,
+    pub challenge_hash: BytesN<32>,
+    pub verified_at: u64,
+    pub verified_by: Address,
+}
+
+/// Protocol Contract Client Interface
+///
+/// The subset of the attestation protocol contract that the facade reads.
+/// Declared locally rather than importing the protocol crate so the facade's Wasm
+/// does not re-export the protocol's contract functions.
+#[contractclient(name = "ProtocolClient")]
+pub trait Protocol {
+    /// Returns one page of the UIDs of attestations about the subject, oldest first
+    fn list_subject_attestations(env: Env, subject: Address, cursor: Bytes, limit: u32) -> AttestationPage;
+
+    /// Returns the attester's verified home domain, if any
+    fn get_attester_domain(env: Env, attester: Address) -> Option<AttesterDomain>;
+}
+
+/// Authority Contract Client Interface
+///
+/// The subset of the authority contract that the facade reads.
+#[contractclient(name = "AuthorityClient")]
+pub trait Authority {
+    /// Returns true if the address is a registered authority
+    fn is_authority(env: Env, authority: Address) -> bool;
+
+    /// Returns true if the authority was suspended after being slashed
+    fn is_suspended(env: Env, authority: Address) -> bool;
+
+    /// Returns the bond currently locked by the authority
+    fn get_bond(env: Env, authority: Address) -> i128;
+}
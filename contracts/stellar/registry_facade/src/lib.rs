@@ -0,0 +1,94 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, Address, Bytes, Env, String, Vec};
+
+mod errors;
+pub mod interfaces;
+mod state;
+
+pub use errors::Error;
+pub use interfaces::{AuthorityClient, ProtocolClient};
+pub use state::{AuthorityStatus, SubjectProfile, PROFILE_ATTESTATION_LIMIT};
+
+/// Prefix of the `did:web` identifiers derived from verified home domains.
+const DID_WEB_PREFIX: &[u8] = b"did:web:";
+
+/// Longest home domain the protocol accepts.
+const MAX_DOMAIN_LEN: usize = 253;
+
+/// Read-only registry facade.
+///
+/// A thin companion contract that aggregates reads across the protocol and authority
+/// contracts, so wallets can fetch a subject's profile with a single cross-contract
+/// call instead of one per contract. It holds no state beyond the two addresses.
+#[contract]
+pub struct RegistryFacadeContract;
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Contract Implementation
+// ══════════════════════════════════════════════════════════════════════════════
+#[contractimpl]
+impl RegistryFacadeContract {
+    // ──────────────────────────────────────────────────────────────────────────
+    //                           Initialization
+    // ──────────────────────────────────────────────────────────────────────────
+
+    /// Binds the facade to the protocol and authority contracts. Can only be called once.
+    pub fn initialize(env: Env, protocol: Address, authority: Address) -> Result<(), Error> {
+        if state::is_initialized(&env) {
+            return Err(Error::AlreadyInitialized);
+        }
+        state::set_contracts(&env, &protocol, &authority);
+        Ok(())
+    }
+
+    /// Returns the address of the protocol contract the facade reads from.
+    pub fn get_protocol(env: Env) -> Result<Address, Error> {
+        state::get_protocol(&env)
+    }
+
+    /// Returns the address of the authority contract the facade reads from.
+    pub fn get_authority(env: Env) -> Result<Address, Error> {
+        state::get_authority(&env)
+    }
+
+    // ──────────────────────────────────────────────────────────────────────────
+    //                           Profiles
+    // ──────────────────────────────────────────────────────────────────────────
+
+    /// Returns the subject's attestations, authority standing and DIDs.
+    ///
+    /// Attestations are listed up to `PROFILE_ATTESTATION_LIMIT`; `has_more_attestations`
+    /// tells the caller to page through the rest on the protocol contract.
+    pub fn resolve(env: Env, subject: Address) -> Result<SubjectProfile, Error> {
+        let protocol = ProtocolClient::new(&env, &state::get_protocol(&env)?);
+        let authority = AuthorityClient::new(&env, &state::get_authority(&env)?);
+
+        let page = protocol.list_subject_attestations(&subject, &Bytes::new(&env), &PROFILE_ATTESTATION_LIMIT);
+        let authority_status = AuthorityStatus {
+            is_authority: authority.is_authority(&subject),
+            is_suspended: authority.is_suspended(&subject),
+            bond: authority.get_bond(&subject),
+        };
+
+        let mut dids = Vec::new(&env);
+        if let Some(binding) = protocol.get_attester_domain(&subject) {
+            dids.push_back(did_web(&env, &binding.domain));
+        }
+
+        Ok(SubjectProfile {
+            attestations: page.uids,
+            has_more_attestations: page.has_more,
+            authority_status,
+            dids,
+        })
+    }
+}
+
+/// Formats a verified home domain as a `did:web` identifier.
+fn did_web(env: &Env, domain: &String) -> String {
+    let mut buf = [0u8; DID_WEB_PREFIX.len() + MAX_DOMAIN_LEN];
+    let len = DID_WEB_PREFIX.len() + domain.len() as usize;
+    buf[..DID_WEB_PREFIX.len()].copy_from_slice(DID_WEB_PREFIX);
+    domain.copy_into_slice(&mut buf[DID_WEB_PREFIX.len()..len]);
+    String::from_bytes(env, &buf[..len])
+}
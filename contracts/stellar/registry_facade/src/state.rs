@@ -0,0 +1,72 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
+
+use crate::errors::Error;
+
+/// Maximum number of attestation UIDs returned in a profile.
+///
+/// Matches the protocol's page limit; subjects with more attestations page through
+/// `list_subject_attestations` on the protocol contract.
+pub const PROFILE_ATTESTATION_LIMIT: u32 = 100;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Protocol,  // Address of the attestation protocol contract
+    Authority, // Address of the authority contract
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Profile Types
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// A subject's standing in the authority contract.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuthorityStatus {
+    /// Whether the subject is a registered authority
+    pub is_authority: bool,
+    /// Whether the authority is suspended after being slashed
+    pub is_suspended: bool,
+    /// The bond the authority has locked, in the authority contract's token
+    pub bond: i128,
+}
+
+/// Everything the registry knows about a subject, gathered in one call.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubjectProfile {
+    /// UIDs of the first `PROFILE_ATTESTATION_LIMIT` attestations about the subject, oldest first
+    pub attestations: Vec<BytesN<32>>,
+    /// Whether the subject has more attestations than are listed
+    pub has_more_attestations: bool,
+    /// The subject's standing in the authority contract
+    pub authority_status: AuthorityStatus,
+    /// DIDs controlled by the subject, derived from its verified home domain as `did:web`
+    pub dids: Vec<String>,
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Storage Helper Functions
+// ══════════════════════════════════════════════════════════════════════════════
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Protocol)
+}
+
+pub fn set_contracts(env: &Env, protocol: &Address, authority: &Address) {
+    env.storage().instance().set(&DataKey::Protocol, protocol);
+    env.storage().instance().set(&DataKey::Authority, authority);
+}
+
+pub fn get_protocol(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Protocol)
+        .ok_or(Error::NotInitialized)
+}
+
+pub fn get_authority(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Authority)
+        .ok_or(Error::NotInitialized)
+}
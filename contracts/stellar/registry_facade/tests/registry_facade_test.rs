@@ -0,0 +1,92 @@
+use authority::{AuthorityResolverContract, AuthorityResolverContractClient};
+use protocol::{AttestationContract, AttestationContractClient};
+use registry_facade::{AuthorityStatus, Error, RegistryFacadeContract, RegistryFacadeContractClient};
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, BytesN, Env, String};
+
+struct Setup<'a> {
+    protocol: AttestationContractClient<'a>,
+    authority: AuthorityResolverContractClient<'a>,
+    facade: RegistryFacadeContractClient<'a>,
+    admin: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let admin = Address::generate(env);
+
+    let protocol_id = env.register(AttestationContract {}, ());
+    let protocol = AttestationContractClient::new(env, &protocol_id);
+    protocol.initialize(&admin);
+
+    let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let authority_id = env.register(AuthorityResolverContract {}, ());
+    let authority = AuthorityResolverContractClient::new(env, &authority_id);
+    authority.initialize(&admin, &token, &BytesN::from_array(env, &[0u8; 32]));
+
+    let facade_id = env.register(RegistryFacadeContract {}, ());
+    let facade = RegistryFacadeContractClient::new(env, &facade_id);
+    facade.initialize(&protocol_id, &authority_id);
+
+    Setup {
+        protocol,
+        authority,
+        facade,
+        admin,
+    }
+}
+
+#[test]
+fn test_initialize_only_once() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(s.facade.get_protocol(), s.protocol.address);
+    assert_eq!(s.facade.get_authority(), s.authority.address);
+    assert_eq!(
+        s.facade.try_initialize(&s.protocol.address, &s.authority.address),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_resolve_aggregates_subject_profile() {
+    let env = Env::default();
+    let s = setup(&env);
+    let subject = Address::generate(&env);
+
+    let profile = s.facade.resolve(&subject);
+    assert!(profile.attestations.is_empty());
+    assert!(!profile.authority_status.is_authority);
+    assert!(profile.dids.is_empty());
+
+    let schema_uid = s
+        .protocol
+        .register(&subject, &String::from_str(&env, "profile"), &None, &true);
+    let uid = s
+        .protocol
+        .attest(&subject, &schema_uid, &String::from_str(&env, "member"), &None);
+    s.authority
+        .admin_register_authority(&s.admin, &subject, &String::from_str(&env, "issuer"));
+
+    let token = Bytes::from_slice(&env, b"attest-domain-token");
+    let challenge_hash: BytesN<32> = env.crypto().sha256(&token).into();
+    s.protocol
+        .request_attester_domain(&subject, &String::from_str(&env, "issuer.example"), &challenge_hash);
+    s.protocol.verify_attester_domain(&s.admin, &subject, &challenge_hash);
+
+    let profile = s.facade.resolve(&subject);
+    assert_eq!(profile.attestations, vec![&env, uid]);
+    assert!(!profile.has_more_attestations);
+    assert_eq!(
+        profile.authority_status,
+        AuthorityStatus {
+            is_authority: true,
+            is_suspended: false,
+            bond: 0,
+        }
+    );
+    assert_eq!(
+        profile.dids,
+        vec![&env, String::from_str(&env, "did:web:issuer.example")]
+    );
+}
@@ -0,0 +1,208 @@
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Allowlist Resolver
+// ►
+// ► Access-control resolver that only accepts attestations from allowlisted
+// ► attesters. Entries may carry an expiry so seasonal issuer programs lapse on
+// ► their own instead of needing manual removal.
+// ►
+// ► MODEL:
+// ► - The admin imports entries in bulk as `(attester, expires_at)` tuples; an
+// ►   `expires_at` of 0 never expires. Re-importing an attester replaces its entry.
+// ► - **onattest** rejects attesters with no entry or an expired one.
+// ► - **prune_expired_entries** is a permissionless maintenance call that removes
+// ►   expired entries from storage.
+// ══════════════════════════════════════════════════════════════════════════════
+
+use crate::interface::{ResolverAttestationData, ResolverError, ResolverInterface, ResolverMetadata, ResolverType};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, String, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Initialized,
+    Entry(Address), // attester -> expires_at (0 = never)
+    Members,        // Vec<Address> of attesters with an entry, used for pruning
+}
+
+#[contract]
+pub struct AllowlistResolver;
+
+#[contractimpl]
+impl AllowlistResolver {
+    /// Initialize the resolver with its admin
+    pub fn initialize(env: Env, admin: Address) -> Result<(), ResolverError> {
+        if env.storage().instance().has(&DataKey::Initialized) {
+            return Err(ResolverError::CustomError); // Already initialized
+        }
+
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Initialized, &true);
+
+        env.storage()
+            .instance()
+            .extend_ttl(env.storage().max_ttl() - 100, env.storage().max_ttl());
+
+        Ok(())
+    }
+
+    /// Import allowlist entries in bulk (admin only)
+    ///
+    /// Each entry is `(attester, expires_at)`; an `expires_at` of 0 never expires.
+    /// Entries that are already expired are rejected with `ValidationFailed`.
+    pub fn import_allowlist(env: Env, admin: Address, entries: Vec<(Address, u64)>) -> Result<u32, ResolverError> {
+        Self::require_admin(&env, &admin)?;
+
+        let now = env.ledger().timestamp();
+        let mut members = Self::members(&env);
+        for (attester, expires_at) in entries.iter() {
+            if expires_at != 0 && expires_at <= now {
+                return Err(ResolverError::ValidationFailed);
+            }
+
+            let key = DataKey::Entry(attester.clone());
+            env.storage().persistent().set(&key, &expires_at);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+
+            if !members.contains(&attester) {
+                members.push_back(attester);
+            }
+        }
+        Self::set_members(&env, &members);
+
+        // Emit event
+        env.events()
+            .publish((String::from_str(&env, "ALLOWLIST_IMPORTED"),), entries.len());
+
+        Ok(entries.len())
+    }
+
+    /// Remove an attester from the allowlist (admin only)
+    pub fn remove_allowlist_entry(env: Env, admin: Address, attester: Address) -> Result<(), ResolverError> {
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().persistent().remove(&DataKey::Entry(attester.clone()));
+        let mut members = Self::members(&env);
+        if let Some(index) = members.first_index_of(&attester) {
+            members.remove(index);
+            Self::set_members(&env, &members);
+        }
+
+        // Emit event
+        env.events()
+            .publish((String::from_str(&env, "ALLOWLIST_REMOVED"),), attester);
+
+        Ok(())
+    }
+
+    /// Remove every expired entry from storage, returning how many were pruned
+    ///
+    /// Permissionless: pruning only deletes entries that can no longer pass `onattest`.
+    pub fn prune_expired_entries(env: Env) -> u32 {
+        let now = env.ledger().timestamp();
+        let members = Self::members(&env);
+        let mut remaining = Vec::new(&env);
+        let mut pruned = 0u32;
+
+        for attester in members.iter() {
+            let key = DataKey::Entry(attester.clone());
+            match env.storage().persistent().get::<DataKey, u64>(&key) {
+                Some(expires_at) if expires_at == 0 || expires_at > now => remaining.push_back(attester),
+                _ => {
+                    env.storage().persistent().remove(&key);
+                    pruned += 1;
+                },
+            }
+        }
+
+        if pruned > 0 {
+            Self::set_members(&env, &remaining);
+
+            // Emit event
+            env.events()
+                .publish((String::from_str(&env, "ALLOWLIST_PRUNED"),), pruned);
+        }
+
+        pruned
+    }
+
+    /// Get an attester's entry expiry, if it has an entry (0 = never expires)
+    pub fn get_allowlist_entry(env: Env, attester: Address) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::Entry(attester))
+    }
+
+    /// Whether the attester currently holds an unexpired entry
+    pub fn is_allowlisted(env: Env, attester: Address) -> bool {
+        match Self::get_allowlist_entry(env.clone(), attester) {
+            Some(expires_at) => expires_at == 0 || expires_at > env.ledger().timestamp(),
+            None => false,
+        }
+    }
+
+    fn members(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn set_members(env: &Env, members: &Vec<Address>) {
+        env.storage().persistent().set(&DataKey::Members, members);
+        env.storage().persistent().extend_ttl(
+            &DataKey::Members,
+            env.storage().max_ttl() - 100,
+            env.storage().max_ttl(),
+        );
+    }
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), ResolverError> {
+        caller.require_auth();
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ResolverError::CustomError)?;
+
+        if caller != &admin {
+            return Err(ResolverError::NotAuthorized);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-allowlist-resolver"))]
+#[contractimpl]
+impl ResolverInterface for AllowlistResolver {
+    /// Only allowlisted attesters with an unexpired entry may attest
+    fn onattest(env: Env, attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+        if !Self::is_allowlisted(env, attestation.attester) {
+            return Err(ResolverError::NotAuthorized);
+        }
+        Ok(true)
+    }
+
+    /// Revocations remain available after an entry lapses
+    fn onrevoke(_env: Env, _attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+        Ok(true)
+    }
+
+    /// No post-processing needed
+    fn onresolve(_env: Env, _attestation_uid: BytesN<32>, _attester: Address) -> Result<(), ResolverError> {
+        Ok(())
+    }
+
+    fn metadata(env: Env) -> ResolverMetadata {
+        ResolverMetadata {
+            name: String::from_str(&env, "Allowlist Resolver"),
+            version: String::from_str(&env, "1.0.0"),
+            description: String::from_str(&env, "Accepts attestations only from allowlisted attesters"),
+            resolver_type: ResolverType::Authority,
+        }
+    }
+}
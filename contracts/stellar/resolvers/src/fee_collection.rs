@@ -1,5 +1,7 @@
-use crate::interface::{ResolverAttestationData, ResolverError, ResolverInterface, ResolverMetadata, ResolverType};
-use soroban_sdk::{contract, contractimpl, contracttype, token, Address, BytesN, Env, String};
+use crate::interface::{
+    ResolverAttestationData, ResolverContext, ResolverError, ResolverInterface, ResolverMetadata, ResolverType,
+};
+use soroban_sdk::{contract, contractclient, contractimpl, contracttype, token, Address, BytesN, Env, String, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -11,6 +13,63 @@ pub enum DataKey {
     FeeRecipient,
     TotalCollected,
     CollectedFees,
+    RebateTiers,
+    AttesterVolume,
+    RebateBalance,
+    Converter,
+    MaxSlippageBps,
+    PaymentAsset,
+    Protocol,
+    RelayerReimbursement,
+    SchemaFee,
+    SchemaPayerMode,
+    AttestationFeePaid,
+}
+
+/// Basis points denominator (100% = 10_000 bps)
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// A volume-based rebate tier.
+///
+/// Once an attester has paid for at least `min_volume` attestations, each further
+/// fee they pay accrues `rebate_bps` basis points back into a claimable balance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RebateTier {
+    pub min_volume: u64,
+    pub rebate_bps: u32,
+}
+
+/// Who pays the attestation fee for a schema.
+///
+/// With `Recipient`, the fee is pulled from the attestation's recipient using an
+/// allowance they granted this resolver, so users can pay for their own credentials.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PayerMode {
+    Attester,
+    Recipient,
+}
+
+/// Swap adapter used to convert fees paid in another asset into the fee token.
+///
+/// Adapters wrap an AMM or router (e.g. Soroswap). The resolver transfers `amount_in`
+/// of `token_in` to the adapter before calling `swap`, and the adapter must send at
+/// least `min_amount_out` of `token_out` to `to`, returning the amount sent.
+#[contractclient(name = "SwapAdapterClient")]
+pub trait SwapAdapter {
+    /// Amount of `token_in` needed to receive `amount_out` of `token_out` at the current price
+    fn quote_in(env: Env, token_in: Address, token_out: Address, amount_out: i128) -> i128;
+
+    /// Swap `amount_in` of `token_in`, already held by the adapter, for `token_out`
+    fn swap(
+        env: Env,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        min_amount_out: i128,
+        to: Address,
+    ) -> i128;
 }
 
 /// FeeCollectionResolver - Collects XLM fees for attestations
@@ -120,6 +179,157 @@ impl FeeCollectionResolver {
         Ok(())
     }
 
+    /// Configure the volume-based rebate schedule (admin only)
+    ///
+    /// Tiers must be ordered by strictly increasing `min_volume` and each `rebate_bps`
+    /// must not exceed 10_000. An empty schedule disables rebates.
+    pub fn set_rebate_tiers(env: Env, admin: Address, tiers: Vec<RebateTier>) -> Result<(), ResolverError> {
+        Self::require_admin(&env, &admin)?;
+
+        let mut previous: Option<u64> = None;
+        for tier in tiers.iter() {
+            if tier.rebate_bps as i128 > BPS_DENOMINATOR {
+                return Err(ResolverError::ValidationFailed);
+            }
+            if let Some(prev) = previous {
+                if tier.min_volume <= prev {
+                    return Err(ResolverError::ValidationFailed);
+                }
+            }
+            previous = Some(tier.min_volume);
+        }
+
+        env.storage().instance().set(&DataKey::RebateTiers, &tiers);
+
+        // Emit event
+        env.events()
+            .publish((String::from_str(&env, "REBATE_TIERS_UPDATED"),), tiers.len());
+
+        Ok(())
+    }
+
+    /// Claim the accrued rebate balance (attester only)
+    pub fn claim_rebate(env: Env, attester: Address) -> Result<i128, ResolverError> {
+        attester.require_auth();
+
+        let key = (DataKey::RebateBalance, attester.clone());
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+
+        if balance == 0 {
+            return Ok(0); // Nothing to claim
+        }
+
+        let fee_token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::FeeToken)
+            .ok_or(ResolverError::CustomError)?;
+
+        // Reset before transfer
+        env.storage().persistent().set(&key, &0i128);
+
+        let token_client = token::Client::new(&env, &fee_token);
+        token_client.transfer(&env.current_contract_address(), &attester, &balance);
+
+        // Emit event
+        env.events()
+            .publish((String::from_str(&env, "REBATE_CLAIMED"), &attester), balance);
+
+        Ok(balance)
+    }
+
+    /// Configure the swap adapter for fees paid in other assets (admin only)
+    ///
+    /// `max_slippage_bps` bounds how far below the fee the converted amount may fall.
+    /// Passing `None` disables conversion; attesters then pay in the fee token.
+    pub fn set_converter(
+        env: Env,
+        admin: Address,
+        converter: Option<Address>,
+        max_slippage_bps: u32,
+    ) -> Result<(), ResolverError> {
+        Self::require_admin(&env, &admin)?;
+
+        if max_slippage_bps as i128 > BPS_DENOMINATOR {
+            return Err(ResolverError::ValidationFailed);
+        }
+
+        match &converter {
+            Some(converter) => env.storage().instance().set(&DataKey::Converter, converter),
+            None => env.storage().instance().remove(&DataKey::Converter),
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxSlippageBps, &max_slippage_bps);
+
+        // Emit event
+        env.events().publish(
+            (String::from_str(&env, "CONVERTER_UPDATED"),),
+            (converter, max_slippage_bps),
+        );
+
+        Ok(())
+    }
+
+    /// Choose the asset an attester pays fees in (attester only)
+    ///
+    /// Requires a configured converter. Passing `None` reverts to paying in the fee token.
+    pub fn set_payment_asset(env: Env, attester: Address, asset: Option<Address>) -> Result<(), ResolverError> {
+        attester.require_auth();
+
+        let key = (DataKey::PaymentAsset, attester.clone());
+        match asset {
+            Some(asset) => {
+                if Self::get_converter(env.clone()).is_none() {
+                    return Err(ResolverError::ValidationFailed);
+                }
+                env.storage().persistent().set(&key, &asset);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+            },
+            None => env.storage().persistent().remove(&key),
+        }
+
+        Ok(())
+    }
+
+    /// Get the configured swap adapter, if any
+    pub fn get_converter(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Converter)
+    }
+
+    /// Get the maximum conversion slippage in basis points
+    pub fn get_max_slippage_bps(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::MaxSlippageBps).unwrap_or(0)
+    }
+
+    /// Get the asset an attester pays fees in, if not the fee token
+    pub fn get_payment_asset(env: Env, attester: Address) -> Option<Address> {
+        let key = (DataKey::PaymentAsset, attester);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Get the configured rebate schedule
+    pub fn get_rebate_tiers(env: Env) -> Vec<RebateTier> {
+        env.storage()
+            .instance()
+            .get(&DataKey::RebateTiers)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the number of paid attestations made by an attester
+    pub fn get_attester_volume(env: Env, attester: Address) -> u64 {
+        let key = (DataKey::AttesterVolume, attester);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Get the claimable rebate balance of an attester
+    pub fn get_rebate_balance(env: Env, attester: Address) -> i128 {
+        let key = (DataKey::RebateBalance, attester);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
     /// Get total fees collected
     pub fn get_total_collected(env: Env) -> i128 {
         env.storage().instance().get(&DataKey::TotalCollected).unwrap_or(0)
@@ -131,6 +341,209 @@ impl FeeCollectionResolver {
         env.storage().persistent().get(&key).unwrap_or(0)
     }
 
+    /// Record a paid attestation and accrue any rebate it earns.
+    ///
+    /// Returns the rebate amount, which is withheld from the fee recipient's balance.
+    fn accrue_rebate(env: &Env, attester: &Address, fee: i128) -> i128 {
+        let volume_key = (DataKey::AttesterVolume, attester.clone());
+        let volume: u64 = env.storage().persistent().get(&volume_key).unwrap_or(0) + 1;
+        env.storage().persistent().set(&volume_key, &volume);
+        env.storage()
+            .persistent()
+            .extend_ttl(&volume_key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+
+        // Highest tier whose threshold has been reached
+        let tiers = Self::get_rebate_tiers(env.clone());
+        let rebate_bps = tiers
+            .iter()
+            .filter(|tier| volume >= tier.min_volume)
+            .last()
+            .map(|tier| tier.rebate_bps)
+            .unwrap_or(0);
+
+        let rebate = fee * rebate_bps as i128 / BPS_DENOMINATOR;
+        if rebate > 0 {
+            let balance_key = (DataKey::RebateBalance, attester.clone());
+            let balance: i128 = env.storage().persistent().get(&balance_key).unwrap_or(0);
+            env.storage().persistent().set(&balance_key, &(balance + rebate));
+            env.storage()
+                .persistent()
+                .extend_ttl(&balance_key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+        }
+        rebate
+    }
+
+    /// Take the attestation fee from the attester, converting from their payment asset if set.
+    ///
+    /// Returns the amount of fee token received, which is at least the fee less the
+    /// configured slippage when converting.
+    fn take_fee(env: &Env, attester: &Address, fee_token: &Address, fee: i128) -> Result<i128, ResolverError> {
+        let payment_asset = Self::get_payment_asset(env.clone(), attester.clone()).filter(|asset| asset != fee_token);
+        let converter = Self::get_converter(env.clone());
+
+        let (Some(payment_asset), Some(converter)) = (payment_asset, converter) else {
+            token::Client::new(env, fee_token).transfer(attester, &env.current_contract_address(), &fee);
+            return Ok(fee);
+        };
+
+        let adapter = SwapAdapterClient::new(env, &converter);
+        let amount_in = adapter.quote_in(&payment_asset, fee_token, &fee);
+        if amount_in <= 0 {
+            return Err(ResolverError::ValidationFailed);
+        }
+
+        let max_slippage_bps = Self::get_max_slippage_bps(env.clone()) as i128;
+        let min_amount_out = fee * (BPS_DENOMINATOR - max_slippage_bps) / BPS_DENOMINATOR;
+
+        token::Client::new(env, &payment_asset).transfer(attester, &converter, &amount_in);
+        let received = adapter.swap(
+            &payment_asset,
+            fee_token,
+            &amount_in,
+            &min_amount_out,
+            &env.current_contract_address(),
+        );
+        if received < min_amount_out {
+            return Err(ResolverError::InsufficientFunds);
+        }
+
+        Ok(received)
+    }
+
+    /// Take the attestation fee from an allowance the payer granted this resolver
+    fn take_fee_from_allowance(
+        env: &Env,
+        payer: &Address,
+        fee_token: &Address,
+        fee: i128,
+    ) -> Result<(), ResolverError> {
+        let token = token::Client::new(env, fee_token);
+        let resolver = env.current_contract_address();
+        if token.allowance(payer, &resolver) < fee {
+            return Err(ResolverError::InsufficientFunds);
+        }
+
+        token.transfer_from(&resolver, payer, &resolver, &fee);
+        Ok(())
+    }
+
+    /// Configure the per-attestation reimbursement paid to relayers of delegated attestations (admin only)
+    ///
+    /// Only the configured `protocol` contract may trigger reimbursements through `onrelayed`.
+    /// Reimbursements are paid from the fee the relayed attestation paid, up to `amount`;
+    /// an amount of 0 disables them.
+    pub fn set_relayer_reimbursement(
+        env: Env,
+        admin: Address,
+        protocol: Address,
+        amount: i128,
+    ) -> Result<(), ResolverError> {
+        Self::require_admin(&env, &admin)?;
+
+        if amount < 0 {
+            return Err(ResolverError::ValidationFailed);
+        }
+
+        env.storage().instance().set(&DataKey::Protocol, &protocol);
+        env.storage().instance().set(&DataKey::RelayerReimbursement, &amount);
+
+        // Emit event
+        env.events().publish(
+            (String::from_str(&env, "RELAYER_REIMBURSEMENT_SET"),),
+            (&protocol, amount),
+        );
+
+        Ok(())
+    }
+
+    /// Configure the protocol contract allowed to call protocol-only hooks (admin only)
+    pub fn set_protocol(env: Env, admin: Address, protocol: Address) -> Result<(), ResolverError> {
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::Protocol, &protocol);
+
+        Ok(())
+    }
+
+    /// Override the attestation fee for a single schema (admin only)
+    pub fn set_schema_fee(env: Env, admin: Address, schema_uid: BytesN<32>, fee: i128) -> Result<(), ResolverError> {
+        Self::require_admin(&env, &admin)?;
+
+        if fee < 0 {
+            return Err(ResolverError::ValidationFailed);
+        }
+
+        Self::store_schema_fee(&env, &schema_uid, fee);
+
+        Ok(())
+    }
+
+    /// Get the attestation fee charged for a schema, falling back to the default fee
+    pub fn get_schema_fee(env: Env, schema_uid: BytesN<32>) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(DataKey::SchemaFee, schema_uid))
+            .unwrap_or_else(|| env.storage().instance().get(&DataKey::AttestationFee).unwrap_or(0))
+    }
+
+    /// Choose who pays the attestation fee for a schema (admin only)
+    ///
+    /// Recipients pay in the fee token through `transfer_from`, so they must approve
+    /// this resolver for at least the schema's fee before being attested.
+    pub fn set_schema_payer_mode(
+        env: Env,
+        admin: Address,
+        schema_uid: BytesN<32>,
+        payer_mode: PayerMode,
+    ) -> Result<(), ResolverError> {
+        Self::require_admin(&env, &admin)?;
+
+        let key = (DataKey::SchemaPayerMode, schema_uid.clone());
+        match payer_mode {
+            PayerMode::Attester => env.storage().persistent().remove(&key),
+            PayerMode::Recipient => {
+                env.storage().persistent().set(&key, &payer_mode);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+            },
+        }
+
+        // Emit event
+        env.events()
+            .publish((String::from_str(&env, "PAYER_MODE_SET"), schema_uid), payer_mode);
+
+        Ok(())
+    }
+
+    /// Get who pays the attestation fee for a schema
+    pub fn get_schema_payer_mode(env: Env, schema_uid: BytesN<32>) -> PayerMode {
+        env.storage()
+            .persistent()
+            .get(&(DataKey::SchemaPayerMode, schema_uid))
+            .unwrap_or(PayerMode::Attester)
+    }
+
+    /// Get the per-attestation relayer reimbursement
+    pub fn get_relayer_reimbursement(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RelayerReimbursement)
+            .unwrap_or(0)
+    }
+
+    fn store_schema_fee(env: &Env, schema_uid: &BytesN<32>, fee: i128) {
+        let key = (DataKey::SchemaFee, schema_uid.clone());
+        env.storage().persistent().set(&key, &fee);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+
+        // Emit event
+        env.events()
+            .publish((String::from_str(env, "SCHEMA_FEE_SET"), schema_uid.clone()), fee);
+    }
+
     fn require_admin(env: &Env, caller: &Address) -> Result<(), ResolverError> {
         caller.require_auth();
 
@@ -148,6 +561,69 @@ impl FeeCollectionResolver {
     }
 }
 
+// Same gating rationale as above; `onrelayed` is an optional protocol hook that is
+// not part of `ResolverInterface`.
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-fee-collection-resolver"))]
+#[contractimpl]
+impl FeeCollectionResolver {
+    /// Reimburse the relayer of a delegated attestation from collected fees
+    ///
+    /// Called by the protocol after storing an attestation submitted through
+    /// `attest_by_delegation`. Pays the configured reimbursement to `context.submitter`,
+    /// capped by the fee this attestation paid in `onattest`, so relayers are never paid
+    /// out of other attesters' fees.
+    pub fn onrelayed(
+        env: Env,
+        attestation: ResolverAttestationData,
+        context: ResolverContext,
+    ) -> Result<(), ResolverError> {
+        let protocol: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Protocol)
+            .ok_or(ResolverError::NotAuthorized)?;
+        protocol.require_auth();
+
+        let reimbursement = Self::get_relayer_reimbursement(env.clone());
+        if reimbursement == 0 {
+            return Ok(());
+        }
+
+        let fee_recipient: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::FeeRecipient)
+            .ok_or(ResolverError::CustomError)?;
+        let fee_token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::FeeToken)
+            .ok_or(ResolverError::CustomError)?;
+
+        let paid_key = (DataKey::AttestationFeePaid, attestation.uid.clone());
+        let fee_paid: i128 = env.storage().temporary().get(&paid_key).unwrap_or(0);
+        env.storage().temporary().remove(&paid_key);
+
+        let key = (DataKey::CollectedFees, fee_recipient);
+        let collected: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        let paid = reimbursement.min(fee_paid).min(collected);
+        if paid <= 0 {
+            return Ok(());
+        }
+
+        env.storage().persistent().set(&key, &(collected - paid));
+        token::Client::new(&env, &fee_token).transfer(&env.current_contract_address(), &context.submitter, &paid);
+
+        // Emit event
+        env.events().publish(
+            (String::from_str(&env, "RELAYER_REIMBURSED"), &context.submitter),
+            (&attestation.uid, paid),
+        );
+
+        Ok(())
+    }
+}
+
 // Same gating rationale as above. This ensures the trait method exports are
 // not duplicated in Wasm unless explicitly requested via the feature flag.
 #[cfg(any(not(target_arch = "wasm32"), feature = "export-fee-collection-resolver"))]
@@ -155,8 +631,8 @@ impl FeeCollectionResolver {
 impl ResolverInterface for FeeCollectionResolver {
     /// Collect fee before attestation
     fn onattest(env: Env, attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
-        // Get fee configuration
-        let attestation_fee: i128 = env.storage().instance().get(&DataKey::AttestationFee).unwrap_or(0);
+        // Get fee configuration, preferring the schema's own fee
+        let attestation_fee = Self::get_schema_fee(env.clone(), attestation.schema_uid.clone());
 
         if attestation_fee == 0 {
             return Ok(true); // No fee required
@@ -171,34 +647,51 @@ impl ResolverInterface for FeeCollectionResolver {
         // Ensure attester authorization is tied to the root invocation
         attestation.attester.require_auth();
 
-        // Collect fee from attester
         let fee_token: Address = env
             .storage()
             .instance()
             .get(&DataKey::FeeToken)
             .ok_or(ResolverError::CustomError)?;
 
-        let token_client = token::Client::new(&env, &fee_token);
-        token_client.transfer(&attestation.attester, &env.current_contract_address(), &attestation_fee);
-
-        // Track collected fees for recipient
+        let (payer, received, rebate) = match Self::get_schema_payer_mode(env.clone(), attestation.schema_uid.clone()) {
+            PayerMode::Attester => {
+                // Converts from the attester's payment asset when one is set
+                let received = Self::take_fee(&env, &attestation.attester, &fee_token, attestation_fee)?;
+                // Accrue volume rebate for the attester
+                let rebate = Self::accrue_rebate(&env, &attestation.attester, received);
+                (attestation.attester.clone(), received, rebate)
+            },
+            PayerMode::Recipient => {
+                // Pull from the recipient's allowance; rebates only reward attester volume
+                Self::take_fee_from_allowance(&env, &attestation.recipient, &fee_token, attestation_fee)?;
+                (attestation.recipient.clone(), attestation_fee, 0)
+            },
+        };
+
+        // Track collected fees for recipient, net of rebates
         let key = (DataKey::CollectedFees, fee_recipient.clone());
         let collected: i128 = env.storage().persistent().get(&key).unwrap_or(0);
-        env.storage().persistent().set(&key, &(collected + attestation_fee));
+        env.storage().persistent().set(&key, &(collected + received - rebate));
         env.storage()
             .persistent()
             .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
 
+        // Remember what this attestation paid in, which caps its relayer's reimbursement
+        env.storage().temporary().set(
+            &(DataKey::AttestationFeePaid, attestation.uid.clone()),
+            &(received - rebate),
+        );
+
         // Update total collected
         let total: i128 = env.storage().instance().get(&DataKey::TotalCollected).unwrap_or(0);
         env.storage()
             .instance()
-            .set(&DataKey::TotalCollected, &(total + attestation_fee));
+            .set(&DataKey::TotalCollected, &(total + received));
 
         // Emit event
         env.events().publish(
-            (String::from_str(&env, "FEE_COLLECTED"), &attestation.attester),
-            (&attestation.uid, &attestation_fee),
+            (String::from_str(&env, "FEE_COLLECTED"), &payer),
+            (&attestation.uid, &received),
         );
 
         Ok(true)
@@ -218,6 +711,53 @@ impl ResolverInterface for FeeCollectionResolver {
         Ok(())
     }
 
+    /// Lock in the current default fee for a newly attached schema
+    ///
+    /// Later changes to the default fee then only apply to schemas attached afterwards;
+    /// the admin can still adjust the schema's fee with `set_schema_fee`.
+    fn on_schema_attach(env: Env, schema_uid: BytesN<32>, _schema_authority: Address) -> Result<(), ResolverError> {
+        let protocol: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Protocol)
+            .ok_or(ResolverError::NotAuthorized)?;
+        protocol.require_auth();
+
+        let attestation_fee: i128 = env.storage().instance().get(&DataKey::AttestationFee).unwrap_or(0);
+        Self::store_schema_fee(&env, &schema_uid, attestation_fee);
+
+        Ok(())
+    }
+
+    /// Quote the schema's fee, in the attester's payment asset when it is converted
+    fn quote_fees(env: Env, schema_uid: BytesN<32>, attester: Address) -> Result<Vec<(Address, i128)>, ResolverError> {
+        let attestation_fee = Self::get_schema_fee(env.clone(), schema_uid.clone());
+        if attestation_fee == 0 {
+            return Ok(Vec::new(&env));
+        }
+
+        let fee_token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::FeeToken)
+            .ok_or(ResolverError::CustomError)?;
+
+        // Recipients always pay in the fee token
+        if Self::get_schema_payer_mode(env.clone(), schema_uid) == PayerMode::Recipient {
+            return Ok(Vec::from_array(&env, [(fee_token, attestation_fee)]));
+        }
+
+        // Mirrors take_fee: the attester pays the adapter's quote in their payment asset
+        let payment_asset = Self::get_payment_asset(env.clone(), attester).filter(|asset| asset != &fee_token);
+        if let (Some(payment_asset), Some(converter)) = (payment_asset, Self::get_converter(env.clone())) {
+            let amount_in =
+                SwapAdapterClient::new(&env, &converter).quote_in(&payment_asset, &fee_token, &attestation_fee);
+            return Ok(Vec::from_array(&env, [(payment_asset, amount_in)]));
+        }
+
+        Ok(Vec::from_array(&env, [(fee_token, attestation_fee)]))
+    }
+
     fn metadata(env: Env) -> ResolverMetadata {
         ResolverMetadata {
             name: String::from_str(&env, "Fee Collection Resolver"),
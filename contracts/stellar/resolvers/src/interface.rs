@@ -1,20 +1,7 @@
-use soroban_sdk::{contracterror, contracttype, Address, BytesN, Env, String};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ResolverAttestationData {
-    pub uid: BytesN<32>,
-    pub schema_uid: BytesN<32>,
-    pub recipient: Address,
-    pub attester: Address,
-    pub time: u64,
-    pub expiration_time: u64, // 0 = not set
-    pub revocation_time: u64, // 0 = not set
-    pub revocable: bool,
-    pub ref_uid: soroban_sdk::Bytes, // empty bytes = not set
-    pub data: soroban_sdk::Bytes,
-    pub value: i128, // 0 = not set
-}
+// The flattened hook view and relay context are shared with the protocol
+pub use attest_types::{ResolverAttestation as ResolverAttestationData, ResolverContext};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -37,19 +24,8 @@ pub enum ResolverType {
     Custom,
 }
 
-#[contracterror]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
-#[repr(u32)]
-pub enum ResolverError {
-    NotAuthorized = 1,
-    InvalidAttestation = 2,
-    InvalidSchema = 3,
-    InsufficientFunds = 4,
-    TokenTransferFailed = 5,
-    StakeRequired = 6,
-    ValidationFailed = 7,
-    CustomError = 8,
-}
+// Error codes live in the shared `attest-errors` registry (resolver range: 3xx).
+pub use attest_errors::ResolverError;
 
 /// Standard Resolver Interface that all resolvers must implement
 /// This provides a consistent interface for the protocol to interact with resolvers
@@ -231,6 +207,76 @@ pub trait ResolverInterface {
     /// - Resolver must determine context (attestation vs revocation) internally
     fn onresolve(env: Env, attestation_uid: BytesN<32>, attester: Address) -> Result<(), ResolverError>;
 
+    /// **SCHEMA ATTACHMENT HOOK** (optional)
+    ///
+    /// Called by the protocol when a newly registered schema selects this resolver,
+    /// so per-schema configuration (fees, limits) is initialized in the same
+    /// transaction instead of a separate admin call that can be forgotten.
+    ///
+    /// # Parameters
+    /// * `env` - Soroban environment for storage and external calls
+    /// * `schema_uid` - Unique identifier of the schema being registered
+    /// * `schema_authority` - Address that registered the schema
+    ///
+    /// # Returns
+    /// * `Ok(())` - Configuration initialized (the default does nothing)
+    /// * `Err(ResolverError)` - Initialization failed (does not revert registration)
+    ///
+    /// # Security Notes
+    /// - Resolvers that store state here MUST verify the caller is the protocol
+    /// - Not exported unless the implementation overrides it
+    fn on_schema_attach(_env: Env, _schema_uid: BytesN<32>, _schema_authority: Address) -> Result<(), ResolverError> {
+        Ok(())
+    }
+
+    /// **FEE QUOTE VIEW** (optional)
+    ///
+    /// Reports the tokens and amounts `onattest` would take from an attester for an
+    /// attestation under a schema, so wallets can authorize exact amounts ahead of
+    /// submission instead of over-approving.
+    ///
+    /// # Parameters
+    /// * `env` - Soroban environment for storage and external calls
+    /// * `schema_uid` - Unique identifier of the schema being attested to
+    /// * `attester` - Address that would pay
+    ///
+    /// # Returns
+    /// * `Ok(Vec<(token, amount)>)` - The charges, empty if none (the default)
+    /// * `Err(ResolverError)` - The quote could not be computed
+    ///
+    /// # Security Notes
+    /// - MUST NOT modify state; the protocol calls it from a read-only view
+    /// - Not exported unless the implementation overrides it
+    fn quote_fees(
+        env: Env,
+        _schema_uid: BytesN<32>,
+        _attester: Address,
+    ) -> Result<Vec<(Address, i128)>, ResolverError> {
+        Ok(Vec::new(&env))
+    }
+
+    /// **EXPIRATION OVERRIDE HOOK** (optional)
+    ///
+    /// Called by the protocol right after `onattest` allows an attestation, so the
+    /// resolver can decide how long it stays valid, e.g. a membership resolver that
+    /// ends the attestation with the member's subscription.
+    ///
+    /// # Parameters
+    /// * `env` - Soroban environment for storage and external calls
+    /// * `attestation` - Attestation data about to be stored
+    ///
+    /// # Returns
+    /// * `Ok(Some(timestamp))` - Store this expiration instead of the attester's
+    /// * `Ok(None)` - Keep the attester's expiration (the default)
+    /// * `Err(ResolverError)` - Treated as no override
+    ///
+    /// # Security Notes
+    /// - The protocol rejects overrides in the past or beyond the schema's expiration horizon
+    /// - Not exported unless the implementation overrides it
+    fn expiration_override(_env: Env, _attestation: ResolverAttestationData) -> Result<Option<u64>, ResolverError> {
+        Ok(None)
+    }
+
     /// **RESOLVER METADATA PROVIDER**
     ///
     /// Returns metadata describing the resolver's purpose, capabilities, and type.
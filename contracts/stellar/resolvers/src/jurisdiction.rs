@@ -0,0 +1,171 @@
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Jurisdiction Resolver
+// ►
+// ► Lets regulated issuers restrict which regions attestations may come from.
+// ►
+// ► MODEL:
+// ► - The admin tags attester addresses with a jurisdiction code (e.g. ISO 3166
+// ►   alpha-2 such as `US` or `DE`).
+// ► - The admin declares, per schema, the jurisdictions it accepts.
+// ► - **onattest** rejects attestations from attesters whose jurisdiction is not
+// ►   allowed for the schema. Untagged attesters are rejected for restricted
+// ►   schemas; schemas without a declared list are unrestricted.
+// ══════════════════════════════════════════════════════════════════════════════
+
+use crate::interface::{ResolverAttestationData, ResolverError, ResolverInterface, ResolverMetadata, ResolverType};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, String, Symbol, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Initialized,
+    AttesterJurisdiction(Address),
+    SchemaJurisdictions(BytesN<32>),
+}
+
+#[contract]
+pub struct JurisdictionResolver;
+
+#[contractimpl]
+impl JurisdictionResolver {
+    /// Initialize the resolver with its admin
+    pub fn initialize(env: Env, admin: Address) -> Result<(), ResolverError> {
+        if env.storage().instance().has(&DataKey::Initialized) {
+            return Err(ResolverError::CustomError); // Already initialized
+        }
+
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Initialized, &true);
+
+        env.storage()
+            .instance()
+            .extend_ttl(env.storage().max_ttl() - 100, env.storage().max_ttl());
+
+        Ok(())
+    }
+
+    /// Tag an attester with a jurisdiction code, or clear it with `None` (admin only)
+    pub fn set_attester_jurisdiction(
+        env: Env,
+        admin: Address,
+        attester: Address,
+        jurisdiction: Option<Symbol>,
+    ) -> Result<(), ResolverError> {
+        Self::require_admin(&env, &admin)?;
+
+        let key = DataKey::AttesterJurisdiction(attester.clone());
+        match &jurisdiction {
+            Some(code) => {
+                env.storage().persistent().set(&key, code);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+            },
+            None => env.storage().persistent().remove(&key),
+        }
+
+        // Emit event
+        env.events()
+            .publish((String::from_str(&env, "JURISDICTION_TAGGED"), &attester), jurisdiction);
+
+        Ok(())
+    }
+
+    /// Declare the jurisdictions a schema accepts attestations from (admin only)
+    ///
+    /// An empty list removes the restriction.
+    pub fn set_schema_jurisdictions(
+        env: Env,
+        admin: Address,
+        schema_uid: BytesN<32>,
+        jurisdictions: Vec<Symbol>,
+    ) -> Result<(), ResolverError> {
+        Self::require_admin(&env, &admin)?;
+
+        let key = DataKey::SchemaJurisdictions(schema_uid.clone());
+        if jurisdictions.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &jurisdictions);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+        }
+
+        // Emit event
+        env.events().publish(
+            (String::from_str(&env, "SCHEMA_JURISDICTIONS"), &schema_uid),
+            jurisdictions,
+        );
+
+        Ok(())
+    }
+
+    /// Get the jurisdiction an attester is tagged with
+    pub fn get_attester_jurisdiction(env: Env, attester: Address) -> Option<Symbol> {
+        env.storage().persistent().get(&DataKey::AttesterJurisdiction(attester))
+    }
+
+    /// Get the jurisdictions a schema accepts; empty means unrestricted
+    pub fn get_schema_jurisdictions(env: Env, schema_uid: BytesN<32>) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SchemaJurisdictions(schema_uid))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), ResolverError> {
+        caller.require_auth();
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ResolverError::CustomError)?;
+
+        if caller != &admin {
+            return Err(ResolverError::NotAuthorized);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-jurisdiction-resolver"))]
+#[contractimpl]
+impl ResolverInterface for JurisdictionResolver {
+    /// Reject attesters whose jurisdiction the schema does not accept
+    fn onattest(env: Env, attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+        let allowed = Self::get_schema_jurisdictions(env.clone(), attestation.schema_uid.clone());
+        if allowed.is_empty() {
+            return Ok(true); // Unrestricted schema
+        }
+
+        match Self::get_attester_jurisdiction(env, attestation.attester) {
+            Some(code) if allowed.contains(&code) => Ok(true),
+            _ => Err(ResolverError::NotAuthorized),
+        }
+    }
+
+    /// Revocations are not restricted by jurisdiction
+    fn onrevoke(_env: Env, _attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+        Ok(true)
+    }
+
+    /// No post-processing needed
+    fn onresolve(_env: Env, _attestation_uid: BytesN<32>, _attester: Address) -> Result<(), ResolverError> {
+        Ok(())
+    }
+
+    fn metadata(env: Env) -> ResolverMetadata {
+        ResolverMetadata {
+            name: String::from_str(&env, "Jurisdiction Resolver"),
+            version: String::from_str(&env, "1.0.0"),
+            description: String::from_str(&env, "Restricts attesters by jurisdiction per schema"),
+            resolver_type: ResolverType::Custom,
+        }
+    }
+}
@@ -19,6 +19,16 @@
 //!   incentivizing participation in the attestation ecosystem
 //! - **FeeCollectionResolver**: Collects fees for attestation processing with configurable
 //!   fee amounts and recipient management, enabling monetization of attestation services
+//! - **NotarizationResolver**: Anchors document content hashes carried in attestation values,
+//!   exposing `verify_document(hash)` for document-proof products
+//! - **JurisdictionResolver**: Rejects attestations from attesters whose admin-assigned
+//!   jurisdiction is not accepted by the schema, for regulated issuers
+//! - **MirrorResolver**: Keeps per-subject attestation UID histories exposed through
+//!   `get_subject_history(subject)`, for deployments without an external indexer
+//! - **AllowlistResolver**: Accepts attestations only from allowlisted attesters, with
+//!   expirable entries, bulk import and `prune_expired_entries` maintenance
+//! - **ReceiptNftResolver**: Mints an NFT receipt to each attestation recipient and burns it
+//!   on revocation, with token URIs pointing back to the attestation UID
 //!
 //! ## Gating Model (Features + Target)
 //!
@@ -27,6 +37,11 @@
 //!   - `export-default-resolver`
 //!   - `export-token-reward-resolver`
 //!   - `export-fee-collection-resolver`
+//!   - `export-notarization-resolver`
+//!   - `export-jurisdiction-resolver`
+//!   - `export-mirror-resolver`
+//!   - `export-allowlist-resolver`
+//!   - `export-receipt-nft-resolver`
 //! - When building for tests (not(target_arch = "wasm32")): all resolvers are available so integration tests
 //!   can import and use any resolver implementation without feature juggling.
 //!
@@ -88,6 +103,33 @@ pub mod token_reward;
 #[cfg(any(not(target_arch = "wasm32"), feature = "export-fee-collection-resolver"))]
 pub mod fee_collection;
 
+/// Notarization resolver implementation that anchors document content hashes.
+/// This resolver extracts a `document_hash` field from the attestation value and
+/// records it with a timestamp, so anyone can verify when a document was notarized.
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-notarization-resolver"))]
+pub mod notarization;
+
+/// Jurisdiction resolver implementation that geofences attesters per schema.
+/// The admin tags attesters with jurisdiction codes and declares which codes each
+/// schema accepts; attestations from other jurisdictions are rejected.
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-jurisdiction-resolver"))]
+pub mod jurisdiction;
+
+/// Mirror resolver implementation that records the attestation UIDs seen for
+/// each subject, letting small deployments query histories without an indexer.
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-mirror-resolver"))]
+pub mod mirror;
+
+/// Allowlist resolver implementation for access control by attester address.
+/// Entries carry optional expiry timestamps and can be imported in bulk.
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-allowlist-resolver"))]
+pub mod allowlist;
+
+/// Receipt NFT resolver implementation that mints a non-fungible receipt to each
+/// attestation recipient and burns it when the attestation is revoked.
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-receipt-nft-resolver"))]
+pub mod receipt_nft;
+
 /// Factory pattern implementation for creating multiple resolver instances.
 /// This module demonstrates how to use env.register_at() to deploy contracts
 /// at predetermined addresses and manage multiple resolver configurations.
@@ -101,7 +143,9 @@ pub mod factory;
 /// Re-export core interface types that are used across all resolver implementations.
 /// These types form the foundation of the resolver system and are always available
 /// regardless of which specific resolver implementations are compiled.
-pub use interface::{ResolverAttestationData, ResolverError, ResolverInterface, ResolverMetadata, ResolverType};
+pub use interface::{
+    ResolverAttestationData, ResolverContext, ResolverError, ResolverInterface, ResolverMetadata, ResolverType,
+};
 
 /// Re-export the DefaultResolver implementation when available.
 /// Only export to Wasm when the `export-default-resolver` feature is enabled;
@@ -121,6 +165,36 @@ pub use token_reward::TokenRewardResolver;
 #[cfg(any(not(target_arch = "wasm32"), feature = "export-fee-collection-resolver"))]
 pub use fee_collection::FeeCollectionResolver;
 
+/// Re-export the NotarizationResolver implementation when available.
+/// Only export to Wasm when the `export-notarization-resolver` feature is enabled;
+/// always available on native builds for tests and integration.
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-notarization-resolver"))]
+pub use notarization::NotarizationResolver;
+
+/// Re-export the JurisdictionResolver implementation when available.
+/// Only export to Wasm when the `export-jurisdiction-resolver` feature is enabled;
+/// always available on native builds for tests and integration.
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-jurisdiction-resolver"))]
+pub use jurisdiction::JurisdictionResolver;
+
+/// Re-export the MirrorResolver implementation when available.
+/// Only export to Wasm when the `export-mirror-resolver` feature is enabled;
+/// always available on native builds for tests and integration.
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-mirror-resolver"))]
+pub use mirror::MirrorResolver;
+
+/// Re-export the AllowlistResolver implementation when available.
+/// Only export to Wasm when the `export-allowlist-resolver` feature is enabled;
+/// always available on native builds for tests and integration.
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-allowlist-resolver"))]
+pub use allowlist::AllowlistResolver;
+
+/// Re-export the ReceiptNftResolver implementation when available.
+/// Only export to Wasm when the `export-receipt-nft-resolver` feature is enabled;
+/// always available on native builds for tests and integration.
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-receipt-nft-resolver"))]
+pub use receipt_nft::ReceiptNftResolver;
+
 /// Re-export the ResolverFactory implementation when available.
 /// Only export to Wasm when the `export-factory` feature is enabled;
 /// always available on native builds for tests and integration.
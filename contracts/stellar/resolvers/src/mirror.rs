@@ -0,0 +1,100 @@
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Mirror Resolver
+// ►
+// ► Keeps a compact per-subject list of the attestation UIDs it has seen, so
+// ► small deployments can answer "what has been attested about X" on-chain
+// ► instead of running an external indexer.
+// ►
+// ► FLOW:
+// ► - **onattest**: Remembers the attestation's subject in temporary storage;
+// ►   onresolve only receives the UID and attester.
+// ► - **onresolve**: Appends the UID to the subject's history once the
+// ►   attestation has been stored. Histories keep the most recent
+// ►   `MAX_SUBJECT_HISTORY` UIDs.
+// ► - **get_subject_history**: Returns the subject's UIDs, oldest first.
+// ══════════════════════════════════════════════════════════════════════════════
+
+use crate::interface::{ResolverAttestationData, ResolverError, ResolverInterface, ResolverMetadata, ResolverType};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, String, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    PendingSubject(BytesN<32>),
+    SubjectHistory(Address),
+}
+
+/// Maximum number of UIDs retained per subject; the oldest are dropped first
+pub const MAX_SUBJECT_HISTORY: u32 = 200;
+
+#[contract]
+pub struct MirrorResolver;
+
+#[contractimpl]
+impl MirrorResolver {
+    /// Get the attestation UIDs seen for a subject, oldest first
+    pub fn get_subject_history(env: Env, subject: Address) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SubjectHistory(subject))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-mirror-resolver"))]
+#[contractimpl]
+impl ResolverInterface for MirrorResolver {
+    /// Remember the subject so onresolve can file the UID under it
+    fn onattest(env: Env, attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+        env.storage()
+            .temporary()
+            .set(&DataKey::PendingSubject(attestation.uid), &attestation.recipient);
+        Ok(true)
+    }
+
+    /// Revocations keep their place in the history
+    fn onrevoke(_env: Env, _attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+        Ok(true)
+    }
+
+    /// Append the stored attestation to its subject's history
+    fn onresolve(env: Env, attestation_uid: BytesN<32>, _attester: Address) -> Result<(), ResolverError> {
+        let pending_key = DataKey::PendingSubject(attestation_uid.clone());
+        let subject: Address = env
+            .storage()
+            .temporary()
+            .get(&pending_key)
+            .ok_or(ResolverError::InvalidAttestation)?;
+        env.storage().temporary().remove(&pending_key);
+
+        let mut history = Self::get_subject_history(env.clone(), subject.clone());
+        if history.contains(&attestation_uid) {
+            return Ok(());
+        }
+        if history.len() >= MAX_SUBJECT_HISTORY {
+            history.pop_front();
+        }
+        history.push_back(attestation_uid.clone());
+
+        let key = DataKey::SubjectHistory(subject.clone());
+        env.storage().persistent().set(&key, &history);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+
+        // Emit event
+        env.events()
+            .publish((String::from_str(&env, "SUBJECT_MIRRORED"), &subject), attestation_uid);
+
+        Ok(())
+    }
+
+    fn metadata(env: Env) -> ResolverMetadata {
+        ResolverMetadata {
+            name: String::from_str(&env, "Mirror Resolver"),
+            version: String::from_str(&env, "1.0.0"),
+            description: String::from_str(&env, "Keeps per-subject attestation histories on-chain"),
+            resolver_type: ResolverType::Custom,
+        }
+    }
+}
@@ -0,0 +1,151 @@
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Notarization Resolver
+// ►
+// ► Anchors document content hashes carried in attestation values, giving
+// ► document-proof products a drop-in resolver instead of a bespoke contract.
+// ►
+// ► VALUE FORMAT:
+// ► The attestation value must contain a `document_hash` field holding the
+// ► hex-encoded SHA-256 of the document, optionally `0x`-prefixed:
+// ►     {"document_hash":"9f86d081884c7d65...","title":"..."}
+// ►
+// ► FLOW:
+// ► - **onattest**: Extracts the hash and records it in the notarization index.
+// ►   The index is written here rather than in onresolve, which only receives the
+// ►   attestation UID; the write is atomic with the attestation itself.
+// ► - **onrevoke**: Marks the anchored record as revoked.
+// ► - **verify_document**: Returns the earliest anchor of a hash, if any.
+// ══════════════════════════════════════════════════════════════════════════════
+
+use crate::interface::{ResolverAttestationData, ResolverError, ResolverInterface, ResolverMetadata, ResolverType};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Bytes, BytesN, Env, String};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Notarization(BytesN<32>),
+}
+
+/// The JSON key holding the document hash in the attestation value
+const DOCUMENT_HASH_FIELD: &[u8] = b"\"document_hash\"";
+
+/// A document hash anchored by an attestation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotarizationRecord {
+    pub attestation_uid: BytesN<32>,
+    pub attester: Address,
+    pub timestamp: u64,
+    pub revoked: bool,
+}
+
+#[contract]
+pub struct NotarizationResolver;
+
+#[contractimpl]
+impl NotarizationResolver {
+    /// Look up the anchor for a document hash
+    pub fn verify_document(env: Env, hash: BytesN<32>) -> Option<NotarizationRecord> {
+        env.storage().persistent().get(&DataKey::Notarization(hash))
+    }
+}
+
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-notarization-resolver"))]
+#[contractimpl]
+impl ResolverInterface for NotarizationResolver {
+    /// Extract the document hash and anchor it; the first anchor of a hash wins
+    fn onattest(env: Env, attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+        let hash = extract_document_hash(&env, &attestation.data).ok_or(ResolverError::InvalidAttestation)?;
+
+        let key = DataKey::Notarization(hash.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(ResolverError::ValidationFailed); // Already notarized
+        }
+
+        let record = NotarizationRecord {
+            attestation_uid: attestation.uid.clone(),
+            attester: attestation.attester.clone(),
+            timestamp: env.ledger().timestamp(),
+            revoked: false,
+        };
+        env.storage().persistent().set(&key, &record);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+
+        // Emit event
+        env.events().publish(
+            (String::from_str(&env, "DOCUMENT_NOTARIZED"), &attestation.attester),
+            (&hash, &attestation.uid),
+        );
+
+        Ok(true)
+    }
+
+    /// Mark the anchor created by this attestation as revoked
+    fn onrevoke(env: Env, attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+        if let Some(hash) = extract_document_hash(&env, &attestation.data) {
+            let key = DataKey::Notarization(hash);
+            if let Some(mut record) = env.storage().persistent().get::<_, NotarizationRecord>(&key) {
+                if record.attestation_uid == attestation.uid {
+                    record.revoked = true;
+                    env.storage().persistent().set(&key, &record);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// Anchoring happens in onattest
+    fn onresolve(_env: Env, _attestation_uid: BytesN<32>, _attester: Address) -> Result<(), ResolverError> {
+        Ok(())
+    }
+
+    fn metadata(env: Env) -> ResolverMetadata {
+        ResolverMetadata {
+            name: String::from_str(&env, "Notarization Resolver"),
+            version: String::from_str(&env, "1.0.0"),
+            description: String::from_str(&env, "Anchors document content hashes with a timestamp"),
+            resolver_type: ResolverType::Custom,
+        }
+    }
+}
+
+/// Find the `document_hash` field in a JSON attestation value and decode its hex value
+fn extract_document_hash(env: &Env, data: &Bytes) -> Option<BytesN<32>> {
+    let field = Bytes::from_slice(env, DOCUMENT_HASH_FIELD);
+    let len = data.len();
+    let mut i =
+        (0..len.checked_sub(field.len())? + 1).find(|&i| data.slice(i..i + field.len()) == field)? + field.len();
+
+    // Skip `:` and whitespace up to the opening quote
+    while i < len && matches!(data.get(i)?, b' ' | b':') {
+        i += 1;
+    }
+    if data.get(i)? != b'"' {
+        return None;
+    }
+    i += 1;
+    if i + 2 <= len && data.get(i)? == b'0' && matches!(data.get(i + 1)?, b'x' | b'X') {
+        i += 2;
+    }
+
+    let mut hash = [0u8; 32];
+    for byte in hash.iter_mut() {
+        *byte = hex_value(data.get(i)?)? << 4 | hex_value(data.get(i + 1)?)?;
+        i += 2;
+    }
+    if data.get(i)? != b'"' {
+        return None;
+    }
+    Some(BytesN::from_array(env, &hash))
+}
+
+fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
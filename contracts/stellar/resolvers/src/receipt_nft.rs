@@ -0,0 +1,241 @@
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Receipt NFT Resolver
+// ►
+// ► Mints a non-fungible receipt to the recipient of every attestation it
+// ► resolves, so credentials show up as visible artifacts in wallets, and burns
+// ► the receipt again when the attestation is revoked.
+// ►
+// ► FLOW:
+// ► - **onattest**: Remembers the recipient in temporary storage; onresolve
+// ►   only receives the UID and attester.
+// ► - **onrevoke**: Marks the attestation's receipt for burning.
+// ► - **onresolve**: Mints the pending receipt, or burns the pending one.
+// ► - **token_uri**: `base_uri` followed by the hex-encoded attestation UID,
+// ►   so each receipt's metadata points back to its attestation.
+// ►
+// ► SECURITY MODEL:
+// ► - **ResolverInterface**: Only the configured protocol contract can call the
+// ►   hooks, otherwise anyone could mint receipts.
+// ► - **Token**: Standard OpenZeppelin non-fungible token via `default_impl`.
+// ══════════════════════════════════════════════════════════════════════════════
+
+use crate::interface::{ResolverAttestationData, ResolverError, ResolverInterface, ResolverMetadata, ResolverType};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, String};
+use stellar_macros::default_impl;
+use stellar_tokens::non_fungible::{burnable::emit_burn, Base, NonFungibleToken, MAX_BASE_URI_LEN};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Initialized,
+    Protocol,
+    PendingMint(BytesN<32>),
+    PendingBurn(BytesN<32>),
+    TokenByAttestation(BytesN<32>),
+    AttestationByToken(u32),
+}
+
+#[contract]
+pub struct ReceiptNftResolver;
+
+#[contractimpl]
+impl ReceiptNftResolver {
+    /// Initialize the resolver with the protocol it serves and the receipt collection metadata
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        protocol: Address,
+        base_uri: String,
+        name: String,
+        symbol: String,
+    ) -> Result<(), ResolverError> {
+        if env.storage().instance().has(&DataKey::Initialized) {
+            return Err(ResolverError::CustomError); // Already initialized
+        }
+
+        admin.require_auth();
+
+        if base_uri.len() as usize > MAX_BASE_URI_LEN {
+            return Err(ResolverError::CustomError);
+        }
+        Base::set_metadata(&env, base_uri, name, symbol);
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Protocol, &protocol);
+        env.storage().instance().set(&DataKey::Initialized, &true);
+
+        env.storage()
+            .instance()
+            .extend_ttl(env.storage().max_ttl() - 100, env.storage().max_ttl());
+
+        Ok(())
+    }
+
+    /// Get the receipt token minted for an attestation, if any
+    pub fn get_receipt_token(env: Env, attestation_uid: BytesN<32>) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TokenByAttestation(attestation_uid))
+    }
+
+    /// Get the attestation a receipt token was minted for
+    pub fn attestation_of(env: Env, token_id: u32) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&DataKey::AttestationByToken(token_id))
+    }
+
+    fn require_protocol(env: &Env) -> Result<(), ResolverError> {
+        let protocol: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Protocol)
+            .ok_or(ResolverError::CustomError)?; // Resolver not properly initialized
+        protocol.require_auth();
+        Ok(())
+    }
+
+    fn mint_receipt(env: &Env, attestation_uid: &BytesN<32>, recipient: &Address) {
+        let token_id = Base::sequential_mint(env, recipient);
+
+        let token_key = DataKey::TokenByAttestation(attestation_uid.clone());
+        let uid_key = DataKey::AttestationByToken(token_id);
+        env.storage().persistent().set(&token_key, &token_id);
+        env.storage().persistent().set(&uid_key, attestation_uid);
+        env.storage()
+            .persistent()
+            .extend_ttl(&token_key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+        env.storage()
+            .persistent()
+            .extend_ttl(&uid_key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+
+        // Emit event
+        env.events().publish(
+            (String::from_str(env, "RECEIPT_MINTED"), recipient),
+            (attestation_uid.clone(), token_id),
+        );
+    }
+
+    fn burn_receipt(env: &Env, attestation_uid: &BytesN<32>) -> Result<(), ResolverError> {
+        let token_key = DataKey::TokenByAttestation(attestation_uid.clone());
+        let token_id: u32 = env
+            .storage()
+            .persistent()
+            .get(&token_key)
+            .ok_or(ResolverError::InvalidAttestation)?;
+
+        // The receipt may have been transferred; burn it from whoever holds it
+        let owner = Base::owner_of(env, token_id);
+        Base::update(env, Some(&owner), None, token_id);
+        emit_burn(env, &owner, token_id);
+
+        env.storage().persistent().remove(&token_key);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AttestationByToken(token_id));
+
+        // Emit event
+        env.events().publish(
+            (String::from_str(env, "RECEIPT_BURNED"), &owner),
+            (attestation_uid.clone(), token_id),
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(any(not(target_arch = "wasm32"), feature = "export-receipt-nft-resolver"))]
+#[contractimpl]
+impl ResolverInterface for ReceiptNftResolver {
+    /// Remember the recipient so onresolve can mint the receipt to them
+    fn onattest(env: Env, attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+        Self::require_protocol(&env)?;
+        env.storage()
+            .temporary()
+            .set(&DataKey::PendingMint(attestation.uid), &attestation.recipient);
+        Ok(true)
+    }
+
+    /// Mark the attestation's receipt for burning once the revocation is resolved
+    fn onrevoke(env: Env, attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+        Self::require_protocol(&env)?;
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::TokenByAttestation(attestation.uid.clone()))
+        {
+            env.storage()
+                .temporary()
+                .set(&DataKey::PendingBurn(attestation.uid), &true);
+        }
+        Ok(true)
+    }
+
+    /// Mint the receipt for a stored attestation, or burn it for a revoked one
+    fn onresolve(env: Env, attestation_uid: BytesN<32>, _attester: Address) -> Result<(), ResolverError> {
+        Self::require_protocol(&env)?;
+
+        let mint_key = DataKey::PendingMint(attestation_uid.clone());
+        if let Some(recipient) = env.storage().temporary().get::<_, Address>(&mint_key) {
+            env.storage().temporary().remove(&mint_key);
+            if !env
+                .storage()
+                .persistent()
+                .has(&DataKey::TokenByAttestation(attestation_uid.clone()))
+            {
+                Self::mint_receipt(&env, &attestation_uid, &recipient);
+            }
+            return Ok(());
+        }
+
+        let burn_key = DataKey::PendingBurn(attestation_uid.clone());
+        if env.storage().temporary().has(&burn_key) {
+            env.storage().temporary().remove(&burn_key);
+            return Self::burn_receipt(&env, &attestation_uid);
+        }
+
+        Err(ResolverError::InvalidAttestation)
+    }
+
+    fn metadata(env: Env) -> ResolverMetadata {
+        ResolverMetadata {
+            name: String::from_str(&env, "Receipt NFT Resolver"),
+            version: String::from_str(&env, "1.0.0"),
+            description: String::from_str(&env, "Mints wallet-visible NFT receipts for attestations"),
+            resolver_type: ResolverType::Custom,
+        }
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► OpenZeppelin Non-Fungible Token Interface Implementation
+// ►
+// ► Receipts are standard non-fungible tokens. `token_uri` is overridden so the
+// ► metadata link carries the attestation UID instead of the sequential token ID.
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[default_impl]
+#[contractimpl]
+impl NonFungibleToken for ReceiptNftResolver {
+    type ContractType = Base;
+
+    fn token_uri(e: &Env, token_id: u32) -> String {
+        // Panics for tokens that do not exist
+        let _ = Base::owner_of(e, token_id);
+        let attestation_uid: BytesN<32> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::AttestationByToken(token_id))
+            .unwrap();
+
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let base_uri = Base::base_uri(e);
+        let base_len = base_uri.len() as usize;
+        let uri = &mut [0u8; MAX_BASE_URI_LEN + 64];
+        base_uri.copy_into_slice(&mut uri[..base_len]);
+        for (i, byte) in attestation_uid.to_array().iter().enumerate() {
+            uri[base_len + 2 * i] = HEX[(byte >> 4) as usize];
+            uri[base_len + 2 * i + 1] = HEX[(byte & 0x0f) as usize];
+        }
+        String::from_bytes(e, &uri[..base_len + 64])
+    }
+}
@@ -0,0 +1,100 @@
+#![cfg(test)]
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, BytesN as _, Ledger, LedgerInfo},
+    vec, Address, Bytes, BytesN, Env,
+};
+
+use resolvers::allowlist::AllowlistResolverClient;
+use resolvers::{AllowlistResolver, ResolverAttestationData, ResolverError};
+
+fn setup<'a>() -> (Env, Address, AllowlistResolverClient<'a>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set(LedgerInfo {
+        timestamp: 1_000,
+        protocol_version: 22,
+        sequence_number: 10,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 16,
+        min_persistent_entry_ttl: 4096,
+        max_entry_ttl: 6_312_000,
+    });
+
+    let admin = Address::generate(&env);
+    let resolver_address = env.register(AllowlistResolver, ());
+    let client = AllowlistResolverClient::new(&env, &resolver_address);
+    client.initialize(&admin);
+    (env, admin, client)
+}
+
+fn build_attestation(env: &Env, attester: &Address) -> ResolverAttestationData {
+    ResolverAttestationData {
+        uid: BytesN::random(env),
+        schema_uid: BytesN::random(env),
+        recipient: Address::generate(env),
+        attester: attester.clone(),
+        time: 0,
+        expiration_time: 0,
+        revocation_time: 0,
+        revocable: true,
+        ref_uid: Bytes::new(env),
+        data: Bytes::new(env),
+        value: 0,
+    }
+}
+
+#[test]
+fn test_expired_entries_are_rejected_and_pruned() {
+    let (env, admin, client) = setup();
+    let seasonal = Address::generate(&env);
+    let permanent = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let imported = client.import_allowlist(
+        &admin,
+        &vec![&env, (seasonal.clone(), 2_000u64), (permanent.clone(), 0u64)],
+    );
+    assert_eq!(imported, 2);
+    assert!(client.onattest(&build_attestation(&env, &seasonal)));
+    assert!(client.onattest(&build_attestation(&env, &permanent)));
+    let res = client.try_onattest(&build_attestation(&env, &stranger));
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::NotAuthorized)));
+    assert_eq!(client.prune_expired_entries(), 0);
+
+    // The seasonal entry lapses on its own
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert!(!client.is_allowlisted(&seasonal));
+    let res = client.try_onattest(&build_attestation(&env, &seasonal));
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::NotAuthorized)));
+    assert!(client.onattest(&build_attestation(&env, &permanent)));
+
+    assert_eq!(client.prune_expired_entries(), 1);
+    assert_eq!(client.get_allowlist_entry(&seasonal), None);
+    assert_eq!(client.get_allowlist_entry(&permanent), Some(0));
+
+    // Re-importing renews the entry for the next season
+    client.import_allowlist(&admin, &vec![&env, (seasonal.clone(), 5_000u64)]);
+    assert!(client.onattest(&build_attestation(&env, &seasonal)));
+}
+
+#[test]
+fn test_allowlist_admin_controls() {
+    let (env, admin, client) = setup();
+    let attester = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let res = client.try_import_allowlist(&stranger, &vec![&env, (stranger.clone(), 0u64)]);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::NotAuthorized)));
+
+    // Already-expired entries are rejected outright
+    let res = client.try_import_allowlist(&admin, &vec![&env, (attester.clone(), 500u64)]);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::ValidationFailed)));
+
+    client.import_allowlist(&admin, &vec![&env, (attester.clone(), 0u64)]);
+    client.remove_allowlist_entry(&admin, &attester);
+    assert!(!client.is_allowlisted(&attester));
+    assert_eq!(client.prune_expired_entries(), 0);
+}
@@ -3,11 +3,11 @@ extern crate std;
 
 use soroban_sdk::{
     testutils::{Address as _, BytesN as _, Ledger, LedgerInfo},
-    token, Address, Bytes, BytesN, Env, String as SorobanString,
+    contract, contractimpl, symbol_short, token, vec, Address, Bytes, BytesN, Env, String as SorobanString,
 };
 
-use resolvers::fee_collection::FeeCollectionResolverClient;
-use resolvers::{FeeCollectionResolver, ResolverAttestationData, ResolverError, ResolverType};
+use resolvers::fee_collection::{FeeCollectionResolverClient, PayerMode, RebateTier};
+use resolvers::{FeeCollectionResolver, ResolverAttestationData, ResolverContext, ResolverError, ResolverType};
 
 const FEE_AMOUNT: i128 = 50;
 
@@ -158,3 +158,289 @@ fn test_metadata() {
     assert_eq!(meta.name, SorobanString::from_str(&env, "Fee Collection Resolver"));
     assert_eq!(meta.resolver_type, ResolverType::FeeCollection);
 }
+
+#[test]
+fn test_volume_rebates_accrue_and_are_claimable() {
+    let (env, admin, fee_recipient, token_client, token_admin_client, resolver_address, resolver_client) = setup();
+    let attester = Address::generate(&env);
+    token_admin_client.mint(&attester, &(FEE_AMOUNT * 4));
+
+    // 10% from the 2nd paid attestation, 20% from the 4th
+    let tiers = vec![
+        &env,
+        RebateTier {
+            min_volume: 2,
+            rebate_bps: 1_000,
+        },
+        RebateTier {
+            min_volume: 4,
+            rebate_bps: 2_000,
+        },
+    ];
+    resolver_client.set_rebate_tiers(&admin, &tiers);
+    assert_eq!(resolver_client.get_rebate_tiers(), tiers);
+
+    for _ in 0..4 {
+        assert!(resolver_client.onattest(&build_attestation(&env, &attester)));
+    }
+
+    // 0 + 5 + 5 + 10
+    let expected_rebate = 20;
+    assert_eq!(resolver_client.get_attester_volume(&attester), 4);
+    assert_eq!(resolver_client.get_rebate_balance(&attester), expected_rebate);
+    assert_eq!(resolver_client.get_total_collected(), FEE_AMOUNT * 4);
+    assert_eq!(
+        resolver_client.get_collected_fees(&fee_recipient),
+        FEE_AMOUNT * 4 - expected_rebate
+    );
+
+    assert_eq!(resolver_client.claim_rebate(&attester), expected_rebate);
+    assert_eq!(token_client.balance(&attester), expected_rebate);
+    assert_eq!(resolver_client.get_rebate_balance(&attester), 0);
+
+    resolver_client.withdraw_fees(&fee_recipient);
+    assert_eq!(token_client.balance(&resolver_address), 0);
+}
+
+#[test]
+fn test_invalid_rebate_tiers_rejected() {
+    let (env, admin, _fee_recipient, _token_client, _token_admin_client, _resolver_address, resolver_client) = setup();
+
+    let unordered = vec![
+        &env,
+        RebateTier {
+            min_volume: 10,
+            rebate_bps: 100,
+        },
+        RebateTier {
+            min_volume: 5,
+            rebate_bps: 200,
+        },
+    ];
+    let res = resolver_client.try_set_rebate_tiers(&admin, &unordered);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::ValidationFailed)));
+
+    let over_100_percent = vec![
+        &env,
+        RebateTier {
+            min_volume: 1,
+            rebate_bps: 10_001,
+        },
+    ];
+    let res = resolver_client.try_set_rebate_tiers(&admin, &over_100_percent);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::ValidationFailed)));
+
+    let attacker = Address::generate(&env);
+    let res = resolver_client.try_set_rebate_tiers(&attacker, &vec![&env]);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::NotAuthorized)));
+}
+
+/// Fixed-rate swap adapter: 2 units in per unit out, minus a configurable shortfall.
+#[contract]
+struct MockSwapAdapter;
+
+#[contractimpl]
+impl MockSwapAdapter {
+    pub fn set_shortfall(env: Env, shortfall: i128) {
+        env.storage().instance().set(&symbol_short!("short"), &shortfall);
+    }
+
+    pub fn quote_in(_env: Env, _token_in: Address, _token_out: Address, amount_out: i128) -> i128 {
+        amount_out * 2
+    }
+
+    pub fn swap(
+        env: Env,
+        _token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        _min_amount_out: i128,
+        to: Address,
+    ) -> i128 {
+        let shortfall: i128 = env.storage().instance().get(&symbol_short!("short")).unwrap_or(0);
+        let amount_out = amount_in / 2 - shortfall;
+        token::Client::new(&env, &token_out).transfer(&env.current_contract_address(), &to, &amount_out);
+        amount_out
+    }
+}
+
+#[test]
+fn test_fee_paid_in_other_asset_is_converted() {
+    let (env, admin, fee_recipient, token_client, token_admin_client, resolver_address, resolver_client) = setup();
+    let attester = Address::generate(&env);
+    let (other_asset, other_client, other_admin_client) = create_token_contract(&env, &admin);
+    other_admin_client.mint(&attester, &(FEE_AMOUNT * 10));
+
+    let adapter_address = env.register(MockSwapAdapter, ());
+    let adapter = MockSwapAdapterClient::new(&env, &adapter_address);
+    token_admin_client.mint(&adapter_address, &(FEE_AMOUNT * 10));
+
+    // Choosing another asset requires a converter
+    let res = resolver_client.try_set_payment_asset(&attester, &Some(other_asset.clone()));
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::ValidationFailed)));
+    let res = resolver_client.try_set_converter(&admin, &Some(adapter_address.clone()), &10_001);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::ValidationFailed)));
+
+    // 2% slippage allowed
+    resolver_client.set_converter(&admin, &Some(adapter_address.clone()), &200);
+    resolver_client.set_payment_asset(&attester, &Some(other_asset.clone()));
+    assert_eq!(resolver_client.get_payment_asset(&attester), Some(other_asset));
+
+    assert!(resolver_client.onattest(&build_attestation(&env, &attester)));
+    assert_eq!(other_client.balance(&attester), FEE_AMOUNT * 8);
+    assert_eq!(token_client.balance(&resolver_address), FEE_AMOUNT);
+    assert_eq!(resolver_client.get_collected_fees(&fee_recipient), FEE_AMOUNT);
+
+    // Within slippage: recipient accrues what was actually received
+    adapter.set_shortfall(&1);
+    assert!(resolver_client.onattest(&build_attestation(&env, &attester)));
+    assert_eq!(resolver_client.get_collected_fees(&fee_recipient), FEE_AMOUNT * 2 - 1);
+
+    // Beyond slippage: the attestation is rejected
+    adapter.set_shortfall(&2);
+    let res = resolver_client.try_onattest(&build_attestation(&env, &attester));
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::InsufficientFunds)));
+    assert_eq!(resolver_client.get_collected_fees(&fee_recipient), FEE_AMOUNT * 2 - 1);
+}
+
+#[test]
+fn test_relayer_reimbursed_from_collected_fees() {
+    let (env, admin, fee_recipient, token_client, token_admin_client, _resolver_address, resolver_client) = setup();
+    let attester = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let protocol = Address::generate(&env);
+    token_admin_client.mint(&attester, &(FEE_AMOUNT * 10));
+    let context = ResolverContext {
+        submitter: relayer.clone(),
+    };
+
+    // Hook is refused until a protocol is configured
+    let res = resolver_client.try_onrelayed(&build_attestation(&env, &attester), &context);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::NotAuthorized)));
+
+    let res = resolver_client.try_set_relayer_reimbursement(&admin, &protocol, &-1);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::ValidationFailed)));
+    resolver_client.set_relayer_reimbursement(&admin, &protocol, &30);
+    assert_eq!(resolver_client.get_relayer_reimbursement(), 30);
+
+    let attestation = build_attestation(&env, &attester);
+    assert!(resolver_client.onattest(&attestation));
+    resolver_client.onrelayed(&attestation, &context);
+    assert_eq!(token_client.balance(&relayer), 30);
+    assert_eq!(resolver_client.get_collected_fees(&fee_recipient), FEE_AMOUNT - 30);
+
+    // Each attestation's fee reimburses its relayer once
+    resolver_client.onrelayed(&attestation, &context);
+    assert_eq!(token_client.balance(&relayer), 30);
+    assert_eq!(resolver_client.get_collected_fees(&fee_recipient), FEE_AMOUNT - 30);
+}
+
+#[test]
+fn test_relayer_reimbursement_capped_by_attestation_fee() {
+    let (env, admin, fee_recipient, token_client, token_admin_client, _resolver_address, resolver_client) = setup();
+    let attester = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let protocol = Address::generate(&env);
+    token_admin_client.mint(&attester, &(FEE_AMOUNT * 10));
+    let context = ResolverContext {
+        submitter: relayer.clone(),
+    };
+    resolver_client.set_relayer_reimbursement(&admin, &protocol, &(FEE_AMOUNT * 2));
+
+    // Fees paid by other attestations stay in the pool
+    assert!(resolver_client.onattest(&build_attestation(&env, &attester)));
+    resolver_client.onrelayed(&build_attestation(&env, &attester), &context);
+    assert_eq!(token_client.balance(&relayer), 0);
+    assert_eq!(resolver_client.get_collected_fees(&fee_recipient), FEE_AMOUNT);
+
+    // A relayed attestation is reimbursed no more than the fee it paid
+    let relayed = build_attestation(&env, &attester);
+    assert!(resolver_client.onattest(&relayed));
+    resolver_client.onrelayed(&relayed, &context);
+    assert_eq!(token_client.balance(&relayer), FEE_AMOUNT);
+    assert_eq!(resolver_client.get_collected_fees(&fee_recipient), FEE_AMOUNT);
+}
+
+#[test]
+fn test_schema_attach_locks_in_default_fee() {
+    let (env, admin, _fee_recipient, token_client, token_admin_client, resolver_address, resolver_client) = setup();
+    let attester = Address::generate(&env);
+    let protocol = Address::generate(&env);
+    token_admin_client.mint(&attester, &(FEE_AMOUNT * 10));
+    let attestation = build_attestation(&env, &attester);
+    let schema_uid = attestation.schema_uid.clone();
+
+    // Hook is refused until a protocol is configured
+    let res = resolver_client.try_on_schema_attach(&schema_uid, &attester);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::NotAuthorized)));
+
+    resolver_client.set_protocol(&admin, &protocol);
+    resolver_client.on_schema_attach(&schema_uid, &attester);
+    resolver_client.set_attestation_fee(&admin, &(FEE_AMOUNT * 2));
+
+    // The attached schema keeps the fee in force when it was attached
+    assert_eq!(resolver_client.get_schema_fee(&schema_uid), FEE_AMOUNT);
+    assert!(resolver_client.onattest(&attestation));
+    assert_eq!(token_client.balance(&resolver_address), FEE_AMOUNT);
+
+    let res = resolver_client.try_set_schema_fee(&admin, &schema_uid, &-1);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::ValidationFailed)));
+    resolver_client.set_schema_fee(&admin, &schema_uid, &0);
+    assert!(resolver_client.onattest(&attestation));
+    assert_eq!(token_client.balance(&resolver_address), FEE_AMOUNT);
+}
+
+#[test]
+fn test_quote_fees_matches_charged_amount() {
+    let (env, admin, _fee_recipient, _token_client, token_admin_client, _resolver_address, resolver_client) = setup();
+    let attester = Address::generate(&env);
+    let schema_uid = BytesN::random(&env);
+    let fee_token = token_admin_client.address.clone();
+
+    assert_eq!(
+        resolver_client.quote_fees(&schema_uid, &attester),
+        vec![&env, (fee_token, FEE_AMOUNT)]
+    );
+
+    // Converted payments are quoted in the attester's payment asset
+    let (other_asset, _other_client, _other_admin_client) = create_token_contract(&env, &admin);
+    let adapter_address = env.register(MockSwapAdapter, ());
+    resolver_client.set_converter(&admin, &Some(adapter_address), &200);
+    resolver_client.set_payment_asset(&attester, &Some(other_asset.clone()));
+    assert_eq!(
+        resolver_client.quote_fees(&schema_uid, &attester),
+        vec![&env, (other_asset, FEE_AMOUNT * 2)]
+    );
+
+    resolver_client.set_schema_fee(&admin, &schema_uid, &0);
+    assert_eq!(resolver_client.quote_fees(&schema_uid, &attester), vec![&env]);
+}
+
+#[test]
+fn test_recipient_pays_fee_from_allowance() {
+    let (env, admin, fee_recipient, token_client, token_admin_client, resolver_address, resolver_client) = setup();
+    let attester = Address::generate(&env);
+    let attestation = build_attestation(&env, &attester);
+    let recipient = attestation.recipient.clone();
+    let schema_uid = attestation.schema_uid.clone();
+    token_admin_client.mint(&recipient, &(FEE_AMOUNT * 2));
+
+    assert_eq!(resolver_client.get_schema_payer_mode(&schema_uid), PayerMode::Attester);
+    resolver_client.set_schema_payer_mode(&admin, &schema_uid, &PayerMode::Recipient);
+    assert_eq!(
+        resolver_client.quote_fees(&schema_uid, &attester),
+        vec![&env, (token_admin_client.address.clone(), FEE_AMOUNT)]
+    );
+
+    // Without an allowance the recipient cannot be charged
+    let res = resolver_client.try_onattest(&attestation);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::InsufficientFunds)));
+
+    token_client.approve(&recipient, &resolver_address, &FEE_AMOUNT, &1_000);
+    assert!(resolver_client.onattest(&attestation));
+    assert_eq!(token_client.balance(&recipient), FEE_AMOUNT);
+    assert_eq!(token_client.balance(&attester), 0);
+    assert_eq!(token_client.allowance(&recipient, &resolver_address), 0);
+    assert_eq!(resolver_client.get_collected_fees(&fee_recipient), FEE_AMOUNT);
+    assert_eq!(resolver_client.get_attester_volume(&attester), 0);
+}
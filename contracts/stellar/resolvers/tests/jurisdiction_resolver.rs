@@ -0,0 +1,89 @@
+#![cfg(test)]
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, BytesN as _},
+    vec, Address, Bytes, BytesN, Env,
+};
+
+use resolvers::jurisdiction::JurisdictionResolverClient;
+use resolvers::{JurisdictionResolver, ResolverAttestationData, ResolverError};
+
+fn setup<'a>() -> (Env, Address, JurisdictionResolverClient<'a>) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let resolver_address = env.register(JurisdictionResolver, ());
+    let client = JurisdictionResolverClient::new(&env, &resolver_address);
+    client.initialize(&admin);
+    (env, admin, client)
+}
+
+fn build_attestation(env: &Env, attester: &Address, schema_uid: &BytesN<32>) -> ResolverAttestationData {
+    ResolverAttestationData {
+        uid: BytesN::random(env),
+        schema_uid: schema_uid.clone(),
+        recipient: Address::generate(env),
+        attester: attester.clone(),
+        time: 0,
+        expiration_time: 0,
+        revocation_time: 0,
+        revocable: true,
+        ref_uid: Bytes::new(env),
+        data: Bytes::new(env),
+        value: 0,
+    }
+}
+
+#[test]
+fn test_schema_restricts_attester_jurisdictions() {
+    let (env, admin, client) = setup();
+    let schema_uid = BytesN::random(&env);
+    let us_attester = Address::generate(&env);
+    let de_attester = Address::generate(&env);
+    let untagged = Address::generate(&env);
+
+    client.set_attester_jurisdiction(&admin, &us_attester, &Some(symbol_short!("US")));
+    client.set_attester_jurisdiction(&admin, &de_attester, &Some(symbol_short!("DE")));
+    assert_eq!(
+        client.get_attester_jurisdiction(&us_attester),
+        Some(symbol_short!("US"))
+    );
+
+    // Unrestricted until the schema declares its jurisdictions
+    assert!(client.onattest(&build_attestation(&env, &untagged, &schema_uid)));
+
+    client.set_schema_jurisdictions(
+        &admin,
+        &schema_uid,
+        &vec![&env, symbol_short!("DE"), symbol_short!("FR")],
+    );
+    assert!(client.onattest(&build_attestation(&env, &de_attester, &schema_uid)));
+    for attester in [&us_attester, &untagged] {
+        let res = client.try_onattest(&build_attestation(&env, attester, &schema_uid));
+        assert!(matches!(res.err().unwrap(), Ok(ResolverError::NotAuthorized)));
+    }
+
+    // Other schemas are unaffected
+    assert!(client.onattest(&build_attestation(&env, &us_attester, &BytesN::random(&env))));
+
+    // Clearing a tag or the schema list takes effect immediately
+    client.set_attester_jurisdiction(&admin, &de_attester, &None);
+    let res = client.try_onattest(&build_attestation(&env, &de_attester, &schema_uid));
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::NotAuthorized)));
+    client.set_schema_jurisdictions(&admin, &schema_uid, &vec![&env]);
+    assert!(client.onattest(&build_attestation(&env, &us_attester, &schema_uid)));
+}
+
+#[test]
+fn test_only_admin_configures_jurisdictions() {
+    let (env, _admin, client) = setup();
+    let stranger = Address::generate(&env);
+
+    let res = client.try_set_attester_jurisdiction(&stranger, &stranger, &Some(symbol_short!("US")));
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::NotAuthorized)));
+    let res = client.try_set_schema_jurisdictions(&stranger, &BytesN::random(&env), &vec![&env, symbol_short!("US")]);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::NotAuthorized)));
+}
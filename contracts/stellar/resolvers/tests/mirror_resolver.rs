@@ -0,0 +1,84 @@
+#![cfg(test)]
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, BytesN as _},
+    Address, Bytes, BytesN, Env,
+};
+
+use resolvers::mirror::{MirrorResolverClient, MAX_SUBJECT_HISTORY};
+use resolvers::{MirrorResolver, ResolverAttestationData, ResolverError};
+
+fn setup<'a>() -> (Env, MirrorResolverClient<'a>) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let resolver_address = env.register(MirrorResolver, ());
+    let client = MirrorResolverClient::new(&env, &resolver_address);
+    (env, client)
+}
+
+fn build_attestation(env: &Env, recipient: &Address) -> ResolverAttestationData {
+    ResolverAttestationData {
+        uid: BytesN::random(env),
+        schema_uid: BytesN::random(env),
+        recipient: recipient.clone(),
+        attester: Address::generate(env),
+        time: 0,
+        expiration_time: 0,
+        revocation_time: 0,
+        revocable: true,
+        ref_uid: Bytes::new(env),
+        data: Bytes::new(env),
+        value: 0,
+    }
+}
+
+fn mirror(client: &MirrorResolverClient, attestation: &ResolverAttestationData) {
+    assert!(client.onattest(attestation));
+    client.onresolve(&attestation.uid, &attestation.attester);
+}
+
+#[test]
+fn test_subject_history_records_resolved_attestations() {
+    let (env, client) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let first = build_attestation(&env, &alice);
+    let second = build_attestation(&env, &alice);
+    let other = build_attestation(&env, &bob);
+    mirror(&client, &first);
+    mirror(&client, &other);
+    mirror(&client, &second);
+
+    let history = client.get_subject_history(&alice);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap(), first.uid);
+    assert_eq!(history.get(1).unwrap(), second.uid);
+    assert_eq!(client.get_subject_history(&bob).len(), 1);
+    assert_eq!(client.get_subject_history(&Address::generate(&env)).len(), 0);
+
+    // Revocation keeps the entry; a second onresolve for the same UID is rejected
+    assert!(client.onrevoke(&first));
+    assert_eq!(client.get_subject_history(&alice).len(), 2);
+    let res = client.try_onresolve(&first.uid, &first.attester);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::InvalidAttestation)));
+}
+
+#[test]
+fn test_subject_history_drops_oldest_beyond_cap() {
+    let (env, client) = setup();
+    env.cost_estimate().budget().reset_unlimited();
+    let subject = Address::generate(&env);
+
+    let oldest = build_attestation(&env, &subject);
+    mirror(&client, &oldest);
+    for _ in 0..MAX_SUBJECT_HISTORY {
+        mirror(&client, &build_attestation(&env, &subject));
+    }
+
+    let history = client.get_subject_history(&subject);
+    assert_eq!(history.len(), MAX_SUBJECT_HISTORY);
+    assert!(!history.contains(&oldest.uid));
+}
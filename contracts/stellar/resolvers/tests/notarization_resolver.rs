@@ -0,0 +1,102 @@
+#![cfg(test)]
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, BytesN as _, Ledger, LedgerInfo},
+    Address, Bytes, BytesN, Env,
+};
+
+use resolvers::notarization::NotarizationResolverClient;
+use resolvers::{NotarizationResolver, ResolverAttestationData, ResolverError, ResolverType};
+
+const DOCUMENT_HASH: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+fn setup<'a>() -> (Env, NotarizationResolverClient<'a>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set(LedgerInfo {
+        timestamp: 1_700_000_000,
+        protocol_version: 22,
+        sequence_number: 1,
+        network_id: Default::default(),
+        base_reserve: 1,
+        min_temp_entry_ttl: 16 * 60 * 60 * 24,
+        min_persistent_entry_ttl: 30 * 60 * 60 * 24,
+        max_entry_ttl: 365 * 60 * 60 * 24,
+    });
+
+    let resolver_address = env.register(NotarizationResolver, ());
+    let client = NotarizationResolverClient::new(&env, &resolver_address);
+    (env, client)
+}
+
+fn build_attestation(env: &Env, attester: &Address, value: &str) -> ResolverAttestationData {
+    ResolverAttestationData {
+        uid: BytesN::random(env),
+        schema_uid: BytesN::random(env),
+        recipient: Address::generate(env),
+        attester: attester.clone(),
+        time: env.ledger().timestamp(),
+        expiration_time: 0,
+        revocation_time: 0,
+        revocable: true,
+        ref_uid: Bytes::new(env),
+        data: Bytes::from_slice(env, value.as_bytes()),
+        value: 0,
+    }
+}
+
+fn document_hash(env: &Env) -> BytesN<32> {
+    let mut hash = [0u8; 32];
+    for (i, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&DOCUMENT_HASH[i * 2..i * 2 + 2], 16).unwrap();
+    }
+    BytesN::from_array(env, &hash)
+}
+
+#[test]
+fn test_document_hash_is_anchored_and_verifiable() {
+    let (env, client) = setup();
+    let attester = Address::generate(&env);
+    let value = std::format!("{{\"title\":\"Deed\", \"document_hash\": \"0x{}\"}}", DOCUMENT_HASH);
+    let attestation = build_attestation(&env, &attester, &value);
+
+    assert_eq!(client.verify_document(&document_hash(&env)), None);
+    assert!(client.onattest(&attestation));
+
+    let record = client.verify_document(&document_hash(&env)).unwrap();
+    assert_eq!(record.attestation_uid, attestation.uid);
+    assert_eq!(record.attester, attester);
+    assert_eq!(record.timestamp, 1_700_000_000);
+    assert!(!record.revoked);
+
+    // The first anchor of a document wins
+    let again = build_attestation(&env, &attester, &std::format!("{{\"document_hash\":\"{}\"}}", DOCUMENT_HASH));
+    let res = client.try_onattest(&again);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::ValidationFailed)));
+
+    assert!(client.onrevoke(&attestation));
+    assert!(client.verify_document(&document_hash(&env)).unwrap().revoked);
+}
+
+#[test]
+fn test_values_without_valid_hash_rejected() {
+    let (env, client) = setup();
+    let attester = Address::generate(&env);
+
+    for value in [
+        "{\"title\":\"Deed\"}",
+        "{\"document_hash\":\"not-hex\"}",
+        "{\"document_hash\":\"9f86d081\"}",
+        "",
+    ] {
+        let res = client.try_onattest(&build_attestation(&env, &attester, value));
+        assert!(matches!(res.err().unwrap(), Ok(ResolverError::InvalidAttestation)));
+    }
+}
+
+#[test]
+fn test_metadata() {
+    let (_env, client) = setup();
+    assert_eq!(client.metadata().resolver_type, ResolverType::Custom);
+}
@@ -0,0 +1,84 @@
+#![cfg(test)]
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, BytesN as _},
+    Address, Bytes, BytesN, Env, String,
+};
+
+use resolvers::receipt_nft::ReceiptNftResolverClient;
+use resolvers::{ReceiptNftResolver, ResolverAttestationData, ResolverError};
+
+fn setup<'a>() -> (Env, ReceiptNftResolverClient<'a>) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let resolver_address = env.register(ReceiptNftResolver, ());
+    let client = ReceiptNftResolverClient::new(&env, &resolver_address);
+    client.initialize(
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &String::from_str(&env, "https://attest.so/a/"),
+        &String::from_str(&env, "Attestation Receipt"),
+        &String::from_str(&env, "ARCPT"),
+    );
+    (env, client)
+}
+
+fn build_attestation(env: &Env, recipient: &Address) -> ResolverAttestationData {
+    ResolverAttestationData {
+        uid: BytesN::from_array(env, &[0xab; 32]),
+        schema_uid: BytesN::random(env),
+        recipient: recipient.clone(),
+        attester: Address::generate(env),
+        time: 0,
+        expiration_time: 0,
+        revocation_time: 0,
+        revocable: true,
+        ref_uid: Bytes::new(env),
+        data: Bytes::new(env),
+        value: 0,
+    }
+}
+
+#[test]
+fn test_receipt_minted_on_attest_and_burned_on_revoke() {
+    let (env, client) = setup();
+    let recipient = Address::generate(&env);
+    let attestation = build_attestation(&env, &recipient);
+
+    assert!(client.onattest(&attestation));
+    client.onresolve(&attestation.uid, &attestation.attester);
+
+    let token_id = client.get_receipt_token(&attestation.uid).unwrap();
+    assert_eq!(client.owner_of(&token_id), recipient);
+    assert_eq!(client.balance(&recipient), 1);
+    assert_eq!(client.attestation_of(&token_id), Some(attestation.uid.clone()));
+    let expected_uri = std::format!("https://attest.so/a/{}", "ab".repeat(32));
+    assert_eq!(client.token_uri(&token_id), String::from_str(&env, &expected_uri));
+
+    // The receipt is burned from its current holder once the revocation resolves
+    let holder = Address::generate(&env);
+    client.transfer(&recipient, &holder, &token_id);
+    assert!(client.onrevoke(&attestation));
+    client.onresolve(&attestation.uid, &attestation.attester);
+
+    assert_eq!(client.balance(&holder), 0);
+    assert_eq!(client.get_receipt_token(&attestation.uid), None);
+    assert_eq!(client.attestation_of(&token_id), None);
+
+    // Nothing is pending anymore
+    let res = client.try_onresolve(&attestation.uid, &attestation.attester);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::InvalidAttestation)));
+}
+
+#[test]
+fn test_receipt_hooks_require_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ReceiptNftResolverClient::new(&env, &env.register(ReceiptNftResolver, ()));
+
+    let attestation = build_attestation(&env, &Address::generate(&env));
+    let res = client.try_onattest(&attestation);
+    assert!(matches!(res.err().unwrap(), Ok(ResolverError::CustomError)));
+}
@@ -0,0 +1,6 @@
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Contract Errors
+// ►
+// ► Defined in the shared `attest-errors` registry (subject policy range: 5xx).
+// ══════════════════════════════════════════════════════════════════════════════
+pub use attest_errors::SubjectPolicyError as Error;
@@ -0,0 +1,28 @@
+use soroban_sdk::{contractclient, contracttype, Address, BytesN, Env, String};
+
+/// A claimable attestation as stored by the protocol contract.
+///
+/// Mirrors the protocol's `ClaimableAttestation` so it can be decoded without
+/// depending on the protocol crate.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClaimableAttestation {
+    pub attester: Address,
+    pub schema_uid: BytesN<32>,
+    pub value: String,
+    pub claim_hash: BytesN<32>,
+    pub expiry: u64,
+    pub nonce: u64,
+    pub created_at: u64,
+}
+
+/// Protocol Contract Client Interface
+///
+/// The subset of the attestation protocol contract that the policy depends on.
+/// Declared locally rather than importing the protocol crate so the policy's Wasm
+/// does not re-export the protocol's contract functions.
+#[contractclient(name = "ProtocolClient")]
+pub trait Protocol {
+    /// Returns the unclaimed attestation with this UID
+    fn get_claimable_attestation(env: Env, uid: BytesN<32>) -> ClaimableAttestation;
+}
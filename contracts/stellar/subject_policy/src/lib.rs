@@ -0,0 +1,161 @@
+#![no_std]
+use soroban_sdk::{
+    auth::{Context, ContractContext, CustomAccountInterface},
+    contract, contractimpl,
+    crypto::Hash,
+    symbol_short, Address, BytesN, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+mod errors;
+pub mod interfaces;
+mod state;
+
+pub use errors::Error;
+pub use interfaces::ProtocolClient;
+
+/// Subject-controlled attestation consent.
+///
+/// An example smart wallet showing how an account can programmatically consent to
+/// being an attestation subject. Every authorization is delegated to the wallet's
+/// owner, and on top of that `__check_auth` only approves protocol calls that make
+/// the wallet the subject of an attestation under an allowlisted schema:
+///
+/// - `attest`, where the wallet attests about itself
+/// - `claim`, where the wallet claims an attestation issued to it
+/// - `revoke_self`, so consent can always be withdrawn
+///
+/// Anything else the wallet is asked to authorize, including token transfers, is refused.
+#[contract]
+pub struct SubjectPolicyContract;
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Contract Implementation
+// ══════════════════════════════════════════════════════════════════════════════
+#[contractimpl]
+impl SubjectPolicyContract {
+    // ──────────────────────────────────────────────────────────────────────────
+    //                           Initialization
+    // ──────────────────────────────────────────────────────────────────────────
+
+    /// Binds the wallet to its owner and an attestation protocol contract, with an
+    /// initial schema allowlist. Can only be called once.
+    pub fn initialize(env: Env, owner: Address, protocol: Address, schemas: Vec<BytesN<32>>) -> Result<(), Error> {
+        if state::is_initialized(&env) {
+            return Err(Error::AlreadyInitialized);
+        }
+        state::set_owner(&env, &owner);
+        state::set_protocol(&env, &protocol);
+        for schema_uid in schemas.iter() {
+            state::set_schema_allowed(&env, &schema_uid, true);
+        }
+        Ok(())
+    }
+
+    /// Returns the address whose authorization the wallet requires.
+    pub fn get_owner(env: Env) -> Result<Address, Error> {
+        state::get_owner(&env)
+    }
+
+    /// Returns the address of the protocol contract the wallet consents to.
+    pub fn get_protocol(env: Env) -> Result<Address, Error> {
+        state::get_protocol(&env)
+    }
+
+    // ──────────────────────────────────────────────────────────────────────────
+    //                           Schema Allowlist
+    // ──────────────────────────────────────────────────────────────────────────
+
+    /// Adds a schema to the allowlist. Requires the wallet's own authorization.
+    pub fn allow_schema(env: Env, schema_uid: BytesN<32>) {
+        env.current_contract_address().require_auth();
+        state::set_schema_allowed(&env, &schema_uid, true);
+    }
+
+    /// Removes a schema from the allowlist. Requires the wallet's own authorization.
+    pub fn disallow_schema(env: Env, schema_uid: BytesN<32>) {
+        env.current_contract_address().require_auth();
+        state::set_schema_allowed(&env, &schema_uid, false);
+    }
+
+    /// Returns true if the wallet consents to attestations under the schema.
+    pub fn is_schema_allowed(env: Env, schema_uid: BytesN<32>) -> bool {
+        state::is_schema_allowed(&env, &schema_uid)
+    }
+}
+
+#[contractimpl]
+impl CustomAccountInterface for SubjectPolicyContract {
+    type Signature = ();
+    type Error = Error;
+
+    /// Approves the payload if the owner authorizes it and every call it covers is
+    /// permitted by the policy.
+    #[allow(non_snake_case)]
+    fn __check_auth(
+        env: Env,
+        signature_payload: Hash<32>,
+        _signature: Self::Signature,
+        auth_contexts: Vec<Context>,
+    ) -> Result<(), Error> {
+        let owner = state::get_owner(&env)?;
+        owner.require_auth_for_args((signature_payload.to_bytes(),).into_val(&env));
+
+        let protocol = state::get_protocol(&env)?;
+        for context in auth_contexts.iter() {
+            let Context::Contract(call) = context else {
+                return Err(Error::CallNotAllowed);
+            };
+            if call.contract == protocol {
+                check_protocol_call(&env, &protocol, &call)?;
+            } else if call.contract == env.current_contract_address() {
+                check_own_call(&env, &call)?;
+            } else {
+                return Err(Error::CallNotAllowed);
+            }
+        }
+        Ok(())
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Policy Checks
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Permits protocol calls that make the wallet an attestation subject under an
+/// allowlisted schema, and self-revocations.
+fn check_protocol_call(env: &Env, protocol: &Address, call: &ContractContext) -> Result<(), Error> {
+    let schema_uid: BytesN<32> = if call.fn_name == symbol_short!("attest") {
+        // attest(attester, schema_uid, value, expiration_time): the attester is the subject
+        arg(env, call, 1)?
+    } else if call.fn_name == symbol_short!("claim") {
+        // claim(attestation_uid, preimage, subject): the schema is fixed by the claimable record
+        let attestation_uid: BytesN<32> = arg(env, call, 0)?;
+        ProtocolClient::new(env, protocol)
+            .try_get_claimable_attestation(&attestation_uid)
+            .map_err(|_| Error::CallNotAllowed)?
+            .map_err(|_| Error::CallNotAllowed)?
+            .schema_uid
+    } else if call.fn_name == Symbol::new(env, "revoke_self") {
+        return Ok(());
+    } else {
+        return Err(Error::CallNotAllowed);
+    };
+
+    if !state::is_schema_allowed(env, &schema_uid) {
+        return Err(Error::SchemaNotAllowed);
+    }
+    Ok(())
+}
+
+/// Permits the wallet's own allowlist management, which the owner has authorized.
+fn check_own_call(env: &Env, call: &ContractContext) -> Result<(), Error> {
+    if call.fn_name == Symbol::new(env, "allow_schema") || call.fn_name == Symbol::new(env, "disallow_schema") {
+        return Ok(());
+    }
+    Err(Error::CallNotAllowed)
+}
+
+fn arg<T: TryFromVal<Env, Val>>(env: &Env, call: &ContractContext, index: u32) -> Result<T, Error> {
+    let value = call.args.get(index).ok_or(Error::CallNotAllowed)?;
+    T::try_from_val(env, &value).map_err(|_| Error::CallNotAllowed)
+}
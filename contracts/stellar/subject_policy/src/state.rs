@@ -0,0 +1,55 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+use crate::errors::Error;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Owner,                     // Address whose authorization the wallet requires
+    Protocol,                  // Address of the attestation protocol contract
+    AllowedSchema(BytesN<32>), // schema_uid -> present if the owner consents to it
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Storage Helper Functions
+// ══════════════════════════════════════════════════════════════════════════════
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Owner)
+}
+
+pub fn set_owner(env: &Env, owner: &Address) {
+    env.storage().instance().set(&DataKey::Owner, owner);
+}
+
+pub fn get_owner(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Owner)
+        .ok_or(Error::NotInitialized)
+}
+
+pub fn set_protocol(env: &Env, protocol: &Address) {
+    env.storage().instance().set(&DataKey::Protocol, protocol);
+}
+
+pub fn get_protocol(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Protocol)
+        .ok_or(Error::NotInitialized)
+}
+
+pub fn set_schema_allowed(env: &Env, schema_uid: &BytesN<32>, allowed: bool) {
+    let key = DataKey::AllowedSchema(schema_uid.clone());
+    if allowed {
+        env.storage().instance().set(&key, &());
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+pub fn is_schema_allowed(env: &Env, schema_uid: &BytesN<32>) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::AllowedSchema(schema_uid.clone()))
+}
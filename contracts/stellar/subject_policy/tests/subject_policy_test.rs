@@ -0,0 +1,179 @@
+use protocol::{AttestationContract, AttestationContractClient};
+use soroban_sdk::{
+    auth::{Context, ContractContext},
+    symbol_short,
+    testutils::{Address as _, BytesN as _},
+    vec, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Val, Vec,
+};
+use subject_policy::{Error, SubjectPolicyContract, SubjectPolicyContractClient};
+
+struct Setup<'a> {
+    protocol: AttestationContractClient<'a>,
+    wallet: SubjectPolicyContractClient<'a>,
+    owner: Address,
+    allowed: BytesN<32>,
+    other: BytesN<32>,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let protocol_id = env.register(AttestationContract {}, ());
+    let protocol = AttestationContractClient::new(env, &protocol_id);
+    protocol.initialize(&Address::generate(env));
+
+    let authority = Address::generate(env);
+    let allowed = protocol.register(&authority, &String::from_str(env, "kyc"), &None, &true);
+    let other = protocol.register(&authority, &String::from_str(env, "rating"), &None, &true);
+
+    let owner = Address::generate(env);
+    let wallet_id = env.register(SubjectPolicyContract {}, ());
+    let wallet = SubjectPolicyContractClient::new(env, &wallet_id);
+    wallet.initialize(&owner, &protocol_id, &vec![env, allowed.clone()]);
+
+    Setup {
+        protocol,
+        wallet,
+        owner,
+        allowed,
+        other,
+    }
+}
+
+fn call(contract: &Address, fn_name: Symbol, args: Vec<Val>) -> Context {
+    Context::Contract(ContractContext {
+        contract: contract.clone(),
+        fn_name,
+        args,
+    })
+}
+
+fn check_auth(
+    env: &Env,
+    wallet: &Address,
+    contexts: Vec<Context>,
+) -> Result<(), Result<Error, soroban_sdk::InvokeError>> {
+    env.try_invoke_contract_check_auth::<Error>(wallet, &BytesN::random(env), ().into_val(env), &contexts)
+}
+
+fn attest_call(env: &Env, s: &Setup, schema_uid: &BytesN<32>) -> Context {
+    let args = (
+        s.wallet.address.clone(),
+        schema_uid.clone(),
+        String::from_str(env, "{}"),
+        Option::<u64>::None,
+    );
+    call(&s.protocol.address, symbol_short!("attest"), args.into_val(env))
+}
+
+#[test]
+fn test_initialize_only_once() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(s.wallet.get_owner(), s.owner);
+    assert_eq!(s.wallet.get_protocol(), s.protocol.address);
+    assert_eq!(
+        s.wallet.try_initialize(&s.owner, &s.protocol.address, &vec![&env]),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_self_attestations_require_allowlisted_schema() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        check_auth(&env, &s.wallet.address, vec![&env, attest_call(&env, &s, &s.allowed)]),
+        Ok(())
+    );
+    assert_eq!(
+        check_auth(&env, &s.wallet.address, vec![&env, attest_call(&env, &s, &s.other)]),
+        Err(Ok(Error::SchemaNotAllowed))
+    );
+
+    s.wallet.allow_schema(&s.other);
+    assert!(s.wallet.is_schema_allowed(&s.other));
+    assert_eq!(
+        check_auth(&env, &s.wallet.address, vec![&env, attest_call(&env, &s, &s.other)]),
+        Ok(())
+    );
+
+    s.wallet.disallow_schema(&s.allowed);
+    assert_eq!(
+        check_auth(&env, &s.wallet.address, vec![&env, attest_call(&env, &s, &s.allowed)]),
+        Err(Ok(Error::SchemaNotAllowed))
+    );
+}
+
+#[test]
+fn test_claims_checked_against_claimable_schema() {
+    let env = Env::default();
+    let s = setup(&env);
+    let issuer = Address::generate(&env);
+    let preimage = Bytes::from_slice(&env, b"secret");
+    let claim_hash: BytesN<32> = env.crypto().sha256(&preimage).into();
+
+    let claim_call = |uid: &BytesN<32>| {
+        let args = (uid.clone(), preimage.clone(), s.wallet.address.clone());
+        call(&s.protocol.address, symbol_short!("claim"), args.into_val(&env))
+    };
+
+    let allowed_uid =
+        s.protocol
+            .attest_claimable(&issuer, &s.allowed, &String::from_str(&env, "{}"), &claim_hash, &1_000);
+    let other_uid = s
+        .protocol
+        .attest_claimable(&issuer, &s.other, &String::from_str(&env, "{}"), &claim_hash, &1_000);
+
+    assert_eq!(
+        check_auth(&env, &s.wallet.address, vec![&env, claim_call(&allowed_uid)]),
+        Ok(())
+    );
+    assert_eq!(
+        check_auth(&env, &s.wallet.address, vec![&env, claim_call(&other_uid)]),
+        Err(Ok(Error::SchemaNotAllowed))
+    );
+    assert_eq!(
+        check_auth(&env, &s.wallet.address, vec![&env, claim_call(&BytesN::random(&env))]),
+        Err(Ok(Error::CallNotAllowed))
+    );
+}
+
+#[test]
+fn test_calls_outside_policy_are_refused() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let revoke_self = call(
+        &s.protocol.address,
+        Symbol::new(&env, "revoke_self"),
+        (s.wallet.address.clone(), BytesN::<32>::random(&env)).into_val(&env),
+    );
+    assert_eq!(check_auth(&env, &s.wallet.address, vec![&env, revoke_self]), Ok(()));
+
+    let revoke = call(
+        &s.protocol.address,
+        symbol_short!("revoke"),
+        (s.wallet.address.clone(), BytesN::<32>::random(&env)).into_val(&env),
+    );
+    assert_eq!(
+        check_auth(&env, &s.wallet.address, vec![&env, revoke]),
+        Err(Ok(Error::CallNotAllowed))
+    );
+
+    // Every call in the payload must be permitted, including sub-invocations such as fee transfers
+    let transfer = call(
+        &Address::generate(&env),
+        symbol_short!("transfer"),
+        (s.wallet.address.clone(), Address::generate(&env), 10_i128).into_val(&env),
+    );
+    assert_eq!(
+        check_auth(
+            &env,
+            &s.wallet.address,
+            vec![&env, attest_call(&env, &s, &s.allowed), transfer]
+        ),
+        Err(Ok(Error::CallNotAllowed))
+    );
+}
@@ -0,0 +1,248 @@
+use deployments::deployment;
+
+/// Connection settings shared by every command.
+///
+/// Each falls back to an `ATTEST_*` environment variable when its flag is omitted.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Settings {
+    /// `stellar` CLI network name (`--network`, `ATTEST_NETWORK`)
+    pub network: String,
+    /// `stellar` CLI identity that signs, and acts as caller (`--source`, `ATTEST_SOURCE`)
+    pub source: String,
+    /// Protocol contract ID (`--contract`, `ATTEST_CONTRACT_ID`), defaulting to the
+    /// official deployment on `network`
+    pub contract: String,
+}
+
+/// A parsed command line.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cli {
+    pub settings: Settings,
+    /// Print the returned value as JSON instead of a table (`--json`)
+    pub json: bool,
+    /// Print the `stellar` command instead of running it (`--dry-run`)
+    pub dry_run: bool,
+    pub invocation: Invocation,
+}
+
+/// A contract function call.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Invocation {
+    /// The contract invoked; the protocol unless a resolver is targeted
+    pub contract: String,
+    pub function: &'static str,
+    /// Function arguments as `(name, value)` pairs, in `stellar` CLI syntax
+    pub args: Vec<(&'static str, String)>,
+    /// Whether the call only reads state and need not be submitted
+    pub read_only: bool,
+}
+
+impl Invocation {
+    /// The `stellar` CLI arguments that perform this call.
+    pub fn to_stellar_args(&self, settings: &Settings) -> Vec<String> {
+        let mut args: Vec<String> = [
+            "contract",
+            "invoke",
+            "--id",
+            &self.contract,
+            "--source",
+            &settings.source,
+        ]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+        args.extend(["--network".to_string(), settings.network.clone()]);
+        if self.read_only {
+            args.push("--send=no".to_string());
+        }
+        args.extend(["--".to_string(), self.function.to_string()]);
+        for (name, value) in &self.args {
+            args.extend([format!("--{name}"), value.clone()]);
+        }
+        args
+    }
+}
+
+pub const USAGE: &str = "\
+usage: attest-cli [--json] [--dry-run] [--network NAME] [--source IDENTITY] [--contract ID] <command>
+
+commands:
+  schema register --definition DEF [--resolver ADDRESS] [--irrevocable]
+  schema get --uid HEX
+  schema check --uid HEX --definition DEF
+  attest --schema HEX --value VALUE [--expires TIMESTAMP]
+  attestation get --uid HEX
+  revoke --uid HEX
+  bls register --key HEX
+  resolver set-fee --resolver ID --fee AMOUNT [--schema HEX]
+  resolver set-payer --resolver ID --schema HEX --payer attester|recipient";
+
+/// Parses command-line arguments (without the program name).
+///
+/// `env` looks up `ATTEST_*` fallbacks; pass `|name| std::env::var(name).ok()`.
+pub fn parse(args: &[String], env: impl Fn(&str) -> Option<String>) -> Result<Cli, String> {
+    let mut flags = Flags::default();
+    let mut words = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.strip_prefix("--") {
+            Some("json") => flags.json = true,
+            Some("dry-run") => flags.dry_run = true,
+            Some("irrevocable") => flags.irrevocable = true,
+            Some(name) => {
+                let value = iter.next().ok_or(format!("--{name} needs a value"))?;
+                flags.values.push((name.to_string(), value.clone()));
+            },
+            None => words.push(arg.as_str()),
+        }
+    }
+
+    let network = flags.take("network").or_else(|| env("ATTEST_NETWORK"));
+    let source = flags.take("source").or_else(|| env("ATTEST_SOURCE"));
+    let contract = flags.take("contract").or_else(|| env("ATTEST_CONTRACT_ID"));
+    let network = network.unwrap_or_else(|| "testnet".into());
+    let source = source.ok_or("--source or ATTEST_SOURCE is required")?;
+    // Without an explicit contract, use the official deployment on the network
+    let contract = match contract {
+        Some(contract) => contract,
+        None => network
+            .parse()
+            .and_then(|network| deployment(network).protocol_id())
+            .map(String::from)
+            .map_err(|err| format!("--contract or ATTEST_CONTRACT_ID is required: {err}"))?,
+    };
+    let settings = Settings {
+        network,
+        source,
+        contract,
+    };
+    let protocol = settings.contract.clone();
+    let caller = settings.source.clone();
+
+    let invocation = match words.as_slice() {
+        ["schema", "register"] => {
+            let mut args = vec![("caller", caller), ("schema_definition", flags.require("definition")?)];
+            if let Some(resolver) = flags.take("resolver") {
+                args.push(("resolver", resolver));
+            }
+            args.push(("revocable", (!flags.irrevocable).to_string()));
+            write(protocol, "register", args)
+        },
+        ["schema", "get"] => read(protocol, "get_schema", vec![("schema_uid", flags.require("uid")?)]),
+        ["schema", "check"] => read(
+            protocol,
+            "check_schema_compatibility",
+            vec![
+                ("schema_uid", flags.require("uid")?),
+                ("definition", flags.require("definition")?),
+            ],
+        ),
+        ["attest"] => {
+            let mut args = vec![
+                ("attester", caller),
+                ("schema_uid", flags.require("schema")?),
+                ("value", flags.require("value")?),
+            ];
+            if let Some(expires) = flags.take("expires") {
+                expires.parse::<u64>().map_err(|_| "--expires must be a timestamp")?;
+                args.push(("expiration_time", expires));
+            }
+            write(protocol, "attest", args)
+        },
+        ["attestation", "get"] => read(
+            protocol,
+            "get_attestation",
+            vec![("attestation_uid", flags.require("uid")?)],
+        ),
+        ["revoke"] => write(
+            protocol,
+            "revoke",
+            vec![("revoker", caller), ("attestation_uid", flags.require("uid")?)],
+        ),
+        ["bls", "register"] => write(
+            protocol,
+            "register_bls_key",
+            vec![("attester", caller), ("public_key", flags.require("key")?)],
+        ),
+        ["resolver", "set-fee"] => {
+            let resolver = flags.require("resolver")?;
+            let fee = flags.require("fee")?;
+            fee.parse::<i128>().map_err(|_| "--fee must be an integer amount")?;
+            match flags.take("schema") {
+                Some(schema) => write(
+                    resolver,
+                    "set_schema_fee",
+                    vec![("admin", caller), ("schema_uid", schema), ("fee", fee)],
+                ),
+                None => write(
+                    resolver,
+                    "set_attestation_fee",
+                    vec![("admin", caller), ("new_fee", fee)],
+                ),
+            }
+        },
+        ["resolver", "set-payer"] => {
+            let payer = match flags.require("payer")?.as_str() {
+                "attester" => "Attester",
+                "recipient" => "Recipient",
+                _ => return Err("--payer must be attester or recipient".into()),
+            };
+            write(
+                flags.require("resolver")?,
+                "set_schema_payer_mode",
+                vec![
+                    ("admin", caller),
+                    ("schema_uid", flags.require("schema")?),
+                    ("payer_mode", format!("\"{payer}\"")),
+                ],
+            )
+        },
+        _ => return Err(USAGE.to_string()),
+    };
+
+    if let Some((name, _)) = flags.values.first() {
+        return Err(format!("unexpected flag --{name}"));
+    }
+    Ok(Cli {
+        settings,
+        json: flags.json,
+        dry_run: flags.dry_run,
+        invocation,
+    })
+}
+
+#[derive(Default)]
+struct Flags {
+    json: bool,
+    dry_run: bool,
+    irrevocable: bool,
+    values: Vec<(String, String)>,
+}
+
+impl Flags {
+    /// Removes and returns a flag's value, so leftovers can be reported as unexpected.
+    fn take(&mut self, name: &str) -> Option<String> {
+        let index = self.values.iter().position(|(flag, _)| flag == name)?;
+        Some(self.values.remove(index).1)
+    }
+
+    fn require(&mut self, name: &str) -> Result<String, String> {
+        self.take(name).ok_or(format!("--{name} is required"))
+    }
+}
+
+fn write(contract: String, function: &'static str, args: Vec<(&'static str, String)>) -> Invocation {
+    Invocation {
+        contract,
+        function,
+        args,
+        read_only: false,
+    }
+}
+
+fn read(contract: String, function: &'static str, args: Vec<(&'static str, String)>) -> Invocation {
+    Invocation {
+        read_only: true,
+        ..write(contract, function, args)
+    }
+}
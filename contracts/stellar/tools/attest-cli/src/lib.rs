@@ -0,0 +1,13 @@
+//! # Attest CLI
+//!
+//! Operator commands for the attestation protocol and its resolvers. Each command
+//! is translated into a `stellar contract invoke` call, which handles RPC, signing
+//! and fees, and the returned value is printed as JSON or as a table.
+//!
+//! - [`command`]: argument parsing into an [`Invocation`]
+//! - [`output`]: rendering of returned values
+pub mod command;
+pub mod output;
+
+pub use command::{parse, Cli, Invocation, Settings};
+pub use output::{render, Format};
@@ -0,0 +1,38 @@
+use attest_cli::{parse, render, Format};
+use std::process::{exit, Command};
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let cli = parse(&args, |name| std::env::var(name).ok()).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        exit(2);
+    });
+
+    let stellar_args = cli.invocation.to_stellar_args(&cli.settings);
+    if cli.dry_run {
+        println!("stellar {}", stellar_args.join(" "));
+        return;
+    }
+
+    let output = Command::new("stellar")
+        .args(&stellar_args)
+        .output()
+        .unwrap_or_else(|err| {
+            eprintln!("failed to run stellar CLI: {err}");
+            exit(1);
+        });
+    if !output.status.success() {
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        exit(output.status.code().unwrap_or(1));
+    }
+
+    let format = if cli.json { Format::Json } else { Format::Table };
+    println!(
+        "{}",
+        render(
+            cli.invocation.function,
+            &String::from_utf8_lossy(&output.stdout),
+            format
+        )
+    );
+}
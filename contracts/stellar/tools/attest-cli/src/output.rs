@@ -0,0 +1,44 @@
+use serde_json::Value;
+
+/// How returned values are printed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Json,
+    Table,
+}
+
+/// Renders the `stellar` CLI's output for `function` in the chosen format.
+///
+/// The CLI prints returned values as JSON; output that is not JSON (e.g. from
+/// functions returning nothing) is shown as is.
+pub fn render(function: &str, stdout: &str, format: Format) -> String {
+    let stdout = stdout.trim();
+    let value = serde_json::from_str(stdout).unwrap_or_else(|_| Value::String(stdout.to_string()));
+    match format {
+        Format::Json => serde_json::json!({ "function": function, "result": value }).to_string(),
+        Format::Table => table(&value),
+    }
+}
+
+fn table(value: &Value) -> String {
+    match value {
+        Value::Object(fields) => {
+            let width = fields.keys().map(String::len).max().unwrap_or(0);
+            fields
+                .iter()
+                .map(|(key, value)| format!("{key:<width$}  {}", cell(value)))
+                .collect::<Vec<_>>()
+                .join("\n")
+        },
+        Value::Array(items) => items.iter().map(cell).collect::<Vec<_>>().join("\n"),
+        other => cell(other),
+    }
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => "-".to_string(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,103 @@
+use attest_cli::{parse, render, Format};
+
+fn args(line: &str) -> Vec<String> {
+    line.split_whitespace().map(String::from).collect()
+}
+
+fn no_env(_: &str) -> Option<String> {
+    None
+}
+
+/// **Test: Commands Map to the Expected Contract Calls**
+#[test]
+fn commands_map_to_stellar_invocations() {
+    let cli = parse(
+        &args("--source admin --contract CPROTO schema register --definition kyc --irrevocable"),
+        no_env,
+    )
+    .unwrap();
+    assert_eq!(
+        cli.invocation.to_stellar_args(&cli.settings).join(" "),
+        "contract invoke --id CPROTO --source admin --network testnet -- register \
+         --caller admin --schema_definition kyc --revocable false"
+    );
+
+    let env = |name: &str| match name {
+        "ATTEST_SOURCE" => Some("issuer".to_string()),
+        "ATTEST_CONTRACT_ID" => Some("CPROTO".to_string()),
+        _ => None,
+    };
+    let cli = parse(&args("--json attestation get --uid ab12"), env).unwrap();
+    assert!(cli.json);
+    assert_eq!(
+        cli.invocation.to_stellar_args(&cli.settings).join(" "),
+        "contract invoke --id CPROTO --source issuer --network testnet --send=no -- get_attestation \
+         --attestation_uid ab12"
+    );
+
+    let cli = parse(
+        &args("resolver set-payer --resolver CFEE --schema ab12 --payer recipient"),
+        env,
+    )
+    .unwrap();
+    assert_eq!(cli.invocation.contract, "CFEE");
+    assert_eq!(cli.invocation.function, "set_schema_payer_mode");
+    assert_eq!(cli.invocation.args[2], ("payer_mode", "\"Recipient\"".to_string()));
+
+    let cli = parse(&args("resolver set-fee --resolver CFEE --fee 50"), env).unwrap();
+    assert_eq!(cli.invocation.function, "set_attestation_fee");
+
+    let cli = parse(&args("--source admin --network mainnet schema get --uid ab12"), no_env).unwrap();
+    assert_eq!(
+        cli.settings.contract,
+        deployments::deployment(deployments::Network::Mainnet)
+            .protocol
+            .unwrap()
+            .id
+    );
+
+    let cli = parse(&args("schema check --uid ab12 --definition XDR:AAAA"), env).unwrap();
+    assert!(cli.invocation.read_only);
+    assert_eq!(cli.invocation.function, "check_schema_compatibility");
+}
+
+/// **Test: Invalid Command Lines Are Reported**
+#[test]
+fn invalid_command_lines_are_rejected() {
+    let err = parse(&args("attest --schema ab12 --value v"), no_env).unwrap_err();
+    assert!(err.contains("ATTEST_SOURCE"));
+
+    let base = "--source a --contract C ";
+    assert!(parse(&args(&format!("{base}attest --schema ab12")), no_env)
+        .unwrap_err()
+        .contains("--value"));
+    assert!(parse(
+        &args(&format!("{base}attest --schema ab --value v --expires soon")),
+        no_env
+    )
+    .is_err());
+    assert!(parse(&args(&format!("{base}revoke --uid ab --bogus 1")), no_env)
+        .unwrap_err()
+        .contains("--bogus"));
+    assert!(parse(&args(&format!("{base}launch")), no_env)
+        .unwrap_err()
+        .starts_with("usage"));
+    assert!(parse(&args("--source a --network futurenet revoke --uid ab"), no_env)
+        .unwrap_err()
+        .contains("not deployed on futurenet"));
+}
+
+/// **Test: Returned Values Render as JSON or Tables**
+#[test]
+fn output_renders_json_and_tables() {
+    let stdout = "{\"revoked\":false,\"nonce\":3,\"expiration_time\":null}\n";
+    assert_eq!(
+        render("get_attestation", stdout, Format::Table),
+        "expiration_time  -\nnonce            3\nrevoked          false"
+    );
+    assert_eq!(
+        render("attest", "\"ab12\"", Format::Json),
+        "{\"function\":\"attest\",\"result\":\"ab12\"}"
+    );
+    assert_eq!(render("revoke", "", Format::Table), "");
+}
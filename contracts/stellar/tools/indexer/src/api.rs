@@ -0,0 +1,125 @@
+use crate::store::{AttestationFilter, Store, StoreError};
+use serde::Serialize;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Page size when a listing has no `limit` parameter.
+pub const DEFAULT_LIMIT: u32 = 50;
+
+/// Largest page a listing returns.
+pub const MAX_LIMIT: u32 = 500;
+
+/// Serves the query API until the listener fails.
+///
+/// - `GET /attestations/{uid}` returns an attestation's current state
+/// - `GET /subjects/{address}/attestations`, `GET /attesters/{address}/attestations`
+///   and `GET /schemas/{uid}/attestations` list attestations, oldest first
+/// - `GET /schemas/{uid}` returns a schema
+/// - `GET /events?after={id}` lists indexed events in emission order
+/// - `GET /health` answers `200 ok`
+///
+/// Listings take a `limit` parameter, up to [`MAX_LIMIT`].
+pub fn serve<S: Store + Send + 'static>(listener: TcpListener, store: Arc<Mutex<S>>) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let store = Arc::clone(&store);
+        thread::spawn(move || {
+            if let Err(err) = handle_connection(stream, &store) {
+                eprintln!("indexer: connection error: {err}");
+            }
+        });
+    }
+    Ok(())
+}
+
+fn handle_connection<S: Store>(mut stream: TcpStream, store: &Mutex<S>) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (method, target) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+    }
+
+    let (status, body) = match store.lock() {
+        Ok(mut store) => route(&mut *store, method, target),
+        Err(_) => (500, error_body("store unavailable")),
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        reason(status),
+        body.len()
+    )
+}
+
+/// Handles one request, returning the status code and JSON body.
+pub fn route<S: Store>(store: &mut S, method: &str, target: &str) -> (u16, String) {
+    if method != "GET" {
+        return (405, error_body("method not allowed"));
+    }
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let param = |name: &str| {
+        query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value)
+    };
+    let limit = match param("limit").map(str::parse::<u32>) {
+        None => DEFAULT_LIMIT,
+        Some(Ok(limit)) => limit.min(MAX_LIMIT),
+        Some(Err(_)) => return (400, error_body("invalid limit")),
+    };
+
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match segments.as_slice() {
+        ["health"] => (200, "\"ok\"".to_string()),
+        ["attestations", uid] => found(store.attestation(uid)),
+        ["subjects", subject, "attestations"] => {
+            ok(store.attestations(&AttestationFilter::Subject(subject.to_string()), limit))
+        },
+        ["attesters", attester, "attestations"] => {
+            ok(store.attestations(&AttestationFilter::Attester(attester.to_string()), limit))
+        },
+        ["schemas", uid, "attestations"] => ok(store.attestations(&AttestationFilter::Schema(uid.to_string()), limit)),
+        ["schemas", uid] => found(store.schema(uid)),
+        ["events"] => ok(store.events(param("after"), limit)),
+        _ => (404, error_body("not found")),
+    }
+}
+
+fn ok<T: Serialize>(result: Result<T, StoreError>) -> (u16, String) {
+    match result {
+        Ok(value) => (200, serde_json::to_string(&value).unwrap_or_default()),
+        Err(err) => (500, error_body(&err.to_string())),
+    }
+}
+
+fn found<T: Serialize>(result: Result<Option<T>, StoreError>) -> (u16, String) {
+    match result {
+        Ok(Some(value)) => (200, serde_json::to_string(&value).unwrap_or_default()),
+        Ok(None) => (404, error_body("not found")),
+        Err(err) => (500, error_body(&err.to_string())),
+    }
+}
+
+fn error_body(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Internal Server Error",
+    }
+}
@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+/// Indexer settings, read from `INDEXER_*` environment variables.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Soroban RPC endpoint (`INDEXER_RPC_URL`)
+    pub rpc_url: String,
+    /// Protocol contract ID (`INDEXER_CONTRACT_ID`, required)
+    pub contract_id: String,
+    /// Postgres connection string (`INDEXER_DATABASE_URL`, required)
+    pub database_url: String,
+    /// Address the query API listens on (`INDEXER_LISTEN`)
+    pub listen: String,
+    /// Ledger to start from when the database has no cursor yet (`INDEXER_START_LEDGER`, required)
+    pub start_ledger: u32,
+    /// Events requested per `getEvents` page (`INDEXER_PAGE_SIZE`)
+    pub page_size: u32,
+    /// Delay between polls once caught up (`INDEXER_POLL_MS`)
+    pub poll_interval: Duration,
+}
+
+impl Config {
+    /// Reads the configuration, naming the first missing or malformed variable on error.
+    pub fn from_env() -> Result<Self, String> {
+        Ok(Config {
+            rpc_url: var_or("INDEXER_RPC_URL", "https://soroban-testnet.stellar.org"),
+            contract_id: required("INDEXER_CONTRACT_ID")?,
+            database_url: required("INDEXER_DATABASE_URL")?,
+            listen: var_or("INDEXER_LISTEN", "127.0.0.1:8788"),
+            start_ledger: required("INDEXER_START_LEDGER")?
+                .parse()
+                .map_err(|_| "INDEXER_START_LEDGER is not a valid number".to_string())?,
+            page_size: parsed("INDEXER_PAGE_SIZE", 200)?,
+            poll_interval: Duration::from_millis(parsed("INDEXER_POLL_MS", 5_000)?),
+        })
+    }
+}
+
+fn var_or(name: &str, default: &str) -> String {
+    std::env::var(name).unwrap_or_else(|_| default.to_string())
+}
+
+fn required(name: &str) -> Result<String, String> {
+    std::env::var(name).map_err(|_| format!("{name} must be set"))
+}
+
+fn parsed<T: std::str::FromStr>(name: &str, default: T) -> Result<T, String> {
+    match std::env::var(name) {
+        Ok(value) => value.parse().map_err(|_| format!("{name} is not a valid number")),
+        Err(_) => Ok(default),
+    }
+}
@@ -0,0 +1,63 @@
+use crate::normalize::{normalize, NormalizeError};
+use crate::rpc::{EventSource, RpcError, Start};
+use crate::store::{Store, StoreError};
+
+/// What one ingestion round did.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Progress {
+    /// Events returned by the RPC
+    pub fetched: usize,
+    /// Events decoded and handed to the store
+    pub indexed: usize,
+    /// Events from the protocol contract that could not be decoded, and were skipped
+    pub malformed: Vec<NormalizeError>,
+    /// Whether the page was short, meaning the indexer reached the chain head
+    pub caught_up: bool,
+}
+
+/// Why a round made no progress.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IndexError {
+    Rpc(RpcError),
+    Store(StoreError),
+}
+
+/// Moves contract events from an [`EventSource`] into a [`Store`].
+pub struct Indexer<S, T> {
+    pub source: S,
+    pub store: T,
+    /// Ledger to start from when the store has no cursor
+    pub start_ledger: u32,
+    pub page_size: u32,
+}
+
+impl<S: EventSource, T: Store> Indexer<S, T> {
+    /// Fetches the page after the stored cursor and applies it.
+    ///
+    /// The cursor only advances together with the page's records, so a crash between
+    /// fetching and storing replays the page on restart.
+    pub fn index_once(&mut self) -> Result<Progress, IndexError> {
+        let start = match self.store.cursor().map_err(IndexError::Store)? {
+            Some(cursor) => Start::Cursor(cursor),
+            None => Start::Ledger(self.start_ledger),
+        };
+        let page = self.source.events(&start, self.page_size).map_err(IndexError::Rpc)?;
+
+        let mut progress = Progress {
+            fetched: page.events.len(),
+            caught_up: page.events.len() < self.page_size as usize,
+            ..Progress::default()
+        };
+        let mut records = Vec::new();
+        for raw in &page.events {
+            match normalize(raw) {
+                Ok(Some(record)) => records.push(record),
+                Ok(None) => {},
+                Err(err) => progress.malformed.push(err),
+            }
+        }
+        progress.indexed = records.len();
+        self.store.apply(&records, &page.cursor).map_err(IndexError::Store)?;
+        Ok(progress)
+    }
+}
@@ -0,0 +1,24 @@
+//! # Attest Indexer
+//!
+//! Reference indexer for the protocol contract. It pages through the contract's
+//! events with Soroban RPC `getEvents`, decodes them into the shared
+//! [`attest_types::events::ProtocolEvent`] type, and persists both the raw event log
+//! and current attestation and schema state to Postgres, served over a small read API.
+//!
+//! - [`rpc`]: the `getEvents` client
+//! - [`normalize`]: decoding of RPC events into typed events
+//! - [`store`]: the Postgres store and an in-memory store with the same projections
+//! - [`indexer`]: the ingestion loop
+//! - [`api`]: the HTTP query endpoints
+pub mod api;
+pub mod config;
+pub mod indexer;
+pub mod normalize;
+pub mod rpc;
+pub mod store;
+
+pub use config::Config;
+pub use indexer::{Indexer, Progress};
+pub use normalize::{normalize, NormalizeError};
+pub use rpc::{EventPage, EventSource, RawEvent, RpcError, SorobanRpc, Start};
+pub use store::{AttestationFilter, IndexedAttestation, MemoryStore, PgStore, Store, StoreError};
@@ -0,0 +1,55 @@
+use indexer::{api, Config, Indexer, PgStore, SorobanRpc};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    let config = Config::from_env().unwrap_or_else(|err| {
+        eprintln!("indexer: {err}");
+        std::process::exit(2);
+    });
+
+    let api_store = PgStore::connect(&config.database_url).expect("failed to connect to database");
+    let listener = TcpListener::bind(&config.listen).expect("failed to bind listener");
+    println!("indexer: serving queries on {}", config.listen);
+    let api_store = Arc::new(Mutex::new(api_store));
+    thread::spawn(move || api::serve(listener, api_store).expect("listener failed"));
+
+    let mut indexer = Indexer {
+        source: SorobanRpc::new(&config.rpc_url, &config.contract_id),
+        store: PgStore::connect(&config.database_url).expect("failed to connect to database"),
+        start_ledger: config.start_ledger,
+        page_size: config.page_size,
+    };
+
+    let mut failures = 0;
+    loop {
+        let caught_up = match indexer.index_once() {
+            Ok(progress) => {
+                failures = 0;
+                for err in &progress.malformed {
+                    eprintln!("indexer: skipped undecodable event: {err:?}");
+                }
+                if progress.fetched > 0 {
+                    println!("indexer: fetched {}, indexed {}", progress.fetched, progress.indexed);
+                }
+                progress.caught_up
+            },
+            Err(err) => {
+                failures += 1;
+                eprintln!("indexer: round failed: {err:?}");
+                true
+            },
+        };
+        if caught_up {
+            let backoff = 1u32 << failures.min(6);
+            thread::sleep(
+                config
+                    .poll_interval
+                    .saturating_mul(backoff)
+                    .min(Duration::from_secs(300)),
+            );
+        }
+    }
+}
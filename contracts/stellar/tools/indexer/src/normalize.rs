@@ -0,0 +1,187 @@
+use crate::rpc::RawEvent;
+use attest_types::events::{EventRecord, ProtocolEvent};
+use attest_types::json::SchemaJson;
+use serde_json::Value;
+
+/// Why an event from the protocol contract could not be decoded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NormalizeError {
+    /// The topics or data don't have the layout the protocol publishes for this event
+    Malformed { id: String, field: &'static str },
+}
+
+/// Decodes an RPC event into a typed record.
+///
+/// Returns `Ok(None)` for events the indexer does not track, such as metrics or
+/// admin events.
+pub fn normalize(raw: &RawEvent) -> Result<Option<EventRecord>, NormalizeError> {
+    let malformed = |field| NormalizeError::Malformed {
+        id: raw.id.clone(),
+        field,
+    };
+    let (Some(namespace), Some(action)) = (
+        raw.topic_json.first().and_then(symbol),
+        raw.topic_json.get(1).and_then(symbol),
+    ) else {
+        return Ok(None);
+    };
+    let data = Tuple(raw.value_json.get("vec").and_then(Value::as_array));
+
+    let event = match (namespace, action) {
+        ("SCHEMA", "REGISTER") => ProtocolEvent::SchemaRegistered {
+            schema_uid: data.bytes(0).ok_or_else(|| malformed("schema_uid"))?,
+            schema: data.get(1).and_then(schema).ok_or_else(|| malformed("schema"))?,
+            authority: data.address(2).ok_or_else(|| malformed("authority"))?,
+        },
+        ("SCHEMA", "REVOKEALL") => {
+            let revocation = Struct(data.get(1).and_then(|value| value.get("map")?.as_array()));
+            ProtocolEvent::SchemaRevokedAll {
+                schema_uid: data.bytes(0).ok_or_else(|| malformed("schema_uid"))?,
+                reason: revocation.string("reason").ok_or_else(|| malformed("reason"))?,
+                revoked_at: revocation
+                    .get("revoked_at")
+                    .and_then(u64_of)
+                    .ok_or_else(|| malformed("revoked_at"))?,
+            }
+        },
+        ("ATTEST", "CREATE") => ProtocolEvent::AttestationCreated {
+            uid: data.bytes(0).ok_or_else(|| malformed("uid"))?,
+            subject: data.address(1).ok_or_else(|| malformed("subject"))?,
+            attester: data.address(2).ok_or_else(|| malformed("attester"))?,
+            value: data.string(3).ok_or_else(|| malformed("value"))?,
+            nonce: data.u64(4).ok_or_else(|| malformed("nonce"))?,
+            timestamp: data.u64(5).ok_or_else(|| malformed("timestamp"))?,
+        },
+        ("ATTEST", "REVOKE") => ProtocolEvent::AttestationRevoked {
+            uid: data.bytes(0).ok_or_else(|| malformed("uid"))?,
+            schema_uid: data.bytes(1).ok_or_else(|| malformed("schema_uid"))?,
+            subject: data.address(2).ok_or_else(|| malformed("subject"))?,
+            attester: data.address(3).ok_or_else(|| malformed("attester"))?,
+            revocation_time: data.u64(5).ok_or_else(|| malformed("revocation_time"))?,
+        },
+        ("ATTEST", "SELF_REV") => ProtocolEvent::AttestationSelfRevoked {
+            uid: data.bytes(0).ok_or_else(|| malformed("uid"))?,
+            schema_uid: data.bytes(1).ok_or_else(|| malformed("schema_uid"))?,
+            subject: data.address(2).ok_or_else(|| malformed("subject"))?,
+            revocation_time: data.u64(3).ok_or_else(|| malformed("revocation_time"))?,
+        },
+        ("ATTEST", "EXPIRED") => ProtocolEvent::AttestationExpired {
+            uid: data.bytes(0).ok_or_else(|| malformed("uid"))?,
+            expiration_time: match data.get(1) {
+                Some(value) if is_void(value) => None,
+                Some(value) => Some(u64_of(value).ok_or_else(|| malformed("expiration_time"))?),
+                None => return Err(malformed("expiration_time")),
+            },
+        },
+        ("ATTEST", "PURGED") => ProtocolEvent::AttestationPurged {
+            uid: data.bytes(0).ok_or_else(|| malformed("uid"))?,
+            reason: data
+                .get(1)
+                .and_then(symbol)
+                .ok_or_else(|| malformed("reason"))?
+                .to_string(),
+        },
+        _ => return Ok(None),
+    };
+
+    Ok(Some(EventRecord {
+        id: raw.id.clone(),
+        ledger: raw.ledger,
+        ledger_closed_at: raw.ledger_closed_at.clone(),
+        contract_id: raw.contract_id.clone(),
+        extra_topics: raw.topic_json[2..].iter().map(topic_text).collect(),
+        event,
+    }))
+}
+
+/// Positional access to the elements of a tuple-shaped event payload.
+struct Tuple<'a>(Option<&'a Vec<Value>>);
+
+impl Tuple<'_> {
+    fn get(&self, index: usize) -> Option<&Value> {
+        self.0?.get(index)
+    }
+
+    fn bytes(&self, index: usize) -> Option<String> {
+        Some(self.get(index)?.get("bytes")?.as_str()?.to_string())
+    }
+
+    fn address(&self, index: usize) -> Option<String> {
+        address(self.get(index)?)
+    }
+
+    fn string(&self, index: usize) -> Option<String> {
+        Some(self.get(index)?.get("string")?.as_str()?.to_string())
+    }
+
+    fn u64(&self, index: usize) -> Option<u64> {
+        u64_of(self.get(index)?)
+    }
+}
+
+/// Field access to a contract struct, rendered as a map keyed by field name.
+struct Struct<'a>(Option<&'a Vec<Value>>);
+
+impl Struct<'_> {
+    fn get(&self, name: &str) -> Option<&Value> {
+        self.0?
+            .iter()
+            .find(|entry| entry.get("key").and_then(symbol) == Some(name))?
+            .get("val")
+    }
+
+    fn string(&self, name: &str) -> Option<String> {
+        Some(self.get(name)?.get("string")?.as_str()?.to_string())
+    }
+}
+
+fn symbol(value: &Value) -> Option<&str> {
+    value.get("symbol")?.as_str()
+}
+
+fn address(value: &Value) -> Option<String> {
+    Some(value.get("address")?.as_str()?.to_string())
+}
+
+/// stellar-xdr JSON renders 64-bit integers as strings; accept numbers too.
+fn u64_of(value: &Value) -> Option<u64> {
+    match value.get("u64")? {
+        Value::String(text) => text.parse().ok(),
+        number => number.as_u64(),
+    }
+}
+
+fn is_void(value: &Value) -> bool {
+    value.as_str() == Some("void")
+}
+
+fn topic_text(value: &Value) -> String {
+    symbol(value)
+        .or_else(|| value.get("string").and_then(Value::as_str))
+        .map(str::to_string)
+        .unwrap_or_else(|| value.to_string())
+}
+
+fn schema(value: &Value) -> Option<SchemaJson> {
+    let fields = Struct(Some(value.get("map")?.as_array()?));
+    let optional = |name: &str, decode: fn(&Value) -> Option<String>| match fields.get(name)? {
+        value if is_void(value) => Some(None),
+        value => decode(value).map(Some),
+    };
+    Some(SchemaJson {
+        authority: address(fields.get("authority")?)?,
+        definition: fields.string("definition")?,
+        resolver: optional("resolver", address)?,
+        revocable: fields.get("revocable")?.get("bool")?.as_bool()?,
+        namespace: optional("namespace", |value| symbol(value).map(str::to_string))?,
+        // Schemas registered before versioning carry neither field
+        version: match fields.get("version") {
+            Some(value) => value.get("u32")?.as_u64()? as u32,
+            None => 1,
+        },
+        previous_uid: match fields.get("previous_uid") {
+            Some(value) if !is_void(value) => Some(value.get("bytes")?.as_str()?.to_string()),
+            _ => None,
+        },
+    })
+}
@@ -0,0 +1,118 @@
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// A contract event as returned by `getEvents` with `xdrFormat: "json"`.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RawEvent {
+    pub id: String,
+    pub ledger: u32,
+    pub ledger_closed_at: String,
+    pub contract_id: String,
+    /// Topics as stellar-xdr JSON `ScVal`s
+    pub topic_json: Vec<Value>,
+    /// Data as a stellar-xdr JSON `ScVal`
+    pub value_json: Value,
+}
+
+/// One page of events and the cursor to continue after it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventPage {
+    pub events: Vec<RawEvent>,
+    pub cursor: String,
+}
+
+/// Where to read events from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Start {
+    /// From the start of a ledger, for the first run
+    Ledger(u32),
+    /// After a cursor returned with an earlier page
+    Cursor(String),
+}
+
+/// Why a page could not be fetched.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RpcError {
+    /// Network trouble; the same call may succeed later
+    Transport(String),
+    /// The RPC server answered with an error
+    Response(String),
+}
+
+/// Access to the protocol contract's events.
+pub trait EventSource {
+    /// Fetches up to `limit` events starting at `start`.
+    fn events(&self, start: &Start, limit: u32) -> Result<EventPage, RpcError>;
+}
+
+/// [`EventSource`] backed by a Soroban RPC server.
+pub struct SorobanRpc {
+    pub url: String,
+    pub contract_id: String,
+    agent: ureq::Agent,
+}
+
+impl SorobanRpc {
+    pub fn new(url: &str, contract_id: &str) -> Self {
+        SorobanRpc {
+            url: url.to_string(),
+            contract_id: contract_id.to_string(),
+            agent: ureq::AgentBuilder::new().timeout(Duration::from_secs(30)).build(),
+        }
+    }
+}
+
+impl EventSource for SorobanRpc {
+    fn events(&self, start: &Start, limit: u32) -> Result<EventPage, RpcError> {
+        let filters = json!([{ "type": "contract", "contractIds": [self.contract_id] }]);
+        // `startLedger` and `cursor` are mutually exclusive
+        let params = match start {
+            Start::Ledger(ledger) => json!({
+                "startLedger": ledger,
+                "filters": filters,
+                "pagination": { "limit": limit },
+                "xdrFormat": "json",
+            }),
+            Start::Cursor(cursor) => json!({
+                "filters": filters,
+                "pagination": { "cursor": cursor, "limit": limit },
+                "xdrFormat": "json",
+            }),
+        };
+        let body = json!({ "jsonrpc": "2.0", "id": 1, "method": "getEvents", "params": params });
+
+        let response: Value = match self.agent.post(&self.url).send_json(body) {
+            Ok(response) => response
+                .into_json()
+                .map_err(|err| RpcError::Transport(err.to_string()))?,
+            Err(ureq::Error::Status(code, response)) => {
+                return Err(RpcError::Transport(format!(
+                    "HTTP {code}: {}",
+                    response.into_string().unwrap_or_default()
+                )))
+            },
+            Err(err) => return Err(RpcError::Transport(err.to_string())),
+        };
+        parse_page(response)
+    }
+}
+
+/// Parses a `getEvents` JSON-RPC response.
+pub fn parse_page(response: Value) -> Result<EventPage, RpcError> {
+    if let Some(error) = response.get("error") {
+        return Err(RpcError::Response(error.to_string()));
+    }
+    #[derive(Deserialize)]
+    struct GetEventsResult {
+        events: Vec<RawEvent>,
+        cursor: String,
+    }
+    let result: GetEventsResult = serde_json::from_value(response["result"].clone())
+        .map_err(|err| RpcError::Response(format!("unexpected getEvents result: {err}")))?;
+    Ok(EventPage {
+        events: result.events,
+        cursor: result.cursor,
+    })
+}
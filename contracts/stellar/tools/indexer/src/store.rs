@@ -0,0 +1,473 @@
+use attest_types::events::{EventRecord, ProtocolEvent};
+use attest_types::json::SchemaJson;
+use postgres::{Client, NoTls, Row, Transaction};
+use serde::Serialize;
+use std::collections::btree_map::{BTreeMap, Entry};
+use std::fmt;
+
+/// Current state of an attestation, projected from its events.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct IndexedAttestation {
+    pub uid: String,
+    /// Unknown until a revocation event names it; see `ProtocolEvent::AttestationCreated`
+    pub schema_uid: Option<String>,
+    pub subject: String,
+    pub attester: String,
+    pub value: String,
+    pub nonce: u64,
+    pub timestamp: u64,
+    pub revoked: bool,
+    pub revocation_time: Option<u64>,
+    pub expired: bool,
+    pub purged: bool,
+    /// Ledger of the latest event applied
+    pub ledger: u32,
+}
+
+/// A registered schema and whether it was revoked as a whole.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct IndexedSchema {
+    pub uid: String,
+    #[serde(flatten)]
+    pub schema: SchemaJson,
+    pub revoked_at: Option<u64>,
+    pub ledger: u32,
+}
+
+/// Which attestations a listing returns.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttestationFilter {
+    Subject(String),
+    Attester(String),
+    Schema(String),
+}
+
+/// A storage failure.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StoreError(pub String);
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<postgres::Error> for StoreError {
+    fn from(err: postgres::Error) -> Self {
+        StoreError(err.to_string())
+    }
+}
+
+/// Persistence for indexed events and the state projected from them.
+pub trait Store {
+    /// The RPC cursor after the last applied page, if any page was applied.
+    fn cursor(&mut self) -> Result<Option<String>, StoreError>;
+
+    /// Appends `records` and advances the cursor, atomically.
+    ///
+    /// Records already stored are skipped, so a page replayed after a crash is not
+    /// applied twice.
+    fn apply(&mut self, records: &[EventRecord], cursor: &str) -> Result<(), StoreError>;
+
+    fn attestation(&mut self, uid: &str) -> Result<Option<IndexedAttestation>, StoreError>;
+
+    /// Lists matching attestations, oldest first.
+    fn attestations(&mut self, filter: &AttestationFilter, limit: u32) -> Result<Vec<IndexedAttestation>, StoreError>;
+
+    fn schema(&mut self, uid: &str) -> Result<Option<IndexedSchema>, StoreError>;
+
+    /// Lists events with an ID after `after`, in emission order.
+    fn events(&mut self, after: Option<&str>, limit: u32) -> Result<Vec<EventRecord>, StoreError>;
+}
+
+/// In-memory [`Store`], for tests and dry runs against a live RPC.
+#[derive(Default)]
+pub struct MemoryStore {
+    cursor: Option<String>,
+    events: BTreeMap<String, EventRecord>,
+    attestations: BTreeMap<String, IndexedAttestation>,
+    schemas: BTreeMap<String, IndexedSchema>,
+}
+
+impl MemoryStore {
+    fn project(&mut self, record: &EventRecord) {
+        let ledger = record.ledger;
+        match &record.event {
+            ProtocolEvent::SchemaRegistered { schema_uid, schema, .. } => {
+                self.schemas.entry(schema_uid.clone()).or_insert(IndexedSchema {
+                    uid: schema_uid.clone(),
+                    schema: schema.clone(),
+                    revoked_at: None,
+                    ledger,
+                });
+            },
+            ProtocolEvent::SchemaRevokedAll {
+                schema_uid, revoked_at, ..
+            } => {
+                if let Some(schema) = self.schemas.get_mut(schema_uid) {
+                    schema.revoked_at = Some(*revoked_at);
+                    schema.ledger = ledger;
+                }
+                for attestation in self.attestations.values_mut() {
+                    if attestation.schema_uid.as_ref() == Some(schema_uid) && !attestation.revoked {
+                        attestation.revoked = true;
+                        attestation.revocation_time = Some(*revoked_at);
+                        attestation.ledger = ledger;
+                    }
+                }
+            },
+            ProtocolEvent::AttestationCreated {
+                uid,
+                subject,
+                attester,
+                value,
+                nonce,
+                timestamp,
+            } => {
+                self.attestations.entry(uid.clone()).or_insert(IndexedAttestation {
+                    uid: uid.clone(),
+                    schema_uid: None,
+                    subject: subject.clone(),
+                    attester: attester.clone(),
+                    value: value.clone(),
+                    nonce: *nonce,
+                    timestamp: *timestamp,
+                    revoked: false,
+                    revocation_time: None,
+                    expired: false,
+                    purged: false,
+                    ledger,
+                });
+            },
+            ProtocolEvent::AttestationRevoked {
+                uid,
+                schema_uid,
+                revocation_time,
+                ..
+            }
+            | ProtocolEvent::AttestationSelfRevoked {
+                uid,
+                schema_uid,
+                revocation_time,
+                ..
+            } => {
+                if let Some(attestation) = self.attestations.get_mut(uid) {
+                    attestation.schema_uid = Some(schema_uid.clone());
+                    attestation.revoked = true;
+                    attestation.revocation_time = Some(*revocation_time);
+                    attestation.ledger = ledger;
+                }
+            },
+            ProtocolEvent::AttestationExpired { uid, .. } => {
+                if let Some(attestation) = self.attestations.get_mut(uid) {
+                    attestation.expired = true;
+                    attestation.ledger = ledger;
+                }
+            },
+            ProtocolEvent::AttestationPurged { uid, .. } => {
+                if let Some(attestation) = self.attestations.get_mut(uid) {
+                    attestation.purged = true;
+                    attestation.ledger = ledger;
+                }
+            },
+        }
+    }
+}
+
+impl Store for MemoryStore {
+    fn cursor(&mut self) -> Result<Option<String>, StoreError> {
+        Ok(self.cursor.clone())
+    }
+
+    fn apply(&mut self, records: &[EventRecord], cursor: &str) -> Result<(), StoreError> {
+        for record in records {
+            if let Entry::Vacant(entry) = self.events.entry(record.id.clone()) {
+                entry.insert(record.clone());
+                self.project(record);
+            }
+        }
+        self.cursor = Some(cursor.to_string());
+        Ok(())
+    }
+
+    fn attestation(&mut self, uid: &str) -> Result<Option<IndexedAttestation>, StoreError> {
+        Ok(self.attestations.get(uid).cloned())
+    }
+
+    fn attestations(&mut self, filter: &AttestationFilter, limit: u32) -> Result<Vec<IndexedAttestation>, StoreError> {
+        let mut matches: Vec<_> = self
+            .attestations
+            .values()
+            .filter(|attestation| match filter {
+                AttestationFilter::Subject(subject) => &attestation.subject == subject,
+                AttestationFilter::Attester(attester) => &attestation.attester == attester,
+                AttestationFilter::Schema(schema_uid) => attestation.schema_uid.as_ref() == Some(schema_uid),
+            })
+            .cloned()
+            .collect();
+        matches.sort_by(|a, b| (a.timestamp, &a.uid).cmp(&(b.timestamp, &b.uid)));
+        matches.truncate(limit as usize);
+        Ok(matches)
+    }
+
+    fn schema(&mut self, uid: &str) -> Result<Option<IndexedSchema>, StoreError> {
+        Ok(self.schemas.get(uid).cloned())
+    }
+
+    fn events(&mut self, after: Option<&str>, limit: u32) -> Result<Vec<EventRecord>, StoreError> {
+        Ok(self
+            .events
+            .values()
+            .filter(|record| after.is_none_or(|after| record.id.as_str() > after))
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+const MIGRATION: &str = "
+CREATE TABLE IF NOT EXISTS indexer_cursor (
+    singleton BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (singleton),
+    cursor TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS events (
+    id TEXT PRIMARY KEY,
+    ledger BIGINT NOT NULL,
+    kind TEXT NOT NULL,
+    attestation_uid TEXT,
+    record JSONB NOT NULL
+);
+CREATE INDEX IF NOT EXISTS events_attestation_uid ON events (attestation_uid);
+CREATE TABLE IF NOT EXISTS schemas (
+    uid TEXT PRIMARY KEY,
+    authority TEXT NOT NULL,
+    definition TEXT NOT NULL,
+    resolver TEXT,
+    revocable BOOLEAN NOT NULL,
+    namespace TEXT,
+    revoked_at BIGINT,
+    ledger BIGINT NOT NULL
+);
+ALTER TABLE schemas ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE schemas ADD COLUMN IF NOT EXISTS previous_uid TEXT;
+CREATE TABLE IF NOT EXISTS attestations (
+    uid TEXT PRIMARY KEY,
+    schema_uid TEXT,
+    subject TEXT NOT NULL,
+    attester TEXT NOT NULL,
+    value TEXT NOT NULL,
+    nonce BIGINT NOT NULL,
+    timestamp BIGINT NOT NULL,
+    revoked BOOLEAN NOT NULL DEFAULT FALSE,
+    revocation_time BIGINT,
+    expired BOOLEAN NOT NULL DEFAULT FALSE,
+    purged BOOLEAN NOT NULL DEFAULT FALSE,
+    ledger BIGINT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS attestations_subject ON attestations (subject, timestamp);
+CREATE INDEX IF NOT EXISTS attestations_attester ON attestations (attester, timestamp);
+CREATE INDEX IF NOT EXISTS attestations_schema_uid ON attestations (schema_uid, timestamp);
+";
+
+/// [`Store`] backed by Postgres. Tables are created on first connect.
+pub struct PgStore {
+    client: Client,
+}
+
+impl PgStore {
+    pub fn connect(database_url: &str) -> Result<Self, StoreError> {
+        let mut client = Client::connect(database_url, NoTls)?;
+        client.batch_execute(MIGRATION)?;
+        Ok(PgStore { client })
+    }
+}
+
+/// Applies one new event's effect on the projected tables.
+fn project(tx: &mut Transaction<'_>, record: &EventRecord) -> Result<(), postgres::Error> {
+    let ledger = i64::from(record.ledger);
+    match &record.event {
+        ProtocolEvent::SchemaRegistered { schema_uid, schema, .. } => {
+            tx.execute(
+                "INSERT INTO schemas (uid, authority, definition, resolver, revocable, namespace, version, previous_uid, ledger)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) ON CONFLICT (uid) DO NOTHING",
+                &[
+                    schema_uid,
+                    &schema.authority,
+                    &schema.definition,
+                    &schema.resolver,
+                    &schema.revocable,
+                    &schema.namespace,
+                    &(schema.version as i32),
+                    &schema.previous_uid,
+                    &ledger,
+                ],
+            )?;
+        },
+        ProtocolEvent::SchemaRevokedAll {
+            schema_uid, revoked_at, ..
+        } => {
+            let revoked_at = *revoked_at as i64;
+            tx.execute(
+                "UPDATE schemas SET revoked_at = $2, ledger = $3 WHERE uid = $1",
+                &[schema_uid, &revoked_at, &ledger],
+            )?;
+            tx.execute(
+                "UPDATE attestations SET revoked = TRUE, revocation_time = $2, ledger = $3
+                 WHERE schema_uid = $1 AND NOT revoked",
+                &[schema_uid, &revoked_at, &ledger],
+            )?;
+        },
+        ProtocolEvent::AttestationCreated {
+            uid,
+            subject,
+            attester,
+            value,
+            nonce,
+            timestamp,
+        } => {
+            tx.execute(
+                "INSERT INTO attestations (uid, subject, attester, value, nonce, timestamp, ledger)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7) ON CONFLICT (uid) DO NOTHING",
+                &[
+                    uid,
+                    subject,
+                    attester,
+                    value,
+                    &(*nonce as i64),
+                    &(*timestamp as i64),
+                    &ledger,
+                ],
+            )?;
+        },
+        ProtocolEvent::AttestationRevoked {
+            uid,
+            schema_uid,
+            revocation_time,
+            ..
+        }
+        | ProtocolEvent::AttestationSelfRevoked {
+            uid,
+            schema_uid,
+            revocation_time,
+            ..
+        } => {
+            tx.execute(
+                "UPDATE attestations SET schema_uid = $2, revoked = TRUE, revocation_time = $3, ledger = $4
+                 WHERE uid = $1",
+                &[uid, schema_uid, &(*revocation_time as i64), &ledger],
+            )?;
+        },
+        ProtocolEvent::AttestationExpired { uid, .. } => {
+            tx.execute(
+                "UPDATE attestations SET expired = TRUE, ledger = $2 WHERE uid = $1",
+                &[uid, &ledger],
+            )?;
+        },
+        ProtocolEvent::AttestationPurged { uid, .. } => {
+            tx.execute(
+                "UPDATE attestations SET purged = TRUE, ledger = $2 WHERE uid = $1",
+                &[uid, &ledger],
+            )?;
+        },
+    }
+    Ok(())
+}
+
+fn attestation_from_row(row: &Row) -> IndexedAttestation {
+    IndexedAttestation {
+        uid: row.get("uid"),
+        schema_uid: row.get("schema_uid"),
+        subject: row.get("subject"),
+        attester: row.get("attester"),
+        value: row.get("value"),
+        nonce: row.get::<_, i64>("nonce") as u64,
+        timestamp: row.get::<_, i64>("timestamp") as u64,
+        revoked: row.get("revoked"),
+        revocation_time: row.get::<_, Option<i64>>("revocation_time").map(|time| time as u64),
+        expired: row.get("expired"),
+        purged: row.get("purged"),
+        ledger: row.get::<_, i64>("ledger") as u32,
+    }
+}
+
+impl Store for PgStore {
+    fn cursor(&mut self) -> Result<Option<String>, StoreError> {
+        let row = self.client.query_opt("SELECT cursor FROM indexer_cursor", &[])?;
+        Ok(row.map(|row| row.get(0)))
+    }
+
+    fn apply(&mut self, records: &[EventRecord], cursor: &str) -> Result<(), StoreError> {
+        let mut tx = self.client.transaction()?;
+        for record in records {
+            let json = serde_json::to_value(record).map_err(|err| StoreError(err.to_string()))?;
+            let kind = json["event"]["kind"].as_str().unwrap_or_default().to_string();
+            let inserted = tx.execute(
+                "INSERT INTO events (id, ledger, kind, attestation_uid, record)
+                 VALUES ($1, $2, $3, $4, $5) ON CONFLICT (id) DO NOTHING",
+                &[
+                    &record.id,
+                    &i64::from(record.ledger),
+                    &kind,
+                    &record.event.attestation_uid(),
+                    &json,
+                ],
+            )?;
+            if inserted == 1 {
+                project(&mut tx, record)?;
+            }
+        }
+        tx.execute(
+            "INSERT INTO indexer_cursor (cursor) VALUES ($1)
+             ON CONFLICT (singleton) DO UPDATE SET cursor = EXCLUDED.cursor",
+            &[&cursor],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn attestation(&mut self, uid: &str) -> Result<Option<IndexedAttestation>, StoreError> {
+        let row = self
+            .client
+            .query_opt("SELECT * FROM attestations WHERE uid = $1", &[&uid])?;
+        Ok(row.as_ref().map(attestation_from_row))
+    }
+
+    fn attestations(&mut self, filter: &AttestationFilter, limit: u32) -> Result<Vec<IndexedAttestation>, StoreError> {
+        let (column, value) = match filter {
+            AttestationFilter::Subject(subject) => ("subject", subject),
+            AttestationFilter::Attester(attester) => ("attester", attester),
+            AttestationFilter::Schema(schema_uid) => ("schema_uid", schema_uid),
+        };
+        let query = format!("SELECT * FROM attestations WHERE {column} = $1 ORDER BY timestamp, uid LIMIT $2");
+        let rows = self.client.query(&query, &[value, &i64::from(limit)])?;
+        Ok(rows.iter().map(attestation_from_row).collect())
+    }
+
+    fn schema(&mut self, uid: &str) -> Result<Option<IndexedSchema>, StoreError> {
+        let row = self.client.query_opt("SELECT * FROM schemas WHERE uid = $1", &[&uid])?;
+        Ok(row.map(|row| IndexedSchema {
+            uid: row.get("uid"),
+            schema: SchemaJson {
+                authority: row.get("authority"),
+                definition: row.get("definition"),
+                resolver: row.get("resolver"),
+                revocable: row.get("revocable"),
+                namespace: row.get("namespace"),
+                version: row.get::<_, i32>("version") as u32,
+                previous_uid: row.get("previous_uid"),
+            },
+            revoked_at: row.get::<_, Option<i64>>("revoked_at").map(|time| time as u64),
+            ledger: row.get::<_, i64>("ledger") as u32,
+        }))
+    }
+
+    fn events(&mut self, after: Option<&str>, limit: u32) -> Result<Vec<EventRecord>, StoreError> {
+        let rows = self.client.query(
+            "SELECT record FROM events WHERE $1::TEXT IS NULL OR id > $1 ORDER BY id LIMIT $2",
+            &[&after, &i64::from(limit)],
+        )?;
+        rows.iter()
+            .map(|row| serde_json::from_value(row.get(0)).map_err(|err| StoreError(err.to_string())))
+            .collect()
+    }
+}
@@ -0,0 +1,197 @@
+use attest_types::events::ProtocolEvent;
+use indexer::api::route;
+use indexer::{
+    normalize, AttestationFilter, EventPage, EventSource, Indexer, MemoryStore, NormalizeError, RawEvent, RpcError,
+    Start, Store,
+};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+
+const SCHEMA_UID: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+const UID: &str = "2222222222222222222222222222222222222222222222222222222222222222";
+const ATTESTER: &str = "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7";
+const SUBJECT: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+
+/// Serves a fixed event log in pages; cursors are positions in the log.
+struct MockSource {
+    log: Vec<RawEvent>,
+    offline: RefCell<bool>,
+}
+
+impl EventSource for MockSource {
+    fn events(&self, start: &Start, limit: u32) -> Result<EventPage, RpcError> {
+        if *self.offline.borrow() {
+            return Err(RpcError::Transport("rpc unavailable".into()));
+        }
+        let from = match start {
+            Start::Ledger(ledger) => self
+                .log
+                .iter()
+                .position(|e| e.ledger >= *ledger)
+                .unwrap_or(self.log.len()),
+            Start::Cursor(cursor) => cursor.parse().unwrap(),
+        };
+        let to = (from + limit as usize).min(self.log.len());
+        Ok(EventPage {
+            events: self.log[from..to].to_vec(),
+            cursor: to.to_string(),
+        })
+    }
+}
+
+fn raw(id: u32, topics: &[&str], value: Value) -> RawEvent {
+    RawEvent {
+        id: format!("{id:019}-0000000001"),
+        ledger: id,
+        ledger_closed_at: "2025-01-01T00:00:00Z".into(),
+        contract_id: "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC".into(),
+        topic_json: topics.iter().map(|topic| json!({ "symbol": topic })).collect(),
+        value_json: value,
+    }
+}
+
+fn log() -> Vec<RawEvent> {
+    let schema = json!({ "map": [
+        { "key": { "symbol": "authority" }, "val": { "address": ATTESTER } },
+        { "key": { "symbol": "definition" }, "val": { "string": "name:string" } },
+        { "key": { "symbol": "namespace" }, "val": "void" },
+        { "key": { "symbol": "resolver" }, "val": "void" },
+        { "key": { "symbol": "revocable" }, "val": { "bool": true } },
+    ]});
+    vec![
+        raw(
+            10,
+            &["SCHEMA", "REGISTER"],
+            json!({ "vec": [{ "bytes": SCHEMA_UID }, schema, { "address": ATTESTER }] }),
+        ),
+        raw(11, &["METRICS"], json!({ "u64": "1" })),
+        raw(
+            12,
+            &["ATTEST", "CREATE", "kyc"],
+            json!({ "vec": [
+                { "bytes": UID },
+                { "address": SUBJECT },
+                { "address": ATTESTER },
+                { "string": "{\"name\":\"Ada\"}" },
+                { "u64": "0" },
+                { "u64": 1_700_000_000u64 },
+            ]}),
+        ),
+        raw(13, &["ATTEST", "CREATE"], json!({ "vec": [{ "bytes": "00" }] })),
+        raw(
+            14,
+            &["ATTEST", "REVOKE"],
+            json!({ "vec": [
+                { "bytes": UID },
+                { "bytes": SCHEMA_UID },
+                { "address": SUBJECT },
+                { "address": ATTESTER },
+                { "bool": true },
+                { "u64": "1700000500" },
+            ]}),
+        ),
+    ]
+}
+
+/// **Test: Protocol Events Decode into Typed Events**
+/// - Untracked events are ignored and malformed ones are reported by field
+#[test]
+fn normalizes_protocol_events() {
+    let log = log();
+    let created = normalize(&log[2]).unwrap().unwrap();
+    assert_eq!(created.extra_topics, vec!["kyc"]);
+    assert_eq!(
+        created.event,
+        ProtocolEvent::AttestationCreated {
+            uid: UID.into(),
+            subject: SUBJECT.into(),
+            attester: ATTESTER.into(),
+            value: "{\"name\":\"Ada\"}".into(),
+            nonce: 0,
+            timestamp: 1_700_000_000,
+        }
+    );
+    match normalize(&log[0]).unwrap().unwrap().event {
+        ProtocolEvent::SchemaRegistered { schema, .. } => {
+            assert_eq!(schema.definition, "name:string");
+            assert_eq!((schema.resolver, schema.revocable), (None, true));
+        },
+        other => panic!("unexpected event {other:?}"),
+    }
+    assert_eq!(normalize(&log[1]).unwrap(), None);
+    assert_eq!(
+        normalize(&log[3]),
+        Err(NormalizeError::Malformed {
+            id: log[3].id.clone(),
+            field: "subject",
+        })
+    );
+}
+
+/// **Test: Rounds Page Through Events and Resume from the Cursor**
+#[test]
+fn indexes_pages_and_projects_state() {
+    let mut indexer = Indexer {
+        source: MockSource {
+            log: log(),
+            offline: RefCell::new(false),
+        },
+        store: MemoryStore::default(),
+        start_ledger: 10,
+        page_size: 3,
+    };
+
+    let first = indexer.index_once().unwrap();
+    assert_eq!((first.fetched, first.indexed, first.caught_up), (3, 2, false));
+    assert!(!indexer.store.attestation(UID).unwrap().unwrap().revoked);
+
+    *indexer.source.offline.borrow_mut() = true;
+    assert!(indexer.index_once().is_err());
+    *indexer.source.offline.borrow_mut() = false;
+
+    let second = indexer.index_once().unwrap();
+    assert_eq!((second.fetched, second.indexed, second.caught_up), (2, 1, true));
+    assert_eq!(second.malformed.len(), 1);
+
+    let attestation = indexer.store.attestation(UID).unwrap().unwrap();
+    assert!(attestation.revoked);
+    assert_eq!(attestation.schema_uid.as_deref(), Some(SCHEMA_UID));
+    assert_eq!(attestation.revocation_time, Some(1_700_000_500));
+    let by_schema = indexer
+        .store
+        .attestations(&AttestationFilter::Schema(SCHEMA_UID.into()), 10)
+        .unwrap();
+    assert_eq!(by_schema, vec![attestation]);
+
+    // Replaying a page does not apply its events twice
+    let events = indexer.store.events(None, 10).unwrap();
+    assert_eq!(events.len(), 3);
+    indexer.store.apply(&events, "5").unwrap();
+    assert_eq!(indexer.store.events(None, 10).unwrap(), events);
+    assert_eq!(indexer.index_once().unwrap().fetched, 0);
+}
+
+/// **Test: The Query API Serves Indexed State**
+#[test]
+fn query_api_serves_indexed_state() {
+    let mut store = MemoryStore::default();
+    let records: Vec<_> = log().iter().filter_map(|raw| normalize(raw).ok().flatten()).collect();
+    store.apply(&records, "5").unwrap();
+
+    let (status, body) = route(&mut store, "GET", &format!("/attestations/{UID}"));
+    assert_eq!(status, 200);
+    assert!(body.contains("\"revoked\":true"));
+    let (status, body) = route(&mut store, "GET", &format!("/subjects/{SUBJECT}/attestations?limit=1"));
+    assert_eq!(status, 200);
+    assert_eq!(serde_json::from_str::<Vec<Value>>(&body).unwrap().len(), 1);
+    let (status, body) = route(&mut store, "GET", &format!("/schemas/{SCHEMA_UID}"));
+    assert_eq!(status, 200);
+    assert!(body.contains("\"definition\":\"name:string\""));
+
+    let (_, body) = route(&mut store, "GET", &format!("/events?after={}&limit=10", records[0].id));
+    assert_eq!(serde_json::from_str::<Vec<Value>>(&body).unwrap().len(), 2);
+    assert_eq!(route(&mut store, "GET", "/attestations/ff").0, 404);
+    assert_eq!(route(&mut store, "GET", "/events?limit=x").0, 400);
+    assert_eq!(route(&mut store, "POST", "/events").0, 405);
+    assert_eq!(route(&mut store, "GET", "/health").0, 200);
+}
@@ -0,0 +1,91 @@
+use attest_types::json::DelegatedAttestationRequestJson;
+use std::process::Command;
+
+/// What the load generator needs from the network.
+pub trait Chain: Sync {
+    /// Creates and funds the identity for attester `index`, registers `public_key` as
+    /// its BLS key, and returns its address.
+    fn enroll(&self, index: u32, public_key: &[u8; 192]) -> Result<String, String>;
+
+    /// The nonce the attester's next delegated request must carry.
+    fn next_nonce(&self, attester: &str) -> Result<u64, String>;
+
+    /// Submits a signed request through `attest_by_delegation` and waits for it to land.
+    fn submit(&self, request: &DelegatedAttestationRequestJson) -> Result<(), String>;
+}
+
+/// [`Chain`] backed by the `stellar` CLI, which handles RPC, signing and fees.
+pub struct StellarCli {
+    pub contract_id: String,
+    pub network: String,
+    pub source: String,
+    pub submitter: String,
+}
+
+impl StellarCli {
+    fn stellar(&self, args: &[&str]) -> Result<String, String> {
+        let output = Command::new("stellar")
+            .args(args)
+            .output()
+            .map_err(|err| format!("failed to run stellar CLI: {err}"))?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    fn invoke(&self, source: &str, send: bool, function: &str, args: &[(&str, &str)]) -> Result<String, String> {
+        let mut command = vec!["contract", "invoke", "--id", &self.contract_id, "--source", source];
+        command.extend(["--network", &self.network]);
+        command.push(if send { "--send=yes" } else { "--send=no" });
+        command.extend(["--", function]);
+        let flags: Vec<String> = args.iter().map(|(name, _)| format!("--{name}")).collect();
+        for ((_, value), flag) in args.iter().zip(&flags) {
+            command.extend([flag.as_str(), value]);
+        }
+        self.stellar(&command)
+    }
+}
+
+impl Chain for StellarCli {
+    fn enroll(&self, index: u32, public_key: &[u8; 192]) -> Result<String, String> {
+        let identity = format!("loadgen-{index}");
+        self.stellar(&[
+            "keys",
+            "generate",
+            &identity,
+            "--network",
+            &self.network,
+            "--fund",
+            "--overwrite",
+        ])?;
+        let address = self.stellar(&["keys", "address", &identity])?;
+        let public_key: String = public_key.iter().map(|byte| format!("{byte:02x}")).collect();
+        self.invoke(
+            &identity,
+            true,
+            "register_bls_key",
+            &[("attester", &address), ("public_key", &public_key)],
+        )?;
+        Ok(address)
+    }
+
+    fn next_nonce(&self, attester: &str) -> Result<u64, String> {
+        let nonce = self.invoke(&self.source, false, "get_attester_nonce", &[("attester", attester)])?;
+        nonce
+            .trim_matches('"')
+            .parse()
+            .map_err(|_| format!("unexpected nonce output: {nonce}"))
+    }
+
+    fn submit(&self, request: &DelegatedAttestationRequestJson) -> Result<(), String> {
+        let request = serde_json::to_string(request).map_err(|err| err.to_string())?;
+        self.invoke(
+            &self.source,
+            true,
+            "attest_by_delegation",
+            &[("submitter", &self.submitter), ("request", &request)],
+        )
+        .map(|_| ())
+    }
+}
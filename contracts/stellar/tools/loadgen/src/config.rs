@@ -0,0 +1,61 @@
+use deployments::Network;
+
+/// Load test settings, read from `LOADGEN_*` environment variables.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Protocol contract ID (`LOADGEN_CONTRACT_ID`, required)
+    pub contract_id: String,
+    /// `stellar` CLI network name (`LOADGEN_NETWORK`); mainnet is refused
+    pub network: String,
+    /// `stellar` CLI identity that pays for submissions (`LOADGEN_SOURCE`, required)
+    pub source: String,
+    /// Address of `source`, passed as the submitter (`LOADGEN_SUBMITTER`, required)
+    pub submitter: String,
+    /// Hex UID of a registered schema to attest under (`LOADGEN_SCHEMA_UID`, required)
+    pub schema_uid: String,
+    /// Subject of every attestation (`LOADGEN_SUBJECT`, defaults to the submitter)
+    pub subject: String,
+    /// Concurrent attesters, each with its own identity and nonce (`LOADGEN_ATTESTERS`)
+    pub attesters: u32,
+    /// Attestations each attester submits, one after another (`LOADGEN_PER_ATTESTER`)
+    pub per_attester: u32,
+    /// Seed the attesters' BLS keys are derived from (`LOADGEN_SEED`)
+    pub seed: u64,
+}
+
+impl Config {
+    /// Reads the configuration, naming the first missing or malformed variable on error.
+    pub fn from_env() -> Result<Self, String> {
+        let network = var_or("LOADGEN_NETWORK", "standalone");
+        if network.parse() == Ok(Network::Mainnet) {
+            return Err("LOADGEN_NETWORK must not be mainnet".to_string());
+        }
+        let submitter = required("LOADGEN_SUBMITTER")?;
+        Ok(Config {
+            contract_id: required("LOADGEN_CONTRACT_ID")?,
+            network,
+            source: required("LOADGEN_SOURCE")?,
+            subject: var_or("LOADGEN_SUBJECT", &submitter),
+            submitter,
+            schema_uid: required("LOADGEN_SCHEMA_UID")?,
+            attesters: parsed("LOADGEN_ATTESTERS", 8)?,
+            per_attester: parsed("LOADGEN_PER_ATTESTER", 25)?,
+            seed: parsed("LOADGEN_SEED", 1)?,
+        })
+    }
+}
+
+fn var_or(name: &str, default: &str) -> String {
+    std::env::var(name).unwrap_or_else(|_| default.to_string())
+}
+
+fn required(name: &str) -> Result<String, String> {
+    std::env::var(name).map_err(|_| format!("{name} must be set"))
+}
+
+fn parsed<T: std::str::FromStr>(name: &str, default: T) -> Result<T, String> {
+    match std::env::var(name) {
+        Ok(value) => value.parse().map_err(|_| format!("{name} is not a valid number")),
+        Err(_) => Ok(default),
+    }
+}
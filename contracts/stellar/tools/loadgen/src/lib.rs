@@ -0,0 +1,20 @@
+//! # Attest Loadgen
+//!
+//! Load generator for delegated attestations. It enrolls a set of attesters, each with
+//! its own identity, BLS key and nonce sequence, then has them all submit signed
+//! requests through `attest_by_delegation` at once, recording submission latency and
+//! failures by cause. Runs are meant for standalone and test networks, to check that
+//! per-attester nonces and attestation storage hold up under concurrent load.
+//!
+//! - [`chain`]: enrollment and submission against the contract
+//! - [`runner`]: the concurrent run
+//! - [`report`]: latency and failure statistics
+pub mod chain;
+pub mod config;
+pub mod report;
+pub mod runner;
+
+pub use chain::{Chain, StellarCli};
+pub use config::Config;
+pub use report::{classify, Latency, Report, Sample};
+pub use runner::{run, secret_key, Plan, DEADLINE_SECONDS};
@@ -0,0 +1,32 @@
+use loadgen::{run, Config, Plan, StellarCli};
+
+fn main() {
+    let config = Config::from_env().unwrap_or_else(|err| {
+        eprintln!("loadgen: {err}");
+        std::process::exit(2);
+    });
+
+    let chain = StellarCli {
+        contract_id: config.contract_id.clone(),
+        network: config.network.clone(),
+        source: config.source.clone(),
+        submitter: config.submitter.clone(),
+    };
+    let plan = Plan {
+        schema_uid: config.schema_uid.clone(),
+        subject: config.subject.clone(),
+        attesters: config.attesters,
+        per_attester: config.per_attester,
+        seed: config.seed,
+    };
+    eprintln!(
+        "loadgen: {} attesters x {} attestations on {}",
+        plan.attesters, plan.per_attester, config.network
+    );
+
+    let report = run(&chain, &plan).unwrap_or_else(|err| {
+        eprintln!("loadgen: enrollment failed: {err}");
+        std::process::exit(1);
+    });
+    println!("{}", serde_json::to_string_pretty(&report).expect("report serializes"));
+}
@@ -0,0 +1,102 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// One timed submission.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sample {
+    pub attester: u32,
+    pub latency: Duration,
+    /// The error the submission failed with, if any
+    pub error: Option<String>,
+}
+
+/// Latency distribution of a set of submissions, in milliseconds.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Latency {
+    pub mean: u64,
+    pub p50: u64,
+    pub p90: u64,
+    pub p99: u64,
+    pub max: u64,
+}
+
+/// Outcome of a load test run.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Report {
+    pub submitted: usize,
+    pub succeeded: usize,
+    /// Failed submissions by [`classify`]d cause
+    pub failures: BTreeMap<String, usize>,
+    /// Latency of successful submissions
+    pub latency_ms: Latency,
+    /// Latency of failed submissions, which includes simulation-time rejections
+    pub failed_latency_ms: Latency,
+    pub wall_time_ms: u64,
+    /// Successful submissions per second of wall time
+    pub throughput: f64,
+}
+
+impl Report {
+    pub fn from_samples(samples: &[Sample], wall_time: Duration) -> Self {
+        let mut failures = BTreeMap::new();
+        let mut succeeded = Vec::new();
+        let mut failed = Vec::new();
+        for sample in samples {
+            match &sample.error {
+                None => succeeded.push(sample.latency),
+                Some(error) => {
+                    *failures.entry(classify(error)).or_insert(0) += 1;
+                    failed.push(sample.latency);
+                },
+            }
+        }
+        let seconds = wall_time.as_secs_f64();
+        Report {
+            submitted: samples.len(),
+            succeeded: succeeded.len(),
+            failures,
+            latency_ms: latency(&mut succeeded),
+            failed_latency_ms: latency(&mut failed),
+            wall_time_ms: wall_time.as_millis() as u64,
+            throughput: if seconds > 0.0 {
+                succeeded.len() as f64 / seconds
+            } else {
+                0.0
+            },
+        }
+    }
+}
+
+/// Groups an error message into a failure category.
+///
+/// Contract errors are grouped by code (`Error(Contract, #119)` for a stale nonce),
+/// other host errors together, and everything else as transport failures.
+pub fn classify(error: &str) -> String {
+    if let Some(start) = error.find("Error(Contract, #") {
+        if let Some(end) = error[start..].find(')') {
+            return error[start..start + end + 1].to_string();
+        }
+    }
+    if error.contains("HostError") || error.contains("Error(") {
+        return "host error".to_string();
+    }
+    "transport".to_string()
+}
+
+fn latency(samples: &mut [Duration]) -> Latency {
+    if samples.is_empty() {
+        return Latency::default();
+    }
+    samples.sort();
+    let millis = |duration: Duration| duration.as_millis() as u64;
+    // Nearest-rank percentile
+    let rank = |percent: usize| millis(samples[(samples.len() * percent).div_ceil(100).max(1) - 1]);
+    Latency {
+        mean: millis(samples.iter().sum::<Duration>() / samples.len() as u32),
+        p50: rank(50),
+        p90: rank(90),
+        p99: rank(99),
+        max: millis(samples[samples.len() - 1]),
+    }
+}
@@ -0,0 +1,111 @@
+use crate::chain::Chain;
+use crate::report::{Report, Sample};
+use attest_types::json::{DelegatedAttestationRequestJson, JsonCodec};
+use attest_types::DelegatedAttestationRequest;
+use delegation_signer::{public_key, sign_attestation_request};
+use soroban_sdk::Env;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Signatures stay valid this long after a request is built.
+pub const DEADLINE_SECONDS: u64 = 600;
+
+/// What a load test submits.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Plan {
+    /// Hex UID of the schema to attest under
+    pub schema_uid: String,
+    pub subject: String,
+    /// Concurrent attesters
+    pub attesters: u32,
+    /// Attestations per attester
+    pub per_attester: u32,
+    pub seed: u64,
+}
+
+/// Derives attester `index`'s BLS secret key from `seed`.
+///
+/// The keys are predictable by design, so a run can be repeated against the same
+/// identities; they must only ever be registered on test networks.
+pub fn secret_key(seed: u64, index: u32) -> [u8; 32] {
+    // The leading zero bytes keep the scalar below the curve order
+    let mut key = [0u8; 32];
+    key[8..16].copy_from_slice(&seed.to_be_bytes());
+    key[24..32].copy_from_slice(&(index as u64 + 1).to_be_bytes());
+    key
+}
+
+/// Enrolls the plan's attesters, then has each submit its attestations concurrently
+/// with the others, timing every submission.
+///
+/// Each attester submits sequentially, since its nonces must land in order; after a
+/// failure it re-reads its nonce so one rejection does not fail the rest of its run.
+/// Enrollment is not part of the measured wall time.
+pub fn run<C: Chain>(chain: &C, plan: &Plan) -> Result<Report, String> {
+    let mut attesters = Vec::new();
+    for index in 0..plan.attesters {
+        let secret = secret_key(plan.seed, index);
+        let public = public_key(&secret).map_err(|err| format!("attester {index}: {err:?}"))?;
+        let address = chain.enroll(index, &public)?;
+        let nonce = chain.next_nonce(&address)?;
+        attesters.push((index, secret, address, nonce));
+    }
+
+    let samples = Mutex::new(Vec::new());
+    let started = Instant::now();
+    thread::scope(|scope| {
+        for (index, secret, address, nonce) in &attesters {
+            let samples = &samples;
+            scope.spawn(move || {
+                let mut nonce = *nonce;
+                for sequence in 0..plan.per_attester {
+                    let request = signed_request(plan, secret, address, nonce, sequence);
+                    let sent = Instant::now();
+                    let result = request.and_then(|request| chain.submit(&request));
+                    let latency = sent.elapsed();
+                    match &result {
+                        Ok(()) => nonce += 1,
+                        Err(_) => nonce = chain.next_nonce(address).unwrap_or(nonce),
+                    }
+                    samples.lock().unwrap().push(Sample {
+                        attester: *index,
+                        latency,
+                        error: result.err(),
+                    });
+                }
+            });
+        }
+    });
+
+    Ok(Report::from_samples(&samples.into_inner().unwrap(), started.elapsed()))
+}
+
+fn signed_request(
+    plan: &Plan,
+    secret: &[u8; 32],
+    attester: &str,
+    nonce: u64,
+    sequence: u32,
+) -> Result<DelegatedAttestationRequestJson, String> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_secs();
+    let mut json = DelegatedAttestationRequestJson {
+        schema_uid: plan.schema_uid.clone(),
+        subject: plan.subject.clone(),
+        attester: attester.to_string(),
+        value: format!("{{\"loadgen\":{sequence}}}"),
+        nonce,
+        deadline: now + DEADLINE_SECONDS,
+        expiration_time: None,
+        version: 1,
+        signature: "00".repeat(96),
+    };
+    let request = DelegatedAttestationRequest::from_json_value(&Env::default(), json.clone())
+        .map_err(|err| format!("invalid request: {err:?}"))?;
+    let signature = sign_attestation_request(secret, &request).map_err(|err| format!("{err:?}"))?;
+    json.signature = signature.to_array().iter().map(|byte| format!("{byte:02x}")).collect();
+    Ok(json)
+}
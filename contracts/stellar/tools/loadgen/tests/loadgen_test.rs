@@ -0,0 +1,99 @@
+use attest_types::json::DelegatedAttestationRequestJson;
+use loadgen::{classify, run, secret_key, Chain, Plan, Report, Sample};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Tracks nonces like the contract does, and drops the first submission it sees from
+/// `flaky` as a transport failure.
+struct MockChain {
+    addresses: Vec<String>,
+    nonces: Mutex<HashMap<String, u64>>,
+    flaky: String,
+    dropped: Mutex<bool>,
+}
+
+impl Chain for MockChain {
+    fn enroll(&self, index: u32, _public_key: &[u8; 192]) -> Result<String, String> {
+        Ok(self.addresses[index as usize].clone())
+    }
+
+    fn next_nonce(&self, attester: &str) -> Result<u64, String> {
+        Ok(*self.nonces.lock().unwrap().get(attester).unwrap_or(&0))
+    }
+
+    fn submit(&self, request: &DelegatedAttestationRequestJson) -> Result<(), String> {
+        assert_ne!(request.signature, "00".repeat(96));
+        if request.attester == self.flaky && !std::mem::replace(&mut *self.dropped.lock().unwrap(), true) {
+            return Err("connection reset by peer".into());
+        }
+        let mut nonces = self.nonces.lock().unwrap();
+        let nonce = nonces.entry(request.attester.clone()).or_insert(0);
+        if request.nonce != *nonce {
+            return Err("HostError: Error(Contract, #119)".into());
+        }
+        *nonce += 1;
+        Ok(())
+    }
+}
+
+/// **Test: Concurrent Attesters Keep Their Nonces in Step**
+/// - A failed submission does not derail the attester's remaining requests
+#[test]
+fn concurrent_run_reports_latency_and_failures() {
+    let env = Env::default();
+    let addresses: Vec<String> = (0..3)
+        .map(|_| Address::generate(&env).to_string().to_string())
+        .collect();
+    let chain = MockChain {
+        flaky: addresses[1].clone(),
+        addresses,
+        nonces: Mutex::new(HashMap::new()),
+        dropped: Mutex::new(false),
+    };
+    let plan = Plan {
+        schema_uid: "11".repeat(32),
+        subject: chain.addresses[0].clone(),
+        attesters: 3,
+        per_attester: 4,
+        seed: 7,
+    };
+
+    let report = run(&chain, &plan).unwrap();
+    assert_eq!((report.submitted, report.succeeded), (12, 11));
+    assert_eq!(report.failures.get("transport"), Some(&1));
+    assert_eq!(chain.next_nonce(&chain.addresses[0]), Ok(4));
+    assert_eq!(chain.next_nonce(&chain.addresses[1]), Ok(3));
+
+    assert_ne!(secret_key(7, 0), secret_key(7, 1));
+    assert_ne!(secret_key(7, 0), secret_key(8, 0));
+}
+
+/// **Test: Failures Are Grouped by Cause and Latencies Summarized**
+#[test]
+fn report_classifies_failures_and_ranks_latency() {
+    assert_eq!(
+        classify("HostError: Error(Contract, #119)\nevent log..."),
+        "Error(Contract, #119)"
+    );
+    assert_eq!(classify("HostError: Error(Budget, ExceededLimit)"), "host error");
+    assert_eq!(classify("error sending request: timed out"), "transport");
+
+    let samples: Vec<Sample> = (1..=100)
+        .map(|millis| Sample {
+            attester: 0,
+            latency: Duration::from_millis(millis),
+            error: millis.is_multiple_of(50).then(|| "Error(Contract, #119)".to_string()),
+        })
+        .collect();
+    let report = Report::from_samples(&samples, Duration::from_secs(2));
+    assert_eq!((report.submitted, report.succeeded), (100, 98));
+    assert_eq!(report.failures.get("Error(Contract, #119)"), Some(&2));
+    assert_eq!(report.latency_ms.p50, 49);
+    assert_eq!(report.latency_ms.max, 99);
+    assert_eq!(report.failed_latency_ms.max, 100);
+    assert_eq!(report.throughput, 49.0);
+    assert_eq!(Report::from_samples(&[], Duration::ZERO).throughput, 0.0);
+}
@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+/// Layout version written into every bundle.
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// Everything a third party needs to check an attestation without access to the network.
+///
+/// Contract values are kept exactly as the `stellar` CLI returned them, so hex-encoded
+/// bytes and strkey addresses read the same as in the contract's own interface.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProofBundle {
+    pub version: u32,
+    pub network: NetworkInfo,
+    /// The protocol contract the attestation was read from
+    pub contract_id: String,
+    /// The `Attestation` record
+    pub attestation: Value,
+    /// UID of the schema the attestation was made under
+    pub schema_uid: String,
+    /// The `Schema` registered under `schema_uid`
+    pub schema: Value,
+    pub issuer: Issuer,
+    /// The `ValidityProof`, binding the attestation to its ledger entry
+    pub validity_proof: Value,
+    /// The ledger the bundle was read at
+    pub ledger: LedgerInfo,
+}
+
+/// The network the bundle was read from.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkInfo {
+    pub name: String,
+    pub passphrase: String,
+}
+
+/// What the chain records about the attester.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Issuer {
+    pub address: String,
+    /// The attester's verified `AttesterDomain`, if any
+    pub domain: Option<Value>,
+    /// The attester's `RegisteredAuthorityData` in the authority resolver, if any
+    pub authority: Option<Value>,
+}
+
+/// Close information for the ledger the bundle was read at.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LedgerInfo {
+    pub sequence: u32,
+    /// Hex hash of the ledger header
+    pub hash: String,
+    /// Unix time the ledger closed
+    pub close_time: u64,
+    pub protocol_version: u32,
+}
+
+/// How a bundle is serialized.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Json,
+    Cbor,
+}
+
+/// Why a bundle could not be read or is not self-consistent.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BundleError {
+    /// The bytes are not a bundle in the expected format
+    Decode(String),
+    /// The bundle was written by a newer version of this tool
+    UnsupportedVersion(u32),
+    /// A field is missing or has the wrong shape
+    Missing(&'static str),
+    /// Two parts of the bundle disagree
+    Inconsistent(&'static str),
+}
+
+impl ProofBundle {
+    pub fn encode(&self, format: Format) -> Vec<u8> {
+        match format {
+            Format::Json => serde_json::to_vec_pretty(self).expect("bundle serializes to JSON"),
+            Format::Cbor => {
+                let mut bytes = Vec::new();
+                ciborium::ser::into_writer(self, &mut bytes).expect("bundle serializes to CBOR");
+                bytes
+            },
+        }
+    }
+
+    /// Reads a bundle in either format; JSON bundles start with `{`.
+    pub fn decode(bytes: &[u8]) -> Result<ProofBundle, BundleError> {
+        let bundle: ProofBundle = if bytes.first() == Some(&b'{') {
+            serde_json::from_slice(bytes).map_err(|err| BundleError::Decode(err.to_string()))?
+        } else {
+            ciborium::de::from_reader(bytes).map_err(|err| BundleError::Decode(err.to_string()))?
+        };
+        if bundle.version > BUNDLE_VERSION {
+            return Err(BundleError::UnsupportedVersion(bundle.version));
+        }
+        Ok(bundle)
+    }
+
+    /// Checks that the parts of the bundle describe the same attestation.
+    ///
+    /// This proves the bundle was not stitched together or edited after it was read;
+    /// proving the ledger entry itself still requires checking `validity_proof` against
+    /// the header of `ledger`.
+    pub fn verify(&self) -> Result<(), BundleError> {
+        let proof = &self.validity_proof;
+        if proof["attestation"] != self.attestation {
+            return Err(BundleError::Inconsistent("validity proof is for another attestation"));
+        }
+        if proof["contract"] != self.contract_id.as_str() {
+            return Err(BundleError::Inconsistent("validity proof is for another contract"));
+        }
+        let value_xdr = hex_field(proof, "value_xdr")?;
+        if hex::encode(Sha256::digest(&value_xdr)) != str_field(proof, "value_hash")? {
+            return Err(BundleError::Inconsistent("value_hash does not match value_xdr"));
+        }
+        if self.attestation["schema_uid"] != self.schema_uid.as_str() {
+            return Err(BundleError::Inconsistent("schema is not the attestation's schema"));
+        }
+        if self.attestation["attester"] != self.issuer.address.as_str() {
+            return Err(BundleError::Inconsistent("issuer is not the attestation's attester"));
+        }
+        let proven_at = proof["ledger_sequence"]
+            .as_u64()
+            .ok_or(BundleError::Missing("ledger_sequence"))?;
+        if proven_at > self.ledger.sequence as u64 {
+            return Err(BundleError::Inconsistent("validity proof is newer than the ledger"));
+        }
+        Ok(())
+    }
+
+    /// The attestation's UID.
+    pub fn attestation_uid(&self) -> Result<&str, BundleError> {
+        str_field(&self.attestation, "uid")
+    }
+}
+
+fn str_field<'a>(value: &'a Value, field: &'static str) -> Result<&'a str, BundleError> {
+    value[field].as_str().ok_or(BundleError::Missing(field))
+}
+
+fn hex_field(value: &Value, field: &'static str) -> Result<Vec<u8>, BundleError> {
+    hex::decode(str_field(value, field)?).ok_or(BundleError::Inconsistent("field is not hex"))
+}
+
+mod hex {
+    pub fn encode(bytes: impl AsRef<[u8]>) -> String {
+        bytes.as_ref().iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    pub fn decode(text: &str) -> Option<Vec<u8>> {
+        if !text.len().is_multiple_of(2) {
+            return None;
+        }
+        (0..text.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+            .collect()
+    }
+}
@@ -0,0 +1,14 @@
+//! # Proof Bundle
+//!
+//! Packages an attestation with everything a third party needs to check it off-line:
+//! the attestation record, its schema, what the chain records about the issuer, the
+//! protocol's validity proof and the ledger it was read at. Bundles are written as
+//! JSON or CBOR and can be checked for internal consistency without network access.
+//!
+//! - [`bundle`]: the bundle layout, encoding and consistency checks
+//! - [`source`]: reading a bundle from the chain
+pub mod bundle;
+pub mod source;
+
+pub use bundle::{BundleError, Format, Issuer, LedgerInfo, NetworkInfo, ProofBundle, BUNDLE_VERSION};
+pub use source::{collect, parse_ledger, ChainReader, FetchError, StellarRpc, Target};
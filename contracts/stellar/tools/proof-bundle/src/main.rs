@@ -0,0 +1,101 @@
+use deployments::{deployment, Network};
+use proof_bundle::{collect, Format, ProofBundle, StellarRpc, Target};
+use std::process::exit;
+
+const USAGE: &str = "\
+usage:
+  attest-proof build --uid HEX --rpc-url URL [--network NAME] [--source IDENTITY]
+                     [--contract ID] [--authority ID] [--format json|cbor] [--out PATH]
+  attest-proof verify PATH
+
+`build` flags fall back to ATTEST_RPC_URL, ATTEST_NETWORK, ATTEST_SOURCE and
+ATTEST_CONTRACT_ID; contracts default to the official deployment on the network.";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("build") => build(&args[1..]),
+        Some("verify") if args.len() == 2 => verify(&args[1]),
+        _ => Err(USAGE.to_string()),
+    };
+    if let Err(err) = result {
+        eprintln!("{err}");
+        exit(2);
+    }
+}
+
+fn build(args: &[String]) -> Result<(), String> {
+    let mut flags = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let name = arg.strip_prefix("--").ok_or(USAGE)?;
+        let value = iter.next().ok_or(format!("--{name} needs a value"))?;
+        flags.push((name, value.clone()));
+    }
+    let flag = |name: &str, var: Option<&str>| {
+        flags
+            .iter()
+            .find(|(flag, _)| *flag == name)
+            .map(|(_, value)| value.clone())
+            .or_else(|| var.and_then(|var| std::env::var(var).ok()))
+    };
+
+    let uid = flag("uid", None).ok_or("--uid is required")?;
+    let rpc_url = flag("rpc-url", Some("ATTEST_RPC_URL")).ok_or("--rpc-url or ATTEST_RPC_URL is required")?;
+    let network: Network = flag("network", Some("ATTEST_NETWORK"))
+        .unwrap_or_else(|| "testnet".into())
+        .parse()
+        .map_err(|err| format!("{err}"))?;
+    let source = flag("source", Some("ATTEST_SOURCE")).ok_or("--source or ATTEST_SOURCE is required")?;
+    let entry = deployment(network);
+    // The official authority resolver only accompanies the official protocol contract
+    let (contract_id, official_authority) = match flag("contract", Some("ATTEST_CONTRACT_ID")) {
+        Some(contract_id) => (contract_id, None),
+        None => (
+            entry.protocol_id().map_err(|err| err.to_string())?.to_string(),
+            entry.authority_id().ok().map(String::from),
+        ),
+    };
+    let authority_id = flag("authority", None).or(official_authority);
+    let format = match flag("format", None).as_deref() {
+        None | Some("json") => Format::Json,
+        Some("cbor") => Format::Cbor,
+        Some(other) => return Err(format!("unknown format `{other}`")),
+    };
+
+    let reader = StellarRpc::new(&rpc_url, network, &source);
+    let target = Target {
+        network,
+        contract_id,
+        authority_id,
+    };
+    let bundle = collect(&reader, &target, &uid).map_err(|err| format!("failed to read attestation: {err:?}"))?;
+    bundle
+        .verify()
+        .map_err(|err| format!("chain returned an inconsistent bundle: {err:?}"))?;
+
+    let bytes = bundle.encode(format);
+    match flag("out", None) {
+        Some(path) => std::fs::write(&path, bytes).map_err(|err| format!("failed to write {path}: {err}")),
+        None if format == Format::Json => {
+            println!("{}", String::from_utf8_lossy(&bytes));
+            Ok(())
+        },
+        None => Err("--out is required for CBOR bundles".to_string()),
+    }
+}
+
+fn verify(path: &str) -> Result<(), String> {
+    let bytes = std::fs::read(path).map_err(|err| format!("failed to read {path}: {err}"))?;
+    let bundle = ProofBundle::decode(&bytes).map_err(|err| format!("{path} is not a proof bundle: {err:?}"))?;
+    bundle
+        .verify()
+        .map_err(|err| format!("bundle is inconsistent: {err:?}"))?;
+    println!(
+        "attestation {} on {} is consistent as of ledger {}",
+        bundle.attestation_uid().unwrap_or("?"),
+        bundle.network.name,
+        bundle.ledger.sequence
+    );
+    Ok(())
+}
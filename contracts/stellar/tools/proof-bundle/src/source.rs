@@ -0,0 +1,179 @@
+use crate::bundle::{Issuer, LedgerInfo, NetworkInfo, ProofBundle, BUNDLE_VERSION};
+use deployments::Network;
+use serde_json::{json, Value};
+use std::process::Command;
+use std::time::Duration;
+
+/// Why the chain could not be read.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FetchError {
+    /// The `stellar` CLI could not be run, or the RPC server could not be reached
+    Transport(String),
+    /// A contract call failed, e.g. because the attestation does not exist
+    Call { function: &'static str, message: String },
+    /// The RPC server answered with an error or an unexpected shape
+    Response(String),
+}
+
+/// Read access to the chain, as of its latest ledger.
+pub trait ChainReader {
+    /// Calls a read-only contract function, returning its value as `stellar` CLI JSON.
+    fn call(&self, contract: &str, function: &'static str, args: &[(&str, &str)]) -> Result<Value, FetchError>;
+
+    /// Close information for the latest ledger.
+    fn latest_ledger(&self) -> Result<LedgerInfo, FetchError>;
+}
+
+/// Where to read a bundle from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Target {
+    pub network: Network,
+    /// The protocol contract
+    pub contract_id: String,
+    /// The authority resolver, if issuer authority data should be included
+    pub authority_id: Option<String>,
+}
+
+/// Reads an attestation and everything needed to check it into a [`ProofBundle`].
+///
+/// The ledger is read last, so the validity proof is never newer than the ledger
+/// recorded next to it.
+pub fn collect<R: ChainReader>(reader: &R, target: &Target, uid: &str) -> Result<ProofBundle, FetchError> {
+    let contract = target.contract_id.as_str();
+    let validity_proof = reader.call(contract, "get_validity_proof", &[("uid", uid)])?;
+    let attestation = validity_proof["attestation"].clone();
+    let field = |name: &str| {
+        attestation[name]
+            .as_str()
+            .map(String::from)
+            .ok_or_else(|| FetchError::Response(format!("attestation has no `{name}`")))
+    };
+    let schema_uid = field("schema_uid")?;
+    let attester = field("attester")?;
+
+    let schema = reader.call(contract, "get_schema", &[("schema_uid", &schema_uid)])?;
+    let domain = reader.call(contract, "get_attester_domain", &[("attester", &attester)])?;
+    let authority = match &target.authority_id {
+        Some(authority_id) => reader.call(authority_id, "get_authority_data", &[("authority", &attester)])?,
+        None => Value::Null,
+    };
+
+    Ok(ProofBundle {
+        version: BUNDLE_VERSION,
+        network: NetworkInfo {
+            name: target.network.name().to_string(),
+            passphrase: target.network.passphrase().to_string(),
+        },
+        contract_id: target.contract_id.clone(),
+        attestation,
+        schema_uid,
+        schema,
+        issuer: Issuer {
+            address: attester,
+            domain: Some(domain).filter(|value| !value.is_null()),
+            authority: Some(authority).filter(|value| !value.is_null()),
+        },
+        validity_proof,
+        ledger: reader.latest_ledger()?,
+    })
+}
+
+/// [`ChainReader`] that simulates calls through the `stellar` CLI and reads ledgers
+/// from the RPC server directly.
+pub struct StellarRpc {
+    pub rpc_url: String,
+    pub network: Network,
+    /// `stellar` CLI identity used as the simulation source
+    pub source: String,
+    agent: ureq::Agent,
+}
+
+impl StellarRpc {
+    pub fn new(rpc_url: &str, network: Network, source: &str) -> Self {
+        StellarRpc {
+            rpc_url: rpc_url.to_string(),
+            network,
+            source: source.to_string(),
+            agent: ureq::AgentBuilder::new().timeout(Duration::from_secs(30)).build(),
+        }
+    }
+
+    fn rpc(&self, method: &str, params: Value) -> Result<Value, FetchError> {
+        let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let response: Value = self
+            .agent
+            .post(&self.rpc_url)
+            .send_json(body)
+            .map_err(|err| FetchError::Transport(err.to_string()))?
+            .into_json()
+            .map_err(|err| FetchError::Transport(err.to_string()))?;
+        if let Some(error) = response.get("error") {
+            return Err(FetchError::Response(format!("{method}: {error}")));
+        }
+        Ok(response["result"].clone())
+    }
+}
+
+impl ChainReader for StellarRpc {
+    fn call(&self, contract: &str, function: &'static str, args: &[(&str, &str)]) -> Result<Value, FetchError> {
+        let mut command = Command::new("stellar");
+        command.args(["contract", "invoke", "--id", contract, "--source", &self.source]);
+        command.args([
+            "--rpc-url",
+            &self.rpc_url,
+            "--network-passphrase",
+            self.network.passphrase(),
+        ]);
+        command.args(["--send=no", "--", function]);
+        for (name, value) in args {
+            command.arg(format!("--{name}")).arg(value);
+        }
+
+        let output = command
+            .output()
+            .map_err(|err| FetchError::Transport(format!("failed to run stellar CLI: {err}")))?;
+        if !output.status.success() {
+            return Err(FetchError::Call {
+                function,
+                message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        serde_json::from_str(stdout.trim())
+            .map_err(|err| FetchError::Response(format!("{function} returned unreadable output: {err}")))
+    }
+
+    fn latest_ledger(&self) -> Result<LedgerInfo, FetchError> {
+        let latest = self.rpc("getLatestLedger", json!({}))?;
+        let sequence = latest["sequence"]
+            .as_u64()
+            .ok_or_else(|| FetchError::Response("getLatestLedger has no sequence".into()))?;
+        let page = self.rpc(
+            "getLedgers",
+            json!({ "startLedger": sequence, "pagination": { "limit": 1 } }),
+        )?;
+        parse_ledger(
+            &page["ledgers"][0],
+            latest["protocolVersion"].as_u64().unwrap_or_default(),
+        )
+    }
+}
+
+/// Reads one entry of a `getLedgers` result.
+pub fn parse_ledger(ledger: &Value, protocol_version: u64) -> Result<LedgerInfo, FetchError> {
+    let unexpected = || FetchError::Response(format!("unexpected getLedgers entry: {ledger}"));
+    // `ledgerCloseTime` is a decimal string
+    let close_time = ledger["ledgerCloseTime"]
+        .as_str()
+        .and_then(|time| time.parse().ok())
+        .ok_or_else(unexpected)?;
+    Ok(LedgerInfo {
+        sequence: ledger["sequence"]
+            .as_u64()
+            .and_then(|sequence| sequence.try_into().ok())
+            .ok_or_else(unexpected)?,
+        hash: ledger["hash"].as_str().ok_or_else(unexpected)?.to_string(),
+        close_time,
+        protocol_version: protocol_version.try_into().map_err(|_| unexpected())?,
+    })
+}
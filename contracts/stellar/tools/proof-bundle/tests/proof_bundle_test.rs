@@ -0,0 +1,140 @@
+use deployments::Network;
+use proof_bundle::{
+    collect, parse_ledger, BundleError, ChainReader, FetchError, Format, LedgerInfo, ProofBundle, Target,
+};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+
+const UID: &str = "2222222222222222222222222222222222222222222222222222222222222222";
+const SCHEMA_UID: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+const ATTESTER: &str = "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7";
+const PROTOCOL: &str = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
+const AUTHORITY: &str = "CCMJGCRSQRZ56BDSLCAYV4BNS3SLIDPIP4CQYNT5X2VOPZIQ2ZM7GBVV";
+
+/// Answers contract calls from fixed values and records which were made.
+struct MockChain {
+    calls: RefCell<Vec<(String, &'static str)>>,
+}
+
+impl ChainReader for MockChain {
+    fn call(&self, contract: &str, function: &'static str, _args: &[(&str, &str)]) -> Result<Value, FetchError> {
+        self.calls.borrow_mut().push((contract.to_string(), function));
+        let attestation = json!({
+            "uid": UID,
+            "schema_uid": SCHEMA_UID,
+            "subject": "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H",
+            "attester": ATTESTER,
+            "value": "{\"kyc\":true}",
+            "revoked": false,
+        });
+        match function {
+            "get_validity_proof" => Ok(json!({
+                "attestation": attestation,
+                "contract": PROTOCOL,
+                "key_xdr": "00000010",
+                "value_xdr": "0000001100000001",
+                "value_hash": "12bfe97246482b8d4d6622ba0dc692e67c882af0accbdf32b526dae456e26a4d",
+                "ledger_sequence": 500,
+            })),
+            "get_schema" => Ok(json!({ "authority": ATTESTER, "definition": "kyc:bool", "revocable": true })),
+            "get_attester_domain" => Ok(Value::Null),
+            "get_authority_data" => Ok(json!({ "address": ATTESTER, "metadata": "Acme KYC" })),
+            _ => Err(FetchError::Call {
+                function,
+                message: "unexpected call".into(),
+            }),
+        }
+    }
+
+    fn latest_ledger(&self) -> Result<LedgerInfo, FetchError> {
+        Ok(LedgerInfo {
+            sequence: 501,
+            hash: "ab".repeat(32),
+            close_time: 1_700_000_000,
+            protocol_version: 22,
+        })
+    }
+}
+
+fn bundle() -> ProofBundle {
+    let chain = MockChain {
+        calls: RefCell::new(Vec::new()),
+    };
+    let target = Target {
+        network: Network::Testnet,
+        contract_id: PROTOCOL.into(),
+        authority_id: Some(AUTHORITY.into()),
+    };
+    let bundle = collect(&chain, &target, UID).unwrap();
+    assert!(chain
+        .calls
+        .borrow()
+        .contains(&(AUTHORITY.to_string(), "get_authority_data")));
+    bundle
+}
+
+/// **Test: A Collected Bundle Is Self-Contained and Survives Both Encodings**
+#[test]
+fn collected_bundle_round_trips_and_verifies() {
+    let bundle = bundle();
+    assert_eq!(bundle.schema_uid, SCHEMA_UID);
+    assert_eq!(bundle.issuer.domain, None);
+    assert_eq!(bundle.issuer.authority.as_ref().unwrap()["metadata"], "Acme KYC");
+    assert_eq!(bundle.network.passphrase, "Test SDF Network ; September 2015");
+    assert_eq!(bundle.verify(), Ok(()));
+
+    for format in [Format::Json, Format::Cbor] {
+        let decoded = ProofBundle::decode(&bundle.encode(format)).unwrap();
+        assert_eq!(decoded, bundle);
+    }
+    assert!(matches!(
+        ProofBundle::decode(b"not a bundle"),
+        Err(BundleError::Decode(_))
+    ));
+}
+
+/// **Test: Edited Bundles Fail Verification**
+#[test]
+fn tampered_bundles_are_rejected() {
+    let mut edited = bundle();
+    edited.attestation["value"] = json!("{\"kyc\":false}");
+    assert!(matches!(edited.verify(), Err(BundleError::Inconsistent(_))));
+
+    let mut edited = bundle();
+    edited.validity_proof["value_xdr"] = json!("0000001100000000");
+    assert_eq!(
+        edited.verify(),
+        Err(BundleError::Inconsistent("value_hash does not match value_xdr"))
+    );
+
+    let mut edited = bundle();
+    edited.schema_uid = UID.into();
+    assert!(edited.verify().is_err());
+
+    let mut edited = bundle();
+    edited.ledger.sequence = 499;
+    assert!(edited.verify().is_err());
+
+    let mut newer = bundle();
+    newer.version += 1;
+    assert_eq!(
+        ProofBundle::decode(&newer.encode(Format::Json)),
+        Err(BundleError::UnsupportedVersion(newer.version))
+    );
+}
+
+/// **Test: Ledger Close Info Is Read from `getLedgers`**
+#[test]
+fn parses_ledger_close_info() {
+    let ledger = json!({ "sequence": 501, "hash": "ab", "ledgerCloseTime": "1700000000" });
+    assert_eq!(
+        parse_ledger(&ledger, 22),
+        Ok(LedgerInfo {
+            sequence: 501,
+            hash: "ab".into(),
+            close_time: 1_700_000_000,
+            protocol_version: 22,
+        })
+    );
+    assert!(parse_ledger(&Value::Null, 22).is_err());
+}
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Relayer settings, read from `RELAYER_*` environment variables.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Address the HTTP endpoint listens on (`RELAYER_LISTEN`)
+    pub listen: String,
+    /// Directory holding the request queue (`RELAYER_DATA_DIR`)
+    pub data_dir: PathBuf,
+    /// Protocol contract ID (`RELAYER_CONTRACT_ID`, required)
+    pub contract_id: String,
+    /// `stellar` CLI identity that signs and pays for submissions (`RELAYER_SOURCE`, required)
+    pub source: String,
+    /// Address of `source`, passed as the batch submitter (`RELAYER_SUBMITTER`, required)
+    pub submitter: String,
+    /// `stellar` CLI network name (`RELAYER_NETWORK`)
+    pub network: String,
+    /// Most requests submitted in one transaction (`RELAYER_BATCH_SIZE`)
+    pub batch_size: usize,
+    /// Delay between queue polls when idle (`RELAYER_POLL_MS`)
+    pub poll_interval: Duration,
+    /// Attempts before a request is given up on (`RELAYER_MAX_ATTEMPTS`)
+    pub max_attempts: u32,
+}
+
+impl Config {
+    /// Reads the configuration, naming the first missing or malformed variable on error.
+    pub fn from_env() -> Result<Self, String> {
+        Ok(Config {
+            listen: var_or("RELAYER_LISTEN", "127.0.0.1:8787"),
+            data_dir: PathBuf::from(var_or("RELAYER_DATA_DIR", "relayer-data")),
+            contract_id: required("RELAYER_CONTRACT_ID")?,
+            source: required("RELAYER_SOURCE")?,
+            submitter: required("RELAYER_SUBMITTER")?,
+            network: var_or("RELAYER_NETWORK", "testnet"),
+            batch_size: parsed("RELAYER_BATCH_SIZE", 20)?,
+            poll_interval: Duration::from_millis(parsed("RELAYER_POLL_MS", 2_000)?),
+            max_attempts: parsed("RELAYER_MAX_ATTEMPTS", 5)?,
+        })
+    }
+}
+
+fn var_or(name: &str, default: &str) -> String {
+    std::env::var(name).unwrap_or_else(|_| default.to_string())
+}
+
+fn required(name: &str) -> Result<String, String> {
+    std::env::var(name).map_err(|_| format!("{name} must be set"))
+}
+
+fn parsed<T: std::str::FromStr>(name: &str, default: T) -> Result<T, String> {
+    match std::env::var(name) {
+        Ok(value) => value.parse().map_err(|_| format!("{name} is not a valid number")),
+        Err(_) => Ok(default),
+    }
+}
@@ -0,0 +1,22 @@
+//! # Attest Relayer
+//!
+//! Reference relayer for delegated attestations. Attesters sign
+//! `DelegatedAttestationRequest`s off-chain and hand them to the relayer over HTTP;
+//! the relayer queues them on disk, simulates them against the protocol contract,
+//! and submits the ones that would succeed through `submit_delegated_batch`, paying
+//! the transaction fees itself.
+//!
+//! - [`server`]: the HTTP intake endpoint
+//! - [`queue`]: the on-disk request queue
+//! - [`submitter`]: simulation and submission against the contract
+//! - [`relayer`]: the batching loop with retry and backoff
+pub mod config;
+pub mod queue;
+pub mod relayer;
+pub mod server;
+pub mod submitter;
+
+pub use config::Config;
+pub use queue::{Queue, QueuedRequest, RequestStatus};
+pub use relayer::{Backoff, Relayer, Round};
+pub use submitter::{Outcome, StellarCli, SubmitError, Submitter};
@@ -0,0 +1,52 @@
+use relayer::{server, Backoff, Config, Queue, Relayer, StellarCli};
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    let config = Config::from_env().unwrap_or_else(|err| {
+        eprintln!("relayer: {err}");
+        std::process::exit(2);
+    });
+
+    let queue = Arc::new(Queue::open(&config.data_dir).expect("failed to open queue"));
+    let listener = TcpListener::bind(&config.listen).expect("failed to bind listener");
+    println!("relayer: listening on {}", config.listen);
+    let intake = Arc::clone(&queue);
+    thread::spawn(move || server::serve(listener, intake).expect("listener failed"));
+
+    let relayer = Relayer {
+        queue: Queue::open(&config.data_dir).expect("failed to open queue"),
+        submitter: StellarCli {
+            contract_id: config.contract_id.clone(),
+            source: config.source.clone(),
+            submitter: config.submitter.clone(),
+            network: config.network.clone(),
+        },
+        batch_size: config.batch_size,
+        max_attempts: config.max_attempts,
+    };
+    let backoff = Backoff {
+        base: config.poll_interval,
+        max: Duration::from_secs(300),
+    };
+
+    let mut failures = 0;
+    loop {
+        let round = relayer.process_once().expect("queue storage failed");
+        if round.submitted + round.failed > 0 {
+            println!(
+                "relayer: submitted {}, failed {}, deferred {}",
+                round.submitted, round.failed, round.deferred
+            );
+        }
+        failures = if round.retry { failures + 1 } else { 0 };
+        let delay = if failures > 0 {
+            backoff.delay(failures)
+        } else {
+            config.poll_interval
+        };
+        thread::sleep(delay);
+    }
+}
@@ -0,0 +1,114 @@
+use attest_types::json::DelegatedAttestationRequestJson;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Where a queued request stands.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum RequestStatus {
+    /// Waiting to be simulated and submitted
+    Pending,
+    /// Stored on-chain under `uid` (hex)
+    Submitted { uid: String },
+    /// Given up on, with the last error seen
+    Failed { error: String },
+}
+
+/// A request and its processing state, as stored on disk.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QueuedRequest {
+    pub id: u64,
+    pub request: DelegatedAttestationRequestJson,
+    pub status: RequestStatus,
+    /// Simulation or submission attempts that ended without a final outcome
+    pub attempts: u32,
+}
+
+/// Durable FIFO of delegated requests.
+///
+/// Each request is one JSON file named by its zero-padded ID, rewritten atomically
+/// (write then rename) on every status change, so a crash never loses or corrupts an
+/// accepted request and a restarted relayer picks up where it stopped.
+pub struct Queue {
+    dir: PathBuf,
+    next_id: Mutex<u64>,
+}
+
+impl Queue {
+    /// Opens the queue in `dir`, creating the directory if needed.
+    pub fn open(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+        let next_id = Self::ids(&dir)?.last().map_or(0, |id| id + 1);
+        Ok(Queue {
+            dir,
+            next_id: Mutex::new(next_id),
+        })
+    }
+
+    /// Appends a request and returns its ID.
+    pub fn push(&self, request: DelegatedAttestationRequestJson) -> io::Result<u64> {
+        let mut next_id = self.next_id.lock().unwrap();
+        let entry = QueuedRequest {
+            id: *next_id,
+            request,
+            status: RequestStatus::Pending,
+            attempts: 0,
+        };
+        self.update(&entry)?;
+        *next_id += 1;
+        Ok(entry.id)
+    }
+
+    /// Gets a request by ID.
+    pub fn get(&self, id: u64) -> io::Result<Option<QueuedRequest>> {
+        match fs::read(self.path(id)) {
+            Ok(raw) => serde_json::from_slice(&raw).map(Some).map_err(io::Error::other),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Gets up to `limit` pending requests, oldest first.
+    pub fn pending(&self, limit: usize) -> io::Result<Vec<QueuedRequest>> {
+        let mut pending = Vec::new();
+        for id in Self::ids(&self.dir)? {
+            if pending.len() == limit {
+                break;
+            }
+            if let Some(entry) = self.get(id)? {
+                if entry.status == RequestStatus::Pending {
+                    pending.push(entry);
+                }
+            }
+        }
+        Ok(pending)
+    }
+
+    /// Stores a request's current state.
+    pub fn update(&self, entry: &QueuedRequest) -> io::Result<()> {
+        let tmp = self.dir.join(format!("{:020}.tmp", entry.id));
+        fs::write(&tmp, serde_json::to_vec(entry).map_err(io::Error::other)?)?;
+        fs::rename(tmp, self.path(entry.id))
+    }
+
+    fn path(&self, id: u64) -> PathBuf {
+        self.dir.join(format!("{id:020}.json"))
+    }
+
+    fn ids(dir: &Path) -> io::Result<Vec<u64>> {
+        let mut ids = Vec::new();
+        for file in fs::read_dir(dir)? {
+            let name = file?.file_name();
+            let name = name.to_string_lossy();
+            if let Some(id) = name.strip_suffix(".json").and_then(|id| id.parse().ok()) {
+                ids.push(id);
+            }
+        }
+        ids.sort_unstable();
+        Ok(ids)
+    }
+}
@@ -0,0 +1,143 @@
+use crate::queue::{Queue, QueuedRequest, RequestStatus};
+use crate::submitter::{Outcome, SubmitError, Submitter};
+use std::io;
+use std::time::Duration;
+
+/// Exponential backoff between retries of failed calls.
+#[derive(Clone, Copy, Debug)]
+pub struct Backoff {
+    pub base: Duration,
+    pub max: Duration,
+}
+
+impl Backoff {
+    /// Delay before retry number `attempt` (starting at 1), doubling up to `max`.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 1u32.checked_shl(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+        self.base.saturating_mul(factor).min(self.max)
+    }
+}
+
+/// What one processing round did.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Round {
+    /// Requests stored on-chain
+    pub submitted: usize,
+    /// Requests given up on
+    pub failed: usize,
+    /// Requests left pending for a later round
+    pub deferred: usize,
+    /// Whether the round hit a transient error and the caller should back off
+    pub retry: bool,
+}
+
+/// Moves requests from the queue onto the chain in batches.
+pub struct Relayer<S> {
+    pub queue: Queue,
+    pub submitter: S,
+    pub batch_size: usize,
+    pub max_attempts: u32,
+}
+
+impl<S: Submitter> Relayer<S> {
+    /// Simulates the oldest pending requests, then submits those that would succeed.
+    ///
+    /// Requests the simulation rejects are failed, except nonce mismatches, which are
+    /// deferred since the attester's earlier requests may still be queued. If the whole
+    /// simulation aborts, each request is simulated alone so one malformed request
+    /// can't hold back the rest.
+    pub fn process_once(&self) -> io::Result<Round> {
+        let mut round = Round::default();
+        let pending = self.queue.pending(self.batch_size)?;
+        if pending.is_empty() {
+            return Ok(round);
+        }
+
+        let outcomes = match self.simulate(&pending) {
+            Ok(outcomes) => outcomes,
+            Err(error) => {
+                self.defer_all(pending, &error, &mut round)?;
+                round.retry = true;
+                return Ok(round);
+            },
+        };
+
+        let mut accepted = Vec::new();
+        for (entry, outcome) in pending.into_iter().zip(outcomes) {
+            match outcome {
+                Outcome::Attested(_) => accepted.push(entry),
+                rejected if rejected.is_nonce_error() => self.defer(entry, &format!("{rejected:?}"), &mut round)?,
+                Outcome::Rejected(error) => self.finish(entry, RequestStatus::Failed { error }, &mut round)?,
+            }
+        }
+        if accepted.is_empty() {
+            return Ok(round);
+        }
+
+        let requests: Vec<_> = accepted.iter().map(|entry| entry.request.clone()).collect();
+        match self.submitter.submit_batch(&requests) {
+            Ok(outcomes) => {
+                for (entry, outcome) in accepted.into_iter().zip(outcomes) {
+                    let status = match outcome {
+                        Outcome::Attested(uid) => RequestStatus::Submitted { uid },
+                        Outcome::Rejected(error) => RequestStatus::Failed { error },
+                    };
+                    self.finish(entry, status, &mut round)?;
+                }
+            },
+            Err(error) => {
+                self.defer_all(accepted, &format!("{error:?}"), &mut round)?;
+                round.retry = true;
+            },
+        }
+        Ok(round)
+    }
+
+    /// Simulates the batch, falling back to one request at a time if the batch aborts.
+    fn simulate(&self, pending: &[QueuedRequest]) -> Result<Vec<Outcome>, String> {
+        let requests: Vec<_> = pending.iter().map(|entry| entry.request.clone()).collect();
+        match self.submitter.simulate_batch(&requests) {
+            Ok(outcomes) => Ok(outcomes),
+            Err(SubmitError::Rejected(_)) if requests.len() > 1 => requests
+                .iter()
+                .map(
+                    |request| match self.submitter.simulate_batch(std::slice::from_ref(request)) {
+                        Ok(mut outcomes) if outcomes.len() == 1 => Ok(outcomes.remove(0)),
+                        Ok(_) => Err("unexpected simulation output".to_string()),
+                        Err(SubmitError::Rejected(error)) => Ok(Outcome::Rejected(error)),
+                        Err(SubmitError::Transient(error)) => Err(error),
+                    },
+                )
+                .collect(),
+            Err(SubmitError::Rejected(error)) => Ok(vec![Outcome::Rejected(error)]),
+            Err(SubmitError::Transient(error)) => Err(error),
+        }
+    }
+
+    fn defer_all(&self, entries: Vec<QueuedRequest>, error: &str, round: &mut Round) -> io::Result<()> {
+        for entry in entries {
+            self.defer(entry, error, round)?;
+        }
+        Ok(())
+    }
+
+    /// Leaves a request pending, failing it once it has used up its attempts.
+    fn defer(&self, mut entry: QueuedRequest, error: &str, round: &mut Round) -> io::Result<()> {
+        entry.attempts += 1;
+        if entry.attempts >= self.max_attempts {
+            let error = error.to_string();
+            return self.finish(entry, RequestStatus::Failed { error }, round);
+        }
+        round.deferred += 1;
+        self.queue.update(&entry)
+    }
+
+    fn finish(&self, mut entry: QueuedRequest, status: RequestStatus, round: &mut Round) -> io::Result<()> {
+        match status {
+            RequestStatus::Submitted { .. } => round.submitted += 1,
+            _ => round.failed += 1,
+        }
+        entry.status = status;
+        self.queue.update(&entry)
+    }
+}
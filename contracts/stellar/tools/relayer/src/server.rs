@@ -0,0 +1,112 @@
+use crate::queue::Queue;
+use attest_types::json::{DelegatedAttestationRequestJson, JsonCodec};
+use attest_types::DelegatedAttestationRequest;
+use soroban_sdk::Env;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread;
+
+/// Largest request body accepted, well above any single signed request.
+pub const MAX_BODY_BYTES: usize = 64 * 1024;
+
+/// Serves the intake endpoint until the listener fails.
+///
+/// - `POST /requests` queues a signed request, answering `202 {"id": N}`
+/// - `GET /requests/{id}` returns a queued request with its status
+/// - `GET /health` answers `200 ok`
+pub fn serve(listener: TcpListener, queue: Arc<Queue>) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || {
+            if let Err(err) = handle_connection(stream, &queue) {
+                eprintln!("relayer: connection error: {err}");
+            }
+        });
+    }
+    Ok(())
+}
+
+fn handle_connection(mut stream: TcpStream, queue: &Queue) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(usize::MAX);
+            }
+        }
+    }
+
+    let (status, body) = if content_length > MAX_BODY_BYTES {
+        (413, error_body("request body too large"))
+    } else {
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body)?;
+        route(queue, method, path, &body)
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        reason(status),
+        body.len()
+    )
+}
+
+/// Handles one request, returning the status code and JSON body.
+pub fn route(queue: &Queue, method: &str, path: &str, body: &[u8]) -> (u16, String) {
+    match (method, path) {
+        ("GET", "/health") => (200, "\"ok\"".to_string()),
+        ("POST", "/requests") => {
+            let request: DelegatedAttestationRequestJson = match serde_json::from_slice(body) {
+                Ok(request) => request,
+                Err(err) => return (400, error_body(&err.to_string())),
+            };
+            // Decode into the contract type to reject bad strkeys and hex before queueing
+            if let Err(err) = DelegatedAttestationRequest::from_json_value(&Env::default(), request.clone()) {
+                return (400, error_body(&format!("{err:?}")));
+            }
+            match queue.push(request) {
+                Ok(id) => (202, serde_json::json!({ "id": id }).to_string()),
+                Err(err) => (500, error_body(&err.to_string())),
+            }
+        },
+        ("GET", path) if path.starts_with("/requests/") => {
+            let Ok(id) = path["/requests/".len()..].parse() else {
+                return (404, error_body("not found"));
+            };
+            match queue.get(id) {
+                Ok(Some(entry)) => (200, serde_json::to_string(&entry).unwrap_or_default()),
+                Ok(None) => (404, error_body("not found")),
+                Err(err) => (500, error_body(&err.to_string())),
+            }
+        },
+        _ => (404, error_body("not found")),
+    }
+}
+
+fn error_body(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        404 => "Not Found",
+        413 => "Payload Too Large",
+        _ => "Internal Server Error",
+    }
+}
@@ -0,0 +1,98 @@
+use attest_types::json::DelegatedAttestationRequestJson;
+use std::process::Command;
+
+/// Result of one request within a simulated or submitted batch.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    /// The attestation was (or would be) stored under this UID (hex)
+    Attested(String),
+    /// The contract rejected the request
+    Rejected(String),
+}
+
+impl Outcome {
+    /// Whether the rejection is a nonce mismatch, which may clear once earlier
+    /// requests from the same attester land.
+    pub fn is_nonce_error(&self) -> bool {
+        matches!(self, Outcome::Rejected(error) if error.contains("InvalidNonce") || error.contains("#119"))
+    }
+}
+
+/// Why a whole simulation or submission call failed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SubmitError {
+    /// Network or RPC trouble; the same call may succeed later
+    Transient(String),
+    /// The contract aborted the whole call, e.g. while paused or on a malformed signature point
+    Rejected(String),
+}
+
+/// Access to the protocol contract's `submit_delegated_batch`.
+pub trait Submitter {
+    /// Simulates the batch without submitting it, returning per-request outcomes.
+    fn simulate_batch(&self, requests: &[DelegatedAttestationRequestJson]) -> Result<Vec<Outcome>, SubmitError>;
+
+    /// Submits the batch, returning per-request outcomes.
+    fn submit_batch(&self, requests: &[DelegatedAttestationRequestJson]) -> Result<Vec<Outcome>, SubmitError>;
+}
+
+/// [`Submitter`] backed by the `stellar` CLI, which handles RPC, signing and fees.
+pub struct StellarCli {
+    pub contract_id: String,
+    pub source: String,
+    pub submitter: String,
+    pub network: String,
+}
+
+impl StellarCli {
+    fn invoke(&self, requests: &[DelegatedAttestationRequestJson], send: bool) -> Result<Vec<Outcome>, SubmitError> {
+        let requests = serde_json::to_string(requests).map_err(|err| SubmitError::Rejected(err.to_string()))?;
+        let output = Command::new("stellar")
+            .args(["contract", "invoke", "--id", &self.contract_id])
+            .args(["--source", &self.source, "--network", &self.network])
+            .arg(if send { "--send=yes" } else { "--send=no" })
+            .args(["--", "submit_delegated_batch", "--submitter", &self.submitter])
+            .args(["--requests", &requests])
+            .output()
+            .map_err(|err| SubmitError::Transient(format!("failed to run stellar CLI: {err}")))?;
+
+        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+        if !output.status.success() {
+            // Contract and host errors are reported as `Error(Contract, #N)` / `HostError`
+            return Err(if stderr.contains("Error(") || stderr.contains("HostError") {
+                SubmitError::Rejected(stderr)
+            } else {
+                SubmitError::Transient(stderr)
+            });
+        }
+
+        parse_outcomes(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+impl Submitter for StellarCli {
+    fn simulate_batch(&self, requests: &[DelegatedAttestationRequestJson]) -> Result<Vec<Outcome>, SubmitError> {
+        self.invoke(requests, false)
+    }
+
+    fn submit_batch(&self, requests: &[DelegatedAttestationRequestJson]) -> Result<Vec<Outcome>, SubmitError> {
+        self.invoke(requests, true)
+    }
+}
+
+/// Parses the CLI's JSON rendering of `Vec<Result<BytesN<32>, Error>>`.
+///
+/// UIDs are rendered as hex strings; anything else is an error value and is kept verbatim.
+pub fn parse_outcomes(stdout: &str) -> Result<Vec<Outcome>, SubmitError> {
+    let items: Vec<serde_json::Value> = serde_json::from_str(stdout.trim())
+        .map_err(|err| SubmitError::Transient(format!("unexpected CLI output: {err}")))?;
+    Ok(items
+        .into_iter()
+        .map(|item| match item.as_str() {
+            Some(uid) if uid.len() == 64 && uid.bytes().all(|b| b.is_ascii_hexdigit()) => {
+                Outcome::Attested(uid.to_string())
+            },
+            _ => Outcome::Rejected(item.to_string()),
+        })
+        .collect())
+}
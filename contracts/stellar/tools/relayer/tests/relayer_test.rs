@@ -0,0 +1,163 @@
+use attest_types::json::{DelegatedAttestationRequestJson, JsonCodec};
+use attest_types::DelegatedAttestationRequest;
+use relayer::server::route;
+use relayer::{Backoff, Outcome, Queue, Relayer, RequestStatus, SubmitError, Submitter};
+use soroban_sdk::{
+    testutils::{Address as _, BytesN as _},
+    Address, BytesN, Env, String,
+};
+use std::cell::{Cell, RefCell};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Decides each request by its `value`: `ok`, `bad` (rejected), `later` (nonce
+/// mismatch) or `poison` (aborts any batch it is in).
+#[derive(Default)]
+struct MockSubmitter {
+    offline: Cell<bool>,
+    submitted: RefCell<Vec<std::string::String>>,
+}
+
+impl MockSubmitter {
+    fn run(&self, requests: &[DelegatedAttestationRequestJson]) -> Result<Vec<Outcome>, SubmitError> {
+        if self.offline.get() {
+            return Err(SubmitError::Transient("rpc unavailable".into()));
+        }
+        if requests.iter().any(|r| r.value == "poison") {
+            return Err(SubmitError::Rejected("HostError: invalid point".into()));
+        }
+        Ok(requests
+            .iter()
+            .map(|r| match r.value.as_str() {
+                "bad" => Outcome::Rejected("Error(Contract, #121)".into()),
+                "later" => Outcome::Rejected("Error(Contract, #119)".into()),
+                _ => Outcome::Attested(r.schema_uid.clone()),
+            })
+            .collect())
+    }
+}
+
+impl Submitter for MockSubmitter {
+    fn simulate_batch(&self, requests: &[DelegatedAttestationRequestJson]) -> Result<Vec<Outcome>, SubmitError> {
+        self.run(requests)
+    }
+
+    fn submit_batch(&self, requests: &[DelegatedAttestationRequestJson]) -> Result<Vec<Outcome>, SubmitError> {
+        let outcomes = self.run(requests)?;
+        self.submitted
+            .borrow_mut()
+            .extend(requests.iter().map(|r| r.value.clone()));
+        Ok(outcomes)
+    }
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("attest-relayer-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+fn request(env: &Env, value: &str) -> DelegatedAttestationRequestJson {
+    DelegatedAttestationRequest {
+        schema_uid: BytesN::random(env),
+        subject: Address::generate(env),
+        attester: Address::generate(env),
+        value: String::from_str(env, value),
+        nonce: 0,
+        deadline: 1_000,
+        expiration_time: None,
+        version: 1,
+        signature: BytesN::random(env),
+    }
+    .to_json_value()
+}
+
+fn relayer(dir: &PathBuf, max_attempts: u32) -> Relayer<MockSubmitter> {
+    Relayer {
+        queue: Queue::open(dir).unwrap(),
+        submitter: MockSubmitter::default(),
+        batch_size: 10,
+        max_attempts,
+    }
+}
+
+/// **Test: Rounds Submit Valid Requests and Isolate Bad Ones**
+#[test]
+fn round_submits_valid_and_isolates_bad_requests() {
+    let env = Env::default();
+    let dir = temp_dir("round");
+    let relayer = relayer(&dir, 2);
+    let ids: Vec<u64> = ["ok", "bad", "later", "poison", "ok"]
+        .iter()
+        .map(|value| relayer.queue.push(request(&env, value)).unwrap())
+        .collect();
+
+    // The poisoned batch is re-simulated one request at a time
+    let round = relayer.process_once().unwrap();
+    assert_eq!((round.submitted, round.failed, round.deferred), (2, 2, 1));
+    assert_eq!(*relayer.submitter.submitted.borrow(), vec!["ok", "ok"]);
+
+    let status = |id| relayer.queue.get(id).unwrap().unwrap().status;
+    assert!(matches!(status(ids[0]), RequestStatus::Submitted { .. }));
+    assert!(matches!(status(ids[1]), RequestStatus::Failed { .. }));
+    assert_eq!(status(ids[2]), RequestStatus::Pending);
+    assert!(matches!(status(ids[3]), RequestStatus::Failed { .. }));
+
+    // Nonce mismatches are retried until they run out of attempts
+    let round = relayer.process_once().unwrap();
+    assert_eq!((round.submitted, round.failed, round.deferred), (0, 1, 0));
+    assert!(relayer.queue.pending(10).unwrap().is_empty());
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+/// **Test: Transient Failures Keep Requests Queued Across Restarts**
+#[test]
+fn transient_failures_back_off_and_survive_restart() {
+    let env = Env::default();
+    let dir = temp_dir("transient");
+    let first = relayer(&dir, 5);
+    let id = first.queue.push(request(&env, "ok")).unwrap();
+    first.submitter.offline.set(true);
+    let round = first.process_once().unwrap();
+    assert!(round.retry);
+    assert_eq!(round.deferred, 1);
+
+    let restarted = relayer(&dir, 5);
+    assert_eq!(restarted.queue.push(request(&env, "ok")).unwrap(), id + 1);
+    let round = restarted.process_once().unwrap();
+    assert_eq!(round.submitted, 2);
+    assert_eq!(restarted.queue.get(id).unwrap().unwrap().attempts, 1);
+
+    let backoff = Backoff {
+        base: Duration::from_secs(2),
+        max: Duration::from_secs(30),
+    };
+    assert_eq!(backoff.delay(1), Duration::from_secs(2));
+    assert_eq!(backoff.delay(3), Duration::from_secs(8));
+    assert_eq!(backoff.delay(40), Duration::from_secs(30));
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+/// **Test: The Intake Endpoint Validates Before Queueing**
+#[test]
+fn intake_validates_requests() {
+    let env = Env::default();
+    let dir = temp_dir("intake");
+    let queue = Queue::open(&dir).unwrap();
+
+    let body = serde_json::to_vec(&request(&env, "ok")).unwrap();
+    let (status, response) = route(&queue, "POST", "/requests", &body);
+    assert_eq!((status, response.as_str()), (202, "{\"id\":0}"));
+    let (status, response) = route(&queue, "GET", "/requests/0", &[]);
+    assert_eq!(status, 200);
+    assert!(response.contains("\"state\":\"pending\""));
+
+    let mut invalid = request(&env, "ok");
+    invalid.attester = "GNOTASTRKEY".into();
+    let (status, _) = route(&queue, "POST", "/requests", &serde_json::to_vec(&invalid).unwrap());
+    assert_eq!(status, 400);
+    assert_eq!(route(&queue, "POST", "/requests", b"{").0, 400);
+    assert_eq!(route(&queue, "GET", "/requests/9", &[]).0, 404);
+    assert_eq!(route(&queue, "GET", "/health", &[]).0, 200);
+    std::fs::remove_dir_all(dir).unwrap();
+}
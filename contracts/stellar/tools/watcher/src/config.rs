@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Watcher settings, read from `WATCHER_*` environment variables.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Soroban RPC endpoint (`WATCHER_RPC_URL`)
+    pub rpc_url: String,
+    /// Protocol contract ID (`WATCHER_CONTRACT_ID`, required)
+    pub contract_id: String,
+    /// Hex UIDs of the schemas whose revocations are reported (`WATCHER_SCHEMAS`,
+    /// comma-separated, required)
+    pub schemas: Vec<String>,
+    /// Endpoint notifications are POSTed to (`WATCHER_WEBHOOK_URL`, required)
+    pub webhook_url: String,
+    /// Key notifications are signed with (`WATCHER_WEBHOOK_SECRET`, required)
+    pub webhook_secret: String,
+    /// File holding the position of the last delivered event (`WATCHER_CURSOR_FILE`)
+    pub cursor_file: PathBuf,
+    /// Ledger to start from when there is no cursor yet (`WATCHER_START_LEDGER`, required)
+    pub start_ledger: u32,
+    /// Events requested per `getEvents` page (`WATCHER_PAGE_SIZE`)
+    pub page_size: u32,
+    /// Delay between polls once caught up (`WATCHER_POLL_MS`)
+    pub poll_interval: Duration,
+}
+
+impl Config {
+    /// Reads the configuration, naming the first missing or malformed variable on error.
+    pub fn from_env() -> Result<Self, String> {
+        let schemas: Vec<String> = required("WATCHER_SCHEMAS")?
+            .split(',')
+            .map(|uid| uid.trim().to_ascii_lowercase())
+            .filter(|uid| !uid.is_empty())
+            .collect();
+        if schemas.is_empty() {
+            return Err("WATCHER_SCHEMAS names no schemas".to_string());
+        }
+        Ok(Config {
+            rpc_url: var_or("WATCHER_RPC_URL", "https://soroban-testnet.stellar.org"),
+            contract_id: required("WATCHER_CONTRACT_ID")?,
+            schemas,
+            webhook_url: required("WATCHER_WEBHOOK_URL")?,
+            webhook_secret: required("WATCHER_WEBHOOK_SECRET")?,
+            cursor_file: PathBuf::from(var_or("WATCHER_CURSOR_FILE", "watcher-cursor")),
+            start_ledger: required("WATCHER_START_LEDGER")?
+                .parse()
+                .map_err(|_| "WATCHER_START_LEDGER is not a valid number".to_string())?,
+            page_size: parsed("WATCHER_PAGE_SIZE", 200)?,
+            poll_interval: Duration::from_millis(parsed("WATCHER_POLL_MS", 2_000)?),
+        })
+    }
+}
+
+fn var_or(name: &str, default: &str) -> String {
+    std::env::var(name).unwrap_or_else(|_| default.to_string())
+}
+
+fn required(name: &str) -> Result<String, String> {
+    std::env::var(name).map_err(|_| format!("{name} must be set"))
+}
+
+fn parsed<T: std::str::FromStr>(name: &str, default: T) -> Result<T, String> {
+    match std::env::var(name) {
+        Ok(value) => value.parse().map_err(|_| format!("{name} is not a valid number")),
+        Err(_) => Ok(default),
+    }
+}
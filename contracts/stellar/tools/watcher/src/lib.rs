@@ -0,0 +1,19 @@
+//! # Attest Watcher
+//!
+//! Revocation watcher for relying parties that cache credentials. It follows the
+//! protocol contract's events through Soroban RPC `getEvents` and, for every
+//! revocation under a configured schema, POSTs the decoded event to a webhook with an
+//! HMAC signature, so caches can be invalidated within a poll interval of the
+//! revocation landing on-chain.
+//!
+//! Events are fetched and decoded with the indexer's RPC client and normalizer.
+//!
+//! - [`webhook`]: notification signing and delivery
+//! - [`watcher`]: the polling loop and its on-disk cursor
+pub mod config;
+pub mod watcher;
+pub mod webhook;
+
+pub use config::Config;
+pub use watcher::{Round, WatchError, Watcher};
+pub use webhook::{sign, verify, HttpNotifier, Notifier, EVENT_ID_HEADER, SIGNATURE_HEADER};
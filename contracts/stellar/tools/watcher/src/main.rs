@@ -0,0 +1,49 @@
+use indexer::SorobanRpc;
+use std::thread;
+use std::time::Duration;
+use watcher::{Config, HttpNotifier, Watcher};
+
+fn main() {
+    let config = Config::from_env().unwrap_or_else(|err| {
+        eprintln!("watcher: {err}");
+        std::process::exit(2);
+    });
+
+    let mut watcher = Watcher {
+        source: SorobanRpc::new(&config.rpc_url, &config.contract_id),
+        notifier: HttpNotifier::new(&config.webhook_url),
+        schemas: config.schemas.clone(),
+        secret: config.webhook_secret.clone().into_bytes(),
+        cursor_file: config.cursor_file.clone(),
+        start_ledger: config.start_ledger,
+        page_size: config.page_size,
+    };
+    println!("watcher: watching {} schema(s)", watcher.schemas.len());
+
+    let mut failures = 0;
+    loop {
+        let caught_up = match watcher.poll_once() {
+            Ok(round) => {
+                failures = 0;
+                if round.delivered > 0 {
+                    println!("watcher: delivered {} revocation(s)", round.delivered);
+                }
+                round.caught_up
+            },
+            Err(err) => {
+                failures += 1;
+                eprintln!("watcher: round failed: {err:?}");
+                true
+            },
+        };
+        if caught_up {
+            let backoff = 1u32 << failures.min(6);
+            thread::sleep(
+                config
+                    .poll_interval
+                    .saturating_mul(backoff)
+                    .min(Duration::from_secs(300)),
+            );
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::webhook::{sign, Notifier};
+use attest_types::events::{EventRecord, ProtocolEvent};
+use indexer::{normalize, EventSource, RpcError, Start};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// What one polling round did.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Round {
+    /// Events returned by the RPC
+    pub fetched: usize,
+    /// Revocations of watched schemas that were delivered
+    pub delivered: usize,
+    /// Whether the page was short, meaning the watcher reached the chain head
+    pub caught_up: bool,
+}
+
+/// Why a round stopped early. Events delivered before the failure stay delivered.
+#[derive(Debug)]
+pub enum WatchError {
+    Rpc(RpcError),
+    /// The webhook refused or could not be reached
+    Delivery {
+        event_id: String,
+        error: String,
+    },
+    /// The cursor file could not be read or written
+    Cursor(io::Error),
+}
+
+/// Forwards revocations of watched schemas from an [`EventSource`] to a [`Notifier`].
+///
+/// Delivery is at-least-once: the cursor advances past an event only after its
+/// notification is accepted, so a failed or interrupted delivery is retried from the
+/// same event. Receivers drop repeats by event ID.
+pub struct Watcher<S, N> {
+    pub source: S,
+    pub notifier: N,
+    /// Lowercase hex UIDs of the watched schemas
+    pub schemas: Vec<String>,
+    pub secret: Vec<u8>,
+    /// File holding the ID of the last handled event
+    pub cursor_file: PathBuf,
+    /// Ledger to start from when there is no cursor yet
+    pub start_ledger: u32,
+    pub page_size: u32,
+}
+
+impl<S: EventSource, N: Notifier> Watcher<S, N> {
+    /// Fetches the page after the cursor and delivers its watched revocations in order.
+    pub fn poll_once(&mut self) -> Result<Round, WatchError> {
+        let start = match self.cursor().map_err(WatchError::Cursor)? {
+            Some(cursor) => Start::Cursor(cursor),
+            None => Start::Ledger(self.start_ledger),
+        };
+        let page = self.source.events(&start, self.page_size).map_err(WatchError::Rpc)?;
+        let mut round = Round {
+            fetched: page.events.len(),
+            caught_up: page.events.len() < self.page_size as usize,
+            ..Round::default()
+        };
+
+        for raw in &page.events {
+            // Undecodable events cannot be revocations the watcher understands; the
+            // indexer reports them
+            if let Ok(Some(record)) = normalize(raw) {
+                if self.is_watched(&record.event) {
+                    self.deliver(&record)?;
+                    round.delivered += 1;
+                    // Event IDs are valid `getEvents` cursors
+                    self.save_cursor(&record.id).map_err(WatchError::Cursor)?;
+                }
+            }
+        }
+        self.save_cursor(&page.cursor).map_err(WatchError::Cursor)?;
+        Ok(round)
+    }
+
+    /// Whether the event revokes attestations under a watched schema.
+    pub fn is_watched(&self, event: &ProtocolEvent) -> bool {
+        let schema_uid = match event {
+            ProtocolEvent::AttestationRevoked { schema_uid, .. }
+            | ProtocolEvent::AttestationSelfRevoked { schema_uid, .. }
+            | ProtocolEvent::SchemaRevokedAll { schema_uid, .. } => schema_uid,
+            _ => return false,
+        };
+        self.schemas
+            .iter()
+            .any(|watched| watched.eq_ignore_ascii_case(schema_uid))
+    }
+
+    fn deliver(&self, record: &EventRecord) -> Result<(), WatchError> {
+        let body = serde_json::to_string(record).expect("event records serialize");
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+        self.notifier
+            .deliver(&record.id, &body, &sign(&self.secret, now, &body))
+            .map_err(|error| WatchError::Delivery {
+                event_id: record.id.clone(),
+                error,
+            })
+    }
+
+    /// The stored cursor, if any.
+    pub fn cursor(&self) -> io::Result<Option<String>> {
+        match fs::read_to_string(&self.cursor_file) {
+            Ok(cursor) => Ok(Some(cursor.trim().to_string()).filter(|cursor| !cursor.is_empty())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Replaces the cursor atomically (write then rename).
+    fn save_cursor(&self, cursor: &str) -> io::Result<()> {
+        let staging = self.cursor_file.with_extension("tmp");
+        fs::write(&staging, cursor)?;
+        fs::rename(staging, &self.cursor_file)
+    }
+}
@@ -0,0 +1,151 @@
+use indexer::{EventPage, EventSource, RawEvent, RpcError, Start};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::path::PathBuf;
+use watcher::{sign, verify, Notifier, WatchError, Watcher};
+
+const WATCHED: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+const OTHER: &str = "3333333333333333333333333333333333333333333333333333333333333333";
+const ATTESTER: &str = "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7";
+const SUBJECT: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+const SECRET: &[u8] = b"shared-webhook-secret";
+
+/// Serves a fixed event log; cursors are either event IDs or positions in the log.
+struct MockSource {
+    log: Vec<RawEvent>,
+}
+
+impl EventSource for MockSource {
+    fn events(&self, start: &Start, limit: u32) -> Result<EventPage, RpcError> {
+        let from = match start {
+            Start::Ledger(ledger) => self
+                .log
+                .iter()
+                .position(|e| e.ledger >= *ledger)
+                .unwrap_or(self.log.len()),
+            Start::Cursor(cursor) => match self.log.iter().position(|e| e.id == *cursor) {
+                Some(position) => position + 1,
+                None => cursor.parse().unwrap(),
+            },
+        };
+        let to = (from + limit as usize).min(self.log.len());
+        Ok(EventPage {
+            events: self.log[from..to].to_vec(),
+            cursor: to.to_string(),
+        })
+    }
+}
+
+/// Records deliveries, refusing them while `down` is set.
+#[derive(Default)]
+struct MockWebhook {
+    received: RefCell<Vec<(String, String, String)>>,
+    down: RefCell<bool>,
+}
+
+impl Notifier for &MockWebhook {
+    fn deliver(&self, event_id: &str, body: &str, signature: &str) -> Result<(), String> {
+        if *self.down.borrow() {
+            return Err("HTTP 503".into());
+        }
+        self.received
+            .borrow_mut()
+            .push((event_id.into(), body.into(), signature.into()));
+        Ok(())
+    }
+}
+
+fn revoke(id: u32, uid: &str, schema_uid: &str) -> RawEvent {
+    RawEvent {
+        id: format!("{id:019}-0000000001"),
+        ledger: id,
+        ledger_closed_at: "2025-01-01T00:00:00Z".into(),
+        contract_id: "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC".into(),
+        topic_json: vec![json!({ "symbol": "ATTEST" }), json!({ "symbol": "REVOKE" })],
+        value_json: json!({ "vec": [
+            { "bytes": uid },
+            { "bytes": schema_uid },
+            { "address": SUBJECT },
+            { "address": ATTESTER },
+            { "bool": true },
+            { "u64": "1700000500" },
+        ]}),
+    }
+}
+
+fn temp_file(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("attest-watcher-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn new_watcher(webhook: &MockWebhook, cursor_file: PathBuf) -> Watcher<MockSource, &MockWebhook> {
+    Watcher {
+        source: MockSource {
+            log: vec![
+                revoke(10, &"a".repeat(64), WATCHED),
+                revoke(11, &"b".repeat(64), OTHER),
+                revoke(12, &"c".repeat(64), WATCHED),
+            ],
+        },
+        notifier: webhook,
+        schemas: vec![WATCHED.into()],
+        secret: SECRET.to_vec(),
+        cursor_file,
+        start_ledger: 10,
+        page_size: 10,
+    }
+}
+
+/// **Test: Only Watched Revocations Are Delivered, Signed**
+#[test]
+fn delivers_signed_revocations_for_watched_schemas() {
+    let webhook = MockWebhook::default();
+    let mut watcher = new_watcher(&webhook, temp_file("deliver"));
+
+    let round = watcher.poll_once().unwrap();
+    assert_eq!((round.fetched, round.delivered, round.caught_up), (3, 2, true));
+
+    let received = webhook.received.borrow();
+    assert_eq!(received[0].0, watcher.source.log[0].id);
+    let body: Value = serde_json::from_str(&received[1].1).unwrap();
+    assert_eq!(body["event"]["kind"], "attestation_revoked");
+    assert_eq!(body["event"]["uid"], "c".repeat(64));
+
+    let (_, body, signature) = &received[0];
+    let timestamp: u64 = signature[2..signature.find(',').unwrap()].parse().unwrap();
+    assert!(verify(SECRET, signature, body, timestamp + 10, 300));
+    assert!(!verify(SECRET, signature, body, timestamp + 600, 300));
+    assert!(!verify(b"wrong-secret", signature, body, timestamp, 300));
+    assert!(!verify(SECRET, signature, "{}", timestamp, 300));
+    assert_eq!(sign(SECRET, timestamp, body), *signature);
+}
+
+/// **Test: Failed Deliveries Are Retried from the Undelivered Event**
+#[test]
+fn failed_delivery_resumes_from_last_delivered_event() {
+    let webhook = MockWebhook::default();
+    let cursor_file = temp_file("retry");
+    let mut watcher = new_watcher(&webhook, cursor_file.clone());
+    watcher.source.log.truncate(1);
+    watcher.poll_once().unwrap();
+    assert_eq!(watcher.cursor().unwrap().as_deref(), Some("1"));
+
+    // Two more revocations land while the webhook is down
+    watcher.source.log = new_watcher(&webhook, cursor_file).source.log;
+    *webhook.down.borrow_mut() = true;
+    assert!(matches!(
+        watcher.poll_once(),
+        Err(WatchError::Delivery { event_id, .. }) if event_id == watcher.source.log[2].id
+    ));
+    assert_eq!(watcher.cursor().unwrap().as_deref(), Some("1"));
+
+    *webhook.down.borrow_mut() = false;
+    let round = watcher.poll_once().unwrap();
+    assert_eq!((round.fetched, round.delivered), (2, 1));
+    let ids: Vec<_> = webhook.received.borrow().iter().map(|(id, _, _)| id.clone()).collect();
+    assert_eq!(
+        ids,
+        vec![watcher.source.log[0].id.clone(), watcher.source.log[2].id.clone()]
+    );
+}
@@ -0,0 +1,91 @@
+//! Typed protocol events, for indexers and watchers.
+//!
+//! The protocol contract publishes its events as positional topic and data tuples
+//! (see `protocol::events`). Off-chain tools decode those into [`ProtocolEvent`], so
+//! consumers match on one enum instead of re-deriving tuple layouts. Values use the
+//! same string encodings as [`crate::json`].
+//!
+//! Only built natively with the `serde` feature; contracts never link this module.
+extern crate std;
+
+use crate::json::SchemaJson;
+use serde::{Deserialize, Serialize};
+use std::string::String;
+use std::vec::Vec;
+
+/// A protocol event decoded from its topics and data.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ProtocolEvent {
+    /// `SCHEMA/REGISTER`: a schema was registered
+    SchemaRegistered {
+        schema_uid: String,
+        schema: SchemaJson,
+        authority: String,
+    },
+    /// `SCHEMA/REVOKEALL`: every attestation under a schema was revoked
+    SchemaRevokedAll {
+        schema_uid: String,
+        reason: String,
+        revoked_at: u64,
+    },
+    /// `ATTEST/CREATE`: an attestation was stored
+    ///
+    /// The event data does not carry the schema UID; indexers learn it from the
+    /// attestation's revocation event, if any.
+    AttestationCreated {
+        uid: String,
+        subject: String,
+        attester: String,
+        value: String,
+        nonce: u64,
+        timestamp: u64,
+    },
+    /// `ATTEST/REVOKE`: an attester revoked an attestation
+    AttestationRevoked {
+        uid: String,
+        schema_uid: String,
+        subject: String,
+        attester: String,
+        revocation_time: u64,
+    },
+    /// `ATTEST/SELF_REV`: a subject revoked its own self-attestation
+    AttestationSelfRevoked {
+        uid: String,
+        schema_uid: String,
+        subject: String,
+        revocation_time: u64,
+    },
+    /// `ATTEST/EXPIRED`: an attestation passed its expiration time
+    AttestationExpired { uid: String, expiration_time: Option<u64> },
+    /// `ATTEST/PURGED`: an attestation was removed from storage
+    AttestationPurged { uid: String, reason: String },
+}
+
+impl ProtocolEvent {
+    /// The attestation the event is about, if any.
+    pub fn attestation_uid(&self) -> Option<&str> {
+        match self {
+            ProtocolEvent::AttestationCreated { uid, .. }
+            | ProtocolEvent::AttestationRevoked { uid, .. }
+            | ProtocolEvent::AttestationSelfRevoked { uid, .. }
+            | ProtocolEvent::AttestationExpired { uid, .. }
+            | ProtocolEvent::AttestationPurged { uid, .. } => Some(uid),
+            ProtocolEvent::SchemaRegistered { .. } | ProtocolEvent::SchemaRevokedAll { .. } => None,
+        }
+    }
+}
+
+/// A [`ProtocolEvent`] with the ledger position it was emitted at.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EventRecord {
+    /// RPC event ID; IDs sort in emission order
+    pub id: String,
+    pub ledger: u32,
+    /// Ledger close time (RFC 3339)
+    pub ledger_closed_at: String,
+    pub contract_id: String,
+    /// Extra topics after the action, such as a schema's custom event topic
+    pub extra_topics: Vec<String>,
+    pub event: ProtocolEvent,
+}
@@ -0,0 +1,318 @@
+//! JSON representations of the contract types, for off-chain tooling.
+//!
+//! Soroban host types can't implement serde on their own, since decoding them needs an
+//! `Env`. Each contract type therefore has an owned mirror struct here that derives
+//! `Serialize`/`Deserialize`, and converts to and from the contract type through
+//! [`JsonCodec`]. Addresses are encoded as strkeys (`G...`/`C...`), byte strings as
+//! lowercase hex, and symbols and strings as JSON strings.
+//!
+//! Only built natively with the `serde` feature; contracts never link this module.
+extern crate std;
+
+use crate::{Attestation, DelegatedAttestationRequest, DelegatedRevocationRequest, Schema};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Symbol};
+use std::string::{String as StdString, ToString};
+use std::vec::Vec as StdVec;
+
+/// Why a value could not be converted to or from JSON.
+#[derive(Debug)]
+pub enum JsonError {
+    /// The input is not valid JSON for the type
+    Json(serde_json::Error),
+    /// A field holds a value that is not valid for the contract type
+    InvalidField(&'static str),
+}
+
+impl From<serde_json::Error> for JsonError {
+    fn from(err: serde_json::Error) -> Self {
+        JsonError::Json(err)
+    }
+}
+
+/// Conversion between a contract type and its JSON mirror.
+pub trait JsonCodec: Sized {
+    /// The serde mirror of the contract type
+    type Json: Serialize + DeserializeOwned;
+
+    /// Converts the contract type to its mirror
+    fn to_json_value(&self) -> Self::Json;
+
+    /// Converts a mirror back to the contract type in `env`
+    fn from_json_value(env: &Env, json: Self::Json) -> Result<Self, JsonError>;
+
+    /// Serializes the value as a JSON string
+    fn to_json(&self) -> Result<StdString, JsonError> {
+        Ok(serde_json::to_string(&self.to_json_value())?)
+    }
+
+    /// Deserializes a value from a JSON string
+    fn from_json(env: &Env, json: &str) -> Result<Self, JsonError> {
+        Self::from_json_value(env, serde_json::from_str(json)?)
+    }
+}
+
+/// JSON mirror of [`Schema`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SchemaJson {
+    pub authority: StdString,
+    pub definition: StdString,
+    pub resolver: Option<StdString>,
+    pub revocable: bool,
+    pub namespace: Option<StdString>,
+    pub version: u32,
+    pub previous_uid: Option<StdString>,
+}
+
+/// JSON mirror of [`Attestation`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AttestationJson {
+    pub uid: StdString,
+    pub schema_uid: StdString,
+    pub schema_version: u32,
+    pub subject: StdString,
+    pub attester: StdString,
+    pub value: StdString,
+    pub nonce: u64,
+    pub timestamp: u64,
+    pub expiration_time: Option<u64>,
+    pub revoked: bool,
+    pub revocation_time: Option<u64>,
+    pub encryption_scheme: Option<StdString>,
+    pub encrypted_key: Option<StdString>,
+    pub on_behalf_of: Option<StdString>,
+    pub external_id: Option<StdString>,
+    pub locale: Option<StdString>,
+    pub ref_uid: Option<StdString>,
+}
+
+/// JSON mirror of [`DelegatedAttestationRequest`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DelegatedAttestationRequestJson {
+    pub schema_uid: StdString,
+    pub subject: StdString,
+    pub attester: StdString,
+    pub value: StdString,
+    pub nonce: u64,
+    pub deadline: u64,
+    pub expiration_time: Option<u64>,
+    pub version: u32,
+    pub signature: StdString,
+}
+
+/// JSON mirror of [`DelegatedRevocationRequest`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DelegatedRevocationRequestJson {
+    pub attestation_uid: StdString,
+    pub schema_uid: StdString,
+    pub subject: StdString,
+    pub nonce: u64,
+    pub revoker: StdString,
+    pub deadline: u64,
+    pub version: u32,
+    pub signature: StdString,
+}
+
+impl JsonCodec for Schema {
+    type Json = SchemaJson;
+
+    fn to_json_value(&self) -> SchemaJson {
+        SchemaJson {
+            authority: encode_address(&self.authority),
+            definition: self.definition.to_string(),
+            resolver: self.resolver.as_ref().map(encode_address),
+            revocable: self.revocable,
+            namespace: self.namespace.as_ref().map(Symbol::to_string),
+            version: self.version,
+            previous_uid: self.previous_uid.as_ref().map(|uid| hex::encode(uid.to_array())),
+        }
+    }
+
+    fn from_json_value(env: &Env, json: SchemaJson) -> Result<Self, JsonError> {
+        Ok(Schema {
+            authority: decode_address(env, &json.authority, "authority")?,
+            definition: String::from_str(env, &json.definition),
+            resolver: json
+                .resolver
+                .map(|resolver| decode_address(env, &resolver, "resolver"))
+                .transpose()?,
+            revocable: json.revocable,
+            namespace: json
+                .namespace
+                .map(|namespace| decode_symbol(env, &namespace, "namespace"))
+                .transpose()?,
+            version: json.version,
+            previous_uid: json
+                .previous_uid
+                .map(|uid| decode_bytes_n(env, &uid, "previous_uid"))
+                .transpose()?,
+        })
+    }
+}
+
+impl JsonCodec for Attestation {
+    type Json = AttestationJson;
+
+    fn to_json_value(&self) -> AttestationJson {
+        AttestationJson {
+            uid: hex::encode(self.uid.to_array()),
+            schema_uid: hex::encode(self.schema_uid.to_array()),
+            schema_version: self.schema_version,
+            subject: encode_address(&self.subject),
+            attester: encode_address(&self.attester),
+            value: self.value.to_string(),
+            nonce: self.nonce,
+            timestamp: self.timestamp,
+            expiration_time: self.expiration_time,
+            revoked: self.revoked,
+            revocation_time: self.revocation_time,
+            encryption_scheme: self.encryption_scheme.as_ref().map(Symbol::to_string),
+            encrypted_key: self.encrypted_key.as_ref().map(encode_bytes),
+            on_behalf_of: self.on_behalf_of.as_ref().map(encode_address),
+            external_id: self.external_id.as_ref().map(encode_bytes),
+            locale: self.locale.as_ref().map(Symbol::to_string),
+            ref_uid: self.ref_uid.as_ref().map(|uid| hex::encode(uid.to_array())),
+        }
+    }
+
+    fn from_json_value(env: &Env, json: AttestationJson) -> Result<Self, JsonError> {
+        Ok(Attestation {
+            uid: decode_bytes_n(env, &json.uid, "uid")?,
+            schema_uid: decode_bytes_n(env, &json.schema_uid, "schema_uid")?,
+            schema_version: json.schema_version,
+            subject: decode_address(env, &json.subject, "subject")?,
+            attester: decode_address(env, &json.attester, "attester")?,
+            value: String::from_str(env, &json.value),
+            nonce: json.nonce,
+            timestamp: json.timestamp,
+            expiration_time: json.expiration_time,
+            revoked: json.revoked,
+            revocation_time: json.revocation_time,
+            encryption_scheme: json
+                .encryption_scheme
+                .map(|scheme| decode_symbol(env, &scheme, "encryption_scheme"))
+                .transpose()?,
+            encrypted_key: json
+                .encrypted_key
+                .map(|key| decode_bytes(env, &key, "encrypted_key"))
+                .transpose()?,
+            on_behalf_of: json
+                .on_behalf_of
+                .map(|org| decode_address(env, &org, "on_behalf_of"))
+                .transpose()?,
+            external_id: json
+                .external_id
+                .map(|id| decode_bytes(env, &id, "external_id"))
+                .transpose()?,
+            locale: json
+                .locale
+                .map(|locale| decode_symbol(env, &locale, "locale"))
+                .transpose()?,
+            ref_uid: json
+                .ref_uid
+                .map(|uid| decode_bytes_n(env, &uid, "ref_uid"))
+                .transpose()?,
+        })
+    }
+}
+
+impl JsonCodec for DelegatedAttestationRequest {
+    type Json = DelegatedAttestationRequestJson;
+
+    fn to_json_value(&self) -> DelegatedAttestationRequestJson {
+        DelegatedAttestationRequestJson {
+            schema_uid: hex::encode(self.schema_uid.to_array()),
+            subject: encode_address(&self.subject),
+            attester: encode_address(&self.attester),
+            value: self.value.to_string(),
+            nonce: self.nonce,
+            deadline: self.deadline,
+            expiration_time: self.expiration_time,
+            version: self.version,
+            signature: hex::encode(self.signature.to_array()),
+        }
+    }
+
+    fn from_json_value(env: &Env, json: DelegatedAttestationRequestJson) -> Result<Self, JsonError> {
+        Ok(DelegatedAttestationRequest {
+            schema_uid: decode_bytes_n(env, &json.schema_uid, "schema_uid")?,
+            subject: decode_address(env, &json.subject, "subject")?,
+            attester: decode_address(env, &json.attester, "attester")?,
+            value: String::from_str(env, &json.value),
+            nonce: json.nonce,
+            deadline: json.deadline,
+            expiration_time: json.expiration_time,
+            version: json.version,
+            signature: decode_bytes_n(env, &json.signature, "signature")?,
+        })
+    }
+}
+
+impl JsonCodec for DelegatedRevocationRequest {
+    type Json = DelegatedRevocationRequestJson;
+
+    fn to_json_value(&self) -> DelegatedRevocationRequestJson {
+        DelegatedRevocationRequestJson {
+            attestation_uid: hex::encode(self.attestation_uid.to_array()),
+            schema_uid: hex::encode(self.schema_uid.to_array()),
+            subject: encode_address(&self.subject),
+            nonce: self.nonce,
+            revoker: encode_address(&self.revoker),
+            deadline: self.deadline,
+            version: self.version,
+            signature: hex::encode(self.signature.to_array()),
+        }
+    }
+
+    fn from_json_value(env: &Env, json: DelegatedRevocationRequestJson) -> Result<Self, JsonError> {
+        Ok(DelegatedRevocationRequest {
+            attestation_uid: decode_bytes_n(env, &json.attestation_uid, "attestation_uid")?,
+            schema_uid: decode_bytes_n(env, &json.schema_uid, "schema_uid")?,
+            subject: decode_address(env, &json.subject, "subject")?,
+            nonce: json.nonce,
+            revoker: decode_address(env, &json.revoker, "revoker")?,
+            deadline: json.deadline,
+            version: json.version,
+            signature: decode_bytes_n(env, &json.signature, "signature")?,
+        })
+    }
+}
+
+fn encode_address(address: &Address) -> StdString {
+    address.to_string().to_string()
+}
+
+/// Decodes an account or contract strkey, which `Address::from_str` would otherwise panic on.
+fn decode_address(env: &Env, strkey: &str, field: &'static str) -> Result<Address, JsonError> {
+    match stellar_strkey::Strkey::from_string(strkey) {
+        Ok(stellar_strkey::Strkey::PublicKeyEd25519(_)) | Ok(stellar_strkey::Strkey::Contract(_)) => {
+            Ok(Address::from_str(env, strkey))
+        },
+        _ => Err(JsonError::InvalidField(field)),
+    }
+}
+
+fn encode_bytes(bytes: &Bytes) -> StdString {
+    let raw: StdVec<u8> = bytes.iter().collect();
+    hex::encode(raw)
+}
+
+fn decode_bytes(env: &Env, hex_str: &str, field: &'static str) -> Result<Bytes, JsonError> {
+    let raw = hex::decode(hex_str).map_err(|_| JsonError::InvalidField(field))?;
+    Ok(Bytes::from_slice(env, &raw))
+}
+
+fn decode_bytes_n<const N: usize>(env: &Env, hex_str: &str, field: &'static str) -> Result<BytesN<N>, JsonError> {
+    let mut raw = [0u8; N];
+    hex::decode_to_slice(hex_str, &mut raw).map_err(|_| JsonError::InvalidField(field))?;
+    Ok(BytesN::from_array(env, &raw))
+}
+
+/// Decodes a symbol, checking the charset and length `Symbol::new` would otherwise panic on.
+fn decode_symbol(env: &Env, symbol: &str, field: &'static str) -> Result<Symbol, JsonError> {
+    let valid = symbol.len() <= 32 && symbol.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(JsonError::InvalidField(field));
+    }
+    Ok(Symbol::new(env, symbol))
+}
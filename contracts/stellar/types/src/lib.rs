@@ -0,0 +1,242 @@
+#![no_std]
+//! # Attest Domain Types
+//!
+//! Contract types shared by the attest.so Soroban contracts. The protocol stores
+//! these records and the authority and resolver contracts receive them in hooks,
+//! so each type is defined once here instead of per contract.
+//!
+//! - [`Attestation`], [`Schema`], [`Authority`]: records stored by the protocol
+//! - [`DelegatedAttestationRequest`], [`DelegatedRevocationRequest`]: signed
+//!   requests relayed through `attest_by_delegation` / `revoke_by_delegation`
+//! - [`ResolverAttestation`], [`ResolverContext`]: the flattened view of an
+//!   attestation passed to resolver hooks, and the relay context for `onrelayed`
+//!
+//! With the `serde` feature, native builds also get the [`json`] module, which
+//! converts these records to and from JSON for relayers, indexers and CLIs, and the
+//! [`events`] module with the typed protocol events indexers decode, and the [`value`]
+//! module, which builds and reads `BIN:` structured attestation values off-chain.
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Symbol};
+
+#[cfg(all(feature = "serde", not(target_family = "wasm")))]
+pub mod events;
+#[cfg(all(feature = "serde", not(target_family = "wasm")))]
+pub mod json;
+#[cfg(all(feature = "serde", not(target_family = "wasm")))]
+pub mod value;
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                               Authority                                   ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Represents an authority that can create schemas and attestations.
+///
+/// Authorities are registered entities with specific permissions in the system
+/// that can create schemas and issue attestations.
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct Authority {
+    /// The Stellar address of the authority
+    pub address: Address,
+    /// Metadata describing the authority
+    ///
+    /// Typically in JSON format, containing information about the authority.
+    pub metadata: String,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                                 Schema                                    ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Represents a schema definition that attestations can follow.
+///
+/// Schemas define the structure and validation rules for attestations.
+/// The definition field supports multiple formats:
+/// - XDR-encoded: Stellar-native binary format for structured data
+/// - JSON: Human-readable structured format
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Schema {
+    /// The address of the authority that created this schema
+    pub authority: Address,
+    /// The schema definition in any supported format
+    ///
+    /// Supports XDR-encoded structured data or JSON
+    pub definition: String,
+    /// Optional address of a resolver contract for this schema
+    ///
+    /// If present, this contract will be called to handle attestation operations.
+    pub resolver: Option<Address>,
+    /// Whether attestations using this schema can be revoked
+    pub revocable: bool,
+    /// Optional namespace of the logical registry this schema belongs to
+    ///
+    /// Lets multiple organizations share one contract instance without UID or
+    /// listing collisions. `None` is the shared global registry.
+    pub namespace: Option<Symbol>,
+    /// Version of the schema within its lineage, starting at 1
+    pub version: u32,
+    /// Optional UID of the version this schema supersedes
+    ///
+    /// `None` for the first version of a schema.
+    pub previous_uid: Option<BytesN<32>>,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                      DelegatedAttestationRequest                          ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Represents a request for delegated attestations.
+///
+/// This allows an attester to sign an attestation off-chain, which can then be
+/// submitted on-chain by any party (who will pay the transaction fees).
+#[contracttype]
+#[derive(Clone)]
+pub struct DelegatedAttestationRequest {
+    /// The unique identifier of the schema this attestation follows
+    pub schema_uid: BytesN<32>,
+    /// The address of the entity that is the subject of this attestation
+    pub subject: Address,
+    /// The address of the original attester (who signed off-chain)
+    pub attester: Address,
+    /// The value or content of the attestation
+    pub value: String,
+    /// The nonce for this attestation (must be the next expected nonce for the attester)
+    pub nonce: u64,
+    /// Expiration timestamp for this signed request
+    ///
+    /// After this time, the signature is no longer valid and cannot be submitted.
+    pub deadline: u64,
+    /// Optional expiration time for the attestation itself
+    pub expiration_time: Option<u64>,
+    /// Version of the signed message format
+    ///
+    /// Signed into the message, and checked against the versions the contract accepts.
+    pub version: u32,
+    /// BLS12-381 G1 signature of the request data (96 bytes)
+    pub signature: BytesN<96>,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                      DelegatedRevocationRequest                           ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Represents a request for delegated revocation.
+///
+/// This allows an attester to sign a revocation off-chain, which can then be
+/// submitted on-chain by any party.
+#[contracttype]
+#[derive(Clone)]
+pub struct DelegatedRevocationRequest {
+    /// The unique identifier of the attestation to revoke
+    pub attestation_uid: BytesN<32>,
+    /// The unique identifier of the schema
+    pub schema_uid: BytesN<32>,
+    /// The address of the entity that is the subject of the attestation to revoke
+    pub subject: Address,
+    /// The nonce of the attestation to revoke
+    pub nonce: u64,
+    /// The address of the original attester (who signed off-chain)
+    pub revoker: Address,
+    /// Expiration timestamp for this signed request
+    pub deadline: u64,
+    /// Version of the signed message format
+    ///
+    /// Signed into the message, and checked against the versions the contract accepts.
+    pub version: u32,
+    /// BLS12-381 G1 signature of the request data (96 bytes)
+    pub signature: BytesN<96>,
+}
+
+/// ╔══════════════════════════════════════════════════════════════════════════╗
+/// ║                            Attestation                                    ║
+/// ╚══════════════════════════════════════════════════════════════════════════╝
+///
+/// Represents an attestation with support for both direct and delegated attestations.
+///
+/// Used for tracking attestations and supporting multiple attestations per schema/subject
+/// pair through nonces.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attestation {
+    /// The unique identifier of the attestation
+    pub uid: BytesN<32>,
+    /// The unique identifier of the schema this attestation follows
+    pub schema_uid: BytesN<32>,
+    /// The version of the schema at the time this attestation was issued
+    pub schema_version: u32,
+    /// The address of the entity that is the subject of this attestation
+    pub subject: Address,
+    /// The address of the entity that created this attestation
+    ///
+    /// In direct attestations, this is the caller.
+    /// In delegated attestations, this is the original signer.
+    pub attester: Address,
+    /// The value or content of the attestation
+    pub value: String,
+    /// Unique nonce for this attestation
+    ///
+    /// Allows for multiple attestations of the same schema for the same subject,
+    /// and prevents replay attacks in delegated attestations.
+    pub nonce: u64,
+    /// Timestamp when the attestation was created
+    pub timestamp: u64,
+    /// Optional expiration timestamp
+    ///
+    /// If set, the attestation is considered invalid after this time.
+    pub expiration_time: Option<u64>,
+    /// Whether this attestation has been revoked
+    pub revoked: bool,
+    /// Optional timestamp when the attestation was revoked
+    pub revocation_time: Option<u64>,
+    /// Optional identifier of the scheme used to encrypt `value`
+    ///
+    /// When set, `value` holds ciphertext and consumers must decrypt it with the
+    /// named scheme. See the protocol's `SUPPORTED_ENCRYPTION_SCHEMES`.
+    pub encryption_scheme: Option<Symbol>,
+    /// Optional content key, wrapped for the intended reader(s) under `encryption_scheme`
+    pub encrypted_key: Option<Bytes>,
+    /// Optional organization the attestation was issued on behalf of
+    ///
+    /// Set when a registered member of an organization attests for it.
+    pub on_behalf_of: Option<Address>,
+    /// Optional attester-defined reference ID, e.g. an internal record ID
+    ///
+    /// Unique per attester; indexed by the protocol's `DataKey::ExternalId`.
+    pub external_id: Option<Bytes>,
+    /// Optional language the attestation is written in, e.g. `en` or `pt_BR`
+    ///
+    /// Limited to the schema's allowed locales when the schema authority has set them.
+    pub locale: Option<Symbol>,
+    /// Optional UID of an attestation this one builds on, e.g. the course credentials a degree references
+    ///
+    /// Checked to exist and be unrevoked when the attestation is made.
+    pub ref_uid: Option<BytesN<32>>,
+}
+
+/************************************************
+* Flattened Attestation Struct for Resolver Calls
+************************************************/
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[contracttype]
+pub struct ResolverAttestation {
+    pub uid: BytesN<32>,
+    pub schema_uid: BytesN<32>,
+    pub recipient: Address,
+    pub attester: Address,
+    pub time: u64,
+    pub expiration_time: u64, // Flattened: 0 = not set
+    pub revocation_time: u64, // Flattened: 0 = not set
+    pub revocable: bool,
+    pub ref_uid: Bytes, // Flattened: empty bytes = not set
+    pub data: Bytes,
+    pub value: i128, // Flattened: 0 = not set
+}
+
+/************************************************
+* Invocation Context for Relayed Attestations
+************************************************/
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[contracttype]
+pub struct ResolverContext {
+    pub submitter: Address, // Relayer that submitted the delegated request and paid the transaction fee
+}
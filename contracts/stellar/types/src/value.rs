@@ -0,0 +1,278 @@
+//! Native codec for `BIN:`-prefixed structured attestation values.
+//!
+//! Mirrors the protocol's on-chain `value_codec`, so tools can build and read
+//! structured values without a contract call. A value is laid out by the schema's
+//! field list: a version byte, a presence bitmap with one bit per field (least
+//! significant bit first), then each present field in declared order: bools as one
+//! byte, integers as fixed-width big-endian, strings and bytes as a LEB128 length
+//! and the raw bytes, and addresses as a kind byte (0 account, 1 contract) and the
+//! 32-byte key or hash. The payload is base64-encoded after the `BIN:` prefix.
+//!
+//! On the JSON side, `i128` fields are decimal strings (JSON numbers lose precision
+//! past 2^53), bytes are lowercase hex and addresses are strkeys; omitted optional
+//! fields are absent keys.
+//!
+//! Only built natively with the `serde` feature; contracts never link this module.
+extern crate std;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::format;
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+/// Prefix marking an attestation value as a structured value
+pub const VALUE_PREFIX: &str = "BIN:";
+
+const VALUE_VERSION: u8 = 1;
+const ADDRESS_ACCOUNT: u8 = 0;
+const ADDRESS_CONTRACT: u8 = 1;
+
+/// One field of a schema definition, in the JSON shape schema definitions use.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FieldSpec {
+    pub name: String,
+    /// One of `bool`, `u32`, `u64`, `i64`, `i128`, `string`, `bytes` or `address`
+    #[serde(rename = "type")]
+    pub field_type: String,
+    #[serde(default)]
+    pub optional: bool,
+}
+
+/// Why a value could not be encoded or decoded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValueError {
+    /// The named field declares a type the codec does not support
+    UnsupportedType(String),
+    /// The named required field has no value
+    Missing(String),
+    /// The named field holds a value that does not match its type, or is not in the schema
+    InvalidField(String),
+    /// The value string is not a canonical encoding for the schema
+    Malformed,
+}
+
+/// Returns true if the value uses the `BIN:` prefix convention.
+pub fn is_structured_value(value: &str) -> bool {
+    value.starts_with(VALUE_PREFIX)
+}
+
+/// Encodes a JSON object as a structured value laid out by `fields`.
+///
+/// Keys not named in `fields` are rejected rather than dropped, and `null` is
+/// treated as an omitted optional field.
+pub fn encode_value(fields: &[FieldSpec], value: &Map<String, Value>) -> Result<String, ValueError> {
+    if let Some(key) = value.keys().find(|key| !fields.iter().any(|field| &field.name == *key)) {
+        return Err(ValueError::InvalidField(key.clone()));
+    }
+
+    let mut bitmap = std::vec![0u8; fields.len().div_ceil(8)];
+    let mut body = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let Some(json) = value.get(&field.name).filter(|json| !json.is_null()) else {
+            if field.optional {
+                continue;
+            }
+            return Err(ValueError::Missing(field.name.clone()));
+        };
+        write_field(&mut body, field, json)?;
+        bitmap[index / 8] |= 1 << (index % 8);
+    }
+
+    let mut payload = std::vec![VALUE_VERSION];
+    payload.extend_from_slice(&bitmap);
+    payload.extend_from_slice(&body);
+    Ok(format!("{VALUE_PREFIX}{}", STANDARD.encode(payload)))
+}
+
+/// Decodes a structured value laid out by `fields` into a JSON object.
+///
+/// Rejects anything the contract would reject: unknown versions, set bitmap bits
+/// past the last field, absent required fields, non-minimal lengths and trailing
+/// bytes.
+pub fn decode_value(fields: &[FieldSpec], value: &str) -> Result<Map<String, Value>, ValueError> {
+    let encoded = value.strip_prefix(VALUE_PREFIX).ok_or(ValueError::Malformed)?;
+    let payload = STANDARD.decode(encoded).map_err(|_| ValueError::Malformed)?;
+
+    let mut reader = Reader {
+        payload: &payload,
+        pos: 0,
+    };
+    if reader.read_u8()? != VALUE_VERSION {
+        return Err(ValueError::Malformed);
+    }
+    let bitmap = reader.read(fields.len().div_ceil(8))?;
+    if (fields.len()..bitmap.len() * 8).any(|bit| bitmap[bit / 8] & (1 << (bit % 8)) != 0) {
+        return Err(ValueError::Malformed);
+    }
+
+    let mut object = Map::new();
+    for (index, field) in fields.iter().enumerate() {
+        let field_type = FieldType::parse(field)?;
+        if bitmap[index / 8] & (1 << (index % 8)) == 0 {
+            if !field.optional {
+                return Err(ValueError::Malformed);
+            }
+            continue;
+        }
+        object.insert(field.name.clone(), reader.read_field(field_type)?);
+    }
+    if reader.pos != payload.len() {
+        return Err(ValueError::Malformed);
+    }
+    Ok(object)
+}
+
+#[derive(Clone, Copy)]
+enum FieldType {
+    Bool,
+    U32,
+    U64,
+    I64,
+    I128,
+    String,
+    Bytes,
+    Address,
+}
+
+impl FieldType {
+    fn parse(field: &FieldSpec) -> Result<FieldType, ValueError> {
+        Ok(match field.field_type.as_str() {
+            "bool" => FieldType::Bool,
+            "u32" => FieldType::U32,
+            "u64" => FieldType::U64,
+            "i64" => FieldType::I64,
+            "i128" => FieldType::I128,
+            "string" => FieldType::String,
+            "bytes" => FieldType::Bytes,
+            "address" => FieldType::Address,
+            _ => return Err(ValueError::UnsupportedType(field.name.clone())),
+        })
+    }
+}
+
+fn write_field(out: &mut Vec<u8>, field: &FieldSpec, json: &Value) -> Result<(), ValueError> {
+    let invalid = || ValueError::InvalidField(field.name.clone());
+    match FieldType::parse(field)? {
+        FieldType::Bool => out.push(json.as_bool().ok_or_else(invalid)? as u8),
+        FieldType::U32 => {
+            let number = json.as_u64().and_then(|number| u32::try_from(number).ok());
+            out.extend_from_slice(&number.ok_or_else(invalid)?.to_be_bytes());
+        },
+        FieldType::U64 => out.extend_from_slice(&json.as_u64().ok_or_else(invalid)?.to_be_bytes()),
+        FieldType::I64 => out.extend_from_slice(&json.as_i64().ok_or_else(invalid)?.to_be_bytes()),
+        FieldType::I128 => {
+            let number = json.as_str().and_then(|text| text.parse::<i128>().ok());
+            out.extend_from_slice(&number.ok_or_else(invalid)?.to_be_bytes());
+        },
+        FieldType::String => write_opaque(out, json.as_str().ok_or_else(invalid)?.as_bytes()),
+        FieldType::Bytes => {
+            let bytes = json.as_str().and_then(|text| hex::decode(text).ok());
+            write_opaque(out, &bytes.ok_or_else(invalid)?);
+        },
+        FieldType::Address => {
+            let strkey = json
+                .as_str()
+                .and_then(|text| stellar_strkey::Strkey::from_string(text).ok());
+            match strkey.ok_or_else(invalid)? {
+                stellar_strkey::Strkey::PublicKeyEd25519(key) => {
+                    out.push(ADDRESS_ACCOUNT);
+                    out.extend_from_slice(&key.0);
+                },
+                stellar_strkey::Strkey::Contract(contract) => {
+                    out.push(ADDRESS_CONTRACT);
+                    out.extend_from_slice(&contract.0);
+                },
+                _ => return Err(invalid()),
+            }
+        },
+    }
+    Ok(())
+}
+
+fn write_opaque(out: &mut Vec<u8>, data: &[u8]) {
+    let mut len = data.len();
+    loop {
+        let byte = (len & 0x7f) as u8;
+        len >>= 7;
+        if len == 0 {
+            out.push(byte);
+            break;
+        }
+        out.push(byte | 0x80);
+    }
+    out.extend_from_slice(data);
+}
+
+struct Reader<'a> {
+    payload: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read(&mut self, len: usize) -> Result<&'a [u8], ValueError> {
+        let end = self.pos.checked_add(len).ok_or(ValueError::Malformed)?;
+        let bytes = self.payload.get(self.pos..end).ok_or(ValueError::Malformed)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, ValueError> {
+        Ok(self.read(1)?[0])
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], ValueError> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.read(N)?);
+        Ok(array)
+    }
+
+    /// Reads a minimally encoded LEB128 length, then that many bytes.
+    fn read_opaque(&mut self) -> Result<&'a [u8], ValueError> {
+        let mut len: u32 = 0;
+        for shift in (0..35).step_by(7) {
+            let byte = self.read_u8()?;
+            if shift > 0 && byte == 0 {
+                return Err(ValueError::Malformed);
+            }
+            len |= ((byte & 0x7f) as u32).checked_shl(shift).ok_or(ValueError::Malformed)?;
+            if byte & 0x80 == 0 {
+                return self.read(len as usize);
+            }
+        }
+        Err(ValueError::Malformed)
+    }
+
+    fn read_field(&mut self, field_type: FieldType) -> Result<Value, ValueError> {
+        Ok(match field_type {
+            FieldType::Bool => match self.read_u8()? {
+                0 => Value::Bool(false),
+                1 => Value::Bool(true),
+                _ => return Err(ValueError::Malformed),
+            },
+            FieldType::U32 => u32::from_be_bytes(self.read_array()?).into(),
+            FieldType::U64 => u64::from_be_bytes(self.read_array()?).into(),
+            FieldType::I64 => i64::from_be_bytes(self.read_array()?).into(),
+            FieldType::I128 => i128::from_be_bytes(self.read_array()?).to_string().into(),
+            FieldType::String => {
+                let text = std::str::from_utf8(self.read_opaque()?).map_err(|_| ValueError::Malformed)?;
+                text.to_string().into()
+            },
+            FieldType::Bytes => hex::encode(self.read_opaque()?).into(),
+            FieldType::Address => {
+                let kind = self.read_u8()?;
+                let payload = self.read_array::<32>()?;
+                let strkey = match kind {
+                    ADDRESS_ACCOUNT => {
+                        stellar_strkey::Strkey::PublicKeyEd25519(stellar_strkey::ed25519::PublicKey(payload))
+                    },
+                    ADDRESS_CONTRACT => stellar_strkey::Strkey::Contract(stellar_strkey::Contract(payload)),
+                    _ => return Err(ValueError::Malformed),
+                };
+                strkey.to_string().into()
+            },
+        })
+    }
+}
@@ -0,0 +1,115 @@
+use attest_types::json::{JsonCodec, JsonError};
+use attest_types::{Attestation, DelegatedAttestationRequest, DelegatedRevocationRequest, Schema};
+use soroban_sdk::{
+    testutils::{Address as _, BytesN as _},
+    Address, Bytes, BytesN, Env, String, Symbol,
+};
+
+/// **Test: Contract Types Round-Trip Through JSON**
+#[test]
+fn contract_types_round_trip_through_json() {
+    let env = Env::default();
+
+    let schema = Schema {
+        authority: Address::generate(&env),
+        definition: String::from_str(&env, "{\"name\":\"kyc\"}"),
+        resolver: Some(Address::generate(&env)),
+        revocable: true,
+        namespace: Some(Symbol::new(&env, "acme")),
+        version: 2,
+        previous_uid: Some(BytesN::random(&env)),
+    };
+    let json = schema.to_json().unwrap();
+    assert_eq!(Schema::from_json(&env, &json).unwrap(), schema);
+
+    let attestation = Attestation {
+        uid: BytesN::random(&env),
+        schema_uid: BytesN::random(&env),
+        schema_version: 2,
+        subject: Address::generate(&env),
+        attester: Address::generate(&env),
+        value: String::from_str(&env, "{\"verified\":true}"),
+        nonce: 7,
+        timestamp: 1_000,
+        expiration_time: Some(2_000),
+        revoked: false,
+        revocation_time: None,
+        encryption_scheme: Some(Symbol::new(&env, "X25519_AES256GCM")),
+        encrypted_key: Some(Bytes::from_slice(&env, &[0xde, 0xad])),
+        on_behalf_of: None,
+        external_id: Some(Bytes::from_slice(&env, b"student-42")),
+        locale: Some(Symbol::new(&env, "pt_BR")),
+        ref_uid: Some(BytesN::from_array(&env, &[7; 32])),
+    };
+    let value = attestation.to_json_value();
+    assert_eq!(value.uid, hex_of(&attestation.uid.to_array()));
+    assert_eq!(value.encrypted_key.as_deref(), Some("dead"));
+    assert_eq!(value.locale.as_deref(), Some("pt_BR"));
+    assert!(value.subject.starts_with('C'));
+    assert_eq!(
+        Attestation::from_json(&env, &attestation.to_json().unwrap()).unwrap(),
+        attestation
+    );
+
+    let request = DelegatedAttestationRequest {
+        schema_uid: BytesN::random(&env),
+        subject: Address::generate(&env),
+        attester: Address::generate(&env),
+        value: String::from_str(&env, "v"),
+        nonce: 1,
+        deadline: 3_000,
+        expiration_time: None,
+        version: 1,
+        signature: BytesN::random(&env),
+    };
+    let decoded = DelegatedAttestationRequest::from_json(&env, &request.to_json().unwrap()).unwrap();
+    assert_eq!(decoded.to_json_value(), request.to_json_value());
+
+    let revocation = DelegatedRevocationRequest {
+        attestation_uid: BytesN::random(&env),
+        schema_uid: BytesN::random(&env),
+        subject: Address::generate(&env),
+        nonce: 1,
+        revoker: Address::generate(&env),
+        deadline: 3_000,
+        version: 1,
+        signature: BytesN::random(&env),
+    };
+    let decoded = DelegatedRevocationRequest::from_json(&env, &revocation.to_json().unwrap()).unwrap();
+    assert_eq!(decoded.to_json_value(), revocation.to_json_value());
+}
+
+/// **Test: Invalid Field Values Are Reported Instead of Panicking**
+#[test]
+fn invalid_fields_are_rejected() {
+    let env = Env::default();
+    let schema = Schema {
+        authority: Address::generate(&env),
+        definition: String::from_str(&env, "def"),
+        resolver: None,
+        revocable: false,
+        namespace: None,
+        version: 1,
+        previous_uid: None,
+    };
+
+    let mut value = schema.to_json_value();
+    value.authority = "not-a-strkey".into();
+    assert!(matches!(
+        Schema::from_json_value(&env, value),
+        Err(JsonError::InvalidField("authority"))
+    ));
+
+    let mut value = schema.to_json_value();
+    value.namespace = Some("has space".into());
+    assert!(matches!(
+        Schema::from_json_value(&env, value),
+        Err(JsonError::InvalidField("namespace"))
+    ));
+
+    assert!(matches!(Schema::from_json(&env, "{}"), Err(JsonError::Json(_))));
+}
+
+fn hex_of(bytes: &[u8]) -> std::string::String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
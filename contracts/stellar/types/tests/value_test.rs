@@ -0,0 +1,73 @@
+use attest_types::value::{decode_value, encode_value, is_structured_value, FieldSpec, ValueError};
+use serde_json::{json, Map, Value};
+
+fn fields() -> Vec<FieldSpec> {
+    serde_json::from_value(json!([
+        {"name": "credential_type", "type": "string"},
+        {"name": "holder", "type": "address"},
+        {"name": "level", "type": "u32"},
+        {"name": "expires_at", "type": "u64", "optional": true},
+        {"name": "score", "type": "i128"},
+        {"name": "evidence", "type": "bytes", "optional": true},
+        {"name": "verified", "type": "bool"},
+    ]))
+    .unwrap()
+}
+
+fn object(value: Value) -> Map<String, Value> {
+    value.as_object().unwrap().clone()
+}
+
+/// **Test: Structured Values Round-Trip Through JSON**
+/// - Omitted and `null` optional fields decode as absent keys
+/// - Mistyped, unknown and missing fields are rejected, as are non-canonical payloads
+#[test]
+fn structured_values_round_trip_through_json() {
+    let value = object(json!({
+        "credential_type": "Certified Kubernetes Administrator",
+        "holder": "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7",
+        "level": 3,
+        "score": "-170141183460469231731687303715884105728",
+        "evidence": "deadbeef",
+        "verified": true,
+    }));
+    let encoded = encode_value(&fields(), &value).unwrap();
+    assert!(is_structured_value(&encoded));
+    assert_eq!(decode_value(&fields(), &encoded).unwrap(), value);
+
+    let mut with_null = value.clone();
+    with_null.insert("expires_at".into(), Value::Null);
+    assert_eq!(encode_value(&fields(), &with_null).unwrap(), encoded);
+
+    let mut mistyped = value.clone();
+    mistyped.insert("level".into(), json!(u64::from(u32::MAX) + 1));
+    assert_eq!(
+        encode_value(&fields(), &mistyped),
+        Err(ValueError::InvalidField("level".into()))
+    );
+    let mut unknown = value.clone();
+    unknown.insert("grade".into(), json!("A"));
+    assert_eq!(
+        encode_value(&fields(), &unknown),
+        Err(ValueError::InvalidField("grade".into()))
+    );
+    let mut missing = value.clone();
+    missing.remove("verified");
+    assert_eq!(
+        encode_value(&fields(), &missing),
+        Err(ValueError::Missing("verified".into()))
+    );
+
+    let flag: Vec<FieldSpec> = serde_json::from_value(json!([{"name": "flag", "type": "bool"}])).unwrap();
+    assert_eq!(decode_value(&flag, "BIN:AQEB").unwrap(), object(json!({"flag": true})));
+    for malformed in [
+        "{\"flag\":true}",
+        "BIN:AgEB",
+        "BIN:AQA=",
+        "BIN:AQMB",
+        "BIN:AQEC",
+        "BIN:AQEBAA==",
+    ] {
+        assert_eq!(decode_value(&flag, malformed), Err(ValueError::Malformed));
+    }
+}
@@ -0,0 +1,36 @@
+/**
+ * Tests for the Solana attestation UID helper
+ *
+ * The vector is shared with the `generate_attestation_uid` unit test in the Solana
+ * program's `utils`, so both sides derive the same UID.
+ */
+
+import { describe, it, expect } from 'vitest'
+import { PublicKey } from '@solana/web3.js'
+import { generateAttestationUid } from '../src/uid'
+
+describe('generateAttestationUid', () => {
+  const schemaUid = new PublicKey(Buffer.alloc(32, 1))
+  const recipient = new PublicKey(Buffer.alloc(32, 2))
+
+  it('matches the on-chain implementation', () => {
+    const uid = generateAttestationUid(schemaUid, recipient, BigInt(7))
+
+    expect(uid.toString('hex')).toBe(
+      'a8a6da8c913058ebb249eb765d8402ed53eb51c045bd777791a3adb2748277a1'
+    )
+  })
+
+  it('changes with the nonce', () => {
+    const uid1 = generateAttestationUid(schemaUid, recipient, BigInt(7))
+    const uid2 = generateAttestationUid(schemaUid, recipient, BigInt(8))
+
+    expect(uid1.equals(uid2)).toBe(false)
+  })
+
+  it('rejects negative nonces', () => {
+    expect(() => generateAttestationUid(schemaUid, recipient, BigInt(-1))).toThrow(
+      'nonce must be a non-negative BigInt'
+    )
+  })
+})
@@ -10,6 +10,9 @@ export { SolanaAttestProtocol } from './solana-sdk'
 // Export Solana-specific types
 export * from './types'
 
+// Export UID helpers shared with the on-chain program
+export { generateAttestationUid } from './uid'
+
 // Re-export core types for convenience
 export {
   type AttestProtocolResponse,
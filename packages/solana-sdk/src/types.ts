@@ -77,6 +77,9 @@ export interface SolanaFetchAuthorityResult {
   authority: anchor.web3.PublicKey
   isVerified: boolean
   firstDeployment: anchor.BN
+  verifiedBy: anchor.web3.PublicKey | null
+  verifiedAt: anchor.BN | null
+  evidenceUri: string
 }
 
 /**
@@ -93,6 +96,7 @@ export interface SolanaFetchSchemaResult {
     asset: anchor.web3.PublicKey
     recipient: anchor.web3.PublicKey
   }
+  rewardsProgram?: anchor.web3.PublicKey
 }
 
 /**
@@ -109,6 +113,7 @@ export interface SolanaFetchAttestationResult {
   revocationTime: anchor.BN
   revocable: boolean
   uid: anchor.web3.PublicKey
+  version: number
 }
 
 /**
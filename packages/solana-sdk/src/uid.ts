@@ -0,0 +1,40 @@
+/**
+ * UID Generation Utilities
+ *
+ * Deterministic attestation UIDs that match the Solana program's
+ * `generate_attestation_uid` byte for byte.
+ */
+
+import { PublicKey } from '@solana/web3.js'
+import { keccak256 } from 'js-sha3'
+
+/**
+ * Generate an attestation UID matching the Solana program implementation.
+ *
+ * @algorithm
+ * - Takes the 32 raw bytes of the schema UID
+ * - Takes the 32 raw bytes of the recipient public key
+ * - Converts nonce to 8-byte big-endian buffer
+ * - Computes Keccak-256 of the concatenated buffer
+ *
+ * @param schemaUid - The schema UID (PDA)
+ * @param recipient - The recipient of the attestation
+ * @param nonce - The nonce as a BigInt (corresponds to Rust u64)
+ * @returns A 32-byte buffer representing the attestation UID
+ */
+export function generateAttestationUid(
+  schemaUid: PublicKey,
+  recipient: PublicKey,
+  nonce: bigint
+): Buffer {
+  if (typeof nonce !== 'bigint' || nonce < BigInt(0)) {
+    throw new Error('nonce must be a non-negative BigInt')
+  }
+
+  const nonceBuffer = Buffer.alloc(8)
+  nonceBuffer.writeBigUInt64BE(nonce, 0)
+
+  const hashInput = Buffer.concat([schemaUid.toBuffer(), recipient.toBuffer(), nonceBuffer])
+
+  return Buffer.from(keccak256(hashInput), 'hex')
+}
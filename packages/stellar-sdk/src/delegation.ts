@@ -13,6 +13,12 @@ import { DelegatedAttestationRequest, DelegatedRevocationRequest } from './types
 import { WeierstrassPoint } from '@noble/curves/abstract/weierstrass.js'
 import { ContractError } from './common/errors'
 
+/**
+ * The delegated message format version produced by this SDK.
+ * Must match `delegation.rs::MESSAGE_VERSION`.
+ */
+export const MESSAGE_VERSION = 1
+
 /**
  * Create a message for signing delegated attestations.
  * Must match the exact format from `delegation.rs::create_attestation_message`.
@@ -23,12 +29,15 @@ import { ContractError } from './common/errors'
  */
 export function createAttestMessage(request: Omit<DelegatedAttestationRequest, 'signature'>, dst: Buffer): WeierstrassPoint<bigint> {
   // Match exact format from Rust contract:
-  // Domain Separator + Schema UID + Nonce + Deadline + [Expiration Time] + Value Length
+  // Domain Separator + Version + Schema UID + Nonce + Deadline + [Expiration Time] + Value Length
   const components: Buffer[] = []
 
   // Domain separation tag
   components.push(dst)
 
+  // Message format version (4 bytes, big-endian u32)
+  components.push(versionBytes(request.version))
+
   // Schema UID (32 bytes)
   components.push(request.schema_uid)
 
@@ -74,6 +83,9 @@ export function createRevokeMessage(request: Omit<DelegatedRevocationRequest, 's
   // Domain separation tag
   components.push(dst)
 
+  // Message format version (4 bytes, big-endian u32)
+  components.push(versionBytes(request.version))
+
   // Attestation UID (32 bytes)
   components.push(request.attestation_uid)
 
@@ -93,6 +105,12 @@ export function createRevokeMessage(request: Omit<DelegatedRevocationRequest, 's
   return bls12_381.shortSignatures.hash(sha256(message))
 }
 
+function versionBytes(version: number): Buffer {
+  const versionBuffer = Buffer.allocUnsafe(4)
+  versionBuffer.writeUInt32BE(version, 0)
+  return versionBuffer
+}
+
 /**
  * Get the domain separator tag for attestations from the contract.
  *
@@ -169,6 +187,7 @@ export async function createDelegatedAttestationRequest(
     deadline: params.deadline,
     nonce: await getAttesterNonce(client, params.attester),
     expiration_time: params.expirationTime ? BigInt(params.expirationTime) : undefined,
+    version: MESSAGE_VERSION,
   }
 }
 
@@ -196,6 +215,7 @@ export async function createDelegatedRevocationRequest(
     revoker: params.revoker,
     deadline: params.deadline,
     nonce: await getAttesterNonce(client, params.revoker),
+    version: MESSAGE_VERSION,
   }
 }
 
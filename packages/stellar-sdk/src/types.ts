@@ -115,6 +115,8 @@ export interface DelegatedAttestationRequest {
   subject: string
   /** The value or content of the attestation */
   value: string
+  /** Version of the signed message format */
+  version: number
 }
 
 /**
@@ -135,6 +137,8 @@ export interface DelegatedRevocationRequest {
   signature: Buffer
   /** The address of the entity that is the subject of the attestation to revoke */
   subject: string
+  /** Version of the signed message format */
+  version: number
 }
 
 /**